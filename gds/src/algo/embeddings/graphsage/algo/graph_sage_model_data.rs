@@ -8,6 +8,9 @@
 
 use crate::algo::embeddings::graphsage::activation_function_factory::ActivationFunctionFactory;
 use crate::algo::embeddings::graphsage::activation_function_wrapper::ActivationFunctionWrapper;
+use crate::algo::embeddings::graphsage::attention_aggregating_layer::AttentionAggregatingLayer;
+use crate::algo::embeddings::graphsage::attention_aggregator::AttentionHead;
+use crate::algo::embeddings::graphsage::gcn_aggregating_layer::GcnAggregatingLayer;
 use crate::algo::embeddings::graphsage::layer::Layer;
 use crate::algo::embeddings::graphsage::max_pool_aggregating_layer::MaxPoolAggregatingLayer;
 use crate::algo::embeddings::graphsage::mean_aggregating_layer::MeanAggregatingLayer;
@@ -17,7 +20,7 @@ use crate::algo::embeddings::graphsage::single_label_feature_function::SingleLab
 use crate::algo::embeddings::graphsage::types::ActivationFunctionType;
 use crate::core::model::ModelData as CatalogModelData;
 use crate::ml::core::functions::Weights;
-use crate::ml::core::tensor::{Matrix, Vector};
+use crate::ml::core::tensor::{Matrix, Tensor, Vector};
 use crate::types::schema::NodeLabel;
 use serde::{Deserialize, Serialize};
 use std::any::Any;
@@ -52,6 +55,40 @@ pub enum LayerData {
         neighbors_weights: MatrixData,
         bias: VectorData,
     },
+    Gcn {
+        sample_size: usize,
+        activation: ActivationFunctionType,
+        weights: MatrixData,
+    },
+    Attention {
+        sample_size: usize,
+        activation: ActivationFunctionType,
+        heads: Vec<AttentionHeadData>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttentionHeadData {
+    pub projection: MatrixData,
+    pub attention: MatrixData,
+}
+
+impl MatrixData {
+    pub fn of(matrix: &Matrix) -> Self {
+        Self {
+            rows: matrix.rows(),
+            cols: matrix.cols(),
+            data: matrix.data().to_vec(),
+        }
+    }
+
+    fn to_weights(&self) -> Arc<Weights> {
+        Arc::new(Weights::new(Box::new(Matrix::new(
+            self.data.clone(),
+            self.rows,
+            self.cols,
+        ))))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -133,6 +170,43 @@ impl GraphSageModelData {
                         activation_wrapper,
                     )) as Arc<dyn Layer>
                 }
+                LayerData::Gcn {
+                    sample_size,
+                    activation,
+                    weights,
+                } => {
+                    let activation_wrapper =
+                        ActivationFunctionFactory::activation_function_wrapper(*activation);
+                    let activation_wrapper: Arc<dyn ActivationFunctionWrapper> =
+                        Arc::from(activation_wrapper);
+                    Arc::new(GcnAggregatingLayer::new(
+                        weights.to_weights(),
+                        *sample_size,
+                        activation_wrapper,
+                    )) as Arc<dyn Layer>
+                }
+                LayerData::Attention {
+                    sample_size,
+                    activation,
+                    heads,
+                } => {
+                    let activation_wrapper =
+                        ActivationFunctionFactory::activation_function_wrapper(*activation);
+                    let activation_wrapper: Arc<dyn ActivationFunctionWrapper> =
+                        Arc::from(activation_wrapper);
+                    let heads = heads
+                        .iter()
+                        .map(|head| AttentionHead {
+                            projection: head.projection.to_weights(),
+                            attention: head.attention.to_weights(),
+                        })
+                        .collect();
+                    Arc::new(AttentionAggregatingLayer::new(
+                        *sample_size,
+                        heads,
+                        activation_wrapper,
+                    )) as Arc<dyn Layer>
+                }
             })
             .collect();
 
@@ -4,6 +4,7 @@ use crate::algo::embeddings::graphsage::algo::graph_sage_train::GraphSageTrain;
 use crate::algo::embeddings::graphsage::algo::multi_label_graph_sage_train::MultiLabelGraphSageTrain;
 use crate::algo::embeddings::graphsage::algo::single_label_graph_sage_train::SingleLabelGraphSageTrain;
 use crate::algo::embeddings::graphsage::types::GraphSageTrainConfig;
+use crate::config::validation::ConfigError;
 use crate::task::concurrency::TerminationFlag;
 use crate::task::progress::TaskProgressTracker;
 use crate::types::graph::Graph;
@@ -18,14 +19,17 @@ impl GraphSageTrainAlgorithmFactory {
         Self { gds_version }
     }
 
+    /// Validates the config against the graph before any training state is built.
     pub fn build(
         &self,
         graph: Arc<dyn Graph>,
         config: GraphSageTrainConfig,
         _progress_tracker: TaskProgressTracker,
         termination_flag: TerminationFlag,
-    ) -> Box<dyn GraphSageTrain> {
-        if config.is_multi_label {
+    ) -> Result<Box<dyn GraphSageTrain>, ConfigError> {
+        config.validate_against_graph(graph.as_ref())?;
+
+        Ok(if config.is_multi_label {
             Box::new(MultiLabelGraphSageTrain::new(
                 graph,
                 config,
@@ -39,6 +43,6 @@ impl GraphSageTrainAlgorithmFactory {
                 termination_flag,
                 self.gds_version.clone(),
             ))
-        }
+        })
     }
 }
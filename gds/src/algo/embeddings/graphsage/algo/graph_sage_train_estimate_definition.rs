@@ -33,11 +33,21 @@ impl MemoryEstimation for GraphSageTrainEstimateDefinition {
         for (i, layer) in self.parameters.layer_configs.iter().enumerate() {
             let base_dims = layer.rows * layer.cols;
             let mut weights_mem = Estimate::size_of_double_array(base_dims);
-            if layer.aggregator_type == AggregatorType::Pool {
-                // selfWeights (rows*cols), neighborsWeights (rows*rows), bias (rows)
-                weights_mem += Estimate::size_of_double_array(layer.rows * layer.cols);
-                weights_mem += Estimate::size_of_double_array(layer.rows * layer.rows);
-                weights_mem += Estimate::size_of_double_array(layer.rows);
+            match layer.aggregator_type {
+                AggregatorType::Pool => {
+                    // selfWeights (rows*cols), neighborsWeights (rows*rows), bias (rows)
+                    weights_mem += Estimate::size_of_double_array(layer.rows * layer.cols);
+                    weights_mem += Estimate::size_of_double_array(layer.rows * layer.rows);
+                    weights_mem += Estimate::size_of_double_array(layer.rows);
+                }
+                AggregatorType::Attention => {
+                    // further head projections (rows*cols) and one attention vector (2*rows) per head
+                    let heads = layer.attention_heads.max(1);
+                    weights_mem +=
+                        (heads - 1) * Estimate::size_of_double_array(layer.rows * layer.cols);
+                    weights_mem += heads * Estimate::size_of_double_array(2 * layer.rows);
+                }
+                AggregatorType::Mean | AggregatorType::Gcn => {}
             }
             weights_components.push(MemoryTree::leaf(
                 format!("layer {}", i + 1),
@@ -2,7 +2,7 @@
 
 use crate::algo::embeddings::graphsage::algo::graph_sage::MODEL_TYPE;
 use crate::algo::embeddings::graphsage::algo::graph_sage_model_data::{
-    FeatureFunctionData, GraphSageModelData, LayerData, MatrixData, VectorData,
+    AttentionHeadData, FeatureFunctionData, GraphSageModelData, LayerData, MatrixData, VectorData,
 };
 use crate::algo::embeddings::graphsage::feature_function::FeatureFunction;
 use crate::algo::embeddings::graphsage::graphsage_helper;
@@ -144,6 +144,41 @@ pub(super) fn serialize_layers(layers: &[Arc<dyn Layer>]) -> Vec<LayerData> {
                     },
                 });
             }
+            AggregatorType::Gcn => {
+                let w = agg.weights()[0].snapshot();
+                let m = w
+                    .as_any()
+                    .downcast_ref::<Matrix>()
+                    .expect("gcn weights must be Matrix");
+                out.push(LayerData::Gcn {
+                    sample_size: layer.sample_size(),
+                    activation: agg.activation_function_type(),
+                    weights: MatrixData::of(m),
+                });
+            }
+            AggregatorType::Attention => {
+                let heads = agg
+                    .weights()
+                    .chunks(2)
+                    .map(|head| {
+                        let projection = head[0].snapshot();
+                        let attention = head[1].snapshot();
+                        AttentionHeadData {
+                            projection: MatrixData::of(
+                                projection.as_any().downcast_ref::<Matrix>().unwrap(),
+                            ),
+                            attention: MatrixData::of(
+                                attention.as_any().downcast_ref::<Matrix>().unwrap(),
+                            ),
+                        }
+                    })
+                    .collect();
+                out.push(LayerData::Attention {
+                    sample_size: layer.sample_size(),
+                    activation: agg.activation_function_type(),
+                    heads,
+                });
+            }
         }
     }
     out
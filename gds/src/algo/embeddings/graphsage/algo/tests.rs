@@ -4,6 +4,7 @@ use crate::algo::embeddings::graphsage::algo::{GraphSageConfig, GraphSageStorage
 use crate::algo::embeddings::graphsage::{
     ActivationFunctionType, AggregatorType, GraphSageAlgorithmFactory,
     GraphSageMemoryEstimateDefinition, GraphSageTrainAlgorithmFactory, GraphSageTrainConfig,
+    GraphSageTrainMetrics,
 };
 #[cfg(test)]
 use crate::core::model::InMemoryModelCatalog;
@@ -12,6 +13,8 @@ use crate::core::ConcreteGraphDimensions;
 #[cfg(test)]
 use crate::core::ModelCatalog;
 #[cfg(test)]
+use crate::ml::core::optimizer::OptimizerConfig;
+#[cfg(test)]
 use crate::task::concurrency::{Concurrency, TerminationFlag};
 #[cfg(test)]
 use crate::task::memory::memory_estimation::MemoryEstimation;
@@ -60,14 +63,19 @@ fn graphsage_train_then_infer_via_catalog() {
         activation_function: ActivationFunctionType::Relu,
         is_multi_label: true,
         projected_feature_dimension: Some(4),
+        attention_heads: 1,
+        label_property: None,
+        optimizer: OptimizerConfig::default(),
     };
 
-    let train = GraphSageTrainAlgorithmFactory::new("1.0.0".to_string()).build(
-        Graph::concurrent_view(graph.as_ref()),
-        train_cfg.clone(),
-        TaskProgressTracker::new(Tasks::leaf_with_volume("GraphSageTrain".to_string(), 1)),
-        TerminationFlag::default(),
-    );
+    let train = GraphSageTrainAlgorithmFactory::new("1.0.0".to_string())
+        .build(
+            Graph::concurrent_view(graph.as_ref()),
+            train_cfg.clone(),
+            TaskProgressTracker::new(Tasks::leaf_with_volume("GraphSageTrain".to_string(), 1)),
+            TerminationFlag::default(),
+        )
+        .expect("valid train config");
     let model = train.compute();
 
     let catalog = InMemoryModelCatalog::new();
@@ -163,9 +171,185 @@ fn graphsage_memory_estimation_smoke() {
         activation_function: ActivationFunctionType::Relu,
         is_multi_label: false,
         projected_feature_dimension: None,
+        attention_heads: 1,
+        label_property: None,
+        optimizer: OptimizerConfig::default(),
     };
     let est = GraphSageMemoryEstimateDefinition::new(cfg, false);
     let tree = est.estimate(&dims, 4);
     assert!(!tree.description().is_empty());
     assert!(tree.memory_usage().max() > 0);
 }
+
+#[cfg(test)]
+fn labelled_graph_store(node_count: usize) -> DefaultGraphStore {
+    let mut store = DefaultGraphStore::random(&RandomGraphConfig {
+        node_count,
+        node_labels: vec!["N".into()],
+        relationships: vec![RandomRelationshipConfig::new("R", 0.2)],
+        seed: Some(3),
+        ..RandomGraphConfig::default()
+    })
+    .unwrap();
+    store
+        .add_node_property_f64(
+            "x".to_string(),
+            (0..node_count).map(|i| (i % 2) as f64 + 0.1).collect(),
+        )
+        .unwrap();
+    store
+        .add_node_property_i64(
+            "class".to_string(),
+            (0..node_count).map(|i| (i % 2) as i64).collect(),
+        )
+        .unwrap();
+    store
+}
+
+#[cfg(test)]
+fn single_label_train_config(
+    aggregator: AggregatorType,
+    attention_heads: usize,
+    label_property: Option<String>,
+) -> GraphSageTrainConfig {
+    GraphSageTrainConfig {
+        model_user: "alice".to_string(),
+        model_name: "m1".to_string(),
+        concurrency: Concurrency::of(1),
+        batch_size: 10,
+        max_iterations: 5,
+        search_depth: 2,
+        epochs: 2,
+        learning_rate: 0.05,
+        tolerance: 0.0,
+        negative_sample_weight: 1,
+        penalty_l2: 0.0,
+        embedding_dimension: 4,
+        sample_sizes: vec![3, 3],
+        feature_properties: vec!["x".to_string()],
+        maybe_batch_sampling_ratio: None,
+        random_seed: Some(42),
+        aggregator,
+        activation_function: ActivationFunctionType::Relu,
+        is_multi_label: false,
+        projected_feature_dimension: None,
+        attention_heads,
+        label_property,
+        optimizer: OptimizerConfig::default(),
+    }
+}
+
+#[cfg(test)]
+fn train_and_embed(
+    store: &DefaultGraphStore,
+    config: GraphSageTrainConfig,
+) -> (GraphSageTrainMetrics, Vec<Vec<f64>>) {
+    let graph = store.graph();
+    let model = GraphSageTrainAlgorithmFactory::new("1.0.0".to_string())
+        .build(
+            Graph::concurrent_view(graph.as_ref()),
+            config,
+            TaskProgressTracker::new(Tasks::leaf_with_volume("GraphSageTrain".to_string(), 1)),
+            TerminationFlag::default(),
+        )
+        .expect("valid train config")
+        .compute();
+    let metrics = model.custom_info().clone();
+
+    let catalog = InMemoryModelCatalog::new();
+    catalog.set(model).unwrap();
+    let result = GraphSageAlgorithmFactory::new(std::sync::Arc::new(catalog))
+        .build(
+            Graph::concurrent_view(graph.as_ref()),
+            "alice",
+            "m1",
+            Concurrency::of(1),
+            10,
+            TaskProgressTracker::new(Tasks::leaf_with_volume("GraphSage".to_string(), 1)),
+        )
+        .compute();
+
+    let embeddings = (0..result.embeddings.size())
+        .map(|node| result.embeddings.get(node).clone())
+        .collect();
+    (metrics, embeddings)
+}
+
+#[test]
+fn graphsage_gcn_train_then_infer() {
+    let store = labelled_graph_store(30);
+    let (metrics, embeddings) = train_and_embed(
+        &store,
+        single_label_train_config(AggregatorType::Gcn, 1, None),
+    );
+
+    assert!(!metrics.iteration_loss_per_epoch.is_empty());
+    assert_eq!(embeddings.len(), 30);
+    assert!(embeddings
+        .iter()
+        .all(|embedding| embedding.len() == 4 && embedding.iter().all(|v| v.is_finite())));
+}
+
+#[test]
+fn graphsage_supervised_attention_reduces_label_loss() {
+    let store = labelled_graph_store(40);
+    let (metrics, embeddings) = train_and_embed(
+        &store,
+        single_label_train_config(AggregatorType::Attention, 2, Some("class".to_string())),
+    );
+
+    let losses: Vec<f64> = metrics
+        .iteration_loss_per_epoch
+        .iter()
+        .flatten()
+        .copied()
+        .collect();
+    assert!(losses.iter().all(|loss| loss.is_finite()));
+    assert!(
+        losses.last().unwrap() < losses.first().unwrap(),
+        "losses: {losses:?}"
+    );
+    assert_eq!(embeddings.len(), 40);
+    assert!(embeddings.iter().all(|embedding| embedding.len() == 4));
+}
+
+#[test]
+fn graphsage_trains_with_configured_optimizer_and_schedule() {
+    use crate::ml::core::optimizer::LearningRateSchedule;
+
+    let store = labelled_graph_store(30);
+    let mut config = single_label_train_config(AggregatorType::Mean, 1, Some("class".to_string()));
+    config.optimizer = OptimizerConfig::sgd(0.9, true)
+        .with_schedule(LearningRateSchedule::cosine(10, 0.001).with_warmup(2));
+    config.validate().unwrap();
+
+    let (metrics, embeddings) = train_and_embed(&store, config);
+
+    assert_eq!(metrics.iteration_loss_per_epoch.len(), 2);
+    assert!(metrics
+        .iteration_loss_per_epoch
+        .iter()
+        .flatten()
+        .all(|loss| loss.is_finite()));
+    assert_eq!(embeddings.len(), 30);
+}
+
+#[test]
+fn graphsage_supervised_train_rejects_invalid_label_property() {
+    let store = labelled_graph_store(10);
+    let graph = store.graph();
+    for (label_property, reason) in [("missing", "does not exist"), ("x", "integer labels")] {
+        let result = GraphSageTrainAlgorithmFactory::new("1.0.0".to_string()).build(
+            Graph::concurrent_view(graph.as_ref()),
+            single_label_train_config(AggregatorType::Mean, 1, Some(label_property.to_string())),
+            TaskProgressTracker::new(Tasks::leaf_with_volume("GraphSageTrain".to_string(), 1)),
+            TerminationFlag::default(),
+        );
+        let error = match result {
+            Ok(_) => panic!("expected label property `{label_property}` to be rejected"),
+            Err(error) => error.to_string(),
+        };
+        assert!(error.contains("labelProperty"), "{error}");
+        assert!(error.contains(reason), "{error}");
+    }
+}
@@ -0,0 +1,40 @@
+//! Multi-head graph attention layer (no Java counterpart).
+
+use std::sync::Arc;
+
+use super::activation_function_wrapper::ActivationFunctionWrapper;
+use super::attention_aggregator::{AttentionAggregator, AttentionHead};
+use super::layer::Layer;
+
+pub struct AttentionAggregatingLayer {
+    sample_size: usize,
+    heads: Vec<AttentionHead>,
+    activation_function_wrapper: Arc<dyn ActivationFunctionWrapper>,
+}
+
+impl AttentionAggregatingLayer {
+    pub fn new(
+        sample_size: usize,
+        heads: Vec<AttentionHead>,
+        activation_function_wrapper: Arc<dyn ActivationFunctionWrapper>,
+    ) -> Self {
+        Self {
+            sample_size,
+            heads,
+            activation_function_wrapper,
+        }
+    }
+}
+
+impl Layer for AttentionAggregatingLayer {
+    fn sample_size(&self) -> usize {
+        self.sample_size
+    }
+
+    fn aggregator(&self) -> Box<dyn super::aggregator::Aggregator> {
+        Box::new(AttentionAggregator::new(
+            self.heads.clone(),
+            Arc::clone(&self.activation_function_wrapper),
+        ))
+    }
+}
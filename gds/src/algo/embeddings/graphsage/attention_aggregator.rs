@@ -0,0 +1,94 @@
+//! Multi-head graph attention aggregator (no Java counterpart).
+
+use crate::ml::core::functions::{
+    ConstantScale, GraphAttention, MatrixMultiplyWithTransposedSecondOperand, MatrixSum, Weights,
+};
+use crate::ml::core::subgraph::SubGraph;
+use crate::ml::core::variable::VariableRef;
+use std::sync::Arc;
+
+use super::activation_function_wrapper::ActivationFunctionWrapper;
+use super::aggregator::Aggregator;
+use super::types::{ActivationFunctionType, AggregatorType};
+
+/// One attention head: a projection of the previous layer and its attention vector.
+#[derive(Clone)]
+pub struct AttentionHead {
+    pub projection: Arc<Weights>,
+    pub attention: Arc<Weights>,
+}
+
+/// Averages several attention heads, so the output keeps the embedding dimension.
+pub struct AttentionAggregator {
+    heads: Vec<AttentionHead>,
+    activation_function_wrapper: Arc<dyn ActivationFunctionWrapper>,
+}
+
+impl AttentionAggregator {
+    pub fn new(
+        heads: Vec<AttentionHead>,
+        activation_function_wrapper: Arc<dyn ActivationFunctionWrapper>,
+    ) -> Self {
+        assert!(!heads.is_empty(), "Attention needs at least one head");
+        Self {
+            heads,
+            activation_function_wrapper,
+        }
+    }
+}
+
+impl Aggregator for AttentionAggregator {
+    fn aggregate(
+        &self,
+        previous_layer_representations: VariableRef,
+        sub_graph: &SubGraph,
+    ) -> VariableRef {
+        let head_outputs: Vec<VariableRef> = self
+            .heads
+            .iter()
+            .map(|head| {
+                let projected = Arc::new(MatrixMultiplyWithTransposedSecondOperand::new_ref(
+                    previous_layer_representations.clone(),
+                    Arc::clone(&head.projection) as VariableRef,
+                )) as VariableRef;
+
+                Arc::new(GraphAttention::new_ref(
+                    projected,
+                    Arc::clone(&head.attention) as VariableRef,
+                    Box::new(sub_graph.clone()),
+                )) as VariableRef
+            })
+            .collect();
+
+        let combined = if head_outputs.len() == 1 {
+            head_outputs.into_iter().next().expect("one head")
+        } else {
+            let head_count = head_outputs.len() as f64;
+            let sum = Arc::new(MatrixSum::new_ref(head_outputs)) as VariableRef;
+            Arc::new(ConstantScale::new_ref(sum, 1.0 / head_count)) as VariableRef
+        };
+
+        self.activation_function_wrapper
+            .activation_function()
+            .apply(combined)
+    }
+
+    fn weights(&self) -> Vec<Arc<Weights>> {
+        self.heads
+            .iter()
+            .flat_map(|head| [Arc::clone(&head.projection), Arc::clone(&head.attention)])
+            .collect()
+    }
+
+    fn weights_without_bias(&self) -> Vec<Arc<Weights>> {
+        self.weights()
+    }
+
+    fn typ(&self) -> AggregatorType {
+        AggregatorType::Attention
+    }
+
+    fn activation_function_type(&self) -> ActivationFunctionType {
+        self.activation_function_wrapper.activation_function_type()
+    }
+}
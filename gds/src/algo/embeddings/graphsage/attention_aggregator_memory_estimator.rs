@@ -0,0 +1,69 @@
+//! Memory estimator for the multi-head attention aggregator (no Java counterpart).
+
+use crate::task::memory::{Estimate, MemoryRange};
+
+use super::aggregator_memory_estimator::AggregatorMemoryEstimator;
+
+pub struct AttentionAggregatorMemoryEstimator {
+    heads: usize,
+}
+
+impl AttentionAggregatorMemoryEstimator {
+    pub fn new(heads: usize) -> Self {
+        Self { heads }
+    }
+
+    fn bound(
+        &self,
+        node_count: usize,
+        previous_node_count: usize,
+        embedding_dimension: usize,
+    ) -> usize {
+        // per head: projections of the previous layer, attention coefficients and the head output
+        let per_head = Estimate::size_of_double_array(previous_node_count * embedding_dimension)
+            + Estimate::size_of_double_array(previous_node_count)
+            + Estimate::size_of_double_array(node_count * embedding_dimension);
+        // head average and activation
+        self.heads * per_head + 2 * Estimate::size_of_double_array(node_count * embedding_dimension)
+    }
+}
+
+impl AggregatorMemoryEstimator for AttentionAggregatorMemoryEstimator {
+    fn estimate(
+        &self,
+        min_node_count: u64,
+        max_node_count: u64,
+        min_previous_node_count: u64,
+        max_previous_node_count: u64,
+        _input_dimension: usize,
+        embedding_dimension: usize,
+    ) -> MemoryRange {
+        MemoryRange::of_range(
+            self.bound(
+                min_node_count as usize,
+                min_previous_node_count as usize,
+                embedding_dimension,
+            ),
+            self.bound(
+                max_node_count as usize,
+                max_previous_node_count as usize,
+                embedding_dimension,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_with_heads() {
+        let single = AttentionAggregatorMemoryEstimator::new(1).estimate(10, 20, 50, 100, 4, 8);
+        let double = AttentionAggregatorMemoryEstimator::new(2).estimate(10, 20, 50, 100, 4, 8);
+
+        assert!(single.min() <= single.max());
+        assert!(double.min() > single.min());
+        assert!(double.max() > single.max());
+    }
+}
@@ -0,0 +1,41 @@
+//! GCN aggregating layer (no Java counterpart).
+
+use crate::ml::core::functions::Weights;
+use std::sync::Arc;
+
+use super::activation_function_wrapper::ActivationFunctionWrapper;
+use super::gcn_aggregator::GcnAggregator;
+use super::layer::Layer;
+
+pub struct GcnAggregatingLayer {
+    sample_size: usize,
+    weights: Arc<Weights>,
+    activation_function_wrapper: Arc<dyn ActivationFunctionWrapper>,
+}
+
+impl GcnAggregatingLayer {
+    pub fn new(
+        weights: Arc<Weights>,
+        sample_size: usize,
+        activation_function_wrapper: Arc<dyn ActivationFunctionWrapper>,
+    ) -> Self {
+        Self {
+            sample_size,
+            weights,
+            activation_function_wrapper,
+        }
+    }
+}
+
+impl Layer for GcnAggregatingLayer {
+    fn sample_size(&self) -> usize {
+        self.sample_size
+    }
+
+    fn aggregator(&self) -> Box<dyn super::aggregator::Aggregator> {
+        Box::new(GcnAggregator::new(
+            Arc::clone(&self.weights),
+            Arc::clone(&self.activation_function_wrapper),
+        ))
+    }
+}
@@ -0,0 +1,67 @@
+//! GCN aggregator (no Java counterpart).
+
+use crate::ml::core::functions::{
+    GcnAggregate, MatrixMultiplyWithTransposedSecondOperand, Weights,
+};
+use crate::ml::core::subgraph::SubGraph;
+use crate::ml::core::variable::VariableRef;
+use std::sync::Arc;
+
+use super::activation_function_wrapper::ActivationFunctionWrapper;
+use super::aggregator::Aggregator;
+use super::types::{ActivationFunctionType, AggregatorType};
+
+pub struct GcnAggregator {
+    weights: Arc<Weights>,
+    activation_function_wrapper: Arc<dyn ActivationFunctionWrapper>,
+}
+
+impl GcnAggregator {
+    pub fn new(
+        weights: Arc<Weights>,
+        activation_function_wrapper: Arc<dyn ActivationFunctionWrapper>,
+    ) -> Self {
+        Self {
+            weights,
+            activation_function_wrapper,
+        }
+    }
+}
+
+impl Aggregator for GcnAggregator {
+    fn aggregate(
+        &self,
+        previous_layer_representations: VariableRef,
+        sub_graph: &SubGraph,
+    ) -> VariableRef {
+        let normalized_sums = Arc::new(GcnAggregate::new_ref(
+            previous_layer_representations,
+            Box::new(sub_graph.clone()),
+        )) as VariableRef;
+
+        let product = Arc::new(MatrixMultiplyWithTransposedSecondOperand::new_ref(
+            normalized_sums,
+            Arc::clone(&self.weights) as VariableRef,
+        )) as VariableRef;
+
+        self.activation_function_wrapper
+            .activation_function()
+            .apply(product)
+    }
+
+    fn weights(&self) -> Vec<Arc<Weights>> {
+        vec![Arc::clone(&self.weights)]
+    }
+
+    fn weights_without_bias(&self) -> Vec<Arc<Weights>> {
+        vec![Arc::clone(&self.weights)]
+    }
+
+    fn typ(&self) -> AggregatorType {
+        AggregatorType::Gcn
+    }
+
+    fn activation_function_type(&self) -> ActivationFunctionType {
+        self.activation_function_wrapper.activation_function_type()
+    }
+}
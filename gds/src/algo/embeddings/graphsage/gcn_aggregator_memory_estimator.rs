@@ -0,0 +1,28 @@
+//! Memory estimator for the GCN aggregator (no Java counterpart).
+
+use crate::task::memory::{Estimate, MemoryRange};
+
+use super::aggregator_memory_estimator::AggregatorMemoryEstimator;
+
+pub struct GcnAggregatorMemoryEstimator;
+
+impl AggregatorMemoryEstimator for GcnAggregatorMemoryEstimator {
+    fn estimate(
+        &self,
+        min_node_count: u64,
+        max_node_count: u64,
+        min_previous_node_count: u64,
+        max_previous_node_count: u64,
+        input_dimension: usize,
+        embedding_dimension: usize,
+    ) -> MemoryRange {
+        // normalized sums, product and activation, plus the per-node source degrees
+        let min_bound = Estimate::size_of_double_array(min_node_count as usize * input_dimension)
+            + 2 * Estimate::size_of_double_array(min_node_count as usize * embedding_dimension)
+            + Estimate::size_of_long_array(min_previous_node_count as usize);
+        let max_bound = Estimate::size_of_double_array(max_node_count as usize * input_dimension)
+            + 2 * Estimate::size_of_double_array(max_node_count as usize * embedding_dimension)
+            + Estimate::size_of_long_array(max_previous_node_count as usize);
+        MemoryRange::of_range(min_bound, max_bound)
+    }
+}
@@ -220,6 +220,7 @@ mod tests {
             bias: None,
             aggregator_type: AggregatorType::Mean,
             activation_function: ActivationFunctionType::Relu,
+            attention_heads: 1,
         };
         let layers = vec![LayerFactory::create_layer(&layer_cfg)];
 
@@ -300,6 +301,7 @@ mod tests {
             bias: None,
             aggregator_type: AggregatorType::Mean,
             activation_function: ActivationFunctionType::Relu,
+            attention_heads: 1,
         };
         let layers = vec![LayerFactory::create_layer(&layer_cfg)];
 
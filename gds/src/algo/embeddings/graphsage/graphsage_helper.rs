@@ -209,6 +209,7 @@ pub fn layer_configs(
     aggregator_type: AggregatorType,
     activation_function: ActivationFunctionType,
     embedding_dimension: usize,
+    attention_heads: usize,
 ) -> Vec<LayerConfig> {
     let mut rng = ChaCha8Rng::seed_from_u64(random_seed.unwrap_or(0));
 
@@ -227,6 +228,7 @@ pub fn layer_configs(
             bias: None,
             aggregator_type,
             activation_function,
+            attention_heads,
         });
     }
     result
@@ -2,10 +2,9 @@
 
 use crate::collections::HugeObjectArray;
 use crate::core::model::ModelCatalogCustomInfo;
+use crate::core::utils::partition::PartitionUtils;
 use crate::ml::core::computation_context::ComputationContext;
 use crate::ml::core::functions::{ConstantScale, ElementSum, L2NormSquared, Weights};
-use crate::ml::core::optimizer::AdamOptimizer;
-use crate::ml::core::optimizer::Updater;
 use crate::ml::core::relationship_weights::{
     ClosureRelationshipWeights, RelationshipWeights, UNWEIGHTED,
 };
@@ -26,6 +25,7 @@ use super::graphsage_helper;
 use super::graphsage_loss::GraphSageLoss;
 use super::layer_factory::LayerFactory;
 use super::model_data::ModelData;
+use super::node_label_objective::NodeLabelObjective;
 use super::types::GraphSageTrainParameters;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -58,6 +58,7 @@ mod tests {
     use crate::algo::embeddings::graphsage::types::{
         ActivationFunctionType, AggregatorType, GraphSageTrainConfig,
     };
+    use crate::ml::core::optimizer::OptimizerConfig;
     use crate::task::concurrency::Concurrency;
     use crate::types::graph_store::DefaultGraphStore;
     use crate::types::random::{RandomGraphConfig, RandomRelationshipConfig};
@@ -131,6 +132,9 @@ mod tests {
             activation_function: ActivationFunctionType::Relu,
             is_multi_label: true,
             projected_feature_dimension: Some(projected_dim),
+            attention_heads: 1,
+            label_property: None,
+            optimizer: OptimizerConfig::default(),
         };
         let params = TrainConfigTransformer::to_parameters(&train_cfg);
 
@@ -185,25 +189,55 @@ impl GraphSageModelTrainer {
             .map(|cfg| LayerFactory::create_layer(cfg))
             .collect();
 
+        let objective = self
+            .parameters
+            .label_property
+            .as_ref()
+            .map(|label_property| {
+                Arc::new(NodeLabelObjective::new(
+                    graph.as_ref(),
+                    label_property,
+                    self.parameters.embedding_dimension,
+                    self.parameters.random_seed.unwrap_or(42),
+                ))
+            });
+
         // Collect all weights in the model
         let mut weights: Vec<Arc<Weights>> = Vec::new();
         weights.extend(self.label_projection_weights.iter().cloned());
         for layer in &layers {
             weights.extend(layer.aggregator().weights());
         }
+        if let Some(objective) = &objective {
+            weights.extend(objective.weights());
+        }
 
-        let batch_sampler = BatchSampler::new(Arc::clone(&graph), self.termination_flag.clone());
-        let extended_batches = batch_sampler.extended_batches(
-            self.parameters.batch_size,
-            self.parameters.search_depth,
-            self.parameters.random_seed.unwrap_or(42),
-        );
+        // The supervised objective only needs the batch nodes themselves, the unsupervised
+        // loss additionally samples a neighbor and a negative node per batch node.
+        let extended_batches = if objective.is_some() {
+            PartitionUtils::range_partition_with_batch_size(
+                graph.node_count(),
+                self.parameters.batch_size,
+                |batch| {
+                    (batch.start_node()..batch.start_node() + batch.node_count())
+                        .map(|node_id| node_id as u64)
+                        .collect()
+                },
+            )
+        } else {
+            BatchSampler::new(Arc::clone(&graph), self.termination_flag.clone()).extended_batches(
+                self.parameters.batch_size,
+                self.parameters.search_depth,
+                self.parameters.random_seed.unwrap_or(42),
+            )
+        };
 
         let mut rng = ChaCha8Rng::seed_from_u64(self.parameters.random_seed.unwrap_or(42));
 
         let mut iteration_losses_per_epoch: Vec<Vec<f64>> = Vec::new();
         let mut prev_epoch_loss = f64::NAN;
         let mut converged = false;
+        let mut update_steps = 0usize;
 
         let relationship_weights: Arc<dyn RelationshipWeights> =
             if graph.has_relationship_property() {
@@ -220,7 +254,12 @@ impl GraphSageModelTrainer {
 
             let batches_per_iter = self.parameters.batches_per_iteration(graph.node_count());
 
-            let mut updater = AdamOptimizer::new(weights.clone(), self.parameters.learning_rate);
+            // Java recreates the Adam updater per epoch; learning rate schedules continue.
+            let mut updater = self.parameters.optimizer.create_updater_at_step(
+                weights.clone(),
+                self.parameters.learning_rate,
+                update_steps,
+            );
 
             let mut prev_loss = prev_epoch_loss;
             let mut epoch_losses = Vec::new();
@@ -253,6 +292,7 @@ impl GraphSageModelTrainer {
                         let negative_sample_weight = self.parameters.negative_sample_weight;
                         let node_count = graph.node_count().max(1);
                         let label_projection_weight_count = self.label_projection_weights.len();
+                        let objective = objective.clone();
                         Box::new(move || {
                             let res = run_batch(
                                 graph,
@@ -266,6 +306,7 @@ impl GraphSageModelTrainer {
                                 penalty_l2,
                                 node_count,
                                 label_projection_weight_count,
+                                objective,
                             );
                             results.lock().push(res);
                         }) as Box<dyn FnOnce() + Send>
@@ -297,6 +338,7 @@ impl GraphSageModelTrainer {
                 // Average gradients across batch tasks and update weights
                 let mean_gradients = average_gradients(&results);
                 updater.update(&mean_gradients);
+                update_steps += 1;
             }
 
             prev_epoch_loss = *epoch_losses.last().unwrap_or(&prev_epoch_loss);
@@ -335,6 +377,7 @@ fn run_batch(
     penalty_l2: f64,
     graph_node_count: usize,
     label_projection_weight_count: usize,
+    objective: Option<Arc<NodeLabelObjective>>,
 ) -> BatchResult {
     let sub_graphs = graphsage_helper::sub_graphs_per_layer(
         Arc::clone(&graph),
@@ -353,12 +396,18 @@ fn run_batch(
     let embedding_var =
         graphsage_helper::embeddings_computation_graph(&sub_graphs, &layers, batched_features);
 
-    let loss_without_penalty: VariableRef = Arc::new(GraphSageLoss::new(
-        relationship_weights,
-        embedding_var,
-        batch.clone(),
-        negative_sampling_factor,
-    ));
+    let (loss_without_penalty, original_batch_size): (VariableRef, usize) = match &objective {
+        Some(objective) => (objective.loss(embedding_var, &batch), batch.len()),
+        None => (
+            Arc::new(GraphSageLoss::new(
+                relationship_weights,
+                embedding_var,
+                batch.clone(),
+                negative_sampling_factor,
+            )),
+            batch.len() / 3,
+        ),
+    };
 
     let loss: VariableRef = if penalty_l2 > 0.0 {
         // Java regularizes weights without bias, plus label-projection weights (if any).
@@ -378,7 +427,6 @@ fn run_batch(
         let penalty_sum: VariableRef = Arc::new(ElementSum::new_ref(l2_terms));
 
         // Java scales by originalBatchSize / nodeCount.
-        let original_batch_size = batch.len() / 3;
         let scale = penalty_l2 * (original_batch_size as f64) / (graph_node_count as f64);
         let scaled_penalty: VariableRef = Arc::new(ConstantScale::new_ref(penalty_sum, scale));
 
@@ -9,6 +9,9 @@ use std::sync::Arc;
 
 use super::activation_function_factory::ActivationFunctionFactory;
 use super::activation_function_wrapper::ActivationFunctionWrapper;
+use super::attention_aggregating_layer::AttentionAggregatingLayer;
+use super::attention_aggregator::AttentionHead;
+use super::gcn_aggregating_layer::GcnAggregatingLayer;
 use super::layer::Layer;
 use super::max_pool_aggregating_layer::MaxPoolAggregatingLayer;
 use super::mean_aggregating_layer::MeanAggregatingLayer;
@@ -66,6 +69,39 @@ impl LayerFactory {
                     activation_wrapper,
                 ))
             }
+            AggregatorType::Gcn => Arc::new(GcnAggregatingLayer::new(
+                weights,
+                layer_config.sample_size,
+                activation_wrapper,
+            )),
+            AggregatorType::Attention => {
+                let heads = (0..layer_config.attention_heads as u64)
+                    .map(|head| AttentionHead {
+                        projection: if head == 0 {
+                            Arc::clone(&weights)
+                        } else {
+                            Arc::new(generate_weights(
+                                rows,
+                                cols,
+                                activation_wrapper.weight_init_bound(rows, cols),
+                                random_seed + 2 * head,
+                            ))
+                        },
+                        attention: Arc::new(generate_weights(
+                            1,
+                            2 * rows,
+                            activation_wrapper.weight_init_bound(1, 2 * rows),
+                            random_seed + 2 * head + 1,
+                        )),
+                    })
+                    .collect();
+
+                Arc::new(AttentionAggregatingLayer::new(
+                    layer_config.sample_size,
+                    heads,
+                    activation_wrapper,
+                ))
+            }
         }
     }
 }
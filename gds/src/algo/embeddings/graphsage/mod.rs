@@ -4,8 +4,14 @@ pub mod activation_function_wrapper;
 pub mod aggregator;
 pub mod aggregator_memory_estimator;
 pub mod algo;
+pub mod attention_aggregating_layer;
+pub mod attention_aggregator;
+pub mod attention_aggregator_memory_estimator;
 pub mod batch_sampler;
 pub mod feature_function;
+pub mod gcn_aggregating_layer;
+pub mod gcn_aggregator;
+pub mod gcn_aggregator_memory_estimator;
 pub mod graphsage_embeddings_generator;
 pub mod graphsage_helper;
 pub mod graphsage_loss;
@@ -20,6 +26,7 @@ pub mod mean_aggregator;
 pub mod mean_aggregator_memory_estimator;
 pub mod model_data;
 pub mod multi_label_feature_function;
+pub mod node_label_objective;
 pub mod pool_aggregator_memory_estimator;
 pub mod relu_wrapper;
 pub mod sigmoid_wrapper;
@@ -33,8 +40,14 @@ pub use activation_function_wrapper::*;
 pub use aggregator::*;
 pub use aggregator_memory_estimator::*;
 pub use algo::*;
+pub use attention_aggregating_layer::*;
+pub use attention_aggregator::*;
+pub use attention_aggregator_memory_estimator::*;
 pub use batch_sampler::*;
 pub use feature_function::*;
+pub use gcn_aggregating_layer::*;
+pub use gcn_aggregator::*;
+pub use gcn_aggregator_memory_estimator::*;
 pub use graphsage_embeddings_generator::*;
 pub use graphsage_helper::*;
 pub use graphsage_loss::*;
@@ -48,6 +61,7 @@ pub use mean_aggregator::*;
 pub use mean_aggregator_memory_estimator::*;
 pub use model_data::*;
 pub use multi_label_feature_function::*;
+pub use node_label_objective::*;
 pub use pool_aggregator_memory_estimator::*;
 pub use relu_wrapper::*;
 pub use sigmoid_wrapper::*;
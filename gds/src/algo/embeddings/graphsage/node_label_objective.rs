@@ -0,0 +1,105 @@
+//! Supervised GraphSAGE objective (no Java counterpart).
+//!
+//! Instead of the unsupervised random-walk loss, embeddings are trained to predict an
+//! integer node label through a softmax layer on top of the final GraphSAGE layer.
+
+use crate::ml::core::functions::{
+    Constant, CrossEntropyLoss, MatrixMultiplyWithTransposedSecondOperand, MatrixVectorSum,
+    Softmax, Weights,
+};
+use crate::ml::core::tensor::Vector;
+use crate::ml::core::variable::VariableRef;
+use crate::types::graph::Graph;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use super::layer_factory::generate_weights;
+
+pub struct NodeLabelObjective {
+    classes: Arc<Vec<usize>>,
+    class_count: usize,
+    weights: Arc<Weights>,
+    bias: Arc<Weights>,
+}
+
+impl NodeLabelObjective {
+    /// Panics on a missing or non-integer label property; training entry points check this
+    /// up front through `GraphSageTrainConfig::validate_against_graph`.
+    pub fn new(
+        graph: &dyn Graph,
+        label_property: &str,
+        embedding_dimension: usize,
+        random_seed: u64,
+    ) -> Self {
+        let values = graph.node_properties(label_property).unwrap_or_else(|| {
+            panic!("Missing node label property `{label_property}` for supervised GraphSAGE.")
+        });
+
+        let labels: Vec<i64> = (0..graph.node_count() as u64)
+            .map(|node_id| {
+                values.long_value(node_id).unwrap_or_else(|e| {
+                    panic!("Node label property `{label_property}` must hold integers: {e}")
+                })
+            })
+            .collect();
+
+        // dense class ids in ascending label order
+        let mut class_ids = BTreeMap::new();
+        for &label in &labels {
+            class_ids.entry(label).or_insert(0);
+        }
+        for (class_id, id) in class_ids.values_mut().enumerate() {
+            *id = class_id;
+        }
+        let classes: Vec<usize> = labels.iter().map(|label| class_ids[label]).collect();
+        let class_count = class_ids.len().max(1);
+
+        let weight_bound = (6.0 / (embedding_dimension + class_count) as f64).sqrt();
+        Self {
+            classes: Arc::new(classes),
+            class_count,
+            weights: Arc::new(generate_weights(
+                class_count,
+                embedding_dimension,
+                weight_bound,
+                random_seed,
+            )),
+            bias: Arc::new(Weights::new(Box::new(Vector::create(0.0, class_count)))),
+        }
+    }
+
+    pub fn class_count(&self) -> usize {
+        self.class_count
+    }
+
+    /// Weights of the softmax layer, trained alongside the GraphSAGE layers.
+    pub fn weights(&self) -> Vec<Arc<Weights>> {
+        vec![Arc::clone(&self.weights), Arc::clone(&self.bias)]
+    }
+
+    /// Cross entropy of the predicted labels for the batch nodes, one embedding row each.
+    pub fn loss(&self, embeddings: VariableRef, batch: &[u64]) -> VariableRef {
+        let logits = Arc::new(MatrixMultiplyWithTransposedSecondOperand::new_ref(
+            embeddings,
+            Arc::clone(&self.weights) as VariableRef,
+        )) as VariableRef;
+        let biased = Arc::new(MatrixVectorSum::new_ref(
+            logits,
+            Arc::clone(&self.bias) as VariableRef,
+        )) as VariableRef;
+        let probabilities = Arc::new(Softmax::new_ref(biased)) as VariableRef;
+
+        let targets = Arc::new(Constant::vector(
+            batch
+                .iter()
+                .map(|&node_id| self.classes[node_id as usize] as f64)
+                .collect(),
+        )) as VariableRef;
+
+        Arc::new(CrossEntropyLoss::new_ref(
+            probabilities,
+            targets,
+            vec![1.0; self.class_count],
+        ))
+    }
+}
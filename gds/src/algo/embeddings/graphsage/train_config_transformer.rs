@@ -22,6 +22,7 @@ impl TrainConfigTransformer {
             config.aggregator,
             config.activation_function,
             config.embedding_dimension,
+            config.attention_heads,
         );
 
         GraphSageTrainMemoryEstimateParameters {
@@ -54,6 +55,9 @@ impl TrainConfigTransformer {
             activation_function: config.activation_function,
             is_multi_label: config.is_multi_label,
             projected_feature_dimension: config.projected_feature_dimension,
+            attention_heads: config.attention_heads,
+            label_property: config.label_property.clone(),
+            optimizer: config.optimizer.clone(),
         }
     }
 }
@@ -6,6 +6,9 @@ use crate::task::concurrency::Concurrency;
 use crate::config::base_types::{BaseConfig, Config};
 use crate::config::validation::ConfigError;
 use crate::core::model::ModelConfig;
+use crate::ml::core::optimizer::OptimizerConfig;
+use crate::types::graph::Graph;
+use crate::ValueType;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -19,6 +22,17 @@ pub enum ActivationFunctionType {
 pub enum AggregatorType {
     Mean,
     Pool,
+    /// Symmetrically normalized graph convolution.
+    Gcn,
+    /// Multi-head graph attention.
+    Attention,
+}
+
+/// Number of attention heads used when `attentionHeads` is not configured.
+pub const DEFAULT_ATTENTION_HEADS: usize = 1;
+
+fn default_attention_heads() -> usize {
+    DEFAULT_ATTENTION_HEADS
 }
 
 /// Rust counterpart to Java `LayerConfig` (value class).
@@ -31,6 +45,9 @@ pub struct LayerConfig {
     pub bias: Option<usize>,
     pub aggregator_type: AggregatorType,
     pub activation_function: ActivationFunctionType,
+    /// Only used by `AggregatorType::Attention`.
+    #[serde(default = "default_attention_heads")]
+    pub attention_heads: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,6 +73,14 @@ pub struct GraphSageTrainConfig {
     pub activation_function: ActivationFunctionType,
     pub is_multi_label: bool,
     pub projected_feature_dimension: Option<usize>,
+    #[serde(default = "default_attention_heads")]
+    pub attention_heads: usize,
+    /// Integer node property to train against; unsupervised when absent.
+    #[serde(default)]
+    pub label_property: Option<String>,
+    /// Optimizer and learning rate schedule; one update is performed per iteration.
+    #[serde(default)]
+    pub optimizer: OptimizerConfig,
 }
 
 impl Config for GraphSageTrainConfig {}
@@ -83,6 +108,13 @@ impl BaseConfig for GraphSageTrainConfig {
         if let Some(v) = self.projected_feature_dimension {
             m.insert("projectedFeatureDimension".into(), (v as u64).into());
         }
+        if self.aggregator == AggregatorType::Attention {
+            m.insert("attentionHeads".into(), (self.attention_heads as u64).into());
+        }
+        if let Some(label_property) = &self.label_property {
+            m.insert("labelProperty".into(), label_property.clone().into());
+        }
+        m.extend(self.optimizer.to_map());
         m
     }
 }
@@ -126,6 +158,18 @@ impl GraphSageTrainConfig {
                 reason: "embeddingDimension must be > 0".to_string(),
             });
         }
+        if self.attention_heads == 0 {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "attentionHeads".to_string(),
+                reason: "attentionHeads must be > 0".to_string(),
+            });
+        }
+        if matches!(&self.label_property, Some(label_property) if label_property.is_empty()) {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "labelProperty".to_string(),
+                reason: "labelProperty must not be empty".to_string(),
+            });
+        }
         for &s in &self.sample_sizes {
             if s == 0 {
                 return Err(ConfigError::InvalidParameter {
@@ -134,6 +178,30 @@ impl GraphSageTrainConfig {
                 });
             }
         }
+        self.optimizer.validate()
+    }
+
+    /// Checks the config against the graph to train on: the label property of supervised
+    /// training must exist and hold integer class labels.
+    pub fn validate_against_graph(&self, graph: &dyn Graph) -> Result<(), ConfigError> {
+        let Some(label_property) = &self.label_property else {
+            return Ok(());
+        };
+        let values = graph
+            .node_properties(label_property)
+            .ok_or_else(|| ConfigError::InvalidParameter {
+                parameter: "labelProperty".to_string(),
+                reason: format!("node property `{label_property}` does not exist in the graph"),
+            })?;
+        if values.value_type() != ValueType::Long {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "labelProperty".to_string(),
+                reason: format!(
+                    "node property `{label_property}` must hold integer labels, got {:?}",
+                    values.value_type()
+                ),
+            });
+        }
         Ok(())
     }
 }
@@ -164,6 +232,9 @@ pub struct GraphSageTrainParameters {
     pub activation_function: ActivationFunctionType,
     pub is_multi_label: bool,
     pub projected_feature_dimension: Option<usize>,
+    pub attention_heads: usize,
+    pub label_property: Option<String>,
+    pub optimizer: OptimizerConfig,
 }
 
 impl GraphSageTrainParameters {
@@ -185,6 +256,7 @@ impl GraphSageTrainParameters {
             self.aggregator,
             self.activation_function,
             self.embedding_dimension,
+            self.attention_heads,
         )
     }
 }
@@ -16,6 +16,17 @@ impl CompressedRandomWalks {
         }
     }
 
+    /// Wrap pre-built sequences (token ids, walk node ids) for training.
+    pub fn from_sequences(sequences: Vec<Vec<i64>>) -> Self {
+        let max_walk_length = sequences.iter().map(Vec::len).max().unwrap_or(0);
+        let size = sequences.len();
+        Self {
+            walks: sequences,
+            max_walk_length,
+            size,
+        }
+    }
+
     pub fn add(&mut self, _index: usize, walk: &[i64]) {
         self.walks.push(walk.to_vec());
         self.max_walk_length = self.max_walk_length.max(walk.len());
@@ -1,13 +1,28 @@
 //! Node2Vec model implementation with skip-gram training.
+//!
+//! The trainer only sees integer sequences, so it is shared with Word2Vec:
+//! build it with [`Node2VecModel::for_sequences`] and pick the objective.
 
 use super::compressed_random_walks::CompressedRandomWalks;
-use super::random_walk_probabilities::NegativeSamplingDistribution;
+use super::random_walk_probabilities::{
+    NegativeSamplingDistribution, RandomWalkProbabilitiesBuilder,
+};
 use super::train_parameters::{EmbeddingInitializer, TrainParameters};
 use crate::task::concurrency::TerminatedException;
 use crate::task::concurrency::{Concurrency, TerminationFlag};
 use crate::task::progress::ProgressTracker;
 use rand::prelude::*;
 
+/// What each window position is trained to predict.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SequenceObjective {
+    /// Predict every context item from the center item.
+    #[default]
+    SkipGram,
+    /// Predict the center item from the mean of its context items.
+    Cbow,
+}
+
 #[derive(Debug)]
 pub struct TrainedNode2Vec {
     pub embeddings: Vec<Vec<f64>>,
@@ -23,6 +38,7 @@ pub struct Node2VecModel {
     positive_sampling_probabilities: Vec<f64>,
     negative_sampling_distribution: NegativeSamplingDistribution,
     termination_flag: TerminationFlag,
+    objective: SequenceObjective,
 }
 
 impl Node2VecModel {
@@ -45,9 +61,50 @@ impl Node2VecModel {
             positive_sampling_probabilities,
             negative_sampling_distribution,
             termination_flag,
+            objective: SequenceObjective::SkipGram,
         }
     }
 
+    /// Trainer over arbitrary id sequences in `0..vocabulary_size`.
+    ///
+    /// Subsampling and negative-sampling statistics are counted from the
+    /// sequences themselves, as Node2Vec does for its walks.
+    pub fn for_sequences(
+        vocabulary_size: usize,
+        train_params: TrainParameters,
+        positive_sampling_factor: f64,
+        negative_sampling_exponent: f64,
+        random_seed: Option<u64>,
+        sequences: CompressedRandomWalks,
+        termination_flag: TerminationFlag,
+    ) -> Self {
+        let mut builder = RandomWalkProbabilitiesBuilder::new(Concurrency::of(1));
+        for sequence in sequences.walks() {
+            builder.register_walk(sequence);
+        }
+        let probabilities = builder.build();
+        let positive_sampling_probabilities = probabilities
+            .positive_sampling_probabilities(vocabulary_size, positive_sampling_factor);
+        let negative_sampling_distribution = probabilities
+            .negative_sampling_distribution(vocabulary_size, negative_sampling_exponent);
+
+        Self::new(
+            vocabulary_size,
+            train_params,
+            Concurrency::of(1),
+            random_seed,
+            sequences,
+            positive_sampling_probabilities,
+            negative_sampling_distribution,
+            termination_flag,
+        )
+    }
+
+    pub fn with_objective(mut self, objective: SequenceObjective) -> Self {
+        self.objective = objective;
+        self
+    }
+
     pub fn train(
         self,
         progress_tracker: &mut dyn ProgressTracker,
@@ -103,6 +160,25 @@ impl Node2VecModel {
                     let start = i.saturating_sub(prefix_window_size);
                     let end = (i + postfix_window_size + 1).min(sampled_walk.len());
 
+                    if self.objective == SequenceObjective::Cbow {
+                        let context: Vec<usize> = (start..end)
+                            .filter(|&j| j != i)
+                            .map(|j| sampled_walk[j])
+                            .collect();
+                        if context.is_empty() {
+                            continue;
+                        }
+                        total_loss += self.train_context(
+                            center_node,
+                            &context,
+                            &mut center_embeddings,
+                            &mut context_embeddings,
+                            learning_rate,
+                            &mut rng,
+                        );
+                        continue;
+                    }
+
                     for (j, &context_node) in sampled_walk.iter().enumerate().take(end).skip(start)
                     {
                         if i == j {
@@ -185,6 +261,63 @@ impl Node2VecModel {
 
         loss
     }
+
+    /// CBOW step: the mean of the context input vectors predicts the
+    /// center's output vector; the hidden gradient is shared by the context.
+    fn train_context(
+        &self,
+        center_idx: usize,
+        context: &[usize],
+        input_embeddings: &mut [Vec<f64>],
+        output_embeddings: &mut [Vec<f64>],
+        learning_rate: f64,
+        rng: &mut impl Rng,
+    ) -> f64 {
+        const EPSILON: f64 = 1e-10;
+
+        let dimension = self.train_params.embedding_dimension;
+        let mut hidden = vec![0.0; dimension];
+        for &idx in context {
+            for (h, value) in hidden.iter_mut().zip(&input_embeddings[idx]) {
+                *h += value;
+            }
+        }
+        let scale = 1.0 / context.len() as f64;
+        hidden.iter_mut().for_each(|h| *h *= scale);
+
+        let mut hidden_gradient = vec![0.0; dimension];
+        let mut step = |target: usize, label: f64, output_embeddings: &mut [Vec<f64>]| {
+            let probability = sigmoid(inner_product(&hidden, &output_embeddings[target]));
+            let gradient = (label - probability) * learning_rate;
+            for ((g, output), h) in hidden_gradient
+                .iter_mut()
+                .zip(output_embeddings[target].iter_mut())
+                .zip(&hidden)
+            {
+                *g += gradient * *output;
+                *output += gradient * h;
+            }
+            probability
+        };
+
+        let mut loss = -(step(center_idx, 1.0, output_embeddings) + EPSILON).ln();
+        for _ in 0..self.train_params.negative_sampling_rate {
+            let Some(neg_idx) = self
+                .negative_sampling_distribution
+                .sample_excluding(center_idx, rng)
+            else {
+                break;
+            };
+            loss -= (1.0 - step(neg_idx, 0.0, output_embeddings) + EPSILON).ln();
+        }
+
+        for &idx in context {
+            for (value, g) in input_embeddings[idx].iter_mut().zip(&hidden_gradient) {
+                *value += g;
+            }
+        }
+        loss
+    }
 }
 
 fn inner_product(left: &[f64], right: &[f64]) -> f64 {
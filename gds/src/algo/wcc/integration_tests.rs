@@ -20,6 +20,8 @@ mod tests {
     use crate::types::graph_store::{
         Capabilities, DatabaseId, DatabaseInfo, DatabaseLocation, DefaultGraphStore, GraphName,
     };
+    use crate::types::properties::node::MappedLongNodePropertyValues;
+    use crate::types::properties::AccessPattern;
     use crate::types::schema::{Direction, MutableGraphSchema};
     use serde_json::json;
 
@@ -68,6 +70,11 @@ mod tests {
             }
         }
 
+        store_from_topology(RelationshipTopology::new(outgoing, Some(incoming)))
+    }
+
+    fn store_from_topology(topology: RelationshipTopology) -> DefaultGraphStore {
+        let node_count = topology.node_capacity();
         let rel_type = RelationshipType::of("REL");
 
         let mut schema_builder = MutableGraphSchema::empty();
@@ -77,10 +84,7 @@ mod tests {
         let schema = schema_builder.build();
 
         let mut relationship_topologies = HashMap::new();
-        relationship_topologies.insert(
-            rel_type,
-            RelationshipTopology::new(outgoing, Some(incoming)),
-        );
+        relationship_topologies.insert(rel_type, topology);
 
         let original_ids: Vec<i64> = (0..node_count)
             .map(|node| i64::try_from(node).expect("fixture node must fit the original ID domain"))
@@ -178,4 +182,32 @@ mod tests {
         assert_eq!(stats.node_count, 4);
         assert_eq!(stats.component_count, 2);
     }
+
+    #[test]
+    fn wcc_runs_on_memory_mapped_topology_and_seeds() {
+        let topology_path =
+            std::env::temp_dir().join(format!("gds-wcc-topology-{}", uuid::Uuid::new_v4()));
+        let seed_path = std::env::temp_dir().join(format!("gds-wcc-seed-{}", uuid::Uuid::new_v4()));
+        RelationshipTopology::new(vec![vec![node(1)], vec![], vec![node(3)], vec![]], None)
+            .write_mapped(&topology_path)
+            .unwrap();
+        MappedLongNodePropertyValues::write(&seed_path, &[7, 7, 9, 9]).unwrap();
+
+        let topology =
+            RelationshipTopology::open_mapped(&topology_path, AccessPattern::Sequential).unwrap();
+        let mut store = store_from_topology(topology);
+        store
+            .add_mapped_node_property("seed".to_string(), &seed_path, AccessPattern::Sequential)
+            .unwrap();
+        let graph = GraphFacade::new(Arc::new(store));
+
+        let result = graph.wcc().run().unwrap();
+        assert_eq!(result.components, vec![0, 0, 2, 2]);
+        let seeded = graph.wcc().seed_property("seed").run().unwrap();
+        assert_eq!(seeded.components, vec![7, 7, 9, 9]);
+
+        drop(graph);
+        std::fs::remove_file(topology_path).unwrap();
+        std::fs::remove_file(seed_path).unwrap();
+    }
 }
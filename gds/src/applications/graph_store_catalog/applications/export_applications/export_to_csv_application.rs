@@ -1,5 +1,6 @@
 use crate::applications::graph_store_catalog::results::ExportResult;
 use crate::projection::RelationshipType;
+use crate::types::graph::ExternalNodeKey;
 use crate::types::graph::IdMap as _;
 use crate::types::graph::MappedNodeId;
 use crate::types::graph_store::DefaultGraphStore;
@@ -60,18 +61,18 @@ impl ExportToCsvApplication {
         for mapped_index in 0..node_count {
             let mapped_node_id = MappedNodeId::try_from(mapped_index)
                 .map_err(|_| "Graph node count exceeds mapped ID space".to_string())?;
-            let original_id = graph
-                .to_original_node_id(mapped_node_id)
+            let node_key = graph
+                .external_node_key(mapped_node_id)
                 .ok_or_else(|| format!("No original node ID for mapped node {mapped_node_id}"))?;
             let labels = graph
                 .node_labels(mapped_node_id)
                 .into_iter()
-                .map(|l| l.name().replace('"', "\""))
+                .map(|l| l.name().replace('"', "\"\""))
                 .collect::<Vec<_>>()
                 .join(";");
 
             // CSV: quote labels to keep delimiter-stable.
-            writeln!(file, "{},\"{}\"", original_id.get(), labels)
+            writeln!(file, "{},\"{}\"", csv_node_key(&node_key), labels)
                 .map_err(|e| format!("Failed to write '{}': {e}", file_path.display()))?;
         }
 
@@ -121,20 +122,24 @@ impl ExportToCsvApplication {
         for mapped_index in 0..node_count {
             let mapped_source = MappedNodeId::try_from(mapped_index)
                 .map_err(|_| "Graph node count exceeds mapped ID space".to_string())?;
-            let source_original = filtered
-                .to_original_node_id(mapped_source)
+            let source_key = filtered
+                .external_node_key(mapped_source)
                 .ok_or_else(|| format!("No original node ID for mapped node {mapped_source}"))?;
 
             for cursor in
                 filtered.stream_relationships(mapped_source, filtered.default_property_value())
             {
                 let mapped_target = cursor.target_id();
-                let target_original =
-                    filtered.to_original_node_id(mapped_target).ok_or_else(|| {
-                        format!("No original node ID for mapped node {mapped_target}")
-                    })?;
-                writeln!(file, "{},{}", source_original.get(), target_original.get())
-                    .map_err(|e| format!("Failed to write '{}': {e}", file_path.display()))?;
+                let target_key = filtered.external_node_key(mapped_target).ok_or_else(|| {
+                    format!("No original node ID for mapped node {mapped_target}")
+                })?;
+                writeln!(
+                    file,
+                    "{},{}",
+                    csv_node_key(&source_key),
+                    csv_node_key(&target_key)
+                )
+                .map_err(|e| format!("Failed to write '{}': {e}", file_path.display()))?;
                 count += 1;
             }
         }
@@ -142,3 +147,32 @@ impl ExportToCsvApplication {
         Ok(count)
     }
 }
+
+/// Integer keys are written bare, string and composite keys as their quoted JSON form, so
+/// that [`ImportFromCsvApplication`](super::ImportFromCsvApplication) parses every key back
+/// unambiguously: the text `"42"` stays distinct from the integer `42`.
+pub(super) fn csv_node_key(key: &ExternalNodeKey) -> String {
+    match key {
+        ExternalNodeKey::Id(id) => id.to_string(),
+        ExternalNodeKey::Text(_) | ExternalNodeKey::Composite { .. } => {
+            let json = serde_json::to_string(key).expect("node keys serialize to JSON");
+            format!("\"{}\"", json.replace('"', "\"\""))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_node_keys_are_quoted_unless_integer() {
+        assert_eq!(csv_node_key(&ExternalNodeKey::Id(-3)), "-3");
+        assert_eq!(csv_node_key(&"say \"hi\"".into()), r#""""say \""hi\""""""#);
+        assert_eq!(csv_node_key(&"42".into()), r#""""42""""#);
+        assert_eq!(
+            csv_node_key(&ExternalNodeKey::composite("Order", "A-17")),
+            r#""{""label"":""Order"",""key"":""A-17""}""#
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use crate::config::GraphStoreConfig;
+use crate::projection::{NodeLabel, RelationshipType};
+use crate::types::graph::ExternalNodeKey;
+use crate::types::graph::IdMap as _;
+use crate::types::graph::MappedNodeId;
+use crate::types::graph::RelationshipTopology;
+use crate::types::graph::SimpleIdMap;
+use crate::types::graph_store::{
+    Capabilities, DatabaseId, DatabaseInfo, DatabaseLocation, DefaultGraphStore, GraphName,
+};
+use crate::types::schema::{Direction, MutableGraphSchema};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// ImportFromCsvApplication
+///
+/// Reads the `nodes.csv` and `relationships_<type>.csv` files written by
+/// [`ExportToCsvApplication`](super::ExportToCsvApplication) back into a `DefaultGraphStore`.
+/// Integer keys become original node IDs again; string and composite keys are interned, so a
+/// graph exported from external keys keeps them.
+#[derive(Clone, Debug, Default)]
+pub struct ImportFromCsvApplication;
+
+impl ImportFromCsvApplication {
+    pub fn compute(
+        &self,
+        import_path: &str,
+        graph_name: &str,
+        database_id: &DatabaseId,
+    ) -> Result<DefaultGraphStore, String> {
+        let import_root = PathBuf::from(import_path);
+        if import_root.as_os_str().is_empty() {
+            return Err("import_path must not be empty".to_string());
+        }
+
+        let mut schema = MutableGraphSchema::empty();
+        let (node_keys, node_labels) = self.read_nodes_csv(&import_root)?;
+        let id_map = if node_keys.iter().all(|key| key.as_id().is_some()) {
+            SimpleIdMap::try_from_original_ids(node_keys.iter().filter_map(ExternalNodeKey::as_id))
+        } else {
+            SimpleIdMap::try_from_external_keys(node_keys)
+        };
+        let mut id_map = id_map.map_err(|e| format!("Invalid node keys in nodes.csv: {e}"))?;
+        for (mapped_index, labels) in node_labels.into_iter().enumerate() {
+            for label in labels {
+                schema.node_schema_mut().add_label(label.clone());
+                id_map
+                    .add_node_id_to_label(MappedNodeId::new(mapped_index as u64), label)
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        let mut relationship_topologies = HashMap::new();
+        for (rel_type, file_path) in self.relationship_files(&import_root)? {
+            let outgoing = self.read_relationships_csv(&file_path, &id_map)?;
+            let topology = RelationshipTopology::try_new(outgoing, None)
+                .map_err(|e| format!("Invalid relationships in '{}': {e}", file_path.display()))?;
+            schema
+                .relationship_schema_mut()
+                .add_relationship_type(rel_type.clone(), Direction::Directed);
+            relationship_topologies.insert(rel_type, topology);
+        }
+
+        DefaultGraphStore::try_new(
+            GraphStoreConfig::default(),
+            GraphName::new(graph_name),
+            DatabaseInfo::new(
+                database_id.clone(),
+                DatabaseLocation::remote("csv", 0, None, None),
+            ),
+            schema.build(),
+            Capabilities::default(),
+            id_map,
+            relationship_topologies,
+        )
+        .map_err(|e| format!("Failed to build graph store from '{import_path}': {e}"))
+    }
+
+    fn read_nodes_csv(
+        &self,
+        import_root: &Path,
+    ) -> Result<(Vec<ExternalNodeKey>, Vec<Vec<NodeLabel>>), String> {
+        let file_path = import_root.join("nodes.csv");
+        let mut node_keys = Vec::new();
+        let mut node_labels = Vec::new();
+        for (line_number, fields) in read_csv_records(&file_path, "id,labels")? {
+            let [key, labels] = fields.as_slice() else {
+                return Err(format!(
+                    "{}:{line_number}: expected 2 fields, found {}",
+                    file_path.display(),
+                    fields.len()
+                ));
+            };
+            node_keys.push(
+                parse_csv_node_key(key)
+                    .map_err(|e| format!("{}:{line_number}: {e}", file_path.display()))?,
+            );
+            node_labels.push(
+                labels
+                    .split(';')
+                    .filter(|label| !label.is_empty())
+                    .map(NodeLabel::of)
+                    .collect(),
+            );
+        }
+        Ok((node_keys, node_labels))
+    }
+
+    /// Relationship files sorted by type, so the type order does not depend on the directory.
+    fn relationship_files(
+        &self,
+        import_root: &Path,
+    ) -> Result<Vec<(RelationshipType, PathBuf)>, String> {
+        let entries = fs::read_dir(import_root)
+            .map_err(|e| format!("Failed to read '{}': {e}", import_root.display()))?;
+        let mut files = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|e| format!("Failed to read '{}': {e}", import_root.display()))?
+                .path();
+            let type_name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix("relationships_"))
+                .and_then(|name| name.strip_suffix(".csv"));
+            if let Some(type_name) = type_name {
+                files.push((RelationshipType::of(type_name), path.clone()));
+            }
+        }
+        files.sort_by(|(left, _), (right, _)| left.name().cmp(right.name()));
+        Ok(files)
+    }
+
+    fn read_relationships_csv(
+        &self,
+        file_path: &Path,
+        id_map: &SimpleIdMap,
+    ) -> Result<Vec<Vec<MappedNodeId>>, String> {
+        let mut outgoing = vec![Vec::new(); id_map.node_count()];
+        for (line_number, fields) in read_csv_records(file_path, "source,target")? {
+            let [source, target] = fields.as_slice() else {
+                return Err(format!(
+                    "{}:{line_number}: expected 2 fields, found {}",
+                    file_path.display(),
+                    fields.len()
+                ));
+            };
+            let resolve = |field: &str| {
+                let key = parse_csv_node_key(field)
+                    .map_err(|e| format!("{}:{line_number}: {e}", file_path.display()))?;
+                id_map.to_mapped_node_id_by_key(&key).ok_or_else(|| {
+                    format!(
+                        "{}:{line_number}: node key {key} not found in nodes.csv",
+                        file_path.display()
+                    )
+                })
+            };
+            let source = resolve(source)?;
+            let target = resolve(target)?;
+            let source_index = source
+                .to_usize()
+                .ok_or_else(|| format!("mapped source {source} exceeds physical index space"))?;
+            outgoing[source_index].push(target);
+        }
+        Ok(outgoing)
+    }
+}
+
+/// Reads the records of a CSV file with the given header, numbered by line.
+fn read_csv_records(file_path: &Path, header: &str) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let contents = fs::read_to_string(file_path)
+        .map_err(|e| format!("Failed to read '{}': {e}", file_path.display()))?;
+    let mut lines = contents.lines();
+    if lines.next() != Some(header) {
+        return Err(format!(
+            "'{}' must start with the header '{header}'",
+            file_path.display()
+        ));
+    }
+    lines
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| {
+            let line_number = index + 2;
+            split_csv_record(line)
+                .map(|fields| (line_number, fields))
+                .map_err(|e| format!("{}:{line_number}: {e}", file_path.display()))
+        })
+        .collect()
+}
+
+/// Splits one CSV record into its unquoted fields; `""` inside quotes is a literal quote.
+fn split_csv_record(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        let mut field = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => return Err("unterminated quoted field".to_string()),
+                }
+            }
+            if !matches!(chars.peek(), None | Some(',')) {
+                return Err("unexpected character after quoted field".to_string());
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c == ',' {
+                    break;
+                }
+                field.push(c);
+                chars.next();
+            }
+        }
+        fields.push(field);
+        if chars.next().is_none() {
+            return Ok(fields);
+        }
+    }
+}
+
+/// Parses a key written by `csv_node_key`: a bare integer, or the JSON form of a string or
+/// composite key.
+fn parse_csv_node_key(field: &str) -> Result<ExternalNodeKey, String> {
+    serde_json::from_str(field).map_err(|_| format!("invalid node key '{field}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::export_to_csv_application::csv_node_key;
+    use super::super::ExportToCsvApplication;
+    use super::*;
+    use crate::types::graph_store::GraphStore as _;
+    use crate::types::properties::relationship::RelationshipPredicate as _;
+
+    #[test]
+    fn csv_node_keys_round_trip() {
+        let keys = [
+            ExternalNodeKey::Id(-3),
+            ExternalNodeKey::from("42"),
+            ExternalNodeKey::from("say \"hi\", then go"),
+            ExternalNodeKey::composite("Order", "A,17"),
+        ];
+        let line = keys.iter().map(csv_node_key).collect::<Vec<_>>().join(",");
+        let fields = split_csv_record(&line).unwrap();
+        let parsed = fields
+            .iter()
+            .map(|field| parse_csv_node_key(field))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(parsed, keys);
+        assert!(split_csv_record(r#""open"#).is_err());
+    }
+
+    #[test]
+    fn exported_graph_imports_with_keys_and_labels() {
+        let snapshot_keys = [
+            ExternalNodeKey::Id(7),
+            ExternalNodeKey::from("ada@example.com"),
+            ExternalNodeKey::composite("Order", "A-17"),
+        ];
+        let mut id_map = SimpleIdMap::try_from_external_keys(snapshot_keys.clone()).unwrap();
+        let person = NodeLabel::of("Person");
+        id_map
+            .add_node_id_to_label(MappedNodeId::new(1), person.clone())
+            .unwrap();
+        let knows = RelationshipType::of("KNOWS");
+        let topology = RelationshipTopology::try_new(
+            vec![
+                vec![MappedNodeId::new(1), MappedNodeId::new(2)],
+                vec![MappedNodeId::new(0)],
+                vec![],
+            ],
+            None,
+        )
+        .unwrap();
+        let mut schema = MutableGraphSchema::empty();
+        schema.node_schema_mut().add_label(person.clone());
+        schema
+            .relationship_schema_mut()
+            .add_relationship_type(knows.clone(), Direction::Directed);
+        let database_id = DatabaseId::new("db1");
+        let store = DefaultGraphStore::try_new(
+            GraphStoreConfig::default(),
+            GraphName::new("exported"),
+            DatabaseInfo::new(
+                database_id.clone(),
+                DatabaseLocation::remote("csv", 0, None, None),
+            ),
+            schema.build(),
+            Capabilities::default(),
+            id_map,
+            HashMap::from([(knows.clone(), topology)]),
+        )
+        .unwrap();
+
+        let export_root =
+            std::env::temp_dir().join(format!("gds-csv-round-trip-{}", uuid::Uuid::new_v4()));
+        let export_path = export_root.to_string_lossy().to_string();
+        ExportToCsvApplication
+            .compute(&store, &export_path)
+            .expect("export graph");
+        let imported = ImportFromCsvApplication
+            .compute(&export_path, "imported", &database_id)
+            .expect("import graph");
+        fs::remove_dir_all(&export_root).unwrap();
+
+        assert_eq!(imported.node_count(), 3);
+        assert_eq!(imported.relationship_count(), 3);
+        let graph = imported.graph();
+        let resolve = |key: &ExternalNodeKey| graph.to_mapped_node_id_by_key(key).unwrap();
+        let [seven, ada, order] = snapshot_keys.each_ref().map(resolve);
+        assert_eq!(graph.external_node_key(seven), Some(ExternalNodeKey::Id(7)));
+        assert!(graph.exists(seven, ada));
+        assert!(graph.exists(seven, order));
+        assert!(graph.exists(ada, seven));
+        assert!(!graph.exists(order, seven));
+        assert!(graph.node_labels(ada).contains(&person));
+        assert!(graph.node_labels(seven).is_empty());
+    }
+}
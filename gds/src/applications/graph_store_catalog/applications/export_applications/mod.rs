@@ -1,9 +1,11 @@
-// Export applications - for exporting graphs to external formats
+// Export applications - for exporting graphs to external formats and reading them back
 
 pub mod export_to_csv_application;
 pub mod export_to_csv_estimate_application;
 pub mod export_to_database_application;
+pub mod import_from_csv_application;
 
 pub use export_to_csv_application::*;
 pub use export_to_csv_estimate_application::*;
 pub use export_to_database_application::*;
+pub use import_from_csv_application::*;
@@ -82,12 +82,15 @@ impl StreamNodePropertiesApplication {
                     },
                 };
 
-                out.push(GraphStreamNodePropertiesResult::new(
-                    original_id.get(),
-                    prop_key.clone(),
-                    value,
-                    node_labels.clone(),
-                ));
+                out.push(
+                    GraphStreamNodePropertiesResult::new(
+                        original_id.get(),
+                        prop_key.clone(),
+                        value,
+                        node_labels.clone(),
+                    )
+                    .with_node_key(graph.external_node_key(mapped_node_id)),
+                );
             }
         }
 
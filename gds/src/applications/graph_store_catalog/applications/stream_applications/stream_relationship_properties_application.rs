@@ -71,13 +71,19 @@ impl StreamRelationshipPropertiesApplication {
                             _ => serde_json::Value::Number(serde_json::Number::from(pv as i64)),
                         };
 
-                        out.push(GraphStreamRelationshipPropertiesResult::new(
-                            original_source.get(),
-                            original_target.get(),
-                            rel_type.name().to_string(),
-                            prop_key.clone(),
-                            value,
-                        ));
+                        out.push(
+                            GraphStreamRelationshipPropertiesResult::new(
+                                original_source.get(),
+                                original_target.get(),
+                                rel_type.name().to_string(),
+                                prop_key.clone(),
+                                value,
+                            )
+                            .with_node_keys(
+                                graph.external_node_key(mapped_source),
+                                graph.external_node_key(mapped_target),
+                            ),
+                        );
                     }
                 }
             }
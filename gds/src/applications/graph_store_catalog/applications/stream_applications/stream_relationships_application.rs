@@ -50,11 +50,17 @@ impl StreamRelationshipsApplication {
                         graph.to_original_node_id(target_mapped).ok_or_else(|| {
                             format!("No original node ID for mapped node {target_mapped}")
                         })?;
-                    out.push(TopologyResult::new(
-                        source_original.get(),
-                        target_original.get(),
-                        rel_type.name().to_string(),
-                    ));
+                    out.push(
+                        TopologyResult::new(
+                            source_original.get(),
+                            target_original.get(),
+                            rel_type.name().to_string(),
+                        )
+                        .with_node_keys(
+                            graph.external_node_key(mapped_source),
+                            graph.external_node_key(target_mapped),
+                        ),
+                    );
                 }
             }
         }
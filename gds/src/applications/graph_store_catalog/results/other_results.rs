@@ -3,6 +3,9 @@
 use serde_json::Value;
 use std::collections::HashMap;
 
+use super::stream_results::distinct_node_key;
+use crate::types::graph::ExternalNodeKey;
+
 /// Result for graph memory usage analysis.
 /// Mirrors Java GraphMemoryUsage class.
 #[derive(Clone, Debug)]
@@ -215,6 +218,11 @@ pub struct TopologyResult {
     pub source_node_id: i64,
     pub target_node_id: i64,
     pub relationship_type: String,
+    /// Source-data keys, where they differ from the node ids (string, composite or mixed keys).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_node_key: Option<ExternalNodeKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_node_key: Option<ExternalNodeKey>,
 }
 
 impl TopologyResult {
@@ -223,8 +231,21 @@ impl TopologyResult {
             source_node_id,
             target_node_id,
             relationship_type,
+            source_node_key: None,
+            target_node_key: None,
         }
     }
+
+    /// Keeps the keys that differ from the reported node ids.
+    pub fn with_node_keys(
+        mut self,
+        source_node_key: Option<ExternalNodeKey>,
+        target_node_key: Option<ExternalNodeKey>,
+    ) -> Self {
+        self.source_node_key = distinct_node_key(source_node_key, self.source_node_id);
+        self.target_node_key = distinct_node_key(target_node_key, self.target_node_id);
+        self
+    }
 }
 
 impl std::fmt::Display for TopologyResult {
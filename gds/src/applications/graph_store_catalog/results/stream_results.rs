@@ -3,6 +3,17 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::types::graph::ExternalNodeKey;
+
+/// Drops keys that merely repeat the reported node id. Integer keys of graphs that also intern
+/// string keys get synthetic node ids, so they are kept like any other interned key.
+pub(crate) fn distinct_node_key(
+    key: Option<ExternalNodeKey>,
+    node_id: i64,
+) -> Option<ExternalNodeKey> {
+    key.filter(|key| key.as_id() != Some(node_id))
+}
+
 /// Result for streaming node properties.
 /// Mirrors Java GraphStreamNodePropertyResult class.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -30,6 +41,9 @@ pub struct GraphStreamNodePropertiesResult {
     pub node_property: String,
     pub property_value: Value,
     pub node_labels: Vec<String>,
+    /// Source-data key, where it differs from the node id (string, composite or mixed keys).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_key: Option<ExternalNodeKey>,
 }
 
 impl GraphStreamNodePropertiesResult {
@@ -44,8 +58,14 @@ impl GraphStreamNodePropertiesResult {
             node_property,
             property_value,
             node_labels,
+            node_key: None,
         }
     }
+
+    pub fn with_node_key(mut self, node_key: Option<ExternalNodeKey>) -> Self {
+        self.node_key = distinct_node_key(node_key, self.node_id);
+        self
+    }
 }
 
 /// Result for streaming relationship properties.
@@ -83,6 +103,10 @@ pub struct GraphStreamRelationshipPropertiesResult {
     pub relationship_type: String,
     pub relationship_property: String,
     pub property_value: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_node_key: Option<ExternalNodeKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_node_key: Option<ExternalNodeKey>,
 }
 
 /// Result for streaming graph properties.
@@ -116,6 +140,18 @@ impl GraphStreamRelationshipPropertiesResult {
             relationship_type,
             relationship_property,
             property_value,
+            source_node_key: None,
+            target_node_key: None,
         }
     }
+
+    pub fn with_node_keys(
+        mut self,
+        source_node_key: Option<ExternalNodeKey>,
+        target_node_key: Option<ExternalNodeKey>,
+    ) -> Self {
+        self.source_node_key = distinct_node_key(source_node_key, self.source_node_id);
+        self.target_node_key = distinct_node_key(target_node_key, self.target_node_id);
+        self
+    }
 }
@@ -2,9 +2,9 @@ use crate::applications::services::tsjson_support::{err, ok, FacadeContext};
 use crate::collections::backends::vec::{VecDouble, VecDoubleArray, VecLong};
 use crate::config::GraphStoreConfig;
 use crate::projection::{NodeLabel, RelationshipType};
+use crate::types::graph::ExternalNodeKey;
+use crate::types::graph::IdMap;
 use crate::types::graph::MappedNodeId;
-use crate::types::graph::OriginalNodeId;
-use crate::types::graph::PartialIdMap;
 use crate::types::graph::RelationshipTopology;
 use crate::types::graph::SimpleIdMap;
 use crate::types::graph_store::{
@@ -39,36 +39,33 @@ pub(super) fn handle_graph_store(request: &Value, ctx: &FacadeContext) -> Value
                 return err(op, "INVALID_REQUEST", "Missing required field: snapshot");
             };
 
+            // Nodes are integer ids, string keys or {"label", "key"} composite keys.
+            const NODES_ERROR: &str =
+                "snapshot.nodes must be a non-empty array of integer, string or {label, key} node keys";
             let nodes_value = snapshot.get("nodes").and_then(|v| v.as_array());
             let Some(nodes) = nodes_value else {
-                return err(
-                    op,
-                    "INVALID_REQUEST",
-                    "snapshot.nodes must be a non-empty integer array",
-                );
+                return err(op, "INVALID_REQUEST", NODES_ERROR);
             };
             if nodes.is_empty() {
-                return err(
-                    op,
-                    "INVALID_REQUEST",
-                    "snapshot.nodes must be a non-empty integer array",
-                );
+                return err(op, "INVALID_REQUEST", NODES_ERROR);
             }
 
-            let mut original_node_ids: Vec<i64> = Vec::with_capacity(nodes.len());
+            let mut node_keys: Vec<ExternalNodeKey> = Vec::with_capacity(nodes.len());
             for v in nodes.iter() {
-                let Some(n) = v.as_i64() else {
-                    return err(
-                        op,
-                        "INVALID_REQUEST",
-                        "snapshot.nodes must be a non-empty integer array",
-                    );
+                let Ok(key) = serde_json::from_value(v.clone()) else {
+                    return err(op, "INVALID_REQUEST", NODES_ERROR);
                 };
-                original_node_ids.push(n);
+                node_keys.push(key);
             }
 
-            let id_map = match SimpleIdMap::try_from_original_ids(original_node_ids.iter().copied())
-            {
+            let id_map = if node_keys.iter().all(|key| key.as_id().is_some()) {
+                SimpleIdMap::try_from_original_ids(
+                    node_keys.iter().filter_map(ExternalNodeKey::as_id),
+                )
+            } else {
+                SimpleIdMap::try_from_external_keys(node_keys.iter().cloned())
+            };
+            let id_map = match id_map {
                 Ok(id_map) => id_map,
                 Err(error) => return err(op, "INVALID_REQUEST", error.to_string()),
             };
@@ -96,33 +93,35 @@ pub(super) fn handle_graph_store(request: &Value, ctx: &FacadeContext) -> Value
                             "snapshot.relationships[*].type must be a non-empty string",
                         );
                     }
-                    let Some(source_original) = rel.get("source").and_then(|v| v.as_i64()) else {
+                    let Some(source_key) = rel
+                        .get("source")
+                        .and_then(|v| serde_json::from_value::<ExternalNodeKey>(v.clone()).ok())
+                    else {
                         return err(
                             op,
                             "INVALID_REQUEST",
-                            "snapshot.relationships[*].source must be an integer",
+                            "snapshot.relationships[*].source must be a node key",
                         );
                     };
-                    let Some(target_original) = rel.get("target").and_then(|v| v.as_i64()) else {
+                    let Some(target_key) = rel
+                        .get("target")
+                        .and_then(|v| serde_json::from_value::<ExternalNodeKey>(v.clone()).ok())
+                    else {
                         return err(
                             op,
                             "INVALID_REQUEST",
-                            "snapshot.relationships[*].target must be an integer",
+                            "snapshot.relationships[*].target must be a node key",
                         );
                     };
 
-                    let Some(source_mapped) =
-                        id_map.to_mapped_node_id(OriginalNodeId::new(source_original))
-                    else {
+                    let Some(source_mapped) = id_map.to_mapped_node_id_by_key(&source_key) else {
                         return err(
                             op,
                             "INVALID_REQUEST",
                             "snapshot.relationships[*].source not found in snapshot.nodes",
                         );
                     };
-                    let Some(target_mapped) =
-                        id_map.to_mapped_node_id(OriginalNodeId::new(target_original))
-                    else {
+                    let Some(target_mapped) = id_map.to_mapped_node_id_by_key(&target_key) else {
                         return err(
                             op,
                             "INVALID_REQUEST",
@@ -152,8 +151,7 @@ pub(super) fn handle_graph_store(request: &Value, ctx: &FacadeContext) -> Value
             > = HashMap::new();
 
             for (rel_type, edges) in rels_by_type.into_iter() {
-                let mut adjacency: Vec<Vec<MappedNodeId>> =
-                    vec![Vec::new(); original_node_ids.len()];
+                let mut adjacency: Vec<Vec<MappedNodeId>> = vec![Vec::new(); node_keys.len()];
 
                 let mut keys: std::collections::HashSet<String> = std::collections::HashSet::new();
                 for e in edges.iter() {
@@ -165,7 +163,7 @@ pub(super) fn handle_graph_store(request: &Value, ctx: &FacadeContext) -> Value
                 if !keys.is_empty() {
                     let mut by_key: HashMap<String, Vec<Vec<serde_json::Value>>> = HashMap::new();
                     for k in keys.iter() {
-                        by_key.insert(k.clone(), vec![Vec::new(); original_node_ids.len()]);
+                        by_key.insert(k.clone(), vec![Vec::new(); node_keys.len()]);
                     }
                     rel_props_by_type.insert(rel_type.clone(), by_key);
                 }
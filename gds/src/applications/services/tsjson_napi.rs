@@ -754,6 +754,57 @@ mod tests {
         assert_eq!(dropped.len(), 2);
     }
 
+    #[test]
+    fn invoke_graph_store_put_mixed_keys_streams_integer_keys() {
+        let catalog = test_catalog("alice", true, "db1");
+        let put = serde_json::json!({
+            "facade": "graph_store",
+            "op": "put",
+            "user": { "username": "alice", "isAdmin": true },
+            "databaseId": "db1",
+            "graphName": "mixed_keys",
+            "snapshot": {
+                "nodes": [7, "ada@example.com", 0],
+                "relationships": [
+                    { "type": "KNOWS", "source": 7, "target": "ada@example.com" },
+                    { "type": "KNOWS", "source": "ada@example.com", "target": 0 }
+                ]
+            }
+        });
+        let response: serde_json::Value = serde_json::from_str(&invoke(put.to_string())).unwrap();
+        assert_eq!(response.get("ok").and_then(|v| v.as_bool()), Some(true));
+
+        let stream = serde_json::json!({
+            "facade": "graph_store_catalog",
+            "op": "streamRelationships",
+            "user": { "username": "alice", "isAdmin": true },
+            "databaseId": "db1",
+            "graphName": "mixed_keys"
+        });
+        let response: serde_json::Value =
+            serde_json::from_str(&invoke(stream.to_string())).unwrap();
+        assert_eq!(response.get("ok").and_then(|v| v.as_bool()), Some(true));
+
+        // Interned graphs report synthetic node ids, so integer keys must come along as keys.
+        let results = response["data"]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["source_node_id"], serde_json::json!(0));
+        assert_eq!(results[0]["source_node_key"], serde_json::json!(7));
+        assert_eq!(
+            results[0]["target_node_key"],
+            serde_json::json!("ada@example.com")
+        );
+        assert_eq!(
+            results[1]["source_node_key"],
+            serde_json::json!("ada@example.com")
+        );
+        // The key 0 was interned third, so its node id differs from it.
+        assert_eq!(results[1]["target_node_id"], serde_json::json!(2));
+        assert_eq!(results[1]["target_node_key"], serde_json::json!(0));
+
+        let _ = GraphCatalog::drop(catalog.as_ref(), &["mixed_keys"], false);
+    }
+
     #[test]
     fn invoke_form_eval_with_store_contract_and_ontology_image_hooks() {
         let request = serde_json::json!({
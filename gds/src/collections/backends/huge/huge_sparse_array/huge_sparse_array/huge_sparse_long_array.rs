@@ -40,6 +40,7 @@ use crate::collections::PageUtil;
 /// assert!(array.contains(0));
 /// assert!(!array.contains(999));
 /// ```
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
@@ -50,15 +51,42 @@ const PAGE_SHIFT: usize = 15; // log2(32768)
 /// Mask to extract offset within a page
 const PAGE_MASK: usize = PAGE_SIZE - 1;
 
+/// One allocated page: its values plus one bit per slot recording explicit writes.
+#[derive(Debug, Clone)]
+struct SparsePage {
+    values: Vec<i64>,
+    present: Vec<u64>,
+}
+
+impl SparsePage {
+    fn new(default_value: i64) -> Self {
+        Self {
+            values: vec![default_value; PAGE_SIZE],
+            present: vec![0; PAGE_SIZE / 64],
+        }
+    }
+
+    fn is_set(&self, index_in_page: usize) -> bool {
+        self.present[index_in_page >> 6] & (1 << (index_in_page & 63)) != 0
+    }
+
+    fn mark_set(&mut self, index_in_page: usize) {
+        self.present[index_in_page >> 6] |= 1 << (index_in_page & 63);
+    }
+}
+
 /// Immutable sparse long array with memory-efficient paged storage.
+#[derive(Debug, Clone)]
 pub struct HugeSparseLongArray {
     capacity: usize,
     default_value: i64,
-    pages: HashMap<usize, Vec<i64>>,
-    set_indices: std::collections::HashSet<usize>, // Track which indices are explicitly set
+    pages: HashMap<usize, SparsePage>,
 }
 
 impl HugeSparseLongArray {
+    /// Number of indices covered by one allocated page.
+    pub const PAGE_SIZE: usize = PAGE_SIZE;
+
     /// Creates a new builder with the specified default value.
     ///
     /// # Arguments
@@ -113,7 +141,7 @@ impl HugeSparseLongArray {
         let page_index = index >> PAGE_SHIFT;
         if let Some(page) = self.pages.get(&page_index) {
             let index_in_page = index & PAGE_MASK;
-            page[index_in_page]
+            page.values[index_in_page]
         } else {
             self.default_value
         }
@@ -134,7 +162,42 @@ impl HugeSparseLongArray {
         if index >= self.capacity {
             return false;
         }
-        self.set_indices.contains(&index)
+        self.pages
+            .get(&(index >> PAGE_SHIFT))
+            .is_some_and(|page| page.is_set(index & PAGE_MASK))
+    }
+
+    /// Visits every explicitly set `(index, value)` pair, page by page.
+    ///
+    /// Unlike scanning `0..capacity`, this only touches allocated pages.
+    pub fn for_each_set(&self, mut consumer: impl FnMut(usize, i64)) {
+        for (&page_index, page) in &self.pages {
+            for (word_index, &word) in page.present.iter().enumerate() {
+                let mut bits = word;
+                while bits != 0 {
+                    let index_in_page = (word_index << 6) + bits.trailing_zeros() as usize;
+                    consumer(
+                        (page_index << PAGE_SHIFT) + index_in_page,
+                        page.values[index_in_page],
+                    );
+                    bits &= bits - 1;
+                }
+            }
+        }
+    }
+
+    /// Reopens the array for writing, moving its pages into a builder.
+    pub fn into_builder(self) -> HugeSparseLongArrayBuilder {
+        HugeSparseLongArrayBuilder {
+            default_value: self.default_value,
+            capacity: Arc::new(RwLock::new(self.capacity)),
+            pages: Arc::new(RwLock::new(self.pages)),
+        }
+    }
+
+    /// Indices of the allocated pages, in no particular order.
+    pub fn page_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.pages.keys().copied()
     }
 
     /// Returns the number of pages currently allocated.
@@ -146,11 +209,11 @@ impl HugeSparseLongArray {
 
     /// Returns the estimated memory usage in bytes.
     ///
-    /// Includes page storage and HashMap overhead.
+    /// Includes page storage, presence bits and HashMap overhead.
     pub fn size_of(&self) -> usize {
         let mut total = std::mem::size_of::<Self>();
-        total += self.pages.len() * (std::mem::size_of::<usize>() + PAGE_SIZE * 8);
-        total += self.pages.capacity() * std::mem::size_of::<(usize, Vec<i64>)>();
+        total += self.pages.len() * (std::mem::size_of::<usize>() + PAGE_SIZE * 8 + PAGE_SIZE / 8);
+        total += self.pages.capacity() * std::mem::size_of::<(usize, SparsePage)>();
         total
     }
 }
@@ -162,19 +225,13 @@ impl HugeSparseLongArray {
 pub struct HugeSparseLongArrayBuilder {
     default_value: i64,
     capacity: Arc<RwLock<usize>>,
-    pages: Arc<RwLock<HashMap<usize, Vec<i64>>>>,
-    set_indices: Arc<RwLock<std::collections::HashSet<usize>>>,
+    pages: Arc<RwLock<HashMap<usize, SparsePage>>>,
 }
 
 impl HugeSparseLongArrayBuilder {
     /// Creates a new builder with the specified default value.
     fn new(default_value: i64) -> Self {
-        Self {
-            default_value,
-            capacity: Arc::new(RwLock::new(0)),
-            pages: Arc::new(RwLock::new(HashMap::new())),
-            set_indices: Arc::new(RwLock::new(std::collections::HashSet::new())),
-        }
+        Self::with_capacity(default_value, 0)
     }
 
     /// Creates a new builder with default value and initial capacity hint.
@@ -183,26 +240,19 @@ impl HugeSparseLongArrayBuilder {
             default_value,
             capacity: Arc::new(RwLock::new(initial_capacity)),
             pages: Arc::new(RwLock::new(HashMap::new())),
-            set_indices: Arc::new(RwLock::new(std::collections::HashSet::new())),
         }
     }
 
-    /// Sets the value at the specified index.
-    ///
-    /// Overwrites any existing value. Thread-safe and automatically grows capacity.
-    ///
-    /// # Arguments
-    ///
-    /// * `index` - Index to set
-    /// * `value` - Value to store
-    pub fn set(&mut self, index: usize, value: i64) {
-        // Ensure capacity
-        {
-            let mut cap = self.capacity.write().unwrap();
-            if index >= *cap {
-                *cap = index + 1;
-            }
+    fn ensure_capacity(&self, index: usize) {
+        let mut cap = self.capacity.write().unwrap();
+        if index >= *cap {
+            *cap = index + 1;
         }
+    }
+
+    /// Applies `update` to the slot at `index` and marks it as set.
+    fn update(&mut self, index: usize, update: impl FnOnce(&mut i64, bool) -> bool) -> bool {
+        self.ensure_capacity(index);
 
         let page_index = index >> PAGE_SHIFT;
         let index_in_page = index & PAGE_MASK;
@@ -210,11 +260,36 @@ impl HugeSparseLongArrayBuilder {
         let mut pages = self.pages.write().unwrap();
         let page = pages
             .entry(page_index)
-            .or_insert_with(|| vec![self.default_value; PAGE_SIZE]);
-        page[index_in_page] = value;
+            .or_insert_with(|| SparsePage::new(self.default_value));
+        let was_set = page.is_set(index_in_page);
+        let written = update(&mut page.values[index_in_page], was_set);
+        if written {
+            page.mark_set(index_in_page);
+        }
+        written
+    }
+
+    /// Returns whether the page holding `index` has been allocated.
+    pub fn has_page(&self, index: usize) -> bool {
+        self.pages
+            .read()
+            .unwrap()
+            .contains_key(&(index >> PAGE_SHIFT))
+    }
 
-        // Track that this index was explicitly set
-        self.set_indices.write().unwrap().insert(index);
+    /// Sets the value at the specified index.
+    ///
+    /// Overwrites any existing value. Thread-safe and automatically grows capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index to set
+    /// * `value` - Value to store
+    pub fn set(&mut self, index: usize, value: i64) {
+        self.update(index, |slot, _| {
+            *slot = value;
+            true
+        });
     }
 
     /// Sets the value at the index only if no value exists there.
@@ -230,22 +305,12 @@ impl HugeSparseLongArrayBuilder {
     ///
     /// `true` if the value was set, `false` if index already had a value
     pub fn set_if_absent(&mut self, index: usize, value: i64) -> bool {
-        // Ensure capacity
-        {
-            let mut cap = self.capacity.write().unwrap();
-            if index >= *cap {
-                *cap = index + 1;
+        self.update(index, |slot, was_set| {
+            if !was_set {
+                *slot = value;
             }
-        }
-
-        // Check if already set
-        if self.set_indices.read().unwrap().contains(&index) {
-            return false;
-        }
-
-        // Not set yet - set it
-        self.set(index, value);
-        true
+            !was_set
+        })
     }
 
     /// Adds the specified value to the existing value at the index.
@@ -257,25 +322,48 @@ impl HugeSparseLongArrayBuilder {
     /// * `index` - Index to add to
     /// * `value` - Value to add
     pub fn add_to(&mut self, index: usize, value: i64) {
-        // Ensure capacity
-        {
-            let mut cap = self.capacity.write().unwrap();
-            if index >= *cap {
-                *cap = index + 1;
-            }
-        }
+        self.update(index, |slot, _| {
+            *slot += value;
+            true
+        });
+    }
 
-        let page_index = index >> PAGE_SHIFT;
-        let index_in_page = index & PAGE_MASK;
+    /// Moves the values of `other` into this builder, e.g. to combine arrays built in parallel.
+    ///
+    /// Pages only `other` allocated are moved whole; pages both allocated are merged word by
+    /// word. Both arrays must share a default value. Returns the first index set in both, in
+    /// which case the builder is left partially merged.
+    pub fn merge_disjoint(&mut self, other: HugeSparseLongArray) -> Result<(), usize> {
+        if other.capacity > 0 {
+            self.ensure_capacity(other.capacity - 1);
+        }
 
         let mut pages = self.pages.write().unwrap();
-        let page = pages
-            .entry(page_index)
-            .or_insert_with(|| vec![self.default_value; PAGE_SIZE]);
-        page[index_in_page] += value;
-
-        // Track that this index was explicitly set
-        self.set_indices.write().unwrap().insert(index);
+        for (page_index, page) in other.pages {
+            let target = match pages.entry(page_index) {
+                Entry::Vacant(entry) => {
+                    entry.insert(page);
+                    continue;
+                }
+                Entry::Occupied(entry) => entry.into_mut(),
+            };
+            for (word_index, &word) in page.present.iter().enumerate() {
+                let overlap = word & target.present[word_index];
+                if overlap != 0 {
+                    return Err((page_index << PAGE_SHIFT)
+                        + (word_index << 6)
+                        + overlap.trailing_zeros() as usize);
+                }
+                let mut bits = word;
+                while bits != 0 {
+                    let index_in_page = (word_index << 6) + bits.trailing_zeros() as usize;
+                    target.values[index_in_page] = page.values[index_in_page];
+                    bits &= bits - 1;
+                }
+                target.present[word_index] |= word;
+            }
+        }
+        Ok(())
     }
 
     /// Builds an immutable sparse array from the current builder state.
@@ -288,13 +376,29 @@ impl HugeSparseLongArrayBuilder {
     pub fn build(&self) -> HugeSparseLongArray {
         let capacity = *self.capacity.read().unwrap();
         let pages = self.pages.read().unwrap().clone();
-        let set_indices = self.set_indices.read().unwrap().clone();
 
         HugeSparseLongArray {
             capacity,
             default_value: self.default_value,
             pages,
-            set_indices,
+        }
+    }
+
+    /// Consumes the builder, moving its pages instead of copying them.
+    pub fn into_array(self) -> HugeSparseLongArray {
+        let capacity = match Arc::try_unwrap(self.capacity) {
+            Ok(lock) => lock.into_inner().unwrap(),
+            Err(shared) => *shared.read().unwrap(),
+        };
+        let pages = match Arc::try_unwrap(self.pages) {
+            Ok(lock) => lock.into_inner().unwrap(),
+            Err(shared) => shared.read().unwrap().clone(),
+        };
+
+        HugeSparseLongArray {
+            capacity,
+            default_value: self.default_value,
+            pages,
         }
     }
 }
@@ -465,4 +569,40 @@ mod tests {
         assert_eq!(array.get(5000), 42);
         assert!(array.capacity() >= 10_000);
     }
+
+    #[test]
+    fn test_for_each_set_visits_only_written_slots() {
+        let mut builder = HugeSparseLongArray::builder(-1);
+        builder.set(3, 30);
+        builder.set(PAGE_SIZE * 5 + 7, -1);
+        let array = builder.into_array();
+
+        let mut visited = Vec::new();
+        array.for_each_set(|index, value| visited.push((index, value)));
+        visited.sort_unstable();
+        assert_eq!(visited, vec![(3, 30), (PAGE_SIZE * 5 + 7, -1)]);
+        assert!(array.contains(PAGE_SIZE * 5 + 7));
+        assert!(!array.contains(PAGE_SIZE * 5 + 8));
+    }
+
+    #[test]
+    fn test_merge_disjoint_moves_and_merges_pages() {
+        let mut left = HugeSparseLongArray::builder(-1);
+        left.set(1, 10);
+        let mut right = HugeSparseLongArray::builder(-1);
+        right.set(2, 20);
+        right.set(PAGE_SIZE * 3, 30);
+
+        left.merge_disjoint(right.into_array()).unwrap();
+        let merged = left.build();
+        assert_eq!(merged.get(1), 10);
+        assert_eq!(merged.get(2), 20);
+        assert_eq!(merged.get(PAGE_SIZE * 3), 30);
+        assert_eq!(merged.page_count(), 2);
+        assert!(merged.capacity() > PAGE_SIZE * 3);
+
+        let mut clash = HugeSparseLongArray::builder(-1);
+        clash.set(PAGE_SIZE * 3, 31);
+        assert_eq!(left.merge_disjoint(clash.into_array()), Err(PAGE_SIZE * 3));
+    }
 }
@@ -8,6 +8,7 @@
 ///
 /// Provides efficient operations for tracking boolean flags across large index ranges.
 /// Uses 32-bit words internally for optimal performance on most platforms.
+#[derive(Debug)]
 pub struct BitSet {
     /// The bits in this set (32-bit words)
     bits: Vec<u32>,
@@ -272,6 +272,11 @@ impl Corpus {
         self.annotations.len()
     }
 
+    /// Document texts in row order.
+    pub fn texts(&self) -> Result<Vec<String>, GDSFrameError> {
+        self.read_text_column()
+    }
+
     // ---- Annotation grouping ---------------------------------------------
 
     /// Distinct annotator-effort tuples present in the annotation frame,
@@ -460,7 +465,8 @@ impl Corpus {
         Ok(added)
     }
 
-    fn document_hashes(&self) -> Result<Vec<String>, CorpusError> {
+    /// Source content hashes, one per document in row order.
+    pub fn document_hashes(&self) -> Result<Vec<String>, CorpusError> {
         let df = self.documents.dataframe().dataframe().clone();
         let series = df.column(DOCUMENT_COL_SOURCE)?.as_materialized_series();
         let mut out = Vec::with_capacity(series.len());
@@ -472,14 +478,11 @@ impl Corpus {
 
     fn read_text_column(&self) -> Result<Vec<String>, GDSFrameError> {
         let df = self.dataset.table().dataframe().clone();
-        let series = df.column("text")?.as_materialized_series();
-        let mut out = Vec::with_capacity(series.len());
-        for i in 0..series.len() {
-            let av = series.get(i)?;
-            let s = av.to_string();
-            out.push(if s == "null" { String::new() } else { s });
-        }
-        Ok(out)
+        let texts = df.column("text")?.str()?.clone();
+        Ok(texts
+            .into_iter()
+            .map(|text| text.unwrap_or_default().to_string())
+            .collect())
     }
 }
 
@@ -8,9 +8,11 @@ pub mod core;
 pub mod document;
 mod manifest;
 pub mod source;
+pub mod text_graph;
 
 pub use annotation::*;
 pub use core::*;
 pub use document::*;
 pub use manifest::*;
 pub use source::*;
+pub use text_graph::*;
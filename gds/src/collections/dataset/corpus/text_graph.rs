@@ -0,0 +1,575 @@
+//! Text-to-graph projection of a `Corpus`.
+//!
+//! [`TextGraphBuilder`] turns corpus documents into a [`DefaultGraphStore`]
+//! so text analytics can run on the graph algorithms directly:
+//!
+//! - `Document` nodes, one per corpus row (keyed by source content hash);
+//! - `Sentence` nodes, one per sentence found by the sentence tokenizer;
+//! - `Token` nodes, one per distinct normalized word form;
+//! - `Lemma` nodes, one per distinct lemma when a lemmatizer is supplied.
+//!
+//! Relationships all carry a `weight` property:
+//!
+//! - `NEXT` — sentence order within a document, and token bigrams
+//!   (weighted by bigram count);
+//! - `CO_OCCURS` — undirected, between tokens that appear within the
+//!   configured window of one another in a sentence (weighted by count);
+//! - `IN_DOCUMENT` — sentences and tokens to their documents (tokens
+//!   weighted by term frequency);
+//! - `HAS_LEMMA` — tokens to their lemmas (weighted by count).
+//!
+//! Every node also carries a `frequency` property: kept-token counts for
+//! documents and sentences, corpus occurrences for tokens and lemmas.
+//! Node text lives on the [`TextGraph`] side table, since the store only
+//! holds numeric properties.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::collections::dataframe::GDSFrameError;
+use crate::collections::dataset::corpus::core::{Corpus, CorpusError};
+use crate::collections::dataset::language::stemmer::Stemmer;
+use crate::collections::dataset::language::token::{Token, TokenKind};
+use crate::collections::dataset::language::tokenizer::Tokenizer;
+use crate::config::GraphStoreConfig;
+use crate::projection::{NodeLabel, RelationshipType};
+use crate::types::graph::{IdMap, MappedNodeId, RelationshipTopology, SimpleIdMap};
+use crate::types::graph_store::{
+    Capabilities, DatabaseId, DatabaseInfo, DatabaseLocation, DefaultGraphStore, GraphName,
+    GraphStore, GraphStoreError,
+};
+use crate::types::properties::relationship::{
+    DefaultRelationshipPropertyValues, RelationshipPropertyValues,
+};
+use crate::types::schema::{Direction, MutableGraphSchema};
+use crate::types::ValueType;
+
+pub const TEXT_GRAPH_NEXT: &str = "NEXT";
+pub const TEXT_GRAPH_CO_OCCURS: &str = "CO_OCCURS";
+pub const TEXT_GRAPH_IN_DOCUMENT: &str = "IN_DOCUMENT";
+pub const TEXT_GRAPH_HAS_LEMMA: &str = "HAS_LEMMA";
+pub const TEXT_GRAPH_WEIGHT: &str = "weight";
+pub const TEXT_GRAPH_FREQUENCY: &str = "frequency";
+
+/// Errors raised while projecting a corpus into a graph.
+#[derive(Debug, thiserror::Error)]
+pub enum TextGraphError {
+    #[error("corpus error: {0}")]
+    Corpus(#[from] CorpusError),
+    #[error("frame error: {0}")]
+    Frame(#[from] GDSFrameError),
+    #[error("graph store error: {0}")]
+    GraphStore(#[from] GraphStoreError),
+}
+
+/// The role a node plays in a [`TextGraph`]; doubles as its node label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TextNodeKind {
+    Document,
+    Sentence,
+    Token,
+    Lemma,
+}
+
+impl TextNodeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextNodeKind::Document => "Document",
+            TextNodeKind::Sentence => "Sentence",
+            TextNodeKind::Token => "Token",
+            TextNodeKind::Lemma => "Lemma",
+        }
+    }
+
+    pub fn label(&self) -> NodeLabel {
+        NodeLabel::of(self.as_str())
+    }
+}
+
+/// Side-table entry for one graph node.
+///
+/// `text` is the content hash for documents, the sentence text for
+/// sentences and the normalized form for tokens and lemmas. `document` is
+/// the corpus row for documents and sentences.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextNode {
+    pub kind: TextNodeKind,
+    pub text: String,
+    pub document: Option<usize>,
+}
+
+/// A corpus projected into a graph store, together with node texts.
+#[derive(Debug, Clone)]
+pub struct TextGraph {
+    store: Arc<DefaultGraphStore>,
+    nodes: Vec<TextNode>,
+    terms: HashMap<String, usize>,
+    sentences: Vec<Vec<usize>>,
+}
+
+impl TextGraph {
+    pub fn store(&self) -> &Arc<DefaultGraphStore> {
+        &self.store
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn nodes(&self) -> &[TextNode] {
+        &self.nodes
+    }
+
+    pub fn node(&self, node_id: usize) -> Option<&TextNode> {
+        self.nodes.get(node_id)
+    }
+
+    /// Node ids of the given kind, in ascending order.
+    pub fn node_ids(&self, kind: TextNodeKind) -> impl Iterator<Item = usize> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(move |(_, node)| node.kind == kind)
+            .map(|(id, _)| id)
+    }
+
+    /// Node id of the `Token` node for a normalized term.
+    pub fn term_node(&self, term: &str) -> Option<usize> {
+        self.terms.get(term).copied()
+    }
+
+    /// Token node ids of each sentence, in sentence node order.
+    pub fn sentence_tokens(&self) -> &[Vec<usize>] {
+        &self.sentences
+    }
+}
+
+/// Builder projecting a [`Corpus`] into a [`TextGraph`].
+///
+/// Documents are split with the sentence tokenizer, sentences with the word
+/// tokenizer. Only word and number tokens are kept; stopwords are dropped
+/// before co-occurrence windows are applied, as in TextRank.
+pub struct TextGraphBuilder<'a> {
+    sentence_tokenizer: &'a dyn Tokenizer,
+    word_tokenizer: &'a dyn Tokenizer,
+    lemmatizer: Option<&'a dyn Stemmer>,
+    stopwords: HashSet<String>,
+    window: usize,
+    lowercase: bool,
+    graph_name: String,
+}
+
+impl<'a> TextGraphBuilder<'a> {
+    pub const DEFAULT_WINDOW: usize = 2;
+
+    pub fn new(sentence_tokenizer: &'a dyn Tokenizer, word_tokenizer: &'a dyn Tokenizer) -> Self {
+        Self {
+            sentence_tokenizer,
+            word_tokenizer,
+            lemmatizer: None,
+            stopwords: HashSet::new(),
+            window: Self::DEFAULT_WINDOW,
+            lowercase: true,
+            graph_name: "text".to_string(),
+        }
+    }
+
+    /// Co-occurrence window in tokens; `2` links adjacent tokens only.
+    pub fn window(mut self, window: usize) -> Self {
+        assert!(window >= 2, "co-occurrence window must be at least 2");
+        self.window = window;
+        self
+    }
+
+    pub fn lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Terms to drop, compared after normalization.
+    pub fn stopwords<I, S>(mut self, stopwords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.stopwords = stopwords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Add `Lemma` nodes using the given stemmer or lemmatizer.
+    pub fn lemmatizer(mut self, lemmatizer: &'a dyn Stemmer) -> Self {
+        self.lemmatizer = Some(lemmatizer);
+        self
+    }
+
+    pub fn graph_name(mut self, graph_name: impl Into<String>) -> Self {
+        self.graph_name = graph_name.into();
+        self
+    }
+
+    pub fn build(&self, corpus: &Corpus) -> Result<TextGraph, TextGraphError> {
+        let texts = corpus.texts()?;
+        let hashes = corpus.document_hashes()?;
+
+        let mut nodes = hashes
+            .into_iter()
+            .enumerate()
+            .map(|(document, hash)| TextNode {
+                kind: TextNodeKind::Document,
+                text: hash,
+                document: Some(document),
+            })
+            .collect::<Vec<_>>();
+
+        let mut sentence_words = Vec::new();
+        for (document, text) in texts.iter().enumerate() {
+            for sentence in self.sentence_tokenizer.tokenize(text) {
+                let words = self
+                    .word_tokenizer
+                    .tokenize(sentence.text())
+                    .into_iter()
+                    .filter(|token| matches!(token.kind(), TokenKind::Word | TokenKind::Number))
+                    .map(|token| self.normalize(token))
+                    .filter(|token| !self.stopwords.contains(token.text()))
+                    .collect::<Vec<_>>();
+                nodes.push(TextNode {
+                    kind: TextNodeKind::Sentence,
+                    text: sentence.text().to_string(),
+                    document: Some(document),
+                });
+                sentence_words.push((nodes.len() - 1, document, words));
+            }
+        }
+
+        let mut terms = HashMap::new();
+        let mut sentences = Vec::with_capacity(sentence_words.len());
+        for (_, _, words) in &sentence_words {
+            let ids = words
+                .iter()
+                .map(|word| {
+                    *terms.entry(word.text().to_string()).or_insert_with(|| {
+                        nodes.push(TextNode {
+                            kind: TextNodeKind::Token,
+                            text: word.text().to_string(),
+                            document: None,
+                        });
+                        nodes.len() - 1
+                    })
+                })
+                .collect::<Vec<_>>();
+            sentences.push(ids);
+        }
+
+        let mut lemmas = HashMap::<String, usize>::new();
+        let mut has_lemma = Edges::new();
+        if let Some(lemmatizer) = self.lemmatizer {
+            for ((_, _, words), ids) in sentence_words.iter().zip(&sentences) {
+                for (word, &token) in words.iter().zip(ids) {
+                    let mut lemma = lemmatizer.stem_token(word).text().to_string();
+                    if self.lowercase {
+                        lemma = lemma.to_lowercase();
+                    }
+                    let lemma = *lemmas.entry(lemma).or_insert_with_key(|lemma| {
+                        nodes.push(TextNode {
+                            kind: TextNodeKind::Lemma,
+                            text: lemma.clone(),
+                            document: None,
+                        });
+                        nodes.len() - 1
+                    });
+                    *has_lemma.entry((token, lemma)).or_default() += 1.0;
+                }
+            }
+        }
+
+        let mut frequency = vec![0.0; nodes.len()];
+        let mut next = Edges::new();
+        let mut co_occurs = Edges::new();
+        let mut in_document = Edges::new();
+        let mut previous_sentence = None::<(usize, usize)>;
+
+        for ((sentence, document, _), ids) in sentence_words.iter().zip(&sentences) {
+            let (sentence, document) = (*sentence, *document);
+            if let Some((previous, previous_document)) = previous_sentence {
+                if previous_document == document {
+                    next.insert((previous, sentence), 1.0);
+                }
+            }
+            previous_sentence = Some((sentence, document));
+            in_document.insert((sentence, document), 1.0);
+
+            frequency[sentence] = ids.len() as f64;
+            frequency[document] += ids.len() as f64;
+            for (position, &token) in ids.iter().enumerate() {
+                frequency[token] += 1.0;
+                *in_document.entry((token, document)).or_default() += 1.0;
+                if let Some(&following) = ids.get(position + 1) {
+                    *next.entry((token, following)).or_default() += 1.0;
+                }
+                for &other in ids.iter().skip(position + 1).take(self.window - 1) {
+                    if other != token {
+                        *co_occurs
+                            .entry((token.min(other), token.max(other)))
+                            .or_default() += 1.0;
+                    }
+                }
+            }
+        }
+        for (&(_, lemma), count) in &has_lemma {
+            frequency[lemma] += count;
+        }
+
+        let relationships = [
+            (TEXT_GRAPH_NEXT, Direction::Directed, next),
+            (TEXT_GRAPH_CO_OCCURS, Direction::Undirected, co_occurs),
+            (TEXT_GRAPH_IN_DOCUMENT, Direction::Directed, in_document),
+            (TEXT_GRAPH_HAS_LEMMA, Direction::Directed, has_lemma),
+        ];
+        let store = self.store(&nodes, relationships, frequency)?;
+
+        Ok(TextGraph {
+            store: Arc::new(store),
+            nodes,
+            terms,
+            sentences,
+        })
+    }
+
+    fn normalize(&self, token: Token) -> Token {
+        if self.lowercase {
+            let text = token.text().to_lowercase();
+            token.with_text(text)
+        } else {
+            token
+        }
+    }
+
+    fn store(
+        &self,
+        nodes: &[TextNode],
+        relationships: [(&str, Direction, Edges); 4],
+        frequency: Vec<f64>,
+    ) -> Result<DefaultGraphStore, TextGraphError> {
+        let node_count = nodes.len();
+        let original_ids = (0..node_count)
+            .map(|id| i64::try_from(id).expect("text graph node count must fit original IDs"));
+        let mut id_map = SimpleIdMap::from_original_ids(original_ids);
+
+        let mut schema_builder = MutableGraphSchema::empty();
+        let kinds = nodes
+            .iter()
+            .map(|node| node.kind)
+            .collect::<std::collections::BTreeSet<_>>();
+        for kind in &kinds {
+            id_map.add_node_label(kind.label());
+            schema_builder.node_schema_mut().add_label(kind.label());
+        }
+        for (id, node) in nodes.iter().enumerate() {
+            id_map
+                .add_node_id_to_label(mapped(id), node.kind.label())
+                .expect("corpus node IDs stay below the node count");
+        }
+
+        let mut topologies = HashMap::new();
+        let mut weights = Vec::new();
+        for (name, direction, edges) in relationships {
+            if edges.is_empty() {
+                continue;
+            }
+            let rel_type = RelationshipType::of(name);
+            schema_builder.relationship_schema_mut().add_property(
+                rel_type.clone(),
+                direction,
+                TEXT_GRAPH_WEIGHT,
+                ValueType::Double,
+            );
+            let (topology, values) = topology(node_count, &edges, direction);
+            topologies.insert(rel_type.clone(), topology);
+            weights.push((rel_type, values));
+        }
+
+        let mut capabilities = Capabilities::new();
+        capabilities.add_feature("text");
+        capabilities.add_feature("transient");
+
+        let mut store = DefaultGraphStore::new(
+            GraphStoreConfig::default(),
+            GraphName::new(&self.graph_name),
+            DatabaseInfo::new(
+                DatabaseId::new("text"),
+                DatabaseLocation::remote("localhost", 7687, None, None),
+            ),
+            schema_builder.build(),
+            capabilities,
+            id_map,
+            topologies,
+        );
+
+        store.add_node_property_f64(TEXT_GRAPH_FREQUENCY.to_string(), frequency)?;
+        for (rel_type, values) in weights {
+            let element_count = values.len();
+            let values: Arc<dyn RelationshipPropertyValues> = Arc::new(
+                DefaultRelationshipPropertyValues::with_values(values, 0.0, element_count),
+            );
+            store.add_relationship_property(rel_type, TEXT_GRAPH_WEIGHT, values)?;
+        }
+        Ok(store)
+    }
+}
+
+type Edges = BTreeMap<(usize, usize), f64>;
+
+fn mapped(id: usize) -> MappedNodeId {
+    MappedNodeId::try_from(id).expect("text graph node count must fit mapped node IDs")
+}
+
+/// Adjacency lists plus weights flattened in outgoing-list order. Undirected
+/// edges are stored once per endpoint.
+fn topology(
+    node_count: usize,
+    edges: &Edges,
+    direction: Direction,
+) -> (RelationshipTopology, Vec<f64>) {
+    let mut adjacency = vec![Vec::new(); node_count];
+    for (&(source, target), &weight) in edges {
+        adjacency[source].push((target, weight));
+        if direction == Direction::Undirected {
+            adjacency[target].push((source, weight));
+        }
+    }
+
+    let mut outgoing = Vec::with_capacity(node_count);
+    let mut incoming = vec![Vec::new(); node_count];
+    let mut weights = Vec::new();
+    for (source, mut targets) in adjacency.into_iter().enumerate() {
+        targets.sort_by_key(|(target, _)| *target);
+        let mut list = Vec::with_capacity(targets.len());
+        for (target, weight) in targets {
+            list.push(mapped(target));
+            incoming[target].push(mapped(source));
+            weights.push(weight);
+        }
+        outgoing.push(list);
+    }
+    (RelationshipTopology::new(outgoing, Some(incoming)), weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::dataset::language::stemmer::SimpleSuffixStemmer;
+    use crate::collections::dataset::language::tokenizer::{RegexpTokenizer, WhitespaceTokenizer};
+    use crate::projection::Orientation;
+
+    fn sentences() -> RegexpTokenizer {
+        RegexpTokenizer::new(r"[^.!?\s][^.!?]*[.!?]?").unwrap()
+    }
+
+    fn corpus() -> Corpus {
+        Corpus::from_texts(&[
+            "Graph algorithms rank nodes. Graph algorithms scale.",
+            "Text graphs connect words.",
+        ])
+        .unwrap()
+    }
+
+    fn weight(graph: &TextGraph, rel: &str, source: usize, target: usize) -> Option<f64> {
+        let rel_type = RelationshipType::of(rel);
+        let view = graph
+            .store()
+            .get_graph_with_types_selectors_and_orientation(
+                &HashSet::from([rel_type.clone()]),
+                &HashMap::from([(rel_type, TEXT_GRAPH_WEIGHT.to_string())]),
+                Orientation::Natural,
+            )
+            .ok()?;
+        for cursor in view.stream_relationships_weighted(mapped(source), 0.0) {
+            if cursor.target_id() == mapped(target) {
+                return Some(cursor.weight());
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn builds_document_sentence_and_token_nodes() {
+        let words = WhitespaceTokenizer;
+        let sentence_tokenizer = sentences();
+        let graph = TextGraphBuilder::new(&sentence_tokenizer, &words)
+            .stopwords(["text"])
+            .build(&corpus())
+            .unwrap();
+
+        assert_eq!(graph.node_ids(TextNodeKind::Document).count(), 2);
+        assert_eq!(graph.node_ids(TextNodeKind::Sentence).count(), 3);
+        assert!(graph.term_node("graph").is_some());
+        assert!(graph.term_node("text").is_none());
+        assert_eq!(graph.store().node_count(), graph.node_count());
+        assert_eq!(graph.sentence_tokens()[0].len(), 4);
+    }
+
+    #[test]
+    fn weights_co_occurrence_next_and_membership() {
+        let words = WhitespaceTokenizer;
+        let sentence_tokenizer = sentences();
+        let graph = TextGraphBuilder::new(&sentence_tokenizer, &words)
+            .window(3)
+            .build(&corpus())
+            .unwrap();
+
+        let graph_term = graph.term_node("graph").unwrap();
+        let algorithms = graph.term_node("algorithms").unwrap();
+        let rank = graph.term_node("rank").unwrap();
+        let nodes = graph.term_node("nodes.").unwrap();
+
+        assert_eq!(
+            weight(&graph, TEXT_GRAPH_CO_OCCURS, graph_term, algorithms),
+            Some(2.0)
+        );
+        assert_eq!(
+            weight(&graph, TEXT_GRAPH_CO_OCCURS, algorithms, graph_term),
+            Some(2.0)
+        );
+        assert_eq!(
+            weight(&graph, TEXT_GRAPH_CO_OCCURS, graph_term, rank),
+            Some(1.0)
+        );
+        assert_eq!(
+            weight(&graph, TEXT_GRAPH_CO_OCCURS, graph_term, nodes),
+            None
+        );
+        assert_eq!(
+            weight(&graph, TEXT_GRAPH_NEXT, graph_term, algorithms),
+            Some(2.0)
+        );
+        assert_eq!(weight(&graph, TEXT_GRAPH_NEXT, 2, 3), Some(1.0));
+        assert_eq!(weight(&graph, TEXT_GRAPH_NEXT, 3, 4), None);
+        assert_eq!(
+            weight(&graph, TEXT_GRAPH_IN_DOCUMENT, graph_term, 0),
+            Some(2.0)
+        );
+        assert_eq!(weight(&graph, TEXT_GRAPH_IN_DOCUMENT, 4, 1), Some(1.0));
+    }
+
+    #[test]
+    fn lemmatizer_adds_lemma_nodes() {
+        let words = WhitespaceTokenizer;
+        let sentence_tokenizer = sentences();
+        let stemmer = SimpleSuffixStemmer::new(true);
+        let graph = TextGraphBuilder::new(&sentence_tokenizer, &words)
+            .lemmatizer(&stemmer)
+            .build(&Corpus::from_texts(&["graphs graph"]).unwrap())
+            .unwrap();
+
+        let lemmas = graph.node_ids(TextNodeKind::Lemma).collect::<Vec<_>>();
+        assert_eq!(lemmas.len(), 1);
+        let graphs = graph.term_node("graphs").unwrap();
+        assert_eq!(
+            weight(&graph, TEXT_GRAPH_HAS_LEMMA, graphs, lemmas[0]),
+            Some(1.0)
+        );
+        assert!(graph
+            .store()
+            .has_relationship_type(&RelationshipType::of(TEXT_GRAPH_HAS_LEMMA)));
+    }
+}
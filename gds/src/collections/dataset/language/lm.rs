@@ -118,6 +118,23 @@ impl Vocabulary {
         self.len_cache
     }
 
+    /// Raw count of `word`, regardless of the cutoff.
+    pub fn count(&self, word: &str) -> usize {
+        self.counts.get(word).copied().unwrap_or(0)
+    }
+
+    /// Words that meet the cutoff, sorted, without the unknown label.
+    pub fn words(&self) -> Vec<&str> {
+        let mut words: Vec<&str> = self
+            .counts
+            .iter()
+            .filter(|(_, freq)| **freq >= self.cutoff)
+            .map(|(word, _)| word.as_str())
+            .collect();
+        words.sort_unstable();
+        words
+    }
+
     fn refresh_len(&mut self) {
         let mut count = 0;
         for (_word, freq) in &self.counts {
@@ -945,6 +962,16 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn vocabulary_lists_words_above_cutoff() {
+        let mut vocab = Vocabulary::with_cutoff(2, "<UNK>");
+        vocab.update(["b", "a", "b", "c", "a"].map(String::from));
+
+        assert_eq!(vocab.words(), vec!["a", "b"]);
+        assert_eq!(vocab.count("c"), 1);
+        assert_eq!(vocab.count("z"), 0);
+    }
+
     #[test]
     fn mle_fit_score_generate() {
         let text = to_text(&[&["a", "b", "c"], &["a", "c", "d", "c", "e", "f"]]);
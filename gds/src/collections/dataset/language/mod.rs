@@ -4,6 +4,7 @@ pub mod lm;
 mod manifest;
 pub mod parse;
 pub mod parser;
+pub mod punkt;
 pub mod stats;
 pub mod stem;
 pub mod stemmer;
@@ -13,6 +14,7 @@ pub mod text;
 pub mod token;
 pub mod tokenizer;
 pub mod tree;
+pub mod treebank;
 
 pub use frames::*;
 pub use grammar::*;
@@ -20,6 +22,7 @@ pub use lm::*;
 pub use manifest::*;
 pub use parse::*;
 pub use parser::*;
+pub use punkt::*;
 pub use stats::*;
 pub use stem::*;
 pub use stemmer::*;
@@ -29,3 +32,4 @@ pub use text::*;
 pub use token::*;
 pub use tokenizer::*;
 pub use tree::*;
+pub use treebank::*;
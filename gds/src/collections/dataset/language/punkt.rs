@@ -0,0 +1,823 @@
+//! Punkt unsupervised sentence boundary detection (Kiss & Strunk, 2006).
+//!
+//! [`PunktTrainer`] learns abbreviations, collocations, frequent sentence
+//! starters and orthographic context from raw text; [`PunktSentenceTokenizer`]
+//! uses those [`PunktParameters`] to decide which periods end a sentence.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::collections::dataset::language::token::{Token, TokenSpan};
+use crate::collections::dataset::language::tokenizer::Tokenizer;
+
+const ORTHO_BEG_UC: u32 = 1 << 1;
+const ORTHO_MID_UC: u32 = 1 << 2;
+const ORTHO_UNK_UC: u32 = 1 << 3;
+const ORTHO_BEG_LC: u32 = 1 << 4;
+const ORTHO_MID_LC: u32 = 1 << 5;
+const ORTHO_UNK_LC: u32 = 1 << 6;
+const ORTHO_UC: u32 = ORTHO_BEG_UC | ORTHO_MID_UC | ORTHO_UNK_UC;
+const ORTHO_LC: u32 = ORTHO_BEG_LC | ORTHO_MID_LC | ORTHO_UNK_LC;
+
+const NUMBER_TYPE: &str = "##number##";
+const SENT_END_CHARS: [&str; 3] = [".", "?", "!"];
+const INTERNAL_PUNCTUATION: [char; 3] = [',', ':', ';'];
+const PUNCTUATION: [&str; 6] = [";", ":", ",", ".", "!", "?"];
+
+/// Characters that cannot start a word token.
+const NON_WORD_START: &str = "(\"`{[:;&#*@)}]-,";
+/// Characters that end a word token and stand alone.
+const NON_WORD: &str = ")\";}]*:@'({[!?";
+/// Closing characters kept with the sentence they follow.
+const SENT_CLOSERS: &str = "\"')]}";
+
+/// Learned Punkt model, serializable as JSON.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PunktParameters {
+    /// Abbreviation types, lowercased and without the final period.
+    pub abbrev_types: BTreeSet<String>,
+    /// Type pairs whose first member's period is not a sentence break.
+    pub collocations: BTreeSet<(String, String)>,
+    /// Types that frequently start sentences.
+    pub sent_starters: BTreeSet<String>,
+    /// Bit flags for the cases and positions in which each type was seen.
+    pub ortho_context: BTreeMap<String, u32>,
+}
+
+impl PunktParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    fn ortho(&self, typ: &str) -> u32 {
+        self.ortho_context.get(typ).copied().unwrap_or(0)
+    }
+
+    fn add_ortho_context(&mut self, typ: &str, flag: u32) {
+        *self.ortho_context.entry(typ.to_string()).or_insert(0) |= flag;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FirstCase {
+    Upper,
+    Lower,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OrthoContext {
+    Initial,
+    Internal,
+    Unknown,
+}
+
+/// A word token with the annotations Punkt accumulates on it.
+#[derive(Debug, Clone)]
+struct PunktToken {
+    tok: String,
+    span: TokenSpan,
+    typ: String,
+    parastart: bool,
+    linestart: bool,
+    sentbreak: bool,
+    abbr: bool,
+    ellipsis: bool,
+}
+
+impl PunktToken {
+    fn new(tok: &str, span: TokenSpan) -> Self {
+        let lower = tok.to_lowercase();
+        let typ = if is_number(&lower) {
+            NUMBER_TYPE.to_string()
+        } else {
+            lower
+        };
+        Self {
+            tok: tok.to_string(),
+            span,
+            typ,
+            parastart: false,
+            linestart: false,
+            sentbreak: false,
+            abbr: false,
+            ellipsis: false,
+        }
+    }
+
+    fn period_final(&self) -> bool {
+        self.tok.ends_with('.')
+    }
+
+    fn type_no_period(&self) -> &str {
+        if self.typ.len() > 1 && self.typ.ends_with('.') {
+            &self.typ[..self.typ.len() - 1]
+        } else {
+            &self.typ
+        }
+    }
+
+    fn type_no_sentperiod(&self) -> &str {
+        if self.sentbreak {
+            self.type_no_period()
+        } else {
+            &self.typ
+        }
+    }
+
+    fn first_case(&self) -> FirstCase {
+        match self.tok.chars().next() {
+            Some(c) if c.is_uppercase() => FirstCase::Upper,
+            Some(c) if c.is_lowercase() => FirstCase::Lower,
+            _ => FirstCase::None,
+        }
+    }
+
+    fn first_upper(&self) -> bool {
+        self.first_case() == FirstCase::Upper
+    }
+
+    fn first_lower(&self) -> bool {
+        self.first_case() == FirstCase::Lower
+    }
+
+    fn is_ellipsis(&self) -> bool {
+        self.tok.len() >= 2 && self.tok.chars().all(|c| c == '.')
+    }
+
+    fn is_number(&self) -> bool {
+        self.typ.starts_with(NUMBER_TYPE)
+    }
+
+    /// A single letter followed by a period, e.g. `J.`.
+    fn is_initial(&self) -> bool {
+        let mut chars = self.tok.chars();
+        matches!(
+            (chars.next(), chars.next(), chars.next()),
+            (Some(c), Some('.'), None) if c.is_alphabetic()
+        )
+    }
+
+    fn is_alpha(&self) -> bool {
+        !self.tok.is_empty() && self.tok.chars().all(char::is_alphabetic)
+    }
+
+    fn is_non_punct(&self) -> bool {
+        self.typ.chars().any(char::is_alphabetic)
+    }
+}
+
+/// Unsupervised Punkt trainer.
+///
+/// Call [`PunktTrainer::train`] on one or more texts, then
+/// [`PunktTrainer::finalize_training`] before reading the parameters.
+#[derive(Debug, Clone, Default)]
+pub struct PunktTrainer {
+    params: PunktParameters,
+    type_fdist: HashMap<String, usize>,
+    type_total: usize,
+    num_period_toks: usize,
+    collocation_fdist: HashMap<(String, String), usize>,
+    sent_starter_fdist: HashMap<String, usize>,
+    sentbreak_count: usize,
+}
+
+impl PunktTrainer {
+    /// Minimum log-likelihood score for a type to count as an abbreviation.
+    pub const ABBREV: f64 = 0.3;
+    /// Rare types below this frequency may become abbreviations from context.
+    pub const ABBREV_BACKOFF: usize = 5;
+    /// Minimum log-likelihood score for a collocation.
+    pub const COLLOCATION: f64 = 7.88;
+    /// Minimum log-likelihood score for a frequent sentence starter.
+    pub const SENT_STARTER: f64 = 30.0;
+    /// Collocations must be seen more often than this.
+    pub const MIN_COLLOC_FREQ: usize = 1;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accumulate statistics from `text` and update the abbreviation list and
+    /// orthographic context.
+    pub fn train(&mut self, text: &str) {
+        let mut tokens = word_tokenize(text);
+
+        for token in &tokens {
+            *self.type_fdist.entry(token.typ.clone()).or_insert(0) += 1;
+            self.type_total += 1;
+            if token.period_final() {
+                self.num_period_toks += 1;
+            }
+        }
+
+        let unique_types = tokens
+            .iter()
+            .map(|token| token.typ.clone())
+            .collect::<HashSet<_>>();
+        for (abbr, score, is_add) in self.reclassify_abbrev_types(unique_types) {
+            if score >= Self::ABBREV {
+                if is_add {
+                    self.params.abbrev_types.insert(abbr);
+                }
+            } else if !is_add {
+                self.params.abbrev_types.remove(&abbr);
+            }
+        }
+
+        first_pass_annotation(&mut tokens, &self.params);
+        self.orthography_data(&tokens);
+        self.sentbreak_count += tokens.iter().filter(|token| token.sentbreak).count();
+
+        for pair in tokens.windows(2) {
+            let (tok1, tok2) = (&pair[0], &pair[1]);
+            if !tok1.period_final() {
+                continue;
+            }
+            if self.is_rare_abbrev_type(tok1, tok2) {
+                self.params
+                    .abbrev_types
+                    .insert(tok1.type_no_period().to_string());
+            }
+            if is_potential_sent_starter(tok2, tok1) {
+                *self.sent_starter_fdist.entry(tok2.typ.clone()).or_insert(0) += 1;
+            }
+            if is_potential_collocation(tok1, tok2) {
+                let key = (
+                    tok1.type_no_period().to_string(),
+                    tok2.type_no_sentperiod().to_string(),
+                );
+                *self.collocation_fdist.entry(key).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Pick sentence starters and collocations from the accumulated counts.
+    pub fn finalize_training(&mut self) {
+        self.params.sent_starters = self.find_sent_starters();
+        self.params.collocations = self.find_collocations();
+    }
+
+    pub fn parameters(&self) -> &PunktParameters {
+        &self.params
+    }
+
+    pub fn into_parameters(self) -> PunktParameters {
+        self.params
+    }
+
+    fn count(&self, typ: &str) -> usize {
+        self.type_fdist.get(typ).copied().unwrap_or(0)
+    }
+
+    /// Count of a type with and without a trailing period.
+    fn count_with_period(&self, typ: &str) -> usize {
+        self.count(typ) + self.count(&format!("{typ}."))
+    }
+
+    fn reclassify_abbrev_types(&self, types: HashSet<String>) -> Vec<(String, f64, bool)> {
+        let mut out = Vec::new();
+        for typ in types {
+            if !typ.chars().any(char::is_alphabetic) || typ == NUMBER_TYPE {
+                continue;
+            }
+            let (typ, is_add) = match typ.strip_suffix('.') {
+                Some(_) if self.params.abbrev_types.contains(&typ) => continue,
+                Some(stripped) => (stripped.to_string(), true),
+                None if !self.params.abbrev_types.contains(&typ) => continue,
+                None => (typ, false),
+            };
+
+            let num_periods = typ.matches('.').count() + 1;
+            let num_nonperiods = typ.chars().count() + 1 - num_periods;
+            let count_with_period = self.count(&format!("{typ}."));
+            let count_without_period = self.count(&typ);
+            let likelihood = dunning_log_likelihood(
+                (count_with_period + count_without_period) as f64,
+                self.num_period_toks as f64,
+                count_with_period as f64,
+                self.type_total as f64,
+            );
+            let f_length = (-(num_nonperiods as f64)).exp();
+            let f_penalty = (num_nonperiods as f64).powi(-(count_without_period as i32));
+            let score = likelihood * f_length * num_periods as f64 * f_penalty;
+            out.push((typ, score, is_add));
+        }
+        out
+    }
+
+    fn orthography_data(&mut self, tokens: &[PunktToken]) {
+        let mut context = OrthoContext::Internal;
+        for token in tokens {
+            if token.parastart && context != OrthoContext::Unknown {
+                context = OrthoContext::Initial;
+            }
+            if token.linestart && context == OrthoContext::Internal {
+                context = OrthoContext::Unknown;
+            }
+
+            let flag = match (context, token.first_case()) {
+                (OrthoContext::Initial, FirstCase::Upper) => ORTHO_BEG_UC,
+                (OrthoContext::Internal, FirstCase::Upper) => ORTHO_MID_UC,
+                (OrthoContext::Unknown, FirstCase::Upper) => ORTHO_UNK_UC,
+                (OrthoContext::Initial, FirstCase::Lower) => ORTHO_BEG_LC,
+                (OrthoContext::Internal, FirstCase::Lower) => ORTHO_MID_LC,
+                (OrthoContext::Unknown, FirstCase::Lower) => ORTHO_UNK_LC,
+                (_, FirstCase::None) => 0,
+            };
+            if flag != 0 {
+                self.params
+                    .add_ortho_context(token.type_no_sentperiod(), flag);
+            }
+
+            context = if token.sentbreak {
+                if token.is_number() || token.is_initial() {
+                    OrthoContext::Unknown
+                } else {
+                    OrthoContext::Initial
+                }
+            } else if token.ellipsis || token.abbr {
+                OrthoContext::Unknown
+            } else {
+                OrthoContext::Internal
+            };
+        }
+    }
+
+    /// A rare period-final type counts as an abbreviation when the next token
+    /// is internal punctuation or a lowercase word that normally starts
+    /// sentences only in uppercase.
+    fn is_rare_abbrev_type(&self, current: &PunktToken, next: &PunktToken) -> bool {
+        if current.abbr || !current.sentbreak {
+            return false;
+        }
+        let typ = current.type_no_sentperiod();
+        let without_last = typ
+            .char_indices()
+            .last()
+            .map_or("", |(index, _)| &typ[..index]);
+        let count = self.count(typ) + self.count(without_last);
+        if self.params.abbrev_types.contains(typ) || count >= Self::ABBREV_BACKOFF {
+            return false;
+        }
+        if next.tok.starts_with(INTERNAL_PUNCTUATION) {
+            return true;
+        }
+        if next.first_lower() {
+            let ortho = self.params.ortho(next.type_no_sentperiod());
+            return ortho & ORTHO_BEG_UC != 0 && ortho & ORTHO_MID_UC == 0;
+        }
+        false
+    }
+
+    fn find_sent_starters(&self) -> BTreeSet<String> {
+        let total = self.type_total as f64;
+        self.sent_starter_fdist
+            .iter()
+            .filter(|(typ, &at_break)| {
+                let typ_count = self.count_with_period(typ);
+                if typ.is_empty() || typ_count < at_break || self.sentbreak_count == 0 {
+                    return false;
+                }
+                let likelihood = col_log_likelihood(
+                    self.sentbreak_count as f64,
+                    typ_count as f64,
+                    at_break as f64,
+                    total,
+                );
+                likelihood >= Self::SENT_STARTER
+                    && total / self.sentbreak_count as f64 > typ_count as f64 / at_break as f64
+            })
+            .map(|(typ, _)| typ.clone())
+            .collect()
+    }
+
+    fn find_collocations(&self) -> BTreeSet<(String, String)> {
+        let total = self.type_total as f64;
+        self.collocation_fdist
+            .iter()
+            .filter(|((typ1, typ2), &col_count)| {
+                if self.params.sent_starters.contains(typ2) {
+                    return false;
+                }
+                let typ1_count = self.count_with_period(typ1);
+                let typ2_count = self.count_with_period(typ2);
+                if typ1_count <= 1
+                    || typ2_count <= 1
+                    || col_count <= Self::MIN_COLLOC_FREQ
+                    || col_count > typ1_count.min(typ2_count)
+                {
+                    return false;
+                }
+                let likelihood = col_log_likelihood(
+                    typ1_count as f64,
+                    typ2_count as f64,
+                    col_count as f64,
+                    total,
+                );
+                likelihood >= Self::COLLOCATION
+                    && total / typ1_count as f64 > typ2_count as f64 / col_count as f64
+            })
+            .map(|(pair, _)| pair.clone())
+            .collect()
+    }
+}
+
+/// Punkt sentence tokenizer.
+///
+/// Sentence tokens carry byte spans into the input; whitespace between
+/// sentences is not part of either one, and closing quotes or brackets after a
+/// sentence-final period stay with that sentence.
+#[derive(Debug, Clone, Default)]
+pub struct PunktSentenceTokenizer {
+    params: PunktParameters,
+}
+
+impl PunktSentenceTokenizer {
+    pub fn new(params: PunktParameters) -> Self {
+        Self { params }
+    }
+
+    /// Train a tokenizer on `text` and use it as is.
+    pub fn train(text: &str) -> Self {
+        let mut trainer = PunktTrainer::new();
+        trainer.train(text);
+        trainer.finalize_training();
+        Self::new(trainer.into_parameters())
+    }
+
+    pub fn parameters(&self) -> &PunktParameters {
+        &self.params
+    }
+
+    /// Split `text` into sentence strings.
+    pub fn sentences(&self, text: &str) -> Vec<String> {
+        self.span_tokenize(text)
+            .into_iter()
+            .map(|span| text[span.start()..span.end()].to_string())
+            .collect()
+    }
+
+    fn sentence_spans(&self, text: &str) -> Vec<TokenSpan> {
+        let mut tokens = word_tokenize(text);
+        first_pass_annotation(&mut tokens, &self.params);
+        for index in 1..tokens.len() {
+            let (before, after) = tokens.split_at_mut(index);
+            self.second_pass_annotation(&mut before[index - 1], &after[0]);
+        }
+
+        let mut spans = Vec::new();
+        let mut start = None::<usize>;
+        let mut index = 0;
+        while index < tokens.len() {
+            let sentence_start = *start.get_or_insert(tokens[index].span.start());
+            if tokens[index].sentbreak {
+                let mut end = tokens[index].span.end();
+                while let Some(next) = tokens.get(index + 1) {
+                    if next.span.start() != end
+                        || !next.tok.chars().all(|c| SENT_CLOSERS.contains(c))
+                    {
+                        break;
+                    }
+                    end = next.span.end();
+                    index += 1;
+                }
+                spans.push(TokenSpan::new(sentence_start, end));
+                start = None;
+            }
+            index += 1;
+        }
+        if let (Some(sentence_start), Some(last)) = (start, tokens.last()) {
+            spans.push(TokenSpan::new(sentence_start, last.span.end()));
+        }
+        spans
+    }
+
+    /// Revisit period-final tokens using collocations, sentence starters and
+    /// the orthographic evidence about the following word.
+    fn second_pass_annotation(&self, current: &mut PunktToken, next: &PunktToken) {
+        if !current.period_final() {
+            return;
+        }
+        let typ = current.type_no_period().to_string();
+        let next_typ = next.type_no_sentperiod();
+        let is_initial = current.is_initial();
+
+        if self
+            .params
+            .collocations
+            .contains(&(typ.clone(), next_typ.to_string()))
+        {
+            current.sentbreak = false;
+            current.abbr = true;
+            return;
+        }
+
+        if (current.abbr || current.ellipsis)
+            && !is_initial
+            && (self.ortho_heuristic(next) == Some(true)
+                || (next.first_upper() && self.params.sent_starters.contains(next_typ)))
+        {
+            current.sentbreak = true;
+            return;
+        }
+
+        if is_initial || typ == NUMBER_TYPE {
+            let starter = self.ortho_heuristic(next);
+            if starter == Some(false)
+                || (starter.is_none()
+                    && is_initial
+                    && next.first_upper()
+                    && self.params.ortho(next_typ) & ORTHO_LC == 0)
+            {
+                current.sentbreak = false;
+                current.abbr = true;
+            }
+        }
+    }
+
+    /// Whether `token` starts a sentence judging by the cases it was seen in;
+    /// `None` when the evidence is inconclusive.
+    fn ortho_heuristic(&self, token: &PunktToken) -> Option<bool> {
+        if PUNCTUATION.contains(&token.tok.as_str()) {
+            return Some(false);
+        }
+        let ortho = self.params.ortho(token.type_no_sentperiod());
+        if token.first_upper() && ortho & ORTHO_LC != 0 && ortho & ORTHO_MID_UC == 0 {
+            return Some(true);
+        }
+        if token.first_lower() && (ortho & ORTHO_UC != 0 || ortho & ORTHO_BEG_LC == 0) {
+            return Some(false);
+        }
+        None
+    }
+}
+
+impl Tokenizer for PunktSentenceTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        self.sentence_spans(text)
+            .into_iter()
+            .map(|span| Token::word(&text[span.start()..span.end()], span))
+            .collect()
+    }
+
+    fn span_tokenize(&self, text: &str) -> Vec<TokenSpan> {
+        self.sentence_spans(text)
+    }
+}
+
+/// Mark sentence-final punctuation, ellipses and known abbreviations.
+fn first_pass_annotation(tokens: &mut [PunktToken], params: &PunktParameters) {
+    for token in tokens {
+        if SENT_END_CHARS.contains(&token.tok.as_str()) {
+            token.sentbreak = true;
+        } else if token.is_ellipsis() {
+            token.ellipsis = true;
+        } else if token.period_final() && !token.tok.ends_with("..") {
+            let stem = token.tok[..token.tok.len() - 1].to_lowercase();
+            let last_part = stem.rsplit('-').next().unwrap_or(&stem);
+            if params.abbrev_types.contains(&stem) || params.abbrev_types.contains(last_part) {
+                token.abbr = true;
+            } else {
+                token.sentbreak = true;
+            }
+        }
+    }
+}
+
+fn is_potential_sent_starter(current: &PunktToken, prev: &PunktToken) -> bool {
+    prev.sentbreak && !(prev.is_number() || prev.is_initial()) && current.is_alpha()
+}
+
+fn is_potential_collocation(first: &PunktToken, second: &PunktToken) -> bool {
+    first.sentbreak
+        && (first.is_number() || first.is_initial())
+        && first.is_non_punct()
+        && second.is_non_punct()
+}
+
+/// Split text into Punkt word tokens, keeping a final period attached to its
+/// word and marking tokens that start a line or paragraph.
+fn word_tokenize(text: &str) -> Vec<PunktToken> {
+    let mut tokens = Vec::new();
+    let mut newlines = 1usize;
+    let mut chunk_start = None::<usize>;
+
+    for (index, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        if !c.is_whitespace() {
+            chunk_start.get_or_insert(index);
+            continue;
+        }
+        if let Some(start) = chunk_start.take() {
+            let first = tokens.len();
+            split_chunk(text, start, index, &mut tokens);
+            if let Some(token) = tokens.get_mut(first) {
+                token.linestart = newlines > 0;
+                token.parastart = newlines > 1 && first > 0;
+            }
+            newlines = 0;
+        }
+        if c == '\n' {
+            newlines += 1;
+        }
+    }
+    tokens
+}
+
+/// Split one whitespace-delimited chunk `text[start..end]` into tokens.
+fn split_chunk(text: &str, start: usize, end: usize, tokens: &mut Vec<PunktToken>) {
+    let mut position = start;
+    while position < end {
+        let rest = &text[position..end];
+        let first = rest.chars().next().expect("non-empty chunk");
+
+        let multi = multi_char_len(rest);
+        let length = if multi > 0 {
+            multi
+        } else if NON_WORD_START.contains(first) {
+            first.len_utf8()
+        } else {
+            word_len(rest)
+        };
+        tokens.push(PunktToken::new(
+            &text[position..position + length],
+            TokenSpan::new(position, position + length),
+        ));
+        position += length;
+    }
+}
+
+/// Length of a leading run of dashes or periods (`--`, `...`), or zero.
+fn multi_char_len(text: &str) -> usize {
+    for marker in ['-', '.'] {
+        let run = text.chars().take_while(|&c| c == marker).count();
+        if run >= 2 {
+            return run;
+        }
+    }
+    0
+}
+
+/// Length of a word starting at the beginning of `text`. The word ends before
+/// stand-alone punctuation, a dash or period run, or a word-final comma.
+fn word_len(text: &str) -> usize {
+    let mut chars = text.char_indices().peekable();
+    let mut length = 0;
+    while let Some((index, c)) = chars.next() {
+        if index > 0 {
+            let rest = &text[index..];
+            let comma_at_end = c == ','
+                && chars
+                    .peek()
+                    .map(|&(_, next)| NON_WORD.contains(next) || multi_char_len(&rest[1..]) > 0)
+                    .unwrap_or(true);
+            if NON_WORD.contains(c) || multi_char_len(rest) > 0 || comma_at_end {
+                break;
+            }
+        }
+        length = index + c.len_utf8();
+    }
+    length
+}
+
+fn is_number(text: &str) -> bool {
+    let text = text.strip_prefix('-').unwrap_or(text);
+    let text = text.strip_prefix(['.', ',']).unwrap_or(text);
+    let mut chars = text.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_digit())
+        && chars.all(|c| c.is_ascii_digit() || matches!(c, ',' | '.' | '-'))
+}
+
+fn xlogy(x: f64, y: f64) -> f64 {
+    if x == 0.0 {
+        0.0
+    } else {
+        x * y.ln()
+    }
+}
+
+/// Dunning log-likelihood that `count_ab` of `count_a` occurrences carry a
+/// period, against the period rate `count_b / n`.
+fn dunning_log_likelihood(count_a: f64, count_b: f64, count_ab: f64, n: f64) -> f64 {
+    let p1 = count_b / n;
+    let p2 = 0.99;
+    let null_hypothesis = xlogy(count_ab, p1) + xlogy(count_a - count_ab, 1.0 - p1);
+    let alternative = xlogy(count_ab, p2) + xlogy(count_a - count_ab, 1.0 - p2);
+    -2.0 * (null_hypothesis - alternative)
+}
+
+/// Log-likelihood ratio that `b` follows `a` more often than chance.
+fn col_log_likelihood(count_a: f64, count_b: f64, count_ab: f64, n: f64) -> f64 {
+    let p = count_b / n;
+    let p1 = count_ab / count_a;
+    let p2 = if n == count_a {
+        1.0
+    } else {
+        (count_b - count_ab) / (n - count_a)
+    };
+    let valid = |p: f64| p > 0.0 && p < 1.0;
+
+    let (summand1, summand2) = if valid(p) {
+        (
+            xlogy(count_ab, p) + xlogy(count_a - count_ab, 1.0 - p),
+            xlogy(count_b - count_ab, p) + xlogy(n - count_a - count_b + count_ab, 1.0 - p),
+        )
+    } else {
+        (0.0, 0.0)
+    };
+    let summand3 = if count_a == count_ab || !valid(p1) {
+        0.0
+    } else {
+        xlogy(count_ab, p1) + xlogy(count_a - count_ab, 1.0 - p1)
+    };
+    let summand4 = if count_b == count_ab || !valid(p2) {
+        0.0
+    } else {
+        xlogy(count_b - count_ab, p2) + xlogy(n - count_a - count_b + count_ab, 1.0 - p2)
+    };
+    -2.0 * (summand1 + summand2 - summand3 - summand4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn training_text() -> String {
+        let mut text = String::new();
+        for name in ["Smith", "Jones", "Brown", "Lee", "Clark", "Hall"] {
+            text.push_str(&format!(
+                "We met Dr. {name} at the station. The train was late again. \
+                 Mr. {name} said it was fine. She arrived at noon. \
+                 Prof. {name} gave a talk, e.g. on birds. They left early.\n"
+            ));
+        }
+        text
+    }
+
+    #[test]
+    fn word_tokenize_keeps_periods_and_splits_punctuation() {
+        let tokens = word_tokenize("He said (twice), \"Stop...\"\n\nDr. No-one.");
+        let texts = tokens.iter().map(|t| t.tok.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            texts,
+            vec!["He", "said", "(", "twice", ")", ",", "\"", "Stop", "...", "\"", "Dr.", "No-one."]
+        );
+        assert!(tokens[0].linestart);
+        assert!(tokens[10].parastart);
+        assert_eq!(tokens[10].span, TokenSpan::new(28, 31));
+    }
+
+    #[test]
+    fn trainer_learns_abbreviations() {
+        let mut trainer = PunktTrainer::new();
+        trainer.train(&training_text());
+        trainer.finalize_training();
+        let params = trainer.parameters();
+
+        for abbr in ["dr", "mr", "e.g"] {
+            assert!(params.abbrev_types.contains(abbr), "missing {abbr}");
+        }
+        assert!(!params.abbrev_types.contains("station"));
+        assert!(params.ortho("the") & ORTHO_BEG_UC != 0);
+    }
+
+    #[test]
+    fn tokenizer_splits_sentences_around_abbreviations() {
+        let tokenizer = PunktSentenceTokenizer::train(&training_text());
+        let text = "Yesterday Dr. Watson called. He asked for Mr. Holmes (urgently.) \
+                    Nobody answered! Why not?";
+        assert_eq!(
+            tokenizer.sentences(text),
+            vec![
+                "Yesterday Dr. Watson called.",
+                "He asked for Mr. Holmes (urgently.)",
+                "Nobody answered!",
+                "Why not?",
+            ]
+        );
+        let spans = tokenizer.span_tokenize(text);
+        assert_eq!(spans[0], TokenSpan::new(0, 28));
+    }
+
+    #[test]
+    fn parameters_roundtrip_through_json() {
+        let tokenizer = PunktSentenceTokenizer::train(&training_text());
+        let json = tokenizer.parameters().to_json().expect("serialize");
+        let restored = PunktParameters::from_json(&json).expect("deserialize");
+        assert_eq!(&restored, tokenizer.parameters());
+
+        let reloaded = PunktSentenceTokenizer::new(restored);
+        let text = "Ask Dr. Lee. He knows.";
+        assert_eq!(reloaded.sentences(text), tokenizer.sentences(text));
+        assert_eq!(reloaded.sentences(text).len(), 2);
+    }
+}
@@ -1,6 +1,6 @@
 //! Tagger traits and default implementations.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use regex::Regex;
 
@@ -42,6 +42,45 @@ pub trait Tagger {
             correct as f64 / total as f64
         }
     }
+
+    /// Token-level accuracy broken down by gold tag.
+    fn per_tag_accuracy(&self, gold: &[Vec<Tag>]) -> BTreeMap<String, TagAccuracy> {
+        let mut report = BTreeMap::<String, TagAccuracy>::new();
+
+        for gold_sent in gold {
+            let tokens = gold_sent
+                .iter()
+                .map(|tag| Token::new(tag.text(), tag.span(), TokenKind::Word))
+                .collect::<Vec<Token>>();
+            let predicted = self.tag_tokens(&tokens);
+            for (g, p) in gold_sent.iter().zip(predicted.iter()) {
+                let entry = report.entry(g.tag().to_string()).or_default();
+                entry.total += 1;
+                if g.tag() == p.tag() {
+                    entry.correct += 1;
+                }
+            }
+        }
+
+        report
+    }
+}
+
+/// Correct and total counts for one gold tag.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TagAccuracy {
+    pub correct: usize,
+    pub total: usize,
+}
+
+impl TagAccuracy {
+    pub fn accuracy(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.correct as f64 / self.total as f64
+        }
+    }
 }
 
 /// Default tagger: assigns a constant tag to all tokens.
@@ -268,4 +307,23 @@ mod tests {
         let score = tagger.accuracy(&gold);
         assert!((score - 0.5).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn per_tag_accuracy_groups_by_gold_tag() {
+        let tagger = DefaultTagger::new("NN");
+        let gold = vec![vec![
+            Tag::new("John", "NN", TokenSpan::new(0, 4)),
+            Tag::new("walks", "VB", TokenSpan::new(5, 10)),
+            Tag::new("Mary", "NN", TokenSpan::new(11, 15)),
+        ]];
+        let report = tagger.per_tag_accuracy(&gold);
+        assert_eq!(
+            report["NN"],
+            TagAccuracy {
+                correct: 2,
+                total: 2
+            }
+        );
+        assert_eq!(report["VB"].accuracy(), 0.0);
+    }
 }
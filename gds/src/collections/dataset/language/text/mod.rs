@@ -1,5 +1,7 @@
 pub mod collocations;
 mod core;
+pub mod vectorize;
 
 pub use collocations::*;
 pub use core::*;
+pub use vectorize::*;
@@ -0,0 +1,690 @@
+//! Term-weighting vectorizers — TF-IDF and Okapi BM25.
+//!
+//! Both vectorizers share a [`TermNormalizer`]: word and number tokens are
+//! optionally lowercased, stopwords are dropped and an optional
+//! [`Stemmer`] maps each remaining token to its index term.
+//!
+//! Fitting builds a [`Vocabulary`] whose cutoff doubles as the minimum
+//! collection frequency of an index term. Vector columns follow the
+//! vocabulary's sorted word order, so fitted vectorizers are reproducible
+//! across runs. Documents become [`SparseVector`]s.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+use crate::collections::dataframe::GDSFrameError;
+use crate::collections::dataset::corpus::Corpus;
+use crate::collections::dataset::language::lm::Vocabulary;
+use crate::collections::dataset::language::stemmer::Stemmer;
+use crate::collections::dataset::language::token::{Token, TokenKind};
+use crate::collections::dataset::language::tokenizer::Tokenizer;
+
+// ---------------------------------------------------------------------------
+// SparseVector
+// ---------------------------------------------------------------------------
+
+/// A sparse vector with sorted, unique column indices and no stored zeros.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SparseVector {
+    dimension: usize,
+    indices: Vec<usize>,
+    values: Vec<f64>,
+}
+
+impl SparseVector {
+    /// Build from `(index, value)` entries. Duplicate indices are summed and
+    /// zero values dropped.
+    pub fn new(dimension: usize, entries: impl IntoIterator<Item = (usize, f64)>) -> Self {
+        let mut sorted = entries.into_iter().collect::<Vec<_>>();
+        sorted.sort_by_key(|(index, _)| *index);
+
+        let mut indices = Vec::with_capacity(sorted.len());
+        let mut values: Vec<f64> = Vec::with_capacity(sorted.len());
+        for (index, value) in sorted {
+            assert!(
+                index < dimension,
+                "sparse index {index} out of bounds for dimension {dimension}"
+            );
+            if indices.last() == Some(&index) {
+                *values.last_mut().expect("values track indices") += value;
+            } else {
+                indices.push(index);
+                values.push(value);
+            }
+        }
+
+        let (indices, values) = indices
+            .into_iter()
+            .zip(values)
+            .filter(|(_, value)| *value != 0.0)
+            .unzip();
+        Self {
+            dimension,
+            indices,
+            values,
+        }
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Number of stored (non-zero) entries.
+    pub fn nnz(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, f64)> + '_ {
+        self.indices
+            .iter()
+            .copied()
+            .zip(self.values.iter().copied())
+    }
+
+    pub fn get(&self, index: usize) -> f64 {
+        self.indices
+            .binary_search(&index)
+            .map(|position| self.values[position])
+            .unwrap_or(0.0)
+    }
+
+    pub fn dot(&self, other: &SparseVector) -> f64 {
+        let (mut i, mut j, mut sum) = (0, 0, 0.0);
+        while i < self.indices.len() && j < other.indices.len() {
+            match self.indices[i].cmp(&other.indices[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    sum += self.values[i] * other.values[j];
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        sum
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.values
+            .iter()
+            .map(|value| value * value)
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Cosine similarity; `0.0` when either vector is zero.
+    pub fn cosine(&self, other: &SparseVector) -> f64 {
+        let denominator = self.norm() * other.norm();
+        if denominator == 0.0 {
+            0.0
+        } else {
+            self.dot(other) / denominator
+        }
+    }
+
+    /// Scale to unit L2 norm. Zero vectors are returned unchanged.
+    pub fn normalized(mut self) -> Self {
+        let norm = self.norm();
+        if norm > 0.0 {
+            self.values.iter_mut().for_each(|value| *value /= norm);
+        }
+        self
+    }
+
+    pub fn to_dense(&self) -> Vec<f64> {
+        let mut dense = vec![0.0; self.dimension];
+        for (index, value) in self.iter() {
+            dense[index] = value;
+        }
+        dense
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TermNormalizer — stopword and stemming hooks
+// ---------------------------------------------------------------------------
+
+/// Maps tokens to index terms.
+///
+/// Stopwords are matched against the (optionally lowercased) surface form,
+/// before stemming.
+#[derive(Clone)]
+pub struct TermNormalizer {
+    lowercase: bool,
+    stopwords: HashSet<String>,
+    stemmer: Option<Arc<dyn Stemmer + Send + Sync>>,
+}
+
+impl Default for TermNormalizer {
+    fn default() -> Self {
+        Self {
+            lowercase: true,
+            stopwords: HashSet::new(),
+            stemmer: None,
+        }
+    }
+}
+
+impl fmt::Debug for TermNormalizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TermNormalizer")
+            .field("lowercase", &self.lowercase)
+            .field("stopwords", &self.stopwords.len())
+            .field("stemmer", &self.stemmer.is_some())
+            .finish()
+    }
+}
+
+impl TermNormalizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    pub fn with_stopwords<I, S>(mut self, stopwords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.stopwords = stopwords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_stemmer(mut self, stemmer: impl Stemmer + Send + Sync + 'static) -> Self {
+        self.stemmer = Some(Arc::new(stemmer));
+        self
+    }
+
+    /// Index terms of a token sequence, in order.
+    pub fn terms(&self, tokens: &[Token]) -> Vec<String> {
+        tokens
+            .iter()
+            .filter(|token| matches!(token.kind(), TokenKind::Word | TokenKind::Number))
+            .filter_map(|token| {
+                let text = if self.lowercase {
+                    token.text().to_lowercase()
+                } else {
+                    token.text().to_string()
+                };
+                if self.stopwords.contains(&text) {
+                    return None;
+                }
+                Some(match &self.stemmer {
+                    Some(stemmer) => stemmer
+                        .stem_token(&token.clone().with_text(text))
+                        .text()
+                        .to_string(),
+                    None => text,
+                })
+            })
+            .collect()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Shared fitted state
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+struct TermIndex {
+    vocabulary: Vocabulary,
+    terms: Vec<String>,
+    index: HashMap<String, usize>,
+    document_frequency: Vec<usize>,
+    document_count: usize,
+    total_length: usize,
+}
+
+impl TermIndex {
+    fn empty() -> Self {
+        Self::fit(&[], 1)
+    }
+
+    fn fit(documents: &[Vec<String>], min_count: usize) -> Self {
+        let mut vocabulary = Vocabulary::with_cutoff(min_count, "<UNK>");
+        for terms in documents {
+            vocabulary.update(terms.iter().cloned());
+        }
+        let terms = vocabulary
+            .words()
+            .into_iter()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        let index = terms
+            .iter()
+            .enumerate()
+            .map(|(column, term)| (term.clone(), column))
+            .collect::<HashMap<_, _>>();
+
+        let mut document_frequency = vec![0; terms.len()];
+        for document in documents {
+            let unique = document
+                .iter()
+                .filter_map(|term| index.get(term))
+                .collect::<HashSet<_>>();
+            for &column in unique {
+                document_frequency[column] += 1;
+            }
+        }
+
+        Self {
+            vocabulary,
+            terms,
+            index,
+            document_frequency,
+            document_count: documents.len(),
+            total_length: documents.iter().map(Vec::len).sum(),
+        }
+    }
+
+    /// Term counts of one document, keyed by column.
+    fn counts(&self, terms: &[String]) -> HashMap<usize, usize> {
+        let mut counts = HashMap::new();
+        for term in terms {
+            if let Some(&column) = self.index.get(term) {
+                *counts.entry(column).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TF-IDF
+// ---------------------------------------------------------------------------
+
+/// TF-IDF weighting options.
+///
+/// With `smooth_idf`, `idf = ln((1 + n) / (1 + df)) + 1`; otherwise
+/// `idf = ln(n / df) + 1`. `sublinear_tf` replaces a raw count `tf` with
+/// `1 + ln(tf)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TfidfConfig {
+    pub sublinear_tf: bool,
+    pub smooth_idf: bool,
+    /// Scale each document vector to unit L2 norm.
+    pub normalize: bool,
+    /// Minimum collection frequency for a term to enter the vocabulary.
+    pub min_count: usize,
+}
+
+impl Default for TfidfConfig {
+    fn default() -> Self {
+        Self {
+            sublinear_tf: false,
+            smooth_idf: true,
+            normalize: true,
+            min_count: 1,
+        }
+    }
+}
+
+/// TF-IDF document vectorizer.
+#[derive(Debug, Clone)]
+pub struct TfidfVectorizer {
+    config: TfidfConfig,
+    normalizer: TermNormalizer,
+    index: TermIndex,
+    idf: Vec<f64>,
+}
+
+impl Default for TfidfVectorizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TfidfVectorizer {
+    pub fn new() -> Self {
+        Self {
+            config: TfidfConfig::default(),
+            normalizer: TermNormalizer::default(),
+            index: TermIndex::empty(),
+            idf: Vec::new(),
+        }
+    }
+
+    pub fn with_config(mut self, config: TfidfConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn with_normalizer(mut self, normalizer: TermNormalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    pub fn config(&self) -> &TfidfConfig {
+        &self.config
+    }
+
+    pub fn vocabulary(&self) -> &Vocabulary {
+        &self.index.vocabulary
+    }
+
+    /// Index terms in column order.
+    pub fn terms(&self) -> &[String] {
+        &self.index.terms
+    }
+
+    pub fn column(&self, term: &str) -> Option<usize> {
+        self.index.index.get(term).copied()
+    }
+
+    pub fn idf(&self) -> &[f64] {
+        &self.idf
+    }
+
+    pub fn fit(&mut self, documents: &[Vec<Token>]) {
+        let documents = documents
+            .iter()
+            .map(|tokens| self.normalizer.terms(tokens))
+            .collect::<Vec<_>>();
+        self.index = TermIndex::fit(&documents, self.config.min_count);
+
+        let n = self.index.document_count as f64;
+        self.idf = self
+            .index
+            .document_frequency
+            .iter()
+            .map(|&df| {
+                let df = df as f64;
+                if self.config.smooth_idf {
+                    ((1.0 + n) / (1.0 + df)).ln() + 1.0
+                } else {
+                    (n / df).ln() + 1.0
+                }
+            })
+            .collect();
+    }
+
+    pub fn transform(&self, tokens: &[Token]) -> SparseVector {
+        let terms = self.normalizer.terms(tokens);
+        let entries = self.index.counts(&terms).into_iter().map(|(column, tf)| {
+            let tf = if self.config.sublinear_tf {
+                1.0 + (tf as f64).ln()
+            } else {
+                tf as f64
+            };
+            (column, tf * self.idf[column])
+        });
+        let vector = SparseVector::new(self.index.terms.len(), entries);
+        if self.config.normalize {
+            vector.normalized()
+        } else {
+            vector
+        }
+    }
+
+    pub fn fit_transform(&mut self, documents: &[Vec<Token>]) -> Vec<SparseVector> {
+        self.fit(documents);
+        documents
+            .iter()
+            .map(|tokens| self.transform(tokens))
+            .collect()
+    }
+
+    /// Tokenize every corpus document, fit, and return one vector per document.
+    pub fn fit_corpus<T: Tokenizer>(
+        &mut self,
+        corpus: &Corpus,
+        tokenizer: &T,
+    ) -> Result<Vec<SparseVector>, GDSFrameError> {
+        let documents = corpus.tokenize(tokenizer)?;
+        Ok(self.fit_transform(&documents))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// BM25
+// ---------------------------------------------------------------------------
+
+/// Okapi BM25 parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bm25Config {
+    /// Term-frequency saturation.
+    pub k1: f64,
+    /// Document-length normalization, from 0 (none) to 1 (full).
+    pub b: f64,
+    /// Minimum collection frequency for a term to enter the vocabulary.
+    pub min_count: usize,
+}
+
+impl Default for Bm25Config {
+    fn default() -> Self {
+        Self {
+            k1: 1.2,
+            b: 0.75,
+            min_count: 1,
+        }
+    }
+}
+
+/// Okapi BM25 document vectorizer.
+///
+/// A document vector holds, per term, its BM25 contribution
+/// `idf * tf * (k1 + 1) / (tf + k1 * (1 - b + b * |d| / avgdl))` with
+/// `idf = ln(1 + (n - df + 0.5) / (df + 0.5))`. The BM25 score of a query is
+/// the sum of those contributions over the query terms.
+#[derive(Debug, Clone)]
+pub struct Bm25Vectorizer {
+    config: Bm25Config,
+    normalizer: TermNormalizer,
+    index: TermIndex,
+    idf: Vec<f64>,
+}
+
+impl Default for Bm25Vectorizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Bm25Vectorizer {
+    pub fn new() -> Self {
+        Self {
+            config: Bm25Config::default(),
+            normalizer: TermNormalizer::default(),
+            index: TermIndex::empty(),
+            idf: Vec::new(),
+        }
+    }
+
+    pub fn with_config(mut self, config: Bm25Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn with_normalizer(mut self, normalizer: TermNormalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    pub fn config(&self) -> &Bm25Config {
+        &self.config
+    }
+
+    pub fn vocabulary(&self) -> &Vocabulary {
+        &self.index.vocabulary
+    }
+
+    /// Index terms in column order.
+    pub fn terms(&self) -> &[String] {
+        &self.index.terms
+    }
+
+    pub fn column(&self, term: &str) -> Option<usize> {
+        self.index.index.get(term).copied()
+    }
+
+    pub fn idf(&self) -> &[f64] {
+        &self.idf
+    }
+
+    /// Mean document length in index terms.
+    pub fn average_length(&self) -> f64 {
+        if self.index.document_count == 0 {
+            0.0
+        } else {
+            self.index.total_length as f64 / self.index.document_count as f64
+        }
+    }
+
+    pub fn fit(&mut self, documents: &[Vec<Token>]) {
+        let documents = documents
+            .iter()
+            .map(|tokens| self.normalizer.terms(tokens))
+            .collect::<Vec<_>>();
+        self.index = TermIndex::fit(&documents, self.config.min_count);
+
+        let n = self.index.document_count as f64;
+        self.idf = self
+            .index
+            .document_frequency
+            .iter()
+            .map(|&df| {
+                let df = df as f64;
+                (1.0 + (n - df + 0.5) / (df + 0.5)).ln()
+            })
+            .collect();
+    }
+
+    pub fn transform(&self, tokens: &[Token]) -> SparseVector {
+        let terms = self.normalizer.terms(tokens);
+        let Bm25Config { k1, b, .. } = self.config;
+        let average_length = self.average_length();
+        let length_norm = if average_length > 0.0 {
+            1.0 - b + b * terms.len() as f64 / average_length
+        } else {
+            1.0
+        };
+        let entries = self.index.counts(&terms).into_iter().map(|(column, tf)| {
+            let tf = tf as f64;
+            let weight = self.idf[column] * tf * (k1 + 1.0) / (tf + k1 * length_norm);
+            (column, weight)
+        });
+        SparseVector::new(self.index.terms.len(), entries)
+    }
+
+    pub fn fit_transform(&mut self, documents: &[Vec<Token>]) -> Vec<SparseVector> {
+        self.fit(documents);
+        documents
+            .iter()
+            .map(|tokens| self.transform(tokens))
+            .collect()
+    }
+
+    /// Tokenize every corpus document, fit, and return one vector per document.
+    pub fn fit_corpus<T: Tokenizer>(
+        &mut self,
+        corpus: &Corpus,
+        tokenizer: &T,
+    ) -> Result<Vec<SparseVector>, GDSFrameError> {
+        let documents = corpus.tokenize(tokenizer)?;
+        Ok(self.fit_transform(&documents))
+    }
+
+    /// BM25 score of `query` against a vector produced by [`Self::transform`].
+    pub fn score(&self, query: &[Token], document: &SparseVector) -> f64 {
+        self.normalizer
+            .terms(query)
+            .iter()
+            .filter_map(|term| self.column(term))
+            .map(|column| document.get(column))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::dataset::language::stemmer::SimpleSuffixStemmer;
+    use crate::collections::dataset::language::tokenizer::WhitespaceTokenizer;
+
+    fn docs(texts: &[&str]) -> Vec<Vec<Token>> {
+        texts
+            .iter()
+            .map(|text| WhitespaceTokenizer.tokenize(text))
+            .collect()
+    }
+
+    #[test]
+    fn sparse_vector_arithmetic() {
+        let a = SparseVector::new(4, [(2, 1.0), (0, 2.0), (2, 1.0), (3, 0.0)]);
+        let b = SparseVector::new(4, [(2, 3.0), (1, 5.0)]);
+
+        assert_eq!(a.indices(), &[0, 2]);
+        assert_eq!(a.get(2), 2.0);
+        assert_eq!(a.get(1), 0.0);
+        assert_eq!(a.dot(&b), 6.0);
+        assert_eq!(a.to_dense(), vec![2.0, 0.0, 2.0, 0.0]);
+        assert!((a.clone().normalized().norm() - 1.0).abs() < 1e-12);
+        assert!((a.cosine(&a) - 1.0).abs() < 1e-12);
+        assert_eq!(a.cosine(&SparseVector::new(4, [])), 0.0);
+    }
+
+    #[test]
+    fn tfidf_weights_rare_terms_higher() {
+        let documents = docs(&["the cat sat", "the dog sat", "the cat ran"]);
+        let mut vectorizer = TfidfVectorizer::new().with_config(TfidfConfig {
+            normalize: false,
+            ..TfidfConfig::default()
+        });
+        let vectors = vectorizer.fit_transform(&documents);
+
+        assert_eq!(vectorizer.terms(), &["cat", "dog", "ran", "sat", "the"]);
+        assert_eq!(vectorizer.vocabulary().count("the"), 3);
+        let the = vectorizer.column("the").unwrap();
+        let dog = vectorizer.column("dog").unwrap();
+        assert!((vectors[1].get(the) - 1.0).abs() < 1e-12);
+        assert!((vectors[1].get(dog) - ((4.0_f64 / 2.0).ln() + 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn normalizer_applies_stopwords_and_stemmer() {
+        let normalizer = TermNormalizer::new()
+            .with_stopwords(["the"])
+            .with_stemmer(SimpleSuffixStemmer::new(true));
+        let terms = normalizer.terms(&WhitespaceTokenizer.tokenize("The Graphs rank graphing"));
+        assert_eq!(terms, vec!["graph", "rank", "graph"]);
+
+        let mut vectorizer = TfidfVectorizer::new().with_normalizer(normalizer);
+        let vectors = vectorizer.fit_transform(&docs(&["the graphs", "graph ranking"]));
+        assert_eq!(vectorizer.terms(), &["graph", "rank"]);
+        assert!((vectors[0].norm() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bm25_ranks_matching_documents() {
+        let documents = docs(&[
+            "graph databases store graph data",
+            "cats sleep all day",
+            "graph algorithms",
+        ]);
+        let mut vectorizer = Bm25Vectorizer::new();
+        let vectors = vectorizer.fit_transform(&documents);
+        let query = WhitespaceTokenizer.tokenize("graph algorithms");
+
+        let scores = vectors
+            .iter()
+            .map(|vector| vectorizer.score(&query, vector))
+            .collect::<Vec<_>>();
+        assert!(scores[2] > scores[0]);
+        assert!(scores[0] > scores[1]);
+        assert_eq!(scores[1], 0.0);
+        assert!((vectorizer.average_length() - 11.0 / 3.0).abs() < 1e-12);
+    }
+}
@@ -123,6 +123,13 @@ impl Token {
     }
 }
 
+/// Token text, so token sequences feed string-based scorers directly.
+impl AsRef<str> for Token {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
 fn shift_offset(offset: usize, delta: isize) -> usize {
     if delta >= 0 {
         offset.saturating_add(delta as usize)
@@ -0,0 +1,278 @@
+//! Penn Treebank word tokenizer and detokenizer.
+
+use regex::Regex;
+
+use crate::collections::dataset::language::token::{Token, TokenKind};
+use crate::collections::dataset::language::tokenizer::{align_token_texts, Tokenizer};
+
+/// Penn Treebank-style word tokenizer, after NLTK's `TreebankWordTokenizer`.
+///
+/// Splits contractions (`don't` -> `do n't`), separates punctuation, turns
+/// double quotes into ``` `` ``` and `''`, and only splits off a period at the
+/// end of the text, so input should already be one sentence. Token spans point
+/// back into the original text; converted quotes map to the quote they replaced.
+#[derive(Debug, Clone)]
+pub struct TreebankWordTokenizer {
+    rules: Vec<(Regex, &'static str)>,
+    contractions: Vec<Regex>,
+    quotes: Regex,
+}
+
+impl TreebankWordTokenizer {
+    pub fn new() -> Self {
+        let rules = [
+            // Starting quotes.
+            (r#"^""#, "``"),
+            (r"(``)", " ${1} "),
+            (r#"([ (\[{<])("|'')"#, "${1} `` "),
+            // Punctuation.
+            (r"([:,])([^\d])", " ${1} ${2}"),
+            (r"([:,])$", " ${1} "),
+            (r"\.\.\.", " ... "),
+            (r"[;@#$%&]", " ${0} "),
+            (r#"([^.])(\.)([\])}>"']*)\s*$"#, "${1} ${2}${3} "),
+            (r"[?!]", " ${0} "),
+            (r"([^'])' ", "${1} ' "),
+            // Parentheses, brackets and double dashes.
+            (r"[\]\[(){}<>]", " ${0} "),
+            (r"--", " -- "),
+        ];
+        let ending_quotes = [
+            (r#"""#, " '' "),
+            (r"(\S)('')", "${1} ${2} "),
+            (r"([^' ])('[sS]|'[mM]|'[dD]|') ", "${1} ${2} "),
+            (r"([^' ])('ll|'LL|'re|'RE|'ve|'VE|n't|N'T) ", "${1} ${2} "),
+        ];
+
+        Self {
+            rules: rules
+                .into_iter()
+                .chain(ending_quotes)
+                .map(|(pattern, replacement)| {
+                    (
+                        Regex::new(pattern).expect("valid treebank pattern"),
+                        replacement,
+                    )
+                })
+                .collect(),
+            contractions: CONTRACTIONS
+                .iter()
+                .map(|pattern| Regex::new(pattern).expect("valid contraction pattern"))
+                .collect(),
+            quotes: Regex::new(r#"``|''|""#).expect("valid quote pattern"),
+        }
+    }
+
+    /// Tokenize into token texts, with double quotes converted.
+    pub fn tokenize_texts(&self, text: &str) -> Vec<String> {
+        let mut text = text.to_string();
+        // The ending-quote rules expect the text to be padded with spaces.
+        let (before_padding, after_padding) = self.rules.split_at(self.rules.len() - 4);
+        for (regex, replacement) in before_padding {
+            text = regex.replace_all(&text, *replacement).into_owned();
+        }
+        text = format!(" {text} ");
+        for (regex, replacement) in after_padding {
+            text = regex.replace_all(&text, *replacement).into_owned();
+        }
+        for regex in &self.contractions {
+            text = regex.replace_all(&text, " ${1} ${2} ").into_owned();
+        }
+        text.split_whitespace().map(str::to_string).collect()
+    }
+}
+
+impl Default for TreebankWordTokenizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tokenizer for TreebankWordTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        let mut texts = self.tokenize_texts(text);
+
+        // Align converted quotes with the quotes actually present in the text.
+        let mut originals = self.quotes.find_iter(text).map(|m| m.as_str());
+        let aligned = texts
+            .iter()
+            .map(|token| match token.as_str() {
+                "``" | "''" => originals.next().unwrap_or(token),
+                _ => token,
+            })
+            .collect::<Vec<_>>();
+        let spans = align_token_texts(&aligned, text)
+            .expect("treebank tokens are substrings of the input in order");
+
+        texts
+            .drain(..)
+            .zip(spans)
+            .map(|(token, span)| {
+                let kind = if token.chars().any(char::is_alphanumeric) {
+                    TokenKind::Word
+                } else {
+                    TokenKind::Punct
+                };
+                Token::new(token, span, kind)
+            })
+            .collect()
+    }
+}
+
+/// Inverse of [`TreebankWordTokenizer`], after NLTK's `TreebankWordDetokenizer`.
+#[derive(Debug, Clone)]
+pub struct TreebankWordDetokenizer {
+    contractions: Vec<Regex>,
+    ending_quotes: Vec<(Regex, &'static str)>,
+    rules: Vec<(Regex, &'static str)>,
+}
+
+impl TreebankWordDetokenizer {
+    pub fn new() -> Self {
+        let compile = |rules: &[(&str, &'static str)]| {
+            rules
+                .iter()
+                .map(|(pattern, replacement)| {
+                    (
+                        Regex::new(pattern).expect("valid detokenizer pattern"),
+                        *replacement,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        Self {
+            contractions: CONTRACTIONS
+                .iter()
+                .map(|pattern| {
+                    Regex::new(&pattern.replacen(")(", r")\s(", 1))
+                        .expect("valid contraction pattern")
+                })
+                .collect(),
+            ending_quotes: compile(&[
+                (r"([^' ])\s('ll|'LL|'re|'RE|'ve|'VE|n't|N'T) ", "${1}${2} "),
+                (r"([^' ])\s('[sS]|'[mM]|'[dD]|') ", "${1}${2} "),
+                (r"(\S)\s('')", "${1}${2}"),
+                (r"('')\s([.,:)\]>};%])", "${1}${2}"),
+                (r"''", "\""),
+            ]),
+            rules: compile(&[
+                // Parentheses and brackets.
+                (r"([\[({<])\s", "${1}"),
+                (r"\s([\])}>])", "${1}"),
+                (r"([\])}>])\s([:;,.])", "${1}${2}"),
+                // Punctuation.
+                (r"([^'])\s'\s", "${1}' "),
+                (r"\s([?!])", "${1}"),
+                (r#"([^.])\s(\.)([\])}>"']*)\s*$"#, "${1}${2}${3}"),
+                (r"([#$])\s", "${1}"),
+                (r"\s([;%])", "${1}"),
+                (r"\s\.\.\.\s", "..."),
+                (r"\s([:,])", "${1}"),
+                // Starting quotes.
+                (r"([ (\[{<])\s``", "${1}``"),
+                (r"(``)\s", "${1}"),
+                (r"``", "\""),
+            ]),
+        }
+    }
+
+    pub fn detokenize<S: AsRef<str>>(&self, tokens: &[S]) -> String {
+        let joined = tokens
+            .iter()
+            .map(AsRef::as_ref)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut text = format!(" {joined} ");
+        for regex in &self.contractions {
+            text = regex.replace_all(&text, "${1}${2}").into_owned();
+        }
+        for (regex, replacement) in &self.ending_quotes {
+            text = regex.replace_all(&text, *replacement).into_owned();
+        }
+        text = text.trim().to_string();
+        for (regex, replacement) in &self.rules {
+            text = regex.replace_all(&text, *replacement).into_owned();
+        }
+        text.trim().to_string()
+    }
+}
+
+impl Default for TreebankWordDetokenizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Tokenize with the default [`TreebankWordTokenizer`].
+pub fn treebank_word_tokenize(text: &str) -> Vec<Token> {
+    TreebankWordTokenizer::new().tokenize(text)
+}
+
+/// Multi-word forms split into two tokens (`cannot` -> `can not`).
+const CONTRACTIONS: [&str; 10] = [
+    r"(?i)\b(can)(not)\b",
+    r"(?i)\b(d)('ye)\b",
+    r"(?i)\b(gim)(me)\b",
+    r"(?i)\b(gon)(na)\b",
+    r"(?i)\b(got)(ta)\b",
+    r"(?i)\b(lem)(me)\b",
+    r"(?i)\b(more)('n)\b",
+    r"(?i)\b(wan)(na)\s",
+    r"(?i) ('t)(is)\b",
+    r"(?i) ('t)(was)\b",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::dataset::language::token::TokenSpan;
+
+    fn texts(tokens: &[Token]) -> Vec<&str> {
+        tokens.iter().map(Token::text).collect()
+    }
+
+    #[test]
+    fn treebank_splits_contractions_and_punctuation() {
+        let tokens = treebank_word_tokenize("They'll save and invest more, can't they? I cannot.");
+        assert_eq!(
+            texts(&tokens),
+            vec![
+                "They", "'ll", "save", "and", "invest", "more", ",", "ca", "n't", "they", "?", "I",
+                "can", "not", "."
+            ]
+        );
+        assert_eq!(tokens[1].span(), TokenSpan::new(4, 7));
+        assert_eq!(tokens[6].kind(), &TokenKind::Punct);
+    }
+
+    #[test]
+    fn treebank_converts_quotes_and_keeps_spans() {
+        let text = r#"She said "hello" (twice)."#;
+        let tokens = treebank_word_tokenize(text);
+        assert_eq!(
+            texts(&tokens),
+            vec!["She", "said", "``", "hello", "''", "(", "twice", ")", "."]
+        );
+        assert_eq!(
+            &text[tokens[2].span().start()..tokens[2].span().end()],
+            "\""
+        );
+        assert_eq!(tokens[4].span(), TokenSpan::new(15, 16));
+        assert_eq!(tokens[8].span(), TokenSpan::new(24, 25));
+    }
+
+    #[test]
+    fn detokenizer_inverts_tokenizer() {
+        let tokenizer = TreebankWordTokenizer::new();
+        let detokenizer = TreebankWordDetokenizer::new();
+        for text in [
+            "They'll save and invest more, can't they?",
+            r#"She said "hello" (twice)."#,
+            "I cannot pay $5 -- or 10% of it; gonna wait.",
+        ] {
+            let tokens = tokenizer.tokenize_texts(text);
+            assert_eq!(detokenizer.detokenize(&tokens), text);
+        }
+    }
+}
@@ -83,8 +83,24 @@ impl CatalogExtension {
         name: &str,
         format: Option<CollectionsIoFormat>,
     ) -> Result<CollectionsCatalogDiskEntry, CatalogError> {
+        let entry = self.table_entry(name, format);
+
+        if self.config.allow_overwrite {
+            let _ = self.catalog.remove(name);
+        }
+
+        self.catalog.register(entry.clone())?;
+        Ok(entry)
+    }
+
+    /// Build the entry [`CatalogExtension::register_table`] would register, without registering it.
+    pub fn table_entry(
+        &self,
+        name: &str,
+        format: Option<CollectionsIoFormat>,
+    ) -> CollectionsCatalogDiskEntry {
         let format = format.unwrap_or(self.config.default_format);
-        let entry = CollectionsCatalogDiskEntry {
+        CollectionsCatalogDiskEntry {
             name: name.to_string(),
             value_type: ValueType::Unknown,
             schema: None,
@@ -96,14 +112,7 @@ impl CatalogExtension {
                 ..Default::default()
             },
             data_path: self.catalog.default_data_path(name, format),
-        };
-
-        if self.config.allow_overwrite {
-            let _ = self.catalog.remove(name);
         }
-
-        self.catalog.register(entry.clone())?;
-        Ok(entry)
     }
 
     pub fn write_table(
@@ -0,0 +1,790 @@
+//! Compressed pages for primitive and array collections.
+//!
+//! Columns are cut into fixed-size pages and every page is encoded on its own, so a lookup
+//! decodes one value from one page instead of inflating the whole column:
+//!
+//! - **Bit-packing** stores zigzag-encoded values in the fewest bits that hold the largest one.
+//! - **Frame-of-reference** subtracts the page minimum first, which suits clustered values.
+//! - **Delta** packs the differences between neighbours, with a checkpoint every
+//!   [`DELTA_CHECKPOINT_INTERVAL`] values to bound the cost of a lookup.
+//! - **Dictionary** packs indices into the page's sorted distinct values, which suits
+//!   low-cardinality columns such as community ids.
+//!
+//! [`CompressionAlgorithm::Auto`] keeps the smallest encoding per page. Floating point values
+//! are encoded through their bit patterns, so every encoding is lossless.
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+use crate::collections::extensions::compression::{CompressionAlgorithm, CompressionError};
+
+/// Values per page unless configured otherwise.
+pub const DEFAULT_PAGE_SIZE: usize = 4096;
+
+/// Distance between absolute values stored in delta pages.
+pub const DELTA_CHECKPOINT_INTERVAL: usize = 1 << DELTA_CHECKPOINT_SHIFT;
+const DELTA_CHECKPOINT_SHIFT: u32 = 7;
+
+/// Fixed-width unsigned integers packed back to back into 64-bit words.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BitPackedVec {
+    words: Vec<u64>,
+    bits: u32,
+    len: usize,
+}
+
+impl BitPackedVec {
+    /// Number of bits needed to represent `max`.
+    pub fn bits_required(max: u64) -> u32 {
+        u64::BITS - max.leading_zeros()
+    }
+
+    /// Pack `values` using the width of the largest one.
+    pub fn pack(values: &[u64]) -> Self {
+        let max = values.iter().copied().max().unwrap_or(0);
+        Self::pack_iter(
+            values.iter().copied(),
+            values.len(),
+            Self::bits_required(max),
+        )
+    }
+
+    fn pack_iter(values: impl Iterator<Item = u64>, len: usize, bits: u32) -> Self {
+        let mut words = vec![0u64; (len * bits as usize).div_ceil(64)];
+        if bits > 0 {
+            for (index, value) in values.enumerate() {
+                debug_assert!(Self::bits_required(value) <= bits);
+                let bit = index * bits as usize;
+                let (word, shift) = (bit / 64, (bit % 64) as u32);
+                words[word] |= value << shift;
+                if shift + bits > 64 {
+                    words[word + 1] |= value >> (64 - shift);
+                }
+            }
+        }
+        Self { words, bits, len }
+    }
+
+    pub fn get(&self, index: usize) -> Option<u64> {
+        (index < self.len).then(|| self.get_unchecked(index))
+    }
+
+    fn get_unchecked(&self, index: usize) -> u64 {
+        if self.bits == 0 {
+            return 0;
+        }
+        let bit = index * self.bits as usize;
+        let (word, shift) = (bit / 64, (bit % 64) as u32);
+        let mut value = self.words[word] >> shift;
+        if shift + self.bits > 64 {
+            value |= self.words[word + 1] << (64 - shift);
+        }
+        value & (u64::MAX >> (64 - self.bits))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u64> + '_ {
+        (0..self.len).map(|index| self.get_unchecked(index))
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Bits used per value.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    pub fn size_in_bytes(&self) -> usize {
+        self.words.len() * size_of::<u64>()
+    }
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Encoding chosen for a single page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PageEncoding {
+    /// Every value in the page is equal.
+    Constant,
+    /// Plain 64-bit values.
+    Raw,
+    BitPacked,
+    FrameOfReference,
+    Delta,
+    Dictionary,
+}
+
+impl PageEncoding {
+    fn candidates(algorithm: &CompressionAlgorithm) -> Result<&'static [Self], CompressionError> {
+        Ok(match algorithm {
+            CompressionAlgorithm::Auto => &[
+                Self::Constant,
+                Self::BitPacked,
+                Self::FrameOfReference,
+                Self::Dictionary,
+                Self::Delta,
+                Self::Raw,
+            ],
+            CompressionAlgorithm::BitPacking => &[Self::BitPacked],
+            CompressionAlgorithm::FrameOfReference => &[Self::FrameOfReference],
+            CompressionAlgorithm::Delta => &[Self::Delta],
+            CompressionAlgorithm::Dictionary => &[Self::Dictionary],
+            other => {
+                return Err(CompressionError::UnsupportedAlgorithm(format!(
+                    "{other:?} is a byte codec; collections compress with BitPacking, \
+                     FrameOfReference, Delta, Dictionary or Auto"
+                )))
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PageData {
+    Constant(i64),
+    Raw(Vec<i64>),
+    BitPacked(BitPackedVec),
+    FrameOfReference {
+        base: i64,
+        offsets: BitPackedVec,
+    },
+    Delta {
+        checkpoints: Vec<i64>,
+        base: i64,
+        deltas: BitPackedVec,
+    },
+    Dictionary {
+        values: Vec<i64>,
+        codes: BitPackedVec,
+    },
+}
+
+/// One encoded page of 64-bit values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedPage {
+    data: PageData,
+    len: usize,
+}
+
+impl CompressedPage {
+    /// Encode `values` with `encoding`; returns `None` for `Constant` on a varying page.
+    pub fn encode(values: &[i64], encoding: PageEncoding) -> Option<Self> {
+        let len = values.len();
+        let min = values.iter().copied().min().unwrap_or(0);
+        let data = match encoding {
+            PageEncoding::Constant => {
+                if values.iter().any(|&value| value != min) {
+                    return None;
+                }
+                PageData::Constant(min)
+            }
+            PageEncoding::Raw => PageData::Raw(values.to_vec()),
+            PageEncoding::BitPacked => PageData::BitPacked(BitPackedVec::pack(
+                &values
+                    .iter()
+                    .map(|&value| zigzag(value))
+                    .collect::<Vec<_>>(),
+            )),
+            PageEncoding::FrameOfReference => PageData::FrameOfReference {
+                base: min,
+                offsets: BitPackedVec::pack(
+                    &values
+                        .iter()
+                        .map(|&value| value.wrapping_sub(min) as u64)
+                        .collect::<Vec<_>>(),
+                ),
+            },
+            PageEncoding::Delta => {
+                let deltas: Vec<i64> = values
+                    .windows(2)
+                    .map(|pair| pair[1].wrapping_sub(pair[0]))
+                    .collect();
+                let base = deltas.iter().copied().min().unwrap_or(0);
+                PageData::Delta {
+                    checkpoints: values
+                        .iter()
+                        .step_by(DELTA_CHECKPOINT_INTERVAL)
+                        .copied()
+                        .collect(),
+                    base,
+                    deltas: BitPackedVec::pack(
+                        &deltas
+                            .iter()
+                            .map(|&delta| delta.wrapping_sub(base) as u64)
+                            .collect::<Vec<_>>(),
+                    ),
+                }
+            }
+            PageEncoding::Dictionary => {
+                let mut dictionary = values.to_vec();
+                dictionary.sort_unstable();
+                dictionary.dedup();
+                let codes: Vec<u64> = values
+                    .iter()
+                    .map(|value| dictionary.binary_search(value).unwrap_or_default() as u64)
+                    .collect();
+                PageData::Dictionary {
+                    values: dictionary,
+                    codes: BitPackedVec::pack(&codes),
+                }
+            }
+        };
+        Some(Self { data, len })
+    }
+
+    /// Encode `values` with each of `encodings` and keep the smallest result.
+    pub fn encode_smallest(values: &[i64], encodings: &[PageEncoding]) -> Self {
+        encodings
+            .iter()
+            .filter_map(|&encoding| Self::encode(values, encoding))
+            .min_by_key(Self::size_in_bytes)
+            .unwrap_or_else(|| Self {
+                data: PageData::Raw(values.to_vec()),
+                len: values.len(),
+            })
+    }
+
+    pub fn encoding(&self) -> PageEncoding {
+        match self.data {
+            PageData::Constant(_) => PageEncoding::Constant,
+            PageData::Raw(_) => PageEncoding::Raw,
+            PageData::BitPacked(_) => PageEncoding::BitPacked,
+            PageData::FrameOfReference { .. } => PageEncoding::FrameOfReference,
+            PageData::Delta { .. } => PageEncoding::Delta,
+            PageData::Dictionary { .. } => PageEncoding::Dictionary,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<i64> {
+        (index < self.len).then(|| self.get_unchecked(index))
+    }
+
+    fn get_unchecked(&self, index: usize) -> i64 {
+        match &self.data {
+            PageData::Constant(value) => *value,
+            PageData::Raw(values) => values[index],
+            PageData::BitPacked(packed) => unzigzag(packed.get_unchecked(index)),
+            PageData::FrameOfReference { base, offsets } => {
+                base.wrapping_add(offsets.get_unchecked(index) as i64)
+            }
+            PageData::Delta {
+                checkpoints,
+                base,
+                deltas,
+            } => {
+                let checkpoint = index >> DELTA_CHECKPOINT_SHIFT;
+                ((checkpoint << DELTA_CHECKPOINT_SHIFT)..index).fold(
+                    checkpoints[checkpoint],
+                    |value, delta| {
+                        value.wrapping_add(base.wrapping_add(deltas.get_unchecked(delta) as i64))
+                    },
+                )
+            }
+            PageData::Dictionary { values, codes } => values[codes.get_unchecked(index) as usize],
+        }
+    }
+
+    /// Decode the whole page.
+    pub fn decode(&self) -> Vec<i64> {
+        match &self.data {
+            PageData::Delta {
+                checkpoints,
+                base,
+                deltas,
+            } => {
+                let mut values = Vec::with_capacity(self.len);
+                if let Some(&first) = checkpoints.first() {
+                    values.push(first);
+                    let mut current = first;
+                    for delta in deltas.iter() {
+                        current = current.wrapping_add(base.wrapping_add(delta as i64));
+                        values.push(current);
+                    }
+                }
+                values
+            }
+            _ => (0..self.len)
+                .map(|index| self.get_unchecked(index))
+                .collect(),
+        }
+    }
+
+    pub fn size_in_bytes(&self) -> usize {
+        let payload = match &self.data {
+            PageData::Constant(_) => 0,
+            PageData::Raw(values) => values.len() * size_of::<i64>(),
+            PageData::BitPacked(packed) => packed.size_in_bytes(),
+            PageData::FrameOfReference { offsets, .. } => offsets.size_in_bytes(),
+            PageData::Delta {
+                checkpoints,
+                deltas,
+                ..
+            } => checkpoints.len() * size_of::<i64>() + deltas.size_in_bytes(),
+            PageData::Dictionary { values, codes } => {
+                values.len() * size_of::<i64>() + codes.size_in_bytes()
+            }
+        };
+        size_of::<Self>() + payload
+    }
+}
+
+/// Element types stored in compressed pages as 64-bit values.
+pub trait PackedValue: Copy + Send + Sync + 'static {
+    fn to_packed(self) -> i64;
+
+    fn from_packed(packed: i64) -> Self;
+}
+
+macro_rules! integer_packed_value {
+    ($($element:ty),*) => {
+        $(
+            impl PackedValue for $element {
+                fn to_packed(self) -> i64 {
+                    self as i64
+                }
+
+                fn from_packed(packed: i64) -> Self {
+                    packed as $element
+                }
+            }
+        )*
+    };
+}
+
+integer_packed_value!(i8, i16, i32, i64, u8);
+
+impl PackedValue for f64 {
+    fn to_packed(self) -> i64 {
+        self.to_bits() as i64
+    }
+
+    fn from_packed(packed: i64) -> Self {
+        f64::from_bits(packed as u64)
+    }
+}
+
+impl PackedValue for f32 {
+    fn to_packed(self) -> i64 {
+        self.to_bits() as i64
+    }
+
+    fn from_packed(packed: i64) -> Self {
+        f32::from_bits(packed as u32)
+    }
+}
+
+impl PackedValue for bool {
+    fn to_packed(self) -> i64 {
+        self as i64
+    }
+
+    fn from_packed(packed: i64) -> Self {
+        packed != 0
+    }
+}
+
+impl PackedValue for char {
+    fn to_packed(self) -> i64 {
+        self as i64
+    }
+
+    fn from_packed(packed: i64) -> Self {
+        char::from_u32(packed as u32).unwrap_or('\0')
+    }
+}
+
+/// A column of primitive values stored as independently encoded pages.
+#[derive(Debug, Clone)]
+pub struct CompressedColumn<T> {
+    pages: Vec<CompressedPage>,
+    page_shift: u32,
+    len: usize,
+    _element: PhantomData<fn() -> T>,
+}
+
+impl<T: PackedValue> CompressedColumn<T> {
+    pub fn compress(
+        values: &[T],
+        algorithm: &CompressionAlgorithm,
+    ) -> Result<Self, CompressionError> {
+        Self::compress_with_page_size(values, algorithm, DEFAULT_PAGE_SIZE)
+    }
+
+    /// Compress `values` into pages of `page_size` values, which must be a power of two.
+    pub fn compress_with_page_size(
+        values: &[T],
+        algorithm: &CompressionAlgorithm,
+        page_size: usize,
+    ) -> Result<Self, CompressionError> {
+        if !page_size.is_power_of_two() {
+            return Err(CompressionError::CompressionFailed(format!(
+                "page size {page_size} is not a power of two"
+            )));
+        }
+        let encodings = PageEncoding::candidates(algorithm)?;
+        let mut packed = Vec::with_capacity(page_size.min(values.len()));
+        let pages = values
+            .chunks(page_size)
+            .map(|chunk| {
+                packed.clear();
+                packed.extend(chunk.iter().map(|value| value.to_packed()));
+                CompressedPage::encode_smallest(&packed, encodings)
+            })
+            .collect();
+        Ok(Self {
+            pages,
+            page_shift: page_size.trailing_zeros(),
+            len: values.len(),
+            _element: PhantomData,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn page_size(&self) -> usize {
+        1 << self.page_shift
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Encoding of each page, in order.
+    pub fn page_encodings(&self) -> impl Iterator<Item = PageEncoding> + '_ {
+        self.pages.iter().map(CompressedPage::encoding)
+    }
+
+    /// Random access into the page holding `index`.
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let page = &self.pages[index >> self.page_shift];
+        Some(T::from_packed(
+            page.get_unchecked(index & (self.page_size() - 1)),
+        ))
+    }
+
+    /// Decode page by page, in order.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.pages
+            .iter()
+            .flat_map(|page| page.decode().into_iter().map(T::from_packed))
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.len);
+        values.extend(self.iter());
+        values
+    }
+
+    pub fn size_in_bytes(&self) -> usize {
+        size_of::<Self>()
+            + self
+                .pages
+                .iter()
+                .map(CompressedPage::size_in_bytes)
+                .sum::<usize>()
+    }
+
+    pub fn uncompressed_size_in_bytes(&self) -> usize {
+        self.len * size_of::<T>()
+    }
+
+    /// Compressed size over uncompressed size.
+    pub fn compression_ratio(&self) -> f64 {
+        ratio(self.size_in_bytes(), self.uncompressed_size_in_bytes())
+    }
+}
+
+fn ratio(compressed: usize, original: usize) -> f64 {
+    if original == 0 {
+        1.0
+    } else {
+        compressed as f64 / original as f64
+    }
+}
+
+/// A column of optional arrays: a presence bitmap, compressed offsets and compressed elements.
+#[derive(Debug, Clone)]
+pub struct CompressedArrayColumn<T> {
+    present: Vec<u64>,
+    offsets: CompressedColumn<i64>,
+    values: CompressedColumn<T>,
+}
+
+impl<T: PackedValue> CompressedArrayColumn<T> {
+    pub fn compress(
+        arrays: &[Option<Vec<T>>],
+        algorithm: &CompressionAlgorithm,
+    ) -> Result<Self, CompressionError> {
+        Self::compress_with_page_size(arrays, algorithm, DEFAULT_PAGE_SIZE)
+    }
+
+    /// Offsets always use [`CompressionAlgorithm::Auto`]; `algorithm` applies to the elements.
+    pub fn compress_with_page_size(
+        arrays: &[Option<Vec<T>>],
+        algorithm: &CompressionAlgorithm,
+        page_size: usize,
+    ) -> Result<Self, CompressionError> {
+        let mut present = vec![0u64; arrays.len().div_ceil(64)];
+        let mut offsets = Vec::with_capacity(arrays.len() + 1);
+        let mut values = Vec::new();
+        offsets.push(0i64);
+        for (index, array) in arrays.iter().enumerate() {
+            if let Some(array) = array {
+                present[index / 64] |= 1 << (index % 64);
+                values.extend_from_slice(array);
+            }
+            offsets.push(values.len() as i64);
+        }
+        Ok(Self {
+            present,
+            offsets: CompressedColumn::compress_with_page_size(
+                &offsets,
+                &CompressionAlgorithm::Auto,
+                page_size,
+            )?,
+            values: CompressedColumn::compress_with_page_size(&values, algorithm, page_size)?,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_present(&self, index: usize) -> bool {
+        index < self.len() && self.present[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// The array at `index`, or `None` when it is missing or out of bounds.
+    pub fn array(&self, index: usize) -> Option<Vec<T>> {
+        if !self.is_present(index) {
+            return None;
+        }
+        let start = self.offsets.get(index)? as usize;
+        let end = self.offsets.get(index + 1)? as usize;
+        (start..end).map(|offset| self.values.get(offset)).collect()
+    }
+
+    pub fn to_vec(&self) -> Vec<Option<Vec<T>>> {
+        (0..self.len()).map(|index| self.array(index)).collect()
+    }
+
+    pub fn size_in_bytes(&self) -> usize {
+        self.present.len() * size_of::<u64>()
+            + self.offsets.size_in_bytes()
+            + self.values.size_in_bytes()
+    }
+
+    pub fn uncompressed_size_in_bytes(&self) -> usize {
+        self.len() * size_of::<Option<Vec<T>>>() + self.values.uncompressed_size_in_bytes()
+    }
+
+    /// Compressed size over uncompressed size.
+    pub fn compression_ratio(&self) -> f64 {
+        ratio(self.size_in_bytes(), self.uncompressed_size_in_bytes())
+    }
+}
+
+/// Collection element types that can be stored in compressed pages.
+pub trait Compressible: Clone + Send + Sync + Sized {
+    type Column: Clone + Debug + Send + Sync;
+
+    fn compress_column(
+        values: &[Self],
+        algorithm: &CompressionAlgorithm,
+        page_size: usize,
+    ) -> Result<Self::Column, CompressionError>;
+
+    fn column_get(column: &Self::Column, index: usize) -> Option<Self>;
+
+    fn column_size_in_bytes(column: &Self::Column) -> usize;
+
+    /// Heap and inline bytes `values` occupy uncompressed.
+    fn size_in_bytes(values: &[Self]) -> usize;
+}
+
+macro_rules! scalar_compressible {
+    ($($element:ty),*) => {
+        $(
+            impl Compressible for $element {
+                type Column = CompressedColumn<$element>;
+
+                fn compress_column(
+                    values: &[Self],
+                    algorithm: &CompressionAlgorithm,
+                    page_size: usize,
+                ) -> Result<Self::Column, CompressionError> {
+                    CompressedColumn::compress_with_page_size(values, algorithm, page_size)
+                }
+
+                fn column_get(column: &Self::Column, index: usize) -> Option<Self> {
+                    column.get(index)
+                }
+
+                fn column_size_in_bytes(column: &Self::Column) -> usize {
+                    column.size_in_bytes()
+                }
+
+                fn size_in_bytes(values: &[Self]) -> usize {
+                    std::mem::size_of_val(values)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! array_compressible {
+    ($($element:ty),*) => {
+        $(
+            impl Compressible for Option<Vec<$element>> {
+                type Column = CompressedArrayColumn<$element>;
+
+                fn compress_column(
+                    values: &[Self],
+                    algorithm: &CompressionAlgorithm,
+                    page_size: usize,
+                ) -> Result<Self::Column, CompressionError> {
+                    CompressedArrayColumn::compress_with_page_size(values, algorithm, page_size)
+                }
+
+                fn column_get(column: &Self::Column, index: usize) -> Option<Self> {
+                    (index < column.len()).then(|| column.array(index))
+                }
+
+                fn column_size_in_bytes(column: &Self::Column) -> usize {
+                    column.size_in_bytes()
+                }
+
+                fn size_in_bytes(values: &[Self]) -> usize {
+                    values
+                        .iter()
+                        .map(|array| {
+                            size_of::<Self>()
+                                + array.as_ref().map_or(0, |array| std::mem::size_of_val(array.as_slice()))
+                        })
+                        .sum()
+                }
+            }
+        )*
+    };
+}
+
+scalar_compressible!(i8, i16, i32, i64, u8, f32, f64, bool, char);
+array_compressible!(i64, f64, f32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_packing_handles_word_boundaries() {
+        let values: Vec<u64> = (0..100).map(|i| (i * 37) % 128).collect();
+        let packed = BitPackedVec::pack(&values);
+        assert_eq!(packed.bits(), 7);
+        assert_eq!(packed.iter().collect::<Vec<_>>(), values);
+        assert_eq!(packed.get(99), Some(values[99]));
+        assert_eq!(packed.get(100), None);
+
+        let wide = BitPackedVec::pack(&[u64::MAX, 0, 1 << 63]);
+        assert_eq!(wide.iter().collect::<Vec<_>>(), vec![u64::MAX, 0, 1 << 63]);
+    }
+
+    #[test]
+    fn every_encoding_round_trips_with_random_access() {
+        let values: Vec<i64> = (0..1000)
+            .map(|i: i64| 1_000_000 + (i * 7919) % 301 - 150)
+            .chain([i64::MIN, i64::MAX, -1, 0])
+            .collect();
+        for algorithm in [
+            CompressionAlgorithm::BitPacking,
+            CompressionAlgorithm::FrameOfReference,
+            CompressionAlgorithm::Delta,
+            CompressionAlgorithm::Dictionary,
+            CompressionAlgorithm::Auto,
+        ] {
+            let column =
+                CompressedColumn::compress_with_page_size(&values, &algorithm, 256).unwrap();
+            assert_eq!(column.page_count(), 4);
+            assert_eq!(column.to_vec(), values, "{algorithm:?}");
+            for index in [0, 127, 128, 255, 256, 700, 1003] {
+                assert_eq!(column.get(index), Some(values[index]), "{algorithm:?}");
+            }
+            assert_eq!(column.get(values.len()), None);
+        }
+        assert!(matches!(
+            CompressedColumn::compress(&values, &CompressionAlgorithm::Zstd),
+            Err(CompressionError::UnsupportedAlgorithm(_))
+        ));
+    }
+
+    #[test]
+    fn auto_picks_a_compact_encoding_per_page() {
+        let communities: Vec<i64> = (0..10_000)
+            .map(|node| [17, 4_200, 98_765][node % 3])
+            .collect();
+        let column = CompressedColumn::compress(&communities, &CompressionAlgorithm::Auto).unwrap();
+        assert!(column
+            .page_encodings()
+            .all(|encoding| encoding == PageEncoding::Dictionary));
+        assert!(column.compression_ratio() < 0.05);
+
+        let sorted: Vec<i64> = (0..10_000).map(|i| i * 1_000_003).collect();
+        let column = CompressedColumn::compress(&sorted, &CompressionAlgorithm::Auto).unwrap();
+        assert!(column
+            .page_encodings()
+            .all(|encoding| encoding == PageEncoding::Delta));
+
+        let weights = vec![0.25f64; 5000];
+        let column = CompressedColumn::compress(&weights, &CompressionAlgorithm::Auto).unwrap();
+        assert_eq!(column.get(4999), Some(0.25));
+        assert!(column.compression_ratio() < 0.01);
+    }
+
+    #[test]
+    fn array_columns_keep_missing_arrays() {
+        let arrays = vec![
+            Some(vec![1.5f64, 2.5]),
+            None,
+            Some(vec![]),
+            Some(vec![-3.0]),
+        ];
+        let column =
+            CompressedArrayColumn::compress(&arrays, &CompressionAlgorithm::Dictionary).unwrap();
+        assert_eq!(column.len(), 4);
+        assert_eq!(column.to_vec(), arrays);
+        assert_eq!(column.array(1), None);
+        assert!(!column.is_present(4));
+    }
+}
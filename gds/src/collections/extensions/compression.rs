@@ -1,12 +1,17 @@
 //! Collections Compression Extensions
 //!
 //! Provides compression capabilities as Collections Extensions for the Collections First approach.
-//! This enables compression for any Collections implementation.
+//! This enables compression for any Collections implementation: [`CompressedCollection`] moves
+//! the values of a Vec, Huge or Arrow backend into [`compressed_pages`](super::compressed_pages)
+//! and serves reads straight from the pages.
 
+use crate::collections::extensions::compressed_pages::Compressible;
 use crate::collections::Collections;
 use crate::config::{CollectionsBackend, Extension};
 use crate::types::ValueType;
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::sync::{Mutex, OnceLock};
 
 /// Compression extension trait for Collections
 pub trait CompressionSupport<T> {
@@ -41,10 +46,20 @@ pub trait CompressionSupport<T> {
 /// Compression algorithms
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum CompressionAlgorithm {
+    /// Smallest page encoding, chosen per page
+    #[default]
+    Auto,
+    /// Zigzag bit-packing (small integers of either sign)
+    BitPacking,
+    /// Bit-packed offsets from the page minimum (clustered values)
+    FrameOfReference,
+    /// Bit-packed differences between neighbours (sorted values)
+    Delta,
+    /// Bit-packed codes into distinct values (low cardinality)
+    Dictionary,
     /// LZ4 compression (fast, moderate compression)
     Lz4,
     /// Zstd compression (good balance of speed and compression)
-    #[default]
     Zstd,
     /// Gzip compression (good compression, slower)
     Gzip,
@@ -69,7 +84,7 @@ pub struct CompressionConfig {
 impl Default for CompressionConfig {
     fn default() -> Self {
         Self {
-            algorithm: CompressionAlgorithm::Zstd,
+            algorithm: CompressionAlgorithm::Auto,
             compression_level: 3,
             auto_compress_threshold: 0.7, // Compress if >70% memory savings
             enable_parallel_compression: true,
@@ -78,6 +93,15 @@ impl Default for CompressionConfig {
     }
 }
 
+impl CompressionConfig {
+    /// Values per compressed page: `chunk_size` bytes of 64-bit values, rounded to a power of two.
+    pub fn page_size(&self) -> usize {
+        (self.chunk_size / std::mem::size_of::<u64>())
+            .max(1)
+            .next_power_of_two()
+    }
+}
+
 /// Compression result
 #[derive(Debug, Clone)]
 pub struct CompressionResult {
@@ -107,227 +131,386 @@ pub struct CompressionStats {
 }
 
 /// Compression-aware collection wrapper
+///
+/// Compressing moves the values into pages and releases the inner collection; reads decode a
+/// single value from its page. Writes decompress first; with auto-compression enabled the
+/// collection is compressed again after a page worth of writes or a sort. Statistics on a
+/// compressed collection decode the pages once and are cached until the next write.
 pub struct CompressedCollection<T, C>
 where
     C: Collections<T>,
+    T: Compressible,
 {
     inner: C,
     compression_config: CompressionConfig,
-    is_compressed: bool,
+    compressed: Option<CompressedState<T>>,
     compression_stats: Option<CompressionStats>,
     auto_compression_enabled: bool,
-    compressed_data: Option<Vec<u8>>,
+    writes_since_decompression: usize,
     _phantom: PhantomData<T>,
 }
 
+/// Pages plus what the released inner collection knew about its values.
+struct CompressedState<T: Compressible> {
+    column: T::Column,
+    len: usize,
+    default_value: T,
+    nulls: Vec<usize>,
+    original_size: usize,
+    algorithm: CompressionAlgorithm,
+    stats: StatsCache<T>,
+}
+
+/// Statistics of one compressed state, each decoded on first use.
+struct StatsCache<T> {
+    sum: OnceLock<Option<T>>,
+    min: OnceLock<Option<T>>,
+    max: OnceLock<Option<T>>,
+    mean: OnceLock<Option<f64>>,
+    std_dev: OnceLock<Option<f64>>,
+    variance: OnceLock<Option<f64>>,
+    median: OnceLock<Option<T>>,
+    /// Keyed by the bits of the requested percentile.
+    percentiles: Mutex<HashMap<u64, Option<T>>>,
+}
+
+impl<T> Default for StatsCache<T> {
+    fn default() -> Self {
+        Self {
+            sum: OnceLock::new(),
+            min: OnceLock::new(),
+            max: OnceLock::new(),
+            mean: OnceLock::new(),
+            std_dev: OnceLock::new(),
+            variance: OnceLock::new(),
+            median: OnceLock::new(),
+            percentiles: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
 impl<T, C> CompressedCollection<T, C>
 where
     C: Collections<T>,
-    T: Clone + Send + Sync + serde::Serialize + serde::de::DeserializeOwned,
+    T: Compressible,
 {
     pub fn new(inner: C) -> Self {
-        Self {
-            inner,
-            compression_config: CompressionConfig::default(),
-            is_compressed: false,
-            compression_stats: None,
-            auto_compression_enabled: false,
-            compressed_data: None,
-            _phantom: PhantomData,
-        }
+        Self::with_config(inner, CompressionConfig::default())
     }
 
     pub fn with_config(inner: C, config: CompressionConfig) -> Self {
         Self {
             inner,
             compression_config: config,
-            is_compressed: false,
+            compressed: None,
             compression_stats: None,
             auto_compression_enabled: false,
-            compressed_data: None,
+            writes_since_decompression: 0,
             _phantom: PhantomData,
         }
     }
+
+    /// Bytes held by the pages, or `None` while uncompressed.
+    pub fn compressed_size_in_bytes(&self) -> Option<usize> {
+        self.compressed
+            .as_ref()
+            .map(|state| T::column_size_in_bytes(&state.column))
+    }
+
+    /// Encode the inner collection without touching `self`.
+    fn encode(
+        &self,
+        algorithm: CompressionAlgorithm,
+    ) -> Result<(CompressedState<T>, CompressionResult), CompressionError> {
+        let start_time = std::time::Instant::now();
+        let len = self.inner.len();
+        let default_value = self.inner.default_value();
+        let values: Vec<T> = (0..len)
+            .map(|i| self.inner.get(i).unwrap_or_else(|| default_value.clone()))
+            .collect();
+        let nulls: Vec<usize> = if self.inner.null_count() == 0 {
+            Vec::new()
+        } else {
+            (0..len).filter(|&i| self.inner.is_null(i)).collect()
+        };
+
+        let original_size = T::size_in_bytes(&values);
+        let column = T::compress_column(&values, &algorithm, self.compression_config.page_size())?;
+        let compressed_size =
+            T::column_size_in_bytes(&column) + nulls.len() * std::mem::size_of::<usize>();
+        let compression_ratio = if original_size == 0 {
+            1.0
+        } else {
+            compressed_size as f64 / original_size as f64
+        };
+
+        let result = CompressionResult {
+            original_size,
+            compressed_size,
+            compression_ratio,
+            compression_time_ms: start_time.elapsed().as_millis() as u64,
+            algorithm_used: algorithm.clone(),
+        };
+        let state = CompressedState {
+            column,
+            len,
+            default_value,
+            nulls,
+            original_size,
+            algorithm,
+            stats: StatsCache::default(),
+        };
+        Ok((state, result))
+    }
+
+    /// Release the inner collection and serve reads from `state`.
+    fn install(&mut self, state: CompressedState<T>, result: &CompressionResult) {
+        self.inner = C::with_capacity(0);
+        self.compressed = Some(state);
+
+        let memory_saved = result.original_size.saturating_sub(result.compressed_size);
+        if let Some(ref mut stats) = self.compression_stats {
+            stats.total_compressions += 1;
+            stats.average_compression_ratio = (stats.average_compression_ratio
+                * (stats.total_compressions - 1) as f64
+                + result.compression_ratio)
+                / stats.total_compressions as f64;
+            stats.memory_saved_bytes += memory_saved;
+        } else {
+            self.compression_stats = Some(CompressionStats {
+                total_compressions: 1,
+                total_decompressions: 0,
+                average_compression_ratio: result.compression_ratio,
+                total_time_saved_ms: 0,
+                memory_saved_bytes: memory_saved,
+            });
+        }
+    }
+
+    /// Compress with the configured algorithm if the measured savings reach the threshold.
+    fn auto_compress(&mut self) -> Result<(), CompressionError> {
+        self.writes_since_decompression = 0;
+        if self.compressed.is_some() {
+            return Ok(());
+        }
+        let (state, result) = self.encode(self.compression_config.algorithm.clone())?;
+        if 1.0 - result.compression_ratio >= self.compression_config.auto_compress_threshold {
+            self.install(state, &result);
+        }
+        Ok(())
+    }
+
+    /// Decode the pages into a fresh inner collection.
+    fn materialize(&self, state: &CompressedState<T>) -> C {
+        let mut inner = C::with_defaults(state.len, state.default_value.clone());
+        for index in 0..state.len {
+            if let Some(value) = T::column_get(&state.column, index) {
+                inner.set(index, value);
+            }
+        }
+        inner
+    }
+
+    /// Move the pages back into the inner collection; returns `None` while uncompressed.
+    fn inflate(&mut self) -> Option<CompressedState<T>> {
+        let state = self.compressed.take()?;
+        self.inner = self.materialize(&state);
+        self.writes_since_decompression = 0;
+        if let Some(ref mut stats) = self.compression_stats {
+            stats.total_decompressions += 1;
+        }
+        Some(state)
+    }
+
+    /// Re-run auto-compression after writes.
+    fn recompress(&mut self) {
+        // The configured algorithm was validated by `enable_auto_compression`, and encoding
+        // only fails for unsupported algorithms.
+        self.auto_compress()
+            .expect("auto-compression algorithm is validated when enabled");
+    }
+
+    /// Count a write and re-run auto-compression once a page worth has accumulated.
+    fn record_write(&mut self) {
+        if !self.auto_compression_enabled {
+            return;
+        }
+        self.writes_since_decompression += 1;
+        if self.writes_since_decompression >= self.compression_config.page_size() {
+            self.recompress();
+        }
+    }
+
+    /// A statistic of the inner collection, or of the pages decoded once and cached.
+    fn cached_stat<S: Clone>(
+        &self,
+        slot: impl FnOnce(&StatsCache<T>) -> &OnceLock<S>,
+        compute: impl FnOnce(&C) -> S,
+    ) -> S {
+        match &self.compressed {
+            Some(state) => slot(&state.stats)
+                .get_or_init(|| compute(&self.materialize(state)))
+                .clone(),
+            None => compute(&self.inner),
+        }
+    }
 }
 
 impl<T, C> Collections<T> for CompressedCollection<T, C>
 where
     C: Collections<T>,
-    T: Clone + Send + Sync + serde::Serialize + serde::de::DeserializeOwned,
+    T: Compressible,
 {
     fn get(&self, index: usize) -> Option<T> {
-        if self.is_compressed {
-            // For compressed collections, we need to decompress to access
-            // This is a limitation - consider using chunked compression
-            None // Placeholder - would need decompression logic
-        } else {
-            self.inner.get(index)
+        match &self.compressed {
+            Some(state) => T::column_get(&state.column, index),
+            None => self.inner.get(index),
         }
     }
 
     fn set(&mut self, index: usize, value: T) {
-        if self.is_compressed {
-            // Cannot set values in compressed collection
-            // Would need to decompress first
-            return;
-        }
-
+        // Pages are read-only
+        self.inflate();
         self.inner.set(index, value);
-
-        // Check if we should auto-compress
-        if self.auto_compression_enabled {
-            let current_size = self.inner.len() * std::mem::size_of::<T>();
-            let estimated_compressed_size = (current_size as f64 * 0.5) as usize; // Assume 50% compression
-            let savings_ratio = 1.0 - (estimated_compressed_size as f64 / current_size as f64);
-
-            if savings_ratio >= self.compression_config.auto_compress_threshold {
-                let _ = self.compress(self.compression_config.algorithm.clone());
-            }
-        }
+        self.record_write();
     }
 
     fn len(&self) -> usize {
-        self.inner.len()
+        match &self.compressed {
+            Some(state) => state.len,
+            None => self.inner.len(),
+        }
     }
 
     fn is_empty(&self) -> bool {
-        self.inner.is_empty()
+        self.len() == 0
     }
 
     fn sum(&self) -> Option<T>
     where
         T: std::iter::Sum,
     {
-        if self.is_compressed {
-            None // Cannot compute sum on compressed data
-        } else {
-            self.inner.sum()
-        }
+        self.cached_stat(|stats| &stats.sum, |inner| inner.sum())
     }
 
     fn min(&self) -> Option<T>
     where
         T: Ord,
     {
-        if self.is_compressed {
-            None // Cannot compute min on compressed data
-        } else {
-            self.inner.min()
-        }
+        self.cached_stat(|stats| &stats.min, |inner| inner.min())
     }
 
     fn max(&self) -> Option<T>
     where
         T: Ord,
     {
-        if self.is_compressed {
-            None // Cannot compute max on compressed data
-        } else {
-            self.inner.max()
-        }
+        self.cached_stat(|stats| &stats.max, |inner| inner.max())
     }
 
     fn mean(&self) -> Option<f64> {
-        if self.is_compressed {
-            None // Cannot compute mean on compressed data
-        } else {
-            self.inner.mean()
-        }
+        self.cached_stat(|stats| &stats.mean, |inner| inner.mean())
     }
 
     fn std_dev(&self) -> Option<f64> {
-        if self.is_compressed {
-            None
-        } else {
-            self.inner.std_dev()
-        }
+        self.cached_stat(|stats| &stats.std_dev, |inner| inner.std_dev())
     }
 
     fn variance(&self) -> Option<f64> {
-        if self.is_compressed {
-            None
-        } else {
-            self.inner.variance()
-        }
+        self.cached_stat(|stats| &stats.variance, |inner| inner.variance())
     }
 
     fn median(&self) -> Option<T>
     where
         T: Ord,
     {
-        if self.is_compressed {
-            None
-        } else {
-            self.inner.median()
-        }
+        self.cached_stat(|stats| &stats.median, |inner| inner.median())
     }
 
     fn percentile(&self, p: f64) -> Option<T>
     where
         T: Ord,
     {
-        if self.is_compressed {
-            None
-        } else {
-            self.inner.percentile(p)
-        }
+        let Some(state) = &self.compressed else {
+            return self.inner.percentile(p);
+        };
+        state
+            .stats
+            .percentiles
+            .lock()
+            .unwrap()
+            .entry(p.to_bits())
+            .or_insert_with(|| self.materialize(state).percentile(p))
+            .clone()
     }
 
     fn binary_search(&self, key: &T) -> Result<usize, usize>
     where
         T: Ord,
     {
-        if self.is_compressed {
-            Err(0) // Cannot binary search compressed data
-        } else {
-            self.inner.binary_search(key)
+        let Some(state) = &self.compressed else {
+            return self.inner.binary_search(key);
+        };
+        // Probes decode one value each, so the search touches O(log n) pages
+        let (mut low, mut high) = (0, state.len);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match T::column_get(&state.column, mid).map(|value| value.cmp(key)) {
+                Some(std::cmp::Ordering::Less) => low = mid + 1,
+                Some(std::cmp::Ordering::Greater) => high = mid,
+                Some(std::cmp::Ordering::Equal) => return Ok(mid),
+                None => break,
+            }
         }
+        Err(low)
     }
 
     fn sort(&mut self)
     where
         T: Ord,
     {
-        if !self.is_compressed {
-            self.inner.sort();
+        self.inflate();
+        self.inner.sort();
+        if self.auto_compression_enabled {
+            self.recompress();
         }
     }
 
     fn to_vec(self) -> Vec<T> {
-        if self.is_compressed {
-            // Would need to decompress first
-            Vec::new() // Placeholder
-        } else {
-            self.inner.to_vec()
+        match &self.compressed {
+            Some(state) => (0..state.len)
+                .filter_map(|index| T::column_get(&state.column, index))
+                .collect(),
+            None => self.inner.to_vec(),
         }
     }
 
     fn as_slice(&self) -> &[T] {
-        if self.is_compressed {
-            &[] // Cannot provide slice for compressed data
+        if self.compressed.is_some() {
+            &[] // Pages cannot be borrowed as a slice
         } else {
             self.inner.as_slice()
         }
     }
 
     fn is_null(&self, index: usize) -> bool {
-        if self.is_compressed {
-            false // Cannot check null on compressed data
-        } else {
-            self.inner.is_null(index)
+        match &self.compressed {
+            Some(state) => state.nulls.binary_search(&index).is_ok(),
+            None => self.inner.is_null(index),
         }
     }
 
     fn null_count(&self) -> usize {
-        if self.is_compressed {
-            0 // Cannot count nulls on compressed data
-        } else {
-            self.inner.null_count()
+        match &self.compressed {
+            Some(state) => state.nulls.len(),
+            None => self.inner.null_count(),
         }
     }
 
     fn default_value(&self) -> T {
-        self.inner.default_value()
+        match &self.compressed {
+            Some(state) => state.default_value.clone(),
+            None => self.inner.default_value(),
+        }
     }
 
     fn backend(&self) -> CollectionsBackend {
@@ -364,135 +547,33 @@ where
 impl<T, C> CompressionSupport<T> for CompressedCollection<T, C>
 where
     C: Collections<T>,
-    T: Clone + Send + Sync + serde::Serialize + serde::de::DeserializeOwned,
+    T: Compressible,
 {
     fn compress(
         &mut self,
         algorithm: CompressionAlgorithm,
     ) -> Result<CompressionResult, CompressionError> {
-        if self.is_compressed {
+        if self.compressed.is_some() {
             return Err(CompressionError::AlreadyCompressed);
         }
-
-        let start_time = std::time::Instant::now();
-        let original_size = self.inner.len() * std::mem::size_of::<T>();
-
-        // Serialize the collection data
-        let data: Vec<T> = (0..self.inner.len())
-            .filter_map(|i| self.inner.get(i))
-            .collect();
-        let serialized = serde_json::to_vec(&data)
-            .map_err(|e| CompressionError::SerializationFailed(e.to_string()))?;
-
-        // Compress the serialized data
-        let compressed = match algorithm {
-            CompressionAlgorithm::Lz4 => {
-                // Placeholder - would use actual LZ4 library
-                serialized.clone() // No compression for now
-            }
-            CompressionAlgorithm::Zstd => {
-                // Placeholder - would use actual Zstd library
-                serialized.clone() // No compression for now
-            }
-            CompressionAlgorithm::Gzip => {
-                // Placeholder - would use actual Gzip library
-                serialized.clone() // No compression for now
-            }
-            CompressionAlgorithm::Brotli => {
-                // Placeholder - would use actual Brotli library
-                serialized.clone() // No compression for now
-            }
-            CompressionAlgorithm::Snappy => {
-                // Placeholder - would use actual Snappy library
-                serialized.clone() // No compression for now
-            }
-            CompressionAlgorithm::Custom(_) => {
-                return Err(CompressionError::UnsupportedAlgorithm(
-                    "Custom algorithms not implemented".to_string(),
-                ));
-            }
-        };
-
-        let compression_time = start_time.elapsed().as_millis() as u64;
-        let compression_ratio = compressed.len() as f64 / original_size as f64;
-
-        // Store compressed data
-        self.compressed_data = Some(compressed.clone());
-        self.is_compressed = true;
-
-        // Update statistics
-        if let Some(ref mut stats) = self.compression_stats {
-            stats.total_compressions += 1;
-            stats.average_compression_ratio = (stats.average_compression_ratio
-                * (stats.total_compressions - 1) as f64
-                + compression_ratio)
-                / stats.total_compressions as f64;
-            stats.memory_saved_bytes += original_size - compressed.len();
-        } else {
-            self.compression_stats = Some(CompressionStats {
-                total_compressions: 1,
-                total_decompressions: 0,
-                average_compression_ratio: compression_ratio,
-                total_time_saved_ms: 0,
-                memory_saved_bytes: original_size - compressed.len(),
-            });
-        }
-
-        Ok(CompressionResult {
-            original_size,
-            compressed_size: compressed.len(),
-            compression_ratio,
-            compression_time_ms: compression_time,
-            algorithm_used: algorithm,
-        })
+        let (state, result) = self.encode(algorithm)?;
+        self.install(state, &result);
+        Ok(result)
     }
 
     fn decompress(&mut self) -> Result<DecompressionResult, CompressionError> {
-        if !self.is_compressed {
-            return Err(CompressionError::NotCompressed);
-        }
-
         let start_time = std::time::Instant::now();
-
-        // Get compressed data
-        let compressed_data = self
-            .compressed_data
-            .take()
-            .ok_or(CompressionError::NoCompressedData)?;
-
-        // Decompress (placeholder - would use actual decompression)
-        let decompressed = compressed_data; // No decompression for now
-
-        // Deserialize back to collection
-        let data: Vec<T> = serde_json::from_slice(&decompressed)
-            .map_err(|e| CompressionError::DeserializationFailed(e.to_string()))?;
-
-        let decompression_time = start_time.elapsed().as_millis() as u64;
-
-        // Restore the collection
-        let mut new_inner = C::with_defaults(data.len(), data[0].clone());
-        for (i, value) in data.into_iter().enumerate() {
-            new_inner.set(i, value);
-        }
-        // Replace the inner collection
-        self.inner = new_inner;
-
-        self.is_compressed = false;
-
-        // Update statistics
-        if let Some(ref mut stats) = self.compression_stats {
-            stats.total_decompressions += 1;
-        }
+        let state = self.inflate().ok_or(CompressionError::NotCompressed)?;
 
         Ok(DecompressionResult {
-            decompressed_size: decompressed.len(),
-            decompression_time_ms: decompression_time,
-            algorithm_used: self.compression_config.algorithm.clone(),
+            decompressed_size: state.original_size,
+            decompression_time_ms: start_time.elapsed().as_millis() as u64,
+            algorithm_used: state.algorithm,
         })
     }
 
     fn is_compressed(&self) -> bool {
-        self.is_compressed
+        self.compressed.is_some()
     }
 
     fn compression_ratio(&self) -> Option<f64> {
@@ -505,10 +586,23 @@ where
         self.compression_stats.clone()
     }
 
+    /// Compresses right away when the measured savings reach `threshold`, and again after
+    /// later writes.
     fn enable_auto_compression(&mut self, threshold: f64) -> Result<(), CompressionError> {
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(CompressionError::CompressionFailed(format!(
+                "auto-compression threshold {threshold} is outside 0..=1"
+            )));
+        }
+        // Reject byte codecs now rather than on the write that triggers recompression
+        T::compress_column(
+            &[],
+            &self.compression_config.algorithm,
+            self.compression_config.page_size(),
+        )?;
         self.compression_config.auto_compress_threshold = threshold;
         self.auto_compression_enabled = true;
-        Ok(())
+        self.auto_compress()
     }
 
     fn disable_auto_compression(&mut self) {
@@ -551,11 +645,16 @@ impl CompressionUtils {
         algorithm: &CompressionAlgorithm,
     ) -> f64 {
         match algorithm {
-            CompressionAlgorithm::Lz4 => 0.6,       // 40% compression
-            CompressionAlgorithm::Zstd => 0.5,      // 50% compression
-            CompressionAlgorithm::Gzip => 0.4,      // 60% compression
-            CompressionAlgorithm::Brotli => 0.3,    // 70% compression
-            CompressionAlgorithm::Snappy => 0.7,    // 30% compression
+            CompressionAlgorithm::Auto => 0.3,
+            CompressionAlgorithm::BitPacking => 0.5,
+            CompressionAlgorithm::FrameOfReference => 0.4,
+            CompressionAlgorithm::Delta => 0.4,
+            CompressionAlgorithm::Dictionary => 0.3,
+            CompressionAlgorithm::Lz4 => 0.6,    // 40% compression
+            CompressionAlgorithm::Zstd => 0.5,   // 50% compression
+            CompressionAlgorithm::Gzip => 0.4,   // 60% compression
+            CompressionAlgorithm::Brotli => 0.3, // 70% compression
+            CompressionAlgorithm::Snappy => 0.7, // 30% compression
             CompressionAlgorithm::Custom(_) => 0.5, // Default estimate
         }
     }
@@ -566,10 +665,13 @@ impl CompressionUtils {
         access_pattern: AccessPattern,
     ) -> CompressionAlgorithm {
         match access_pattern {
-            AccessPattern::Sequential => CompressionAlgorithm::Gzip,
-            AccessPattern::Random => CompressionAlgorithm::Lz4,
-            AccessPattern::Mixed => CompressionAlgorithm::Zstd,
-            AccessPattern::WriteHeavy => CompressionAlgorithm::Snappy,
+            // Page encodings decode single values without inflating the page, so reads of any
+            // pattern take the smallest encoding per page
+            AccessPattern::Sequential | AccessPattern::Random | AccessPattern::Mixed => {
+                CompressionAlgorithm::Auto
+            }
+            // Writes re-encode the collection, and bit-packing encodes each page in one pass
+            AccessPattern::WriteHeavy => CompressionAlgorithm::BitPacking,
         }
     }
 
@@ -610,3 +712,160 @@ pub struct CompressionBenefits {
     pub time_saved_per_access_ms: f64,
     pub memory_efficiency_gain: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::backends::huge::HugeLongArray;
+    use crate::collections::backends::vec::{VecDouble, VecLong, VecLongArray};
+
+    #[test]
+    fn compressed_collections_serve_reads_from_pages() {
+        let communities: Vec<i64> = (0..20_000).map(|node| (node % 12) * 100).collect();
+        let mut collection =
+            CompressedCollection::new(HugeLongArray::from_vec(communities.clone()));
+        let result = collection.compress(CompressionAlgorithm::Auto).unwrap();
+        assert!(collection.is_compressed());
+        assert!(result.compression_ratio < 0.1);
+        assert_eq!(result.original_size, communities.len() * 8);
+        assert_eq!(collection.len(), 20_000);
+        assert_eq!(collection.get(13_337), Some(communities[13_337]));
+        assert_eq!(collection.max(), Some(1_100));
+        assert!(matches!(
+            collection.compress(CompressionAlgorithm::Auto),
+            Err(CompressionError::AlreadyCompressed)
+        ));
+
+        collection.set(0, 5);
+        assert!(!collection.is_compressed());
+        assert_eq!(collection.get(0), Some(5));
+        assert_eq!(
+            collection.compression_stats().unwrap().total_decompressions,
+            1
+        );
+
+        let mut sorted =
+            CompressedCollection::new(VecLong::from((0..1_000).map(|i| i * 3).collect::<Vec<_>>()));
+        sorted.compress(CompressionAlgorithm::Delta).unwrap();
+        assert_eq!(sorted.binary_search(&300), Ok(100));
+        assert_eq!(sorted.binary_search(&301), Err(101));
+        assert!(sorted.as_slice().is_empty());
+        assert_eq!(sorted.to_vec()[999], 2_997);
+    }
+
+    #[test]
+    fn float_and_array_collections_round_trip() {
+        let mut weights = CompressedCollection::new(VecDouble::from(vec![0.5, 1.5, 0.5]));
+        assert!(matches!(
+            weights.compress(CompressionAlgorithm::Zstd),
+            Err(CompressionError::UnsupportedAlgorithm(_))
+        ));
+        weights.compress(CompressionAlgorithm::Dictionary).unwrap();
+        assert_eq!(weights.mean(), Some(2.5 / 3.0));
+        weights.decompress().unwrap();
+        assert_eq!(weights.as_slice(), &[0.5, 1.5, 0.5]);
+
+        let arrays = vec![Some(vec![1, 2, 3]), None, Some(vec![4])];
+        let mut embeddings = CompressedCollection::new(VecLongArray::from(arrays.clone()));
+        embeddings
+            .compress(CompressionAlgorithm::FrameOfReference)
+            .unwrap();
+        assert_eq!(embeddings.get(0), Some(Some(vec![1, 2, 3])));
+        assert_eq!(embeddings.get(3), None);
+        assert!(embeddings.is_null(1));
+        assert_eq!(embeddings.null_count(), 1);
+        assert_eq!(embeddings.to_vec(), arrays);
+    }
+
+    #[test]
+    fn auto_compression_requires_measured_savings() {
+        let mut noisy = CompressedCollection::new(VecLong::from(vec![i64::MIN, i64::MAX, 0, -7]));
+        noisy.enable_auto_compression(0.5).unwrap();
+        assert!(noisy.is_auto_compression_enabled());
+        assert!(!noisy.is_compressed());
+
+        let mut constant = CompressedCollection::new(VecLong::from(vec![42; 10_000]));
+        constant.enable_auto_compression(0.9).unwrap();
+        assert!(constant.is_compressed());
+        assert!(constant.enable_auto_compression(1.5).is_err());
+
+        let mut byte_codec = CompressedCollection::with_config(
+            VecLong::from(vec![42; 10_000]),
+            CompressionConfig {
+                algorithm: CompressionAlgorithm::Lz4,
+                ..CompressionConfig::default()
+            },
+        );
+        assert!(matches!(
+            byte_codec.enable_auto_compression(0.9),
+            Err(CompressionError::UnsupportedAlgorithm(_))
+        ));
+        assert!(!byte_codec.is_auto_compression_enabled());
+    }
+
+    #[test]
+    fn auto_compression_resumes_after_writes() {
+        let config = CompressionConfig {
+            chunk_size: 64 * 8,
+            ..CompressionConfig::default()
+        };
+        let mut collection =
+            CompressedCollection::with_config(VecLong::from(vec![42; 1_000]), config);
+        collection.enable_auto_compression(0.5).unwrap();
+        assert!(collection.is_compressed());
+
+        for index in 0..63 {
+            collection.set(index, 7);
+        }
+        assert!(!collection.is_compressed());
+        collection.set(63, 7);
+        assert!(collection.is_compressed());
+        assert_eq!(collection.get(10), Some(7));
+        assert_eq!(collection.get(64), Some(42));
+
+        collection.set(999, 1);
+        collection.sort();
+        assert!(collection.is_compressed());
+        assert_eq!(collection.get(0), Some(1));
+        assert_eq!(
+            collection.compression_stats().unwrap().total_compressions,
+            3
+        );
+    }
+
+    #[test]
+    fn statistics_of_compressed_collections_are_cached() {
+        let mut collection =
+            CompressedCollection::new(VecLong::from((0..1_000).collect::<Vec<i64>>()));
+        collection.compress(CompressionAlgorithm::Auto).unwrap();
+        assert_eq!(collection.max(), Some(999));
+        let median = VecLong::from((0..1_000).collect::<Vec<i64>>()).percentile(50.0);
+        assert_eq!(collection.percentile(50.0), median);
+        let state = collection.compressed.as_ref().unwrap();
+        assert_eq!(state.stats.max.get(), Some(&Some(999)));
+        assert!(state.stats.min.get().is_none());
+        assert_eq!(state.stats.percentiles.lock().unwrap().len(), 1);
+        assert_eq!(collection.max(), Some(999));
+        assert_eq!(collection.sum(), Some(499_500));
+
+        // Writes drop the cache along with the pages
+        collection.set(0, 5_000);
+        collection.compress(CompressionAlgorithm::Auto).unwrap();
+        assert_eq!(collection.max(), Some(5_000));
+    }
+
+    #[test]
+    fn suggested_algorithms_are_page_encodings() {
+        for access_pattern in [
+            AccessPattern::Sequential,
+            AccessPattern::Random,
+            AccessPattern::Mixed,
+            AccessPattern::WriteHeavy,
+        ] {
+            let algorithm =
+                CompressionUtils::choose_optimal_algorithm::<i64>(1_000, access_pattern);
+            let mut collection = CompressedCollection::new(VecLong::from(vec![3; 1_000]));
+            assert!(collection.compress(algorithm).is_ok());
+        }
+    }
+}
@@ -6,6 +6,7 @@
 pub mod caching;
 pub mod catalog;
 pub mod chunking;
+pub mod compressed_pages;
 pub mod compression;
 pub mod distributed;
 pub mod encryption;
@@ -21,21 +22,26 @@ pub mod paging;
 pub mod partitioning;
 pub mod queue;
 pub mod random;
+pub mod sql;
 pub mod stack;
 pub mod streaming;
 
 pub use caching::*;
 pub use catalog::*;
 pub use chunking::*;
+pub use compressed_pages::*;
 pub use compression::*;
+pub use encryption::*;
 pub use estimation::*;
 pub use framing::*;
 pub use graph::*;
 pub use indexing::*;
 pub use metrics::*;
+pub use ndarray::*;
 pub use paging::*;
 pub use partitioning::*;
 pub use queue::*;
 pub use random::*;
+pub use sql::*;
 pub use stack::*;
 pub use streaming::*;
@@ -1 +1,266 @@
+//! Ndarray extension for Collections.
+//!
+//! Zero-copy matrix views over double and float collections. Views follow ndarray's
+//! `ArrayView2` conventions (row-major shape, element strides, `t()` swaps the axes) without
+//! pulling in the ndarray crate; `as_slice` plus `shape` is all `ArrayView2::from_shape`
+//! needs when interop is required.
+//!
+//! Vec backends and single-page Huge arrays are one contiguous buffer. Paged Huge arrays are
+//! exposed as row blocks, one per page, as long as the page size is a multiple of the row
+//! length.
 
+use crate::collections::backends::huge::{HugeDoubleArray, HugeFloatArray};
+use crate::collections::backends::vec::{VecDouble, VecFloat};
+use crate::collections::cursor::{init_cursor, HugeCursor};
+
+/// Ndarray error types
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum NdarrayError {
+    #[error("Cannot view {len} elements as a {rows}x{cols} matrix")]
+    ShapeMismatch {
+        len: usize,
+        rows: usize,
+        cols: usize,
+    },
+    #[error("Collection is not contiguous: {0}")]
+    NotContiguous(String),
+}
+
+/// Borrowed two-dimensional view with ndarray-style shape and strides.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatrixView<'a, T> {
+    data: &'a [T],
+    shape: [usize; 2],
+    strides: [usize; 2],
+}
+
+impl<'a, T: Copy> MatrixView<'a, T> {
+    /// View `data` as a row-major `rows` x `cols` matrix.
+    pub fn from_shape(rows: usize, cols: usize, data: &'a [T]) -> Result<Self, NdarrayError> {
+        if rows.checked_mul(cols) != Some(data.len()) {
+            return Err(NdarrayError::ShapeMismatch {
+                len: data.len(),
+                rows,
+                cols,
+            });
+        }
+        Ok(Self {
+            data,
+            shape: [rows, cols],
+            strides: [cols, 1],
+        })
+    }
+
+    pub fn shape(&self) -> [usize; 2] {
+        self.shape
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.shape[0]
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.shape[1]
+    }
+
+    /// Element strides per axis.
+    pub fn strides(&self) -> [usize; 2] {
+        self.strides
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        (row < self.shape[0] && col < self.shape[1])
+            .then(|| self.data[row * self.strides[0] + col * self.strides[1]])
+    }
+
+    /// Transposed view over the same buffer.
+    pub fn t(&self) -> Self {
+        Self {
+            data: self.data,
+            shape: [self.shape[1], self.shape[0]],
+            strides: [self.strides[1], self.strides[0]],
+        }
+    }
+
+    /// Returns true if rows are contiguous and laid out back to back.
+    pub fn is_standard_layout(&self) -> bool {
+        (self.strides[1] == 1 && self.strides[0] == self.shape[1]) || self.shape[0] <= 1
+    }
+
+    /// The underlying buffer, when in standard layout.
+    pub fn as_slice(&self) -> Option<&'a [T]> {
+        self.is_standard_layout().then_some(self.data)
+    }
+
+    /// Row `index` as a slice, when rows are contiguous.
+    pub fn row(&self, index: usize) -> Option<&'a [T]> {
+        if index >= self.shape[0] || self.strides[1] != 1 {
+            return None;
+        }
+        let start = index * self.strides[0];
+        Some(&self.data[start..start + self.shape[1]])
+    }
+
+    /// Iterate the elements of row `index` in column order, for any layout.
+    pub fn row_iter(&self, index: usize) -> impl Iterator<Item = T> + '_ {
+        (0..self.ncols()).filter_map(move |col| self.get(index, col))
+    }
+
+    /// Copy into a row-major vector.
+    pub fn to_vec(&self) -> Vec<T> {
+        (0..self.nrows())
+            .flat_map(|row| self.row_iter(row).collect::<Vec<_>>())
+            .collect()
+    }
+}
+
+impl<T: Copy + Into<f64>> MatrixView<'_, T> {
+    /// Matrix-vector product, accumulated in f64.
+    pub fn dot(&self, vector: &[T]) -> Result<Vec<f64>, NdarrayError> {
+        if vector.len() != self.ncols() {
+            return Err(NdarrayError::ShapeMismatch {
+                len: vector.len(),
+                rows: self.ncols(),
+                cols: 1,
+            });
+        }
+        Ok((0..self.nrows())
+            .map(|row| {
+                self.row_iter(row)
+                    .zip(vector)
+                    .map(|(a, &b)| a.into() * b.into())
+                    .sum()
+            })
+            .collect())
+    }
+}
+
+/// Ndarray extension trait for float and double Collections
+pub trait NdarraySupport<T> {
+    /// View the whole collection as a vector.
+    fn vector_view(&self) -> Result<&[T], NdarrayError>;
+
+    /// View the collection as a row-major matrix with `cols` columns.
+    fn matrix_view(&self, cols: usize) -> Result<MatrixView<'_, T>, NdarrayError>;
+
+    /// View the collection as consecutive row blocks with `cols` columns each.
+    fn matrix_blocks(&self, cols: usize) -> Result<Vec<MatrixView<'_, T>>, NdarrayError>;
+}
+
+fn single_matrix<T: Copy>(data: &[T], cols: usize) -> Result<MatrixView<'_, T>, NdarrayError> {
+    MatrixView::from_shape(data.len().checked_div(cols).unwrap_or(0), cols, data)
+}
+
+macro_rules! vec_ndarray_support {
+    ($collection:ty, $element:ty) => {
+        impl NdarraySupport<$element> for $collection {
+            fn vector_view(&self) -> Result<&[$element], NdarrayError> {
+                Ok(&self.data)
+            }
+
+            fn matrix_view(&self, cols: usize) -> Result<MatrixView<'_, $element>, NdarrayError> {
+                single_matrix(&self.data, cols)
+            }
+
+            fn matrix_blocks(
+                &self,
+                cols: usize,
+            ) -> Result<Vec<MatrixView<'_, $element>>, NdarrayError> {
+                Ok(vec![self.matrix_view(cols)?])
+            }
+        }
+    };
+}
+
+macro_rules! huge_ndarray_support {
+    ($collection:ty, $element:ty) => {
+        impl NdarraySupport<$element> for $collection {
+            fn vector_view(&self) -> Result<&[$element], NdarrayError> {
+                let mut pages = self.page_slices();
+                match (pages.next(), pages.next()) {
+                    (page, None) => Ok(page.unwrap_or(&[])),
+                    _ => Err(NdarrayError::NotContiguous(format!(
+                        "{} elements span several pages",
+                        self.size()
+                    ))),
+                }
+            }
+
+            fn matrix_view(&self, cols: usize) -> Result<MatrixView<'_, $element>, NdarrayError> {
+                single_matrix(self.vector_view()?, cols)
+            }
+
+            fn matrix_blocks(
+                &self,
+                cols: usize,
+            ) -> Result<Vec<MatrixView<'_, $element>>, NdarrayError> {
+                let pages: Vec<&[$element]> = self.page_slices().collect();
+                let last = pages.len().saturating_sub(1);
+                pages
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, page)| {
+                        if cols > 0 && index < last && page.len() % cols != 0 {
+                            return Err(NdarrayError::NotContiguous(format!(
+                                "page of {} elements splits rows of {cols}",
+                                page.len()
+                            )));
+                        }
+                        single_matrix(page, cols)
+                    })
+                    .collect()
+            }
+        }
+
+        impl $collection {
+            /// Borrow each page's valid elements in order.
+            fn page_slices(&self) -> impl Iterator<Item = &[$element]> + '_ {
+                let mut cursor = self.new_cursor();
+                init_cursor(self, &mut cursor);
+                std::iter::from_fn(move || {
+                    if !cursor.next() {
+                        return None;
+                    }
+                    let page = cursor.array()?;
+                    Some(&page[cursor.offset()..cursor.limit()])
+                })
+            }
+        }
+    };
+}
+
+vec_ndarray_support!(VecDouble, f64);
+vec_ndarray_support!(VecFloat, f32);
+huge_ndarray_support!(HugeDoubleArray, f64);
+huge_ndarray_support!(HugeFloatArray, f32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn views_share_the_collection_buffer() {
+        let vec = VecDouble::from(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let matrix = vec.matrix_view(3).unwrap();
+        assert_eq!(matrix.shape(), [2, 3]);
+        assert_eq!(matrix.as_slice().unwrap().as_ptr(), vec.data.as_ptr());
+        assert_eq!(matrix.row(1), Some(&[4.0, 5.0, 6.0][..]));
+        assert_eq!(matrix.dot(&[1.0, 0.0, 1.0]).unwrap(), vec![4.0, 10.0]);
+
+        let transposed = matrix.t();
+        assert_eq!(transposed.shape(), [3, 2]);
+        assert_eq!(transposed.get(2, 1), Some(6.0));
+        assert_eq!(transposed.row(0), None);
+        assert_eq!(transposed.to_vec(), vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+        assert!(matches!(
+            vec.matrix_view(4),
+            Err(NdarrayError::ShapeMismatch { len: 6, .. })
+        ));
+
+        let huge = HugeFloatArray::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+        let blocks = huge.matrix_blocks(2).unwrap();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].get(1, 0), Some(3.0));
+        assert_eq!(huge.vector_view().unwrap().len(), 4);
+    }
+}
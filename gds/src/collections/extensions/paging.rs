@@ -303,4 +303,12 @@ impl PagingUtils {
         // Enable paging for large datasets (>1MB)
         data_size * std::mem::size_of::<T>() > 1024 * 1024
     }
+
+    /// Check if a column should be read from a memory-mapped file instead of the heap
+    ///
+    /// Columns that would take more than half of `memory_budget` bytes are left to the page
+    /// cache (see `types::properties::mapped`), which evicts cold pages under pressure.
+    pub fn should_memory_map<T>(element_count: usize, memory_budget: usize) -> bool {
+        element_count.saturating_mul(std::mem::size_of::<T>()) > memory_budget / 2
+    }
 }
@@ -0,0 +1,166 @@
+//! SQL extension for Collections.
+//!
+//! Runs SQL over catalog tables and in-memory frames through the Polars SQL context. Tables
+//! are registered lazily, so a query only scans the columns and row groups it needs.
+
+use polars::prelude::{IntoLazy, LazyFrame};
+use polars::sql::SQLContext;
+
+use crate::collections::catalog::types::CatalogError;
+use crate::collections::dataframe::GDSDataFrame;
+use crate::collections::extensions::catalog::CatalogExtension;
+
+/// SQL query facade over registered Collections tables.
+pub struct SqlExtension {
+    context: SQLContext,
+}
+
+impl Default for SqlExtension {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for SqlExtension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqlExtension")
+            .field("tables", &self.table_names())
+            .finish()
+    }
+}
+
+impl SqlExtension {
+    pub fn new() -> Self {
+        Self {
+            context: SQLContext::new(),
+        }
+    }
+
+    /// Create an extension with every table of `catalog` registered under its catalog name.
+    pub fn from_catalog(catalog: &CatalogExtension) -> Result<Self, CatalogError> {
+        let mut extension = Self::new();
+        extension.register_catalog(catalog)?;
+        Ok(extension)
+    }
+
+    /// Register all catalog tables; returns the number of registered tables.
+    pub fn register_catalog(&mut self, catalog: &CatalogExtension) -> Result<usize, CatalogError> {
+        let names: Vec<String> = catalog
+            .catalog()
+            .list()
+            .iter()
+            .map(|entry| entry.name.clone())
+            .collect();
+        for name in &names {
+            self.register_catalog_table(catalog, name)?;
+        }
+        Ok(names.len())
+    }
+
+    /// Register one catalog table as a lazy scan.
+    pub fn register_catalog_table(
+        &mut self,
+        catalog: &CatalogExtension,
+        name: &str,
+    ) -> Result<(), CatalogError> {
+        let lazy = catalog.scan_table(name)?;
+        self.context.register(name, lazy);
+        Ok(())
+    }
+
+    /// Register an in-memory table.
+    pub fn register_frame(&mut self, name: &str, table: &GDSDataFrame) {
+        self.context
+            .register(name, table.dataframe().clone().lazy());
+    }
+
+    /// Register a lazy query result as a table.
+    pub fn register_lazy(&mut self, name: &str, lazy: LazyFrame) {
+        self.context.register(name, lazy);
+    }
+
+    pub fn unregister(&mut self, name: &str) {
+        self.context.unregister(name);
+    }
+
+    /// Names of the registered tables, sorted.
+    pub fn table_names(&self) -> Vec<String> {
+        let mut names = self.context.get_tables();
+        names.sort();
+        names
+    }
+
+    /// Plan `query` without executing it.
+    pub fn query_lazy(&mut self, query: &str) -> Result<LazyFrame, CatalogError> {
+        self.context
+            .execute(query)
+            .map_err(|e| CatalogError::Polars(e.to_string()))
+    }
+
+    /// Execute `query` and collect the result.
+    pub fn query(&mut self, query: &str) -> Result<GDSDataFrame, CatalogError> {
+        let df = self
+            .query_lazy(query)?
+            .collect()
+            .map_err(|e| CatalogError::Polars(e.to_string()))?;
+        Ok(GDSDataFrame::new(df))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polars::prelude::{NamedFrom, PlSmallStr, Series};
+
+    fn people() -> GDSDataFrame {
+        GDSDataFrame::from_series(vec![
+            Series::new(PlSmallStr::from_static("name"), ["ada", "bob", "cy"]),
+            Series::new(PlSmallStr::from_static("age"), [36i64, 25, 41]),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn queries_registered_frames() {
+        let mut sql = SqlExtension::new();
+        sql.register_frame("people", &people());
+        assert_eq!(sql.table_names(), vec!["people".to_string()]);
+
+        let result = sql
+            .query("SELECT name FROM people WHERE age > 30 ORDER BY name")
+            .unwrap();
+        let names: Vec<_> = result
+            .dataframe()
+            .column("name")
+            .unwrap()
+            .str()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(names, vec!["ada", "cy"]);
+        assert!(matches!(
+            sql.query("SELECT * FROM missing"),
+            Err(CatalogError::Polars(_))
+        ));
+    }
+
+    #[test]
+    fn queries_catalog_tables() {
+        let root = std::env::temp_dir().join(format!("gds-sql-{}", uuid::Uuid::new_v4()));
+        let mut catalog = CatalogExtension::new(&root).unwrap();
+        catalog.write_table("people", &people(), None).unwrap();
+
+        let mut sql = SqlExtension::from_catalog(&catalog).unwrap();
+        let result = sql.query("SELECT SUM(age) AS total FROM people").unwrap();
+        let total = result
+            .dataframe()
+            .column("total")
+            .unwrap()
+            .i64()
+            .unwrap()
+            .get(0);
+        assert_eq!(total, Some(102));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -2,12 +2,17 @@
 
 mod catalog;
 mod model;
+mod model_file;
 mod model_metadata;
 mod types;
 mod user_catalog;
 
 pub use catalog::{EmptyModelCatalog, InMemoryModelCatalog, ModelCatalog, ModelCatalogListener};
 pub use model::Model as MLModel;
+pub use model_file::{
+    model_directory, validate_store_name, ModelFileHeader, ModelFileReader, ModelFileWriter,
+    UntypedModelFileHeader, META_DATA_FILE, MODEL_DATA_FILE, MODEL_FORMAT_VERSION,
+};
 pub use model_metadata::ModelMetaData;
 pub use types::{CustomInfo as ModelCatalogCustomInfo, Model, ModelConfig, ModelData};
 pub use user_catalog::UserCatalog;
@@ -0,0 +1,417 @@
+//! Versioned on-disk model format.
+//!
+//! Mirrors Java GDS `ModelFileWriter` / `ModelFileReader`: every stored model lives in
+//! `<store_location>/<creator>/<model_name>/` and consists of
+//!
+//! - `model.meta.json`: a [`ModelFileHeader`] with the catalog metadata, train config
+//!   and custom info as JSON, tagged with [`MODEL_FORMAT_VERSION`];
+//! - `model.data`: the bincode-encoded model data.
+//!
+//! The header is written last, so a directory without it is an incomplete store and is
+//! skipped by [`ModelFileReader::list`].
+
+use super::types::{CustomInfo, Model, ModelConfig, ModelData};
+use crate::types::schema::GraphSchema;
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Current on-disk model format version. Bump when the layout changes incompatibly.
+///
+/// - 2: decision tree nodes record their training sample count and impurity.
+pub const MODEL_FORMAT_VERSION: u32 = 2;
+
+pub const META_DATA_FILE: &str = "model.meta.json";
+pub const MODEL_DATA_FILE: &str = "model.data";
+
+/// Directory holding the files of one stored model.
+///
+/// Fails unless `username` and `model_name` are each a single plain path component, so a
+/// name can never address files outside `store_location`.
+pub fn model_directory(store_location: &Path, username: &str, model_name: &str) -> Result<PathBuf> {
+    validate_store_name("username", username)?;
+    validate_store_name("model name", model_name)?;
+    Ok(store_location.join(username).join(model_name))
+}
+
+/// Checks that `value` can be used as one directory name below the model store.
+pub fn validate_store_name(kind: &str, value: &str) -> Result<()> {
+    let mut components = Path::new(value).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(name)), None) if name == value && !value.contains(['/', '\\']) => {}
+        _ => bail!("Invalid {kind} `{value}`: must be a single name without path separators"),
+    }
+    Ok(())
+}
+
+/// Everything about a stored model except its data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelFileHeader<C, I> {
+    pub format_version: u32,
+    pub creator: String,
+    pub shared_with: Vec<String>,
+    pub name: String,
+    pub algo_type: String,
+    pub graph_schema: GraphSchema,
+    pub train_config: C,
+    pub creation_time: DateTime<Utc>,
+    pub gds_version: String,
+    pub custom_info: I,
+}
+
+/// Header with train config and custom info left as JSON, readable without model types.
+pub type UntypedModelFileHeader = ModelFileHeader<Value, Value>;
+
+pub struct ModelFileWriter;
+
+impl ModelFileWriter {
+    /// Writes `model` below `store_location` and returns the model directory.
+    pub fn write<D, C, I>(model: &Model<D, C, I>, store_location: &Path) -> Result<PathBuf>
+    where
+        D: ModelData,
+        C: ModelConfig,
+        I: CustomInfo,
+    {
+        let data = model
+            .data()
+            .ok_or_else(|| anyhow!("Model `{}` is not loaded", model.name()))?;
+
+        let dir = model_directory(store_location, model.creator(), model.name())?;
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create model directory {}", dir.display()))?;
+
+        let bytes = bincode::serialize(data)
+            .map_err(|err| anyhow!("Failed to serialize model `{}`: {err}", model.name()))?;
+        fs::write(dir.join(MODEL_DATA_FILE), bytes)
+            .with_context(|| format!("Failed to write model data to {}", dir.display()))?;
+
+        let header = ModelFileHeader {
+            format_version: MODEL_FORMAT_VERSION,
+            creator: model.creator().to_string(),
+            shared_with: model.shared_with().to_vec(),
+            name: model.name().to_string(),
+            algo_type: model.algo_type().to_string(),
+            graph_schema: model.graph_schema().clone(),
+            train_config: model.train_config(),
+            creation_time: model.creation_time(),
+            gds_version: model.gds_version().to_string(),
+            custom_info: model.custom_info(),
+        };
+        let json = serde_json::to_vec_pretty(&header)?;
+        fs::write(dir.join(META_DATA_FILE), json)
+            .with_context(|| format!("Failed to write model metadata to {}", dir.display()))?;
+
+        Ok(dir)
+    }
+}
+
+pub struct ModelFileReader {
+    dir: PathBuf,
+}
+
+impl ModelFileReader {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn for_model(store_location: &Path, username: &str, model_name: &str) -> Result<Self> {
+        model_directory(store_location, username, model_name).map(Self::new)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn exists(&self) -> bool {
+        self.dir.join(META_DATA_FILE).is_file()
+    }
+
+    pub fn read_header<C, I>(&self) -> Result<ModelFileHeader<C, I>>
+    where
+        C: DeserializeOwned,
+        I: DeserializeOwned,
+    {
+        let path = self.dir.join(META_DATA_FILE);
+        let bytes = fs::read(&path)
+            .with_context(|| format!("No stored model found at {}", self.dir.display()))?;
+
+        // Check the version before committing to the typed layout; older layouts have no
+        // migration and would otherwise fail deep inside bincode.
+        let version = serde_json::from_slice::<Value>(&bytes)?
+            .get("formatVersion")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| anyhow!("{} has no formatVersion", path.display()))?;
+        if version != u64::from(MODEL_FORMAT_VERSION) {
+            bail!(
+                "Model at {} uses unsupported model format version {version}, this build reads version {MODEL_FORMAT_VERSION}",
+                self.dir.display()
+            );
+        }
+
+        serde_json::from_slice(&bytes)
+            .with_context(|| format!("Corrupt model metadata at {}", path.display()))
+    }
+
+    pub fn read_untyped_header(&self) -> Result<UntypedModelFileHeader> {
+        self.read_header()
+    }
+
+    /// Reads the full model; the returned model records this directory as its file location.
+    pub fn read<D, C, I>(&self) -> Result<Model<D, C, I>>
+    where
+        D: ModelData + DeserializeOwned,
+        C: ModelConfig + DeserializeOwned,
+        I: CustomInfo + DeserializeOwned,
+    {
+        let header = self.read_header::<C, I>()?;
+        let bytes = fs::read(self.dir.join(MODEL_DATA_FILE))
+            .with_context(|| format!("Missing model data at {}", self.dir.display()))?;
+        let data: D = bincode::deserialize(&bytes)
+            .map_err(|err| anyhow!("Failed to deserialize model `{}`: {err}", header.name))?;
+
+        Ok(Model::restore(
+            header.creator,
+            header.shared_with,
+            header.name,
+            header.algo_type,
+            header.graph_schema,
+            Some(data),
+            header.train_config,
+            header.creation_time,
+            header.gds_version,
+            header.custom_info,
+            Some(self.dir.clone()),
+        ))
+    }
+
+    /// Lists the headers of all complete model stores below `store_location`.
+    ///
+    /// Stores whose header cannot be read are skipped with a warning, so one corrupt model
+    /// does not hide the others.
+    pub fn list(store_location: &Path) -> Result<Vec<UntypedModelFileHeader>> {
+        let mut headers = Vec::new();
+        if !store_location.is_dir() {
+            return Ok(headers);
+        }
+
+        for user_dir in fs::read_dir(store_location)? {
+            let user_dir = user_dir?.path();
+            if !user_dir.is_dir() {
+                continue;
+            }
+            let model_dirs = match fs::read_dir(&user_dir) {
+                Ok(model_dirs) => model_dirs,
+                Err(err) => {
+                    log::warn!("Skipping model store {}: {err}", user_dir.display());
+                    continue;
+                }
+            };
+            for model_dir in model_dirs.flatten() {
+                let reader = Self::new(model_dir.path());
+                if !reader.exists() {
+                    continue;
+                }
+                match reader.read_untyped_header() {
+                    Ok(header) => headers.push(header),
+                    Err(err) => {
+                        log::warn!(
+                            "Skipping stored model at {}: {err:#}",
+                            reader.dir().display()
+                        )
+                    }
+                }
+            }
+        }
+
+        headers.sort_by(|a, b| (&a.creator, &a.name).cmp(&(&b.creator, &b.name)));
+        Ok(headers)
+    }
+
+    /// Removes the stored files of this model.
+    pub fn delete(&self) -> Result<()> {
+        if !self.exists() {
+            bail!("No stored model found at {}", self.dir.display());
+        }
+        fs::remove_dir_all(&self.dir)
+            .with_context(|| format!("Failed to delete {}", self.dir.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{BaseConfig, Config};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Weights {
+        values: Vec<f64>,
+    }
+
+    impl ModelData for Weights {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Info {
+        epochs: usize,
+    }
+
+    impl CustomInfo for Info {
+        fn to_map(&self) -> Value {
+            json!({ "epochs": self.epochs })
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct TrainConfig {
+        model_name: String,
+        model_user: String,
+    }
+
+    impl Config for TrainConfig {}
+
+    impl BaseConfig for TrainConfig {
+        fn parameters(&self) -> HashMap<String, Value> {
+            HashMap::from([("modelName".to_string(), json!(self.model_name))])
+        }
+    }
+
+    impl ModelConfig for TrainConfig {
+        fn model_name(&self) -> &str {
+            &self.model_name
+        }
+
+        fn model_user(&self) -> &str {
+            &self.model_user
+        }
+    }
+
+    fn store_location(test: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("gds-model-file-{test}-{}", uuid::Uuid::new_v4()))
+    }
+
+    fn model(name: &str) -> Model<Weights, TrainConfig, Info> {
+        Model::new(
+            "alice".to_string(),
+            name.to_string(),
+            "Dummy".to_string(),
+            GraphSchema::empty(),
+            Some(Weights {
+                values: vec![0.5, -1.5],
+            }),
+            TrainConfig {
+                model_name: name.to_string(),
+                model_user: "alice".to_string(),
+            },
+            "0.0-test".to_string(),
+            Info { epochs: 3 },
+        )
+    }
+
+    #[test]
+    fn write_then_read_round_trips() {
+        let root = store_location("round-trip");
+        let original = model("m1");
+
+        let dir = ModelFileWriter::write(&original, &root).unwrap();
+        let loaded: Model<Weights, TrainConfig, Info> = ModelFileReader::new(&dir).read().unwrap();
+
+        assert_eq!(loaded.data(), original.data());
+        assert_eq!(loaded.algo_type(), "Dummy");
+        assert_eq!(loaded.custom_info().epochs, 3);
+        assert_eq!(loaded.creation_time(), original.creation_time());
+        assert!(loaded.is_stored());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn list_and_delete() {
+        let root = store_location("list");
+        ModelFileWriter::write(&model("b"), &root).unwrap();
+        ModelFileWriter::write(&model("a"), &root).unwrap();
+
+        let names: Vec<_> = ModelFileReader::list(&root)
+            .unwrap()
+            .into_iter()
+            .map(|header| header.name)
+            .collect();
+        assert_eq!(names, vec!["a", "b"]);
+
+        ModelFileReader::for_model(&root, "alice", "a")
+            .unwrap()
+            .delete()
+            .unwrap();
+        assert_eq!(ModelFileReader::list(&root).unwrap().len(), 1);
+        assert!(ModelFileReader::for_model(&root, "alice", "a")
+            .unwrap()
+            .delete()
+            .is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn rejects_other_format_versions() {
+        let root = store_location("version");
+        let dir = ModelFileWriter::write(&model("m1"), &root).unwrap();
+
+        let mut header: Value =
+            serde_json::from_slice(&fs::read(dir.join(META_DATA_FILE)).unwrap()).unwrap();
+        for version in [1, MODEL_FORMAT_VERSION + 1] {
+            header["formatVersion"] = json!(version);
+            fs::write(dir.join(META_DATA_FILE), header.to_string()).unwrap();
+
+            let err = ModelFileReader::new(&dir)
+                .read::<Weights, TrainConfig, Info>()
+                .unwrap_err();
+            assert!(err
+                .to_string()
+                .contains(&format!("unsupported model format version {version}")));
+        }
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn rejects_names_that_escape_the_store() {
+        let root = store_location("escape");
+        for name in ["../../x", "a/b", "..", ".", "/abs", "a\\b", ""] {
+            assert!(model_directory(&root, "alice", name).is_err(), "{name}");
+            assert!(model_directory(&root, name, "m1").is_err(), "{name}");
+            assert!(ModelFileReader::for_model(&root, "alice", name).is_err());
+        }
+        assert!(ModelFileWriter::write(&model("../escaped"), &root).is_err());
+        assert!(!root.exists());
+        assert_eq!(
+            model_directory(&root, "alice", "m1.v2").unwrap(),
+            root.join("alice").join("m1.v2")
+        );
+    }
+
+    #[test]
+    fn list_skips_corrupt_headers() {
+        let root = store_location("corrupt");
+        ModelFileWriter::write(&model("good"), &root).unwrap();
+        let bad = ModelFileWriter::write(&model("bad"), &root).unwrap();
+        fs::write(bad.join(META_DATA_FILE), b"{ not json").unwrap();
+
+        let names: Vec<_> = ModelFileReader::list(&root)
+            .unwrap()
+            .into_iter()
+            .map(|header| header.name)
+            .collect();
+        assert_eq!(names, vec!["good"]);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
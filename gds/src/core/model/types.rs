@@ -91,10 +91,44 @@ where
         }
     }
 
+    /// Restores a persisted model, keeping its original creation time and sharing.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn restore(
+        creator: String,
+        shared_with: Vec<String>,
+        name: String,
+        algo_type: String,
+        graph_schema: GraphSchema,
+        data: Option<D>,
+        train_config: C,
+        creation_time: DateTime<Utc>,
+        gds_version: String,
+        custom_info: I,
+        file_location: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            creator,
+            shared_with,
+            name,
+            algo_type,
+            graph_schema,
+            data,
+            train_config,
+            creation_time,
+            gds_version,
+            custom_info,
+            file_location,
+        }
+    }
+
     pub fn creator(&self) -> &str {
         &self.creator
     }
 
+    pub fn shared_with(&self) -> &[String] {
+        &self.shared_with
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -119,6 +153,10 @@ where
         self.creation_time
     }
 
+    pub fn gds_version(&self) -> &str {
+        &self.gds_version
+    }
+
     pub fn custom_info(&self) -> &I {
         &self.custom_info
     }
@@ -0,0 +1,242 @@
+//! GCN aggregation function for ML in GDS.
+//!
+//! Graph convolution as in Kipf & Welling, "Semi-Supervised Classification with
+//! Graph Convolutional Networks" (2017), restricted to a sampled batch subgraph.
+
+use crate::ml::core::dimensions::{COLUMNS_INDEX, ROWS_INDEX};
+use crate::ml::core::subgraph::BatchNeighbors;
+use crate::ml::core::AbstractVariable;
+use crate::ml::core::ComputationContext;
+use crate::ml::core::{Matrix, Tensor};
+use crate::ml::core::{Variable, VariableRef};
+use std::fmt;
+
+/// Symmetrically normalized sum over each batch node and its neighbors.
+///
+/// Every batch node receives a self loop. A batch node `i` with `d_i` sampled neighbors
+/// (plus itself) aggregates `h_j * w_ij / sqrt(d_i * s_j)` where `s_j` counts how often
+/// `j` is aggregated within the batch, the degree of `j` in the sampled block.
+pub struct GcnAggregate {
+    base: AbstractVariable,
+    sub_graph: Box<dyn BatchNeighbors>,
+}
+
+impl GcnAggregate {
+    pub fn new(parent: Box<dyn Variable>, sub_graph: Box<dyn BatchNeighbors>) -> Self {
+        Self::new_ref(parent.into(), sub_graph)
+    }
+
+    /// Ref-based constructor for DAG-safe graph building.
+    pub fn new_ref(parent: VariableRef, sub_graph: Box<dyn BatchNeighbors>) -> Self {
+        assert!(
+            parent.dimension(ROWS_INDEX) >= sub_graph.node_count(),
+            "Expecting a row for each node in the subgraph"
+        );
+
+        let dimensions = vec![sub_graph.batch_size(), parent.dimension(COLUMNS_INDEX)];
+        let base = AbstractVariable::with_gradient_requirement(vec![parent], dimensions, true);
+
+        Self { base, sub_graph }
+    }
+
+    fn parent(&self) -> &dyn Variable {
+        self.base.parents()[0].as_ref()
+    }
+
+    /// Normalization coefficients `(batch index, source node, coefficient)` for all
+    /// aggregated pairs, including the self loops.
+    fn coefficients(&self) -> Vec<(usize, usize, f64)> {
+        let batch_ids = self.sub_graph.batch_ids();
+
+        let mut source_degrees = vec![0usize; self.sub_graph.node_count()];
+        for &batch_node_id in batch_ids {
+            source_degrees[batch_node_id] += 1;
+            for &neighbor in self.sub_graph.neighbors(batch_node_id) {
+                source_degrees[neighbor] += 1;
+            }
+        }
+
+        let mut coefficients = Vec::new();
+        for (batch_idx, &batch_node_id) in batch_ids.iter().enumerate() {
+            let neighbors = self.sub_graph.neighbors(batch_node_id);
+            let target_degree = (neighbors.len() + 1) as f64;
+
+            let self_norm = (target_degree * source_degrees[batch_node_id] as f64).sqrt();
+            coefficients.push((batch_idx, batch_node_id, 1.0 / self_norm));
+
+            for &neighbor in neighbors {
+                let relationship_weight =
+                    self.sub_graph.relationship_weight(batch_node_id, neighbor);
+                let norm = (target_degree * source_degrees[neighbor] as f64).sqrt();
+                coefficients.push((batch_idx, neighbor, relationship_weight / norm));
+            }
+        }
+        coefficients
+    }
+}
+
+impl Variable for GcnAggregate {
+    fn apply(&self, ctx: &ComputationContext) -> Box<dyn Tensor> {
+        let parent_tensor = ctx.data(self.parent()).expect("Parent data not computed");
+        let parent_data = parent_tensor
+            .as_any()
+            .downcast_ref::<Matrix>()
+            .expect("Parent must be Matrix");
+
+        let cols = parent_data.cols();
+        let mut result = Matrix::create(0.0, self.sub_graph.batch_size(), cols);
+
+        for (batch_idx, source, coefficient) in self.coefficients() {
+            for col in 0..cols {
+                result.add_data_at(
+                    batch_idx,
+                    col,
+                    coefficient * parent_data.data_at(source, col),
+                );
+            }
+        }
+
+        Box::new(result)
+    }
+
+    fn gradient(&self, parent: &dyn Variable, ctx: &ComputationContext) -> Box<dyn Tensor> {
+        assert!(
+            std::ptr::eq(parent, self.parent()),
+            "Gradient requested for unknown parent"
+        );
+
+        let gradient_tensor = ctx.gradient(self).expect("Gradient not computed");
+        let self_gradient = gradient_tensor
+            .as_any()
+            .downcast_ref::<Matrix>()
+            .expect("Gradient must be Matrix");
+
+        let parent_tensor = ctx.data(self.parent()).expect("Parent data not computed");
+        let parent_data = parent_tensor
+            .as_any()
+            .downcast_ref::<Matrix>()
+            .expect("Parent must be Matrix");
+
+        let cols = parent_data.cols();
+        let mut result = Matrix::with_dimensions(parent_data.rows(), cols);
+
+        for (batch_idx, source, coefficient) in self.coefficients() {
+            for col in 0..cols {
+                result.add_data_at(
+                    source,
+                    col,
+                    coefficient * self_gradient.data_at(batch_idx, col),
+                );
+            }
+        }
+
+        Box::new(result)
+    }
+
+    fn require_gradient(&self) -> bool {
+        self.base.require_gradient()
+    }
+
+    fn parents(&self) -> &[VariableRef] {
+        self.base.parents()
+    }
+
+    fn dimensions(&self) -> &[usize] {
+        self.base.dimensions()
+    }
+}
+
+impl fmt::Display for GcnAggregate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GcnAggregate")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::core::functions::{L2NormSquared, Weights};
+    use crate::ml::core::relationship_weights::UNWEIGHTED;
+    use crate::ml::core::subgraph::SubGraph;
+    use crate::ml::core::Scalar;
+    use std::sync::Arc;
+
+    fn sub_graph() -> SubGraph {
+        SubGraph::new(
+            vec![0, 1],
+            vec![10, 11, 12],
+            vec![vec![1, 2], vec![2]],
+            Arc::new(UNWEIGHTED),
+            false,
+        )
+    }
+
+    #[test]
+    fn normalizes_by_target_and_source_degree() {
+        let features = Arc::new(Weights::new(Box::new(Matrix::new(
+            vec![1.0, 2.0, 4.0],
+            3,
+            1,
+        ))));
+        let aggregate = GcnAggregate::new_ref(features, Box::new(sub_graph()));
+
+        let ctx = ComputationContext::new();
+        let result = ctx.forward(&aggregate);
+        let result = result.as_any().downcast_ref::<Matrix>().unwrap();
+
+        // source degrees: node 0 -> 1, node 1 -> 2, node 2 -> 2
+        let expected_0 = 1.0 / (3.0f64).sqrt() + 2.0 / 6.0f64.sqrt() + 4.0 / 6.0f64.sqrt();
+        let expected_1 = 2.0 / 4.0f64.sqrt() + 4.0 / 4.0f64.sqrt();
+        assert!((result.data_at(0, 0) - expected_0).abs() < 1e-12);
+        assert!((result.data_at(1, 0) - expected_1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gradient_matches_finite_differences() {
+        let features = Arc::new(Weights::new(Box::new(Matrix::new(
+            vec![0.5, -1.0, 2.0, 0.3, -0.7, 1.5],
+            3,
+            2,
+        ))));
+        let aggregate: VariableRef = Arc::new(GcnAggregate::new_ref(
+            Arc::clone(&features) as VariableRef,
+            Box::new(sub_graph()),
+        ));
+        let loss = L2NormSquared::new_ref(aggregate);
+
+        let ctx = ComputationContext::new();
+        ctx.forward(&loss);
+        ctx.backward(&loss);
+        let gradient = ctx.gradient(features.as_ref()).unwrap();
+        let gradient = gradient.as_any().downcast_ref::<Matrix>().unwrap().clone();
+
+        let loss_value = || {
+            let ctx = ComputationContext::new();
+            let value = ctx.forward(&loss);
+            value.as_any().downcast_ref::<Scalar>().unwrap().value()
+        };
+
+        let epsilon = 1e-6;
+        for index in 0..6 {
+            let original = features.borrow_matrix().data_at_flat(index);
+            features
+                .borrow_matrix_mut()
+                .set_data_at_flat(index, original + epsilon);
+            let plus = loss_value();
+            features
+                .borrow_matrix_mut()
+                .set_data_at_flat(index, original - epsilon);
+            let minus = loss_value();
+            features
+                .borrow_matrix_mut()
+                .set_data_at_flat(index, original);
+
+            let numeric = (plus - minus) / (2.0 * epsilon);
+            assert!(
+                (numeric - gradient.data_at_flat(index)).abs() < 1e-5,
+                "index {index}: {numeric} vs {}",
+                gradient.data_at_flat(index)
+            );
+        }
+    }
+}
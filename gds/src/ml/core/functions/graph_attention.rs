@@ -0,0 +1,367 @@
+//! Graph attention function for ML in GDS.
+//!
+//! A single attention head as in Veličković et al., "Graph Attention Networks" (2018),
+//! restricted to a sampled batch subgraph.
+
+use crate::ml::core::dimensions::{COLUMNS_INDEX, ROWS_INDEX};
+use crate::ml::core::subgraph::BatchNeighbors;
+use crate::ml::core::AbstractVariable;
+use crate::ml::core::ComputationContext;
+use crate::ml::core::{Matrix, Tensor};
+use crate::ml::core::{Variable, VariableRef};
+use std::fmt;
+
+/// Attention-weighted sum over each batch node and its neighbors.
+///
+/// The first parent holds the projected representations `z` of all subgraph nodes, the
+/// second parent is the `1 x 2d` attention vector `a = [a_self | a_neighbor]`. A batch
+/// node `i` scores each node `j` of its neighborhood (itself included) with
+/// `LeakyReLU(a_self . z_i + a_neighbor . z_j)`, normalizes the scores with a softmax and
+/// aggregates `sum_j alpha_ij * z_j`.
+pub struct GraphAttention {
+    base: AbstractVariable,
+    sub_graph: Box<dyn BatchNeighbors>,
+}
+
+impl GraphAttention {
+    pub const NEGATIVE_SLOPE: f64 = 0.2;
+
+    pub fn new(
+        projected: Box<dyn Variable>,
+        attention: Box<dyn Variable>,
+        sub_graph: Box<dyn BatchNeighbors>,
+    ) -> Self {
+        Self::new_ref(projected.into(), attention.into(), sub_graph)
+    }
+
+    /// Ref-based constructor for DAG-safe graph building.
+    pub fn new_ref(
+        projected: VariableRef,
+        attention: VariableRef,
+        sub_graph: Box<dyn BatchNeighbors>,
+    ) -> Self {
+        assert!(
+            projected.dimension(ROWS_INDEX) >= sub_graph.node_count(),
+            "Expecting a row for each node in the subgraph"
+        );
+        assert_eq!(
+            attention.dimension(COLUMNS_INDEX),
+            2 * projected.dimension(COLUMNS_INDEX),
+            "Attention vector must span the self and neighbor representation"
+        );
+
+        let dimensions = vec![sub_graph.batch_size(), projected.dimension(COLUMNS_INDEX)];
+        let base = AbstractVariable::with_gradient_requirement(
+            vec![projected, attention],
+            dimensions,
+            true,
+        );
+
+        Self { base, sub_graph }
+    }
+
+    fn projected(&self) -> &dyn Variable {
+        self.base.parents()[0].as_ref()
+    }
+
+    fn attention(&self) -> &dyn Variable {
+        self.base.parents()[1].as_ref()
+    }
+
+    /// The aggregated nodes of a batch node, the node itself first.
+    fn neighborhood(&self, batch_node_id: usize) -> Vec<usize> {
+        std::iter::once(batch_node_id)
+            .chain(self.sub_graph.neighbors(batch_node_id).iter().copied())
+            .collect()
+    }
+
+    /// Pre-activation scores and the normalized attention coefficients of a neighborhood.
+    fn coefficients(
+        z: &Matrix,
+        attention: &Matrix,
+        batch_node_id: usize,
+        neighborhood: &[usize],
+    ) -> (Vec<f64>, Vec<f64>) {
+        let dimension = z.cols();
+        let self_score: f64 = (0..dimension)
+            .map(|col| attention.data_at(0, col) * z.data_at(batch_node_id, col))
+            .sum();
+
+        let scores: Vec<f64> = neighborhood
+            .iter()
+            .map(|&node| {
+                self_score
+                    + (0..dimension)
+                        .map(|col| attention.data_at(0, dimension + col) * z.data_at(node, col))
+                        .sum::<f64>()
+            })
+            .collect();
+
+        let activated: Vec<f64> = scores.iter().map(|&score| leaky_relu(score)).collect();
+        let max = activated.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let exponentials: Vec<f64> = activated.iter().map(|&value| (value - max).exp()).collect();
+        let total: f64 = exponentials.iter().sum();
+        let alphas = exponentials.iter().map(|&value| value / total).collect();
+
+        (scores, alphas)
+    }
+
+    fn parent_data(&self, ctx: &ComputationContext) -> (Matrix, Matrix) {
+        let projected = ctx
+            .data(self.projected())
+            .expect("Projected data not computed");
+        let attention = ctx
+            .data(self.attention())
+            .expect("Attention data not computed");
+        (
+            projected
+                .as_any()
+                .downcast_ref::<Matrix>()
+                .expect("Projected must be Matrix")
+                .clone(),
+            attention
+                .as_any()
+                .downcast_ref::<Matrix>()
+                .expect("Attention must be Matrix")
+                .clone(),
+        )
+    }
+
+    /// Gradients with respect to the projected representations and the attention vector.
+    fn gradients(&self, ctx: &ComputationContext) -> (Matrix, Matrix) {
+        let gradient_tensor = ctx.gradient(self).expect("Gradient not computed");
+        let self_gradient = gradient_tensor
+            .as_any()
+            .downcast_ref::<Matrix>()
+            .expect("Gradient must be Matrix");
+        let (z, attention) = self.parent_data(ctx);
+
+        let dimension = z.cols();
+        let mut z_gradient = Matrix::with_dimensions(z.rows(), dimension);
+        let mut attention_gradient = Matrix::with_dimensions(1, 2 * dimension);
+
+        for (batch_idx, &batch_node_id) in self.sub_graph.batch_ids().iter().enumerate() {
+            let neighborhood = self.neighborhood(batch_node_id);
+            let (scores, alphas) = Self::coefficients(&z, &attention, batch_node_id, &neighborhood);
+            let upstream = self_gradient.row(batch_idx);
+
+            // d out / d z_j through the weighted sum
+            for (&node, &alpha) in neighborhood.iter().zip(&alphas) {
+                for (col, &gradient) in upstream.iter().enumerate() {
+                    z_gradient.add_data_at(node, col, alpha * gradient);
+                }
+            }
+
+            // through the softmax and the leaky ReLU back to the raw scores
+            let alpha_gradients: Vec<f64> = neighborhood
+                .iter()
+                .map(|&node| {
+                    upstream
+                        .iter()
+                        .enumerate()
+                        .map(|(col, &gradient)| gradient * z.data_at(node, col))
+                        .sum()
+                })
+                .collect();
+            let expected: f64 = alphas
+                .iter()
+                .zip(&alpha_gradients)
+                .map(|(alpha, gradient)| alpha * gradient)
+                .sum();
+
+            for (position, &node) in neighborhood.iter().enumerate() {
+                let score_gradient = alphas[position]
+                    * (alpha_gradients[position] - expected)
+                    * leaky_relu_derivative(scores[position]);
+
+                for col in 0..dimension {
+                    attention_gradient.add_data_at(
+                        0,
+                        col,
+                        score_gradient * z.data_at(batch_node_id, col),
+                    );
+                    attention_gradient.add_data_at(
+                        0,
+                        dimension + col,
+                        score_gradient * z.data_at(node, col),
+                    );
+                    z_gradient.add_data_at(
+                        batch_node_id,
+                        col,
+                        score_gradient * attention.data_at(0, col),
+                    );
+                    z_gradient.add_data_at(
+                        node,
+                        col,
+                        score_gradient * attention.data_at(0, dimension + col),
+                    );
+                }
+            }
+        }
+
+        (z_gradient, attention_gradient)
+    }
+}
+
+fn leaky_relu(value: f64) -> f64 {
+    if value > 0.0 {
+        value
+    } else {
+        GraphAttention::NEGATIVE_SLOPE * value
+    }
+}
+
+fn leaky_relu_derivative(value: f64) -> f64 {
+    if value > 0.0 {
+        1.0
+    } else {
+        GraphAttention::NEGATIVE_SLOPE
+    }
+}
+
+impl Variable for GraphAttention {
+    fn apply(&self, ctx: &ComputationContext) -> Box<dyn Tensor> {
+        let (z, attention) = self.parent_data(ctx);
+        let dimension = z.cols();
+        let mut result = Matrix::create(0.0, self.sub_graph.batch_size(), dimension);
+
+        for (batch_idx, &batch_node_id) in self.sub_graph.batch_ids().iter().enumerate() {
+            let neighborhood = self.neighborhood(batch_node_id);
+            let (_, alphas) = Self::coefficients(&z, &attention, batch_node_id, &neighborhood);
+            for (&node, &alpha) in neighborhood.iter().zip(&alphas) {
+                for col in 0..dimension {
+                    result.add_data_at(batch_idx, col, alpha * z.data_at(node, col));
+                }
+            }
+        }
+
+        Box::new(result)
+    }
+
+    fn gradient(&self, parent: &dyn Variable, ctx: &ComputationContext) -> Box<dyn Tensor> {
+        let (z_gradient, attention_gradient) = self.gradients(ctx);
+        if std::ptr::eq(parent, self.projected()) {
+            Box::new(z_gradient)
+        } else if std::ptr::eq(parent, self.attention()) {
+            Box::new(attention_gradient)
+        } else {
+            panic!("Gradient requested for unknown parent")
+        }
+    }
+
+    fn require_gradient(&self) -> bool {
+        self.base.require_gradient()
+    }
+
+    fn parents(&self) -> &[VariableRef] {
+        self.base.parents()
+    }
+
+    fn dimensions(&self) -> &[usize] {
+        self.base.dimensions()
+    }
+}
+
+impl fmt::Display for GraphAttention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GraphAttention")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::core::functions::{L2NormSquared, Weights};
+    use crate::ml::core::relationship_weights::UNWEIGHTED;
+    use crate::ml::core::subgraph::SubGraph;
+    use crate::ml::core::Scalar;
+    use std::sync::Arc;
+
+    fn sub_graph() -> SubGraph {
+        SubGraph::new(
+            vec![0, 1],
+            vec![10, 11, 12, 13],
+            vec![vec![1, 2, 3], vec![3]],
+            Arc::new(UNWEIGHTED),
+            false,
+        )
+    }
+
+    fn assert_gradient_matches(weights: &Arc<Weights>, loss: &dyn Variable, size: usize) {
+        let ctx = ComputationContext::new();
+        ctx.forward(loss);
+        ctx.backward(loss);
+        let gradient = ctx.gradient(weights.as_ref()).unwrap();
+        let gradient = gradient.as_any().downcast_ref::<Matrix>().unwrap().clone();
+
+        let loss_value = || {
+            let ctx = ComputationContext::new();
+            let value = ctx.forward(loss);
+            value.as_any().downcast_ref::<Scalar>().unwrap().value()
+        };
+
+        let epsilon = 1e-6;
+        for index in 0..size {
+            let original = weights.borrow_matrix().data_at_flat(index);
+            weights
+                .borrow_matrix_mut()
+                .set_data_at_flat(index, original + epsilon);
+            let plus = loss_value();
+            weights
+                .borrow_matrix_mut()
+                .set_data_at_flat(index, original - epsilon);
+            let minus = loss_value();
+            weights
+                .borrow_matrix_mut()
+                .set_data_at_flat(index, original);
+
+            let numeric = (plus - minus) / (2.0 * epsilon);
+            assert!(
+                (numeric - gradient.data_at_flat(index)).abs() < 1e-5,
+                "index {index}: {numeric} vs {}",
+                gradient.data_at_flat(index)
+            );
+        }
+    }
+
+    #[test]
+    fn uniform_attention_averages_the_neighborhood() {
+        let projected = Arc::new(Weights::new(Box::new(Matrix::new(
+            vec![1.0, 2.0, 3.0, 6.0],
+            4,
+            1,
+        ))));
+        let attention = Arc::new(Weights::new(Box::new(Matrix::new(vec![0.0, 0.0], 1, 2))));
+        let aggregated = GraphAttention::new_ref(projected, attention, Box::new(sub_graph()));
+
+        let ctx = ComputationContext::new();
+        let result = ctx.forward(&aggregated);
+        let result = result.as_any().downcast_ref::<Matrix>().unwrap();
+
+        assert!((result.data_at(0, 0) - 3.0).abs() < 1e-12);
+        assert!((result.data_at(1, 0) - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gradients_match_finite_differences() {
+        let projected = Arc::new(Weights::new(Box::new(Matrix::new(
+            vec![0.5, -1.0, 2.0, 0.3, -0.7, 1.5, 0.1, 0.9],
+            4,
+            2,
+        ))));
+        let attention = Arc::new(Weights::new(Box::new(Matrix::new(
+            vec![0.4, -0.3, 0.8, 0.2],
+            1,
+            4,
+        ))));
+        let aggregated: VariableRef = Arc::new(GraphAttention::new_ref(
+            Arc::clone(&projected) as VariableRef,
+            Arc::clone(&attention) as VariableRef,
+            Box::new(sub_graph()),
+        ));
+        let loss = L2NormSquared::new_ref(aggregated);
+
+        assert_gradient_matches(&projected, &loss, 8);
+        assert_gradient_matches(&attention, &loss, 4);
+    }
+}
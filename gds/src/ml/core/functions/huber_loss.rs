@@ -0,0 +1,197 @@
+//! Huber loss function for ML in GDS.
+//!
+//! - **Formula**: `L = (1/n) * Σ h(prediction - target)` where
+//!   `h(r) = r² / 2` for `|r| <= δ` and `h(r) = δ * (|r| - δ / 2)` otherwise
+//! - **Why Important**: Quadratic for small errors like MSE, linear for large ones,
+//!   so outliers pull the fit far less
+//! - **Gradient**: `∇L = (1/n) * clip(prediction - target, -δ, δ)` (scaled by self gradient)
+
+use crate::ml::core::dimensions;
+use crate::ml::core::AbstractVariable;
+use crate::ml::core::ComputationContext;
+use crate::ml::core::{Matrix, Scalar, Tensor, Vector};
+use crate::ml::core::{Variable, VariableRef};
+use std::fmt;
+
+/// Mean Huber loss between predictions and targets.
+///
+/// Uses composition pattern: VariableBase holds parents [predictions, targets].
+pub struct HuberLoss {
+    base: AbstractVariable,
+    delta: f64,
+}
+
+impl HuberLoss {
+    pub fn new(predictions: Box<dyn Variable>, targets: Box<dyn Variable>, delta: f64) -> Self {
+        Self::new_ref(predictions.into(), targets.into(), delta)
+    }
+
+    pub fn new_ref(predictions: VariableRef, targets: VariableRef, delta: f64) -> Self {
+        Self::validate_dimensions(predictions.as_ref(), targets.as_ref());
+        assert!(delta > 0.0, "Huber delta must be > 0, got {delta}");
+
+        let parents: Vec<VariableRef> = vec![predictions, targets];
+        let base = AbstractVariable::new(parents, dimensions::scalar());
+
+        Self { base, delta }
+    }
+
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+
+    /// Helper to access predictions parent (index 0)
+    fn predictions(&self) -> &dyn Variable {
+        self.base.parents()[0].as_ref()
+    }
+
+    /// Helper to access targets parent (index 1)
+    fn targets(&self) -> &dyn Variable {
+        self.base.parents()[1].as_ref()
+    }
+
+    fn validate_dimensions(predictions: &dyn Variable, targets: &dyn Variable) {
+        let pred_size = dimensions::total_size(predictions.dimensions());
+        let target_size = dimensions::total_size(targets.dimensions());
+
+        if pred_size != target_size {
+            panic!(
+                "Targets and predictions must be of equal size. Got predictions: {}, targets: {}",
+                dimensions::render(predictions.dimensions()),
+                dimensions::render(targets.dimensions())
+            );
+        }
+    }
+}
+
+impl Variable for HuberLoss {
+    fn apply(&self, ctx: &ComputationContext) -> Box<dyn Tensor> {
+        let predicted_data = ctx
+            .data(self.predictions())
+            .expect("Predictions not computed");
+
+        let target_data = ctx.data(self.targets()).expect("Targets not computed");
+
+        let length = predicted_data.total_size();
+        let mut sum = 0.0;
+
+        for i in 0..length {
+            let error = (predicted_data.data()[i] - target_data.data()[i]).abs();
+            sum += if error <= self.delta {
+                0.5 * error * error
+            } else {
+                self.delta * (error - 0.5 * self.delta)
+            };
+        }
+
+        if !sum.is_finite() {
+            return Box::new(Scalar::new(f64::MAX));
+        }
+
+        Box::new(Scalar::new(sum / length as f64))
+    }
+
+    fn gradient(&self, parent: &dyn Variable, ctx: &ComputationContext) -> Box<dyn Tensor> {
+        let parent_data = ctx.data(parent).expect("Parent data not computed");
+
+        let other_parent_data = if std::ptr::eq(parent, self.predictions()) {
+            ctx.data(self.targets()).expect("Targets not computed")
+        } else {
+            ctx.data(self.predictions())
+                .expect("Predictions not computed")
+        };
+
+        let length = parent_data.data().len();
+
+        let self_gradient = ctx
+            .gradient(self)
+            .expect("Self gradient not computed")
+            .as_any()
+            .downcast_ref::<Scalar>()
+            .expect("Self gradient must be Scalar")
+            .value();
+
+        let scale = self_gradient / length as f64;
+
+        let gradient_data: Vec<f64> = (0..length)
+            .map(|i| {
+                let error = parent_data.data()[i] - other_parent_data.data()[i];
+                scale * error.clamp(-self.delta, self.delta)
+            })
+            .collect();
+
+        if let Some(matrix) = parent_data.as_any().downcast_ref::<Matrix>() {
+            Box::new(Matrix::new(gradient_data, matrix.rows(), matrix.cols())) as Box<dyn Tensor>
+        } else if parent_data.as_any().downcast_ref::<Vector>().is_some() {
+            Box::new(Vector::new(gradient_data)) as Box<dyn Tensor>
+        } else if parent_data.as_any().downcast_ref::<Scalar>().is_some() {
+            Box::new(Scalar::new(gradient_data[0])) as Box<dyn Tensor>
+        } else {
+            panic!("Unknown tensor type");
+        }
+    }
+
+    fn require_gradient(&self) -> bool {
+        self.base.require_gradient()
+    }
+
+    fn parents(&self) -> &[VariableRef] {
+        self.base.parents()
+    }
+
+    fn dimensions(&self) -> &[usize] {
+        self.base.dimensions()
+    }
+}
+
+impl fmt::Display for HuberLoss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HuberLoss(delta={})", self.delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::core::functions::{Constant, Weights};
+    use std::sync::Arc;
+
+    #[test]
+    fn is_quadratic_within_delta_and_linear_outside() {
+        let predictions: VariableRef = Arc::new(Constant::vector(vec![0.5, 3.0]));
+        let targets: VariableRef = Arc::new(Constant::vector(vec![0.0, 0.0]));
+        let loss = HuberLoss::new_ref(predictions, targets, 1.0);
+
+        let ctx = ComputationContext::new();
+        let value = ctx.forward(&loss);
+        let value = value.as_any().downcast_ref::<Scalar>().unwrap().value();
+
+        // (0.5 * 0.25 + 1.0 * (3.0 - 0.5)) / 2
+        assert!((value - 1.3125).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gradient_is_clipped_error() {
+        let predictions = Arc::new(Weights::new(Box::new(Vector::new(vec![0.5, 3.0, -4.0]))));
+        let targets: VariableRef = Arc::new(Constant::vector(vec![0.0, 0.0, 0.0]));
+        let loss = HuberLoss::new_ref(Arc::clone(&predictions) as VariableRef, targets, 1.0);
+
+        let ctx = ComputationContext::new();
+        ctx.forward(&loss);
+        ctx.backward(&loss);
+        let gradient = ctx.gradient(predictions.as_ref()).unwrap();
+
+        let expected = [0.5 / 3.0, 1.0 / 3.0, -1.0 / 3.0];
+        for (actual, expected) in gradient.data().iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "delta must be > 0")]
+    fn rejects_non_positive_delta() {
+        let predictions: VariableRef = Arc::new(Constant::vector(vec![1.0]));
+        let targets: VariableRef = Arc::new(Constant::vector(vec![1.0]));
+        let _ = HuberLoss::new_ref(predictions, targets, 0.0);
+    }
+}
@@ -5,6 +5,9 @@ pub mod element_sum;
 pub mod element_wise_max;
 pub mod ewise_add_matrix_scalar;
 pub mod focal_loss;
+pub mod gcn_aggregate;
+pub mod graph_attention;
+pub mod huber_loss;
 pub mod l2_norm_squared;
 pub mod labelwise_feature_projection;
 pub mod lazy_constant;
@@ -35,6 +38,9 @@ pub use element_sum::*;
 pub use element_wise_max::*;
 pub use ewise_add_matrix_scalar::*;
 pub use focal_loss::*;
+pub use gcn_aggregate::*;
+pub use graph_attention::*;
+pub use huber_loss::*;
 pub use l2_norm_squared::*;
 pub use labelwise_feature_projection::*;
 pub use lazy_constant::*;
@@ -0,0 +1,68 @@
+use super::Updater;
+use crate::ml::core::functions::Weights;
+use crate::ml::core::Tensor;
+use crate::task::memory::Estimate;
+use std::sync::Arc;
+
+/// Adagrad optimizer based on https://jmlr.org/papers/v12/duchi11a.html
+///
+/// Scales each step by the root of all squared gradients seen so far, so frequently
+/// updated weights receive smaller steps.
+pub struct AdagradOptimizer {
+    learning_rate: f64,
+    epsilon: f64,
+    weights: Vec<Arc<Weights>>,
+    squared_sums: Vec<Box<dyn Tensor>>,
+}
+
+impl AdagradOptimizer {
+    /// Calculate memory size in bytes for given dimensions
+    pub fn size_in_bytes(rows: usize, cols: usize) -> usize {
+        let term_size = Weights::size_in_bytes(rows, cols);
+        Estimate::size_of_instance("AdagradOptimizer")
+            + term_size // fields
+            + 2 * term_size // working memory: squared gradient, update
+    }
+
+    /// Create a new Adagrad optimizer
+    pub fn new(weights: Vec<Arc<Weights>>, learning_rate: f64) -> Self {
+        let squared_sums: Vec<Box<dyn Tensor>> = weights
+            .iter()
+            .map(|w| w.borrow().create_with_same_dimensions())
+            .collect();
+
+        Self {
+            learning_rate,
+            epsilon: 1e-10,
+            weights,
+            squared_sums,
+        }
+    }
+}
+
+impl Updater for AdagradOptimizer {
+    fn update(&mut self, context_local_weight_gradients: &[Box<dyn Tensor>]) {
+        for i in 0..self.weights.len() {
+            let gradient = context_local_weight_gradients[i].as_ref();
+
+            // G_t = G_t + g_t^2
+            let squared_gradient = gradient.map(|v| v * v);
+            self.squared_sums[i].add_inplace(squared_gradient.as_ref());
+
+            // theta_t = theta_t - lr * g_t / (sqrt(G_t) + epsilon)
+            let root = self.squared_sums[i].map(f64::sqrt);
+            let epsilon_tensor = root.ones_like().scalar_multiply(self.epsilon);
+            let root_inv = root.add(epsilon_tensor.as_ref()).map(|v| 1.0 / v);
+            let update = gradient
+                .scalar_multiply(-self.learning_rate)
+                .elementwise_product(root_inv.as_ref());
+
+            let mut weight = self.weights[i].borrow_mut();
+            weight.add_inplace(update.as_ref());
+        }
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+}
@@ -107,4 +107,8 @@ impl Updater for AdamOptimizer {
             weight.add_inplace(final_update.as_ref());
         }
     }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
 }
@@ -0,0 +1,114 @@
+use super::Updater;
+use crate::ml::core::functions::Weights;
+use crate::ml::core::Tensor;
+use crate::task::memory::Estimate;
+use std::sync::Arc;
+
+const CLIP_MAX: f64 = 5.0;
+const CLIP_MIN: f64 = -5.0;
+
+/// AdamW optimizer based on https://arxiv.org/abs/1711.05101
+///
+/// Identical to [`super::AdamOptimizer`], except that weight decay is applied directly to
+/// the weights instead of being folded into the gradient.
+pub struct AdamWOptimizer {
+    learning_rate: f64,
+    weight_decay: f64,
+    beta_1: f64,
+    beta_2: f64,
+    epsilon: f64,
+    weights: Vec<Arc<Weights>>,
+    momentum_terms: Vec<Box<dyn Tensor>>,
+    velocity_terms: Vec<Box<dyn Tensor>>,
+    iteration: usize,
+}
+
+impl AdamWOptimizer {
+    /// Calculate memory size in bytes for given dimensions
+    pub fn size_in_bytes(rows: usize, cols: usize) -> usize {
+        let term_size = Weights::size_in_bytes(rows, cols);
+        Estimate::size_of_instance("AdamWOptimizer")
+            + 2 * term_size // fields
+            + 3 * term_size // working memory: mCap, vCap, decay
+    }
+
+    /// Create a new AdamW optimizer
+    pub fn new(weights: Vec<Arc<Weights>>, learning_rate: f64, weight_decay: f64) -> Self {
+        let momentum_terms: Vec<Box<dyn Tensor>> = weights
+            .iter()
+            .map(|w| w.borrow().create_with_same_dimensions())
+            .collect();
+
+        let velocity_terms: Vec<Box<dyn Tensor>> = weights
+            .iter()
+            .map(|w| w.borrow().create_with_same_dimensions())
+            .collect();
+
+        Self {
+            learning_rate,
+            weight_decay,
+            beta_1: 0.9,
+            beta_2: 0.999,
+            epsilon: 1e-8,
+            weights,
+            momentum_terms,
+            velocity_terms,
+            iteration: 0,
+        }
+    }
+
+    /// Clip gradient values to avoid exploding gradients
+    fn clip(value: f64) -> f64 {
+        if value > CLIP_MAX {
+            CLIP_MAX
+        } else {
+            value.max(CLIP_MIN)
+        }
+    }
+}
+
+impl Updater for AdamWOptimizer {
+    fn update(&mut self, context_local_weight_gradients: &[Box<dyn Tensor>]) {
+        self.iteration += 1;
+
+        for i in 0..self.weights.len() {
+            let gradient = context_local_weight_gradients[i].as_ref();
+            let clipped_gradient = gradient.map(Self::clip);
+
+            // m_t = beta_1 * m_t + (1 - beta_1) * g_t
+            let mut new_momentum = self.momentum_terms[i].scalar_multiply(self.beta_1);
+            let scaled_gradient = clipped_gradient.scalar_multiply(1.0 - self.beta_1);
+            new_momentum.add_inplace(scaled_gradient.as_ref());
+            self.momentum_terms[i] = new_momentum;
+
+            // v_t = beta_2 * v_t + (1 - beta_2) * (g_t^2)
+            let mut new_velocity = self.velocity_terms[i].scalar_multiply(self.beta_2);
+            let squared_gradient = clipped_gradient.map(|v| v * v);
+            let scaled_squared = squared_gradient.scalar_multiply(1.0 - self.beta_2);
+            new_velocity.add_inplace(scaled_squared.as_ref());
+            self.velocity_terms[i] = new_velocity;
+
+            let m_cap = self.momentum_terms[i]
+                .scalar_multiply(1.0 / (1.0 - self.beta_1.powi(self.iteration as i32)));
+            let v_cap = self.velocity_terms[i]
+                .scalar_multiply(1.0 / (1.0 - self.beta_2.powi(self.iteration as i32)));
+
+            // adam step: -(alpha * m_cap) / (sqrt(v_cap) + epsilon)
+            let update = m_cap.scalar_multiply(-self.learning_rate);
+            let v_cap_sqrt = v_cap.map(f64::sqrt);
+            let epsilon_tensor = v_cap_sqrt.ones_like().scalar_multiply(self.epsilon);
+            let v_cap_inv = v_cap_sqrt.add(epsilon_tensor.as_ref()).map(|v| 1.0 / v);
+            let adam_update = update.elementwise_product(v_cap_inv.as_ref());
+
+            // theta_t = theta_t - alpha * lambda * theta_t + adam step
+            let mut weight = self.weights[i].borrow_mut();
+            let decay = weight.scalar_multiply(-self.learning_rate * self.weight_decay);
+            weight.add_inplace(decay.as_ref());
+            weight.add_inplace(adam_update.as_ref());
+        }
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+}
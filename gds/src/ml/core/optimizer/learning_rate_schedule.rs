@@ -0,0 +1,231 @@
+use super::Updater;
+use crate::config::validation::ConfigError;
+use crate::ml::core::Tensor;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// How the learning rate decays after warmup.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum LearningRateDecay {
+    /// Keep the base learning rate.
+    #[default]
+    Constant,
+    /// Multiply the learning rate by `gamma` every `step_size` updates.
+    Step { step_size: usize, gamma: f64 },
+    /// Anneal from the base learning rate to `min_learning_rate` over `decay_steps`
+    /// updates following half a cosine, as in SGDR (https://arxiv.org/abs/1608.03983).
+    Cosine {
+        decay_steps: usize,
+        min_learning_rate: f64,
+    },
+}
+
+impl LearningRateDecay {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LearningRateDecay::Constant => "constant",
+            LearningRateDecay::Step { .. } => "step",
+            LearningRateDecay::Cosine { .. } => "cosine",
+        }
+    }
+}
+
+/// Learning rate as a function of the number of performed updates.
+///
+/// The first `warmup_steps` updates ramp the learning rate linearly up to the base
+/// learning rate, after which `decay` takes over.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct LearningRateSchedule {
+    pub decay: LearningRateDecay,
+    pub warmup_steps: usize,
+}
+
+impl LearningRateSchedule {
+    pub fn constant() -> Self {
+        Self::default()
+    }
+
+    pub fn step(step_size: usize, gamma: f64) -> Self {
+        Self {
+            decay: LearningRateDecay::Step { step_size, gamma },
+            warmup_steps: 0,
+        }
+    }
+
+    pub fn cosine(decay_steps: usize, min_learning_rate: f64) -> Self {
+        Self {
+            decay: LearningRateDecay::Cosine {
+                decay_steps,
+                min_learning_rate,
+            },
+            warmup_steps: 0,
+        }
+    }
+
+    pub fn with_warmup(mut self, warmup_steps: usize) -> Self {
+        self.warmup_steps = warmup_steps;
+        self
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.warmup_steps == 0 && self.decay == LearningRateDecay::Constant
+    }
+
+    /// Learning rate for the update following `step` earlier updates.
+    pub fn learning_rate(&self, base_learning_rate: f64, step: usize) -> f64 {
+        if step < self.warmup_steps {
+            return base_learning_rate * (step + 1) as f64 / self.warmup_steps as f64;
+        }
+
+        let step = step - self.warmup_steps;
+        match self.decay {
+            LearningRateDecay::Constant => base_learning_rate,
+            LearningRateDecay::Step { step_size, gamma } => {
+                base_learning_rate * gamma.powi((step / step_size) as i32)
+            }
+            LearningRateDecay::Cosine {
+                decay_steps,
+                min_learning_rate,
+            } => {
+                let progress = step.min(decay_steps) as f64 / decay_steps as f64;
+                min_learning_rate
+                    + 0.5 * (base_learning_rate - min_learning_rate) * (1.0 + (PI * progress).cos())
+            }
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        match self.decay {
+            LearningRateDecay::Constant => {}
+            LearningRateDecay::Step { step_size, gamma } => {
+                if step_size == 0 {
+                    return Err(ConfigError::InvalidParameter {
+                        parameter: "stepSize".to_string(),
+                        reason: "stepSize must be > 0".to_string(),
+                    });
+                }
+                if gamma <= 0.0 || gamma > 1.0 {
+                    return Err(ConfigError::InvalidParameter {
+                        parameter: "decayRate".to_string(),
+                        reason: "decayRate must be in (0, 1]".to_string(),
+                    });
+                }
+            }
+            LearningRateDecay::Cosine {
+                decay_steps,
+                min_learning_rate,
+            } => {
+                if decay_steps == 0 {
+                    return Err(ConfigError::InvalidParameter {
+                        parameter: "decaySteps".to_string(),
+                        reason: "decaySteps must be > 0".to_string(),
+                    });
+                }
+                if min_learning_rate < 0.0 {
+                    return Err(ConfigError::InvalidParameter {
+                        parameter: "minLearningRate".to_string(),
+                        reason: "minLearningRate must be >= 0".to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sets the learning rate of the wrapped updater according to a schedule before
+/// each update.
+pub struct ScheduledUpdater {
+    updater: Box<dyn Updater>,
+    base_learning_rate: f64,
+    schedule: LearningRateSchedule,
+    step: usize,
+}
+
+impl ScheduledUpdater {
+    pub fn new(
+        updater: Box<dyn Updater>,
+        base_learning_rate: f64,
+        schedule: LearningRateSchedule,
+    ) -> Self {
+        Self {
+            updater,
+            base_learning_rate,
+            schedule,
+            step: 0,
+        }
+    }
+
+    /// Continue a schedule after `step` updates performed by an earlier updater.
+    pub fn starting_at(mut self, step: usize) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn step(&self) -> usize {
+        self.step
+    }
+}
+
+impl Updater for ScheduledUpdater {
+    fn update(&mut self, gradients: &[Box<dyn Tensor>]) {
+        let learning_rate = self
+            .schedule
+            .learning_rate(self.base_learning_rate, self.step);
+        self.updater.set_learning_rate(learning_rate);
+        self.updater.update(gradients);
+        self.step += 1;
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.base_learning_rate = learning_rate;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_schedule_keeps_learning_rate() {
+        let schedule = LearningRateSchedule::constant();
+        assert!(schedule.is_constant());
+        assert_eq!(schedule.learning_rate(0.1, 0), 0.1);
+        assert_eq!(schedule.learning_rate(0.1, 1000), 0.1);
+    }
+
+    #[test]
+    fn step_schedule_decays_every_step_size_updates() {
+        let schedule = LearningRateSchedule::step(10, 0.5);
+        assert_eq!(schedule.learning_rate(0.1, 9), 0.1);
+        assert_eq!(schedule.learning_rate(0.1, 10), 0.05);
+        assert_eq!(schedule.learning_rate(0.1, 25), 0.025);
+    }
+
+    #[test]
+    fn cosine_schedule_anneals_to_minimum() {
+        let schedule = LearningRateSchedule::cosine(100, 0.01);
+        assert!((schedule.learning_rate(0.1, 0) - 0.1).abs() < 1e-12);
+        assert!((schedule.learning_rate(0.1, 50) - 0.055).abs() < 1e-12);
+        assert!((schedule.learning_rate(0.1, 100) - 0.01).abs() < 1e-12);
+        assert!((schedule.learning_rate(0.1, 500) - 0.01).abs() < 1e-12);
+    }
+
+    #[test]
+    fn warmup_ramps_up_before_decay() {
+        let schedule = LearningRateSchedule::step(10, 0.5).with_warmup(4);
+        assert!(!schedule.is_constant());
+        assert!((schedule.learning_rate(0.1, 0) - 0.025).abs() < 1e-12);
+        assert!((schedule.learning_rate(0.1, 3) - 0.1).abs() < 1e-12);
+        assert!((schedule.learning_rate(0.1, 13) - 0.1).abs() < 1e-12);
+        assert!((schedule.learning_rate(0.1, 14) - 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rejects_invalid_decay_parameters() {
+        assert!(LearningRateSchedule::step(0, 0.5).validate().is_err());
+        assert!(LearningRateSchedule::step(5, 1.5).validate().is_err());
+        assert!(LearningRateSchedule::cosine(0, 0.0).validate().is_err());
+        assert!(LearningRateSchedule::cosine(10, 0.0).validate().is_ok());
+    }
+}
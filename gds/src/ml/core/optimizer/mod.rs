@@ -1,5 +1,17 @@
+pub mod adagrad_optimizer;
 pub mod adam_optimizer;
+pub mod adamw_optimizer;
+pub mod learning_rate_schedule;
+pub mod optimizer_config;
+pub mod rmsprop_optimizer;
+pub mod sgd_optimizer;
 pub mod updater;
 
+pub use adagrad_optimizer::*;
 pub use adam_optimizer::*;
+pub use adamw_optimizer::*;
+pub use learning_rate_schedule::*;
+pub use optimizer_config::*;
+pub use rmsprop_optimizer::*;
+pub use sgd_optimizer::*;
 pub use updater::*;
@@ -0,0 +1,383 @@
+use super::{
+    AdagradOptimizer, AdamOptimizer, AdamWOptimizer, LearningRateDecay, LearningRateSchedule,
+    RmsPropOptimizer, ScheduledUpdater, SgdOptimizer, Updater,
+};
+use crate::config::validation::ConfigError;
+use crate::ml::core::functions::Weights;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum OptimizerType {
+    #[default]
+    Adam,
+    AdamW,
+    Sgd,
+    RmsProp,
+    Adagrad,
+}
+
+impl OptimizerType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            OptimizerType::Adam => "Adam",
+            OptimizerType::AdamW => "AdamW",
+            OptimizerType::Sgd => "SGD",
+            OptimizerType::RmsProp => "RMSProp",
+            OptimizerType::Adagrad => "Adagrad",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "adam" => Ok(OptimizerType::Adam),
+            "adamw" => Ok(OptimizerType::AdamW),
+            "sgd" => Ok(OptimizerType::Sgd),
+            "rmsprop" => Ok(OptimizerType::RmsProp),
+            "adagrad" => Ok(OptimizerType::Adagrad),
+            _ => Err(format!(
+                "Unknown optimizer `{value}`. Expected one of Adam, AdamW, SGD, RMSProp, Adagrad."
+            )),
+        }
+    }
+}
+
+/// Which optimizer updates the weights, and how its learning rate evolves.
+///
+/// `momentum` and `nesterov` only apply to SGD, `weight_decay` only to AdamW.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OptimizerConfig {
+    pub optimizer: OptimizerType,
+    pub momentum: f64,
+    pub nesterov: bool,
+    pub weight_decay: f64,
+    pub schedule: LearningRateSchedule,
+}
+
+impl Default for OptimizerConfig {
+    fn default() -> Self {
+        Self {
+            optimizer: OptimizerType::Adam,
+            momentum: 0.0,
+            nesterov: false,
+            weight_decay: 0.01,
+            schedule: LearningRateSchedule::constant(),
+        }
+    }
+}
+
+impl OptimizerConfig {
+    pub fn adam() -> Self {
+        Self::default()
+    }
+
+    pub fn sgd(momentum: f64, nesterov: bool) -> Self {
+        Self {
+            optimizer: OptimizerType::Sgd,
+            momentum,
+            nesterov,
+            ..Self::default()
+        }
+    }
+
+    pub fn adamw(weight_decay: f64) -> Self {
+        Self {
+            optimizer: OptimizerType::AdamW,
+            weight_decay,
+            ..Self::default()
+        }
+    }
+
+    pub fn of(optimizer: OptimizerType) -> Self {
+        Self {
+            optimizer,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_schedule(mut self, schedule: LearningRateSchedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Create the configured updater for `weights`.
+    pub fn create_updater(
+        &self,
+        weights: Vec<Arc<Weights>>,
+        learning_rate: f64,
+    ) -> Box<dyn Updater> {
+        self.create_updater_at_step(weights, learning_rate, 0)
+    }
+
+    /// Create the configured updater, continuing the learning rate schedule after
+    /// `step` updates. Used by trainers that recreate their updater between epochs.
+    pub fn create_updater_at_step(
+        &self,
+        weights: Vec<Arc<Weights>>,
+        learning_rate: f64,
+        step: usize,
+    ) -> Box<dyn Updater> {
+        let optimizer: Box<dyn Updater> = match self.optimizer {
+            OptimizerType::Adam => Box::new(AdamOptimizer::new(weights, learning_rate)),
+            OptimizerType::AdamW => Box::new(AdamWOptimizer::new(
+                weights,
+                learning_rate,
+                self.weight_decay,
+            )),
+            OptimizerType::Sgd => Box::new(SgdOptimizer::with_momentum(
+                weights,
+                learning_rate,
+                self.momentum,
+                self.nesterov,
+            )),
+            OptimizerType::RmsProp => Box::new(RmsPropOptimizer::new(weights, learning_rate)),
+            OptimizerType::Adagrad => Box::new(AdagradOptimizer::new(weights, learning_rate)),
+        };
+
+        if self.schedule.is_constant() {
+            optimizer
+        } else {
+            Box::new(
+                ScheduledUpdater::new(optimizer, learning_rate, self.schedule).starting_at(step),
+            )
+        }
+    }
+
+    /// Memory of the optimizer state and working memory for one weight of the given size.
+    pub fn size_in_bytes(&self, rows: usize, cols: usize) -> usize {
+        match self.optimizer {
+            OptimizerType::Adam => AdamOptimizer::size_in_bytes(rows, cols),
+            OptimizerType::AdamW => AdamWOptimizer::size_in_bytes(rows, cols),
+            OptimizerType::Sgd => SgdOptimizer::size_in_bytes(rows, cols),
+            OptimizerType::RmsProp => RmsPropOptimizer::size_in_bytes(rows, cols),
+            OptimizerType::Adagrad => AdagradOptimizer::size_in_bytes(rows, cols),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !(0.0..1.0).contains(&self.momentum) {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "momentum".to_string(),
+                reason: "momentum must be in [0, 1)".to_string(),
+            });
+        }
+        if self.nesterov && (self.optimizer != OptimizerType::Sgd || self.momentum == 0.0) {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "nesterov".to_string(),
+                reason: "nesterov requires the SGD optimizer with momentum > 0".to_string(),
+            });
+        }
+        if self.weight_decay < 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "weightDecay".to_string(),
+                reason: "weightDecay must be >= 0".to_string(),
+            });
+        }
+        self.schedule.validate()
+    }
+
+    /// Parse the optimizer settings out of a user configuration map.
+    ///
+    /// Recognized keys: `optimizer`, `momentum`, `nesterov`, `weightDecay`,
+    /// `learningRateSchedule` (`constant`, `step`, `cosine`), `stepSize`, `decayRate`,
+    /// `decaySteps`, `minLearningRate` and `warmupSteps`. Snake case keys are accepted too.
+    pub fn from_map(map: &HashMap<String, serde_json::Value>) -> Result<Self, String> {
+        let mut config = Self::default();
+
+        if let Some(value) = string_value(map, &["optimizer"]) {
+            config.optimizer = OptimizerType::parse(&value)?;
+        }
+        if let Some(value) = f64_value(map, &["momentum"]) {
+            config.momentum = value;
+        }
+        if let Some(value) = map.get("nesterov").and_then(|value| value.as_bool()) {
+            config.nesterov = value;
+        }
+        if let Some(value) = f64_value(map, &["weightDecay", "weight_decay"]) {
+            config.weight_decay = value;
+        }
+
+        let schedule = string_value(map, &["learningRateSchedule", "learning_rate_schedule"])
+            .unwrap_or_else(|| "constant".to_string());
+        config.schedule.decay = match schedule.to_ascii_lowercase().as_str() {
+            "constant" => LearningRateDecay::Constant,
+            "step" => LearningRateDecay::Step {
+                step_size: usize_value(map, &["stepSize", "step_size"]).unwrap_or(10),
+                gamma: f64_value(map, &["decayRate", "decay_rate"]).unwrap_or(0.5),
+            },
+            "cosine" => LearningRateDecay::Cosine {
+                decay_steps: usize_value(map, &["decaySteps", "decay_steps"]).unwrap_or(100),
+                min_learning_rate: f64_value(map, &["minLearningRate", "min_learning_rate"])
+                    .unwrap_or(0.0),
+            },
+            _ => {
+                return Err(format!(
+                    "Unknown learning rate schedule `{schedule}`. Expected one of constant, step, cosine."
+                ))
+            }
+        };
+        if let Some(value) = usize_value(map, &["warmupSteps", "warmup_steps"]) {
+            config.schedule.warmup_steps = value;
+        }
+
+        Ok(config)
+    }
+
+    /// The settings relevant to the chosen optimizer and schedule, keyed like `from_map`.
+    pub fn to_map(&self) -> HashMap<String, serde_json::Value> {
+        let mut map = HashMap::new();
+        map.insert("optimizer".to_string(), self.optimizer.name().into());
+        match self.optimizer {
+            OptimizerType::Sgd => {
+                map.insert("momentum".to_string(), self.momentum.into());
+                map.insert("nesterov".to_string(), self.nesterov.into());
+            }
+            OptimizerType::AdamW => {
+                map.insert("weightDecay".to_string(), self.weight_decay.into());
+            }
+            _ => {}
+        }
+
+        map.insert(
+            "learningRateSchedule".to_string(),
+            self.schedule.decay.name().into(),
+        );
+        match self.schedule.decay {
+            LearningRateDecay::Constant => {}
+            LearningRateDecay::Step { step_size, gamma } => {
+                map.insert("stepSize".to_string(), step_size.into());
+                map.insert("decayRate".to_string(), gamma.into());
+            }
+            LearningRateDecay::Cosine {
+                decay_steps,
+                min_learning_rate,
+            } => {
+                map.insert("decaySteps".to_string(), decay_steps.into());
+                map.insert("minLearningRate".to_string(), min_learning_rate.into());
+            }
+        }
+        if self.schedule.warmup_steps > 0 {
+            map.insert("warmupSteps".to_string(), self.schedule.warmup_steps.into());
+        }
+        map
+    }
+}
+
+fn string_value(map: &HashMap<String, serde_json::Value>, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .find_map(|key| map.get(*key).and_then(|value| value.as_str()))
+        .map(str::to_string)
+}
+
+fn f64_value(map: &HashMap<String, serde_json::Value>, keys: &[&str]) -> Option<f64> {
+    keys.iter()
+        .find_map(|key| map.get(*key).and_then(|value| value.as_f64()))
+}
+
+fn usize_value(map: &HashMap<String, serde_json::Value>, keys: &[&str]) -> Option<usize> {
+    keys.iter()
+        .find_map(|key| map.get(*key).and_then(|value| value.as_u64()))
+        .map(|value| value as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::core::tensor::Vector;
+    use crate::ml::core::Tensor;
+
+    /// Minimize `||w||^2` starting at `(3, -2)` and return the final norm.
+    fn minimize_quadratic(config: &OptimizerConfig, learning_rate: f64) -> f64 {
+        let weights = Arc::new(Weights::new(Box::new(Vector::new(vec![3.0, -2.0]))));
+        let mut updater = config.create_updater(vec![Arc::clone(&weights)], learning_rate);
+
+        for _ in 0..200 {
+            let gradient = weights.snapshot().scalar_multiply(2.0);
+            updater.update(&[gradient]);
+        }
+
+        let snapshot = weights.snapshot();
+        snapshot.data().iter().map(|v| v * v).sum::<f64>().sqrt()
+    }
+
+    #[test]
+    fn every_optimizer_minimizes_a_quadratic() {
+        let initial_norm = 13.0f64.sqrt();
+        let configs = [
+            (OptimizerConfig::adam(), 0.1),
+            (OptimizerConfig::adamw(0.01), 0.1),
+            (OptimizerConfig::sgd(0.0, false), 0.05),
+            (OptimizerConfig::sgd(0.9, false), 0.01),
+            (OptimizerConfig::sgd(0.9, true), 0.01),
+            (OptimizerConfig::of(OptimizerType::RmsProp), 0.05),
+            (OptimizerConfig::of(OptimizerType::Adagrad), 0.5),
+        ];
+
+        for (config, learning_rate) in configs {
+            let norm = minimize_quadratic(&config, learning_rate);
+            assert!(
+                norm < 0.1 * initial_norm,
+                "{} did not converge: {norm}",
+                config.optimizer.name()
+            );
+        }
+    }
+
+    #[test]
+    fn schedule_is_applied_to_updates() {
+        // halving the learning rate after every update: 1 - 0.4 - 0.2 - 0.1
+        let config =
+            OptimizerConfig::sgd(0.0, false).with_schedule(LearningRateSchedule::step(1, 0.5));
+        let weights = Arc::new(Weights::new(Box::new(Vector::new(vec![1.0]))));
+        let mut updater = config.create_updater(vec![Arc::clone(&weights)], 0.4);
+
+        for _ in 0..3 {
+            updater.update(&[Box::new(Vector::new(vec![1.0])) as Box<dyn Tensor>]);
+        }
+        assert!((weights.snapshot().data()[0] - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn parses_optimizer_from_map() {
+        let map: HashMap<String, serde_json::Value> = serde_json::from_value(serde_json::json!({
+            "optimizer": "sgd",
+            "momentum": 0.9,
+            "nesterov": true,
+            "learningRateSchedule": "cosine",
+            "decaySteps": 50,
+            "warmupSteps": 5
+        }))
+        .unwrap();
+
+        let config = OptimizerConfig::from_map(&map).unwrap();
+        assert_eq!(config.optimizer, OptimizerType::Sgd);
+        assert_eq!(config.momentum, 0.9);
+        assert!(config.nesterov);
+        assert_eq!(
+            config.schedule,
+            LearningRateSchedule::cosine(50, 0.0).with_warmup(5)
+        );
+        assert!(config.validate().is_ok());
+
+        let round_trip: HashMap<String, serde_json::Value> = config.to_map();
+        assert_eq!(OptimizerConfig::from_map(&round_trip).unwrap(), config);
+    }
+
+    #[test]
+    fn rejects_unknown_optimizer_and_invalid_momentum() {
+        let map: HashMap<String, serde_json::Value> =
+            serde_json::from_value(serde_json::json!({ "optimizer": "lbfgs" })).unwrap();
+        assert!(OptimizerConfig::from_map(&map).is_err());
+
+        assert!(OptimizerConfig::sgd(1.0, false).validate().is_err());
+        assert!(OptimizerConfig::adam()
+            .with_schedule(LearningRateSchedule::step(0, 0.5))
+            .validate()
+            .is_err());
+        let mut nesterov_adam = OptimizerConfig::adam();
+        nesterov_adam.nesterov = true;
+        assert!(nesterov_adam.validate().is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use super::Updater;
+use crate::ml::core::functions::Weights;
+use crate::ml::core::Tensor;
+use crate::task::memory::Estimate;
+use std::sync::Arc;
+
+/// RMSProp optimizer as proposed by Hinton in the Coursera lecture "Neural Networks for
+/// Machine Learning" (lecture 6e).
+///
+/// Keeps a moving average of squared gradients and scales each step by its root.
+pub struct RmsPropOptimizer {
+    learning_rate: f64,
+    decay_rate: f64,
+    epsilon: f64,
+    weights: Vec<Arc<Weights>>,
+    square_averages: Vec<Box<dyn Tensor>>,
+}
+
+impl RmsPropOptimizer {
+    /// Calculate memory size in bytes for given dimensions
+    pub fn size_in_bytes(rows: usize, cols: usize) -> usize {
+        let term_size = Weights::size_in_bytes(rows, cols);
+        Estimate::size_of_instance("RmsPropOptimizer")
+            + term_size // fields
+            + 2 * term_size // working memory: squared gradient, update
+    }
+
+    /// Create a new RMSProp optimizer
+    pub fn new(weights: Vec<Arc<Weights>>, learning_rate: f64) -> Self {
+        let square_averages: Vec<Box<dyn Tensor>> = weights
+            .iter()
+            .map(|w| w.borrow().create_with_same_dimensions())
+            .collect();
+
+        Self {
+            learning_rate,
+            decay_rate: 0.9,
+            epsilon: 1e-8,
+            weights,
+            square_averages,
+        }
+    }
+}
+
+impl Updater for RmsPropOptimizer {
+    fn update(&mut self, context_local_weight_gradients: &[Box<dyn Tensor>]) {
+        for i in 0..self.weights.len() {
+            let gradient = context_local_weight_gradients[i].as_ref();
+
+            // s_t = rho * s_t + (1 - rho) * (g_t^2)
+            let mut new_average = self.square_averages[i].scalar_multiply(self.decay_rate);
+            let squared_gradient = gradient.map(|v| v * v);
+            new_average.add_inplace(
+                squared_gradient
+                    .scalar_multiply(1.0 - self.decay_rate)
+                    .as_ref(),
+            );
+            self.square_averages[i] = new_average;
+
+            // theta_t = theta_t - lr * g_t / (sqrt(s_t) + epsilon)
+            let root = self.square_averages[i].map(f64::sqrt);
+            let epsilon_tensor = root.ones_like().scalar_multiply(self.epsilon);
+            let root_inv = root.add(epsilon_tensor.as_ref()).map(|v| 1.0 / v);
+            let update = gradient
+                .scalar_multiply(-self.learning_rate)
+                .elementwise_product(root_inv.as_ref());
+
+            let mut weight = self.weights[i].borrow_mut();
+            weight.add_inplace(update.as_ref());
+        }
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+}
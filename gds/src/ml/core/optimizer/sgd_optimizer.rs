@@ -0,0 +1,87 @@
+use super::Updater;
+use crate::ml::core::functions::Weights;
+use crate::ml::core::Tensor;
+use crate::task::memory::Estimate;
+use std::sync::Arc;
+
+/// Stochastic gradient descent with optional (Nesterov) momentum.
+///
+/// Uses the formulation of Sutskever et al., "On the importance of initialization and
+/// momentum in deep learning" (2013): `v_t = mu * v_{t-1} + g_t`, followed by
+/// `theta -= lr * v_t`, or `theta -= lr * (g_t + mu * v_t)` for Nesterov momentum.
+pub struct SgdOptimizer {
+    learning_rate: f64,
+    momentum: f64,
+    nesterov: bool,
+    weights: Vec<Arc<Weights>>,
+    velocity_terms: Vec<Box<dyn Tensor>>,
+}
+
+impl SgdOptimizer {
+    /// Calculate memory size in bytes for given dimensions
+    pub fn size_in_bytes(rows: usize, cols: usize) -> usize {
+        let term_size = Weights::size_in_bytes(rows, cols);
+        Estimate::size_of_instance("SgdOptimizer")
+            + term_size // fields
+            + term_size // working memory: update
+    }
+
+    /// Create plain SGD without momentum
+    pub fn new(weights: Vec<Arc<Weights>>, learning_rate: f64) -> Self {
+        Self::with_momentum(weights, learning_rate, 0.0, false)
+    }
+
+    /// Create SGD with classical or Nesterov momentum
+    pub fn with_momentum(
+        weights: Vec<Arc<Weights>>,
+        learning_rate: f64,
+        momentum: f64,
+        nesterov: bool,
+    ) -> Self {
+        let velocity_terms: Vec<Box<dyn Tensor>> = weights
+            .iter()
+            .map(|w| w.borrow().create_with_same_dimensions())
+            .collect();
+
+        Self {
+            learning_rate,
+            momentum,
+            nesterov,
+            weights,
+            velocity_terms,
+        }
+    }
+}
+
+impl Updater for SgdOptimizer {
+    fn update(&mut self, context_local_weight_gradients: &[Box<dyn Tensor>]) {
+        for i in 0..self.weights.len() {
+            let gradient = context_local_weight_gradients[i].as_ref();
+
+            // v_t = mu * v_t + g_t
+            let mut new_velocity = self.velocity_terms[i].scalar_multiply(self.momentum);
+            new_velocity.add_inplace(gradient);
+            self.velocity_terms[i] = new_velocity;
+
+            let step = if self.nesterov {
+                // g_t + mu * v_t
+                gradient.add(
+                    self.velocity_terms[i]
+                        .scalar_multiply(self.momentum)
+                        .as_ref(),
+                )
+            } else {
+                self.velocity_terms[i].clone_box()
+            };
+
+            // theta_t = theta_t - lr * step
+            let update = step.scalar_multiply(-self.learning_rate);
+            let mut weight = self.weights[i].borrow_mut();
+            weight.add_inplace(update.as_ref());
+        }
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+}
@@ -4,4 +4,7 @@ use crate::ml::core::Tensor;
 pub trait Updater {
     /// Update weights using computed gradients
     fn update(&mut self, gradients: &[Box<dyn Tensor>]);
+
+    /// Learning rate used by subsequent updates
+    fn set_learning_rate(&mut self, learning_rate: f64);
 }
@@ -1,6 +1,8 @@
 use crate::ml::decision_tree::TreeNode;
+use serde::Deserialize;
+use serde::Serialize;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DecisionTreePredictor<P> {
     pub root: TreeNode<P>,
 }
@@ -13,6 +13,13 @@ pub trait DecisionTreeTrainer<P: Clone> {
     fn feature_bagger(&self) -> &FeatureBagger;
     fn to_terminal(&self, group: &Group) -> P;
 
+    /// Creates a leaf for `group`, recording its size and impurity.
+    fn to_leaf(&self, group: &Group) -> TreeNode<P> {
+        let mut leaf = TreeNode::new_leaf(self.to_terminal(group));
+        leaf.set_statistics(group.size(), group.impurity_data().impurity());
+        leaf
+    }
+
     fn estimate_tree(
         config: &DecisionTreeTrainerConfig,
         number_of_training_samples: usize,
@@ -103,23 +110,24 @@ pub trait DecisionTreeTrainer<P: Clone> {
         assert!(depth >= 1);
 
         if group.size() < self.config().min_split_size() {
-            return TreeNode::new_leaf(self.to_terminal(group));
+            return self.to_leaf(group);
         }
 
         let split = splitter.find_best_split(group);
         if split.groups().right().size() == 0 {
             let groups = split.into_groups();
             let (left_group, _) = groups.into_parts();
-            return TreeNode::new_leaf(self.to_terminal(&left_group));
+            return self.to_leaf(&left_group);
         } else if split.groups().left().size() == 0 {
             let groups = split.into_groups();
             let (_, right_group) = groups.into_parts();
-            return TreeNode::new_leaf(self.to_terminal(&right_group));
+            return self.to_leaf(&right_group);
         }
 
         let max_depth = self.config().max_depth();
         let min_split_size = self.config().min_split_size();
         let mut node = TreeNode::new_split(split.index(), split.value());
+        node.set_statistics(group.size(), group.impurity_data().impurity());
         let groups = split.into_groups();
         let (left_group, right_group) = groups.into_parts();
 
@@ -127,7 +135,7 @@ pub trait DecisionTreeTrainer<P: Clone> {
             && depth >= max_depth)
             || left_group.size() < min_split_size
         {
-            TreeNode::new_leaf(self.to_terminal(&left_group))
+            self.to_leaf(&left_group)
         } else {
             self.build_subtree(splitter, &left_group, depth + 1)
         };
@@ -136,7 +144,7 @@ pub trait DecisionTreeTrainer<P: Clone> {
             && depth >= max_depth)
             || right_group.size() < min_split_size
         {
-            TreeNode::new_leaf(self.to_terminal(&right_group))
+            self.to_leaf(&right_group)
         } else {
             self.build_subtree(splitter, &right_group, depth + 1)
         };
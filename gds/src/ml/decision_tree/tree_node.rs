@@ -1,17 +1,24 @@
 use std::fmt;
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::task::memory::Estimate;
 
 /// A node in a decision tree, either a split node or a leaf.
 ///
 /// Corresponds to TreeNode<PREDICTION> in Java GDS.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TreeNode<P> {
     prediction: Option<P>,
     feature_index: i32,
     threshold_value: f64,
     left_child: Option<Box<TreeNode<P>>>,
     right_child: Option<Box<TreeNode<P>>>,
+    /// Number of training samples that reached this node.
+    samples: usize,
+    /// Impurity of the training samples that reached this node.
+    impurity: f64,
 }
 
 impl<P: Clone> TreeNode<P> {
@@ -23,6 +30,8 @@ impl<P: Clone> TreeNode<P> {
             threshold_value: value,
             left_child: None,
             right_child: None,
+            samples: 0,
+            impurity: 0.0,
         }
     }
 
@@ -34,6 +43,8 @@ impl<P: Clone> TreeNode<P> {
             threshold_value: 0.0,
             left_child: None,
             right_child: None,
+            samples: 0,
+            impurity: 0.0,
         }
     }
 
@@ -64,6 +75,25 @@ impl<P: Clone> TreeNode<P> {
         self.threshold_value
     }
 
+    /// Records the training samples that reached this node and their impurity.
+    pub fn set_statistics(&mut self, samples: usize, impurity: f64) {
+        self.samples = samples;
+        self.impurity = impurity;
+    }
+
+    /// Number of training samples that reached this node; 0 if not recorded.
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    pub fn impurity(&self) -> f64 {
+        self.impurity
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        !self.has_left_child()
+    }
+
     pub fn left_child(&self) -> Option<&TreeNode<P>> {
         self.left_child.as_deref()
     }
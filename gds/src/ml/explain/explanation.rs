@@ -0,0 +1,69 @@
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+
+use super::feature_importance::feature_label;
+
+/// Additive explanation of a single model output.
+///
+/// `base_value` is the expected output of the model and every feature contributes
+/// its share of the difference, so `base_value + contributions.sum()` reproduces the
+/// explained output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Explanation {
+    base_value: f64,
+    contributions: Vec<f64>,
+}
+
+impl Explanation {
+    pub fn new(base_value: f64, contributions: Vec<f64>) -> Self {
+        Self {
+            base_value,
+            contributions,
+        }
+    }
+
+    pub fn base_value(&self) -> f64 {
+        self.base_value
+    }
+
+    pub fn contributions(&self) -> &[f64] {
+        &self.contributions
+    }
+
+    /// The explained output, `base_value` plus all contributions.
+    pub fn output(&self) -> f64 {
+        self.base_value + self.contributions.iter().sum::<f64>()
+    }
+
+    /// Weighted sum of explanations, e.g. the mean over the trees of a forest.
+    pub fn weighted_sum<'a>(
+        explanations: impl IntoIterator<Item = (f64, &'a Explanation)>,
+        number_of_features: usize,
+    ) -> Self {
+        let mut base_value = 0.0;
+        let mut contributions = vec![0.0; number_of_features];
+        for (weight, explanation) in explanations {
+            base_value += weight * explanation.base_value;
+            for (total, value) in contributions.iter_mut().zip(&explanation.contributions) {
+                *total += weight * value;
+            }
+        }
+        Self::new(base_value, contributions)
+    }
+
+    pub fn to_map(&self, feature_labels: &[String]) -> serde_json::Value {
+        let contributions: serde_json::Map<String, serde_json::Value> = self
+            .contributions
+            .iter()
+            .enumerate()
+            .map(|(feature, value)| (feature_label(feature_labels, feature), json!(value)))
+            .collect();
+
+        json!({
+            "baseValue": self.base_value,
+            "contributions": contributions,
+        })
+    }
+}
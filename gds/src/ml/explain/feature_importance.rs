@@ -0,0 +1,211 @@
+use crate::ml::core::tensor::Matrix;
+use crate::ml::decision_tree::TreeNode;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use std::fmt;
+
+/// How a [`FeatureImportance`] was derived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum FeatureImportanceKind {
+    /// Mean decrease in impurity over the splits of a tree ensemble.
+    Impurity,
+    /// Magnitude of the weights of a linear model.
+    Coefficient,
+    /// Drop of the main metric when a feature is shuffled on held-out data.
+    Permutation,
+}
+
+impl fmt::Display for FeatureImportanceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FeatureImportanceKind::Impurity => "impurity",
+            FeatureImportanceKind::Coefficient => "coefficient",
+            FeatureImportanceKind::Permutation => "permutation",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// One importance score per feature dimension.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureImportance {
+    kind: FeatureImportanceKind,
+    scores: Vec<f64>,
+}
+
+impl FeatureImportance {
+    pub fn new(kind: FeatureImportanceKind, scores: Vec<f64>) -> Self {
+        Self { kind, scores }
+    }
+
+    pub fn kind(&self) -> FeatureImportanceKind {
+        self.kind
+    }
+
+    pub fn scores(&self) -> &[f64] {
+        &self.scores
+    }
+
+    /// Features ordered from most to least important.
+    pub fn ranked(&self, feature_labels: &[String]) -> Vec<(String, f64)> {
+        let mut ranked: Vec<(String, f64)> = self
+            .scores
+            .iter()
+            .enumerate()
+            .map(|(feature, score)| (feature_label(feature_labels, feature), *score))
+            .collect();
+        ranked.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        ranked
+    }
+
+    pub fn to_map(&self, feature_labels: &[String]) -> serde_json::Value {
+        serde_json::Value::Array(
+            self.ranked(feature_labels)
+                .into_iter()
+                .map(
+                    |(feature, importance)| json!({ "feature": feature, "importance": importance }),
+                )
+                .collect(),
+        )
+    }
+}
+
+/// Labels for the feature dimensions of a model trained on `feature_properties`.
+///
+/// Scalar properties map to one dimension each and keep their name; when array
+/// properties widen the feature vector, dimensions are labelled by position.
+pub fn feature_labels(feature_properties: &[String], feature_dimension: usize) -> Vec<String> {
+    if feature_properties.len() == feature_dimension {
+        feature_properties.to_vec()
+    } else {
+        (0..feature_dimension)
+            .map(|feature| format!("feature[{feature}]"))
+            .collect()
+    }
+}
+
+pub(super) fn feature_label(feature_labels: &[String], feature: usize) -> String {
+    feature_labels
+        .get(feature)
+        .cloned()
+        .unwrap_or_else(|| format!("feature[{feature}]"))
+}
+
+/// Mean decrease in impurity, normalized per tree and then over the ensemble.
+///
+/// Needs trees that recorded their node statistics during training; trees without
+/// them contribute nothing.
+pub fn impurity_importance<'a, P: Clone + 'a>(
+    trees: impl IntoIterator<Item = &'a TreeNode<P>>,
+    number_of_features: usize,
+) -> FeatureImportance {
+    let mut importance = vec![0.0; number_of_features];
+
+    for root in trees {
+        let mut tree_importance = vec![0.0; number_of_features];
+        accumulate_impurity_decrease(root, &mut tree_importance);
+        normalize(&mut tree_importance);
+        for (total, value) in importance.iter_mut().zip(tree_importance) {
+            *total += value;
+        }
+    }
+    normalize(&mut importance);
+
+    FeatureImportance::new(FeatureImportanceKind::Impurity, importance)
+}
+
+fn accumulate_impurity_decrease<P: Clone>(node: &TreeNode<P>, importance: &mut [f64]) {
+    let (Some(left), Some(right)) = (node.left_child(), node.right_child()) else {
+        return;
+    };
+
+    let weighted = |node: &TreeNode<P>| node.samples() as f64 * node.impurity();
+    let decrease = weighted(node) - weighted(left) - weighted(right);
+    if let Some(slot) = importance.get_mut(node.feature_index() as usize) {
+        *slot += decrease.max(0.0);
+    }
+
+    accumulate_impurity_decrease(left, importance);
+    accumulate_impurity_decrease(right, importance);
+}
+
+fn normalize(values: &mut [f64]) {
+    let total: f64 = values.iter().sum();
+    if total > 0.0 {
+        values.iter_mut().for_each(|value| *value /= total);
+    }
+}
+
+/// Mean absolute weight of every feature over the rows of a linear model.
+///
+/// Weights are only comparable across features on a common scale, so this is most
+/// meaningful after feature scaling.
+pub fn coefficient_importance(weights: &Matrix) -> FeatureImportance {
+    let rows = weights.rows().max(1) as f64;
+    let scores = (0..weights.cols())
+        .map(|col| {
+            (0..weights.rows())
+                .map(|row| weights[(row, col)].abs())
+                .sum::<f64>()
+                / rows
+        })
+        .collect();
+
+    FeatureImportance::new(FeatureImportanceKind::Coefficient, scores)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(feature: usize, samples: usize, impurity: f64) -> TreeNode<f64> {
+        let mut node = TreeNode::new_split(feature, 0.5);
+        node.set_statistics(samples, impurity);
+        node
+    }
+
+    fn leaf(samples: usize, impurity: f64) -> TreeNode<f64> {
+        let mut leaf = TreeNode::new_leaf(0.0);
+        leaf.set_statistics(samples, impurity);
+        leaf
+    }
+
+    #[test]
+    fn impurity_importance_weighs_decrease_by_samples() {
+        // Root on feature 1 removes 10 * 0.5 - 2 * 0.5 - 8 * 0.25 = 2.0,
+        // its left child on feature 0 removes 2 * 0.5 = 1.0.
+        let mut left = node(0, 2, 0.5);
+        left.set_left_child(leaf(1, 0.0));
+        left.set_right_child(leaf(1, 0.0));
+        let mut root = node(1, 10, 0.5);
+        root.set_left_child(left);
+        root.set_right_child(leaf(8, 0.25));
+
+        let importance = impurity_importance([&root], 3);
+
+        assert_eq!(importance.kind(), FeatureImportanceKind::Impurity);
+        let scores = importance.scores();
+        assert!((scores[0] - 1.0 / 3.0).abs() < 1e-12);
+        assert!((scores[1] - 2.0 / 3.0).abs() < 1e-12);
+        assert_eq!(scores[2], 0.0);
+
+        let labels = feature_labels(&["a".into(), "b".into(), "c".into()], 3);
+        let map = importance.to_map(&labels);
+        assert_eq!(map[0]["feature"], "b");
+        assert_eq!(map[2]["feature"], "c");
+    }
+
+    #[test]
+    fn coefficient_importance_averages_absolute_weights() {
+        let weights = Matrix::new(vec![1.0, -4.0, 0.0, -3.0, 2.0, 0.5], 2, 3);
+
+        let importance = coefficient_importance(&weights);
+
+        assert_eq!(importance.scores(), &[2.0, 3.0, 0.25]);
+        assert_eq!(
+            feature_labels(&["array".into()], 2),
+            vec!["feature[0]".to_string(), "feature[1]".to_string()]
+        );
+    }
+}
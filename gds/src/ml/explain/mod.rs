@@ -0,0 +1,9 @@
+pub mod explanation;
+pub mod feature_importance;
+pub mod permutation_importance;
+pub mod tree_shap;
+
+pub use explanation::*;
+pub use feature_importance::*;
+pub use permutation_importance::*;
+pub use tree_shap::*;
@@ -0,0 +1,128 @@
+use super::FeatureImportance;
+use super::FeatureImportanceKind;
+use crate::ml::models::automl::optimizer::seeded_rng;
+use crate::ml::models::Features;
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+
+/// Number of shuffles averaged per feature when none is configured.
+pub const DEFAULT_PERMUTATION_REPEATS: usize = 3;
+
+/// Permutation importance of every feature dimension on `evaluation_set`.
+///
+/// `score` evaluates the model on the given features, restricted to `evaluation_set`,
+/// and must be oriented so that higher is better. The importance of a feature is the
+/// mean drop of that score over `repeats` shuffles of its column; features the model
+/// ignores score about zero and may come out slightly negative.
+pub fn permutation_importance(
+    features: &dyn Features,
+    evaluation_set: &[usize],
+    repeats: usize,
+    random_seed: Option<u64>,
+    score: impl Fn(&dyn Features) -> f64,
+) -> FeatureImportance {
+    let number_of_features = features.feature_dimension();
+    let baseline = score(features);
+    let mut rng = seeded_rng(random_seed);
+    let repeats = repeats.max(1);
+
+    let scores = (0..number_of_features)
+        .map(|feature| {
+            let column: Vec<f64> = evaluation_set
+                .iter()
+                .map(|&id| features.get(id)[feature])
+                .collect();
+
+            let total_drop: f64 = (0..repeats)
+                .map(|_| {
+                    let mut shuffled = column.clone();
+                    shuffled.shuffle(&mut rng);
+                    let permuted =
+                        PermutedFeatures::new(features, evaluation_set, feature, &shuffled);
+                    baseline - score(&permuted)
+                })
+                .sum();
+
+            total_drop / repeats as f64
+        })
+        .collect();
+
+    FeatureImportance::new(FeatureImportanceKind::Permutation, scores)
+}
+
+/// `features` with one column replaced on the rows of an evaluation set.
+struct PermutedFeatures<'a> {
+    original: &'a dyn Features,
+    rows: HashMap<usize, Vec<f64>>,
+}
+
+impl<'a> PermutedFeatures<'a> {
+    fn new(
+        original: &'a dyn Features,
+        evaluation_set: &[usize],
+        feature: usize,
+        column: &[f64],
+    ) -> Self {
+        let rows = evaluation_set
+            .iter()
+            .zip(column)
+            .map(|(&id, &value)| {
+                let mut row = original.get(id).to_vec();
+                row[feature] = value;
+                (id, row)
+            })
+            .collect();
+
+        Self { original, rows }
+    }
+}
+
+impl Features for PermutedFeatures<'_> {
+    fn size(&self) -> usize {
+        self.original.size()
+    }
+
+    fn get(&self, id: usize) -> &[f64] {
+        self.rows
+            .get(&id)
+            .map(Vec::as_slice)
+            .unwrap_or_else(|| self.original.get(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::models::FeaturesFactory;
+
+    #[test]
+    fn shuffling_an_ignored_feature_costs_nothing() {
+        // The target is the first feature; the second one is noise.
+        let rows: Vec<Vec<f64>> = (0..40)
+            .map(|i| vec![i as f64, ((i * 7) % 11) as f64])
+            .collect();
+        let targets: Vec<f64> = rows.iter().map(|row| row[0]).collect();
+        let features = FeaturesFactory::wrap_list(rows);
+        let evaluation_set: Vec<usize> = (0..40).collect();
+
+        let negative_mse = |features: &dyn Features| {
+            -evaluation_set
+                .iter()
+                .map(|&id| (features.get(id)[0] - targets[id]).powi(2))
+                .sum::<f64>()
+                / evaluation_set.len() as f64
+        };
+
+        let importance = permutation_importance(
+            features.as_ref(),
+            &evaluation_set,
+            DEFAULT_PERMUTATION_REPEATS,
+            Some(42),
+            negative_mse,
+        );
+
+        assert_eq!(importance.kind(), FeatureImportanceKind::Permutation);
+        assert!(importance.scores()[0] > 10.0);
+        assert_eq!(importance.scores()[1], 0.0);
+    }
+}
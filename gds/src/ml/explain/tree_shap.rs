@@ -0,0 +1,318 @@
+//! Exact SHAP values for decision trees (Lundberg et al., "Consistent Individualized
+//! Feature Attribution for Tree Ensembles", Algorithm 2).
+//!
+//! Absent features are marginalised over the training distribution recorded in the
+//! tree: at a split on an absent feature both branches are followed, weighted by the
+//! share of training samples that went either way.
+
+use super::Explanation;
+use crate::ml::decision_tree::TreeNode;
+
+/// SHAP values of `features` for a single tree.
+///
+/// `leaf_value` maps a leaf prediction to the explained output, e.g. the indicator
+/// of one class for a classification tree.
+pub fn tree_shap<P: Clone>(
+    root: &TreeNode<P>,
+    features: &[f64],
+    leaf_value: &dyn Fn(&P) -> f64,
+) -> Explanation {
+    let mut contributions = vec![0.0; features.len()];
+    let mut shap = TreeShap {
+        features,
+        leaf_value,
+        contributions: &mut contributions,
+    };
+    shap.recurse(root, &[], 0, 1.0, 1.0, None);
+
+    Explanation::new(expected_value(root, leaf_value), contributions)
+}
+
+/// Cover-weighted mean of the leaf values, the output when no feature is known.
+fn expected_value<P: Clone>(node: &TreeNode<P>, leaf_value: &dyn Fn(&P) -> f64) -> f64 {
+    match (node.left_child(), node.right_child()) {
+        (Some(left), Some(right)) => {
+            let (left_cover, right_cover) = (cover(left), cover(right));
+            (left_cover * expected_value(left, leaf_value)
+                + right_cover * expected_value(right, leaf_value))
+                / (left_cover + right_cover)
+        }
+        _ => node.prediction().map(leaf_value).unwrap_or(0.0),
+    }
+}
+
+/// Training samples of a node; trees built without statistics weigh every leaf equally.
+fn cover<P: Clone>(node: &TreeNode<P>) -> f64 {
+    if node.samples() > 0 {
+        return node.samples() as f64;
+    }
+    match (node.left_child(), node.right_child()) {
+        (Some(left), Some(right)) => cover(left) + cover(right),
+        _ => 1.0,
+    }
+}
+
+#[derive(Clone, Copy)]
+struct PathElement {
+    feature: Option<usize>,
+    zero_fraction: f64,
+    one_fraction: f64,
+    weight: f64,
+}
+
+struct TreeShap<'a, P> {
+    features: &'a [f64],
+    leaf_value: &'a dyn Fn(&P) -> f64,
+    contributions: &'a mut [f64],
+}
+
+impl<P: Clone> TreeShap<'_, P> {
+    fn recurse(
+        &mut self,
+        node: &TreeNode<P>,
+        parent_path: &[PathElement],
+        unique_depth: usize,
+        zero_fraction: f64,
+        one_fraction: f64,
+        feature: Option<usize>,
+    ) {
+        let mut path = parent_path[..unique_depth.min(parent_path.len())].to_vec();
+        extend(&mut path, zero_fraction, one_fraction, feature);
+        let mut unique_depth = unique_depth;
+
+        let (Some(left), Some(right)) = (node.left_child(), node.right_child()) else {
+            let value = node.prediction().map(self.leaf_value).unwrap_or(0.0);
+            for i in 1..=unique_depth {
+                let weight = unwound_path_sum(&path, unique_depth, i);
+                let element = path[i];
+                if let Some(feature) = element.feature {
+                    self.contributions[feature] +=
+                        weight * (element.one_fraction - element.zero_fraction) * value;
+                }
+            }
+            return;
+        };
+
+        let split_feature = node.feature_index() as usize;
+        let (hot, cold) = if self.features[split_feature] < node.threshold_value() {
+            (left, right)
+        } else {
+            (right, left)
+        };
+
+        let mut incoming_zero_fraction = 1.0;
+        let mut incoming_one_fraction = 1.0;
+        if let Some(k) = (1..=unique_depth).find(|&k| path[k].feature == Some(split_feature)) {
+            incoming_zero_fraction = path[k].zero_fraction;
+            incoming_one_fraction = path[k].one_fraction;
+            unwind(&mut path, unique_depth, k);
+            unique_depth -= 1;
+        }
+
+        let node_cover = cover(left) + cover(right);
+        self.recurse(
+            hot,
+            &path,
+            unique_depth + 1,
+            incoming_zero_fraction * cover(hot) / node_cover,
+            incoming_one_fraction,
+            Some(split_feature),
+        );
+        self.recurse(
+            cold,
+            &path,
+            unique_depth + 1,
+            incoming_zero_fraction * cover(cold) / node_cover,
+            0.0,
+            Some(split_feature),
+        );
+    }
+}
+
+/// Grows the path by one feature, updating the permutation weights of all subsets.
+fn extend(
+    path: &mut Vec<PathElement>,
+    zero_fraction: f64,
+    one_fraction: f64,
+    feature: Option<usize>,
+) {
+    let depth = path.len();
+    path.push(PathElement {
+        feature,
+        zero_fraction,
+        one_fraction,
+        weight: if depth == 0 { 1.0 } else { 0.0 },
+    });
+
+    for i in (0..depth).rev() {
+        path[i + 1].weight += one_fraction * path[i].weight * (i + 1) as f64 / (depth + 1) as f64;
+        path[i].weight = zero_fraction * path[i].weight * (depth - i) as f64 / (depth + 1) as f64;
+    }
+}
+
+/// Undoes `extend` for the element at `path_index`.
+fn unwind(path: &mut Vec<PathElement>, unique_depth: usize, path_index: usize) {
+    let one_fraction = path[path_index].one_fraction;
+    let zero_fraction = path[path_index].zero_fraction;
+    let mut next_one_portion = path[unique_depth].weight;
+
+    for i in (0..unique_depth).rev() {
+        if one_fraction != 0.0 {
+            let weight = path[i].weight;
+            path[i].weight =
+                next_one_portion * (unique_depth + 1) as f64 / ((i + 1) as f64 * one_fraction);
+            next_one_portion = weight
+                - path[i].weight * zero_fraction * (unique_depth - i) as f64
+                    / (unique_depth + 1) as f64;
+        } else {
+            path[i].weight = path[i].weight * (unique_depth + 1) as f64
+                / (zero_fraction * (unique_depth - i) as f64);
+        }
+    }
+
+    for i in path_index..unique_depth {
+        path[i].feature = path[i + 1].feature;
+        path[i].zero_fraction = path[i + 1].zero_fraction;
+        path[i].one_fraction = path[i + 1].one_fraction;
+    }
+    path.truncate(unique_depth);
+}
+
+/// Total permutation weight of the path with the element at `path_index` unwound.
+fn unwound_path_sum(path: &[PathElement], unique_depth: usize, path_index: usize) -> f64 {
+    let one_fraction = path[path_index].one_fraction;
+    let zero_fraction = path[path_index].zero_fraction;
+    let mut next_one_portion = path[unique_depth].weight;
+    let mut total = 0.0;
+
+    for i in (0..unique_depth).rev() {
+        if one_fraction != 0.0 {
+            let weight =
+                next_one_portion * (unique_depth + 1) as f64 / ((i + 1) as f64 * one_fraction);
+            total += weight;
+            next_one_portion = path[i].weight
+                - weight * zero_fraction * (unique_depth - i) as f64 / (unique_depth + 1) as f64;
+        } else if zero_fraction != 0.0 {
+            total += path[i].weight
+                / zero_fraction
+                / ((unique_depth - i) as f64 / (unique_depth + 1) as f64);
+        }
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(feature: usize, threshold: f64, samples: usize) -> TreeNode<f64> {
+        let mut node = TreeNode::new_split(feature, threshold);
+        node.set_statistics(samples, 0.0);
+        node
+    }
+
+    fn leaf(value: f64, samples: usize) -> TreeNode<f64> {
+        let mut node = TreeNode::new_leaf(value);
+        node.set_statistics(samples, 0.0);
+        node
+    }
+
+    fn with_children(
+        mut node: TreeNode<f64>,
+        left: TreeNode<f64>,
+        right: TreeNode<f64>,
+    ) -> TreeNode<f64> {
+        node.set_left_child(left);
+        node.set_right_child(right);
+        node
+    }
+
+    /// Feature 0 is used twice on one path, feature 2 only on the other side.
+    fn tree() -> TreeNode<f64> {
+        with_children(
+            split(0, 0.5, 100),
+            with_children(
+                split(1, 0.5, 60),
+                leaf(1.0, 20),
+                with_children(split(0, 0.2, 40), leaf(5.0, 10), leaf(-2.0, 30)),
+            ),
+            with_children(split(2, 0.5, 40), leaf(3.0, 25), leaf(8.0, 15)),
+        )
+    }
+
+    /// E[f(x) | x_S] by following known features and averaging over unknown ones.
+    fn conditional_expectation(node: &TreeNode<f64>, features: &[f64], known: u32) -> f64 {
+        match (node.left_child(), node.right_child()) {
+            (Some(left), Some(right)) => {
+                let feature = node.feature_index() as usize;
+                if known & (1 << feature) != 0 {
+                    let next = if features[feature] < node.threshold_value() {
+                        left
+                    } else {
+                        right
+                    };
+                    conditional_expectation(next, features, known)
+                } else {
+                    (cover(left) * conditional_expectation(left, features, known)
+                        + cover(right) * conditional_expectation(right, features, known))
+                        / (cover(left) + cover(right))
+                }
+            }
+            _ => *node.prediction().unwrap(),
+        }
+    }
+
+    fn brute_force_shap(root: &TreeNode<f64>, features: &[f64]) -> Vec<f64> {
+        let n = features.len();
+        let factorial = |k: usize| (1..=k).product::<usize>() as f64;
+        (0..n)
+            .map(|feature| {
+                (0u32..1 << n)
+                    .filter(|subset| subset & (1 << feature) == 0)
+                    .map(|subset| {
+                        let size = subset.count_ones() as usize;
+                        let weight = factorial(size) * factorial(n - size - 1) / factorial(n);
+                        weight
+                            * (conditional_expectation(root, features, subset | (1 << feature))
+                                - conditional_expectation(root, features, subset))
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_brute_force_shapley_values() {
+        let root = tree();
+        for features in [
+            [0.1, 0.9, 0.0],
+            [0.3, 0.7, 0.9],
+            [0.1, 0.1, 0.1],
+            [0.8, 0.2, 0.9],
+            [0.8, 0.9, 0.1],
+        ] {
+            let explanation = tree_shap(&root, &features, &|value: &f64| *value);
+            let expected = brute_force_shap(&root, &features);
+
+            for (actual, expected) in explanation.contributions().iter().zip(&expected) {
+                assert!(
+                    (actual - expected).abs() < 1e-9,
+                    "{features:?}: {actual} != {expected}"
+                );
+            }
+            let prediction = conditional_expectation(&root, &features, 0b111);
+            assert!((explanation.output() - prediction).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn base_value_is_the_cover_weighted_mean() {
+        let root = tree();
+
+        let explanation = tree_shap(&root, &[0.0, 0.0, 0.0], &|value: &f64| *value);
+
+        let expected = (20.0 * 1.0 + 10.0 * 5.0 + 30.0 * -2.0 + 25.0 * 3.0 + 15.0 * 8.0) / 100.0;
+        assert!((explanation.base_value() - expected).abs() < 1e-12);
+    }
+}
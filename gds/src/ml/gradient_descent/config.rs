@@ -1,4 +1,5 @@
 use crate::config::validation::ConfigError;
+use crate::ml::core::optimizer::OptimizerConfig;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +23,10 @@ pub struct GradientDescentConfig {
 
     #[builder(default = "0.001")]
     learning_rate: f64,
+
+    #[builder(default)]
+    #[serde(default)]
+    optimizer: OptimizerConfig,
 }
 
 impl GradientDescentConfig {
@@ -53,6 +58,11 @@ impl GradientDescentConfig {
         self.learning_rate
     }
 
+    /// Optimizer and learning rate schedule; one update is performed per epoch.
+    pub fn optimizer(&self) -> &OptimizerConfig {
+        &self.optimizer
+    }
+
     pub fn validate(&self) -> Result<(), ConfigError> {
         if self.batch_size == 0 {
             return Err(ConfigError::InvalidParameter {
@@ -84,7 +94,7 @@ impl GradientDescentConfig {
                 reason: "tolerance must be >= 0".to_string(),
             });
         }
-        Ok(())
+        self.optimizer.validate()
     }
 }
 
@@ -103,6 +113,7 @@ impl Default for GradientDescentConfig {
             max_epochs: 100,
             tolerance: 1e-3,
             learning_rate: 0.001,
+            optimizer: OptimizerConfig::default(),
         }
     }
 }
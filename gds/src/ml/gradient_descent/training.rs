@@ -6,8 +6,6 @@ use crate::ml::core::batch::AnyBatch;
 use crate::ml::core::batch::Batch;
 use crate::ml::core::batch::BatchQueue;
 use crate::ml::core::computation_context::ComputationContext;
-use crate::ml::core::optimizer::AdamOptimizer;
-use crate::ml::core::optimizer::Updater;
 use crate::ml::core::tensor::Tensor;
 use parking_lot::RwLock;
 use std::sync::Arc;
@@ -48,7 +46,10 @@ impl Training {
 
         // Create updater with weights
         let weights = objective.weights();
-        let mut updater = AdamOptimizer::new(weights, self.config.learning_rate());
+        let mut updater = self
+            .config
+            .optimizer()
+            .create_updater(weights, self.config.learning_rate());
         let mut stopper = factory::default_stopper(&self.config);
 
         let mut losses = Vec::new();
@@ -36,6 +36,19 @@ const EPSILON: f64 = 1e-8;
 
 pub trait ClassificationMetric: Metric {
     fn compute(&self, targets: &HugeLongArray, predictions: &HugeLongArray) -> f64;
+
+    /// Computes the metric given the predicted class probabilities as well.
+    ///
+    /// `probabilities[i][c]` is the probability of internal class `c` for example `i`.
+    /// Metrics that only look at the predicted classes keep the default.
+    fn compute_with_probabilities(
+        &self,
+        targets: &HugeLongArray,
+        predictions: &HugeLongArray,
+        _probabilities: &[Vec<f64>],
+    ) -> f64 {
+        self.compute(targets, predictions)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -417,6 +430,273 @@ impl ClassificationMetric for F1Weighted {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct BalancedAccuracy {
+    class_id_map: LocalIdMap,
+}
+
+impl BalancedAccuracy {
+    pub const NAME: &'static str = "BALANCED_ACCURACY";
+
+    pub fn new(class_id_map: LocalIdMap) -> Self {
+        Self { class_id_map }
+    }
+}
+
+impl Metric for BalancedAccuracy {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn comparator(&self) -> MetricComparator {
+        MetricComparator::Natural
+    }
+
+    fn as_classification_metric(&self) -> Option<&dyn ClassificationMetric> {
+        Some(self)
+    }
+}
+
+impl ClassificationMetric for BalancedAccuracy {
+    /// The mean recall over the classes present in `targets`.
+    fn compute(&self, targets: &HugeLongArray, predictions: &HugeLongArray) -> f64 {
+        let mut sum = 0.0;
+        let mut count = 0usize;
+        for (original, internal) in self.class_id_map.mappings() {
+            let present = (0..targets.size()).any(|i| targets.get(i) == internal as i64);
+            if !present {
+                continue;
+            }
+            sum += Recall::new(original as i64, internal as i64).compute(targets, predictions);
+            count += 1;
+        }
+
+        if count == 0 {
+            0.0
+        } else {
+            sum / count as f64
+        }
+    }
+}
+
+/// Area under the ROC curve of one class against all others.
+#[derive(Debug, Clone)]
+pub struct RocAuc {
+    internal_target: i64,
+    name: String,
+}
+
+impl RocAuc {
+    pub const NAME: &'static str = "ROC_AUC";
+
+    pub fn new(original_target: i64, internal_target: i64) -> Self {
+        Self {
+            internal_target,
+            name: format!("{}(class={})", Self::NAME, original_target),
+        }
+    }
+
+    fn compute_for_scores(&self, targets: &HugeLongArray, scores: &[f64]) -> f64 {
+        let labels: Vec<bool> = (0..targets.size())
+            .map(|i| targets.get(i) == self.internal_target)
+            .collect();
+        roc_auc(scores, &labels)
+    }
+}
+
+impl Metric for RocAuc {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn comparator(&self) -> MetricComparator {
+        MetricComparator::Natural
+    }
+
+    fn as_classification_metric(&self) -> Option<&dyn ClassificationMetric> {
+        Some(self)
+    }
+}
+
+impl ClassificationMetric for RocAuc {
+    /// Without probabilities, the predicted classes are used as 0/1 scores.
+    fn compute(&self, targets: &HugeLongArray, predictions: &HugeLongArray) -> f64 {
+        let scores: Vec<f64> = (0..predictions.size())
+            .map(|i| (predictions.get(i) == self.internal_target) as u8 as f64)
+            .collect();
+        self.compute_for_scores(targets, &scores)
+    }
+
+    fn compute_with_probabilities(
+        &self,
+        targets: &HugeLongArray,
+        _predictions: &HugeLongArray,
+        probabilities: &[Vec<f64>],
+    ) -> f64 {
+        let scores: Vec<f64> = probabilities
+            .iter()
+            .map(|p| p.get(self.internal_target as usize).copied().unwrap_or(0.0))
+            .collect();
+        self.compute_for_scores(targets, &scores)
+    }
+}
+
+/// Macro average of the one-vs-rest ROC-AUC over the classes.
+#[derive(Debug, Clone)]
+pub struct RocAucMacro {
+    class_id_map: LocalIdMap,
+}
+
+impl RocAucMacro {
+    pub const NAME: &'static str = "ROC_AUC";
+
+    pub fn new(class_id_map: LocalIdMap) -> Self {
+        Self { class_id_map }
+    }
+
+    fn average(&self, per_class: impl Fn(&RocAuc) -> f64) -> f64 {
+        let mut sum = 0.0;
+        let mut count = 0usize;
+        for (original, internal) in self.class_id_map.mappings() {
+            sum += per_class(&RocAuc::new(original as i64, internal as i64));
+            count += 1;
+        }
+
+        if count == 0 {
+            0.0
+        } else {
+            sum / count as f64
+        }
+    }
+}
+
+impl Metric for RocAucMacro {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn comparator(&self) -> MetricComparator {
+        MetricComparator::Natural
+    }
+
+    fn as_classification_metric(&self) -> Option<&dyn ClassificationMetric> {
+        Some(self)
+    }
+}
+
+impl ClassificationMetric for RocAucMacro {
+    fn compute(&self, targets: &HugeLongArray, predictions: &HugeLongArray) -> f64 {
+        self.average(|metric| metric.compute(targets, predictions))
+    }
+
+    fn compute_with_probabilities(
+        &self,
+        targets: &HugeLongArray,
+        predictions: &HugeLongArray,
+        probabilities: &[Vec<f64>],
+    ) -> f64 {
+        self.average(|metric| {
+            metric.compute_with_probabilities(targets, predictions, probabilities)
+        })
+    }
+}
+
+/// Mean negative log-likelihood of the true classes.
+#[derive(Debug, Clone, Default)]
+pub struct LogLoss;
+
+impl LogLoss {
+    pub const NAME: &'static str = "LOG_LOSS";
+    /// Probabilities are clipped to `[EPSILON, 1 - EPSILON]` to keep the loss finite.
+    const EPSILON: f64 = 1e-15;
+
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Metric for LogLoss {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn comparator(&self) -> MetricComparator {
+        MetricComparator::Inverse
+    }
+
+    fn as_classification_metric(&self) -> Option<&dyn ClassificationMetric> {
+        Some(self)
+    }
+}
+
+impl ClassificationMetric for LogLoss {
+    /// Without probabilities, the predicted classes are treated as certain.
+    fn compute(&self, targets: &HugeLongArray, predictions: &HugeLongArray) -> f64 {
+        let probabilities: Vec<Vec<f64>> = (0..predictions.size())
+            .map(|i| {
+                let predicted = predictions.get(i).max(0) as usize;
+                let mut one_hot = vec![0.0; predicted + 1];
+                one_hot[predicted] = 1.0;
+                one_hot
+            })
+            .collect();
+        self.compute_with_probabilities(targets, predictions, &probabilities)
+    }
+
+    fn compute_with_probabilities(
+        &self,
+        targets: &HugeLongArray,
+        _predictions: &HugeLongArray,
+        probabilities: &[Vec<f64>],
+    ) -> f64 {
+        if targets.size() == 0 {
+            return 0.0;
+        }
+
+        let total: f64 = (0..targets.size())
+            .map(|i| {
+                let probability = probabilities[i]
+                    .get(targets.get(i) as usize)
+                    .copied()
+                    .unwrap_or(0.0)
+                    .clamp(Self::EPSILON, 1.0 - Self::EPSILON);
+                -probability.ln()
+            })
+            .sum();
+        total / targets.size() as f64
+    }
+}
+
+/// Area under the ROC curve via the rank statistic, averaging ranks over ties.
+/// Returns 0.5 when only one of the two classes is present.
+pub(crate) fn roc_auc(scores: &[f64], labels: &[bool]) -> f64 {
+    let positives = labels.iter().filter(|&&label| label).count();
+    let negatives = labels.len() - positives;
+    if positives == 0 || negatives == 0 {
+        return 0.5;
+    }
+
+    let mut order: Vec<usize> = (0..scores.len()).collect();
+    order.sort_by(|&a, &b| scores[a].total_cmp(&scores[b]));
+
+    let mut positive_rank_sum = 0.0;
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start;
+        while end + 1 < order.len() && scores[order[end + 1]] == scores[order[start]] {
+            end += 1;
+        }
+        // Ranks are 1-based; tied examples share the mean of their ranks.
+        let rank = (start + end) as f64 / 2.0 + 1.0;
+        positive_rank_sum +=
+            rank * order[start..=end].iter().filter(|&&i| labels[i]).count() as f64;
+        start = end + 1;
+    }
+
+    let positives = positives as f64;
+    (positive_rank_sum - positives * (positives + 1.0) / 2.0) / (positives * negatives as f64)
+}
+
 fn round_up(value: f64, decimals: u32) -> f64 {
     if decimals == 0 {
         return value.ceil();
@@ -425,3 +705,66 @@ fn round_up(value: f64, decimals: u32) -> f64 {
     let factor = 10_f64.powi(decimals as i32);
     (value * factor).ceil() / factor
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn array(values: &[i64]) -> HugeLongArray {
+        let mut array = HugeLongArray::new(values.len());
+        for (i, &value) in values.iter().enumerate() {
+            array.set(i, value);
+        }
+        array
+    }
+
+    #[test]
+    fn roc_auc_ranks_probabilities() {
+        let targets = array(&[0, 0, 1, 1]);
+        let predictions = array(&[0, 1, 0, 1]);
+        let probabilities = vec![
+            vec![0.9, 0.1],
+            vec![0.6, 0.4],
+            vec![0.65, 0.35],
+            vec![0.2, 0.8],
+        ];
+
+        let metric = RocAuc::new(1, 1);
+        let auc = metric.compute_with_probabilities(&targets, &predictions, &probabilities);
+        assert!((auc - 0.75).abs() < 1e-12);
+        assert!((metric.compute(&targets, &predictions) - 0.5).abs() < 1e-12);
+
+        let macro_auc = RocAucMacro::new(LocalIdMap::of(&[0, 1]));
+        let both = macro_auc.compute_with_probabilities(&targets, &predictions, &probabilities);
+        assert!((both - 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn roc_auc_averages_ties() {
+        assert_eq!(roc_auc(&[0.5, 0.5], &[true, false]), 0.5);
+        assert_eq!(roc_auc(&[0.1, 0.9], &[true, true]), 0.5);
+    }
+
+    #[test]
+    fn log_loss_of_true_class_probabilities() {
+        let targets = array(&[0, 1]);
+        let predictions = array(&[0, 1]);
+        let probabilities = vec![vec![0.5, 0.5], vec![0.25, 0.75]];
+
+        let loss =
+            LogLoss::new().compute_with_probabilities(&targets, &predictions, &probabilities);
+        let expected = -(0.5_f64.ln() + 0.75_f64.ln()) / 2.0;
+        assert!((loss - expected).abs() < 1e-12);
+        assert_eq!(LogLoss::new().comparator(), MetricComparator::Inverse);
+    }
+
+    #[test]
+    fn balanced_accuracy_averages_recall_over_present_classes() {
+        let class_id_map = LocalIdMap::of(&[0, 1, 2]);
+        let targets = array(&[0, 0, 0, 1]);
+        let predictions = array(&[0, 0, 0, 0]);
+
+        let score = BalancedAccuracy::new(class_id_map).compute(&targets, &predictions);
+        assert!((score - 0.5).abs() < 1e-6);
+    }
+}
@@ -5,13 +5,17 @@ use crate::task::memory::MemoryEstimations;
 use crate::task::memory::MemoryRange;
 
 use super::classification::Accuracy;
+use super::classification::BalancedAccuracy;
 use super::classification::F1Macro;
 use super::classification::F1Score;
 use super::classification::F1Weighted;
 use super::classification::GlobalAccuracy;
+use super::classification::LogLoss;
 use super::classification::OutOfBagError;
 use super::classification::Precision;
 use super::classification::Recall;
+use super::classification::RocAuc;
+use super::classification::RocAucMacro;
 use super::metric::Metric;
 use std::collections::HashSet;
 
@@ -171,6 +175,7 @@ impl ClassificationMetricSpecification {
             Accuracy::NAME => {
                 Some(|original, internal| Box::new(Accuracy::new(original, internal)))
             }
+            RocAuc::NAME => Some(|original, internal| Box::new(RocAuc::new(original, internal))),
             _ => None,
         }
     }
@@ -184,6 +189,13 @@ impl ClassificationMetricSpecification {
             }),
             F1Macro::NAME => Some(|class_id_map, _| Box::new(F1Macro::new(class_id_map.clone()))),
             GlobalAccuracy::NAME => Some(|_, _| Box::new(GlobalAccuracy::new())),
+            BalancedAccuracy::NAME => {
+                Some(|class_id_map, _| Box::new(BalancedAccuracy::new(class_id_map.clone())))
+            }
+            RocAucMacro::NAME => {
+                Some(|class_id_map, _| Box::new(RocAucMacro::new(class_id_map.clone())))
+            }
+            LogLoss::NAME => Some(|_, _| Box::new(LogLoss::new())),
             _ => None,
         }
     }
@@ -272,12 +284,25 @@ impl ClassificationMetricSpecification {
         let mut valid = vec![OutOfBagError::NAME.to_string()];
 
         valid.extend(
-            [F1Weighted::NAME, F1Macro::NAME, GlobalAccuracy::NAME]
-                .iter()
-                .map(|name| (*name).to_string()),
+            [
+                F1Weighted::NAME,
+                F1Macro::NAME,
+                GlobalAccuracy::NAME,
+                BalancedAccuracy::NAME,
+                RocAucMacro::NAME,
+                LogLoss::NAME,
+            ]
+            .iter()
+            .map(|name| (*name).to_string()),
         );
 
-        for single in [F1Score::NAME, Precision::NAME, Recall::NAME, Accuracy::NAME] {
+        for single in [
+            F1Score::NAME,
+            Precision::NAME,
+            Recall::NAME,
+            Accuracy::NAME,
+            RocAuc::NAME,
+        ] {
             if include_syntactic_sugar_metrics {
                 valid.push(format!("{}(class=*)", single));
             }
@@ -0,0 +1,137 @@
+use crate::collections::HugeLongArray;
+use crate::ml::core::subgraph::LocalIdMap;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+
+/// Counts of (actual, predicted) class pairs.
+///
+/// Classes are indexed by their internal id; `classes` holds the original class
+/// values so that the rendered matrix can be read without the class id map.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfusionMatrix {
+    classes: Vec<i64>,
+    /// `counts[actual][predicted]`
+    counts: Vec<Vec<u64>>,
+}
+
+impl ConfusionMatrix {
+    pub fn compute(
+        targets: &HugeLongArray,
+        predictions: &HugeLongArray,
+        class_id_map: &LocalIdMap,
+    ) -> Self {
+        debug_assert_eq!(targets.size(), predictions.size());
+
+        let number_of_classes = class_id_map.size();
+        let mut counts = vec![vec![0u64; number_of_classes]; number_of_classes];
+        for i in 0..targets.size() {
+            let actual = targets.get(i) as usize;
+            let predicted = predictions.get(i) as usize;
+            if actual < number_of_classes && predicted < number_of_classes {
+                counts[actual][predicted] += 1;
+            }
+        }
+
+        let classes = (0..number_of_classes)
+            .map(|internal| class_id_map.to_original(internal) as i64)
+            .collect();
+
+        Self { classes, counts }
+    }
+
+    /// Original class values, in internal id order.
+    pub fn classes(&self) -> &[i64] {
+        &self.classes
+    }
+
+    pub fn count(&self, actual: usize, predicted: usize) -> u64 {
+        self.counts[actual][predicted]
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.iter().flatten().sum()
+    }
+
+    pub fn true_positives(&self, class: usize) -> u64 {
+        self.counts[class][class]
+    }
+
+    pub fn false_positives(&self, class: usize) -> u64 {
+        (0..self.counts.len())
+            .filter(|&actual| actual != class)
+            .map(|actual| self.counts[actual][class])
+            .sum()
+    }
+
+    pub fn false_negatives(&self, class: usize) -> u64 {
+        self.counts[class].iter().sum::<u64>() - self.true_positives(class)
+    }
+
+    pub fn true_negatives(&self, class: usize) -> u64 {
+        self.total()
+            - self.true_positives(class)
+            - self.false_positives(class)
+            - self.false_negatives(class)
+    }
+
+    /// The full matrix, plus the one-vs-rest counts of every class.
+    pub fn to_map(&self) -> serde_json::Value {
+        let per_class: serde_json::Map<String, serde_json::Value> = self
+            .classes
+            .iter()
+            .enumerate()
+            .map(|(class, original)| {
+                (
+                    original.to_string(),
+                    json!({
+                        "truePositives": self.true_positives(class),
+                        "falsePositives": self.false_positives(class),
+                        "falseNegatives": self.false_negatives(class),
+                        "trueNegatives": self.true_negatives(class),
+                    }),
+                )
+            })
+            .collect();
+
+        json!({
+            "classes": self.classes,
+            "matrix": self.counts,
+            "perClass": per_class,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn array(values: &[i64]) -> HugeLongArray {
+        let mut array = HugeLongArray::new(values.len());
+        for (i, &value) in values.iter().enumerate() {
+            array.set(i, value);
+        }
+        array
+    }
+
+    #[test]
+    fn counts_pairs_and_one_vs_rest_totals() {
+        let class_id_map = LocalIdMap::of(&[7, 3, 5]);
+        let targets = array(&[0, 0, 1, 1, 2, 2]);
+        let predictions = array(&[0, 1, 1, 1, 2, 0]);
+
+        let matrix = ConfusionMatrix::compute(&targets, &predictions, &class_id_map);
+
+        assert_eq!(matrix.classes(), &[7, 3, 5]);
+        assert_eq!(matrix.count(0, 1), 1);
+        assert_eq!(matrix.count(2, 0), 1);
+        assert_eq!(matrix.true_positives(0), 1);
+        assert_eq!(matrix.false_positives(0), 1);
+        assert_eq!(matrix.false_negatives(0), 1);
+        assert_eq!(matrix.true_negatives(0), 3);
+
+        let map = matrix.to_map();
+        assert_eq!(map["matrix"][1], json!([0, 2, 0]));
+        assert_eq!(map["perClass"]["3"]["falsePositives"], json!(1));
+    }
+}
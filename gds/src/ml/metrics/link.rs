@@ -1,22 +1,78 @@
+use super::classification::roc_auc;
 use crate::ml::metrics::Metric;
 use crate::ml::metrics::MetricComparator;
 use crate::ml::metrics::OutOfBagError;
+use std::collections::HashMap;
 
-#[derive(Debug, Clone, Copy)]
+/// Link prediction metrics, computed over the positive and sampled negative examples.
+///
+/// The ranking metrics rank every positive relationship against the negatives sampled for
+/// it, that is the negatives in the same ranking group.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LinkMetric {
     AUCPR,
+    RocAuc,
+    LogLoss,
+    HitsAtK { k: usize, name: String },
+    MeanReciprocalRank,
 }
 
 impl LinkMetric {
-    pub fn parse_link_metric(name: &str) -> Box<dyn Metric> {
-        if name == OutOfBagError::NAME {
-            return Box::new(OutOfBagError::new());
+    /// Probabilities are clipped to `[EPSILON, 1]` to keep the log loss finite.
+    const LOG_LOSS_EPSILON: f64 = 1e-15;
+
+    pub fn hits_at(k: usize) -> Self {
+        LinkMetric::HitsAtK {
+            k,
+            name: format!("HITS@{k}"),
+        }
+    }
+
+    /// Parses a metric name such as `AUCPR`, `ROC_AUC`, `LOG_LOSS`, `HITS@10` or `MRR`,
+    /// case-insensitively.
+    pub fn parse(name: &str) -> Result<Self, String> {
+        let upper = name.trim().to_ascii_uppercase();
+        match upper.as_str() {
+            "AUCPR" => return Ok(LinkMetric::AUCPR),
+            "ROC_AUC" => return Ok(LinkMetric::RocAuc),
+            "LOG_LOSS" => return Ok(LinkMetric::LogLoss),
+            "MRR" | "MEAN_RECIPROCAL_RANK" => return Ok(LinkMetric::MeanReciprocalRank),
+            _ => {}
+        }
+
+        if let Some(k) = upper.strip_prefix("HITS@") {
+            return match k.parse::<usize>() {
+                Ok(k) if k > 0 => Ok(Self::hits_at(k)),
+                _ => Err(format!(
+                    "Invalid k in link metric `{name}`, expected a positive integer"
+                )),
+            };
         }
 
-        match name {
-            "AUCPR" => Box::new(LinkMetric::AUCPR),
-            _ => panic!("Metrics must be strings"),
+        Err(format!(
+            "Invalid link metric `{name}`. Available metrics are {}.",
+            Self::valid_metric_expressions().join(", ")
+        ))
+    }
+
+    pub fn valid_metric_expressions() -> Vec<&'static str> {
+        vec![
+            "AUCPR",
+            "ROC_AUC",
+            "LOG_LOSS",
+            "HITS@<k>",
+            "MRR",
+            OutOfBagError::NAME,
+        ]
+    }
+
+    /// Parses a link metric or `OUT_OF_BAG_ERROR`.
+    pub fn parse_link_metric(name: &str) -> Result<Box<dyn Metric>, String> {
+        if name.trim().eq_ignore_ascii_case(OutOfBagError::NAME) {
+            return Ok(Box::new(OutOfBagError::new()));
         }
+
+        Self::parse(name).map(|metric| Box::new(metric) as Box<dyn Metric>)
     }
 
     pub fn compute(
@@ -26,6 +82,39 @@ impl LinkMetric {
     ) -> f64 {
         match self {
             LinkMetric::AUCPR => self.compute_aucpr(signed_probabilities, negative_class_weight),
+            LinkMetric::RocAuc => roc_auc(
+                signed_probabilities.probabilities(),
+                signed_probabilities.labels(),
+            ),
+            LinkMetric::LogLoss => {
+                let probabilities = signed_probabilities.probabilities();
+                if probabilities.is_empty() {
+                    return 0.0;
+                }
+                let loss: f64 = probabilities
+                    .iter()
+                    .zip(signed_probabilities.labels())
+                    .map(|(&p, &is_positive)| {
+                        let likelihood = if is_positive { p } else { 1.0 - p };
+                        -likelihood.clamp(Self::LOG_LOSS_EPSILON, 1.0).ln()
+                    })
+                    .sum();
+                loss / probabilities.len() as f64
+            }
+            LinkMetric::HitsAtK { k, .. } => {
+                let ranks = positive_ranks(signed_probabilities);
+                if ranks.is_empty() {
+                    return 0.0;
+                }
+                ranks.iter().filter(|&&rank| rank <= *k as f64).count() as f64 / ranks.len() as f64
+            }
+            LinkMetric::MeanReciprocalRank => {
+                let ranks = positive_ranks(signed_probabilities);
+                if ranks.is_empty() {
+                    return 0.0;
+                }
+                ranks.iter().map(|rank| 1.0 / rank).sum::<f64>() / ranks.len() as f64
+            }
         }
     }
 
@@ -47,12 +136,16 @@ impl LinkMetric {
         let mut prev_recall = 0.0;
         let mut prev_precision = 1.0;
 
-        for &prob in signed_probabilities.probabilities() {
-            if prob.abs() < SignedProbabilities::ALMOST_ZERO {
+        for (&prob, &is_positive) in signed_probabilities
+            .probabilities()
+            .iter()
+            .zip(signed_probabilities.labels())
+        {
+            if prob < SignedProbabilities::ALMOST_ZERO {
                 continue;
             }
 
-            if prob > 0.0 {
+            if is_positive {
                 true_positives += 1.0;
             } else {
                 false_positives += negative_class_weight;
@@ -74,21 +167,79 @@ impl LinkMetric {
     }
 }
 
+/// The rank of every positive among the negatives of its group: one plus the number of
+/// those negatives scoring higher, with ties counting half.
+fn positive_ranks(signed_probabilities: &SignedProbabilities) -> Vec<f64> {
+    let mut groups: HashMap<u64, Vec<(f64, bool)>> = HashMap::new();
+    for ((&probability, &is_positive), &group) in signed_probabilities
+        .probabilities()
+        .iter()
+        .zip(signed_probabilities.labels())
+        .zip(signed_probabilities.groups())
+    {
+        groups
+            .entry(group)
+            .or_default()
+            .push((probability, is_positive));
+    }
+
+    let mut ranks = Vec::with_capacity(signed_probabilities.positive_count());
+    for examples in groups.values() {
+        group_positive_ranks(examples, &mut ranks);
+    }
+    ranks
+}
+
+fn group_positive_ranks(examples: &[(f64, bool)], ranks: &mut Vec<f64>) {
+    // Examples are sorted by probability, so walking them from the top tells how many
+    // negatives outrank each positive.
+    let mut negatives_above = 0usize;
+    let mut end = examples.len();
+    while end > 0 {
+        let score = examples[end - 1].0;
+        let mut start = end - 1;
+        while start > 0 && examples[start - 1].0 == score {
+            start -= 1;
+        }
+        let tied = &examples[start..end];
+        let tied_negatives = tied.iter().filter(|(_, is_positive)| !is_positive).count();
+        for _ in tied.iter().filter(|(_, is_positive)| *is_positive) {
+            ranks.push(1.0 + negatives_above as f64 + tied_negatives as f64 / 2.0);
+        }
+        negatives_above += tied_negatives;
+        end = start;
+    }
+}
+
 impl Metric for LinkMetric {
     fn name(&self) -> &str {
         match self {
             LinkMetric::AUCPR => "AUCPR",
+            LinkMetric::RocAuc => "ROC_AUC",
+            LinkMetric::LogLoss => "LOG_LOSS",
+            LinkMetric::HitsAtK { name, .. } => name,
+            LinkMetric::MeanReciprocalRank => "MRR",
         }
     }
 
     fn comparator(&self) -> MetricComparator {
-        MetricComparator::Natural
+        match self {
+            LinkMetric::LogLoss => MetricComparator::Inverse,
+            _ => MetricComparator::Natural,
+        }
     }
 }
 
+/// Predicted probabilities of the positive class with their true labels, sorted by
+/// probability. Each example also carries its ranking group.
+///
+/// Labels are kept apart from the probabilities, so a positive scored `0.0` still counts
+/// as a positive.
 #[derive(Debug, Clone)]
 pub struct SignedProbabilities {
     probabilities: Vec<f64>,
+    labels: Vec<bool>,
+    groups: Vec<u64>,
     positive_count: usize,
     negative_count: usize,
 }
@@ -96,14 +247,44 @@ pub struct SignedProbabilities {
 impl SignedProbabilities {
     pub const ALMOST_ZERO: f64 = 1e-100;
 
-    pub fn new(mut probabilities: Vec<f64>, positive_count: usize, negative_count: usize) -> Self {
-        probabilities.sort_by(|a, b| {
-            a.abs()
-                .partial_cmp(&b.abs())
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+    /// All examples share one ranking group.
+    pub fn new(probabilities: Vec<f64>, labels: Vec<bool>) -> Self {
+        let groups = vec![0; probabilities.len()];
+        Self::with_groups(probabilities, labels, groups)
+    }
+
+    pub fn with_groups(probabilities: Vec<f64>, labels: Vec<bool>, groups: Vec<u64>) -> Self {
+        assert_eq!(
+            probabilities.len(),
+            labels.len(),
+            "every probability needs a label"
+        );
+        assert_eq!(
+            probabilities.len(),
+            groups.len(),
+            "every probability needs a ranking group"
+        );
+        let positive_count = labels.iter().filter(|&&is_positive| is_positive).count();
+        let negative_count = labels.len() - positive_count;
+        let mut examples: Vec<(f64, bool, u64)> = probabilities
+            .into_iter()
+            .zip(labels)
+            .zip(groups)
+            .map(|((probability, is_positive), group)| (probability, is_positive, group))
+            .collect();
+        examples.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        let mut probabilities = Vec::with_capacity(examples.len());
+        let mut labels = Vec::with_capacity(examples.len());
+        let mut groups = Vec::with_capacity(examples.len());
+        for (probability, is_positive, group) in examples {
+            probabilities.push(probability);
+            labels.push(is_positive);
+            groups.push(group);
+        }
         Self {
             probabilities,
+            labels,
+            groups,
             positive_count,
             negative_count,
         }
@@ -120,34 +301,140 @@ impl SignedProbabilities {
     pub fn probabilities(&self) -> &[f64] {
         &self.probabilities
     }
+
+    /// True label of each probability, in the same order.
+    pub fn labels(&self) -> &[bool] {
+        &self.labels
+    }
+
+    /// Ranking group of each probability, in the same order.
+    pub fn groups(&self) -> &[u64] {
+        &self.groups
+    }
 }
 
 pub struct SignedProbabilitiesBuilder {
     probabilities: Vec<f64>,
-    positive_count: usize,
-    negative_count: usize,
+    labels: Vec<bool>,
+    groups: Vec<u64>,
 }
 
 impl SignedProbabilitiesBuilder {
     pub fn new(concurrency: usize) -> Self {
         Self {
             probabilities: Vec::with_capacity(concurrency),
-            positive_count: 0,
-            negative_count: 0,
+            labels: Vec::with_capacity(concurrency),
+            groups: Vec::with_capacity(concurrency),
         }
     }
 
+    /// Adds an example to the default ranking group.
     pub fn add_probability(&mut self, prob: f64, is_positive: bool) {
-        self.probabilities
-            .push(if is_positive { prob } else { -prob });
-        if is_positive {
-            self.positive_count += 1;
-        } else {
-            self.negative_count += 1;
-        }
+        self.add_grouped_probability(prob, is_positive, 0);
+    }
+
+    /// Adds an example; a positive is only ranked against negatives of the same `group`.
+    pub fn add_grouped_probability(&mut self, prob: f64, is_positive: bool, group: u64) {
+        self.probabilities.push(prob);
+        self.labels.push(is_positive);
+        self.groups.push(group);
     }
 
     pub fn build(self) -> SignedProbabilities {
-        SignedProbabilities::new(self.probabilities, self.positive_count, self.negative_count)
+        SignedProbabilities::with_groups(self.probabilities, self.labels, self.groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Positives scored 0.9 and 0.5, negatives 0.7, 0.3 and 0.1.
+    fn signed_probabilities() -> SignedProbabilities {
+        let mut builder = SignedProbabilitiesBuilder::new(5);
+        builder.add_probability(0.9, true);
+        builder.add_probability(0.5, true);
+        builder.add_probability(0.7, false);
+        builder.add_probability(0.3, false);
+        builder.add_probability(0.1, false);
+        builder.build()
+    }
+
+    #[test]
+    fn ranks_positives_against_sampled_negatives() {
+        let probabilities = signed_probabilities();
+
+        assert_eq!(LinkMetric::hits_at(1).compute(&probabilities, 1.0), 0.5);
+        assert_eq!(LinkMetric::hits_at(2).compute(&probabilities, 1.0), 1.0);
+        let mrr = LinkMetric::MeanReciprocalRank.compute(&probabilities, 1.0);
+        assert!((mrr - 0.75).abs() < 1e-12);
+        let auc = LinkMetric::RocAuc.compute(&probabilities, 1.0);
+        assert!((auc - 5.0 / 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ranks_each_positive_against_its_own_negatives() {
+        // Group 1 ranks its positive first, group 2 ranks its positive below both negatives,
+        // although the group 1 negatives outscore the group 2 positive globally.
+        let mut builder = SignedProbabilitiesBuilder::new(6);
+        builder.add_grouped_probability(0.6, true, 1);
+        builder.add_grouped_probability(0.95, false, 2);
+        builder.add_grouped_probability(0.5, false, 1);
+        builder.add_grouped_probability(0.4, false, 1);
+        builder.add_grouped_probability(0.2, true, 2);
+        builder.add_grouped_probability(0.3, false, 2);
+        let probabilities = builder.build();
+
+        assert_eq!(LinkMetric::hits_at(1).compute(&probabilities, 1.0), 0.5);
+        assert_eq!(LinkMetric::hits_at(2).compute(&probabilities, 1.0), 0.5);
+        let mrr = LinkMetric::MeanReciprocalRank.compute(&probabilities, 1.0);
+        assert!((mrr - (1.0 + 1.0 / 3.0) / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn log_loss_penalizes_confident_mistakes() {
+        let probabilities = signed_probabilities();
+        let expected = -[0.9f64, 0.5, 0.3, 0.7, 0.9]
+            .iter()
+            .map(|p| p.ln())
+            .sum::<f64>()
+            / 5.0;
+        let loss = LinkMetric::LogLoss.compute(&probabilities, 1.0);
+        assert!((loss - expected).abs() < 1e-12);
+        assert_eq!(LinkMetric::LogLoss.comparator(), MetricComparator::Inverse);
+    }
+
+    #[test]
+    fn positive_scored_zero_stays_positive() {
+        let mut builder = SignedProbabilitiesBuilder::new(2);
+        builder.add_probability(0.0, true);
+        builder.add_probability(0.4, false);
+        let probabilities = builder.build();
+
+        assert_eq!(probabilities.positive_count(), 1);
+        assert_eq!(probabilities.labels(), &[true, false]);
+        assert_eq!(LinkMetric::hits_at(1).compute(&probabilities, 1.0), 0.0);
+        assert_eq!(LinkMetric::hits_at(2).compute(&probabilities, 1.0), 1.0);
+        assert_eq!(LinkMetric::RocAuc.compute(&probabilities, 1.0), 0.0);
+        let loss = LinkMetric::LogLoss.compute(&probabilities, 1.0);
+        let expected = (-LinkMetric::LOG_LOSS_EPSILON.ln() - 0.6f64.ln()) / 2.0;
+        assert!((loss - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn parses_metric_names() {
+        assert_eq!(LinkMetric::parse("hits@10"), Ok(LinkMetric::hits_at(10)));
+        assert_eq!(LinkMetric::parse("MRR"), Ok(LinkMetric::MeanReciprocalRank));
+        assert_eq!(LinkMetric::hits_at(10).name(), "HITS@10");
+        assert!(LinkMetric::parse("HITS@0").is_err());
+        assert!(LinkMetric::parse("ACCURACY").is_err());
+        assert_eq!(LinkMetric::parse("log_loss"), Ok(LinkMetric::LogLoss));
+        assert_eq!(
+            LinkMetric::parse_link_metric("OUT_OF_BAG_ERROR")
+                .unwrap()
+                .name(),
+            OutOfBagError::NAME
+        );
+        assert!(LinkMetric::parse_link_metric("ACCURACY").is_err());
     }
 }
@@ -32,6 +32,14 @@ impl MetricComparator {
             Self::Inverse => b.partial_cmp(&a).unwrap_or(Ordering::Equal),
         }
     }
+
+    /// Maps a score so that higher is better.
+    pub fn orient(&self, score: f64) -> f64 {
+        match self {
+            Self::Natural => score,
+            Self::Inverse => -score,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,19 +47,36 @@ pub struct EvaluationScores {
     pub avg: f64,
     pub min: f64,
     pub max: f64,
+    /// The score of every fold, in fold order; empty when not computed per fold.
+    #[serde(default)]
+    pub folds: Vec<f64>,
 }
 
 impl EvaluationScores {
     pub fn new(avg: f64, min: f64, max: f64) -> Self {
-        Self { avg, min, max }
+        Self {
+            avg,
+            min,
+            max,
+            folds: Vec::new(),
+        }
+    }
+
+    pub fn with_folds(mut self, folds: Vec<f64>) -> Self {
+        self.folds = folds;
+        self
     }
 
     pub fn to_map(&self) -> serde_json::Value {
-        serde_json::json!({
+        let mut map = serde_json::json!({
             "avg": self.avg,
             "min": self.min,
             "max": self.max,
-        })
+        });
+        if !self.folds.is_empty() {
+            map["folds"] = serde_json::json!(self.folds);
+        }
+        map
     }
 }
 
@@ -64,6 +89,7 @@ pub struct ModelStatsBuilder {
     min: HashMap<String, f64>,
     max: HashMap<String, f64>,
     sum: HashMap<String, f64>,
+    folds: HashMap<String, Vec<f64>>,
     num_splits: usize,
 }
 
@@ -73,6 +99,7 @@ impl ModelStatsBuilder {
             min: HashMap::new(),
             max: HashMap::new(),
             sum: HashMap::new(),
+            folds: HashMap::new(),
             num_splits,
         }
     }
@@ -88,9 +115,14 @@ impl ModelStatsBuilder {
             .and_modify(|e| *e = e.max(value))
             .or_insert(value);
         self.sum
-            .entry(key)
+            .entry(key.clone())
             .and_modify(|e| *e += value)
             .or_insert(value);
+        self.folds.entry(key).or_default().push(value);
+    }
+
+    fn folds_of(&self, key: &str) -> Vec<f64> {
+        self.folds.get(key).cloned().unwrap_or_default()
     }
 
     pub fn build(&self) -> HashMap<String, EvaluationScores> {
@@ -99,7 +131,10 @@ impl ModelStatsBuilder {
             let min = self.min[key];
             let max = self.max[key];
             let avg = sum / self.num_splits as f64;
-            scores.insert(key.clone(), EvaluationScores::new(avg, min, max));
+            scores.insert(
+                key.clone(),
+                EvaluationScores::new(avg, min, max).with_folds(self.folds_of(key)),
+            );
         }
         scores
     }
@@ -109,11 +144,10 @@ impl ModelStatsBuilder {
         if let (Some(&min), Some(&max), Some(&sum)) =
             (self.min.get(key), self.max.get(key), self.sum.get(key))
         {
-            Some(EvaluationScores::new(
-                sum / self.num_splits as f64,
-                min,
-                max,
-            ))
+            Some(
+                EvaluationScores::new(sum / self.num_splits as f64, min, max)
+                    .with_folds(self.folds_of(key)),
+            )
         } else {
             None
         }
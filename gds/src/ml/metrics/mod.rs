@@ -1,5 +1,6 @@
 pub mod classification;
 pub mod classification_specification;
+pub mod confusion_matrix;
 pub mod link;
 pub mod metric;
 pub mod model_specific_handler;
@@ -7,6 +8,7 @@ pub mod regression;
 
 pub use classification::*;
 pub use classification_specification::*;
+pub use confusion_matrix::*;
 pub use link::*;
 pub use metric::*;
 pub use model_specific_handler::*;
@@ -7,6 +7,8 @@ pub enum RegressionMetric {
     MeanSquaredError,
     RootMeanSquaredError,
     MeanAbsoluteError,
+    MeanAbsolutePercentageError,
+    RSquared,
 }
 
 impl RegressionMetric {
@@ -15,6 +17,8 @@ impl RegressionMetric {
             Self::MeanSquaredError => self.compute_mse(targets, predictions),
             Self::RootMeanSquaredError => self.compute_rmse(targets, predictions),
             Self::MeanAbsoluteError => self.compute_mae(targets, predictions),
+            Self::MeanAbsolutePercentageError => self.compute_mape(targets, predictions),
+            Self::RSquared => self.compute_r_squared(targets, predictions),
         }
     }
 
@@ -53,12 +57,64 @@ impl RegressionMetric {
         total_error / number_of_examples as f64
     }
 
+    /// Mean of `|target - prediction| / |target|`, skipping examples whose target is zero.
+    fn compute_mape(&self, targets: &HugeDoubleArray, predictions: &HugeDoubleArray) -> f64 {
+        debug_assert_eq!(targets.size(), predictions.size());
+
+        let mut total_error = 0.0;
+        let mut counted = 0usize;
+        for i in 0..targets.size() {
+            let target = targets.get(i);
+            if target.abs() < f64::EPSILON {
+                continue;
+            }
+            total_error += ((target - predictions.get(i)) / target).abs();
+            counted += 1;
+        }
+
+        if counted == 0 {
+            return 0.0;
+        }
+        total_error / counted as f64
+    }
+
+    /// Coefficient of determination, `1 - SS_res / SS_tot`.
+    /// Constant targets score 1 when predicted exactly and 0 otherwise.
+    fn compute_r_squared(&self, targets: &HugeDoubleArray, predictions: &HugeDoubleArray) -> f64 {
+        let number_of_examples = targets.size();
+        debug_assert_eq!(number_of_examples, predictions.size());
+        if number_of_examples == 0 {
+            return 0.0;
+        }
+
+        let mean = (0..number_of_examples).map(|i| targets.get(i)).sum::<f64>()
+            / number_of_examples as f64;
+        let mut residual_sum = 0.0;
+        let mut total_sum = 0.0;
+        for i in 0..number_of_examples {
+            let target = targets.get(i);
+            residual_sum += (target - predictions.get(i)).powi(2);
+            total_sum += (target - mean).powi(2);
+        }
+
+        if total_sum < f64::EPSILON {
+            return if residual_sum < f64::EPSILON {
+                1.0
+            } else {
+                0.0
+            };
+        }
+        1.0 - residual_sum / total_sum
+    }
+
     pub fn parse(input: &str) -> Self {
         let input_upper = input.to_ascii_uppercase();
         match input_upper.as_str() {
             "MEAN_SQUARED_ERROR" => Self::MeanSquaredError,
             "ROOT_MEAN_SQUARED_ERROR" => Self::RootMeanSquaredError,
             "MEAN_ABSOLUTE_ERROR" => Self::MeanAbsoluteError,
+            "MEAN_ABSOLUTE_PERCENTAGE_ERROR" => Self::MeanAbsolutePercentageError,
+            "R2" | "R_SQUARED" => Self::RSquared,
             _ => panic!(
                 "RegressionMetric `{}` is not supported. Must be one of: {:?}.",
                 input,
@@ -72,6 +128,8 @@ impl RegressionMetric {
             "MEAN_SQUARED_ERROR",
             "ROOT_MEAN_SQUARED_ERROR",
             "MEAN_ABSOLUTE_ERROR",
+            "MEAN_ABSOLUTE_PERCENTAGE_ERROR",
+            "R_SQUARED",
         ]
     }
 }
@@ -82,11 +140,16 @@ impl Metric for RegressionMetric {
             Self::MeanSquaredError => "MEAN_SQUARED_ERROR",
             Self::RootMeanSquaredError => "ROOT_MEAN_SQUARED_ERROR",
             Self::MeanAbsoluteError => "MEAN_ABSOLUTE_ERROR",
+            Self::MeanAbsolutePercentageError => "MEAN_ABSOLUTE_PERCENTAGE_ERROR",
+            Self::RSquared => "R_SQUARED",
         }
     }
 
     fn comparator(&self) -> MetricComparator {
-        MetricComparator::Inverse
+        match self {
+            Self::RSquared => MetricComparator::Natural,
+            _ => MetricComparator::Inverse,
+        }
     }
 }
 
@@ -95,3 +158,50 @@ impl std::fmt::Display for RegressionMetric {
         write!(f, "{}", self.name())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn array(values: &[f64]) -> HugeDoubleArray {
+        let mut array = HugeDoubleArray::new(values.len());
+        for (i, &value) in values.iter().enumerate() {
+            array.set(i, value);
+        }
+        array
+    }
+
+    #[test]
+    fn r_squared_compares_against_the_mean() {
+        let targets = array(&[1.0, 2.0, 3.0, 4.0]);
+
+        assert_eq!(RegressionMetric::RSquared.compute(&targets, &targets), 1.0);
+        let mean = array(&[2.5, 2.5, 2.5, 2.5]);
+        assert_eq!(RegressionMetric::RSquared.compute(&targets, &mean), 0.0);
+        assert_eq!(
+            RegressionMetric::RSquared.comparator(),
+            MetricComparator::Natural
+        );
+    }
+
+    #[test]
+    fn mape_skips_zero_targets() {
+        let targets = array(&[0.0, 2.0, 4.0]);
+        let predictions = array(&[5.0, 1.0, 5.0]);
+
+        let mape = RegressionMetric::MeanAbsolutePercentageError.compute(&targets, &predictions);
+        assert!((mape - 0.375).abs() < 1e-12);
+    }
+
+    #[test]
+    fn parses_new_metric_names() {
+        assert!(matches!(
+            RegressionMetric::parse("r2"),
+            RegressionMetric::RSquared
+        ));
+        assert!(matches!(
+            RegressionMetric::parse("mean_absolute_percentage_error"),
+            RegressionMetric::MeanAbsolutePercentageError
+        ));
+    }
+}
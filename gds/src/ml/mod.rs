@@ -1,5 +1,6 @@
 pub mod core;
 pub mod decision_tree;
+pub mod explain;
 pub mod gradient_descent;
 pub mod link_models;
 pub mod metrics;
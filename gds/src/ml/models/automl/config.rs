@@ -4,15 +4,22 @@ use super::hyperparameter::IntegerRangeParameter;
 use super::hyperparameter::NumericalRangeParameter;
 use super::parameter_parser::parse_concrete_parameters;
 use super::parameter_parser::parse_range_parameters;
+use crate::ml::core::optimizer::OptimizerConfig;
 use crate::ml::decision_tree::ClassifierImpurityCriterionType;
 use crate::ml::gradient_descent::GradientDescentConfig;
+use crate::ml::models::gradient_boosting::{
+    GradientBoostingClassifierTrainerConfig, GradientBoostingConfig,
+    GradientBoostingRegressorTrainerConfig,
+};
 use crate::ml::models::linear_regression::LinearRegressionTrainConfig;
 use crate::ml::models::logistic_regression::LogisticRegressionTrainConfig;
-use crate::ml::models::mlp::MLPClassifierTrainConfig;
+use crate::ml::models::mlp::{
+    MLPClassifierTrainConfig, MLPRegressionLoss, MLPRegressorTrainConfig,
+};
 use crate::ml::models::random_forest::{
     RandomForestClassifierTrainerConfig, RandomForestConfig, RandomForestRegressorTrainerConfig,
 };
-use crate::ml::models::svm::SVMClassifierTrainConfig;
+use crate::ml::models::svm::{SVMClassifierTrainConfig, SVMKernelType, SVMRegressorTrainConfig};
 use crate::ml::models::{TrainerConfig, TrainingMethod};
 use std::collections::{HashMap, HashSet};
 
@@ -23,6 +30,7 @@ pub const LOG_SCALE_PARAMETERS: &[&str] =
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NonNumericParameterType {
     String,
+    Boolean,
     List,
 }
 
@@ -32,7 +40,11 @@ pub fn is_log_scale_parameter(key: &str) -> bool {
 
 pub fn non_numeric_parameter_type(key: &str) -> Option<NonNumericParameterType> {
     match key {
-        "criterion" => Some(NonNumericParameterType::String),
+        "criterion" | "kernel" | "loss" => Some(NonNumericParameterType::String),
+        "optimizer" | "learningRateSchedule" | "learning_rate_schedule" => {
+            Some(NonNumericParameterType::String)
+        }
+        "nesterov" => Some(NonNumericParameterType::Boolean),
         "hiddenLayerSizes" | "hidden_layer_sizes" => Some(NonNumericParameterType::List),
         "classWeights" | "class_weights" => Some(NonNumericParameterType::List),
         _ => None,
@@ -53,6 +65,9 @@ impl TunableTrainerConfig {
         method: TrainingMethod,
     ) -> Result<Self, String> {
         let range_parameters = parse_range_parameters(user_input)?;
+        if uses_gradient_descent(method) {
+            OptimizerConfig::from_map(user_input)?;
+        }
         let defaults = create_trainer_config_from_map(HashMap::new(), method).to_map();
         let input_with_defaults = fill_defaults(user_input, &defaults);
         let concrete_parameters = parse_concrete_parameters(&input_with_defaults)?;
@@ -192,6 +207,16 @@ fn fill_defaults(
         .collect()
 }
 
+fn uses_gradient_descent(method: TrainingMethod) -> bool {
+    matches!(
+        method,
+        TrainingMethod::LogisticRegression
+            | TrainingMethod::MLPClassification
+            | TrainingMethod::LinearRegression
+            | TrainingMethod::MLPRegression
+    )
+}
+
 fn training_method_name(method: TrainingMethod) -> &'static str {
     match method {
         TrainingMethod::LogisticRegression => "LogisticRegression",
@@ -200,6 +225,10 @@ fn training_method_name(method: TrainingMethod) -> &'static str {
         TrainingMethod::MLPClassification => "MLPClassification",
         TrainingMethod::LinearRegression => "LinearRegression",
         TrainingMethod::RandomForestRegression => "RandomForestRegression",
+        TrainingMethod::GradientBoostingClassification => "GradientBoostingClassification",
+        TrainingMethod::GradientBoostingRegression => "GradientBoostingRegression",
+        TrainingMethod::MLPRegression => "MLPRegression",
+        TrainingMethod::SVMRegression => "SVMRegression",
     }
 }
 
@@ -218,6 +247,18 @@ pub fn create_trainer_config_from_map(
         TrainingMethod::RandomForestRegression => {
             Box::new(random_forest_regressor_from_map(&config_map))
         }
+        TrainingMethod::GradientBoostingClassification => {
+            Box::new(GradientBoostingClassifierTrainerConfig {
+                boosting: gradient_boosting_config_from_map(&config_map),
+            })
+        }
+        TrainingMethod::GradientBoostingRegression => {
+            Box::new(GradientBoostingRegressorTrainerConfig {
+                boosting: gradient_boosting_config_from_map(&config_map),
+            })
+        }
+        TrainingMethod::MLPRegression => Box::new(mlp_regressor_from_map(&config_map)),
+        TrainingMethod::SVMRegression => Box::new(svm_regressor_from_map(&config_map)),
     }
 }
 
@@ -246,12 +287,18 @@ fn logistic_regression_from_map(
     if let Some(values) = get_vec_f64(map, &["classWeights", "class_weights"]) {
         config.class_weights = Some(values);
     }
+    if let Ok(optimizer) = OptimizerConfig::from_map(map) {
+        config.optimizer = optimizer;
+    }
     config
 }
 
 fn svm_classifier_from_map(map: &HashMap<String, serde_json::Value>) -> SVMClassifierTrainConfig {
     let mut config = SVMClassifierTrainConfig::default();
 
+    if let Some(kernel) = svm_kernel_from_map(map) {
+        config.kernel = kernel;
+    }
     if let Some(value) = get_f64(map, &["c"]) {
         config.c = value;
     }
@@ -268,6 +315,39 @@ fn svm_classifier_from_map(map: &HashMap<String, serde_json::Value>) -> SVMClass
     config
 }
 
+fn svm_regressor_from_map(map: &HashMap<String, serde_json::Value>) -> SVMRegressorTrainConfig {
+    let mut config = SVMRegressorTrainConfig::default();
+
+    if let Some(kernel) = svm_kernel_from_map(map) {
+        config.kernel = kernel;
+    }
+    if let Some(value) = get_f64(map, &["c"]) {
+        config.c = value;
+    }
+    if let Some(value) = get_f64(map, &["epsilon"]) {
+        config.epsilon = value;
+    }
+    if let Some(value) = get_f64(map, &["tolerance"]) {
+        config.tolerance = value;
+    }
+    if let Some(value) = get_usize(map, &["maxIterations", "max_iterations"]) {
+        config.max_iterations = value;
+    }
+
+    config
+}
+
+/// `kernel: "rbf"` takes its width from `gamma`, defaulting to 1.0.
+fn svm_kernel_from_map(map: &HashMap<String, serde_json::Value>) -> Option<SVMKernelType> {
+    match get_string(map, &["kernel"])?.to_ascii_lowercase().as_str() {
+        "linear" => Some(SVMKernelType::Linear),
+        "rbf" => Some(SVMKernelType::Rbf {
+            gamma: get_f64(map, &["gamma"]).unwrap_or(1.0),
+        }),
+        _ => None,
+    }
+}
+
 fn linear_regression_from_map(
     map: &HashMap<String, serde_json::Value>,
 ) -> LinearRegressionTrainConfig {
@@ -290,6 +370,9 @@ fn linear_regression_from_map(
     if let Some(value) = get_f64(map, &["learningRate", "learning_rate"]) {
         builder.learning_rate(value);
     }
+    if let Ok(optimizer) = OptimizerConfig::from_map(map) {
+        builder.optimizer(optimizer);
+    }
 
     let gradient = builder
         .build()
@@ -333,6 +416,47 @@ fn mlp_classifier_from_map(map: &HashMap<String, serde_json::Value>) -> MLPClass
     if let Some(values) = get_vec_usize(map, &["hiddenLayerSizes", "hidden_layer_sizes"]) {
         config.hidden_layer_sizes = values;
     }
+    if let Ok(optimizer) = OptimizerConfig::from_map(map) {
+        config.optimizer = optimizer;
+    }
+    config
+}
+
+fn mlp_regressor_from_map(map: &HashMap<String, serde_json::Value>) -> MLPRegressorTrainConfig {
+    let mut config = MLPRegressorTrainConfig::default();
+    if let Some(value) = get_usize(map, &["batchSize", "batch_size"]) {
+        config.batch_size = value;
+    }
+    if let Some(value) = get_usize(map, &["minEpochs", "min_epochs"]) {
+        config.min_epochs = value;
+    }
+    if let Some(value) = get_usize(map, &["patience"]) {
+        config.patience = value;
+    }
+    if let Some(value) = get_usize(map, &["maxEpochs", "max_epochs"]) {
+        config.max_epochs = value;
+    }
+    if let Some(value) = get_f64(map, &["tolerance"]) {
+        config.tolerance = value;
+    }
+    if let Some(value) = get_f64(map, &["learningRate", "learning_rate"]) {
+        config.learning_rate = value;
+    }
+    if let Some(value) = get_f64(map, &["penalty"]) {
+        config.penalty = value;
+    }
+    if let Some(loss) = get_string(map, &["loss"]).and_then(|v| MLPRegressionLoss::parse(&v).ok()) {
+        config.loss = loss;
+    }
+    if let Some(value) = get_f64(map, &["huberDelta", "huber_delta"]) {
+        config.huber_delta = value;
+    }
+    if let Some(values) = get_vec_usize(map, &["hiddenLayerSizes", "hidden_layer_sizes"]) {
+        config.hidden_layer_sizes = values;
+    }
+    if let Ok(optimizer) = OptimizerConfig::from_map(map) {
+        config.optimizer = optimizer;
+    }
     config
 }
 
@@ -382,6 +506,43 @@ fn random_forest_config_from_map(map: &HashMap<String, serde_json::Value>) -> Ra
     config
 }
 
+fn gradient_boosting_config_from_map(
+    map: &HashMap<String, serde_json::Value>,
+) -> GradientBoostingConfig {
+    let mut config = GradientBoostingConfig::default();
+    if let Some(value) = get_f64(map, &["learningRate", "learning_rate"]) {
+        config.learning_rate = value;
+    }
+    if let Some(value) = get_usize(map, &["maxIterations", "max_iterations"]) {
+        config.max_iterations = value;
+    }
+    if let Some(value) = get_usize(map, &["maxDepth", "max_depth"]) {
+        config.max_depth = value;
+    }
+    if let Some(value) = get_usize(map, &["minSamplesSplit", "min_samples_split"]) {
+        config.min_samples_split = value;
+    }
+    if let Some(value) = get_usize(map, &["minSamplesLeaf", "min_samples_leaf"]) {
+        config.min_samples_leaf = value;
+    }
+    if let Some(value) = get_f64(map, &["subsampleRatio", "subsample_ratio"]) {
+        config.subsample_ratio = value;
+    }
+    if let Some(value) = get_f64(map, &["maxFeaturesRatio", "max_features_ratio"]) {
+        config.max_features_ratio = Some(value);
+    }
+    if let Some(value) = get_usize(map, &["patience"]) {
+        config.patience = value;
+    }
+    if let Some(value) = get_f64(map, &["validationRatio", "validation_ratio"]) {
+        config.validation_ratio = value;
+    }
+    if let Some(value) = get_f64(map, &["tolerance"]) {
+        config.tolerance = value;
+    }
+    config
+}
+
 fn get_f64(map: &HashMap<String, serde_json::Value>, keys: &[&str]) -> Option<f64> {
     keys.iter().find_map(|key| {
         map.get(*key).and_then(|value| match value {
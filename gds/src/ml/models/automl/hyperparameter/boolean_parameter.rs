@@ -0,0 +1,29 @@
+use super::concrete_parameter::ConcreteParameter;
+use super::concrete_parameter::ConcreteParameterValue;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Boolean parameter value
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BooleanParameter(pub bool);
+
+impl BooleanParameter {
+    /// Create a new BooleanParameter
+    ///
+    pub fn of(value: bool) -> Self {
+        Self(value)
+    }
+
+    /// Get the value
+    ///
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl ConcreteParameter for BooleanParameter {
+    fn value(&self) -> ConcreteParameterValue {
+        ConcreteParameterValue::Boolean(self.0)
+    }
+}
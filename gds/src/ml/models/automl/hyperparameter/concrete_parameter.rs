@@ -6,6 +6,7 @@ pub enum ConcreteParameterValue {
     Integer(i32),
     Double(f64),
     String(String),
+    Boolean(bool),
     IntList(Vec<i32>),
     DoubleList(Vec<f64>),
 }
@@ -18,6 +19,7 @@ impl ConcreteParameterValue {
                 .map(serde_json::Value::Number)
                 .unwrap_or(serde_json::Value::Null),
             Self::String(value) => serde_json::Value::String(value.clone()),
+            Self::Boolean(value) => serde_json::Value::Bool(*value),
             Self::IntList(values) => serde_json::Value::Array(
                 values
                     .iter()
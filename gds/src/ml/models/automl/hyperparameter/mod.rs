@@ -1,3 +1,4 @@
+pub mod boolean_parameter;
 pub mod concrete_parameter;
 pub mod double_parameter;
 pub mod double_range_parameter;
@@ -7,6 +8,7 @@ pub mod list_parameter;
 pub mod numerical_range_parameter;
 pub mod string_parameter;
 
+pub use boolean_parameter::*;
 pub use concrete_parameter::*;
 pub use double_parameter::*;
 pub use double_range_parameter::*;
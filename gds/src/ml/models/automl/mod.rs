@@ -3,9 +3,11 @@ pub mod hyperparameter;
 pub mod optimizer;
 pub mod parameter;
 pub mod parameter_parser;
+pub mod tree_parzen_estimator;
 
 pub use config::*;
 pub use hyperparameter::*;
 pub use optimizer::*;
 pub use parameter::*;
 pub use parameter_parser::*;
+pub use tree_parzen_estimator::*;
@@ -11,7 +11,19 @@ use std::collections::HashMap;
 
 /// HyperParameterOptimizer defines a strategy for searching through a hyperparameter space
 ///
-pub trait HyperParameterOptimizer: Iterator<Item = Box<dyn TrainerConfig>> {}
+/// Model selection reports the validation score of every fold back to the optimizer, so
+/// adaptive strategies can propose configs based on previous trials and abandon trials
+/// that are clearly worse than the ones before. Scores are oriented so that higher is better.
+pub trait HyperParameterOptimizer: Iterator<Item = Box<dyn TrainerConfig>> {
+    /// Reports the fold scores of the most recently proposed config.
+    /// A pruned trial reports the scores of the folds it finished.
+    fn report(&mut self, _fold_scores: &[f64]) {}
+
+    /// Whether the current trial should be abandoned given its finished fold scores.
+    fn should_prune(&self, _fold_scores: &[f64]) -> bool {
+        false
+    }
+}
 
 /// A basic implementation of random search through a hyperparameter space
 ///
@@ -38,29 +50,11 @@ impl RandomSearch {
         max_trials: usize,
         random_seed: Option<u64>,
     ) -> Self {
-        let mut ordered_parameter_space: Vec<_> = parameter_space.into_iter().collect();
-        ordered_parameter_space.sort_by_key(|(method, _)| training_method_rank(*method));
-        let ordered_configs: Vec<TunableTrainerConfig> = ordered_parameter_space
-            .into_iter()
-            .flat_map(|(_, configs)| configs)
-            .collect();
-        let concrete_configs: Vec<TunableTrainerConfig> = ordered_configs
-            .iter()
-            .filter(|config| config.is_concrete())
-            .cloned()
-            .collect();
-
-        let tunable_configs: Vec<TunableTrainerConfig> = ordered_configs
-            .iter()
-            .filter(|config| !config.is_concrete())
-            .cloned()
-            .collect();
+        let (concrete_configs, tunable_configs) = split_parameter_space(parameter_space);
 
         let number_of_concrete_trials = concrete_configs.len();
         let total_number_of_trials = max_trials + number_of_concrete_trials;
-        let random = random_seed
-            .map(StdRng::seed_from_u64)
-            .unwrap_or_else(StdRng::from_entropy);
+        let random = seeded_rng(random_seed);
 
         Self {
             concrete_configs,
@@ -147,6 +141,25 @@ impl Iterator for RandomSearch {
 
 impl HyperParameterOptimizer for RandomSearch {}
 
+/// Splits the parameter space into concrete and tunable configs, ordered by training
+/// method so that seeded searches do not depend on the map's iteration order.
+pub(crate) fn split_parameter_space(
+    parameter_space: HashMap<TrainingMethod, Vec<TunableTrainerConfig>>,
+) -> (Vec<TunableTrainerConfig>, Vec<TunableTrainerConfig>) {
+    let mut ordered_parameter_space: Vec<_> = parameter_space.into_iter().collect();
+    ordered_parameter_space.sort_by_key(|(method, _)| training_method_rank(*method));
+    ordered_parameter_space
+        .into_iter()
+        .flat_map(|(_, configs)| configs)
+        .partition(|config| config.is_concrete())
+}
+
+pub(crate) fn seeded_rng(random_seed: Option<u64>) -> StdRng {
+    random_seed
+        .map(StdRng::seed_from_u64)
+        .unwrap_or_else(StdRng::from_entropy)
+}
+
 fn training_method_rank(method: TrainingMethod) -> usize {
     match method {
         TrainingMethod::LogisticRegression => 0,
@@ -155,6 +168,10 @@ fn training_method_rank(method: TrainingMethod) -> usize {
         TrainingMethod::RandomForestRegression => 3,
         TrainingMethod::SVMClassification => 4,
         TrainingMethod::MLPClassification => 5,
+        TrainingMethod::GradientBoostingClassification => 6,
+        TrainingMethod::GradientBoostingRegression => 7,
+        TrainingMethod::MLPRegression => 8,
+        TrainingMethod::SVMRegression => 9,
     }
 }
 
@@ -240,4 +257,67 @@ mod tests {
         assert!(search.next().is_none());
         assert!(search.next().is_none());
     }
+
+    #[test]
+    fn materializes_optimizer_settings() {
+        let config = TunableTrainerConfig::of(
+            &HashMap::from([
+                ("optimizer".to_string(), json!("SGD")),
+                ("momentum".to_string(), json!({"range": [0.5, 0.9]})),
+                ("nesterov".to_string(), json!(true)),
+                ("learningRateSchedule".to_string(), json!("step")),
+            ]),
+            TrainingMethod::LogisticRegression,
+        )
+        .expect("valid optimizer parameters");
+
+        let map = config
+            .materialize(HashMap::from([("momentum".to_string(), json!(0.8))]))
+            .to_map();
+        assert_eq!(map["optimizer"], json!("SGD"));
+        assert_eq!(map["momentum"], json!(0.8));
+        assert_eq!(map["nesterov"], json!(true));
+        assert_eq!(map["learningRateSchedule"], json!("step"));
+
+        assert!(TunableTrainerConfig::of(
+            &HashMap::from([("optimizer".to_string(), json!("lbfgs"))]),
+            TrainingMethod::MLPClassification,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn materializes_regressor_kernel_and_loss_settings() {
+        let svm = TunableTrainerConfig::of(
+            &HashMap::from([
+                ("kernel".to_string(), json!("rbf")),
+                ("gamma".to_string(), json!({"range": [0.1, 2.0]})),
+                ("epsilon".to_string(), json!(0.2)),
+            ]),
+            TrainingMethod::SVMRegression,
+        )
+        .expect("valid svm regression parameters");
+        let map = svm
+            .materialize(HashMap::from([("gamma".to_string(), json!(0.5))]))
+            .to_map();
+        assert_eq!(map["method"], json!("SVMRegression"));
+        assert_eq!(map["kernel"], json!("rbf"));
+        assert_eq!(map["gamma"], json!(0.5));
+        assert_eq!(map["epsilon"], json!(0.2));
+
+        let mlp = TunableTrainerConfig::of(
+            &HashMap::from([
+                ("loss".to_string(), json!("huber")),
+                ("huberDelta".to_string(), json!({"range": [0.5, 2.0]})),
+            ]),
+            TrainingMethod::MLPRegression,
+        )
+        .expect("valid mlp regression parameters");
+        let map = mlp
+            .materialize(HashMap::from([("huberDelta".to_string(), json!(1.5))]))
+            .to_map();
+        assert_eq!(map["method"], json!("MLPRegression"));
+        assert_eq!(map["loss"], json!("huber"));
+        assert_eq!(map["huberDelta"], json!(1.5));
+    }
 }
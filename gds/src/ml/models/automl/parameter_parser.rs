@@ -1,6 +1,7 @@
 use super::config::is_log_scale_parameter;
 use super::config::non_numeric_parameter_type;
 use super::config::NonNumericParameterType;
+use super::hyperparameter::BooleanParameter;
 use super::hyperparameter::ConcreteParameter;
 use super::hyperparameter::DoubleParameter;
 use super::hyperparameter::DoubleRangeParameter;
@@ -95,6 +96,7 @@ pub fn parse_range_parameters(
             .map(|(key, value)| {
                 let type_name = match non_numeric_parameter_type(key) {
                     Some(NonNumericParameterType::String) => "String",
+                    Some(NonNumericParameterType::Boolean) => "Boolean",
                     Some(NonNumericParameterType::List) => "List",
                     None => "Unknown",
                 };
@@ -173,6 +175,13 @@ fn parse_concrete_non_numeric_parameter(
                 &[key, "String"],
             )),
         },
+        Some(NonNumericParameterType::Boolean) => match value {
+            serde_json::Value::Bool(flag) => Ok(Box::new(BooleanParameter::of(*flag))),
+            _ => Err(format_with_locale(
+                "Parameter `%s` must be of the type `%s`.",
+                &[key, "Boolean"],
+            )),
+        },
         Some(NonNumericParameterType::List) => match value {
             serde_json::Value::Array(values) => {
                 if matches!(key, "hiddenLayerSizes" | "hidden_layer_sizes") {
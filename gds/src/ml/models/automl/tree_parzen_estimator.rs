@@ -0,0 +1,430 @@
+use super::config::TunableTrainerConfig;
+use super::hyperparameter::DoubleRangeParameter;
+use super::hyperparameter::IntegerRangeParameter;
+use super::hyperparameter::NumericalRangeParameter;
+use super::optimizer::seeded_rng;
+use super::optimizer::split_parameter_space;
+use super::optimizer::HyperParameterOptimizer;
+use crate::ml::models::TrainerConfig;
+use crate::ml::models::TrainingMethod;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::HashMap;
+
+/// Trials sampled uniformly at random before the density models are used.
+const STARTUP_TRIALS: usize = 5;
+/// Fraction of the scored trials that make up the "good" density.
+const GAMMA: f64 = 0.25;
+/// Candidates drawn from the good density per proposal; the one maximizing l(x) / g(x) wins.
+const EI_CANDIDATES: usize = 24;
+/// Lower bound for the kernel bandwidth in the unit hypercube.
+const MIN_BANDWIDTH: f64 = 0.05;
+/// Smallest value for log-scaled ranges starting at zero, matching `RandomSearch`.
+const MIN_LOG_VALUE: f64 = 1e-20;
+
+/// A Tree-structured Parzen Estimator (Bergstra et al., 2011) over a hyperparameter space.
+///
+/// Concrete configs are tried first. The first tunable trials are sampled uniformly;
+/// after that, trials are split by score into a good and a bad group. One Parzen density
+/// per group and hyperparameter is built, and the candidate that maximizes the ratio of the
+/// good to the bad density is proposed. The choice between several tunable configs
+/// is treated as an extra categorical hyperparameter.
+///
+/// With pruning enabled, a trial is abandoned once the mean of its finished folds falls
+/// below the median of what earlier trials achieved on the same folds.
+pub struct TreeParzenEstimator {
+    concrete_configs: Vec<TunableTrainerConfig>,
+    tunable_configs: Vec<TunableTrainerConfig>,
+    total_number_of_trials: usize,
+    number_of_finished_trials: usize,
+    pruning: bool,
+    random: StdRng,
+    pending: Option<Trial>,
+    history: Vec<ScoredTrial>,
+}
+
+/// A proposed trial: the tunable config it materializes, and its position in the
+/// unit hypercube of that config's ranges. Concrete trials have no point.
+struct Trial {
+    config_idx: Option<usize>,
+    point: Vec<f64>,
+}
+
+struct ScoredTrial {
+    trial: Trial,
+    fold_scores: Vec<f64>,
+}
+
+impl ScoredTrial {
+    fn score(&self) -> f64 {
+        mean(&self.fold_scores)
+    }
+}
+
+impl TreeParzenEstimator {
+    pub fn new(
+        parameter_space: HashMap<TrainingMethod, Vec<TunableTrainerConfig>>,
+        max_trials: usize,
+        random_seed: Option<u64>,
+    ) -> Self {
+        let (concrete_configs, tunable_configs) = split_parameter_space(parameter_space);
+        let total_number_of_trials = max_trials + concrete_configs.len();
+
+        Self {
+            concrete_configs,
+            tunable_configs,
+            total_number_of_trials,
+            number_of_finished_trials: 0,
+            pruning: true,
+            random: seeded_rng(random_seed),
+            pending: None,
+            history: Vec::new(),
+        }
+    }
+
+    /// Enables or disables pruning of clearly worse trials.
+    pub fn with_pruning(mut self, pruning: bool) -> Self {
+        self.pruning = pruning;
+        self
+    }
+
+    pub fn has_next(&self) -> bool {
+        (self.number_of_finished_trials < self.concrete_configs.len())
+            || (self.number_of_finished_trials < self.total_number_of_trials
+                && !self.tunable_configs.is_empty())
+    }
+
+    fn scored_tunable_trials(&self) -> Vec<&ScoredTrial> {
+        self.history
+            .iter()
+            .filter(|scored| scored.trial.config_idx.is_some())
+            .collect()
+    }
+
+    fn propose(&mut self) -> Trial {
+        let mut scored = self.scored_tunable_trials();
+        if scored.len() < STARTUP_TRIALS {
+            return self.random_trial();
+        }
+
+        scored.sort_by(|a, b| b.score().total_cmp(&a.score()));
+        let number_of_good = ((GAMMA * scored.len() as f64).ceil() as usize).max(1);
+        let (good, bad) = scored.split_at(number_of_good);
+        let good = ParzenModel::fit(good, &self.tunable_configs);
+        let bad = ParzenModel::fit(bad, &self.tunable_configs);
+
+        let mut best: Option<(f64, Trial)> = None;
+        for _ in 0..EI_CANDIDATES {
+            let candidate = good.sample(&mut self.random);
+            let ratio = good.log_density(&candidate) - bad.log_density(&candidate);
+            if best
+                .as_ref()
+                .map_or(true, |(best_ratio, _)| ratio > *best_ratio)
+            {
+                best = Some((ratio, candidate));
+            }
+        }
+
+        best.map(|(_, trial)| trial)
+            .expect("at least one candidate is drawn")
+    }
+
+    fn random_trial(&mut self) -> Trial {
+        let config_idx = self.random.gen_range(0..self.tunable_configs.len());
+        let dimensions = dimensions(&self.tunable_configs[config_idx]);
+        let point = (0..dimensions).map(|_| self.random.gen::<f64>()).collect();
+        Trial {
+            config_idx: Some(config_idx),
+            point,
+        }
+    }
+
+    fn materialize(&self, trial: &Trial) -> Box<dyn TrainerConfig> {
+        let config_idx = trial.config_idx.expect("tunable trial");
+        let tunable_config = &self.tunable_configs[config_idx];
+        let mut coordinates = trial.point.iter().copied();
+        let mut hyper_parameter_values = HashMap::new();
+
+        for (name, range) in sorted(tunable_config.double_ranges()) {
+            let value = double_value(range, coordinates.next().unwrap_or(0.0));
+            hyper_parameter_values.insert(
+                name.clone(),
+                serde_json::Value::Number(serde_json::Number::from_f64(value).unwrap()),
+            );
+        }
+        for (name, range) in sorted(tunable_config.integer_ranges()) {
+            let value = integer_value(range, coordinates.next().unwrap_or(0.0));
+            hyper_parameter_values.insert(name.clone(), serde_json::Value::Number(value.into()));
+        }
+
+        tunable_config.materialize(hyper_parameter_values)
+    }
+}
+
+impl Iterator for TreeParzenEstimator {
+    type Item = Box<dyn TrainerConfig>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.has_next() {
+            return None;
+        }
+
+        let (trial, config) = if self.number_of_finished_trials < self.concrete_configs.len() {
+            let config =
+                self.concrete_configs[self.number_of_finished_trials].materialize(HashMap::new());
+            let trial = Trial {
+                config_idx: None,
+                point: Vec::new(),
+            };
+            (trial, config)
+        } else {
+            let trial = self.propose();
+            let config = self.materialize(&trial);
+            (trial, config)
+        };
+
+        self.number_of_finished_trials += 1;
+        self.pending = Some(trial);
+        Some(config)
+    }
+}
+
+impl HyperParameterOptimizer for TreeParzenEstimator {
+    fn report(&mut self, fold_scores: &[f64]) {
+        if fold_scores.is_empty() {
+            return;
+        }
+        if let Some(trial) = self.pending.take() {
+            self.history.push(ScoredTrial {
+                trial,
+                fold_scores: fold_scores.to_vec(),
+            });
+        }
+    }
+
+    fn should_prune(&self, fold_scores: &[f64]) -> bool {
+        if !self.pruning || fold_scores.is_empty() {
+            return false;
+        }
+
+        let finished_folds = fold_scores.len();
+        let mut previous: Vec<f64> = self
+            .history
+            .iter()
+            .filter(|scored| scored.fold_scores.len() >= finished_folds)
+            .map(|scored| mean(&scored.fold_scores[..finished_folds]))
+            .collect();
+        if previous.len() < STARTUP_TRIALS {
+            return false;
+        }
+
+        previous.sort_by(f64::total_cmp);
+        mean(fold_scores) < median(&previous)
+    }
+}
+
+/// Per-config Parzen densities over the unit hypercube, fitted to a group of trials.
+struct ParzenModel {
+    /// Smoothed probability of each tunable config.
+    config_weights: Vec<f64>,
+    /// The points of the group, per tunable config.
+    points: Vec<Vec<Vec<f64>>>,
+    dimensions: Vec<usize>,
+}
+
+impl ParzenModel {
+    fn fit(trials: &[&ScoredTrial], tunable_configs: &[TunableTrainerConfig]) -> Self {
+        let mut points = vec![Vec::new(); tunable_configs.len()];
+        for scored in trials {
+            if let Some(config_idx) = scored.trial.config_idx {
+                points[config_idx].push(scored.trial.point.clone());
+            }
+        }
+
+        // Laplace smoothing keeps every config reachable.
+        let total = (trials.len() + tunable_configs.len()) as f64;
+        let config_weights = points
+            .iter()
+            .map(|config_points| (config_points.len() + 1) as f64 / total)
+            .collect();
+
+        Self {
+            config_weights,
+            points,
+            dimensions: tunable_configs.iter().map(dimensions).collect(),
+        }
+    }
+
+    fn sample(&self, random: &mut StdRng) -> Trial {
+        let mut threshold = random.gen::<f64>() * self.config_weights.iter().sum::<f64>();
+        let mut config_idx = self.config_weights.len() - 1;
+        for (idx, weight) in self.config_weights.iter().enumerate() {
+            if threshold < *weight {
+                config_idx = idx;
+                break;
+            }
+            threshold -= weight;
+        }
+
+        let observations = &self.points[config_idx];
+        let bandwidth = bandwidth(observations.len());
+        let point = (0..self.dimensions[config_idx])
+            .map(|dim| {
+                // Component 0 is the uniform prior, the others are kernels around observations.
+                let component = random.gen_range(0..=observations.len());
+                if component == 0 {
+                    random.gen::<f64>()
+                } else {
+                    let center = observations[component - 1][dim];
+                    (center + bandwidth * standard_normal(random)).clamp(0.0, 1.0)
+                }
+            })
+            .collect();
+
+        Trial {
+            config_idx: Some(config_idx),
+            point,
+        }
+    }
+
+    fn log_density(&self, trial: &Trial) -> f64 {
+        let config_idx = trial.config_idx.expect("tunable trial");
+        let observations = &self.points[config_idx];
+        let bandwidth = bandwidth(observations.len());
+
+        let mut log_density = self.config_weights[config_idx].ln();
+        for (dim, &x) in trial.point.iter().enumerate() {
+            let kernels: f64 = observations
+                .iter()
+                .map(|observation| normal_density(x, observation[dim], bandwidth))
+                .sum();
+            log_density += ((1.0 + kernels) / (observations.len() + 1) as f64).ln();
+        }
+        log_density
+    }
+}
+
+fn dimensions(config: &TunableTrainerConfig) -> usize {
+    config.double_ranges().len() + config.integer_ranges().len()
+}
+
+fn sorted<T>(ranges: &HashMap<String, T>) -> Vec<(&String, &T)> {
+    let mut ranges: Vec<_> = ranges.iter().collect();
+    ranges.sort_by_key(|(name, _)| *name);
+    ranges
+}
+
+fn double_value(range: &DoubleRangeParameter, unit: f64) -> f64 {
+    if range.log_scale() {
+        let min = range.min().max(MIN_LOG_VALUE).ln();
+        let max = range.max().ln();
+        (min + unit * (max - min)).exp()
+    } else {
+        range.min() + unit * (range.max() - range.min())
+    }
+}
+
+/// Maps onto `min..max`, excluding `max` like `RandomSearch` does.
+fn integer_value(range: &IntegerRangeParameter, unit: f64) -> i32 {
+    let width = (range.max() - range.min()).max(1);
+    range.min() + ((unit * width as f64).floor() as i32).min(width - 1)
+}
+
+fn bandwidth(number_of_observations: usize) -> f64 {
+    (0.5 * ((number_of_observations + 1) as f64).powf(-0.2)).max(MIN_BANDWIDTH)
+}
+
+fn normal_density(x: f64, mean: f64, std_dev: f64) -> f64 {
+    let z = (x - mean) / std_dev;
+    (-0.5 * z * z).exp() / (std_dev * (2.0 * std::f64::consts::PI).sqrt())
+}
+
+/// Box-Muller transform.
+fn standard_normal(random: &mut StdRng) -> f64 {
+    let u1 = random.gen::<f64>().max(f64::MIN_POSITIVE);
+    let u2 = random.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len().max(1) as f64
+}
+
+fn median(sorted_values: &[f64]) -> f64 {
+    let middle = sorted_values.len() / 2;
+    if sorted_values.len() % 2 == 0 {
+        (sorted_values[middle - 1] + sorted_values[middle]) / 2.0
+    } else {
+        sorted_values[middle]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parameter_space() -> HashMap<TrainingMethod, Vec<TunableTrainerConfig>> {
+        let config = TunableTrainerConfig::of(
+            &HashMap::from([
+                ("penalty".to_string(), json!({"range": [0.0001, 1.0]})),
+                ("maxEpochs".to_string(), json!({"range": [10, 50]})),
+            ]),
+            TrainingMethod::LinearRegression,
+        )
+        .expect("valid linear parameter ranges");
+        HashMap::from([(TrainingMethod::LinearRegression, vec![config])])
+    }
+
+    fn penalty(config: &dyn TrainerConfig) -> f64 {
+        config.to_map()["penalty"].as_f64().unwrap()
+    }
+
+    /// Runs a full search where the score peaks at a penalty of 0.01.
+    fn search(seed: u64) -> Vec<f64> {
+        let mut tpe = TreeParzenEstimator::new(parameter_space(), 30, Some(seed));
+        let mut penalties = Vec::new();
+        while let Some(config) = tpe.next() {
+            let penalty = penalty(config.as_ref());
+            tpe.report(&[-(penalty.log10() + 2.0).abs()]);
+            penalties.push(penalty);
+        }
+        penalties
+    }
+
+    #[test]
+    fn seeded_search_is_deterministic() {
+        assert_eq!(search(42), search(42));
+        assert_eq!(search(42).len(), 30);
+    }
+
+    #[test]
+    fn proposals_concentrate_on_good_scores() {
+        let penalties = search(7);
+        let distance = |penalties: &[f64]| {
+            penalties
+                .iter()
+                .map(|p| (p.log10() + 2.0).abs())
+                .sum::<f64>()
+                / penalties.len() as f64
+        };
+
+        // Later proposals are closer to the optimum than the uniform startup trials.
+        assert!(distance(&penalties[20..]) < distance(&penalties[..STARTUP_TRIALS]));
+    }
+
+    #[test]
+    fn prunes_trials_below_the_median() {
+        let mut tpe = TreeParzenEstimator::new(parameter_space(), 10, Some(1));
+        for score in [0.5, 0.6, 0.7, 0.8, 0.9] {
+            tpe.next();
+            assert!(!tpe.should_prune(&[0.1]));
+            tpe.report(&[score, score]);
+        }
+
+        tpe.next();
+        assert!(tpe.should_prune(&[0.1]));
+        assert!(!tpe.should_prune(&[0.75]));
+        assert!(!tpe.with_pruning(false).should_prune(&[0.1]));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::collections::{HugeDoubleArray, HugeIntArray};
 use crate::ml::core::tensor::Matrix;
+use crate::ml::explain::{Explanation, FeatureImportance};
 use crate::ml::models::training_method::TrainingMethod;
 use anyhow::Result;
 use std::fmt::Debug;
@@ -52,6 +53,18 @@ pub trait Classifier: Send + Sync + Debug {
     /// batch: indices into the feature store
     /// features: the feature store
     fn predict_probabilities_batch(&self, batch: &[usize], features: &dyn Features) -> Matrix;
+
+    /// Importance of every feature derived from the model parameters alone, if the
+    /// model type supports it.
+    fn feature_importance(&self) -> Option<FeatureImportance> {
+        None
+    }
+
+    /// Additive per-feature explanation of the score of `class` for `features`,
+    /// if the model type supports it.
+    fn explain(&self, _features: &[f64], _class: usize) -> Option<Explanation> {
+        None
+    }
 }
 
 pub trait Regressor: Send + Sync + Debug {
@@ -63,6 +76,18 @@ pub trait Regressor: Send + Sync + Debug {
 
     /// Get self as Any for downcasting
     fn as_any(&self) -> &dyn std::any::Any;
+
+    /// Importance of every feature derived from the model parameters alone, if the
+    /// model type supports it.
+    fn feature_importance(&self) -> Option<FeatureImportance> {
+        None
+    }
+
+    /// Additive per-feature explanation of the prediction for `features`, if the
+    /// model type supports it.
+    fn explain(&self, _features: &[f64]) -> Option<Explanation> {
+        None
+    }
 }
 
 pub trait Features: Send + Sync {
@@ -2,6 +2,13 @@ use crate::task::memory::{Estimate, MemoryEstimation, MemoryEstimations, MemoryR
 use crate::ml::decision_tree::DecisionTreeClassifierTrainer;
 use crate::ml::decision_tree::DecisionTreeTrainerConfig;
 use crate::ml::models::base::TrainerConfigTrait;
+use crate::ml::decision_tree::DecisionTreeRegressorTrainer;
+use crate::ml::decision_tree::DecisionTreeTrainer;
+use crate::ml::decision_tree::TreeNode;
+use crate::ml::models::gradient_boosting::{
+    GradientBoostingClassifier, GradientBoostingClassifierData,
+    GradientBoostingClassifierTrainerConfig,
+};
 use crate::ml::models::logistic_regression::{
     LogisticRegressionClassifier, LogisticRegressionData,
 };
@@ -50,6 +57,13 @@ impl ClassifierFactory {
                     .expect("Invalid ClassifierData type for SVMClassification");
                 Box::new(SVMClassifier::new(svm_data.clone()))
             }
+            TrainingMethod::GradientBoostingClassification => {
+                let gb_data = classifier_data
+                    .as_any()
+                    .downcast_ref::<GradientBoostingClassifierData>()
+                    .expect("Invalid ClassifierData type for GradientBoostingClassification");
+                Box::new(GradientBoostingClassifier::new(gb_data.clone()))
+            }
             _ => panic!(
                 "No such classifier for training method: {:?}",
                 classifier_data.trainer_method()
@@ -90,6 +104,11 @@ impl ClassifierFactory {
                 let probs = Estimate::size_of_double_array(_batch_size * _number_of_classes.max(1));
                 MemoryRange::of(probs)
             }
+            TrainingMethod::GradientBoostingClassification => {
+                let scores = Estimate::size_of_double_array(_number_of_classes.max(1));
+                let probs = Estimate::size_of_double_array(_batch_size * _number_of_classes.max(1));
+                MemoryRange::of(scores + probs)
+            }
             _ => panic!("No such classifier for training method: {:?}", method),
         }
     }
@@ -181,6 +200,33 @@ impl ClassifierFactory {
                 )
             }
             TrainingMethod::SVMClassification => MemoryEstimations::empty(),
+            TrainingMethod::GradientBoostingClassification => {
+                let gb_config = (trainer_config as &dyn std::any::Any)
+                    .downcast_ref::<GradientBoostingClassifierTrainerConfig>()
+                    .expect("Invalid config type for GradientBoostingClassification");
+
+                let tree_config = std::sync::Arc::new(
+                    DecisionTreeTrainerConfig::builder()
+                        .max_depth(gb_config.boosting.max_depth)
+                        .min_split_size(gb_config.boosting.min_samples_split)
+                        .min_leaf_size(gb_config.boosting.min_samples_leaf)
+                        .build()
+                        .expect("Invalid decision tree config"),
+                );
+                let num_trees = gb_config.boosting.max_iterations
+                    * GradientBoostingClassifierData::number_of_scores(_number_of_classes);
+
+                MemoryEstimations::of_resident("GradientBoostingClassifierData", move |dim, _| {
+                    let training_samples = _number_of_training_samples(dim.node_count() as u64);
+                    let per_tree =
+                        <DecisionTreeRegressorTrainer as DecisionTreeTrainer<f64>>::estimate_tree_memory(
+                            tree_config.as_ref(),
+                            training_samples as usize,
+                            TreeNode::<f64>::leaf_memory_estimation(),
+                        );
+                    MemoryRange::of(per_tree.saturating_mul(num_trees))
+                })
+            }
             _ => panic!(
                 "No such classifier for training method: {:?}",
                 trainer_config.method()
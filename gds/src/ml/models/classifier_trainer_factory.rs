@@ -2,6 +2,9 @@ use crate::task::concurrency::Concurrency;
 use crate::task::concurrency::TerminationFlag;
 use crate::task::progress::{ProgressTracker, TaskProgressTracker, Tasks};
 use crate::ml::metrics::ModelSpecificMetricsHandler;
+use crate::ml::models::gradient_boosting::{
+    GradientBoostingClassifierTrainer, GradientBoostingClassifierTrainerConfig,
+};
 use crate::ml::models::logistic_regression::{
     LogisticRegressionTrainConfig, LogisticRegressionTrainer,
 };
@@ -64,6 +67,24 @@ impl ClassifierTrainerFactory {
                     Arc::new(metrics_handler.clone()),
                 ))
             }
+            TrainingMethod::GradientBoostingClassification => {
+                let gb_config = (config as &dyn std::any::Any)
+                    .downcast_ref::<GradientBoostingClassifierTrainerConfig>()
+                    .expect("Invalid config type for GradientBoostingClassification");
+
+                let base_task = Tasks::leaf("GradientBoostingClassifierTrainer".to_string());
+                let progress_tracker =
+                    TaskProgressTracker::with_concurrency(base_task, concurrency.value());
+
+                Box::new(GradientBoostingClassifierTrainer::new(
+                    *concurrency,
+                    number_of_classes,
+                    gb_config.clone(),
+                    random_seed,
+                    progress_tracker,
+                    _termination_flag.clone(),
+                ))
+            }
             TrainingMethod::MLPClassification => {
                 // In Java: new MLPClassifierTrainer(numberOfClasses, (MLPClassifierTrainConfig) config, randomSeed, ...)
                 let mlp_config = (config as &dyn std::any::Any)
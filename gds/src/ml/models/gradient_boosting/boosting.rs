@@ -0,0 +1,239 @@
+//! Shared machinery of the gradient boosting trainers.
+//!
+//! Both trainers fit one regression tree per round (per class, for multiclass
+//! classification) to the negative gradient of their loss, using the decision tree
+//! regressor with MSE splits. For the squared loss the resulting leaf means are
+//! already optimal; for the logistic loss they are replaced by a Newton step.
+
+use crate::collections::HugeDoubleArray;
+use crate::ml::decision_tree::DecisionTreePredictor;
+use crate::ml::decision_tree::DecisionTreeRegressorTrainer;
+use crate::ml::decision_tree::DecisionTreeTrainer;
+use crate::ml::decision_tree::DecisionTreeTrainerConfig;
+use crate::ml::decision_tree::FeatureBagger;
+use crate::ml::decision_tree::TreeNode;
+use crate::ml::models::gradient_boosting::GradientBoostingConfig;
+use crate::ml::models::Features;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::HashMap;
+
+pub(crate) fn rng(random_seed: Option<u64>) -> StdRng {
+    match random_seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Splits off the early stopping fold. Without early stopping, or when the fold
+/// would leave nothing to train on, all samples are used for training.
+pub(crate) fn holdout_split(
+    config: &GradientBoostingConfig,
+    train_set: &[u64],
+    rng: &mut StdRng,
+) -> (Vec<u64>, Vec<u64>) {
+    let mut samples = train_set.to_vec();
+    if !config.early_stopping() {
+        return (samples, Vec::new());
+    }
+
+    samples.shuffle(rng);
+    let validation_size = (config.validation_ratio * samples.len() as f64).floor() as usize;
+    if validation_size == 0 || validation_size == samples.len() {
+        return (train_set.to_vec(), Vec::new());
+    }
+
+    let train = samples.split_off(validation_size);
+    (train, samples)
+}
+
+/// Draws the samples of one boosting round without replacement.
+pub(crate) fn subsample(
+    config: &GradientBoostingConfig,
+    train: &[u64],
+    rng: &mut StdRng,
+) -> Vec<i64> {
+    if config.subsample_ratio >= 1.0 {
+        return train.iter().map(|&id| id as i64).collect();
+    }
+
+    let size = ((config.subsample_ratio * train.len() as f64).ceil() as usize).max(1);
+    train
+        .choose_multiple(rng, size)
+        .map(|&id| id as i64)
+        .collect()
+}
+
+pub(crate) fn tree_config(config: &GradientBoostingConfig) -> DecisionTreeTrainerConfig {
+    DecisionTreeTrainerConfig::builder()
+        .max_depth(config.max_depth)
+        .min_split_size(config.min_samples_split)
+        .min_leaf_size(config.min_samples_leaf)
+        .build()
+        .expect("Invalid decision tree config")
+}
+
+/// Fits one regression tree to `residuals` on the given samples.
+pub(crate) fn fit_tree(
+    config: &GradientBoostingConfig,
+    features: &dyn Features,
+    residuals: &HugeDoubleArray,
+    samples: &[i64],
+    seed: u64,
+) -> DecisionTreePredictor<f64> {
+    let feature_bagger = FeatureBagger::new(
+        seed,
+        features.feature_dimension(),
+        config.max_features_ratio(),
+    );
+    let mut trainer = DecisionTreeRegressorTrainer::new(
+        residuals.clone(),
+        features,
+        tree_config(config),
+        feature_bagger,
+    );
+    trainer.train(samples)
+}
+
+/// Replaces every leaf value by one Newton-Raphson step of the logistic loss,
+/// `scale * sum(r) / sum(|r| * (1 - |r|))` over the samples reaching the leaf,
+/// where `r` is the residual (indicator minus probability) of a sample.
+pub(crate) fn newton_leaves(
+    tree: &mut DecisionTreePredictor<f64>,
+    features: &dyn Features,
+    residuals: &HugeDoubleArray,
+    samples: &[i64],
+    scale: f64,
+) {
+    let mut sums: HashMap<*const TreeNode<f64>, (f64, f64)> = HashMap::new();
+    for &id in samples {
+        let leaf = leaf_of(&tree.root, features.get(id as usize));
+        let residual = residuals.get(id as usize);
+        let (gradient, hessian) = sums.entry(leaf as *const _).or_default();
+        *gradient += residual;
+        *hessian += residual.abs() * (1.0 - residual.abs());
+    }
+    set_newton_leaves(&mut tree.root, &sums, scale);
+}
+
+fn leaf_of<'t>(root: &'t TreeNode<f64>, features: &[f64]) -> &'t TreeNode<f64> {
+    let mut node = root;
+    while let (Some(left), Some(right)) = (node.left_child(), node.right_child()) {
+        node = if features[node.feature_index() as usize] < node.threshold_value() {
+            left
+        } else {
+            right
+        };
+    }
+    node
+}
+
+fn set_newton_leaves(
+    node: &mut TreeNode<f64>,
+    sums: &HashMap<*const TreeNode<f64>, (f64, f64)>,
+    scale: f64,
+) {
+    if node.has_left_child() {
+        if let Some(left) = node.left_child_mut() {
+            set_newton_leaves(left, sums, scale);
+        }
+        if let Some(right) = node.right_child_mut() {
+            set_newton_leaves(right, sums, scale);
+        }
+        return;
+    }
+
+    let key = node as *const TreeNode<f64>;
+    let (gradient, hessian) = sums.get(&key).copied().unwrap_or_default();
+    let value = if hessian > f64::EPSILON {
+        scale * gradient / hessian
+    } else {
+        0.0
+    };
+    node.set_prediction(value);
+}
+
+/// Tracks the validation loss and decides when to stop boosting.
+pub(crate) struct EarlyStopping {
+    patience: usize,
+    tolerance: f64,
+    best_loss: f64,
+    best_rounds: usize,
+    rounds_without_improvement: usize,
+}
+
+impl EarlyStopping {
+    pub(crate) fn new(config: &GradientBoostingConfig) -> Self {
+        Self {
+            patience: config.patience,
+            tolerance: config.tolerance,
+            best_loss: f64::INFINITY,
+            best_rounds: 0,
+            rounds_without_improvement: 0,
+        }
+    }
+
+    /// Records the loss after `rounds` rounds; returns true once patience ran out.
+    pub(crate) fn should_stop(&mut self, rounds: usize, validation_loss: f64) -> bool {
+        if validation_loss < self.best_loss - self.tolerance {
+            self.best_loss = validation_loss;
+            self.best_rounds = rounds;
+            self.rounds_without_improvement = 0;
+            return false;
+        }
+
+        self.rounds_without_improvement += 1;
+        self.rounds_without_improvement >= self.patience
+    }
+
+    /// Number of rounds that achieved the best validation loss.
+    pub(crate) fn best_rounds(&self) -> usize {
+        self.best_rounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(patience: usize) -> GradientBoostingConfig {
+        GradientBoostingConfig {
+            patience,
+            validation_ratio: 0.25,
+            ..GradientBoostingConfig::default()
+        }
+    }
+
+    #[test]
+    fn holdout_is_disjoint_and_complete() {
+        let train_set: Vec<u64> = (0..20).collect();
+        let (train, validation) = holdout_split(&config(2), &train_set, &mut rng(Some(7)));
+
+        assert_eq!(validation.len(), 5);
+        assert_eq!(train.len(), 15);
+        let mut all: Vec<u64> = train.iter().chain(&validation).copied().collect();
+        all.sort_unstable();
+        assert_eq!(all, train_set);
+    }
+
+    #[test]
+    fn no_holdout_without_early_stopping() {
+        let train_set: Vec<u64> = (0..20).collect();
+        let (train, validation) = holdout_split(&config(0), &train_set, &mut rng(Some(7)));
+
+        assert_eq!(train, train_set);
+        assert!(validation.is_empty());
+    }
+
+    #[test]
+    fn early_stopping_remembers_best_round() {
+        let mut early_stopping = EarlyStopping::new(&config(2));
+
+        assert!(!early_stopping.should_stop(1, 1.0));
+        assert!(!early_stopping.should_stop(2, 0.5));
+        assert!(!early_stopping.should_stop(3, 0.6));
+        assert!(early_stopping.should_stop(4, 0.5));
+        assert_eq!(early_stopping.best_rounds(), 2);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::ml::core::tensor::Matrix;
+use crate::ml::decision_tree::DecisionTreePredictor;
+use crate::ml::explain::{impurity_importance, tree_shap, Explanation, FeatureImportance};
+use crate::ml::models::BaseModelData;
+use crate::ml::models::Classifier;
+use crate::ml::models::ClassifierData;
+use crate::ml::models::Features;
+use crate::ml::models::ModelData;
+use crate::ml::models::TrainingMethod;
+use anyhow::anyhow;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+
+/// Gradient boosting classifier model.
+///
+/// Binary problems use a single logit per round, squashed with the sigmoid;
+/// multiclass problems boost one score per class and apply a softmax.
+pub struct GradientBoostingClassifier {
+    data: GradientBoostingClassifierData,
+}
+
+impl GradientBoostingClassifier {
+    pub fn new(data: GradientBoostingClassifierData) -> Self {
+        Self { data }
+    }
+
+    pub fn num_rounds(&self) -> usize {
+        self.data.rounds.len()
+    }
+}
+
+impl Classifier for GradientBoostingClassifier {
+    fn data(&self) -> &dyn ClassifierData {
+        &self.data
+    }
+
+    /// Predict class probabilities for a single feature vector
+    fn predict_probabilities(&self, features: &[f64]) -> Vec<f64> {
+        self.data.probabilities(&self.data.raw_scores(features))
+    }
+
+    /// Predict class probabilities for a batch of features
+    fn predict_probabilities_batch(&self, batch: &[usize], features: &dyn Features) -> Matrix {
+        let mut predicted_probabilities = Matrix::zeros(batch.len(), self.data.number_of_classes());
+
+        for (offset, &id) in batch.iter().enumerate() {
+            let probabilities = self.predict_probabilities(features.get(id));
+            for (class_idx, probability) in probabilities.iter().enumerate() {
+                predicted_probabilities[(offset, class_idx)] = *probability;
+            }
+        }
+
+        predicted_probabilities
+    }
+
+    fn feature_importance(&self) -> Option<FeatureImportance> {
+        Some(impurity_importance(
+            self.data.rounds.iter().flatten().map(|tree| &tree.root),
+            self.data.num_features,
+        ))
+    }
+
+    /// Explains the raw boosted score of `class`: its log-odds for binary models and
+    /// its softmax logit otherwise.
+    fn explain(&self, features: &[f64], class: usize) -> Option<Explanation> {
+        let (score, sign) = if self.data.initial_scores.len() == 1 {
+            (0, if class == 1 { 1.0 } else { -1.0 })
+        } else {
+            (class, 1.0)
+        };
+        let initial_score = *self.data.initial_scores.get(score)?;
+
+        let explanations: Vec<Explanation> = self
+            .data
+            .rounds
+            .iter()
+            .filter_map(|round| round.get(score))
+            .map(|tree| tree_shap(&tree.root, features, &|value: &f64| *value))
+            .collect();
+        let boosted = Explanation::weighted_sum(
+            explanations
+                .iter()
+                .map(|explanation| (sign * self.data.learning_rate, explanation)),
+            features.len(),
+        );
+
+        Some(Explanation::new(
+            sign * initial_score + boosted.base_value(),
+            boosted.contributions().to_vec(),
+        ))
+    }
+}
+
+/// Gradient Boosting Classifier Data.
+///
+/// `rounds[r][k]` is the tree fitted for score `k` in round `r`; there is one score
+/// for binary problems and one per class otherwise.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GradientBoostingClassifierData {
+    pub initial_scores: Vec<f64>,
+    pub rounds: Vec<Vec<DecisionTreePredictor<f64>>>,
+    pub learning_rate: f64,
+    pub num_classes: usize,
+    pub num_features: usize,
+}
+
+impl GradientBoostingClassifierData {
+    /// Number of boosted scores for the given number of classes.
+    pub fn number_of_scores(num_classes: usize) -> usize {
+        if num_classes <= 2 {
+            1
+        } else {
+            num_classes
+        }
+    }
+
+    /// Sum of the initial scores and all shrunk tree outputs.
+    pub fn raw_scores(&self, features: &[f64]) -> Vec<f64> {
+        let mut scores = self.initial_scores.clone();
+        for round in &self.rounds {
+            for (score, tree) in scores.iter_mut().zip(round) {
+                *score += self.learning_rate * *tree.predict(features);
+            }
+        }
+        scores
+    }
+
+    /// Turns raw scores into a distribution over the classes.
+    pub fn probabilities(&self, scores: &[f64]) -> Vec<f64> {
+        class_probabilities(self.num_classes, scores)
+    }
+}
+
+pub(crate) fn class_probabilities(num_classes: usize, scores: &[f64]) -> Vec<f64> {
+    match num_classes {
+        0 => Vec::new(),
+        1 => vec![1.0],
+        2 => {
+            let positive = sigmoid(scores[0]);
+            vec![1.0 - positive, positive]
+        }
+        _ => softmax(scores),
+    }
+}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+fn softmax(scores: &[f64]) -> Vec<f64> {
+    let max = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let exps: Vec<f64> = scores.iter().map(|score| (score - max).exp()).collect();
+    let sum: f64 = exps.iter().sum();
+    exps.into_iter().map(|e| e / sum).collect()
+}
+
+impl fmt::Debug for GradientBoostingClassifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GradientBoostingClassifier")
+            .field("num_rounds", &self.data.rounds.len())
+            .field("num_classes", &self.data.num_classes)
+            .field("num_features", &self.data.num_features)
+            .finish()
+    }
+}
+
+impl fmt::Debug for GradientBoostingClassifierData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GradientBoostingClassifierData")
+            .field("num_rounds", &self.rounds.len())
+            .field("learning_rate", &self.learning_rate)
+            .field("num_classes", &self.num_classes)
+            .field("num_features", &self.num_features)
+            .finish()
+    }
+}
+
+impl BaseModelData for GradientBoostingClassifierData {
+    fn trainer_method(&self) -> TrainingMethod {
+        TrainingMethod::GradientBoostingClassification
+    }
+
+    fn feature_dimension(&self) -> usize {
+        self.num_features
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ClassifierData for GradientBoostingClassifierData {
+    fn number_of_classes(&self) -> usize {
+        self.num_classes
+    }
+}
+
+impl ModelData for GradientBoostingClassifierData {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self)
+            .map_err(|err| anyhow!("GradientBoostingClassifierData serialization failed: {err}"))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bincode::deserialize(bytes)
+            .map_err(|err| anyhow!("GradientBoostingClassifierData deserialization failed: {err}"))
+    }
+
+    fn num_features(&self) -> usize {
+        self.num_features
+    }
+}
@@ -0,0 +1,291 @@
+use crate::collections::HugeDoubleArray;
+use crate::collections::HugeIntArray;
+use crate::ml::models::gradient_boosting::boosting;
+use crate::ml::models::gradient_boosting::boosting::EarlyStopping;
+use crate::ml::models::gradient_boosting::classifier::class_probabilities;
+use crate::ml::models::gradient_boosting::GradientBoostingClassifier;
+use crate::ml::models::gradient_boosting::GradientBoostingClassifierData;
+use crate::ml::models::gradient_boosting::GradientBoostingClassifierTrainerConfig;
+use crate::ml::models::Classifier;
+use crate::ml::models::ClassifierTrainer;
+use crate::ml::models::Features;
+use crate::task::concurrency::Concurrency;
+use crate::task::concurrency::TerminationFlag;
+use crate::task::progress::tasks::LogLevel;
+use crate::task::progress::tasks::ProgressTracker;
+use crate::task::progress::tasks::TaskProgressTracker;
+use std::sync::Arc;
+
+/// Keeps initial log-odds finite when a class is absent from the training set.
+const PRIOR_EPSILON: f64 = 1e-6;
+
+/// Gradient Boosting Classifier Trainer, minimizing the logistic loss.
+#[allow(dead_code)]
+pub struct GradientBoostingClassifierTrainer {
+    number_of_classes: usize,
+    config: GradientBoostingClassifierTrainerConfig,
+    concurrency: Concurrency,
+    random_seed: Option<u64>,
+    progress_tracker: TaskProgressTracker,
+    termination_flag: TerminationFlag,
+}
+
+impl GradientBoostingClassifierTrainer {
+    /// Create a new Gradient Boosting Classifier Trainer.
+    pub fn new(
+        concurrency: Concurrency,
+        number_of_classes: usize,
+        config: GradientBoostingClassifierTrainerConfig,
+        random_seed: Option<u64>,
+        progress_tracker: TaskProgressTracker,
+        termination_flag: TerminationFlag,
+    ) -> Self {
+        Self {
+            number_of_classes,
+            config,
+            concurrency,
+            random_seed,
+            progress_tracker,
+            termination_flag,
+        }
+    }
+
+    /// Log-odds (binary) or log-priors (multiclass) of the training labels.
+    fn initial_scores(&self, labels: &HugeIntArray, train: &[u64]) -> Vec<f64> {
+        let mut counts = vec![0usize; self.number_of_classes];
+        for &id in train {
+            let label = labels.get(id as usize) as usize;
+            if label < counts.len() {
+                counts[label] += 1;
+            }
+        }
+        let prior = |class: usize| {
+            (counts[class] as f64 / train.len().max(1) as f64)
+                .clamp(PRIOR_EPSILON, 1.0 - PRIOR_EPSILON)
+        };
+
+        match self.number_of_classes {
+            0 | 1 => vec![0.0],
+            2 => {
+                let positive = prior(1);
+                vec![(positive / (1.0 - positive)).ln()]
+            }
+            _ => (0..self.number_of_classes)
+                .map(|class| prior(class).ln())
+                .collect(),
+        }
+    }
+
+    fn train_internal(
+        &self,
+        features: &dyn Features,
+        labels: &HugeIntArray,
+        train_set: &[u64],
+    ) -> GradientBoostingClassifierData {
+        let config = &self.config.boosting;
+        let number_of_classes = self.number_of_classes;
+        let number_of_scores = GradientBoostingClassifierData::number_of_scores(number_of_classes);
+        let mut rng = boosting::rng(self.random_seed);
+        let (train, validation) = boosting::holdout_split(config, train_set, &mut rng);
+
+        let initial_scores = self.initial_scores(labels, &train);
+        let mut rounds = Vec::new();
+
+        if train.is_empty() || number_of_classes < 2 {
+            return GradientBoostingClassifierData {
+                initial_scores,
+                rounds,
+                learning_rate: config.learning_rate,
+                num_classes: number_of_classes,
+                num_features: features.feature_dimension(),
+            };
+        }
+
+        // The class whose indicator score `k` is fitted to.
+        let class_of_score = |k: usize| if number_of_scores == 1 { 1 } else { k };
+
+        let mut scores: Vec<HugeDoubleArray> = initial_scores
+            .iter()
+            .map(|&initial| {
+                let mut array = HugeDoubleArray::new(features.size());
+                for &id in train.iter().chain(&validation) {
+                    array.set(id as usize, initial);
+                }
+                array
+            })
+            .collect();
+        let mut residuals: Vec<HugeDoubleArray> = (0..number_of_scores)
+            .map(|_| HugeDoubleArray::new(features.size()))
+            .collect();
+        // Friedman's (K - 1) / K factor for the multiclass Newton step.
+        let newton_scale = if number_of_scores == 1 {
+            1.0
+        } else {
+            (number_of_classes - 1) as f64 / number_of_classes as f64
+        };
+        let probabilities_of = |scores: &[HugeDoubleArray], id: usize| {
+            let raw: Vec<f64> = scores.iter().map(|score| score.get(id)).collect();
+            class_probabilities(number_of_classes, &raw)
+        };
+        let mut early_stopping = EarlyStopping::new(config);
+
+        for round in 0..config.max_iterations {
+            self.termination_flag.assert_running();
+
+            // Negative gradient of the logistic loss: indicator minus probability.
+            for &id in &train {
+                let id = id as usize;
+                let label = labels.get(id) as usize;
+                let probabilities = probabilities_of(&scores, id);
+                for (k, residual) in residuals.iter_mut().enumerate() {
+                    let class = class_of_score(k);
+                    let indicator = if label == class { 1.0 } else { 0.0 };
+                    residual.set(id, indicator - probabilities[class]);
+                }
+            }
+
+            let samples = boosting::subsample(config, &train, &mut rng);
+            let mut trees = Vec::with_capacity(number_of_scores);
+            for (k, residual) in residuals.iter().enumerate() {
+                let tree_idx = (round * number_of_scores + k) as u64;
+                let seed = self.random_seed.map(|s| s + tree_idx).unwrap_or(tree_idx);
+                let mut tree = boosting::fit_tree(config, features, residual, &samples, seed);
+                boosting::newton_leaves(&mut tree, features, residual, &samples, newton_scale);
+                trees.push(tree);
+            }
+
+            for &id in train.iter().chain(&validation) {
+                let id = id as usize;
+                let feature_vec = features.get(id);
+                for (score, tree) in scores.iter_mut().zip(&trees) {
+                    let update = config.learning_rate * *tree.predict(feature_vec);
+                    score.set(id, score.get(id) + update);
+                }
+            }
+            rounds.push(trees);
+
+            let mut progress_tracker = self.progress_tracker.clone();
+            progress_tracker.log_message(
+                LogLevel::Info,
+                &format!(
+                    "Trained boosting round {} out of {}",
+                    round + 1,
+                    config.max_iterations
+                ),
+            );
+
+            if !validation.is_empty() {
+                let log_loss = validation
+                    .iter()
+                    .map(|&id| {
+                        let id = id as usize;
+                        let label = labels.get(id) as usize;
+                        let probability = probabilities_of(&scores, id)
+                            .get(label)
+                            .copied()
+                            .unwrap_or(0.0);
+                        -probability.max(f64::MIN_POSITIVE).ln()
+                    })
+                    .sum::<f64>()
+                    / validation.len() as f64;
+                if early_stopping.should_stop(rounds.len(), log_loss) {
+                    break;
+                }
+            }
+        }
+
+        if !validation.is_empty() {
+            rounds.truncate(early_stopping.best_rounds());
+        }
+
+        GradientBoostingClassifierData {
+            initial_scores,
+            rounds,
+            learning_rate: config.learning_rate,
+            num_classes: number_of_classes,
+            num_features: features.feature_dimension(),
+        }
+    }
+}
+
+impl ClassifierTrainer for GradientBoostingClassifierTrainer {
+    /// Train a gradient boosting classifier.
+    fn train(
+        &self,
+        features: &dyn Features,
+        labels: &HugeIntArray,
+        train_set: &Arc<Vec<u64>>,
+    ) -> Box<dyn Classifier> {
+        let data = self.train_internal(features, labels, train_set);
+        Box::new(GradientBoostingClassifier::new(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::models::gradient_boosting::GradientBoostingConfig;
+    use crate::ml::models::DenseFeatures;
+    use crate::task::progress::Tasks;
+
+    fn trainer(number_of_classes: usize) -> GradientBoostingClassifierTrainer {
+        GradientBoostingClassifierTrainer::new(
+            Concurrency::single_threaded(),
+            number_of_classes,
+            GradientBoostingClassifierTrainerConfig {
+                boosting: GradientBoostingConfig {
+                    learning_rate: 0.3,
+                    max_iterations: 20,
+                    ..GradientBoostingConfig::default()
+                },
+            },
+            Some(42),
+            TaskProgressTracker::new(Tasks::leaf("GradientBoosting".to_string())),
+            TerminationFlag::default(),
+        )
+    }
+
+    /// Points on a line, labelled by which of `number_of_classes` segments they fall in.
+    fn segments(number_of_classes: usize) -> (DenseFeatures, HugeIntArray) {
+        let n = 30 * number_of_classes;
+        let features = DenseFeatures::new((0..n).map(|i| vec![i as f64, 1.0]).collect());
+        let mut labels = HugeIntArray::new(n);
+        for i in 0..n {
+            labels.set(i, (i / 30) as i32);
+        }
+        (features, labels)
+    }
+
+    fn argmax(values: &[f64]) -> usize {
+        (0..values.len())
+            .max_by(|&a, &b| values[a].total_cmp(&values[b]))
+            .unwrap()
+    }
+
+    #[test]
+    fn separates_binary_classes() {
+        let (features, labels) = segments(2);
+        let train_set = Arc::new((0..60).collect::<Vec<u64>>());
+
+        let classifier = trainer(2).train(&features, &labels, &train_set);
+
+        let low = classifier.predict_probabilities(&[5.0, 1.0]);
+        let high = classifier.predict_probabilities(&[55.0, 1.0]);
+        assert_eq!(low.len(), 2);
+        assert!((low.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(low[0] > 0.9);
+        assert!(high[1] > 0.9);
+    }
+
+    #[test]
+    fn separates_multiple_classes() {
+        let (features, labels) = segments(3);
+        let train_set = Arc::new((0..90).collect::<Vec<u64>>());
+
+        let classifier = trainer(3).train(&features, &labels, &train_set);
+
+        assert_eq!(argmax(&classifier.predict_probabilities(&[10.0, 1.0])), 0);
+        assert_eq!(argmax(&classifier.predict_probabilities(&[45.0, 1.0])), 1);
+        assert_eq!(argmax(&classifier.predict_probabilities(&[80.0, 1.0])), 2);
+    }
+}
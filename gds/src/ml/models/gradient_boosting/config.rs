@@ -0,0 +1,311 @@
+use crate::config::validation::ConfigError;
+use crate::ml::models::TrainerConfig;
+use crate::ml::models::TrainingMethod;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Shared configuration for gradient-boosted tree models.
+/// Note: `max_depth == 0` means "unlimited", as for random forests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GradientBoostingConfig {
+    /// Shrinkage applied to every tree's contribution
+    #[serde(default = "default_learning_rate")]
+    pub learning_rate: f64,
+
+    /// Maximum number of boosting rounds
+    #[serde(default = "default_max_iterations")]
+    pub max_iterations: usize,
+
+    /// Maximum depth of each tree
+    #[serde(default = "default_max_depth")]
+    pub max_depth: usize,
+
+    /// Minimum samples required to split
+    #[serde(default = "default_min_samples_split")]
+    pub min_samples_split: usize,
+
+    /// Minimum samples required in a leaf
+    #[serde(default = "default_min_samples_leaf")]
+    pub min_samples_leaf: usize,
+
+    /// Ratio of training samples drawn (without replacement) for each round
+    #[serde(default = "default_subsample_ratio")]
+    pub subsample_ratio: f64,
+
+    /// Ratio of features considered for splits; all features when unset
+    #[serde(default)]
+    pub max_features_ratio: Option<f64>,
+
+    /// Rounds without validation improvement before stopping; 0 disables early stopping
+    #[serde(default)]
+    pub patience: usize,
+
+    /// Ratio of the training set held out for early stopping
+    #[serde(default = "default_validation_ratio")]
+    pub validation_ratio: f64,
+
+    /// Minimum validation loss improvement that resets patience
+    #[serde(default = "default_tolerance")]
+    pub tolerance: f64,
+}
+
+impl GradientBoostingConfig {
+    pub fn max_features_ratio(&self) -> f64 {
+        self.max_features_ratio.unwrap_or(1.0)
+    }
+
+    pub fn early_stopping(&self) -> bool {
+        self.patience > 0
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !self.learning_rate.is_finite()
+            || !(self.learning_rate > 0.0 && self.learning_rate <= 1.0)
+        {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "learningRate".to_string(),
+                reason: "learningRate must be finite and in (0.0, 1.0]".to_string(),
+            });
+        }
+
+        if self.max_iterations == 0 || self.max_iterations > 100_000 {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "maxIterations".to_string(),
+                reason: "maxIterations must be > 0 and <= 100_000".to_string(),
+            });
+        }
+
+        if !crate::ml::decision_tree::is_unlimited_depth(self.max_depth) && self.max_depth > 10_000
+        {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "maxDepth".to_string(),
+                reason: "maxDepth must be 0 (unlimited) or in [1, 10_000]".to_string(),
+            });
+        }
+
+        if self.min_samples_split < 2 || self.min_samples_split > 1_000_000 {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "minSamplesSplit".to_string(),
+                reason: "minSamplesSplit must be >= 2 and <= 1_000_000".to_string(),
+            });
+        }
+        if self.min_samples_leaf < 1 || self.min_samples_leaf >= self.min_samples_split {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "minSamplesLeaf".to_string(),
+                reason: "minSamplesLeaf must be >= 1 and strictly smaller than minSamplesSplit"
+                    .to_string(),
+            });
+        }
+
+        if !self.subsample_ratio.is_finite()
+            || !(self.subsample_ratio > 0.0 && self.subsample_ratio <= 1.0)
+        {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "subsampleRatio".to_string(),
+                reason: "subsampleRatio must be finite and in (0.0, 1.0]".to_string(),
+            });
+        }
+
+        if let Some(r) = self.max_features_ratio {
+            if !r.is_finite() || !(r > 0.0 && r <= 1.0) {
+                return Err(ConfigError::InvalidParameter {
+                    parameter: "maxFeaturesRatio".to_string(),
+                    reason: "maxFeaturesRatio must be finite and in (0.0, 1.0]".to_string(),
+                });
+            }
+        }
+
+        if !self.validation_ratio.is_finite()
+            || !(self.validation_ratio > 0.0 && self.validation_ratio < 1.0)
+        {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "validationRatio".to_string(),
+                reason: "validationRatio must be finite and in (0.0, 1.0)".to_string(),
+            });
+        }
+
+        if !self.tolerance.is_finite() || self.tolerance < 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "tolerance".to_string(),
+                reason: "tolerance must be finite and >= 0.0".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn insert_into(&self, map: &mut HashMap<String, serde_json::Value>) {
+        map.insert(
+            "learningRate".to_string(),
+            serde_json::json!(self.learning_rate),
+        );
+        map.insert(
+            "maxIterations".to_string(),
+            serde_json::json!(self.max_iterations),
+        );
+        map.insert("maxDepth".to_string(), serde_json::json!(self.max_depth));
+        map.insert(
+            "minSamplesSplit".to_string(),
+            serde_json::json!(self.min_samples_split),
+        );
+        map.insert(
+            "minSamplesLeaf".to_string(),
+            serde_json::json!(self.min_samples_leaf),
+        );
+        map.insert(
+            "subsampleRatio".to_string(),
+            serde_json::json!(self.subsample_ratio),
+        );
+        map.insert(
+            "maxFeaturesRatio".to_string(),
+            serde_json::json!(self.max_features_ratio),
+        );
+        map.insert("patience".to_string(), serde_json::json!(self.patience));
+        map.insert(
+            "validationRatio".to_string(),
+            serde_json::json!(self.validation_ratio),
+        );
+        map.insert("tolerance".to_string(), serde_json::json!(self.tolerance));
+    }
+}
+
+impl crate::config::ValidatedConfig for GradientBoostingConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        GradientBoostingConfig::validate(self)
+    }
+}
+
+impl Default for GradientBoostingConfig {
+    fn default() -> Self {
+        Self {
+            learning_rate: default_learning_rate(),
+            max_iterations: default_max_iterations(),
+            max_depth: default_max_depth(),
+            min_samples_split: default_min_samples_split(),
+            min_samples_leaf: default_min_samples_leaf(),
+            subsample_ratio: default_subsample_ratio(),
+            max_features_ratio: None,
+            patience: 0,
+            validation_ratio: default_validation_ratio(),
+            tolerance: default_tolerance(),
+        }
+    }
+}
+
+fn default_learning_rate() -> f64 {
+    0.1
+}
+fn default_max_iterations() -> usize {
+    100
+}
+fn default_max_depth() -> usize {
+    3
+}
+fn default_min_samples_split() -> usize {
+    2
+}
+fn default_min_samples_leaf() -> usize {
+    1
+}
+fn default_subsample_ratio() -> f64 {
+    1.0
+}
+fn default_validation_ratio() -> f64 {
+    0.1
+}
+fn default_tolerance() -> f64 {
+    1e-7
+}
+
+/// Configuration for the gradient boosting classifier trainer (logistic loss).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GradientBoostingClassifierTrainerConfig {
+    #[serde(flatten)]
+    pub boosting: GradientBoostingConfig,
+}
+
+impl TrainerConfig for GradientBoostingClassifierTrainerConfig {
+    fn method(&self) -> TrainingMethod {
+        TrainingMethod::GradientBoostingClassification
+    }
+
+    fn to_map(&self) -> HashMap<String, serde_json::Value> {
+        let mut map = HashMap::new();
+        map.insert(
+            "method".to_string(),
+            serde_json::Value::String("GradientBoostingClassification".to_string()),
+        );
+        self.boosting.insert_into(&mut map);
+        map
+    }
+}
+
+impl crate::config::ValidatedConfig for GradientBoostingClassifierTrainerConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        crate::config::ValidatedConfig::validate(&self.boosting)
+    }
+}
+
+/// Configuration for the gradient boosting regressor trainer (squared loss).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GradientBoostingRegressorTrainerConfig {
+    #[serde(flatten)]
+    pub boosting: GradientBoostingConfig,
+}
+
+impl TrainerConfig for GradientBoostingRegressorTrainerConfig {
+    fn method(&self) -> TrainingMethod {
+        TrainingMethod::GradientBoostingRegression
+    }
+
+    fn to_map(&self) -> HashMap<String, serde_json::Value> {
+        let mut map = HashMap::new();
+        map.insert(
+            "method".to_string(),
+            serde_json::Value::String("GradientBoostingRegression".to_string()),
+        );
+        self.boosting.insert_into(&mut map);
+        map
+    }
+}
+
+impl crate::config::ValidatedConfig for GradientBoostingRegressorTrainerConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        crate::config::ValidatedConfig::validate(&self.boosting)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ValidatedConfig;
+
+    #[test]
+    fn defaults_are_valid() {
+        assert!(GradientBoostingConfig::default().validate().is_ok());
+        assert!(!GradientBoostingConfig::default().early_stopping());
+    }
+
+    #[test]
+    fn rejects_out_of_range_learning_rate() {
+        let config = GradientBoostingClassifierTrainerConfig {
+            boosting: GradientBoostingConfig {
+                learning_rate: 1.5,
+                ..GradientBoostingConfig::default()
+            },
+        };
+        assert!(ValidatedConfig::validate(&config).is_err());
+    }
+
+    #[test]
+    fn map_includes_method_discriminator() {
+        let config = GradientBoostingRegressorTrainerConfig::default();
+        let map = config.to_map();
+        assert_eq!(
+            map.get("method"),
+            Some(&serde_json::json!("GradientBoostingRegression"))
+        );
+        assert_eq!(map.get("learningRate"), Some(&serde_json::json!(0.1)));
+    }
+}
@@ -0,0 +1,12 @@
+pub(crate) mod boosting;
+pub mod classifier;
+pub mod classifier_trainer;
+pub mod config;
+pub mod regressor;
+pub mod regressor_trainer;
+
+pub use classifier::*;
+pub use classifier_trainer::*;
+pub use config::*;
+pub use regressor::*;
+pub use regressor_trainer::*;
@@ -0,0 +1,138 @@
+use crate::ml::decision_tree::DecisionTreePredictor;
+use crate::ml::explain::{impurity_importance, tree_shap, Explanation, FeatureImportance};
+use crate::ml::models::BaseModelData;
+use crate::ml::models::ModelData;
+use crate::ml::models::Regressor;
+use crate::ml::models::RegressorData;
+use crate::ml::models::TrainingMethod;
+use anyhow::anyhow;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+
+/// Gradient boosting regressor model.
+pub struct GradientBoostingRegressor {
+    data: GradientBoostingRegressorData,
+}
+
+impl GradientBoostingRegressor {
+    pub fn new(data: GradientBoostingRegressorData) -> Self {
+        Self { data }
+    }
+
+    pub fn num_trees(&self) -> usize {
+        self.data.trees.len()
+    }
+}
+
+impl Regressor for GradientBoostingRegressor {
+    fn data(&self) -> &dyn RegressorData {
+        &self.data
+    }
+
+    /// Predict a single value for given features
+    fn predict(&self, features: &[f64]) -> f64 {
+        self.data.predict(features)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn feature_importance(&self) -> Option<FeatureImportance> {
+        Some(impurity_importance(
+            self.data.trees.iter().map(|tree| &tree.root),
+            self.data.num_features,
+        ))
+    }
+
+    fn explain(&self, features: &[f64]) -> Option<Explanation> {
+        let explanations: Vec<Explanation> = self
+            .data
+            .trees
+            .iter()
+            .map(|tree| tree_shap(&tree.root, features, &|value: &f64| *value))
+            .collect();
+        let boosted = Explanation::weighted_sum(
+            explanations
+                .iter()
+                .map(|explanation| (self.data.learning_rate, explanation)),
+            features.len(),
+        );
+
+        Some(Explanation::new(
+            self.data.initial_score + boosted.base_value(),
+            boosted.contributions().to_vec(),
+        ))
+    }
+}
+
+/// Gradient Boosting Regressor Data.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GradientBoostingRegressorData {
+    pub initial_score: f64,
+    pub trees: Vec<DecisionTreePredictor<f64>>,
+    pub learning_rate: f64,
+    pub num_features: usize,
+}
+
+impl GradientBoostingRegressorData {
+    pub fn predict(&self, features: &[f64]) -> f64 {
+        self.trees.iter().fold(self.initial_score, |sum, tree| {
+            sum + self.learning_rate * *tree.predict(features)
+        })
+    }
+}
+
+impl fmt::Debug for GradientBoostingRegressor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GradientBoostingRegressor")
+            .field("num_trees", &self.data.trees.len())
+            .field("num_features", &self.data.num_features)
+            .finish()
+    }
+}
+
+impl fmt::Debug for GradientBoostingRegressorData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GradientBoostingRegressorData")
+            .field("initial_score", &self.initial_score)
+            .field("num_trees", &self.trees.len())
+            .field("learning_rate", &self.learning_rate)
+            .field("num_features", &self.num_features)
+            .finish()
+    }
+}
+
+impl BaseModelData for GradientBoostingRegressorData {
+    fn trainer_method(&self) -> TrainingMethod {
+        TrainingMethod::GradientBoostingRegression
+    }
+
+    fn feature_dimension(&self) -> usize {
+        self.num_features
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl RegressorData for GradientBoostingRegressorData {}
+
+impl ModelData for GradientBoostingRegressorData {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self)
+            .map_err(|err| anyhow!("GradientBoostingRegressorData serialization failed: {err}"))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bincode::deserialize(bytes)
+            .map_err(|err| anyhow!("GradientBoostingRegressorData deserialization failed: {err}"))
+    }
+
+    fn num_features(&self) -> usize {
+        self.num_features
+    }
+}
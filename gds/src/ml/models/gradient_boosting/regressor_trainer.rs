@@ -0,0 +1,245 @@
+use crate::collections::HugeDoubleArray;
+use crate::ml::models::gradient_boosting::boosting;
+use crate::ml::models::gradient_boosting::boosting::EarlyStopping;
+use crate::ml::models::gradient_boosting::GradientBoostingRegressor;
+use crate::ml::models::gradient_boosting::GradientBoostingRegressorData;
+use crate::ml::models::gradient_boosting::GradientBoostingRegressorTrainerConfig;
+use crate::ml::models::Features;
+use crate::ml::models::Regressor;
+use crate::ml::models::RegressorTrainer;
+use crate::task::concurrency::Concurrency;
+use crate::task::concurrency::TerminationFlag;
+use crate::task::progress::tasks::LogLevel;
+use crate::task::progress::tasks::ProgressTracker;
+use crate::task::progress::tasks::TaskProgressTracker;
+use std::sync::Arc;
+
+/// Gradient Boosting Regressor Trainer, minimizing the squared loss.
+#[allow(dead_code)]
+pub struct GradientBoostingRegressorTrainer {
+    config: GradientBoostingRegressorTrainerConfig,
+    concurrency: Concurrency,
+    random_seed: Option<u64>,
+    progress_tracker: TaskProgressTracker,
+    termination_flag: TerminationFlag,
+}
+
+impl GradientBoostingRegressorTrainer {
+    /// Create a new Gradient Boosting Regressor Trainer.
+    pub fn new(
+        concurrency: Concurrency,
+        config: GradientBoostingRegressorTrainerConfig,
+        random_seed: Option<u64>,
+        termination_flag: TerminationFlag,
+        progress_tracker: TaskProgressTracker,
+    ) -> Self {
+        Self {
+            config,
+            concurrency,
+            random_seed,
+            progress_tracker,
+            termination_flag,
+        }
+    }
+
+    fn train_internal(
+        &self,
+        features: &dyn Features,
+        targets: &HugeDoubleArray,
+        train_set: &[u64],
+    ) -> GradientBoostingRegressorData {
+        let config = &self.config.boosting;
+        let mut rng = boosting::rng(self.random_seed);
+        let (train, validation) = boosting::holdout_split(config, train_set, &mut rng);
+
+        let initial_score = if train.is_empty() {
+            0.0
+        } else {
+            train
+                .iter()
+                .map(|&id| targets.get(id as usize))
+                .sum::<f64>()
+                / train.len() as f64
+        };
+
+        let mut trees = Vec::new();
+        if train.is_empty() {
+            return GradientBoostingRegressorData {
+                initial_score,
+                trees,
+                learning_rate: config.learning_rate,
+                num_features: features.feature_dimension(),
+            };
+        }
+
+        // Current model output for every sample we train or validate on.
+        let mut scores = HugeDoubleArray::new(features.size());
+        for &id in train.iter().chain(&validation) {
+            scores.set(id as usize, initial_score);
+        }
+        let mut residuals = HugeDoubleArray::new(features.size());
+        let mut early_stopping = EarlyStopping::new(config);
+
+        for round in 0..config.max_iterations {
+            self.termination_flag.assert_running();
+
+            for &id in &train {
+                let id = id as usize;
+                residuals.set(id, targets.get(id) - scores.get(id));
+            }
+
+            let samples = boosting::subsample(config, &train, &mut rng);
+            let seed = self
+                .random_seed
+                .map(|s| s + round as u64)
+                .unwrap_or(round as u64);
+            let tree = boosting::fit_tree(config, features, &residuals, &samples, seed);
+
+            for &id in train.iter().chain(&validation) {
+                let id = id as usize;
+                let update = config.learning_rate * *tree.predict(features.get(id));
+                scores.set(id, scores.get(id) + update);
+            }
+            trees.push(tree);
+
+            let mut progress_tracker = self.progress_tracker.clone();
+            progress_tracker.log_message(
+                LogLevel::Info,
+                &format!(
+                    "Trained boosting round {} out of {}",
+                    round + 1,
+                    config.max_iterations
+                ),
+            );
+
+            if !validation.is_empty() {
+                let loss = validation
+                    .iter()
+                    .map(|&id| {
+                        let error = targets.get(id as usize) - scores.get(id as usize);
+                        error * error
+                    })
+                    .sum::<f64>()
+                    / validation.len() as f64;
+                if early_stopping.should_stop(trees.len(), loss) {
+                    break;
+                }
+            }
+        }
+
+        if !validation.is_empty() {
+            trees.truncate(early_stopping.best_rounds());
+        }
+
+        GradientBoostingRegressorData {
+            initial_score,
+            trees,
+            learning_rate: config.learning_rate,
+            num_features: features.feature_dimension(),
+        }
+    }
+}
+
+impl RegressorTrainer for GradientBoostingRegressorTrainer {
+    /// Train a gradient boosting regressor.
+    fn train(
+        &self,
+        features: &dyn Features,
+        targets: &HugeDoubleArray,
+        train_set: &Arc<Vec<u64>>,
+    ) -> Box<dyn Regressor> {
+        let data = self.train_internal(features, targets, train_set);
+        Box::new(GradientBoostingRegressor::new(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::models::gradient_boosting::GradientBoostingConfig;
+    use crate::ml::models::DenseFeatures;
+    use crate::task::progress::Tasks;
+
+    fn trainer(config: GradientBoostingConfig) -> GradientBoostingRegressorTrainer {
+        GradientBoostingRegressorTrainer::new(
+            Concurrency::single_threaded(),
+            GradientBoostingRegressorTrainerConfig { boosting: config },
+            Some(42),
+            TerminationFlag::default(),
+            TaskProgressTracker::new(Tasks::leaf("GradientBoosting".to_string())),
+        )
+    }
+
+    fn step_data() -> (DenseFeatures, HugeDoubleArray) {
+        let n = 40;
+        let features = DenseFeatures::new((0..n).map(|i| vec![i as f64]).collect());
+        let mut targets = HugeDoubleArray::new(n);
+        for i in 0..n {
+            targets.set(i, if i < n / 2 { 1.0 } else { 5.0 });
+        }
+        (features, targets)
+    }
+
+    #[test]
+    fn fits_a_step_function() {
+        let (features, targets) = step_data();
+        let train_set = Arc::new((0..40).collect::<Vec<u64>>());
+
+        let regressor = trainer(GradientBoostingConfig {
+            learning_rate: 0.5,
+            max_iterations: 30,
+            ..GradientBoostingConfig::default()
+        })
+        .train(&features, &targets, &train_set);
+
+        assert!((regressor.predict(&[3.0]) - 1.0).abs() < 1e-3);
+        assert!((regressor.predict(&[33.0]) - 5.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn early_stopping_truncates_rounds() {
+        let (features, targets) = step_data();
+        let train_set = Arc::new((0..40).collect::<Vec<u64>>());
+
+        let regressor = trainer(GradientBoostingConfig {
+            learning_rate: 1.0,
+            max_iterations: 50,
+            patience: 2,
+            validation_ratio: 0.25,
+            ..GradientBoostingConfig::default()
+        })
+        .train(&features, &targets, &train_set);
+
+        let regressor = regressor
+            .as_any()
+            .downcast_ref::<GradientBoostingRegressor>()
+            .unwrap();
+        assert!(regressor.num_trees() >= 1);
+        assert!(regressor.num_trees() < 50);
+    }
+
+    #[test]
+    fn explanations_add_up_to_the_prediction() {
+        let features = DenseFeatures::new(
+            (0..40)
+                .map(|i| vec![i as f64, ((i * 7) % 5) as f64])
+                .collect(),
+        );
+        let (_, targets) = step_data();
+        let train_set = Arc::new((0..40).collect::<Vec<u64>>());
+
+        let regressor = trainer(GradientBoostingConfig {
+            learning_rate: 0.5,
+            max_iterations: 10,
+            ..GradientBoostingConfig::default()
+        })
+        .train(&features, &targets, &train_set);
+
+        for row in [[3.0, 1.0], [33.0, 4.0]] {
+            let explanation = regressor.explain(&row).unwrap();
+            assert!((explanation.output() - regressor.predict(&row)).abs() < 1e-9);
+        }
+        let importance = regressor.feature_importance().unwrap();
+        assert!(importance.scores()[0] > 0.99);
+    }
+}
@@ -129,6 +129,7 @@ impl TrainerConfig for LinearRegressionTrainConfig {
             "patience".to_string(),
             serde_json::Value::Number(serde_json::Number::from(self.gradient.patience())),
         );
+        map.extend(self.gradient.optimizer().to_map());
 
         map
     }
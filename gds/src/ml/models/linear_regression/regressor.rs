@@ -1,5 +1,6 @@
 use crate::ml::core::functions::{EWiseAddMatrixScalar, MatrixMultiplyWithTransposedSecondOperand};
 use crate::ml::core::VariableRef;
+use crate::ml::explain::{coefficient_importance, Explanation, FeatureImportance};
 use crate::ml::models::Regressor;
 use crate::ml::models::RegressorData;
 use std::sync::Arc;
@@ -71,4 +72,22 @@ impl Regressor for LinearRegressor {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn feature_importance(&self) -> Option<FeatureImportance> {
+        Some(coefficient_importance(&self.data.weights().borrow_matrix()))
+    }
+
+    fn explain(&self, features: &[f64]) -> Option<Explanation> {
+        let weights = self.data.weights().borrow_matrix();
+        let contributions = features
+            .iter()
+            .enumerate()
+            .map(|(feature, value)| weights[(0, feature)] * value)
+            .collect();
+
+        Some(Explanation::new(
+            self.data.bias().borrow_scalar().value(),
+            contributions,
+        ))
+    }
 }
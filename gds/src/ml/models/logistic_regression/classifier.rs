@@ -7,6 +7,7 @@ use crate::ml::core::functions::{
 use crate::ml::core::tensor::Matrix;
 use crate::ml::core::variable::VariableRef;
 use crate::ml::core::ComputationContext;
+use crate::ml::explain::{coefficient_importance, Explanation, FeatureImportance};
 use crate::ml::models::Classifier;
 use crate::ml::models::ClassifierData;
 use crate::ml::models::Features;
@@ -147,4 +148,31 @@ impl Classifier for LogisticRegressionClassifier {
     fn data(&self) -> &dyn ClassifierData {
         &self.data
     }
+
+    fn feature_importance(&self) -> Option<FeatureImportance> {
+        Some(coefficient_importance(&self.data.weights().borrow_matrix()))
+    }
+
+    /// Explains the log-odds of `class` against the other class for binary models,
+    /// and its softmax logit otherwise.
+    fn explain(&self, features: &[f64], class: usize) -> Option<Explanation> {
+        let weights = self.data.weights().borrow_matrix();
+        let bias = self.data.bias().borrow_vector();
+        let (row, sign) = match self.prediction_strategy {
+            PredictionStrategy::Binary => (0, if class == 0 { 1.0 } else { -1.0 }),
+            PredictionStrategy::MultiClass if class < weights.rows() => (class, 1.0),
+            // The last class of a reduced model has a constant logit of zero.
+            PredictionStrategy::MultiClass => {
+                return Some(Explanation::new(0.0, vec![0.0; features.len()]))
+            }
+        };
+
+        let contributions = features
+            .iter()
+            .enumerate()
+            .map(|(feature, value)| sign * weights[(row, feature)] * value)
+            .collect();
+
+        Some(Explanation::new(sign * bias[row], contributions))
+    }
 }
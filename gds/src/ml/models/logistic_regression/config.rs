@@ -1,4 +1,5 @@
 use crate::config::validation::ConfigError;
+use crate::ml::core::optimizer::OptimizerConfig;
 use crate::ml::models::TrainerConfig;
 use crate::ml::models::TrainingMethod;
 use serde::Deserialize;
@@ -35,6 +36,10 @@ pub struct LogisticRegressionTrainConfig {
     /// Class weights for handling imbalanced data
     #[serde(default)]
     pub class_weights: Option<Vec<f64>>,
+
+    /// Optimizer and learning rate schedule
+    #[serde(default)]
+    pub optimizer: OptimizerConfig,
 }
 
 fn default_penalty() -> f64 {
@@ -66,6 +71,7 @@ impl Default for LogisticRegressionTrainConfig {
             tolerance: default_tolerance(),
             focus_weight: default_focus_weight(),
             class_weights: None,
+            optimizer: OptimizerConfig::default(),
         }
     }
 }
@@ -116,6 +122,7 @@ impl TrainerConfig for LogisticRegressionTrainConfig {
                 serde_json::Value::Array(weights_array),
             );
         }
+        map.extend(self.optimizer.to_map());
 
         map
     }
@@ -147,7 +154,7 @@ impl LogisticRegressionTrainConfig {
                 reason: "tolerance must be >= 0".to_string(),
             });
         }
-        Ok(())
+        self.optimizer.validate()
     }
 }
 
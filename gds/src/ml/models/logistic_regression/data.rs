@@ -1,7 +1,14 @@
 use crate::ml::core::functions::Weights;
+use crate::ml::core::tensor::Matrix;
+use crate::ml::core::tensor::Vector;
 use crate::ml::models::BaseModelData;
 use crate::ml::models::ClassifierData;
+use crate::ml::models::ModelData;
 use crate::ml::models::TrainingMethod;
+use anyhow::anyhow;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
 use std::sync::Arc;
 
 /// Data structure holding the parameters of a logistic regression model
@@ -94,3 +101,38 @@ impl ClassifierData for LogisticRegressionData {
         self.number_of_classes
     }
 }
+
+impl ModelData for LogisticRegressionData {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        let payload = LogisticRegressionDataSnapshot {
+            weights: self.weights.borrow_matrix().clone(),
+            bias: self.bias.borrow_vector().clone(),
+            number_of_classes: self.number_of_classes,
+        };
+
+        bincode::serialize(&payload)
+            .map_err(|err| anyhow!("LogisticRegressionData serialization failed: {err}"))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let snapshot: LogisticRegressionDataSnapshot = bincode::deserialize(bytes)
+            .map_err(|err| anyhow!("LogisticRegressionData deserialization failed: {err}"))?;
+
+        Ok(Self {
+            weights: Arc::new(Weights::from_tensor(Box::new(snapshot.weights))),
+            bias: Arc::new(Weights::from_tensor(Box::new(snapshot.bias))),
+            number_of_classes: snapshot.number_of_classes,
+        })
+    }
+
+    fn num_features(&self) -> usize {
+        self.feature_dimension()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct LogisticRegressionDataSnapshot {
+    weights: Matrix,
+    bias: Vector,
+    number_of_classes: usize,
+}
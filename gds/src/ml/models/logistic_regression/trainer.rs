@@ -81,6 +81,7 @@ impl ClassifierTrainer for LogisticRegressionTrainer {
                 .learning_rate(self.train_config.learning_rate)
                 .max_epochs(self.train_config.max_epochs)
                 .tolerance(self.train_config.tolerance)
+                .optimizer(self.train_config.optimizer.clone())
                 .build()
                 .unwrap(),
             train_set.len(),
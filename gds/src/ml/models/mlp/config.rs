@@ -2,6 +2,7 @@
 //!
 
 use crate::config::validation::ConfigError;
+use crate::ml::core::optimizer::OptimizerConfig;
 use crate::ml::models::base::TrainerConfigTrait;
 use crate::ml::models::TrainingMethod;
 use derive_builder::Builder;
@@ -34,6 +35,10 @@ pub struct MLPClassifierTrainConfig {
     #[builder(default = "0.001")]
     pub learning_rate: f64,
 
+    #[builder(default)]
+    #[serde(default)]
+    pub optimizer: OptimizerConfig,
+
     // Penalty Configuration
     #[builder(default = "0.0")]
     pub penalty: f64,
@@ -63,6 +68,7 @@ impl Default for MLPClassifierTrainConfig {
             max_epochs: 100,
             tolerance: 1e-3,
             learning_rate: 0.001,
+            optimizer: OptimizerConfig::default(),
             penalty: 0.0,
             focus_weight: 0.0,
             class_weights: Vec::new(),
@@ -144,7 +150,7 @@ impl MLPClassifierTrainConfig {
                 });
             }
         }
-        Ok(())
+        self.optimizer.validate()
     }
 }
 
@@ -223,6 +229,7 @@ impl TrainerConfigTrait for MLPClassifierTrainConfig {
             "hidden_layer_sizes".to_string(),
             serde_json::Value::Array(hidden_layers),
         );
+        map.extend(self.optimizer.to_map());
         map
     }
 }
@@ -7,10 +7,15 @@ use crate::ml::core::Vector;
 use crate::ml::core::Weights;
 use crate::ml::models::BaseModelData;
 use crate::ml::models::ClassifierData;
+use crate::ml::models::ModelData;
 use crate::ml::models::TrainingMethod;
+use anyhow::anyhow;
+use anyhow::Result;
 use rand::rngs::StdRng;
 use rand::Rng;
 use rand::SeedableRng;
+use serde::Deserialize;
+use serde::Serialize;
 use std::sync::Arc;
 
 /// Data structure for MLP Classifier
@@ -170,6 +175,65 @@ impl ClassifierData for MLPClassifierData {
     }
 }
 
+impl ModelData for MLPClassifierData {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        let payload = MLPClassifierDataSnapshot {
+            number_of_classes: self.number_of_classes,
+            weights: self
+                .weights
+                .iter()
+                .map(|w| w.borrow_matrix().clone())
+                .collect(),
+            biases: self
+                .biases
+                .iter()
+                .map(|b| b.borrow_vector().clone())
+                .collect(),
+        };
+
+        bincode::serialize(&payload)
+            .map_err(|err| anyhow!("MLPClassifierData serialization failed: {err}"))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let snapshot: MLPClassifierDataSnapshot = bincode::deserialize(bytes)
+            .map_err(|err| anyhow!("MLPClassifierData deserialization failed: {err}"))?;
+
+        if snapshot.weights.len() != snapshot.biases.len() {
+            return Err(anyhow!(
+                "MLPClassifierData has {} weight matrices but {} bias vectors",
+                snapshot.weights.len(),
+                snapshot.biases.len()
+            ));
+        }
+
+        Ok(Self {
+            number_of_classes: snapshot.number_of_classes,
+            weights: snapshot
+                .weights
+                .into_iter()
+                .map(|m| Arc::new(Weights::from_tensor(Box::new(m))))
+                .collect(),
+            biases: snapshot
+                .biases
+                .into_iter()
+                .map(|v| Arc::new(Weights::from_tensor(Box::new(v))))
+                .collect(),
+        })
+    }
+
+    fn num_features(&self) -> usize {
+        self.feature_dimension()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct MLPClassifierDataSnapshot {
+    number_of_classes: usize,
+    weights: Vec<Matrix>,
+    biases: Vec<Vector>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,6 +273,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bytes_round_trip() {
+        let data = MLPClassifierData::create(3, 4, &[5], 7);
+        let restored = MLPClassifierData::from_bytes(&data.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(restored.number_of_classes(), 3);
+        assert_eq!(restored.feature_dimension(), 4);
+        for (original, loaded) in data.weights().iter().zip(restored.weights()) {
+            assert_eq!(original.snapshot().data(), loaded.snapshot().data());
+        }
+        for (original, loaded) in data.biases().iter().zip(restored.biases()) {
+            assert_eq!(original.snapshot().data(), loaded.snapshot().data());
+        }
+    }
+
     #[test]
     #[should_panic(expected = "hidden_layer_sizes must not be empty")]
     fn test_empty_hidden_layer_sizes_panics() {
@@ -2,10 +2,20 @@ pub mod classifier;
 pub mod config;
 pub mod data;
 pub mod objective;
+pub mod regressor;
+pub mod regressor_config;
+pub mod regressor_data;
+pub mod regressor_objective;
+pub mod regressor_trainer;
 pub mod trainer;
 
 pub use classifier::*;
 pub use config::*;
 pub use data::*;
 pub use objective::*;
+pub use regressor::*;
+pub use regressor_config::*;
+pub use regressor_data::*;
+pub use regressor_objective::*;
+pub use regressor_trainer::*;
 pub use trainer::*;
@@ -0,0 +1,114 @@
+//! MLP Regressor
+//!
+
+use super::regressor_data::MLPRegressorData;
+use crate::ml::core::{
+    computation_context::ComputationContext,
+    functions::{
+        constant::Constant,
+        matrix_multiply_with_transposed_second_operand::MatrixMultiplyWithTransposedSecondOperand,
+        matrix_vector_sum::MatrixVectorSum, relu::Relu,
+    },
+    tensor::Matrix,
+    variable::VariableRef,
+};
+use crate::ml::models::Regressor;
+use crate::ml::models::RegressorData;
+use std::sync::Arc;
+
+/// Multi-Layer Perceptron Regressor
+///
+/// Hidden layers use ReLU like [`super::MLPClassifier`]; the single output unit stays
+/// linear so the network can predict unbounded targets.
+#[derive(Debug)]
+pub struct MLPRegressor {
+    data: MLPRegressorData,
+}
+
+impl MLPRegressor {
+    /// Create a new MLP regressor
+    ///
+    pub fn new(data: MLPRegressorData) -> Self {
+        Self { data }
+    }
+
+    /// Build the computation graph producing a `batch_size x 1` prediction matrix
+    ///
+    pub fn predictions_variable(&self, batch_features: VariableRef) -> VariableRef {
+        let mut input_to_next_layer = batch_features;
+        let layer_count = self.data.weights().len();
+
+        for i in 0..layer_count {
+            let output_from_prev_layer = input_to_next_layer;
+
+            let weights_var: VariableRef = self.data.weights()[i].clone();
+            let weighted_features: VariableRef =
+                Arc::new(MatrixMultiplyWithTransposedSecondOperand::new_ref(
+                    output_from_prev_layer,
+                    weights_var,
+                ));
+
+            let bias_var: VariableRef = self.data.biases()[i].clone();
+            let biased_features: VariableRef =
+                Arc::new(MatrixVectorSum::new_ref(weighted_features, bias_var));
+
+            input_to_next_layer = if i + 1 < layer_count {
+                Arc::new(Relu::new_ref(biased_features, 0.0))
+            } else {
+                biased_features
+            };
+        }
+
+        input_to_next_layer
+    }
+
+    /// Get the regressor data
+    ///
+    pub fn data(&self) -> &MLPRegressorData {
+        &self.data
+    }
+}
+
+impl Regressor for MLPRegressor {
+    fn data(&self) -> &dyn RegressorData {
+        &self.data
+    }
+
+    fn predict(&self, features: &[f64]) -> f64 {
+        let ctx = ComputationContext::new();
+        let features_matrix = Matrix::new(features.to_vec(), 1, features.len());
+        let features_variable: VariableRef = Arc::new(Constant::new(Box::new(features_matrix)));
+        let predictions_variable = self.predictions_variable(features_variable);
+        let result = ctx.forward(predictions_variable.as_ref());
+
+        result.data()[0]
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predictions_variable_has_single_output() {
+        let data = MLPRegressorData::create(3, &[4], 456);
+        let regressor = MLPRegressor::new(data);
+
+        let features = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+        let features_var: VariableRef = Arc::new(Constant::new(Box::new(features)));
+        let predictions_var = regressor.predictions_variable(features_var);
+
+        let ctx = ComputationContext::new();
+        let result = ctx.forward(predictions_var.as_ref());
+        let result_matrix = result.as_any().downcast_ref::<Matrix>().unwrap();
+        assert_eq!(result_matrix.rows(), 2);
+        assert_eq!(result_matrix.cols(), 1);
+
+        let first = regressor.predict(&[1.0, 2.0, 3.0]);
+        assert!((result_matrix[(0, 0)] - first).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,256 @@
+//! MLP Regressor Training Configuration
+//!
+
+use crate::config::validation::ConfigError;
+use crate::ml::core::optimizer::OptimizerConfig;
+use crate::ml::models::base::TrainerConfigTrait;
+use crate::ml::models::TrainingMethod;
+use derive_builder::Builder;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Loss minimized by the MLP regressor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum MLPRegressionLoss {
+    /// Mean squared error.
+    #[default]
+    MeanSquaredError,
+    /// Mean Huber loss, see [`crate::ml::core::functions::HuberLoss`].
+    Huber,
+}
+
+impl MLPRegressionLoss {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MLPRegressionLoss::MeanSquaredError => "mse",
+            MLPRegressionLoss::Huber => "huber",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "mse" | "meansquarederror" | "mean_squared_error" => {
+                Ok(MLPRegressionLoss::MeanSquaredError)
+            }
+            "huber" => Ok(MLPRegressionLoss::Huber),
+            other => Err(format!(
+                "Unknown loss `{other}`, expected one of `mse`, `huber`"
+            )),
+        }
+    }
+}
+
+/// Configuration for MLP Regressor training
+///
+/// Gradient descent and penalty settings match [`super::MLPClassifierTrainConfig`].
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(pattern = "mutable")]
+pub struct MLPRegressorTrainConfig {
+    // Gradient Descent Configuration
+    #[builder(default = "100")]
+    pub batch_size: usize,
+
+    #[builder(default = "1")]
+    pub min_epochs: usize,
+
+    #[builder(default = "1")]
+    pub patience: usize,
+
+    #[builder(default = "100")]
+    pub max_epochs: usize,
+
+    #[builder(default = "1e-3")]
+    pub tolerance: f64,
+
+    #[builder(default = "0.001")]
+    pub learning_rate: f64,
+
+    #[builder(default)]
+    #[serde(default)]
+    pub optimizer: OptimizerConfig,
+
+    // Penalty Configuration
+    #[builder(default = "0.0")]
+    pub penalty: f64,
+
+    // Loss Configuration
+    #[builder(default)]
+    #[serde(default)]
+    pub loss: MLPRegressionLoss,
+
+    #[builder(default = "1.0")]
+    pub huber_delta: f64,
+
+    // MLP-Specific Configuration
+    #[builder(default = "vec![100]")]
+    pub hidden_layer_sizes: Vec<usize>,
+}
+
+impl Default for MLPRegressorTrainConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            min_epochs: 1,
+            patience: 1,
+            max_epochs: 100,
+            tolerance: 1e-3,
+            learning_rate: 0.001,
+            optimizer: OptimizerConfig::default(),
+            penalty: 0.0,
+            loss: MLPRegressionLoss::default(),
+            huber_delta: 1.0,
+            hidden_layer_sizes: vec![100],
+        }
+    }
+}
+
+impl MLPRegressorTrainConfig {
+    /// Create a new MLP regressor training configuration
+    pub fn builder() -> MLPRegressorTrainConfigBuilder {
+        MLPRegressorTrainConfigBuilder::default()
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.batch_size == 0 {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "batchSize".to_string(),
+                reason: "batchSize must be > 0".to_string(),
+            });
+        }
+        if self.max_epochs < self.min_epochs {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "maxEpochs/minEpochs".to_string(),
+                reason: "maxEpochs must be >= minEpochs".to_string(),
+            });
+        }
+        if self.learning_rate <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "learningRate".to_string(),
+                reason: "learningRate must be > 0".to_string(),
+            });
+        }
+        if self.tolerance < 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "tolerance".to_string(),
+                reason: "tolerance must be >= 0".to_string(),
+            });
+        }
+        if self.huber_delta <= 0.0 {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "huberDelta".to_string(),
+                reason: "huberDelta must be > 0".to_string(),
+            });
+        }
+        if self.hidden_layer_sizes.is_empty() || self.hidden_layer_sizes.contains(&0) {
+            return Err(ConfigError::InvalidParameter {
+                parameter: "hiddenLayerSizes".to_string(),
+                reason: "hiddenLayerSizes must be non-empty with entries > 0".to_string(),
+            });
+        }
+        self.optimizer.validate()
+    }
+}
+
+impl crate::config::ValidatedConfig for MLPRegressorTrainConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        MLPRegressorTrainConfig::validate(self)
+    }
+}
+
+impl TrainerConfigTrait for MLPRegressorTrainConfig {
+    fn method(&self) -> TrainingMethod {
+        TrainingMethod::MLPRegression
+    }
+
+    fn to_map(&self) -> std::collections::HashMap<String, serde_json::Value> {
+        let mut map = std::collections::HashMap::new();
+        map.insert(
+            "method".to_string(),
+            serde_json::Value::String("MLPRegression".to_string()),
+        );
+        map.insert(
+            "batch_size".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.batch_size)),
+        );
+        map.insert(
+            "min_epochs".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.min_epochs)),
+        );
+        map.insert(
+            "patience".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.patience)),
+        );
+        map.insert(
+            "max_epochs".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.max_epochs)),
+        );
+        map.insert(
+            "tolerance".to_string(),
+            serde_json::Value::Number(serde_json::Number::from_f64(self.tolerance).unwrap()),
+        );
+        map.insert(
+            "learning_rate".to_string(),
+            serde_json::Value::Number(serde_json::Number::from_f64(self.learning_rate).unwrap()),
+        );
+        map.insert(
+            "penalty".to_string(),
+            serde_json::Value::Number(serde_json::Number::from_f64(self.penalty).unwrap()),
+        );
+        map.insert(
+            "loss".to_string(),
+            serde_json::Value::String(self.loss.name().to_string()),
+        );
+        map.insert(
+            "huberDelta".to_string(),
+            serde_json::Value::Number(serde_json::Number::from_f64(self.huber_delta).unwrap()),
+        );
+        let hidden_layers: Vec<serde_json::Value> = self
+            .hidden_layer_sizes
+            .iter()
+            .map(|&v| serde_json::Value::Number(serde_json::Number::from(v)))
+            .collect();
+        map.insert(
+            "hidden_layer_sizes".to_string(),
+            serde_json::Value::Array(hidden_layers),
+        );
+        map.extend(self.optimizer.to_map());
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = MLPRegressorTrainConfig::default();
+
+        assert_eq!(config.loss, MLPRegressionLoss::MeanSquaredError);
+        assert_eq!(config.huber_delta, 1.0);
+        assert_eq!(config.hidden_layer_sizes, vec![100]);
+        assert_eq!(config.method(), TrainingMethod::MLPRegression);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_loss_parse_round_trip() {
+        for loss in [
+            MLPRegressionLoss::MeanSquaredError,
+            MLPRegressionLoss::Huber,
+        ] {
+            assert_eq!(MLPRegressionLoss::parse(loss.name()).unwrap(), loss);
+        }
+        assert!(MLPRegressionLoss::parse("hinge").is_err());
+    }
+
+    #[test]
+    fn test_rejects_non_positive_huber_delta() {
+        let config = MLPRegressorTrainConfig::builder()
+            .loss(MLPRegressionLoss::Huber)
+            .huber_delta(0.0)
+            .build()
+            .unwrap();
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! MLP Regressor Data
+//!
+
+use super::data::MLPClassifierData;
+use crate::ml::core::Matrix;
+use crate::ml::core::Variable;
+use crate::ml::core::Vector;
+use crate::ml::core::Weights;
+use crate::ml::models::BaseModelData;
+use crate::ml::models::ModelData;
+use crate::ml::models::RegressorData;
+use crate::ml::models::TrainingMethod;
+use anyhow::anyhow;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Data structure for MLP Regressor
+///
+/// Same layer layout as [`MLPClassifierData`], with a single linear output unit.
+#[derive(Clone, Debug)]
+pub struct MLPRegressorData {
+    /// Weight matrices for each layer
+    weights: Vec<Arc<Weights>>,
+    /// Bias vectors for each layer
+    biases: Vec<Arc<Weights>>,
+}
+
+impl MLPRegressorData {
+    /// Create MLP regressor data with Kaiming initialization
+    pub fn create(feature_count: usize, hidden_layer_sizes: &[usize], random_seed: u64) -> Self {
+        let layers = MLPClassifierData::create(1, feature_count, hidden_layer_sizes, random_seed);
+        Self {
+            weights: layers.weights().clone(),
+            biases: layers.biases().clone(),
+        }
+    }
+
+    /// Get the depth of the network (number of layers)
+    ///
+    pub fn depth(&self) -> usize {
+        self.biases.len() + 1
+    }
+
+    /// Get the feature dimension (input dimension)
+    ///
+    pub fn feature_dimension(&self) -> usize {
+        self.weights
+            .first()
+            .map(|first_weight| first_weight.dimensions()[1])
+            .unwrap_or(0)
+    }
+
+    /// Get weight matrices
+    ///
+    pub fn weights(&self) -> &Vec<Arc<Weights>> {
+        &self.weights
+    }
+
+    /// Get bias vectors
+    ///
+    pub fn biases(&self) -> &Vec<Arc<Weights>> {
+        &self.biases
+    }
+}
+
+impl BaseModelData for MLPRegressorData {
+    fn trainer_method(&self) -> TrainingMethod {
+        TrainingMethod::MLPRegression
+    }
+
+    fn feature_dimension(&self) -> usize {
+        self.feature_dimension()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl RegressorData for MLPRegressorData {}
+
+impl ModelData for MLPRegressorData {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        let payload = MLPRegressorDataSnapshot {
+            weights: self
+                .weights
+                .iter()
+                .map(|w| w.borrow_matrix().clone())
+                .collect(),
+            biases: self
+                .biases
+                .iter()
+                .map(|b| b.borrow_vector().clone())
+                .collect(),
+        };
+
+        bincode::serialize(&payload)
+            .map_err(|err| anyhow!("MLPRegressorData serialization failed: {err}"))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let snapshot: MLPRegressorDataSnapshot = bincode::deserialize(bytes)
+            .map_err(|err| anyhow!("MLPRegressorData deserialization failed: {err}"))?;
+
+        if snapshot.weights.len() != snapshot.biases.len() {
+            return Err(anyhow!(
+                "MLPRegressorData has {} weight matrices but {} bias vectors",
+                snapshot.weights.len(),
+                snapshot.biases.len()
+            ));
+        }
+
+        Ok(Self {
+            weights: snapshot
+                .weights
+                .into_iter()
+                .map(|m| Arc::new(Weights::from_tensor(Box::new(m))))
+                .collect(),
+            biases: snapshot
+                .biases
+                .into_iter()
+                .map(|v| Arc::new(Weights::from_tensor(Box::new(v))))
+                .collect(),
+        })
+    }
+
+    fn num_features(&self) -> usize {
+        self.feature_dimension()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct MLPRegressorDataSnapshot {
+    weights: Vec<Matrix>,
+    biases: Vec<Vector>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mlp_regressor_data_creation() {
+        let data = MLPRegressorData::create(10, &[8, 4], 42);
+
+        assert_eq!(data.feature_dimension(), 10);
+        assert_eq!(data.depth(), 4);
+        assert_eq!(data.weights()[0].dimensions(), vec![8, 10]);
+        assert_eq!(data.weights()[1].dimensions(), vec![4, 8]);
+        assert_eq!(data.weights()[2].dimensions(), vec![1, 4]);
+        assert_eq!(data.biases()[2].dimensions(), vec![1]);
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let data = MLPRegressorData::create(3, &[5], 7);
+        let restored = MLPRegressorData::from_bytes(&data.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(restored.feature_dimension(), 3);
+        assert_eq!(restored.trainer_method(), TrainingMethod::MLPRegression);
+        for (original, loaded) in data.weights().iter().zip(restored.weights()) {
+            assert_eq!(original.snapshot().data(), loaded.snapshot().data());
+        }
+        for (original, loaded) in data.biases().iter().zip(restored.biases()) {
+            assert_eq!(original.snapshot().data(), loaded.snapshot().data());
+        }
+    }
+}
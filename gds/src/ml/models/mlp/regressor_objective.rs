@@ -0,0 +1,121 @@
+//! MLP Regressor Objective
+//!
+
+use super::regressor::MLPRegressor;
+use super::regressor_config::MLPRegressionLoss;
+use super::regressor_data::MLPRegressorData;
+use crate::collections::HugeDoubleArray;
+use crate::ml::core::batch::Batch;
+use crate::ml::core::functions::Constant;
+use crate::ml::core::functions::ConstantScale;
+use crate::ml::core::functions::ElementSum;
+use crate::ml::core::functions::HuberLoss;
+use crate::ml::core::functions::L2NormSquared;
+use crate::ml::core::functions::MeanSquareError;
+use crate::ml::core::functions::Weights;
+use crate::ml::core::variable::VariableRef;
+use crate::ml::gradient_descent::batch_feature_matrix;
+use crate::ml::gradient_descent::Objective;
+use crate::ml::models::Features;
+use std::sync::Arc;
+
+/// Objective function for MLP regressor training
+///
+/// Regression loss over the batch plus an L2 penalty on all weight matrices.
+pub struct MLPRegressorObjective<'a> {
+    pub regressor: MLPRegressor,
+    features: &'a dyn Features,
+    targets: &'a HugeDoubleArray,
+    penalty: f64,
+    loss: MLPRegressionLoss,
+    huber_delta: f64,
+}
+
+impl<'a> MLPRegressorObjective<'a> {
+    /// Create a new MLP regressor objective
+    ///
+    pub fn new(
+        regressor: MLPRegressor,
+        features: &'a dyn Features,
+        targets: &'a HugeDoubleArray,
+        penalty: f64,
+        loss: MLPRegressionLoss,
+        huber_delta: f64,
+    ) -> Self {
+        Self {
+            regressor,
+            features,
+            targets,
+            penalty,
+            loss,
+            huber_delta,
+        }
+    }
+
+    fn regression_loss<B: Batch>(&self, batch: &B) -> VariableRef {
+        let batch_targets = self.batch_targets(batch);
+        let batch_features: VariableRef = Arc::new(batch_feature_matrix(batch, self.features));
+        let predictions = self.regressor.predictions_variable(batch_features);
+
+        match self.loss {
+            MLPRegressionLoss::MeanSquaredError => {
+                Arc::new(MeanSquareError::new_ref(predictions, batch_targets))
+            }
+            MLPRegressionLoss::Huber => Arc::new(HuberLoss::new_ref(
+                predictions,
+                batch_targets,
+                self.huber_delta,
+            )),
+        }
+    }
+
+    fn penalty_for_batch<B: Batch>(&self, batch: &B, train_size: usize) -> VariableRef {
+        let l2_norms: Vec<VariableRef> = self
+            .regressor
+            .data()
+            .weights()
+            .iter()
+            .map(|weights| {
+                let w: VariableRef = weights.clone();
+                Arc::new(L2NormSquared::new_ref(w)) as VariableRef
+            })
+            .collect();
+
+        let penalty_sum: VariableRef = Arc::new(ElementSum::new_ref(l2_norms));
+        let scale = (batch.size() as f64) * self.penalty / (train_size as f64);
+
+        Arc::new(ConstantScale::new_ref(penalty_sum, scale))
+    }
+
+    fn batch_targets<B: Batch>(&self, batch: &B) -> VariableRef {
+        let batched_targets: Vec<f64> = batch
+            .element_ids()
+            .map(|element_id| self.targets.get(element_id as usize))
+            .collect();
+        Arc::new(Constant::vector(batched_targets))
+    }
+}
+
+impl<'a> Objective for MLPRegressorObjective<'a> {
+    type ModelData = MLPRegressorData;
+
+    fn weights(&self) -> Vec<Arc<Weights>> {
+        let data = self.regressor.data();
+        data.weights()
+            .iter()
+            .chain(data.biases().iter())
+            .cloned()
+            .collect()
+    }
+
+    fn loss<B: Batch>(&self, batch: &B, train_size: usize) -> VariableRef {
+        let regression_loss = self.regression_loss(batch);
+        let penalty = self.penalty_for_batch(batch, train_size);
+
+        Arc::new(ElementSum::new_ref(vec![regression_loss, penalty]))
+    }
+
+    fn model_data(&self) -> &Self::ModelData {
+        self.regressor.data()
+    }
+}
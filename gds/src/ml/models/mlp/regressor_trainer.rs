@@ -0,0 +1,187 @@
+//! MLP Regressor Trainer
+//!
+
+use crate::collections::HugeDoubleArray;
+use crate::ml::core::batch::from_array;
+use crate::ml::gradient_descent::GradientDescentConfig;
+use crate::ml::gradient_descent::Training;
+use crate::ml::models::Features;
+use crate::ml::models::Regressor;
+use crate::ml::models::RegressorTrainer;
+use parking_lot::RwLock;
+use rand::rngs::StdRng;
+use rand::RngCore;
+use rand::SeedableRng;
+use std::sync::Arc;
+
+use super::{
+    regressor::MLPRegressor, regressor_config::MLPRegressorTrainConfig,
+    regressor_data::MLPRegressorData, regressor_objective::MLPRegressorObjective,
+};
+
+/// Trainer for MLP Regressor
+///
+/// Uses the gradient descent system for training.
+pub struct MLPRegressorTrainer {
+    train_config: MLPRegressorTrainConfig,
+    random_seed: Option<u64>,
+    _termination_flag: Arc<RwLock<bool>>,
+    concurrency: usize,
+}
+
+impl MLPRegressorTrainer {
+    /// Create a new MLP regressor trainer
+    ///
+    pub fn new(
+        train_config: MLPRegressorTrainConfig,
+        random_seed: Option<u64>,
+        concurrency: usize,
+    ) -> Self {
+        Self {
+            train_config,
+            random_seed,
+            _termination_flag: Arc::new(RwLock::new(false)),
+            concurrency,
+        }
+    }
+
+    /// Get the training configuration
+    pub fn train_config(&self) -> &MLPRegressorTrainConfig {
+        &self.train_config
+    }
+}
+
+impl RegressorTrainer for MLPRegressorTrainer {
+    fn train(
+        &self,
+        features: &dyn Features,
+        targets: &HugeDoubleArray,
+        train_set: &Arc<Vec<u64>>,
+    ) -> Box<dyn Regressor> {
+        let mut random = if let Some(seed) = self.random_seed {
+            StdRng::seed_from_u64(seed)
+        } else {
+            StdRng::from_entropy()
+        };
+
+        let data = MLPRegressorData::create(
+            features.feature_dimension(),
+            &self.train_config.hidden_layer_sizes,
+            random.next_u64(),
+        );
+
+        let objective = MLPRegressorObjective::new(
+            MLPRegressor::new(data),
+            features,
+            targets,
+            self.train_config.penalty,
+            self.train_config.loss,
+            self.train_config.huber_delta,
+        );
+
+        let gradient_config = GradientDescentConfig::builder()
+            .batch_size(self.train_config.batch_size)
+            .min_epochs(self.train_config.min_epochs)
+            .patience(self.train_config.patience)
+            .max_epochs(self.train_config.max_epochs)
+            .tolerance(self.train_config.tolerance)
+            .learning_rate(self.train_config.learning_rate)
+            .optimizer(self.train_config.optimizer.clone())
+            .build()
+            .unwrap();
+
+        let training = Training::new(
+            gradient_config,
+            train_set.len(),
+            Arc::clone(&self._termination_flag),
+        );
+
+        let train_ids = Arc::clone(train_set);
+        let batch_size = self.train_config.batch_size;
+        let queue_supplier = move || from_array(Arc::clone(&train_ids), batch_size);
+
+        training.train(&objective, queue_supplier, self.concurrency);
+
+        Box::new(objective.regressor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::models::mlp::MLPRegressionLoss;
+
+    struct GridFeatures {
+        data: Vec<Vec<f64>>,
+    }
+
+    impl Features for GridFeatures {
+        fn get(&self, node_id: usize) -> &[f64] {
+            &self.data[node_id]
+        }
+
+        fn feature_dimension(&self) -> usize {
+            1
+        }
+
+        fn size(&self) -> usize {
+            self.data.len()
+        }
+    }
+
+    /// Points on [-1, 1] with the non-linear target x².
+    fn squares() -> (GridFeatures, HugeDoubleArray) {
+        let xs: Vec<f64> = (0..41).map(|i| -1.0 + i as f64 * 0.05).collect();
+        let targets = HugeDoubleArray::from_vec(xs.iter().map(|x| x * x).collect());
+        let features = GridFeatures {
+            data: xs.into_iter().map(|x| vec![x]).collect(),
+        };
+        (features, targets)
+    }
+
+    fn mean_squared_error(
+        regressor: &dyn Regressor,
+        features: &GridFeatures,
+        targets: &HugeDoubleArray,
+    ) -> f64 {
+        (0..features.size())
+            .map(|i| {
+                let error = regressor.predict(features.get(i)) - targets.get(i);
+                error * error
+            })
+            .sum::<f64>()
+            / features.size() as f64
+    }
+
+    fn fit(loss: MLPRegressionLoss) -> f64 {
+        let (features, targets) = squares();
+        let config = MLPRegressorTrainConfig::builder()
+            .batch_size(41)
+            .min_epochs(300)
+            .max_epochs(300)
+            .learning_rate(0.02)
+            .loss(loss)
+            .huber_delta(0.5)
+            .hidden_layer_sizes(vec![16])
+            .build()
+            .unwrap();
+        let trainer = MLPRegressorTrainer::new(config, Some(42), 1);
+        let train_set = Arc::new((0..41).collect::<Vec<u64>>());
+
+        let regressor = trainer.train(&features, &targets, &train_set);
+        mean_squared_error(regressor.as_ref(), &features, &targets)
+    }
+
+    #[test]
+    fn test_fits_non_linear_target_with_mse() {
+        // The best linear fit of x² on [-1, 1] has an MSE of about 0.09.
+        let error = fit(MLPRegressionLoss::MeanSquaredError);
+        assert!(error < 0.01, "mse {error}");
+    }
+
+    #[test]
+    fn test_fits_non_linear_target_with_huber() {
+        let error = fit(MLPRegressionLoss::Huber);
+        assert!(error < 0.02, "mse {error}");
+    }
+}
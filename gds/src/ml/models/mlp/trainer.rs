@@ -90,6 +90,7 @@ impl MLPClassifierTrainer {
             .max_epochs(self.train_config.max_epochs)
             .tolerance(self.train_config.tolerance)
             .learning_rate(self.train_config.learning_rate)
+            .optimizer(self.train_config.optimizer.clone())
             .build()
             .unwrap();
 
@@ -164,6 +165,7 @@ impl ClassifierTrainer for MLPClassifierTrainer {
             .max_epochs(self.train_config.max_epochs)
             .tolerance(self.train_config.tolerance)
             .learning_rate(self.train_config.learning_rate)
+            .optimizer(self.train_config.optimizer.clone())
             .build()
             .unwrap();
 
@@ -4,9 +4,11 @@ pub mod classifier_factory;
 pub mod classifier_trainer_factory;
 pub mod config;
 pub mod features;
+pub mod gradient_boosting;
 pub mod linear_regression;
 pub mod logistic_regression;
 pub mod mlp;
+pub mod model_serializer;
 pub mod random_forest;
 pub mod regression_trainer_factory;
 pub mod svm;
@@ -17,6 +19,7 @@ pub use classifier_factory::*;
 pub use classifier_trainer_factory::*;
 pub use config::*;
 pub use features::*;
+pub use model_serializer::*;
 pub use random_forest::*;
 pub use regression_trainer_factory::*;
 pub use training_method::*;
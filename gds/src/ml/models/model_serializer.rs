@@ -0,0 +1,254 @@
+//! Byte-level (de)serialization of trained classifier and regressor data.
+//!
+//! Mirrors Java GDS's per-algorithm model serializers: every trained model data type
+//! implements [`ModelData`], and this module dispatches on [`TrainingMethod`] so callers
+//! can persist a `Box<dyn Classifier>` / `Box<dyn Regressor>` without knowing its
+//! concrete type.
+
+use crate::ml::models::gradient_boosting::{
+    GradientBoostingClassifierData, GradientBoostingRegressor, GradientBoostingRegressorData,
+};
+use crate::ml::models::linear_regression::{LinearRegressionData, LinearRegressor};
+use crate::ml::models::logistic_regression::LogisticRegressionData;
+use crate::ml::models::mlp::{MLPClassifierData, MLPRegressor, MLPRegressorData};
+use crate::ml::models::random_forest::{
+    RandomForestClassifierData, RandomForestRegressor, RandomForestRegressorData,
+};
+use crate::ml::models::svm::{SVMClassifierData, SVMRegressor, SVMRegressorData};
+use crate::ml::models::{
+    Classifier, ClassifierData, ClassifierFactory, ModelData, Regressor, RegressorData,
+    TrainingMethod,
+};
+use anyhow::anyhow;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Trained model parameters tagged with the training method that produced them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SerializedModelData {
+    pub method: TrainingMethod,
+    pub bytes: Vec<u8>,
+}
+
+/// Dispatches model data (de)serialization by training method.
+pub struct ModelSerializer;
+
+impl ModelSerializer {
+    pub fn serialize_classifier(data: &dyn ClassifierData) -> Result<SerializedModelData> {
+        let method = data.trainer_method();
+        let bytes = match method {
+            TrainingMethod::LogisticRegression => {
+                Self::downcast::<LogisticRegressionData>(data.as_any(), method)?.to_bytes()?
+            }
+            TrainingMethod::RandomForestClassification => {
+                Self::downcast::<RandomForestClassifierData>(data.as_any(), method)?.to_bytes()?
+            }
+            TrainingMethod::MLPClassification => {
+                Self::downcast::<MLPClassifierData>(data.as_any(), method)?.to_bytes()?
+            }
+            TrainingMethod::SVMClassification => {
+                Self::downcast::<SVMClassifierData>(data.as_any(), method)?.to_bytes()?
+            }
+            TrainingMethod::GradientBoostingClassification => {
+                Self::downcast::<GradientBoostingClassifierData>(data.as_any(), method)?
+                    .to_bytes()?
+            }
+            other => return Err(anyhow!("{other:?} is not a classification method")),
+        };
+        Ok(SerializedModelData { method, bytes })
+    }
+
+    pub fn deserialize_classifier(serialized: &SerializedModelData) -> Result<Box<dyn Classifier>> {
+        let bytes = serialized.bytes.as_slice();
+        let classifier = match serialized.method {
+            TrainingMethod::LogisticRegression => {
+                ClassifierFactory::create(&LogisticRegressionData::from_bytes(bytes)?)
+            }
+            TrainingMethod::RandomForestClassification => {
+                ClassifierFactory::create(&RandomForestClassifierData::from_bytes(bytes)?)
+            }
+            TrainingMethod::MLPClassification => {
+                ClassifierFactory::create(&MLPClassifierData::from_bytes(bytes)?)
+            }
+            TrainingMethod::SVMClassification => {
+                ClassifierFactory::create(&SVMClassifierData::from_bytes(bytes)?)
+            }
+            TrainingMethod::GradientBoostingClassification => {
+                ClassifierFactory::create(&GradientBoostingClassifierData::from_bytes(bytes)?)
+            }
+            other => return Err(anyhow!("{other:?} is not a classification method")),
+        };
+        Ok(classifier)
+    }
+
+    pub fn serialize_regressor(data: &dyn RegressorData) -> Result<SerializedModelData> {
+        let method = data.trainer_method();
+        let bytes = match method {
+            TrainingMethod::LinearRegression => {
+                Self::downcast::<LinearRegressionData>(data.as_any(), method)?.to_bytes()?
+            }
+            TrainingMethod::RandomForestRegression => {
+                Self::downcast::<RandomForestRegressorData>(data.as_any(), method)?.to_bytes()?
+            }
+            TrainingMethod::GradientBoostingRegression => {
+                Self::downcast::<GradientBoostingRegressorData>(data.as_any(), method)?
+                    .to_bytes()?
+            }
+            TrainingMethod::MLPRegression => {
+                Self::downcast::<MLPRegressorData>(data.as_any(), method)?.to_bytes()?
+            }
+            TrainingMethod::SVMRegression => {
+                Self::downcast::<SVMRegressorData>(data.as_any(), method)?.to_bytes()?
+            }
+            other => return Err(anyhow!("{other:?} is not a regression method")),
+        };
+        Ok(SerializedModelData { method, bytes })
+    }
+
+    pub fn deserialize_regressor(serialized: &SerializedModelData) -> Result<Box<dyn Regressor>> {
+        let bytes = serialized.bytes.as_slice();
+        let regressor: Box<dyn Regressor> = match serialized.method {
+            TrainingMethod::LinearRegression => Box::new(LinearRegressor::new(
+                LinearRegressionData::from_bytes(bytes)?,
+            )),
+            TrainingMethod::RandomForestRegression => Box::new(RandomForestRegressor::new(
+                RandomForestRegressorData::from_bytes(bytes)?,
+            )),
+            TrainingMethod::GradientBoostingRegression => Box::new(GradientBoostingRegressor::new(
+                GradientBoostingRegressorData::from_bytes(bytes)?,
+            )),
+            TrainingMethod::MLPRegression => {
+                Box::new(MLPRegressor::new(MLPRegressorData::from_bytes(bytes)?))
+            }
+            TrainingMethod::SVMRegression => {
+                Box::new(SVMRegressor::new(SVMRegressorData::from_bytes(bytes)?))
+            }
+            other => return Err(anyhow!("{other:?} is not a regression method")),
+        };
+        Ok(regressor)
+    }
+
+    fn downcast<T: 'static>(data: &dyn std::any::Any, method: TrainingMethod) -> Result<&T> {
+        data.downcast_ref::<T>()
+            .ok_or_else(|| anyhow!("Model data does not match training method {method:?}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::decision_tree::{DecisionTreePredictor, TreeNode};
+    use crate::ml::models::mlp::MLPClassifier;
+    use crate::ml::models::svm::SVMKernelType;
+    use std::sync::Arc;
+
+    fn stump<P: Clone>(threshold: f64, left: P, right: P) -> DecisionTreePredictor<P> {
+        let mut root = TreeNode::new_split(0, threshold);
+        root.set_left_child(TreeNode::new_leaf(left));
+        root.set_right_child(TreeNode::new_leaf(right));
+        DecisionTreePredictor::new(root)
+    }
+
+    #[test]
+    fn random_forest_classifier_round_trip_preserves_predictions() {
+        let data = RandomForestClassifierData {
+            decision_trees: vec![Arc::new(stump(0.5, 0usize, 1usize))],
+            num_classes: 2,
+            num_features: 1,
+        };
+
+        let serialized = ModelSerializer::serialize_classifier(&data).unwrap();
+        assert_eq!(
+            serialized.method,
+            TrainingMethod::RandomForestClassification
+        );
+
+        let classifier = ModelSerializer::deserialize_classifier(&serialized).unwrap();
+        assert_eq!(classifier.predict_probabilities(&[0.1]), vec![1.0, 0.0]);
+        assert_eq!(classifier.predict_probabilities(&[0.9]), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn random_forest_regressor_round_trip_preserves_predictions() {
+        let data = RandomForestRegressorData {
+            decision_trees: vec![Box::new(stump(1.0, -2.0, 3.0))],
+            num_features: 1,
+        };
+
+        let serialized = ModelSerializer::serialize_regressor(&data).unwrap();
+        let regressor = ModelSerializer::deserialize_regressor(&serialized).unwrap();
+
+        assert_eq!(regressor.predict(&[0.0]), -2.0);
+        assert_eq!(regressor.predict(&[2.0]), 3.0);
+    }
+
+    #[test]
+    fn gradient_boosting_round_trips_preserve_predictions() {
+        let classifier_data = GradientBoostingClassifierData {
+            initial_scores: vec![0.0],
+            rounds: vec![vec![stump(0.5, -4.0, 4.0)]],
+            learning_rate: 0.5,
+            num_classes: 2,
+            num_features: 1,
+        };
+        let serialized = ModelSerializer::serialize_classifier(&classifier_data).unwrap();
+        let classifier = ModelSerializer::deserialize_classifier(&serialized).unwrap();
+        let probabilities = classifier.predict_probabilities(&[0.9]);
+        assert!((probabilities[1] - 1.0 / (1.0 + (-2.0f64).exp())).abs() < 1e-12);
+
+        let regressor_data = GradientBoostingRegressorData {
+            initial_score: 1.0,
+            trees: vec![stump(1.0, -2.0, 2.0), stump(1.0, -2.0, 2.0)],
+            learning_rate: 0.5,
+            num_features: 1,
+        };
+        let serialized = ModelSerializer::serialize_regressor(&regressor_data).unwrap();
+        let regressor = ModelSerializer::deserialize_regressor(&serialized).unwrap();
+        assert_eq!(regressor.predict(&[0.0]), -1.0);
+        assert_eq!(regressor.predict(&[2.0]), 3.0);
+    }
+
+    #[test]
+    fn mlp_round_trip_preserves_predictions() {
+        let data = MLPClassifierData::create(3, 2, &[4], 11);
+        let expected = MLPClassifier::new(data.clone()).predict_probabilities(&[0.3, -0.7]);
+
+        let serialized = ModelSerializer::serialize_classifier(&data).unwrap();
+        let classifier = ModelSerializer::deserialize_classifier(&serialized).unwrap();
+
+        assert_eq!(classifier.predict_probabilities(&[0.3, -0.7]), expected);
+    }
+
+    #[test]
+    fn mlp_and_svm_regressor_round_trips_preserve_predictions() {
+        let mlp_data = MLPRegressorData::create(2, &[4], 5);
+        let expected = MLPRegressor::new(mlp_data.clone()).predict(&[0.3, -0.7]);
+        let serialized = ModelSerializer::serialize_regressor(&mlp_data).unwrap();
+        assert_eq!(serialized.method, TrainingMethod::MLPRegression);
+        let regressor = ModelSerializer::deserialize_regressor(&serialized).unwrap();
+        assert_eq!(regressor.predict(&[0.3, -0.7]), expected);
+
+        let svm_data = SVMRegressorData::new(
+            vec![vec![0.0, 1.0], vec![1.0, 0.0]],
+            vec![0.5, -0.25],
+            0.1,
+            SVMKernelType::Rbf { gamma: 0.5 },
+            2,
+        );
+        let expected = SVMRegressor::new(svm_data.clone()).predict(&[0.3, -0.7]);
+        let serialized = ModelSerializer::serialize_regressor(&svm_data).unwrap();
+        assert_eq!(serialized.method, TrainingMethod::SVMRegression);
+        let regressor = ModelSerializer::deserialize_regressor(&serialized).unwrap();
+        assert_eq!(regressor.predict(&[0.3, -0.7]), expected);
+    }
+
+    #[test]
+    fn rejects_regressor_method_for_classifier() {
+        let serialized = SerializedModelData {
+            method: TrainingMethod::LinearRegression,
+            bytes: vec![],
+        };
+        assert!(ModelSerializer::deserialize_classifier(&serialized).is_err());
+    }
+}
@@ -1,10 +1,14 @@
 use crate::ml::core::tensor::Matrix;
 use crate::ml::decision_tree::DecisionTreePredictor;
+use crate::ml::explain::{impurity_importance, tree_shap, Explanation, FeatureImportance};
 use crate::ml::models::BaseModelData;
 use crate::ml::models::Classifier;
 use crate::ml::models::ClassifierData;
 use crate::ml::models::Features;
+use crate::ml::models::ModelData;
 use crate::ml::models::TrainingMethod;
+use anyhow::anyhow;
+use anyhow::Result;
 use serde::Deserialize;
 use serde::Serialize;
 use std::fmt;
@@ -77,11 +81,33 @@ impl Classifier for RandomForestClassifier {
 
         predicted_probabilities
     }
+
+    fn feature_importance(&self) -> Option<FeatureImportance> {
+        Some(impurity_importance(
+            self.data.decision_trees.iter().map(|tree| &tree.root),
+            self.data.num_features,
+        ))
+    }
+
+    /// Explains the vote share of `class`, i.e. its predicted probability.
+    fn explain(&self, features: &[f64], class: usize) -> Option<Explanation> {
+        let weight = 1.0 / self.data.decision_trees.len().max(1) as f64;
+        let is_class = |predicted: &usize| if *predicted == class { 1.0 } else { 0.0 };
+        let explanations: Vec<Explanation> = self
+            .data
+            .decision_trees
+            .iter()
+            .map(|tree| tree_shap(&tree.root, features, &is_class))
+            .collect();
+
+        Some(Explanation::weighted_sum(
+            explanations.iter().map(|explanation| (weight, explanation)),
+            features.len(),
+        ))
+    }
 }
 
 /// Random Forest Classifier Data.
-///
-/// NOTE: Serialization deferred - trait objects require special handling
 #[derive(Clone)]
 pub struct RandomForestClassifierData {
     pub decision_trees: Vec<Arc<DecisionTreePredictor<usize>>>,
@@ -129,6 +155,53 @@ impl ClassifierData for RandomForestClassifierData {
     }
 }
 
+impl ModelData for RandomForestClassifierData {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        let payload = RandomForestClassifierDataRef {
+            decision_trees: self
+                .decision_trees
+                .iter()
+                .map(|tree| tree.as_ref())
+                .collect(),
+            num_classes: self.num_classes,
+            num_features: self.num_features,
+        };
+
+        bincode::serialize(&payload)
+            .map_err(|err| anyhow!("RandomForestClassifierData serialization failed: {err}"))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let snapshot: RandomForestClassifierDataSnapshot = bincode::deserialize(bytes)
+            .map_err(|err| anyhow!("RandomForestClassifierData deserialization failed: {err}"))?;
+
+        Ok(Self {
+            decision_trees: snapshot.decision_trees.into_iter().map(Arc::new).collect(),
+            num_classes: snapshot.num_classes,
+            num_features: snapshot.num_features,
+        })
+    }
+
+    fn num_features(&self) -> usize {
+        self.num_features
+    }
+}
+
+/// Borrowing twin of `RandomForestClassifierDataSnapshot`; both share one bincode layout.
+#[derive(Serialize)]
+struct RandomForestClassifierDataRef<'a> {
+    decision_trees: Vec<&'a DecisionTreePredictor<usize>>,
+    num_classes: usize,
+    num_features: usize,
+}
+
+#[derive(Deserialize)]
+struct RandomForestClassifierDataSnapshot {
+    decision_trees: Vec<DecisionTreePredictor<usize>>,
+    num_classes: usize,
+    num_features: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RandomForestClassifierConfig {
     #[serde(flatten)]
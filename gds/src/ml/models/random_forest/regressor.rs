@@ -1,8 +1,12 @@
 use crate::ml::decision_tree::DecisionTreePredictor;
+use crate::ml::explain::{impurity_importance, tree_shap, Explanation, FeatureImportance};
 use crate::ml::models::BaseModelData;
+use crate::ml::models::ModelData;
 use crate::ml::models::Regressor;
 use crate::ml::models::RegressorData;
 use crate::ml::models::TrainingMethod;
+use anyhow::anyhow;
+use anyhow::Result;
 use serde::Deserialize;
 use serde::Serialize;
 use std::fmt;
@@ -47,6 +51,28 @@ impl Regressor for RandomForestRegressor {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn feature_importance(&self) -> Option<FeatureImportance> {
+        Some(impurity_importance(
+            self.data.decision_trees.iter().map(|tree| &tree.root),
+            self.data.num_features,
+        ))
+    }
+
+    fn explain(&self, features: &[f64]) -> Option<Explanation> {
+        let weight = 1.0 / self.data.decision_trees.len().max(1) as f64;
+        let explanations: Vec<Explanation> = self
+            .data
+            .decision_trees
+            .iter()
+            .map(|tree| tree_shap(&tree.root, features, &|value: &f64| *value))
+            .collect();
+
+        Some(Explanation::weighted_sum(
+            explanations.iter().map(|explanation| (weight, explanation)),
+            features.len(),
+        ))
+    }
 }
 
 /// Random Forest Regressor Data.
@@ -89,6 +115,49 @@ impl BaseModelData for RandomForestRegressorData {
 
 impl RegressorData for RandomForestRegressorData {}
 
+impl ModelData for RandomForestRegressorData {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        let payload = RandomForestRegressorDataRef {
+            decision_trees: self
+                .decision_trees
+                .iter()
+                .map(|tree| tree.as_ref())
+                .collect(),
+            num_features: self.num_features,
+        };
+
+        bincode::serialize(&payload)
+            .map_err(|err| anyhow!("RandomForestRegressorData serialization failed: {err}"))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let snapshot: RandomForestRegressorDataSnapshot = bincode::deserialize(bytes)
+            .map_err(|err| anyhow!("RandomForestRegressorData deserialization failed: {err}"))?;
+
+        Ok(Self {
+            decision_trees: snapshot.decision_trees.into_iter().map(Box::new).collect(),
+            num_features: snapshot.num_features,
+        })
+    }
+
+    fn num_features(&self) -> usize {
+        self.num_features
+    }
+}
+
+/// Borrowing twin of `RandomForestRegressorDataSnapshot`; both share one bincode layout.
+#[derive(Serialize)]
+struct RandomForestRegressorDataRef<'a> {
+    decision_trees: Vec<&'a DecisionTreePredictor<f64>>,
+    num_features: usize,
+}
+
+#[derive(Deserialize)]
+struct RandomForestRegressorDataSnapshot {
+    decision_trees: Vec<DecisionTreePredictor<f64>>,
+    num_features: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RandomForestRegressorConfig {
     #[serde(flatten)]
@@ -1,12 +1,17 @@
-use crate::task::concurrency::Concurrency;
-use crate::task::concurrency::TerminationFlag;
-use crate::task::progress::TaskProgressTracker;
 use crate::core::LogLevel as ProcedureLogLevel;
+use crate::ml::models::gradient_boosting::{
+    GradientBoostingRegressorTrainer, GradientBoostingRegressorTrainerConfig,
+};
 use crate::ml::models::linear_regression::{LinearRegressionTrainConfig, LinearRegressionTrainer};
+use crate::ml::models::mlp::{MLPRegressorTrainConfig, MLPRegressorTrainer};
 use crate::ml::models::random_forest::{
     RandomForestRegressorTrainer, RandomForestRegressorTrainerConfig,
 };
+use crate::ml::models::svm::{SVMRegressorTrainConfig, SVMRegressorTrainer};
 use crate::ml::models::{base::TrainerConfigTrait, RegressorTrainer, TrainingMethod};
+use crate::task::concurrency::Concurrency;
+use crate::task::concurrency::TerminationFlag;
+use crate::task::progress::TaskProgressTracker;
 use parking_lot::RwLock;
 use std::sync::Arc;
 
@@ -50,6 +55,34 @@ impl RegressionTrainerFactory {
                     ProcedureLogLevel::Info, // Default log level
                 ))
             }
+            TrainingMethod::GradientBoostingRegression => {
+                let gb_config = (config as &dyn std::any::Any)
+                    .downcast_ref::<GradientBoostingRegressorTrainerConfig>()
+                    .expect("Invalid config type for GradientBoostingRegression");
+                Box::new(GradientBoostingRegressorTrainer::new(
+                    *concurrency,
+                    gb_config.clone(),
+                    random_seed,
+                    termination_flag.clone(),
+                    progress_tracker.clone(),
+                ))
+            }
+            TrainingMethod::MLPRegression => {
+                let mlp_config = (config as &dyn std::any::Any)
+                    .downcast_ref::<MLPRegressorTrainConfig>()
+                    .expect("Invalid config type for MLPRegression");
+                Box::new(MLPRegressorTrainer::new(
+                    mlp_config.clone(),
+                    random_seed,
+                    concurrency.value(),
+                ))
+            }
+            TrainingMethod::SVMRegression => {
+                let svm_config = (config as &dyn std::any::Any)
+                    .downcast_ref::<SVMRegressorTrainConfig>()
+                    .expect("Invalid config type for SVMRegression");
+                Box::new(SVMRegressorTrainer::new(svm_config.clone()))
+            }
             _ => panic!(
                 "No such training method for regression: {:?}",
                 config.method()
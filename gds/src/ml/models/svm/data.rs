@@ -2,9 +2,14 @@ use crate::ml::models::svm::PlattScaler;
 use crate::ml::models::svm::SVMKernelType;
 use crate::ml::models::BaseModelData;
 use crate::ml::models::ClassifierData;
+use crate::ml::models::ModelData;
 use crate::ml::models::TrainingMethod;
+use anyhow::anyhow;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SVMOneVsRestModel {
     pub support_vectors: Vec<Vec<f64>>,
     pub support_alphas: Vec<f64>,
@@ -14,7 +19,7 @@ pub struct SVMOneVsRestModel {
     pub platt_scaler: PlattScaler,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SVMClassifierData {
     models: Vec<SVMOneVsRestModel>,
     number_of_classes: usize,
@@ -76,3 +81,19 @@ impl ClassifierData for SVMClassifierData {
         self.number_of_classes
     }
 }
+
+impl ModelData for SVMClassifierData {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self)
+            .map_err(|err| anyhow!("SVMClassifierData serialization failed: {err}"))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bincode::deserialize(bytes)
+            .map_err(|err| anyhow!("SVMClassifierData deserialization failed: {err}"))
+    }
+
+    fn num_features(&self) -> usize {
+        self.feature_dimension
+    }
+}
@@ -3,6 +3,10 @@ pub mod config;
 pub mod data;
 pub mod kernel;
 pub mod platt_scaling;
+pub mod regressor;
+pub mod regressor_config;
+pub mod regressor_data;
+pub mod regressor_trainer;
 pub mod trainer;
 
 pub use classifier::*;
@@ -10,4 +14,8 @@ pub use config::*;
 pub use data::*;
 pub use kernel::*;
 pub use platt_scaling::*;
+pub use regressor::*;
+pub use regressor_config::*;
+pub use regressor_data::*;
+pub use regressor_trainer::*;
 pub use trainer::*;
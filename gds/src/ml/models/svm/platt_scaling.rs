@@ -1,4 +1,7 @@
-#[derive(Clone, Debug)]
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PlattScaler {
     a: f64,
     b: f64,
@@ -0,0 +1,51 @@
+use crate::ml::models::svm::compute;
+use crate::ml::models::svm::SVMRegressorData;
+use crate::ml::models::Regressor;
+use crate::ml::models::RegressorData;
+
+#[derive(Clone)]
+pub struct SVMRegressor {
+    data: SVMRegressorData,
+}
+
+impl std::fmt::Debug for SVMRegressor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SVMRegressor")
+            .field("support_vectors", &self.data.support_vectors().len())
+            .field("feature_dimension", &self.data.feature_dimension())
+            .finish()
+    }
+}
+
+impl SVMRegressor {
+    pub fn new(data: SVMRegressorData) -> Self {
+        Self { data }
+    }
+
+    pub fn data(&self) -> &SVMRegressorData {
+        &self.data
+    }
+}
+
+impl Regressor for SVMRegressor {
+    fn data(&self) -> &dyn RegressorData {
+        &self.data
+    }
+
+    fn predict(&self, features: &[f64]) -> f64 {
+        let kernel = self.data.kernel();
+        self.data
+            .support_vectors()
+            .iter()
+            .zip(self.data.coefficients())
+            .map(|(support_vector, coefficient)| {
+                coefficient * compute(kernel, support_vector, features)
+            })
+            .sum::<f64>()
+            + self.data.bias()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
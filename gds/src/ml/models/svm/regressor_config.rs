@@ -0,0 +1,113 @@
+use crate::ml::models::svm::SVMKernelType;
+use crate::ml::models::TrainerConfig;
+use crate::ml::models::TrainingMethod;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Configuration of an epsilon-insensitive support vector regressor.
+///
+/// Residuals within `epsilon` of the target are not penalized; `c` bounds the dual
+/// coefficients and thereby the influence of any single training example.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SVMRegressorTrainConfig {
+    #[serde(default)]
+    pub kernel: SVMKernelType,
+
+    #[serde(default = "default_c")]
+    pub c: f64,
+
+    #[serde(default = "default_epsilon")]
+    pub epsilon: f64,
+
+    #[serde(default = "default_tolerance")]
+    pub tolerance: f64,
+
+    #[serde(default = "default_max_iterations")]
+    pub max_iterations: usize,
+}
+
+fn default_c() -> f64 {
+    1.0
+}
+
+fn default_epsilon() -> f64 {
+    0.1
+}
+
+fn default_tolerance() -> f64 {
+    1e-3
+}
+
+fn default_max_iterations() -> usize {
+    1000
+}
+
+impl Default for SVMRegressorTrainConfig {
+    fn default() -> Self {
+        Self {
+            kernel: SVMKernelType::default(),
+            c: default_c(),
+            epsilon: default_epsilon(),
+            tolerance: default_tolerance(),
+            max_iterations: default_max_iterations(),
+        }
+    }
+}
+
+impl TrainerConfig for SVMRegressorTrainConfig {
+    fn method(&self) -> TrainingMethod {
+        TrainingMethod::SVMRegression
+    }
+
+    fn to_map(&self) -> HashMap<String, serde_json::Value> {
+        let mut map = HashMap::new();
+        map.insert(
+            "method".to_string(),
+            serde_json::Value::String("SVMRegression".to_string()),
+        );
+
+        let kernel_name = match self.kernel {
+            SVMKernelType::Linear => "linear".to_string(),
+            SVMKernelType::Rbf { .. } => "rbf".to_string(),
+        };
+        map.insert("kernel".to_string(), serde_json::Value::String(kernel_name));
+
+        if let SVMKernelType::Rbf { gamma } = self.kernel {
+            map.insert(
+                "gamma".to_string(),
+                serde_json::Value::Number(
+                    serde_json::Number::from_f64(gamma)
+                        .expect("gamma should serialize as finite f64"),
+                ),
+            );
+        }
+
+        map.insert(
+            "c".to_string(),
+            serde_json::Value::Number(
+                serde_json::Number::from_f64(self.c).expect("c should serialize as finite f64"),
+            ),
+        );
+        map.insert(
+            "epsilon".to_string(),
+            serde_json::Value::Number(
+                serde_json::Number::from_f64(self.epsilon)
+                    .expect("epsilon should serialize as finite f64"),
+            ),
+        );
+        map.insert(
+            "tolerance".to_string(),
+            serde_json::Value::Number(
+                serde_json::Number::from_f64(self.tolerance)
+                    .expect("tolerance should serialize as finite f64"),
+            ),
+        );
+        map.insert(
+            "max_iterations".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.max_iterations)),
+        );
+
+        map
+    }
+}
@@ -0,0 +1,107 @@
+use crate::ml::models::svm::SVMKernelType;
+use crate::ml::models::BaseModelData;
+use crate::ml::models::ModelData;
+use crate::ml::models::RegressorData;
+use crate::ml::models::TrainingMethod;
+use anyhow::anyhow;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Support vectors and dual coefficients of a trained epsilon-SVR.
+///
+/// The prediction for `x` is `Σ coefficient_i * k(support_vector_i, x) + bias`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SVMRegressorData {
+    support_vectors: Vec<Vec<f64>>,
+    coefficients: Vec<f64>,
+    bias: f64,
+    kernel: SVMKernelType,
+    feature_dimension: usize,
+}
+
+impl std::fmt::Debug for SVMRegressorData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SVMRegressorData")
+            .field("support_vectors", &self.support_vectors.len())
+            .field("bias", &self.bias)
+            .field("kernel", &self.kernel)
+            .field("feature_dimension", &self.feature_dimension)
+            .finish()
+    }
+}
+
+impl SVMRegressorData {
+    pub fn new(
+        support_vectors: Vec<Vec<f64>>,
+        coefficients: Vec<f64>,
+        bias: f64,
+        kernel: SVMKernelType,
+        feature_dimension: usize,
+    ) -> Self {
+        assert_eq!(
+            support_vectors.len(),
+            coefficients.len(),
+            "every support vector needs exactly one coefficient"
+        );
+        Self {
+            support_vectors,
+            coefficients,
+            bias,
+            kernel,
+            feature_dimension,
+        }
+    }
+
+    pub fn support_vectors(&self) -> &[Vec<f64>] {
+        &self.support_vectors
+    }
+
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    pub fn bias(&self) -> f64 {
+        self.bias
+    }
+
+    pub fn kernel(&self) -> &SVMKernelType {
+        &self.kernel
+    }
+
+    pub fn feature_dimension(&self) -> usize {
+        self.feature_dimension
+    }
+}
+
+impl BaseModelData for SVMRegressorData {
+    fn trainer_method(&self) -> TrainingMethod {
+        TrainingMethod::SVMRegression
+    }
+
+    fn feature_dimension(&self) -> usize {
+        self.feature_dimension
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl RegressorData for SVMRegressorData {}
+
+impl ModelData for SVMRegressorData {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self)
+            .map_err(|err| anyhow!("SVMRegressorData serialization failed: {err}"))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bincode::deserialize(bytes)
+            .map_err(|err| anyhow!("SVMRegressorData deserialization failed: {err}"))
+    }
+
+    fn num_features(&self) -> usize {
+        self.feature_dimension
+    }
+}
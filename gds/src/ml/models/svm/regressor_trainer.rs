@@ -0,0 +1,256 @@
+use crate::collections::HugeDoubleArray;
+use crate::ml::models::svm::compute;
+use crate::ml::models::svm::SVMKernelType;
+use crate::ml::models::svm::SVMRegressor;
+use crate::ml::models::svm::SVMRegressorData;
+use crate::ml::models::svm::SVMRegressorTrainConfig;
+use crate::ml::models::Features;
+use crate::ml::models::Regressor;
+use crate::ml::models::RegressorTrainer;
+use std::sync::Arc;
+
+/// Trains an epsilon-SVR by coordinate descent on its dual.
+///
+/// The dual of epsilon-SVR, with the bias folded into the kernel as `k(x, y) + 1`, is
+/// `min_β ½ βᵀKβ - yᵀβ + ε‖β‖₁` subject to `-C <= β_i <= C`. Each coordinate has a
+/// closed-form minimizer (a soft-thresholded Newton step clipped to the box), so a
+/// pass over all coordinates is a single sweep over the kernel matrix.
+#[derive(Debug, Clone)]
+pub struct SVMRegressorTrainer {
+    config: SVMRegressorTrainConfig,
+}
+
+impl SVMRegressorTrainer {
+    pub fn new(config: SVMRegressorTrainConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl RegressorTrainer for SVMRegressorTrainer {
+    fn train(
+        &self,
+        features: &dyn Features,
+        targets: &HugeDoubleArray,
+        train_set: &Arc<Vec<u64>>,
+    ) -> Box<dyn Regressor> {
+        let train_features = train_set
+            .iter()
+            .map(|&index| features.get(index as usize).to_vec())
+            .collect::<Vec<Vec<f64>>>();
+        let train_targets = train_set
+            .iter()
+            .map(|&index| targets.get(index as usize))
+            .collect::<Vec<f64>>();
+
+        let data = train_svr(
+            &train_features,
+            &train_targets,
+            &self.config,
+            features.feature_dimension(),
+        );
+
+        Box::new(SVMRegressor::new(data))
+    }
+}
+
+fn train_svr(
+    train_features: &[Vec<f64>],
+    train_targets: &[f64],
+    config: &SVMRegressorTrainConfig,
+    feature_dimension: usize,
+) -> SVMRegressorData {
+    let sample_count = train_features.len();
+    let kernel = config.kernel.clone();
+    let kernel_matrix = precompute_biased_kernel_matrix(train_features, &kernel);
+
+    let mut coefficients = vec![0.0; sample_count];
+    // fitted[i] = Σ_j coefficients[j] * kernel_matrix[i][j]
+    let mut fitted = vec![0.0; sample_count];
+
+    for _ in 0..config.max_iterations {
+        let mut max_change = 0.0f64;
+
+        for i in 0..sample_count {
+            let curvature = kernel_matrix[i][i];
+            if curvature <= 0.0 {
+                continue;
+            }
+
+            let old = coefficients[i];
+            let newton = old - (fitted[i] - train_targets[i]) / curvature;
+            let new = soft_threshold(newton, config.epsilon / curvature).clamp(-config.c, config.c);
+            let change = new - old;
+            if change == 0.0 {
+                continue;
+            }
+
+            coefficients[i] = new;
+            for (j, value) in fitted.iter_mut().enumerate() {
+                *value += change * kernel_matrix[i][j];
+            }
+            max_change = max_change.max(change.abs());
+        }
+
+        if max_change < config.tolerance {
+            break;
+        }
+    }
+
+    let bias = coefficients.iter().sum::<f64>();
+    let mut support_vectors = Vec::new();
+    let mut support_coefficients = Vec::new();
+
+    for (index, &coefficient) in coefficients.iter().enumerate() {
+        if coefficient.abs() > 1e-8 {
+            support_vectors.push(train_features[index].clone());
+            support_coefficients.push(coefficient);
+        }
+    }
+
+    SVMRegressorData::new(
+        support_vectors,
+        support_coefficients,
+        bias,
+        kernel,
+        feature_dimension,
+    )
+}
+
+fn soft_threshold(value: f64, threshold: f64) -> f64 {
+    if value > threshold {
+        value - threshold
+    } else if value < -threshold {
+        value + threshold
+    } else {
+        0.0
+    }
+}
+
+fn precompute_biased_kernel_matrix(features: &[Vec<f64>], kernel: &SVMKernelType) -> Vec<Vec<f64>> {
+    let n = features.len();
+    let mut matrix = vec![vec![0.0; n]; n];
+
+    for i in 0..n {
+        for j in i..n {
+            let value = compute(kernel, &features[i], &features[j]) + 1.0;
+            matrix[i][j] = value;
+            matrix[j][i] = value;
+        }
+    }
+
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::models::ModelData;
+
+    struct GridFeatures {
+        data: Vec<Vec<f64>>,
+    }
+
+    impl Features for GridFeatures {
+        fn get(&self, node_id: usize) -> &[f64] {
+            &self.data[node_id]
+        }
+
+        fn feature_dimension(&self) -> usize {
+            1
+        }
+
+        fn size(&self) -> usize {
+            self.data.len()
+        }
+    }
+
+    fn fit(
+        target: fn(f64) -> f64,
+        config: SVMRegressorTrainConfig,
+    ) -> (Box<dyn Regressor>, GridFeatures, Vec<f64>) {
+        let xs: Vec<f64> = (0..61).map(|i| -3.0 + i as f64 * 0.1).collect();
+        let ys: Vec<f64> = xs.iter().map(|&x| target(x)).collect();
+        let features = GridFeatures {
+            data: xs.into_iter().map(|x| vec![x]).collect(),
+        };
+        let targets = HugeDoubleArray::from_vec(ys.clone());
+        let train_set = Arc::new((0..61).collect::<Vec<u64>>());
+
+        let regressor = SVMRegressorTrainer::new(config).train(&features, &targets, &train_set);
+        (regressor, features, ys)
+    }
+
+    fn max_error(regressor: &dyn Regressor, features: &GridFeatures, targets: &[f64]) -> f64 {
+        targets
+            .iter()
+            .enumerate()
+            .map(|(i, target)| (regressor.predict(features.get(i)) - target).abs())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn rbf_kernel_fits_non_linear_target() {
+        let config = SVMRegressorTrainConfig {
+            kernel: SVMKernelType::Rbf { gamma: 1.0 },
+            c: 10.0,
+            epsilon: 0.05,
+            ..SVMRegressorTrainConfig::default()
+        };
+        let (regressor, features, targets) = fit(f64::sin, config);
+
+        let error = max_error(regressor.as_ref(), &features, &targets);
+        assert!(error < 0.1, "max error {error}");
+    }
+
+    #[test]
+    fn linear_kernel_recovers_line() {
+        let config = SVMRegressorTrainConfig {
+            c: 100.0,
+            epsilon: 0.01,
+            tolerance: 1e-6,
+            max_iterations: 5000,
+            ..SVMRegressorTrainConfig::default()
+        };
+        let (regressor, _, _) = fit(|x| 2.0 * x + 1.0, config);
+
+        assert!((regressor.predict(&[0.0]) - 1.0).abs() < 0.05);
+        assert!((regressor.predict(&[2.5]) - 6.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn wide_tube_keeps_few_support_vectors() {
+        let config = SVMRegressorTrainConfig {
+            kernel: SVMKernelType::Rbf { gamma: 1.0 },
+            c: 10.0,
+            epsilon: 0.3,
+            ..SVMRegressorTrainConfig::default()
+        };
+        let (regressor, _, _) = fit(f64::sin, config);
+        let data = regressor
+            .as_any()
+            .downcast_ref::<SVMRegressor>()
+            .unwrap()
+            .data();
+
+        assert!(data.support_vectors().len() < 61 / 2);
+    }
+
+    #[test]
+    fn data_round_trips_through_bytes() {
+        let (regressor, features, _) = fit(f64::sin, SVMRegressorTrainConfig::default());
+        let data = regressor
+            .as_any()
+            .downcast_ref::<SVMRegressor>()
+            .unwrap()
+            .data();
+
+        let restored =
+            SVMRegressor::new(SVMRegressorData::from_bytes(&data.to_bytes().unwrap()).unwrap());
+        for i in 0..features.size() {
+            assert_eq!(
+                restored.predict(features.get(i)),
+                regressor.predict(features.get(i))
+            );
+        }
+    }
+}
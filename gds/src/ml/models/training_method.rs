@@ -22,9 +22,21 @@ pub enum TrainingMethod {
     /// Support vector machine classifier.
     SVMClassification,
 
+    /// Gradient-boosted trees classifier.
+    GradientBoostingClassification,
+
+    /// Gradient-boosted trees regressor.
+    GradientBoostingRegression,
+
     /// MLP classifier for neural network classification.
     #[default]
     MLPClassification,
+
+    /// MLP regressor for neural network regression.
+    MLPRegression,
+
+    /// Epsilon-insensitive support vector regressor.
+    SVMRegression,
 }
 
 impl fmt::Display for TrainingMethod {
@@ -36,6 +48,10 @@ impl fmt::Display for TrainingMethod {
             TrainingMethod::RandomForestRegression => write!(f, "RandomForest"),
             TrainingMethod::SVMClassification => write!(f, "SupportVectorMachine"),
             TrainingMethod::MLPClassification => write!(f, "MultilayerPerceptron"),
+            TrainingMethod::MLPRegression => write!(f, "MultilayerPerceptron"),
+            TrainingMethod::SVMRegression => write!(f, "SupportVectorMachine"),
+            TrainingMethod::GradientBoostingClassification => write!(f, "GradientBoosting"),
+            TrainingMethod::GradientBoostingRegression => write!(f, "GradientBoosting"),
         }
     }
 }
@@ -49,6 +65,7 @@ impl TrainingMethod {
                 | TrainingMethod::RandomForestClassification
                 | TrainingMethod::SVMClassification
                 | TrainingMethod::MLPClassification
+                | TrainingMethod::GradientBoostingClassification
         )
     }
 
@@ -56,7 +73,11 @@ impl TrainingMethod {
     pub fn is_regression(&self) -> bool {
         matches!(
             self,
-            TrainingMethod::LinearRegression | TrainingMethod::RandomForestRegression
+            TrainingMethod::LinearRegression
+                | TrainingMethod::RandomForestRegression
+                | TrainingMethod::GradientBoostingRegression
+                | TrainingMethod::MLPRegression
+                | TrainingMethod::SVMRegression
         )
     }
 
@@ -68,14 +89,29 @@ impl TrainingMethod {
         )
     }
 
+    /// Check if this is a gradient boosting method.
+    pub fn is_gradient_boosting(&self) -> bool {
+        matches!(
+            self,
+            TrainingMethod::GradientBoostingClassification
+                | TrainingMethod::GradientBoostingRegression
+        )
+    }
+
     /// Check if this is a neural network method.
     pub fn is_neural_network(&self) -> bool {
-        matches!(self, TrainingMethod::MLPClassification)
+        matches!(
+            self,
+            TrainingMethod::MLPClassification | TrainingMethod::MLPRegression
+        )
     }
 
     /// Check if this is a support vector machine method.
     pub fn is_support_vector_machine(&self) -> bool {
-        matches!(self, TrainingMethod::SVMClassification)
+        matches!(
+            self,
+            TrainingMethod::SVMClassification | TrainingMethod::SVMRegression
+        )
     }
 }
 
@@ -131,6 +167,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_display_mlp_and_svm_regression() {
+        assert_eq!(
+            TrainingMethod::MLPRegression.to_string(),
+            "MultilayerPerceptron"
+        );
+        assert_eq!(
+            TrainingMethod::SVMRegression.to_string(),
+            "SupportVectorMachine"
+        );
+    }
+
+    #[test]
+    fn test_display_gradient_boosting() {
+        assert_eq!(
+            TrainingMethod::GradientBoostingClassification.to_string(),
+            "GradientBoosting"
+        );
+        assert_eq!(
+            TrainingMethod::GradientBoostingRegression.to_string(),
+            "GradientBoosting"
+        );
+    }
+
     #[test]
     fn test_is_classification() {
         assert!(TrainingMethod::LogisticRegression.is_classification());
@@ -139,6 +199,8 @@ mod tests {
         assert!(TrainingMethod::MLPClassification.is_classification());
         assert!(!TrainingMethod::LinearRegression.is_classification());
         assert!(!TrainingMethod::RandomForestRegression.is_classification());
+        assert!(TrainingMethod::GradientBoostingClassification.is_classification());
+        assert!(!TrainingMethod::GradientBoostingRegression.is_classification());
     }
 
     #[test]
@@ -148,6 +210,12 @@ mod tests {
         assert!(!TrainingMethod::LogisticRegression.is_regression());
         assert!(!TrainingMethod::RandomForestClassification.is_regression());
         assert!(!TrainingMethod::MLPClassification.is_regression());
+        assert!(TrainingMethod::GradientBoostingRegression.is_regression());
+        assert!(!TrainingMethod::GradientBoostingClassification.is_regression());
+        assert!(TrainingMethod::MLPRegression.is_regression());
+        assert!(TrainingMethod::SVMRegression.is_regression());
+        assert!(!TrainingMethod::MLPRegression.is_classification());
+        assert!(!TrainingMethod::SVMRegression.is_classification());
     }
 
     #[test]
@@ -159,9 +227,18 @@ mod tests {
         assert!(!TrainingMethod::MLPClassification.is_random_forest());
     }
 
+    #[test]
+    fn test_is_gradient_boosting() {
+        assert!(TrainingMethod::GradientBoostingClassification.is_gradient_boosting());
+        assert!(TrainingMethod::GradientBoostingRegression.is_gradient_boosting());
+        assert!(!TrainingMethod::RandomForestClassification.is_gradient_boosting());
+        assert!(!TrainingMethod::RandomForestRegression.is_gradient_boosting());
+    }
+
     #[test]
     fn test_is_neural_network() {
         assert!(TrainingMethod::MLPClassification.is_neural_network());
+        assert!(TrainingMethod::MLPRegression.is_neural_network());
         assert!(!TrainingMethod::LogisticRegression.is_neural_network());
         assert!(!TrainingMethod::LinearRegression.is_neural_network());
         assert!(!TrainingMethod::RandomForestClassification.is_neural_network());
@@ -171,6 +248,7 @@ mod tests {
     #[test]
     fn test_is_support_vector_machine() {
         assert!(TrainingMethod::SVMClassification.is_support_vector_machine());
+        assert!(TrainingMethod::SVMRegression.is_support_vector_machine());
         assert!(!TrainingMethod::LogisticRegression.is_support_vector_machine());
         assert!(!TrainingMethod::LinearRegression.is_support_vector_machine());
         assert!(!TrainingMethod::RandomForestClassification.is_support_vector_machine());
@@ -1,9 +1,11 @@
 pub mod api;
+pub mod named_entity;
 pub mod regexp;
 pub mod types;
 pub mod util;
 
 pub use api::*;
+pub use named_entity::*;
 pub use regexp::*;
 pub use types::*;
 pub use util::*;
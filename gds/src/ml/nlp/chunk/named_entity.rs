@@ -0,0 +1,349 @@
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+
+use crate::collections::dataset::language::tag::Tag;
+use crate::collections::dataset::language::token::TokenSpan;
+use crate::ml::nlp::classify::classify::Classifier;
+use crate::ml::nlp::classify::maxent::{
+    BinaryMaxentFeatureEncoding, MaxentClassifier, MaxentTrainingConfig,
+};
+use crate::ml::nlp::classify::util::FeatureValue;
+use crate::ml::nlp::tag::{word_shape, AveragedPerceptron};
+
+use super::api::ChunkParser;
+use super::types::{ChunkTree, TaggedToken};
+use super::util::{conlltags2tree, tree2conlltags};
+
+const START: &str = "<START>";
+const END: &str = "<END>";
+
+/// Token classifier behind an [`IobChunker`].
+#[derive(Debug, Clone)]
+pub enum IobClassifier {
+    Maxent(MaxentClassifier<String, BinaryMaxentFeatureEncoding<String>>),
+    Perceptron(AveragedPerceptron),
+}
+
+/// A chunk found by [`IobChunker::extract`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkSpan {
+    pub label: String,
+    pub text: String,
+    /// Token positions covered by the chunk.
+    pub tokens: Range<usize>,
+    /// Source span from the first to the last token.
+    pub span: TokenSpan,
+}
+
+/// Trainable IOB chunker.
+///
+/// Tokens are classified left to right into `B-X`, `I-X` or `O` from the word,
+/// its POS tag and shape, the neighbouring words and tags, and the previous
+/// IOB tag. The tag sequence is read back into chunks, so trained on entity
+/// labels (`PER`, `LOC`, ...) this is a named entity recognizer.
+#[derive(Debug, Clone)]
+pub struct IobChunker {
+    classifier: IobClassifier,
+    root_label: String,
+}
+
+impl IobChunker {
+    /// Train on gold chunk trees with the maxent classifier. During training
+    /// the previous IOB tag comes from the gold sequence.
+    pub fn train_maxent(gold: &[ChunkTree], config: MaxentTrainingConfig) -> Self {
+        let mut train_toks = Vec::new();
+        for tree in gold {
+            let triples = tree2conlltags(tree);
+            let tokens = to_tagged_tokens(&triples);
+            let mut prev_iob = START.to_string();
+            for (position, (_, _, iob)) in triples.iter().enumerate() {
+                let features = iob_features(&tokens, position, &prev_iob)
+                    .into_iter()
+                    .map(|(name, value)| (name, FeatureValue::Text(value)))
+                    .collect::<HashMap<_, _>>();
+                train_toks.push((features, iob.clone()));
+                prev_iob = iob.clone();
+            }
+        }
+
+        let encoding = BinaryMaxentFeatureEncoding::train(&train_toks, None, true);
+        let classifier = MaxentClassifier::train(encoding, &train_toks, config);
+        Self {
+            classifier: IobClassifier::Maxent(classifier),
+            root_label: root_label(gold),
+        }
+    }
+
+    /// Train on gold chunk trees with the averaged perceptron, conditioning on
+    /// its own previous predictions as the tagger does.
+    pub fn train_perceptron(gold: &[ChunkTree], iterations: usize) -> Self {
+        let sentences = gold.iter().map(tree2conlltags).collect::<Vec<_>>();
+        let mut model = AveragedPerceptron::new();
+        model.classes = sentences
+            .iter()
+            .flatten()
+            .map(|(_, _, iob)| iob.clone())
+            .collect::<BTreeSet<_>>();
+
+        for _ in 0..iterations {
+            for triples in &sentences {
+                let tokens = to_tagged_tokens(triples);
+                let mut prev_iob = START.to_string();
+                for (position, (_, _, truth)) in triples.iter().enumerate() {
+                    let features = perceptron_features(&tokens, position, &prev_iob);
+                    let guess = model.predict(&features);
+                    model.update(truth, &guess, &features);
+                    prev_iob = guess;
+                }
+            }
+        }
+        model.average_weights();
+
+        Self {
+            classifier: IobClassifier::Perceptron(model),
+            root_label: root_label(gold),
+        }
+    }
+
+    pub fn with_root_label(mut self, root_label: impl Into<String>) -> Self {
+        self.root_label = root_label.into();
+        self
+    }
+
+    pub fn classifier(&self) -> &IobClassifier {
+        &self.classifier
+    }
+
+    /// Predict one IOB tag per token.
+    pub fn iob_tags(&self, tokens: &[TaggedToken]) -> Vec<String> {
+        let mut prev_iob = START.to_string();
+        let mut out = Vec::with_capacity(tokens.len());
+        for position in 0..tokens.len() {
+            let iob = match &self.classifier {
+                IobClassifier::Maxent(classifier) => {
+                    let features = iob_features(tokens, position, &prev_iob)
+                        .into_iter()
+                        .map(|(name, value)| (name, FeatureValue::Text(value)))
+                        .collect::<HashMap<_, _>>();
+                    Classifier::classify(classifier, &features)
+                }
+                IobClassifier::Perceptron(model) => {
+                    model.predict(&perceptron_features(tokens, position, &prev_iob))
+                }
+            };
+            prev_iob = iob.clone();
+            out.push(iob);
+        }
+        out
+    }
+
+    /// Chunk POS-tagged text, e.g. the output of
+    /// [`Corpus::tag`](crate::collections::dataset::corpus::Corpus::tag), and
+    /// return the chunks with their source spans.
+    pub fn extract(&self, tags: &[Tag]) -> Vec<ChunkSpan> {
+        let tokens = tags
+            .iter()
+            .map(|tag| TaggedToken::new(tag.text(), tag.tag()))
+            .collect::<Vec<_>>();
+        let iob = self.iob_tags(&tokens);
+
+        let mut chunks = Vec::new();
+        let mut open: Option<(String, usize)> = None;
+        for (position, iob) in iob.iter().enumerate() {
+            let (continues, begins) = match iob.split_once('-') {
+                Some(("I", label)) => (
+                    open.as_ref().is_some_and(|(current, _)| current == label),
+                    Some(label),
+                ),
+                Some((_, label)) => (false, Some(label)),
+                None => (false, None),
+            };
+            if continues {
+                continue;
+            }
+            if let Some((label, start)) = open.take() {
+                chunks.push(chunk_span(tags, label, start..position));
+            }
+            open = begins.map(|label| (label.to_string(), position));
+        }
+        if let Some((label, start)) = open {
+            chunks.push(chunk_span(tags, label, start..tags.len()));
+        }
+        chunks
+    }
+}
+
+impl ChunkParser for IobChunker {
+    fn parse(&self, tokens: &[TaggedToken]) -> ChunkTree {
+        let sentence = tokens
+            .iter()
+            .zip(self.iob_tags(tokens))
+            .map(|(tok, iob)| (tok.word.clone(), tok.tag.clone(), Some(iob)))
+            .collect::<Vec<_>>();
+        conlltags2tree(&sentence, None, &self.root_label, false)
+            .expect("predicted IOB tags are well formed")
+    }
+}
+
+fn root_label(gold: &[ChunkTree]) -> String {
+    gold.first()
+        .map(|tree| tree.root_label.clone())
+        .unwrap_or_else(|| "S".to_string())
+}
+
+fn to_tagged_tokens(triples: &[(String, String, String)]) -> Vec<TaggedToken> {
+    triples
+        .iter()
+        .map(|(word, tag, _)| TaggedToken::new(word.clone(), tag.clone()))
+        .collect()
+}
+
+fn chunk_span(tags: &[Tag], label: String, tokens: Range<usize>) -> ChunkSpan {
+    let covered = &tags[tokens.clone()];
+    ChunkSpan {
+        label,
+        text: covered.iter().map(Tag::text).collect::<Vec<_>>().join(" "),
+        span: TokenSpan::new(
+            covered[0].span().start(),
+            covered[covered.len() - 1].span().end(),
+        ),
+        tokens,
+    }
+}
+
+fn iob_features(tokens: &[TaggedToken], position: usize, prev_iob: &str) -> Vec<(String, String)> {
+    let token = &tokens[position];
+    let (prev_word, prev_pos) = match position.checked_sub(1) {
+        Some(prev) => (tokens[prev].word.to_lowercase(), tokens[prev].tag.clone()),
+        None => (START.to_string(), START.to_string()),
+    };
+    let (next_word, next_pos) = match tokens.get(position + 1) {
+        Some(next) => (next.word.to_lowercase(), next.tag.clone()),
+        None => (END.to_string(), END.to_string()),
+    };
+
+    vec![
+        ("word".to_string(), token.word.to_lowercase()),
+        ("pos".to_string(), token.tag.clone()),
+        ("shape".to_string(), word_shape(&token.word)),
+        ("prev_word".to_string(), prev_word),
+        ("prev_pos".to_string(), prev_pos.clone()),
+        ("next_word".to_string(), next_word),
+        ("next_pos".to_string(), next_pos),
+        ("prev_iob".to_string(), prev_iob.to_string()),
+        (
+            "prev_pos+pos".to_string(),
+            format!("{prev_pos}+{}", token.tag),
+        ),
+        (
+            "prev_iob+pos".to_string(),
+            format!("{prev_iob}+{}", token.tag),
+        ),
+    ]
+}
+
+fn perceptron_features(
+    tokens: &[TaggedToken],
+    position: usize,
+    prev_iob: &str,
+) -> HashMap<String, f64> {
+    let mut features = iob_features(tokens, position, prev_iob)
+        .into_iter()
+        .map(|(name, value)| (format!("{name}={value}"), 1.0))
+        .collect::<HashMap<_, _>>();
+    features.insert("bias".to_string(), 1.0);
+    features
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::nlp::chunk::util::{chunk_scores_by_label, conllstr2tree, tagstr2tree};
+
+    fn np_trees() -> Vec<ChunkTree> {
+        [
+            "[ the/DT cat/NN ] sat/VBD on/IN [ the/DT mat/NN ]",
+            "[ a/DT dog/NN ] barked/VBD",
+            "[ the/DT big/JJ dog/NN ] ran/VBD",
+        ]
+        .into_iter()
+        .map(|text| tagstr2tree(text, "NP", "S", Some("/")).expect("valid chunk string"))
+        .collect()
+    }
+
+    fn ner_trees() -> Vec<ChunkTree> {
+        [
+            "John NNP B-PER\nlives VBZ O\nin IN O\nParis NNP B-LOC",
+            "Mary NNP B-PER\nvisited VBD O\nNew NNP B-LOC\nYork NNP I-LOC",
+            "Anna NNP B-PER\nflew VBD O\nto TO O\nRome NNP B-LOC",
+        ]
+        .into_iter()
+        .map(|text| conllstr2tree(text, None, "S").expect("valid conll"))
+        .collect()
+    }
+
+    #[test]
+    fn maxent_chunker_learns_noun_phrases() {
+        let config = MaxentTrainingConfig {
+            learning_rate: 0.5,
+            max_iter: 300,
+            ..MaxentTrainingConfig::default()
+        };
+        let chunker = IobChunker::train_maxent(&np_trees(), config);
+
+        let expected = tagstr2tree("[ a/DT cat/NN ] sat/VBD", "NP", "S", Some("/"))
+            .expect("valid chunk string");
+        assert_eq!(chunker.parse(&expected.leaves()), expected);
+
+        let mut score = chunker.evaluate(&np_trees());
+        assert_eq!(score.f1(), 1.0);
+    }
+
+    #[test]
+    fn perceptron_chunker_recognizes_entities() {
+        let chunker = IobChunker::train_perceptron(&ner_trees(), 10);
+
+        let mut scores = chunk_scores_by_label(&chunker, &ner_trees());
+        for score in scores.values_mut() {
+            assert_eq!(score.precision(), 1.0);
+            assert_eq!(score.recall(), 1.0);
+        }
+
+        let tags = vec![
+            Tag::new("Peter", "NNP", TokenSpan::new(0, 5)),
+            Tag::new("moved", "VBD", TokenSpan::new(6, 11)),
+            Tag::new("to", "TO", TokenSpan::new(12, 14)),
+            Tag::new("New", "NNP", TokenSpan::new(15, 18)),
+            Tag::new("York", "NNP", TokenSpan::new(19, 23)),
+        ];
+        let entities = chunker.extract(&tags);
+        assert_eq!(
+            entities,
+            vec![
+                ChunkSpan {
+                    label: "PER".to_string(),
+                    text: "Peter".to_string(),
+                    tokens: 0..1,
+                    span: TokenSpan::new(0, 5),
+                },
+                ChunkSpan {
+                    label: "LOC".to_string(),
+                    text: "New York".to_string(),
+                    tokens: 3..5,
+                    span: TokenSpan::new(15, 23),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn features_pad_sentence_boundaries() {
+        let tokens = vec![TaggedToken::new("Rome", "NNP")];
+        let features = iob_features(&tokens, 0, START)
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        assert_eq!(features["prev_pos"], START);
+        assert_eq!(features["next_word"], END);
+        assert_eq!(features["shape"], "Xx");
+    }
+}
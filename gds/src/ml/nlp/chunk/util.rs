@@ -1,7 +1,9 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 use regex::Regex;
 
+use crate::collections::dataset::language::tree::{TreeLeafValue, TreeValue};
+
 use super::api::ChunkParser;
 use super::types::{Chunk, ChunkItem, ChunkTree, TaggedToken};
 
@@ -112,6 +114,11 @@ impl ChunkScore {
         }
     }
 
+    /// Balanced F-measure of chunk precision and recall.
+    pub fn f1(&mut self) -> f64 {
+        self.f_measure(0.5)
+    }
+
     pub fn f_measure(&mut self, alpha: f64) -> f64 {
         let p = self.precision();
         let r = self.recall();
@@ -141,6 +148,36 @@ impl ChunkScore {
     }
 }
 
+/// Score a chunker separately for every chunk label found in the gold trees.
+pub fn chunk_scores_by_label<P: ChunkParser>(
+    chunker: &P,
+    gold: &[ChunkTree],
+) -> BTreeMap<String, ChunkScore> {
+    let labels = gold
+        .iter()
+        .flat_map(|tree| tree.items.iter())
+        .filter_map(|item| match item {
+            ChunkItem::Chunk(chunk) => Some(chunk.label.clone()),
+            ChunkItem::Token(_) => None,
+        })
+        .collect::<BTreeSet<_>>();
+    let guesses = gold
+        .iter()
+        .map(|tree| chunker.parse(&tree.leaves()))
+        .collect::<Vec<_>>();
+
+    labels
+        .into_iter()
+        .map(|label| {
+            let mut score = ChunkScore::new(format!("^{}$", regex::escape(&label)));
+            for (correct, guessed) in gold.iter().zip(&guesses) {
+                score.score(correct, guessed);
+            }
+            (label, score)
+        })
+        .collect()
+}
+
 fn chunksets(
     tree: &ChunkTree,
     count: usize,
@@ -397,6 +434,82 @@ pub fn tree2conllstr(tree: &ChunkTree) -> String {
         .join("\n")
 }
 
+/// Convert a [`ChunkTree`] into a two-level [`TreeValue`]: chunks become nodes
+/// under the root and each tagged token becomes a preterminal `(TAG word)`.
+pub fn chunktree2tree(tree: &ChunkTree) -> TreeValue {
+    let preterminal = |tok: &TaggedToken| {
+        TreeValue::node(tok.tag.clone(), vec![TreeValue::leaf(tok.word.clone())])
+    };
+    let children = tree
+        .items
+        .iter()
+        .map(|item| match item {
+            ChunkItem::Token(tok) => preterminal(tok),
+            ChunkItem::Chunk(chunk) => TreeValue::node(
+                chunk.label.clone(),
+                chunk.tokens.iter().map(preterminal).collect(),
+            ),
+        })
+        .collect();
+    TreeValue::node(tree.root_label.clone(), children)
+}
+
+/// Read a [`ChunkTree`] back from a tree in the shape produced by
+/// [`chunktree2tree`]. Chunks nested deeper than one level are rejected.
+pub fn tree2chunktree(tree: &TreeValue) -> Result<ChunkTree, String> {
+    let root = tree
+        .as_node()
+        .ok_or_else(|| "Chunk tree root must be a node".to_string())?;
+
+    let mut items = Vec::with_capacity(root.children().len());
+    for child in root.children() {
+        if let Some(tok) = preterminal_token(child) {
+            items.push(ChunkItem::Token(tok));
+            continue;
+        }
+        let chunk = child
+            .as_node()
+            .ok_or_else(|| "Chunk tree leaves must be wrapped in (TAG word) nodes".to_string())?;
+        let tokens = chunk
+            .children()
+            .iter()
+            .map(|grandchild| {
+                preterminal_token(grandchild)
+                    .ok_or_else(|| format!("Chunk {} is too deeply nested for IOB", chunk.label()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        items.push(ChunkItem::Chunk(Chunk::new(chunk.label(), tokens)));
+    }
+
+    Ok(ChunkTree::new(root.label(), items))
+}
+
+/// IOB triples `(word, tag, iob)` for a chunk-structured [`TreeValue`].
+pub fn tree2iob(tree: &TreeValue) -> Result<Vec<(String, String, String)>, String> {
+    tree2chunktree(tree).map(|chunked| tree2conlltags(&chunked))
+}
+
+/// Build a chunk-structured [`TreeValue`] from IOB triples. Stray `I-` tags
+/// open a new chunk, as in non-strict [`conlltags2tree`].
+pub fn iob2tree(
+    sentence: &[(String, String, String)],
+    root_label: &str,
+) -> Result<TreeValue, String> {
+    let sentence = sentence
+        .iter()
+        .map(|(word, tag, iob)| (word.clone(), tag.clone(), Some(iob.clone())))
+        .collect::<Vec<_>>();
+    conlltags2tree(&sentence, None, root_label, false).map(|chunked| chunktree2tree(&chunked))
+}
+
+fn preterminal_token(tree: &TreeValue) -> Option<TaggedToken> {
+    let node = tree.as_node()?;
+    match node.children() {
+        [TreeValue::Leaf(TreeLeafValue::Text(word))] => Some(TaggedToken::new(word, node.label())),
+        _ => None,
+    }
+}
+
 fn str2tuple(text: &str, sep: &str) -> (String, Option<String>) {
     if let Some((word, tag)) = text.rsplit_once(sep) {
         (word.to_string(), Some(tag.to_string()))
@@ -440,6 +553,44 @@ mod tests {
         assert!((score.recall() - 1.0).abs() < 1e-12);
     }
 
+    #[test]
+    fn tree_and_iob_conversions_roundtrip() {
+        let chunked = conllstr2tree(
+            "Mark NNP B-PER\nvisited VBD O\nNew NNP B-LOC\nYork NNP I-LOC",
+            None,
+            "S",
+        )
+        .expect("conll parse");
+        let tree = chunktree2tree(&chunked);
+        assert_eq!(
+            tree.format_bracketed(),
+            "(S (PER (NNP Mark)) (VBD visited) (LOC (NNP New) (NNP York)))"
+        );
+        assert_eq!(tree2chunktree(&tree).expect("two-level tree"), chunked);
+
+        let iob = tree2iob(&tree).expect("two-level tree");
+        assert_eq!(iob[3].2, "I-LOC");
+        assert_eq!(iob2tree(&iob, "S").expect("valid iob"), tree);
+
+        let nested = TreeValue::node("S", vec![TreeValue::node("NP", vec![tree])]);
+        assert!(tree2iob(&nested).is_err());
+    }
+
+    #[test]
+    fn chunk_scores_split_by_label() {
+        let gold = conllstr2tree(
+            "Mark NNP B-PER\nvisited VBD O\nNew NNP B-LOC\nYork NNP I-LOC",
+            None,
+            "S",
+        )
+        .expect("gold parse");
+        let mut scores = chunk_scores_by_label(&PassthroughChunker, &[gold]);
+        assert_eq!(scores.keys().collect::<Vec<_>>(), vec!["LOC", "PER"]);
+        let per = scores.get_mut("PER").expect("PER score");
+        assert_eq!(per.recall(), 0.0);
+        assert_eq!(per.f1(), 0.0);
+    }
+
     #[test]
     fn parser_accuracy_runs() {
         let gold_tree =
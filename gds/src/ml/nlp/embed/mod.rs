@@ -0,0 +1,3 @@
+pub mod word2vec;
+
+pub use word2vec::*;
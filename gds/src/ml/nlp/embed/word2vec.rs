@@ -0,0 +1,459 @@
+//! Word2Vec word embeddings (skip-gram and CBOW with negative sampling).
+//!
+//! Sentences are mapped to vocabulary ids and handed to the Node2Vec
+//! trainer ([`Node2VecModel::for_sequences`]), which treats them exactly like
+//! random walks: frequent-word subsampling, unigram^0.75 negative sampling and
+//! a linearly decaying learning rate.
+//!
+//! Trained vectors can be queried for nearest words, saved in the textual
+//! word2vec format (`<count> <dimension>` header, then one `word v1 … vd` line
+//! per word) and attached to the term nodes of a [`TextGraph`].
+
+use crate::algo::embeddings::node2vec::{
+    CompressedRandomWalks, EmbeddingInitializer, Node2VecModel, SequenceObjective, TrainParameters,
+};
+use crate::collections::dataframe::GDSFrameError;
+use crate::collections::dataset::corpus::{Corpus, TextGraph, TextNodeKind};
+use crate::collections::dataset::language::text::TermNormalizer;
+use crate::collections::dataset::language::tokenizer::Tokenizer;
+use crate::task::concurrency::TerminationFlag;
+use crate::task::progress::NoopProgressTracker;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Word2VecError {
+    #[error("no word occurs at least {0} times")]
+    EmptyVocabulary(usize),
+    #[error("invalid word2vec parameter: {0}")]
+    InvalidParameter(String),
+    #[error("word2vec training was terminated")]
+    Terminated,
+    #[error("malformed word2vec file at line {line}: {message}")]
+    Format { line: usize, message: String },
+    #[error(transparent)]
+    Frame(#[from] GDSFrameError),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+/// Training configuration; defaults follow the reference word2vec tool.
+#[derive(Debug, Clone)]
+pub struct Word2VecConfig {
+    pub objective: SequenceObjective,
+    pub dimension: usize,
+    /// Context words on each side of the center word.
+    pub window: usize,
+    pub min_count: usize,
+    pub negative_samples: usize,
+    pub iterations: usize,
+    pub initial_learning_rate: f64,
+    pub min_learning_rate: f64,
+    /// Subsampling threshold for frequent words.
+    pub sample: f64,
+    pub negative_sampling_exponent: f64,
+    pub random_seed: Option<u64>,
+}
+
+impl Default for Word2VecConfig {
+    fn default() -> Self {
+        Self {
+            objective: SequenceObjective::SkipGram,
+            dimension: 100,
+            window: 5,
+            min_count: 5,
+            negative_samples: 5,
+            iterations: 5,
+            initial_learning_rate: 0.025,
+            min_learning_rate: 1e-4,
+            sample: 1e-3,
+            negative_sampling_exponent: 0.75,
+            random_seed: Some(42),
+        }
+    }
+}
+
+impl Word2VecConfig {
+    fn validate(&self) -> Result<(), Word2VecError> {
+        let invalid = |message: &str| Err(Word2VecError::InvalidParameter(message.to_string()));
+        if self.dimension == 0 {
+            return invalid("dimension must be positive");
+        }
+        if self.window == 0 {
+            return invalid("window must be positive");
+        }
+        if self.iterations == 0 {
+            return invalid("iterations must be positive");
+        }
+        if self.sample <= 0.0 {
+            return invalid("sample must be positive");
+        }
+        if self.min_learning_rate > self.initial_learning_rate {
+            return invalid("min_learning_rate must not exceed initial_learning_rate");
+        }
+        Ok(())
+    }
+}
+
+/// A trained word-vector table.
+#[derive(Debug, Clone)]
+pub struct Word2Vec {
+    words: Vec<String>,
+    index: HashMap<String, usize>,
+    vectors: Vec<Vec<f64>>,
+    loss_per_iteration: Vec<f64>,
+}
+
+impl Word2Vec {
+    /// Train on pre-tokenized sentences.
+    pub fn train<S: AsRef<str>>(
+        sentences: &[Vec<S>],
+        config: &Word2VecConfig,
+    ) -> Result<Self, Word2VecError> {
+        config.validate()?;
+
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for word in sentences.iter().flatten() {
+            *counts.entry(word.as_ref()).or_default() += 1;
+        }
+        // Most frequent first, ties alphabetical, so ids are deterministic.
+        let mut vocabulary: Vec<(&str, usize)> = counts
+            .into_iter()
+            .filter(|(_, count)| *count >= config.min_count)
+            .collect();
+        vocabulary.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        if vocabulary.is_empty() {
+            return Err(Word2VecError::EmptyVocabulary(config.min_count));
+        }
+        let words: Vec<String> = vocabulary.iter().map(|(w, _)| w.to_string()).collect();
+        let index: HashMap<String, usize> = words
+            .iter()
+            .enumerate()
+            .map(|(id, word)| (word.clone(), id))
+            .collect();
+
+        let sequences = sentences
+            .iter()
+            .map(|sentence| {
+                sentence
+                    .iter()
+                    .filter_map(|word| index.get(word.as_ref()).map(|&id| id as i64))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|sequence| sequence.len() > 1)
+            .collect();
+
+        let train_params = TrainParameters {
+            initial_learning_rate: config.initial_learning_rate,
+            min_learning_rate: config.min_learning_rate,
+            iterations: config.iterations,
+            // The trainer's window spans both sides plus the center.
+            window_size: 2 * config.window + 1,
+            negative_sampling_rate: config.negative_samples,
+            embedding_dimension: config.dimension,
+            embedding_initializer: EmbeddingInitializer::Normalized,
+        };
+        let trained = Node2VecModel::for_sequences(
+            words.len(),
+            train_params,
+            config.sample,
+            config.negative_sampling_exponent,
+            config.random_seed,
+            CompressedRandomWalks::from_sequences(sequences),
+            TerminationFlag::running_true(),
+        )
+        .with_objective(config.objective)
+        .train(&mut NoopProgressTracker)
+        .map_err(|_| Word2VecError::Terminated)?;
+
+        Ok(Self {
+            words,
+            index,
+            vectors: trained.embeddings,
+            loss_per_iteration: trained.loss_per_iteration,
+        })
+    }
+
+    /// Tokenize every corpus document, normalize it into terms and train.
+    ///
+    /// Each document is one training sentence.
+    pub fn train_corpus<T: Tokenizer>(
+        corpus: &Corpus,
+        tokenizer: &T,
+        normalizer: &TermNormalizer,
+        config: &Word2VecConfig,
+    ) -> Result<Self, Word2VecError> {
+        let sentences: Vec<Vec<String>> = corpus
+            .tokenize(tokenizer)?
+            .iter()
+            .map(|tokens| normalizer.terms(tokens))
+            .collect();
+        Self::train(&sentences, config)
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.vectors.first().map_or(0, Vec::len)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Vocabulary, most frequent first.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.index.contains_key(word)
+    }
+
+    pub fn vector(&self, word: &str) -> Option<&[f64]> {
+        self.index.get(word).map(|&id| self.vectors[id].as_slice())
+    }
+
+    pub fn loss_per_iteration(&self) -> &[f64] {
+        &self.loss_per_iteration
+    }
+
+    /// Cosine similarity of two in-vocabulary words.
+    pub fn similarity(&self, left: &str, right: &str) -> Option<f64> {
+        Some(cosine(self.vector(left)?, self.vector(right)?))
+    }
+
+    /// The `k` words closest to `word` by cosine similarity, best first.
+    pub fn most_similar(&self, word: &str, k: usize) -> Vec<(String, f64)> {
+        match self.index.get(word) {
+            Some(&id) => self.nearest_excluding(&self.vectors[id], k, Some(id)),
+            None => Vec::new(),
+        }
+    }
+
+    /// The `k` words closest to an arbitrary vector, best first.
+    pub fn nearest_to_vector(&self, vector: &[f64], k: usize) -> Vec<(String, f64)> {
+        self.nearest_excluding(vector, k, None)
+    }
+
+    fn nearest_excluding(
+        &self,
+        vector: &[f64],
+        k: usize,
+        excluded: Option<usize>,
+    ) -> Vec<(String, f64)> {
+        let mut scored: Vec<(usize, f64)> = self
+            .vectors
+            .iter()
+            .enumerate()
+            .filter(|(id, _)| Some(*id) != excluded)
+            .map(|(id, other)| (id, cosine(vector, other)))
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        scored
+            .into_iter()
+            .take(k)
+            .map(|(id, score)| (self.words[id].clone(), score))
+            .collect()
+    }
+
+    /// One vector per text-graph node: `Token` nodes whose term is in the
+    /// vocabulary get its vector, every other node gets `None`.
+    ///
+    /// The result lines up with node ids and can back a double-array node
+    /// property for term nodes.
+    pub fn term_vectors(&self, graph: &TextGraph) -> Vec<Option<Vec<f64>>> {
+        graph
+            .nodes()
+            .iter()
+            .map(|node| match node.kind {
+                TextNodeKind::Token => self.vector(&node.text).map(<[f64]>::to_vec),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Write the textual word2vec format.
+    pub fn write_text<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+        writeln!(writer, "{} {}", self.len(), self.dimension())?;
+        for (word, vector) in self.words.iter().zip(&self.vectors) {
+            write!(writer, "{word}")?;
+            for value in vector {
+                write!(writer, " {value}")?;
+            }
+            writeln!(writer)?;
+        }
+        writer.flush()
+    }
+
+    /// Read the textual word2vec format written by [`Word2Vec::write_text`]
+    /// (or by the reference tool with `-binary 0`).
+    pub fn read_text<R: BufRead>(reader: R) -> Result<Self, Word2VecError> {
+        let format_error = |line: usize, message: String| Word2VecError::Format { line, message };
+        let mut lines = reader.lines();
+        let header = lines
+            .next()
+            .ok_or_else(|| format_error(1, "missing header".to_string()))??;
+        let mut fields = header.split_whitespace().map(str::parse::<usize>);
+        let (Some(Ok(count)), Some(Ok(dimension)), None) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            return Err(format_error(
+                1,
+                format!("expected '<count> <dimension>', got '{header}'"),
+            ));
+        };
+
+        let mut words = Vec::with_capacity(count);
+        let mut vectors = Vec::with_capacity(count);
+        for (offset, line) in lines.enumerate() {
+            let line = line?;
+            let line_number = offset + 2;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let word = fields.next().unwrap_or_default().to_string();
+            let vector = fields
+                .map(str::parse::<f64>)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format_error(line_number, e.to_string()))?;
+            if vector.len() != dimension {
+                return Err(format_error(
+                    line_number,
+                    format!("expected {dimension} values, got {}", vector.len()),
+                ));
+            }
+            words.push(word);
+            vectors.push(vector);
+        }
+        if words.len() != count {
+            return Err(format_error(
+                words.len() + 1,
+                format!("header announces {count} words, found {}", words.len()),
+            ));
+        }
+
+        let index = words
+            .iter()
+            .enumerate()
+            .map(|(id, word)| (word.clone(), id))
+            .collect();
+        Ok(Self {
+            words,
+            index,
+            vectors,
+            loss_per_iteration: Vec::new(),
+        })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.write_text(File::create(path)?)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Word2VecError> {
+        Self::read_text(BufReader::new(File::open(path)?))
+    }
+}
+
+fn cosine(left: &[f64], right: &[f64]) -> f64 {
+    let dot: f64 = left.iter().zip(right).map(|(a, b)| a * b).sum();
+    let norm = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let denominator = norm(left) * norm(right);
+    if denominator == 0.0 {
+        0.0
+    } else {
+        dot / denominator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two topics that never share a sentence.
+    fn sentences() -> Vec<Vec<&'static str>> {
+        let mut sentences = Vec::new();
+        for _ in 0..40 {
+            sentences.push(vec!["cat", "dog", "pet", "fur", "cat", "dog"]);
+            sentences.push(vec!["dog", "pet", "cat", "fur", "pet"]);
+            sentences.push(vec!["car", "road", "engine", "wheel", "car", "road"]);
+            sentences.push(vec!["road", "engine", "car", "wheel", "engine"]);
+        }
+        sentences
+    }
+
+    fn config(objective: SequenceObjective) -> Word2VecConfig {
+        Word2VecConfig {
+            objective,
+            dimension: 16,
+            window: 2,
+            min_count: 1,
+            negative_samples: 3,
+            iterations: 10,
+            sample: 1.0,
+            ..Word2VecConfig::default()
+        }
+    }
+
+    #[test]
+    fn skip_gram_separates_topics() {
+        let model = Word2Vec::train(&sentences(), &config(SequenceObjective::SkipGram)).unwrap();
+
+        assert_eq!(model.len(), 8);
+        assert_eq!(model.dimension(), 16);
+        let nearest = model.most_similar("cat", 3);
+        assert!(nearest
+            .iter()
+            .all(|(word, _)| ["dog", "pet", "fur"].contains(&word.as_str())));
+        assert!(model.similarity("cat", "dog").unwrap() > model.similarity("cat", "car").unwrap());
+    }
+
+    #[test]
+    fn cbow_separates_topics() {
+        let model = Word2Vec::train(&sentences(), &config(SequenceObjective::Cbow)).unwrap();
+
+        let loss = model.loss_per_iteration();
+        assert!(loss.last().unwrap() < loss.first().unwrap());
+        assert!(
+            model.similarity("engine", "wheel").unwrap()
+                > model.similarity("engine", "fur").unwrap()
+        );
+    }
+
+    #[test]
+    fn min_count_filters_vocabulary() {
+        let sentences = vec![vec!["a", "b", "a"], vec!["a", "c"]];
+        let mut config = config(SequenceObjective::SkipGram);
+        config.min_count = 2;
+        let model = Word2Vec::train(&sentences, &config).unwrap();
+        assert_eq!(model.words(), ["a"]);
+        assert!(model.vector("b").is_none());
+
+        config.min_count = 5;
+        assert!(matches!(
+            Word2Vec::train(&sentences, &config),
+            Err(Word2VecError::EmptyVocabulary(5))
+        ));
+    }
+
+    #[test]
+    fn text_format_round_trips() {
+        let model = Word2Vec::train(&sentences(), &config(SequenceObjective::SkipGram)).unwrap();
+        let mut buffer = Vec::new();
+        model.write_text(&mut buffer).unwrap();
+
+        let loaded = Word2Vec::read_text(buffer.as_slice()).unwrap();
+        assert_eq!(loaded.words(), model.words());
+        assert_eq!(loaded.vector("road"), model.vector("road"));
+        assert!(matches!(
+            Word2Vec::read_text("2 3\ncat 1 2 3\n".as_bytes()),
+            Err(Word2VecError::Format { .. })
+        ));
+    }
+}
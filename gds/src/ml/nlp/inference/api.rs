@@ -43,6 +43,30 @@ pub struct ModelResult<M> {
     pub model: Option<M>,
 }
 
+/// Outcome of running a prover and a model builder side by side.
+#[derive(Debug, Clone)]
+pub enum ProveOrCountermodel<M> {
+    /// The prover proved the goal (or refuted the assumptions).
+    Proved(ProofResult),
+    /// The builder found a model of the assumptions and the negated goal.
+    Countermodel(M),
+    /// Neither side succeeded within its bounds.
+    Undecided,
+}
+
+impl<M> ProveOrCountermodel<M> {
+    pub fn is_proved(&self) -> bool {
+        matches!(self, ProveOrCountermodel::Proved(_))
+    }
+
+    pub fn countermodel(&self) -> Option<&M> {
+        match self {
+            ProveOrCountermodel::Countermodel(m) => Some(m),
+            _ => None,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Traits
 // ---------------------------------------------------------------------------
@@ -131,3 +155,132 @@ impl<'a, P: Prover> ProverCommand<'a, P> {
         self.cached.as_ref().map(|r| r.proof.as_str())
     }
 }
+
+/// A prover and a model builder run in parallel on the same problem.
+/// Mirrors NLTK `ParallelProverBuilder`.
+///
+/// The two searches are complementary: a proof shows the goal follows
+/// from the assumptions, a model of the assumptions plus the negated goal
+/// shows it does not. Both run on scoped threads under their own step
+/// bounds; a decisive answer from either side wins over an error from the
+/// other.
+pub struct ParallelProverBuilder<'a, P: Prover, B> {
+    prover: &'a P,
+    builder: &'a B,
+}
+
+impl<'a, P: Prover, B> ParallelProverBuilder<'a, P, B> {
+    pub fn new(prover: &'a P, builder: &'a B) -> Self {
+        Self { prover, builder }
+    }
+
+    pub fn prove_or_countermodel<M>(
+        &self,
+        goal: Option<&Expression>,
+        assumptions: &[Expression],
+        verbose: bool,
+    ) -> Result<ProveOrCountermodel<M>, InferenceError>
+    where
+        P: Sync,
+        B: ModelBuilder<M> + Sync,
+        M: Send,
+    {
+        let (proof, model) = std::thread::scope(|scope| {
+            let proof = scope.spawn(|| self.prover.prove(goal, assumptions, verbose));
+            let model = scope.spawn(|| self.builder.build_model(goal, assumptions, verbose));
+            (
+                proof
+                    .join()
+                    .unwrap_or_else(|_| Err(InferenceError("prover panicked".to_string()))),
+                model
+                    .join()
+                    .unwrap_or_else(|_| Err(InferenceError("model builder panicked".to_string()))),
+            )
+        });
+
+        match (proof, model) {
+            (Ok(proof), _) if proof.proved => Ok(ProveOrCountermodel::Proved(proof)),
+            (
+                _,
+                Ok(ModelResult {
+                    model: Some(model), ..
+                }),
+            ) => Ok(ProveOrCountermodel::Countermodel(model)),
+            (Err(e), _) | (_, Err(e)) => Err(e),
+            _ => Ok(ProveOrCountermodel::Undecided),
+        }
+    }
+}
+
+/// A [`ParallelProverBuilder`] bundled with its goal and assumptions,
+/// caching the outcome. Mirrors NLTK `ParallelProverBuilderCommand`.
+pub struct ParallelProverBuilderCommand<'a, P: Prover, B, M> {
+    runner: ParallelProverBuilder<'a, P, B>,
+    goal: Option<Expression>,
+    assumptions: Vec<Expression>,
+    cached: Option<ProveOrCountermodel<M>>,
+}
+
+impl<'a, P, B, M> ParallelProverBuilderCommand<'a, P, B, M>
+where
+    P: Prover + Sync,
+    B: ModelBuilder<M> + Sync,
+    M: Send,
+{
+    pub fn new(
+        prover: &'a P,
+        builder: &'a B,
+        goal: Option<Expression>,
+        assumptions: Vec<Expression>,
+    ) -> Self {
+        Self {
+            runner: ParallelProverBuilder::new(prover, builder),
+            goal,
+            assumptions,
+            cached: None,
+        }
+    }
+
+    pub fn add_assumptions(&mut self, new_assumptions: impl IntoIterator<Item = Expression>) {
+        self.assumptions.extend(new_assumptions);
+        self.cached = None;
+    }
+
+    pub fn retract_assumptions(&mut self, retracted: &[Expression]) {
+        let before = self.assumptions.len();
+        self.assumptions.retain(|a| !retracted.contains(a));
+        if self.assumptions.len() != before {
+            self.cached = None;
+        }
+    }
+
+    pub fn assumptions(&self) -> &[Expression] {
+        &self.assumptions
+    }
+
+    pub fn goal(&self) -> Option<&Expression> {
+        self.goal.as_ref()
+    }
+
+    pub fn run(&mut self, verbose: bool) -> Result<&ProveOrCountermodel<M>, InferenceError> {
+        if self.cached.is_none() {
+            let outcome = self.runner.prove_or_countermodel(
+                self.goal.as_ref(),
+                &self.assumptions,
+                verbose,
+            )?;
+            self.cached = Some(outcome);
+        }
+        Ok(self.cached.as_ref().unwrap())
+    }
+
+    pub fn prove(&mut self, verbose: bool) -> Result<bool, InferenceError> {
+        Ok(self.run(verbose)?.is_proved())
+    }
+
+    pub fn countermodel(&self) -> Option<&M> {
+        self.cached
+            .as_ref()
+            .and_then(ProveOrCountermodel::countermodel)
+    }
+}
@@ -0,0 +1,795 @@
+//! Finite model builder.
+//!
+//! Native Rust stand-in for `nltk/inference/mace.py`, which shells out to
+//! Mace4. Like Mace4 we search for a model of the assumptions (plus the
+//! negated goal, if any) over domains of increasing size, and return it as
+//! a [`Model`] that [`Model::satisfy`] can evaluate directly.
+//!
+//! ## Algorithm sketch
+//!
+//! For each domain size `n = min_domain_size ..= max_domain_size`:
+//!
+//! 1. Assign every individual constant a domain element, using the Mace4
+//!    "least number" rule (a constant may only take an element already in
+//!    use or the next fresh one) to skip isomorphic assignments.
+//! 2. Ground every formula over the domain: `∀` becomes a conjunction,
+//!    `∃` a disjunction, and `=` between terms is decided outright. What is
+//!    left is propositional over the cells `P(d_i, …)` of the predicates.
+//! 3. Backtrack over the cells, evaluating each ground formula in
+//!    three-valued logic and pruning as soon as one is false.
+//!
+//! Open formulas are universally closed over their free individual
+//! variables, matching the [`ModelBuilder`] contract.
+//!
+//! ## Differences from Mace4
+//!
+//! - Function symbols are not supported: terms must be individual
+//!   constants or bound variables. [`crate::ml::nlp::sem::skolemize`]
+//!   output with Skolem functions is therefore rejected.
+//! - The search is bounded by [`MaceModelBuilder::max_steps`] instead of a
+//!   wall-clock timeout. Running out of steps reports "not built".
+
+use crate::ml::nlp::inference::api::{InferenceError, ModelBuilder, ModelResult};
+use crate::ml::nlp::sem::evaluate::{Denotation, Individual, Model, Valuation};
+use crate::ml::nlp::sem::logic::{Expression, Variable};
+use std::collections::BTreeSet;
+
+// ---------------------------------------------------------------------------
+// Public builder
+// ---------------------------------------------------------------------------
+
+/// Configuration for the finite model builder.
+#[derive(Debug, Clone)]
+pub struct MaceModelBuilder {
+    /// Smallest domain size tried.
+    pub min_domain_size: usize,
+    /// Largest domain size tried. Mirrors Mace4's `end_size`.
+    pub max_domain_size: usize,
+    /// Maximum number of search nodes over all domain sizes before giving
+    /// up.
+    pub max_steps: u64,
+}
+
+impl Default for MaceModelBuilder {
+    fn default() -> Self {
+        Self {
+            min_domain_size: 1,
+            max_domain_size: 6,
+            max_steps: 100_000,
+        }
+    }
+}
+
+impl MaceModelBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ModelBuilder<Model> for MaceModelBuilder {
+    fn build_model(
+        &self,
+        goal: Option<&Expression>,
+        assumptions: &[Expression],
+        _verbose: bool,
+    ) -> Result<ModelResult<Model>, InferenceError> {
+        let mut formulas = assumptions
+            .iter()
+            .map(|a| universal_closure(&a.simplify()))
+            .collect::<Vec<_>>();
+        if let Some(g) = goal {
+            formulas.push(Expression::not(universal_closure(&g.simplify())));
+        }
+
+        let mut signature = Signature::default();
+        for f in &formulas {
+            signature.collect_formula(f, &mut Vec::new())?;
+        }
+
+        let mut steps: u64 = 0;
+        for size in self.min_domain_size.max(1)..=self.max_domain_size {
+            match search_domain(&formulas, &signature, size, self.max_steps, &mut steps)? {
+                Search::Found(model) => {
+                    return Ok(ModelResult {
+                        built: true,
+                        model: Some(model),
+                    })
+                }
+                Search::Exhausted => {}
+                Search::OutOfSteps => break,
+            }
+        }
+        Ok(ModelResult {
+            built: false,
+            model: None,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Signature
+// ---------------------------------------------------------------------------
+
+/// Non-logical symbols of the formula set, split by the role they play.
+#[derive(Debug, Default)]
+struct Signature {
+    constants: Vec<String>,
+    predicates: Vec<(String, usize)>,
+    propositions: Vec<String>,
+}
+
+impl Signature {
+    fn collect_formula(
+        &mut self,
+        expr: &Expression,
+        bound: &mut Vec<String>,
+    ) -> Result<(), InferenceError> {
+        match expr {
+            Expression::Negated(e) => self.collect_formula(e, bound),
+            Expression::And(a, b)
+            | Expression::Or(a, b)
+            | Expression::Imp(a, b)
+            | Expression::Iff(a, b) => {
+                self.collect_formula(a, bound)?;
+                self.collect_formula(b, bound)
+            }
+            Expression::All(v, body) | Expression::Exists(v, body) => {
+                bound.push(v.name().to_string());
+                let result = self.collect_formula(body, bound);
+                bound.pop();
+                result
+            }
+            Expression::Equality(a, b) => {
+                self.collect_term(a, bound)?;
+                self.collect_term(b, bound)
+            }
+            Expression::Application(_, _) => {
+                let (head, args) = uncurry(expr);
+                let name = match head {
+                    Expression::Constant(c) => c.as_str(),
+                    Expression::Variable(v) if !is_bound(bound, v.name()) => v.name(),
+                    other => {
+                        return Err(InferenceError(format!(
+                            "model builder only supports named predicates, got {other}"
+                        )))
+                    }
+                };
+                self.add_predicate(name, args.len())?;
+                for arg in args {
+                    self.collect_term(arg, bound)?;
+                }
+                Ok(())
+            }
+            Expression::Constant(c) => self.add_proposition(c),
+            Expression::Variable(v) if !is_bound(bound, v.name()) => self.add_proposition(v.name()),
+            other => Err(InferenceError(format!(
+                "model builder cannot interpret {other} as a formula"
+            ))),
+        }
+    }
+
+    fn collect_term(&mut self, expr: &Expression, bound: &[String]) -> Result<(), InferenceError> {
+        match expr {
+            Expression::Variable(v) if is_bound(bound, v.name()) => Ok(()),
+            Expression::Variable(v) => self.add_constant(v.name()),
+            Expression::Constant(c) => self.add_constant(c),
+            other => Err(InferenceError(format!(
+                "model builder does not support function terms: {other}"
+            ))),
+        }
+    }
+
+    fn add_constant(&mut self, name: &str) -> Result<(), InferenceError> {
+        self.check_unused(name, Role::Constant)?;
+        if !self.constants.iter().any(|c| c == name) {
+            self.constants.push(name.to_string());
+        }
+        Ok(())
+    }
+
+    fn add_predicate(&mut self, name: &str, arity: usize) -> Result<(), InferenceError> {
+        self.check_unused(name, Role::Predicate)?;
+        match self.predicates.iter().find(|(p, _)| p == name) {
+            Some((_, known)) if *known != arity => Err(InferenceError(format!(
+                "predicate {name} is used with arities {known} and {arity}"
+            ))),
+            Some(_) => Ok(()),
+            None => {
+                self.predicates.push((name.to_string(), arity));
+                Ok(())
+            }
+        }
+    }
+
+    fn add_proposition(&mut self, name: &str) -> Result<(), InferenceError> {
+        self.check_unused(name, Role::Proposition)?;
+        if !self.propositions.iter().any(|p| p == name) {
+            self.propositions.push(name.to_string());
+        }
+        Ok(())
+    }
+
+    fn check_unused(&self, name: &str, role: Role) -> Result<(), InferenceError> {
+        let clash = (role != Role::Constant && self.constants.iter().any(|c| c == name))
+            || (role != Role::Predicate && self.predicates.iter().any(|(p, _)| p == name))
+            || (role != Role::Proposition && self.propositions.iter().any(|p| p == name));
+        if clash {
+            return Err(InferenceError(format!(
+                "symbol {name} is used in more than one role"
+            )));
+        }
+        Ok(())
+    }
+
+    fn constant_index(&self, name: &str) -> usize {
+        self.constants
+            .iter()
+            .position(|c| c == name)
+            .expect("constant collected in signature")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Constant,
+    Predicate,
+    Proposition,
+}
+
+// ---------------------------------------------------------------------------
+// Grounding
+// ---------------------------------------------------------------------------
+
+/// A quantifier-free formula over the cells of the model under search.
+#[derive(Debug, Clone)]
+enum Ground {
+    Const(bool),
+    Cell(usize),
+    Not(Box<Ground>),
+    And(Vec<Ground>),
+    Or(Vec<Ground>),
+}
+
+impl Ground {
+    fn not(g: Ground) -> Ground {
+        match g {
+            Ground::Const(b) => Ground::Const(!b),
+            Ground::Not(inner) => *inner,
+            other => Ground::Not(Box::new(other)),
+        }
+    }
+
+    fn and(parts: Vec<Ground>) -> Ground {
+        let mut kept = Vec::with_capacity(parts.len());
+        for part in parts {
+            match part {
+                Ground::Const(true) => {}
+                Ground::Const(false) => return Ground::Const(false),
+                Ground::And(inner) => kept.extend(inner),
+                other => kept.push(other),
+            }
+        }
+        match kept.len() {
+            0 => Ground::Const(true),
+            1 => kept.pop().unwrap(),
+            _ => Ground::And(kept),
+        }
+    }
+
+    fn or(parts: Vec<Ground>) -> Ground {
+        let mut kept = Vec::with_capacity(parts.len());
+        for part in parts {
+            match part {
+                Ground::Const(false) => {}
+                Ground::Const(true) => return Ground::Const(true),
+                Ground::Or(inner) => kept.extend(inner),
+                other => kept.push(other),
+            }
+        }
+        match kept.len() {
+            0 => Ground::Const(false),
+            1 => kept.pop().unwrap(),
+            _ => Ground::Or(kept),
+        }
+    }
+
+    /// Three-valued evaluation: `None` while the value still depends on an
+    /// unassigned cell.
+    fn eval(&self, cells: &[Option<bool>]) -> Option<bool> {
+        match self {
+            Ground::Const(b) => Some(*b),
+            Ground::Cell(i) => cells[*i],
+            Ground::Not(g) => g.eval(cells).map(|b| !b),
+            Ground::And(parts) => {
+                let mut value = Some(true);
+                for part in parts {
+                    match part.eval(cells) {
+                        Some(false) => return Some(false),
+                        None => value = None,
+                        Some(true) => {}
+                    }
+                }
+                value
+            }
+            Ground::Or(parts) => {
+                let mut value = Some(false);
+                for part in parts {
+                    match part.eval(cells) {
+                        Some(true) => return Some(true),
+                        None => value = None,
+                        Some(false) => {}
+                    }
+                }
+                value
+            }
+        }
+    }
+
+    fn first_unassigned(&self, cells: &[Option<bool>]) -> Option<usize> {
+        match self {
+            Ground::Const(_) => None,
+            Ground::Cell(i) => cells[*i].is_none().then_some(*i),
+            Ground::Not(g) => g.first_unassigned(cells),
+            Ground::And(parts) | Ground::Or(parts) => parts
+                .iter()
+                .filter(|part| part.eval(cells).is_none())
+                .find_map(|part| part.first_unassigned(cells)),
+        }
+    }
+}
+
+/// Cell layout for one domain size: predicate `p` of arity `k` owns the
+/// `size^k` cells starting at `offsets[p]`; propositions follow.
+struct Cells<'a> {
+    signature: &'a Signature,
+    size: usize,
+    offsets: Vec<usize>,
+    proposition_offset: usize,
+}
+
+impl<'a> Cells<'a> {
+    fn new(signature: &'a Signature, size: usize) -> Self {
+        let mut offsets = Vec::with_capacity(signature.predicates.len());
+        let mut next = 0;
+        for (_, arity) in &signature.predicates {
+            offsets.push(next);
+            next += size.pow(*arity as u32);
+        }
+        Self {
+            signature,
+            size,
+            offsets,
+            proposition_offset: next,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.proposition_offset + self.signature.propositions.len()
+    }
+
+    fn predicate_cell(&self, name: &str, args: &[usize]) -> usize {
+        let p = self
+            .signature
+            .predicates
+            .iter()
+            .position(|(q, _)| q == name)
+            .expect("predicate collected in signature");
+        self.offsets[p] + args.iter().fold(0, |acc, a| acc * self.size + a)
+    }
+
+    fn proposition_cell(&self, name: &str) -> usize {
+        let p = self
+            .signature
+            .propositions
+            .iter()
+            .position(|q| q == name)
+            .expect("proposition collected in signature");
+        self.proposition_offset + p
+    }
+
+    fn ground(
+        &self,
+        expr: &Expression,
+        env: &mut Vec<(String, usize)>,
+        constants: &[usize],
+    ) -> Ground {
+        match expr {
+            Expression::Negated(e) => Ground::not(self.ground(e, env, constants)),
+            Expression::And(a, b) => Ground::and(vec![
+                self.ground(a, env, constants),
+                self.ground(b, env, constants),
+            ]),
+            Expression::Or(a, b) => Ground::or(vec![
+                self.ground(a, env, constants),
+                self.ground(b, env, constants),
+            ]),
+            Expression::Imp(a, b) => Ground::or(vec![
+                Ground::not(self.ground(a, env, constants)),
+                self.ground(b, env, constants),
+            ]),
+            Expression::Iff(a, b) => {
+                let a = self.ground(a, env, constants);
+                let b = self.ground(b, env, constants);
+                Ground::or(vec![
+                    Ground::and(vec![a.clone(), b.clone()]),
+                    Ground::and(vec![Ground::not(a), Ground::not(b)]),
+                ])
+            }
+            Expression::All(v, body) | Expression::Exists(v, body) => {
+                let parts = (0..self.size)
+                    .map(|d| {
+                        env.push((v.name().to_string(), d));
+                        let g = self.ground(body, env, constants);
+                        env.pop();
+                        g
+                    })
+                    .collect();
+                if matches!(expr, Expression::All(_, _)) {
+                    Ground::and(parts)
+                } else {
+                    Ground::or(parts)
+                }
+            }
+            Expression::Equality(a, b) => {
+                Ground::Const(self.term(a, env, constants) == self.term(b, env, constants))
+            }
+            Expression::Application(_, _) => {
+                let (head, args) = uncurry(expr);
+                let name = match head {
+                    Expression::Constant(c) => c.as_str(),
+                    Expression::Variable(v) => v.name(),
+                    _ => unreachable!("rejected while collecting the signature"),
+                };
+                let args = args
+                    .iter()
+                    .map(|a| self.term(a, env, constants))
+                    .collect::<Vec<_>>();
+                Ground::Cell(self.predicate_cell(name, &args))
+            }
+            Expression::Constant(c) => Ground::Cell(self.proposition_cell(c)),
+            Expression::Variable(v) => Ground::Cell(self.proposition_cell(v.name())),
+            _ => unreachable!("rejected while collecting the signature"),
+        }
+    }
+
+    fn term(&self, expr: &Expression, env: &[(String, usize)], constants: &[usize]) -> usize {
+        let name = match expr {
+            Expression::Variable(v) => v.name(),
+            Expression::Constant(c) => c.as_str(),
+            _ => unreachable!("rejected while collecting the signature"),
+        };
+        env.iter()
+            .rev()
+            .find(|(bound, _)| bound == name)
+            .map(|(_, d)| *d)
+            .unwrap_or_else(|| constants[self.signature.constant_index(name)])
+    }
+
+    fn to_model(
+        &self,
+        constants: &[usize],
+        cells: &[Option<bool>],
+    ) -> Result<Model, InferenceError> {
+        let domain = (0..self.size).map(individual).collect::<BTreeSet<_>>();
+        let mut valuation = Valuation::new();
+        for (name, d) in self.signature.constants.iter().zip(constants) {
+            valuation.set(name.clone(), Denotation::Individual(individual(*d)));
+        }
+        for (p, (name, arity)) in self.signature.predicates.iter().enumerate() {
+            let mut relation = BTreeSet::new();
+            for offset in 0..self.size.pow(*arity as u32) {
+                if cells[self.offsets[p] + offset] == Some(true) {
+                    relation.insert(self.tuple(offset, *arity));
+                }
+            }
+            valuation.set(name.clone(), Denotation::Relation(relation));
+        }
+        for name in &self.signature.propositions {
+            let value = cells[self.proposition_cell(name)] == Some(true);
+            valuation.set(name.clone(), Denotation::Boolean(value));
+        }
+        Model::new(domain, valuation).map_err(|e| InferenceError(e.to_string()))
+    }
+
+    fn tuple(&self, mut offset: usize, arity: usize) -> Vec<Individual> {
+        let mut tuple = vec![String::new(); arity];
+        for slot in tuple.iter_mut().rev() {
+            *slot = individual(offset % self.size);
+            offset /= self.size;
+        }
+        tuple
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Search
+// ---------------------------------------------------------------------------
+
+enum Search {
+    Found(Model),
+    Exhausted,
+    OutOfSteps,
+}
+
+fn search_domain(
+    formulas: &[Expression],
+    signature: &Signature,
+    size: usize,
+    max_steps: u64,
+    steps: &mut u64,
+) -> Result<Search, InferenceError> {
+    let layout = Cells::new(signature, size);
+    let mut constants = vec![0; signature.constants.len()];
+
+    loop {
+        let mut ground = Vec::new();
+        for f in formulas {
+            match layout.ground(f, &mut Vec::new(), &constants) {
+                Ground::And(parts) => ground.extend(parts),
+                other => ground.push(other),
+            }
+        }
+
+        let mut cells = vec![None; layout.len()];
+        match solve(&ground, &mut cells, max_steps, steps) {
+            Some(true) => return Ok(Search::Found(layout.to_model(&constants, &cells)?)),
+            Some(false) => {}
+            None => return Ok(Search::OutOfSteps),
+        }
+
+        if !next_constant_assignment(&mut constants, size) {
+            return Ok(Search::Exhausted);
+        }
+    }
+}
+
+/// Backtracking over cells. `None` means the step budget ran out.
+fn solve(
+    ground: &[Ground],
+    cells: &mut [Option<bool>],
+    max_steps: u64,
+    steps: &mut u64,
+) -> Option<bool> {
+    *steps += 1;
+    if *steps > max_steps {
+        return None;
+    }
+
+    let mut branch = None;
+    for g in ground {
+        match g.eval(cells) {
+            Some(false) => return Some(false),
+            Some(true) => {}
+            None => {
+                if branch.is_none() {
+                    branch = g.first_unassigned(cells);
+                }
+            }
+        }
+    }
+    let Some(cell) = branch else {
+        return Some(true);
+    };
+
+    for value in [false, true] {
+        cells[cell] = Some(value);
+        match solve(ground, cells, max_steps, steps) {
+            Some(false) => {}
+            other => return other,
+        }
+    }
+    cells[cell] = None;
+    Some(false)
+}
+
+/// Advance to the next constant assignment under the "least number" rule:
+/// constant `i` ranges over the elements used by constants `0..i` plus one
+/// fresh element.
+fn next_constant_assignment(constants: &mut [usize], size: usize) -> bool {
+    for i in (0..constants.len()).rev() {
+        let limit = constants[..i]
+            .iter()
+            .map(|d| d + 1)
+            .max()
+            .unwrap_or(0)
+            .min(size - 1);
+        if constants[i] < limit {
+            constants[i] += 1;
+            for later in &mut constants[i + 1..] {
+                *later = 0;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+// ---------------------------------------------------------------------------
+// Small helpers
+// ---------------------------------------------------------------------------
+
+/// Domain element names follow NLTK's Mace conversion: `d0`, `d1`, ….
+fn individual(d: usize) -> Individual {
+    format!("d{d}")
+}
+
+fn is_bound(bound: &[String], name: &str) -> bool {
+    bound.iter().any(|b| b == name)
+}
+
+/// Quantify the free individual (and event) variables of `expr`
+/// universally, in name order.
+fn universal_closure(expr: &Expression) -> Expression {
+    expr.free()
+        .into_iter()
+        .filter(|v| Variable::is_individual_name(v.name()) || Variable::is_event_name(v.name()))
+        .rev()
+        .fold(expr.clone(), |body, v| Expression::all(v, body))
+}
+
+/// Left-spine of an application: head and arguments in order.
+fn uncurry(expr: &Expression) -> (&Expression, Vec<&Expression>) {
+    let mut args = Vec::new();
+    let mut cur = expr;
+    while let Expression::Application(f, x) = cur {
+        args.push(x.as_ref());
+        cur = f;
+    }
+    args.reverse();
+    (cur, args)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::nlp::inference::api::{
+        ParallelProverBuilder, ParallelProverBuilderCommand, ProveOrCountermodel,
+    };
+    use crate::ml::nlp::inference::tableau::TableauProver;
+    use crate::ml::nlp::sem::evaluate::{Assignment, EvalValue};
+    use crate::ml::nlp::sem::logic::parse;
+
+    fn parsed(s: &str) -> Expression {
+        parse(s).unwrap()
+    }
+
+    fn build(goal: Option<&str>, assumptions: &[&str]) -> ModelResult<Model> {
+        let goal = goal.map(parsed);
+        let asx: Vec<Expression> = assumptions.iter().map(|s| parsed(s)).collect();
+        MaceModelBuilder::new()
+            .build_model(goal.as_ref(), &asx, false)
+            .unwrap()
+    }
+
+    fn holds(model: &Model, s: &str) -> bool {
+        let g = Assignment::new(model.domain().clone());
+        model.satisfy(&parsed(s), &g).unwrap() == EvalValue::Bool(true)
+    }
+
+    #[test]
+    fn consistent_assumptions_get_a_model() {
+        let assumptions = ["all x.(man(x) -> mortal(x))", "man(socrates)"];
+        let result = build(None, &assumptions);
+        assert!(result.built);
+
+        let model = result.model.unwrap();
+        for a in assumptions {
+            assert!(holds(&model, a), "{a}");
+        }
+        assert_eq!(model.domain().len(), 1);
+    }
+
+    #[test]
+    fn invalid_argument_gets_a_countermodel() {
+        let result = build(
+            Some("mortal(socrates)"),
+            &["all x.(man(x) -> mortal(x))", "man(plato)"],
+        );
+        let model = result.model.expect("countermodel");
+        assert!(holds(&model, "man(plato)"));
+        assert!(!holds(&model, "mortal(socrates)"));
+    }
+
+    #[test]
+    fn valid_argument_has_no_countermodel() {
+        let result = build(
+            Some("mortal(socrates)"),
+            &["all x.(man(x) -> mortal(x))", "man(socrates)"],
+        );
+        assert!(!result.built);
+        assert!(result.model.is_none());
+    }
+
+    #[test]
+    fn domain_grows_until_a_model_fits() {
+        let result = build(
+            None,
+            &["exists x.exists y.exists z.(-(x = y) & -(y = z) & -(x = z))"],
+        );
+        assert_eq!(result.model.unwrap().domain().len(), 3);
+    }
+
+    #[test]
+    fn open_formulas_are_universally_closed() {
+        let result = build(None, &["(dog(x) -> barks(x))", "dog(rex)", "-barks(fido)"]);
+        let model = result.model.unwrap();
+        assert!(holds(&model, "all x.(dog(x) -> barks(x))"));
+        assert!(holds(&model, "-dog(fido)"));
+    }
+
+    #[test]
+    fn propositional_symbols_become_booleans() {
+        let result = build(Some("Q"), &["(P -> Q) | R", "P"]);
+        let model = result.model.unwrap();
+        assert_eq!(
+            model.valuation().get("Q"),
+            Some(&Denotation::Boolean(false))
+        );
+        assert_eq!(model.valuation().get("R"), Some(&Denotation::Boolean(true)));
+    }
+
+    #[test]
+    fn function_terms_are_rejected() {
+        let err = MaceModelBuilder::new()
+            .build_model(None, &[parsed("man(father(john))")], false)
+            .unwrap_err();
+        assert!(err.0.contains("function terms"));
+    }
+
+    #[test]
+    fn parallel_command_proves_or_finds_countermodel() {
+        let prover = TableauProver::new();
+        let builder = MaceModelBuilder::new();
+        let kb = vec![
+            parsed("all x.(man(x) -> mortal(x))"),
+            parsed("man(socrates)"),
+        ];
+
+        let mut valid: ParallelProverBuilderCommand<_, _, Model> =
+            ParallelProverBuilderCommand::new(
+                &prover,
+                &builder,
+                Some(parsed("mortal(socrates)")),
+                kb.clone(),
+            );
+        assert!(valid.prove(false).unwrap());
+        assert!(valid.countermodel().is_none());
+
+        let mut invalid: ParallelProverBuilderCommand<_, _, Model> =
+            ParallelProverBuilderCommand::new(&prover, &builder, Some(parsed("mortal(plato)")), kb);
+        assert!(!invalid.prove(false).unwrap());
+        let model = invalid.countermodel().expect("countermodel");
+        assert!(holds(model, "man(socrates)"));
+        assert!(!holds(model, "mortal(plato)"));
+    }
+
+    #[test]
+    fn consistency_check_reports_model_or_refutation() {
+        let prover = TableauProver::new();
+        let builder = MaceModelBuilder::new();
+        let runner = ParallelProverBuilder::new(&prover, &builder);
+
+        let consistent = [
+            parsed("all x.(bird(x) -> flies(x))"),
+            parsed("bird(tweety)"),
+        ];
+        let outcome: ProveOrCountermodel<Model> = runner
+            .prove_or_countermodel(None, &consistent, false)
+            .unwrap();
+        assert!(outcome.countermodel().is_some());
+
+        let inconsistent = [
+            parsed("all x.(bird(x) -> flies(x))"),
+            parsed("bird(tweety)"),
+            parsed("-flies(tweety)"),
+        ];
+        let outcome: ProveOrCountermodel<Model> = runner
+            .prove_or_countermodel(None, &inconsistent, false)
+            .unwrap();
+        assert!(outcome.is_proved());
+    }
+}
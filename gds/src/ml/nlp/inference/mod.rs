@@ -2,7 +2,8 @@
 //!
 //! Self-contained: no external dependencies on Prover9, Mace4, etc.
 //! NLTK's `prover9.py` and `mace.py` are subprocess wrappers and are
-//! intentionally **not** ported here.
+//! intentionally **not** ported here; [`mace`] is a native replacement
+//! for the latter.
 //!
 //! Currently provides:
 //! - [`api`] — abstract `Prover` and `ModelBuilder` traits (mirrors
@@ -12,9 +13,13 @@
 //! - [`resolution`] — native first-order resolution prover (port of
 //!   `nltk/inference/resolution.py`). Implements the same `Prover`
 //!   trait so it can be swapped under [`api::ProverCommand`].
+//! - [`mace`] — native finite model builder standing in for
+//!   `nltk/inference/mace.py`. Implements `ModelBuilder` and pairs with
+//!   either prover under [`api::ParallelProverBuilder`].
 //!
 //! Planned slices: `nonmonotonic`.
 
 pub mod api;
+pub mod mace;
 pub mod resolution;
 pub mod tableau;
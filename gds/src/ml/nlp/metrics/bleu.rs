@@ -0,0 +1,289 @@
+//! BLEU (Papineni et al., 2002) with the smoothing methods of Chen & Cherry
+//! (2014).
+//!
+//! Sentence BLEU scores one hypothesis against its references; corpus BLEU
+//! pools the clipped n-gram counts and lengths of every segment before
+//! combining them, which is not the mean of sentence scores.
+
+use std::collections::HashMap;
+
+/// How zero n-gram precisions are handled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BleuSmoothing {
+    /// No smoothing: any zero precision makes the score 0.
+    None,
+    /// Method 1: a zero count becomes `epsilon`.
+    Epsilon(f64),
+    /// Method 2: add one to numerator and denominator for n > 1.
+    AddOne,
+    /// Method 3 (NIST geometric): the k-th zero count becomes `1 / 2^k`.
+    Exponential,
+}
+
+/// Clipped n-gram matches against the references and hypothesis n-grams.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NgramPrecision {
+    pub matches: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct BleuScorer {
+    weights: Vec<f64>,
+    smoothing: BleuSmoothing,
+}
+
+impl Default for BleuScorer {
+    fn default() -> Self {
+        Self {
+            weights: vec![0.25; 4],
+            smoothing: BleuSmoothing::None,
+        }
+    }
+}
+
+impl BleuScorer {
+    /// BLEU-4 with uniform weights and no smoothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// One weight per n-gram order, starting at unigrams.
+    pub fn with_weights(mut self, weights: Vec<f64>) -> Self {
+        assert!(!weights.is_empty(), "BLEU needs at least one n-gram weight");
+        self.weights = weights;
+        self
+    }
+
+    pub fn with_smoothing(mut self, smoothing: BleuSmoothing) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    pub fn max_order(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// BLEU of one hypothesis against its references.
+    pub fn sentence_bleu<R: AsRef<str>, H: AsRef<str>>(
+        &self,
+        references: &[Vec<R>],
+        hypothesis: &[H],
+    ) -> f64 {
+        let references = references.iter().map(|r| texts(r)).collect();
+        self.score([(references, texts(hypothesis))])
+    }
+
+    /// Corpus BLEU over aligned `(references, hypothesis)` segments.
+    pub fn corpus_bleu<R: AsRef<str>, H: AsRef<str>>(
+        &self,
+        references: &[Vec<Vec<R>>],
+        hypotheses: &[Vec<H>],
+    ) -> f64 {
+        self.score(
+            references.iter().zip(hypotheses).map(|(refs, hypothesis)| {
+                (refs.iter().map(|r| texts(r)).collect(), texts(hypothesis))
+            }),
+        )
+    }
+
+    fn score<'a>(
+        &self,
+        segments: impl IntoIterator<Item = (Vec<Vec<&'a str>>, Vec<&'a str>)>,
+    ) -> f64 {
+        let mut precisions = vec![NgramPrecision::default(); self.max_order()];
+        let (mut hypothesis_length, mut reference_length) = (0usize, 0usize);
+
+        for (refs, hypothesis) in segments {
+            for (n, precision) in precisions.iter_mut().enumerate() {
+                let p = modified_precision(&refs, &hypothesis, n + 1);
+                precision.matches += p.matches;
+                precision.total += p.total;
+            }
+            hypothesis_length += hypothesis.len();
+            reference_length += closest_reference_length(&refs, hypothesis.len());
+        }
+
+        if hypothesis_length == 0 || precisions[0].matches == 0 {
+            return 0.0;
+        }
+        let Some(log_precisions) = self.smoothed_log_precisions(&precisions) else {
+            return 0.0;
+        };
+        let score = self
+            .weights
+            .iter()
+            .zip(log_precisions)
+            .map(|(w, log_p)| w * log_p)
+            .sum::<f64>()
+            .exp();
+        brevity_penalty(reference_length, hypothesis_length) * score
+    }
+
+    fn smoothed_log_precisions(&self, precisions: &[NgramPrecision]) -> Option<Vec<f64>> {
+        let mut zeros_seen = 0;
+        precisions
+            .iter()
+            .enumerate()
+            .map(|(index, p)| {
+                let total = p.total.max(1) as f64;
+                let value = match self.smoothing {
+                    BleuSmoothing::AddOne if index > 0 => {
+                        (p.matches as f64 + 1.0) / (p.total as f64 + 1.0)
+                    }
+                    _ if p.matches > 0 => p.matches as f64 / total,
+                    BleuSmoothing::None | BleuSmoothing::AddOne => return None,
+                    BleuSmoothing::Epsilon(epsilon) => epsilon / total,
+                    BleuSmoothing::Exponential => {
+                        zeros_seen += 1;
+                        1.0 / (2f64.powi(zeros_seen) * total)
+                    }
+                };
+                Some(value.ln())
+            })
+            .collect()
+    }
+}
+
+/// Clipped n-gram precision of `hypothesis` against `references`.
+pub fn modified_precision<S: AsRef<str>>(
+    references: &[Vec<S>],
+    hypothesis: &[S],
+    n: usize,
+) -> NgramPrecision {
+    let counts = ngram_counts(hypothesis, n);
+    let mut max_reference_counts = HashMap::<Vec<&str>, usize>::new();
+    for reference in references {
+        for (ngram, count) in ngram_counts(reference, n) {
+            let entry = max_reference_counts.entry(ngram).or_default();
+            *entry = (*entry).max(count);
+        }
+    }
+    let matches = counts
+        .iter()
+        .map(|(ngram, count)| (*count).min(max_reference_counts.get(ngram).copied().unwrap_or(0)))
+        .sum();
+    NgramPrecision {
+        matches,
+        total: counts.values().sum(),
+    }
+}
+
+/// `exp(1 - r/c)` for hypotheses shorter than the reference, else 1.
+pub fn brevity_penalty(reference_length: usize, hypothesis_length: usize) -> f64 {
+    if hypothesis_length == 0 {
+        0.0
+    } else if hypothesis_length > reference_length {
+        1.0
+    } else {
+        (1.0 - reference_length as f64 / hypothesis_length as f64).exp()
+    }
+}
+
+/// Reference length closest to the hypothesis length, shorter on ties.
+fn closest_reference_length<S: AsRef<str>>(
+    references: &[Vec<S>],
+    hypothesis_length: usize,
+) -> usize {
+    references
+        .iter()
+        .map(Vec::len)
+        .min_by_key(|&length| (length.abs_diff(hypothesis_length), length))
+        .unwrap_or(0)
+}
+
+pub(super) fn ngram_counts<S: AsRef<str>>(tokens: &[S], n: usize) -> HashMap<Vec<&str>, usize> {
+    let mut counts = HashMap::new();
+    if n == 0 || tokens.len() < n {
+        return counts;
+    }
+    for window in tokens.windows(n) {
+        let ngram = window.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        *counts.entry(ngram).or_default() += 1;
+    }
+    counts
+}
+
+pub(super) fn texts<S: AsRef<str>>(tokens: &[S]) -> Vec<&str> {
+    tokens.iter().map(AsRef::as_ref).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(text: &str) -> Vec<&str> {
+        text.split_whitespace().collect()
+    }
+
+    #[test]
+    fn identical_sentence_scores_one() {
+        let reference = split("the cat is on the mat today");
+        let score = BleuScorer::new().sentence_bleu(std::slice::from_ref(&reference), &reference);
+        assert!((score - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn modified_precision_clips_repeated_words() {
+        let references = [
+            split("the cat is on the mat"),
+            split("there is a cat on the mat"),
+        ];
+        let hypothesis = split("the the the the the the the");
+        let precision = modified_precision(&references, &hypothesis, 1);
+        assert_eq!(
+            precision,
+            NgramPrecision {
+                matches: 2,
+                total: 7
+            }
+        );
+    }
+
+    #[test]
+    fn smoothing_rescues_missing_higher_orders() {
+        let reference = split("the quick brown fox jumps");
+        let hypothesis = split("the fast brown dog jumps");
+        let refs = [reference];
+
+        assert_eq!(BleuScorer::new().sentence_bleu(&refs, &hypothesis), 0.0);
+        let epsilon = BleuScorer::new()
+            .with_smoothing(BleuSmoothing::Epsilon(0.1))
+            .sentence_bleu(&refs, &hypothesis);
+        let add_one = BleuScorer::new()
+            .with_smoothing(BleuSmoothing::AddOne)
+            .sentence_bleu(&refs, &hypothesis);
+        let exponential = BleuScorer::new()
+            .with_smoothing(BleuSmoothing::Exponential)
+            .sentence_bleu(&refs, &hypothesis);
+        assert!(epsilon > 0.0 && add_one > epsilon && exponential > 0.0);
+        assert!(add_one < 1.0);
+    }
+
+    #[test]
+    fn corpus_bleu_pools_counts_and_lengths() {
+        let references = vec![
+            vec![split(
+                "it is a guide to action that ensures the military obeys",
+            )],
+            vec![split(
+                "he read the book because he was interested in world history",
+            )],
+        ];
+        let hypotheses = vec![
+            split("it is a guide to action which ensures that the military always obeys"),
+            split("he was interested in world history because he read the book"),
+        ];
+        let bigram = BleuScorer::new().with_weights(vec![0.5, 0.5]);
+        let corpus = bigram.corpus_bleu(&references, &hypotheses);
+        let mean = hypotheses
+            .iter()
+            .zip(&references)
+            .map(|(h, r)| bigram.sentence_bleu(r, h))
+            .sum::<f64>()
+            / 2.0;
+        assert!(corpus > 0.0 && corpus < 1.0);
+        assert!((corpus - mean).abs() > 1e-6);
+        assert_eq!(brevity_penalty(10, 5), (-1.0f64).exp());
+    }
+}
@@ -0,0 +1,134 @@
+//! chrF (Popović, 2015): character n-gram F-score.
+//!
+//! Tokens are joined with single spaces and whitespace is then ignored, so
+//! the score does not depend on how a sentence was tokenized.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChrfScorer {
+    pub min_order: usize,
+    pub max_order: usize,
+    /// Recall is weighted `beta` times as much as precision.
+    pub beta: f64,
+}
+
+impl Default for ChrfScorer {
+    fn default() -> Self {
+        Self {
+            min_order: 1,
+            max_order: 6,
+            beta: 3.0,
+        }
+    }
+}
+
+impl ChrfScorer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// chrF of a hypothesis against one reference.
+    pub fn sentence_chrf<R: AsRef<str>, H: AsRef<str>>(
+        &self,
+        reference: &[R],
+        hypothesis: &[H],
+    ) -> f64 {
+        let reference = characters(reference);
+        let hypothesis = characters(hypothesis);
+
+        let (mut precision, mut recall, mut orders) = (0.0, 0.0, 0);
+        for n in self.min_order..=self.max_order {
+            let reference_counts = char_ngrams(&reference, n);
+            let hypothesis_counts = char_ngrams(&hypothesis, n);
+            let reference_total = reference_counts.values().sum::<usize>();
+            let hypothesis_total = hypothesis_counts.values().sum::<usize>();
+            if reference_total == 0 || hypothesis_total == 0 {
+                continue;
+            }
+            let matches = hypothesis_counts
+                .iter()
+                .map(|(ngram, count)| {
+                    (*count).min(reference_counts.get(ngram).copied().unwrap_or(0))
+                })
+                .sum::<usize>() as f64;
+            precision += matches / hypothesis_total as f64;
+            recall += matches / reference_total as f64;
+            orders += 1;
+        }
+        if orders == 0 {
+            return 0.0;
+        }
+        let (precision, recall) = (precision / orders as f64, recall / orders as f64);
+        let beta_squared = self.beta * self.beta;
+        let denominator = beta_squared * precision + recall;
+        if denominator == 0.0 {
+            0.0
+        } else {
+            (1.0 + beta_squared) * precision * recall / denominator
+        }
+    }
+
+    /// Mean sentence chrF over aligned segments.
+    pub fn corpus_chrf<R: AsRef<str>, H: AsRef<str>>(
+        &self,
+        references: &[Vec<R>],
+        hypotheses: &[Vec<H>],
+    ) -> f64 {
+        let scores = references
+            .iter()
+            .zip(hypotheses)
+            .map(|(reference, hypothesis)| self.sentence_chrf(reference, hypothesis))
+            .collect::<Vec<_>>();
+        if scores.is_empty() {
+            0.0
+        } else {
+            scores.iter().sum::<f64>() / scores.len() as f64
+        }
+    }
+}
+
+fn characters<S: AsRef<str>>(tokens: &[S]) -> Vec<char> {
+    tokens
+        .iter()
+        .flat_map(|token| token.as_ref().chars())
+        .filter(|c| !c.is_whitespace())
+        .collect()
+}
+
+fn char_ngrams(chars: &[char], n: usize) -> HashMap<&[char], usize> {
+    let mut counts = HashMap::new();
+    if n == 0 {
+        return counts;
+    }
+    for window in chars.windows(n) {
+        *counts.entry(window).or_default() += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chrf_rewards_partial_word_overlap() {
+        let scorer = ChrfScorer::new();
+        let reference = ["the", "cats", "sat"];
+        assert!((scorer.sentence_chrf(&reference, &reference) - 1.0).abs() < 1e-12);
+
+        let close = scorer.sentence_chrf(&reference, &["the", "cat", "sat"]);
+        let far = scorer.sentence_chrf(&reference, &["a", "dog", "ran"]);
+        assert!(close > 0.6 && close < 1.0);
+        assert!(far < 0.1);
+        // Tokenization does not matter, only characters do.
+        assert_eq!(
+            scorer.sentence_chrf(&reference, &["thecats", "sat"]),
+            scorer.sentence_chrf(&reference, &reference)
+        );
+        assert_eq!(
+            scorer.corpus_chrf(&[reference.to_vec()], &[vec!["the", "cat", "sat"]]),
+            close
+        );
+    }
+}
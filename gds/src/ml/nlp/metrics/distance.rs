@@ -0,0 +1,261 @@
+//! Edit distances with alignment output, and word error rate.
+//!
+//! Distances are generic over sequence items: characters for string
+//! distance, token texts for word-level distance.
+
+/// One step of an optimal edit script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOperation {
+    Match,
+    Substitute,
+    Insert,
+    Delete,
+    /// Swap of two adjacent items (Damerau only); indices point at the first
+    /// item of each pair.
+    Transpose,
+}
+
+/// An edit step with the source/target positions it touches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EditStep {
+    pub operation: EditOperation,
+    pub source: Option<usize>,
+    pub target: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditAlignment {
+    pub distance: usize,
+    pub steps: Vec<EditStep>,
+}
+
+impl EditAlignment {
+    /// `(source, target)` pairs of matched or substituted items.
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        self.steps
+            .iter()
+            .filter_map(|step| match (step.operation, step.source, step.target) {
+                (EditOperation::Match | EditOperation::Substitute, Some(s), Some(t)) => {
+                    Some((s, t))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Levenshtein (or, with `transpositions`, optimal-string-alignment
+/// Damerau) distance between two sequences.
+pub fn edit_distance<T: PartialEq>(source: &[T], target: &[T], transpositions: bool) -> usize {
+    let table = distance_table(source, target, transpositions);
+    table[source.len()][target.len()]
+}
+
+/// Edit distance plus one optimal edit script, in source order.
+pub fn edit_alignment<T: PartialEq>(
+    source: &[T],
+    target: &[T],
+    transpositions: bool,
+) -> EditAlignment {
+    let table = distance_table(source, target, transpositions);
+    let (mut i, mut j) = (source.len(), target.len());
+    let mut steps = Vec::with_capacity(i.max(j));
+
+    // Walk back preferring diagonal moves so substitutions are reported as
+    // aligned pairs rather than insert/delete pairs.
+    while i > 0 || j > 0 {
+        let current = table[i][j];
+        if i > 0 && j > 0 {
+            let same = source[i - 1] == target[j - 1];
+            if current == table[i - 1][j - 1] + usize::from(!same) {
+                steps.push(EditStep {
+                    operation: if same {
+                        EditOperation::Match
+                    } else {
+                        EditOperation::Substitute
+                    },
+                    source: Some(i - 1),
+                    target: Some(j - 1),
+                });
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+            if transpositions
+                && i > 1
+                && j > 1
+                && source[i - 1] == target[j - 2]
+                && source[i - 2] == target[j - 1]
+                && current == table[i - 2][j - 2] + 1
+            {
+                steps.push(EditStep {
+                    operation: EditOperation::Transpose,
+                    source: Some(i - 2),
+                    target: Some(j - 2),
+                });
+                i -= 2;
+                j -= 2;
+                continue;
+            }
+        }
+        if i > 0 && current == table[i - 1][j] + 1 {
+            steps.push(EditStep {
+                operation: EditOperation::Delete,
+                source: Some(i - 1),
+                target: None,
+            });
+            i -= 1;
+        } else {
+            steps.push(EditStep {
+                operation: EditOperation::Insert,
+                source: None,
+                target: Some(j - 1),
+            });
+            j -= 1;
+        }
+    }
+    steps.reverse();
+
+    EditAlignment {
+        distance: table[source.len()][target.len()],
+        steps,
+    }
+}
+
+/// Character-level Levenshtein distance.
+pub fn levenshtein(source: &str, target: &str) -> usize {
+    let source = source.chars().collect::<Vec<_>>();
+    let target = target.chars().collect::<Vec<_>>();
+    edit_distance(&source, &target, false)
+}
+
+/// Character-level Damerau-Levenshtein (optimal string alignment) distance.
+pub fn damerau_levenshtein(source: &str, target: &str) -> usize {
+    let source = source.chars().collect::<Vec<_>>();
+    let target = target.chars().collect::<Vec<_>>();
+    edit_distance(&source, &target, true)
+}
+
+/// Word error rate: word-level edit distance over the reference length.
+///
+/// An empty reference scores 0.0 against an empty hypothesis and 1.0
+/// otherwise.
+pub fn word_error_rate<R: AsRef<str>, H: AsRef<str>>(reference: &[R], hypothesis: &[H]) -> f64 {
+    let (edits, words) = word_edits(reference, hypothesis);
+    match words {
+        0 if edits == 0 => 0.0,
+        0 => 1.0,
+        _ => edits as f64 / words as f64,
+    }
+}
+
+/// Corpus word error rate: total edits over total reference words.
+pub fn corpus_word_error_rate<R: AsRef<str>, H: AsRef<str>>(
+    references: &[Vec<R>],
+    hypotheses: &[Vec<H>],
+) -> f64 {
+    let (edits, words) = references
+        .iter()
+        .zip(hypotheses)
+        .map(|(reference, hypothesis)| word_edits(reference, hypothesis))
+        .fold((0, 0), |(e, w), (edits, words)| (e + edits, w + words));
+    if words == 0 {
+        0.0
+    } else {
+        edits as f64 / words as f64
+    }
+}
+
+fn word_edits<R: AsRef<str>, H: AsRef<str>>(reference: &[R], hypothesis: &[H]) -> (usize, usize) {
+    let reference = reference.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+    let hypothesis = hypothesis.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+    (
+        edit_distance(&reference, &hypothesis, false),
+        reference.len(),
+    )
+}
+
+fn distance_table<T: PartialEq>(
+    source: &[T],
+    target: &[T],
+    transpositions: bool,
+) -> Vec<Vec<usize>> {
+    let mut table = vec![vec![0usize; target.len() + 1]; source.len() + 1];
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=source.len() {
+        for j in 1..=target.len() {
+            let cost = usize::from(source[i - 1] != target[j - 1]);
+            let mut best = (table[i - 1][j] + 1)
+                .min(table[i][j - 1] + 1)
+                .min(table[i - 1][j - 1] + cost);
+            if transpositions
+                && i > 1
+                && j > 1
+                && source[i - 1] == target[j - 2]
+                && source[i - 2] == target[j - 1]
+            {
+                best = best.min(table[i - 2][j - 2] + 1);
+            }
+            table[i][j] = best;
+        }
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levenshtein_and_damerau_distances() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("ab", "ba"), 2);
+        assert_eq!(damerau_levenshtein("ab", "ba"), 1);
+        assert_eq!(damerau_levenshtein("ca", "abc"), 3);
+    }
+
+    #[test]
+    fn alignment_reports_operations() {
+        let source = "kitten".chars().collect::<Vec<_>>();
+        let target = "sitting".chars().collect::<Vec<_>>();
+        let alignment = edit_alignment(&source, &target, false);
+        assert_eq!(alignment.distance, 3);
+
+        let operations = alignment
+            .steps
+            .iter()
+            .map(|step| step.operation)
+            .filter(|op| *op != EditOperation::Match)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            operations,
+            [
+                EditOperation::Substitute,
+                EditOperation::Substitute,
+                EditOperation::Insert
+            ]
+        );
+        assert_eq!(alignment.pairs()[0], (0, 0));
+
+        let swapped = edit_alignment(&['a', 'b'], &['b', 'a'], true);
+        assert_eq!(swapped.steps[0].operation, EditOperation::Transpose);
+    }
+
+    #[test]
+    fn word_error_rate_counts_word_edits() {
+        let reference = ["the", "cat", "sat", "down"];
+        let hypothesis = ["the", "cat", "sit", "down", "now"];
+        assert_eq!(word_error_rate(&reference, &hypothesis), 0.5);
+        assert_eq!(word_error_rate::<&str, &str>(&[], &[]), 0.0);
+        assert_eq!(
+            corpus_word_error_rate(&[reference.to_vec()], &[hypothesis.to_vec()]),
+            0.5
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! METEOR-lite (Banerjee & Lavie, 2005): unigram alignment with exact and
+//! optional stem matching, a recall-weighted F-mean and a fragmentation
+//! penalty. Matching is case-insensitive; synonym matching and paraphrase
+//! tables are not implemented.
+
+use crate::collections::dataset::language::{Stemmer, Token, TokenSpan};
+
+pub struct MeteorScorer {
+    /// Weight of precision against recall in the F-mean.
+    pub alpha: f64,
+    /// Exponent of the fragmentation penalty.
+    pub beta: f64,
+    /// Maximum fragmentation penalty.
+    pub gamma: f64,
+    stemmer: Option<Box<dyn Stemmer>>,
+}
+
+impl Default for MeteorScorer {
+    fn default() -> Self {
+        Self {
+            alpha: 0.9,
+            beta: 3.0,
+            gamma: 0.5,
+            stemmer: None,
+        }
+    }
+}
+
+impl MeteorScorer {
+    /// Exact matching only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a second matching stage over stems of still-unaligned words.
+    pub fn with_stemmer(mut self, stemmer: impl Stemmer + 'static) -> Self {
+        self.stemmer = Some(Box::new(stemmer));
+        self
+    }
+
+    /// Best METEOR score of the hypothesis over its references.
+    pub fn sentence_meteor<R: AsRef<str>, H: AsRef<str>>(
+        &self,
+        references: &[Vec<R>],
+        hypothesis: &[H],
+    ) -> f64 {
+        references
+            .iter()
+            .map(|reference| self.single_meteor(reference, hypothesis))
+            .fold(0.0, f64::max)
+    }
+
+    /// Mean sentence METEOR over aligned segments.
+    pub fn corpus_meteor<R: AsRef<str>, H: AsRef<str>>(
+        &self,
+        references: &[Vec<Vec<R>>],
+        hypotheses: &[Vec<H>],
+    ) -> f64 {
+        let scores = references
+            .iter()
+            .zip(hypotheses)
+            .map(|(refs, hypothesis)| self.sentence_meteor(refs, hypothesis))
+            .collect::<Vec<_>>();
+        if scores.is_empty() {
+            0.0
+        } else {
+            scores.iter().sum::<f64>() / scores.len() as f64
+        }
+    }
+
+    fn single_meteor<R: AsRef<str>, H: AsRef<str>>(
+        &self,
+        reference: &[R],
+        hypothesis: &[H],
+    ) -> f64 {
+        if reference.is_empty() || hypothesis.is_empty() {
+            return 0.0;
+        }
+        let alignment = self.align(reference, hypothesis);
+        let matches = alignment.len() as f64;
+        if matches == 0.0 {
+            return 0.0;
+        }
+
+        let precision = matches / hypothesis.len() as f64;
+        let recall = matches / reference.len() as f64;
+        let f_mean = precision * recall / (self.alpha * precision + (1.0 - self.alpha) * recall);
+        let penalty = self.gamma * (count_chunks(&alignment) as f64 / matches).powf(self.beta);
+        f_mean * (1.0 - penalty)
+    }
+
+    /// `(hypothesis, reference)` index pairs, sorted by hypothesis index.
+    fn align<R: AsRef<str>, H: AsRef<str>>(
+        &self,
+        reference: &[R],
+        hypothesis: &[H],
+    ) -> Vec<(usize, usize)> {
+        let mut reference_used = vec![false; reference.len()];
+        let mut hypothesis_used = vec![false; hypothesis.len()];
+        let mut alignment = Vec::new();
+
+        let exact = |word: &str| word.to_lowercase();
+        let reference_words = reference
+            .iter()
+            .map(|w| exact(w.as_ref()))
+            .collect::<Vec<_>>();
+        let hypothesis_words = hypothesis
+            .iter()
+            .map(|w| exact(w.as_ref()))
+            .collect::<Vec<_>>();
+        match_stage(
+            &reference_words,
+            &hypothesis_words,
+            &mut reference_used,
+            &mut hypothesis_used,
+            &mut alignment,
+        );
+
+        if let Some(stemmer) = &self.stemmer {
+            let stem = |word: &str| {
+                let token = Token::word(word, TokenSpan::new(0, word.len()));
+                stemmer.stem_token(&token).text().to_string()
+            };
+            let reference_stems = reference_words.iter().map(|w| stem(w)).collect::<Vec<_>>();
+            let hypothesis_stems = hypothesis_words.iter().map(|w| stem(w)).collect::<Vec<_>>();
+            match_stage(
+                &reference_stems,
+                &hypothesis_stems,
+                &mut reference_used,
+                &mut hypothesis_used,
+                &mut alignment,
+            );
+        }
+
+        alignment.sort_unstable();
+        alignment
+    }
+}
+
+/// Greedily aligns unaligned hypothesis words to unaligned reference words
+/// with the same key, preferring the reference position that continues the
+/// previous alignment so fewer chunks are produced.
+fn match_stage(
+    reference: &[String],
+    hypothesis: &[String],
+    reference_used: &mut [bool],
+    hypothesis_used: &mut [bool],
+    alignment: &mut Vec<(usize, usize)>,
+) {
+    let mut previous: Option<usize> = None;
+    for (h, word) in hypothesis.iter().enumerate() {
+        if hypothesis_used[h] {
+            previous = alignment.iter().find(|(ah, _)| *ah == h).map(|(_, r)| *r);
+            continue;
+        }
+        let candidates = (0..reference.len())
+            .filter(|&r| !reference_used[r] && reference[r] == *word)
+            .collect::<Vec<_>>();
+        let chosen = previous
+            .map(|p| p + 1)
+            .filter(|next| candidates.contains(next))
+            .or_else(|| candidates.first().copied());
+        if let Some(r) = chosen {
+            reference_used[r] = true;
+            hypothesis_used[h] = true;
+            alignment.push((h, r));
+        }
+        previous = chosen;
+    }
+}
+
+/// Runs of alignments that are adjacent in both hypothesis and reference.
+fn count_chunks(alignment: &[(usize, usize)]) -> usize {
+    if alignment.is_empty() {
+        return 0;
+    }
+    1 + alignment
+        .windows(2)
+        .filter(|pair| !(pair[1].0 == pair[0].0 + 1 && pair[1].1 == pair[0].1 + 1))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::dataset::language::SimpleSuffixStemmer;
+
+    fn split(text: &str) -> Vec<&str> {
+        text.split_whitespace().collect()
+    }
+
+    #[test]
+    fn meteor_penalizes_fragmentation() {
+        let scorer = MeteorScorer::new();
+        let reference = split("the cat sat on the mat");
+
+        let identical = scorer.sentence_meteor(std::slice::from_ref(&reference), &reference);
+        // A single chunk still pays 0.5 * (1/6)^3.
+        assert!((identical - (1.0 - 0.5 / 216.0)).abs() < 1e-12);
+
+        let shuffled = scorer.sentence_meteor(
+            std::slice::from_ref(&reference),
+            &split("on the mat the cat sat"),
+        );
+        assert!(shuffled < identical);
+        assert_eq!(
+            scorer.sentence_meteor(&[reference], &split("dogs run")),
+            0.0
+        );
+    }
+
+    #[test]
+    fn stemmer_stage_matches_inflections() {
+        let reference = split("the cats were sitting");
+        let hypothesis = split("the cat was sitting");
+        let exact =
+            MeteorScorer::new().sentence_meteor(std::slice::from_ref(&reference), &hypothesis);
+        let stemmed = MeteorScorer::new()
+            .with_stemmer(SimpleSuffixStemmer::default())
+            .sentence_meteor(std::slice::from_ref(&reference), &hypothesis);
+        assert!(stemmed > exact);
+
+        let best = MeteorScorer::new().sentence_meteor(&[split("a dog"), reference], &hypothesis);
+        assert_eq!(best, exact);
+    }
+}
@@ -0,0 +1,11 @@
+pub mod bleu;
+pub mod chrf;
+pub mod distance;
+pub mod meteor;
+pub mod rouge;
+
+pub use bleu::*;
+pub use chrf::*;
+pub use distance::*;
+pub use meteor::*;
+pub use rouge::*;
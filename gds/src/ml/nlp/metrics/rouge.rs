@@ -0,0 +1,136 @@
+//! ROUGE (Lin, 2004): recall-oriented n-gram and longest-common-subsequence
+//! overlap, as used for summary evaluation.
+//!
+//! With several references the best-scoring reference (by F-measure) is
+//! reported.
+
+use super::bleu::ngram_counts;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RougeScore {
+    pub precision: f64,
+    pub recall: f64,
+    pub f_measure: f64,
+}
+
+impl RougeScore {
+    fn from_counts(overlap: usize, hypothesis_total: usize, reference_total: usize) -> Self {
+        let precision = ratio(overlap, hypothesis_total);
+        let recall = ratio(overlap, reference_total);
+        let f_measure = if precision + recall == 0.0 {
+            0.0
+        } else {
+            2.0 * precision * recall / (precision + recall)
+        };
+        Self {
+            precision,
+            recall,
+            f_measure,
+        }
+    }
+}
+
+/// ROUGE-N: clipped n-gram overlap.
+pub fn rouge_n<R: AsRef<str>, H: AsRef<str>>(
+    references: &[Vec<R>],
+    hypothesis: &[H],
+    n: usize,
+) -> RougeScore {
+    let hypothesis_counts = ngram_counts(hypothesis, n);
+    let hypothesis_total = hypothesis_counts.values().sum();
+    best(references.iter().map(|reference| {
+        let reference_counts = ngram_counts(reference, n);
+        let overlap = reference_counts
+            .iter()
+            .map(|(ngram, count)| (*count).min(hypothesis_counts.get(ngram).copied().unwrap_or(0)))
+            .sum();
+        RougeScore::from_counts(overlap, hypothesis_total, reference_counts.values().sum())
+    }))
+}
+
+/// ROUGE-L: longest common subsequence of the token sequences.
+pub fn rouge_l<R: AsRef<str>, H: AsRef<str>>(
+    references: &[Vec<R>],
+    hypothesis: &[H],
+) -> RougeScore {
+    best(references.iter().map(|reference| {
+        let lcs = longest_common_subsequence(reference, hypothesis);
+        RougeScore::from_counts(lcs, hypothesis.len(), reference.len())
+    }))
+}
+
+/// Length of the longest common subsequence of two token sequences.
+pub fn longest_common_subsequence<A: AsRef<str>, B: AsRef<str>>(a: &[A], b: &[B]) -> usize {
+    let mut previous = vec![0usize; b.len() + 1];
+    let mut current = vec![0usize; b.len() + 1];
+    for x in a {
+        for (j, y) in b.iter().enumerate() {
+            current[j + 1] = if x.as_ref() == y.as_ref() {
+                previous[j] + 1
+            } else {
+                previous[j + 1].max(current[j])
+            };
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+fn best(scores: impl Iterator<Item = RougeScore>) -> RougeScore {
+    scores.fold(RougeScore::default(), |best, score| {
+        if score.f_measure > best.f_measure {
+            score
+        } else {
+            best
+        }
+    })
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(text: &str) -> Vec<&str> {
+        text.split_whitespace().collect()
+    }
+
+    #[test]
+    fn rouge_n_clips_and_picks_best_reference() {
+        let reference = split("the cat was found under the bed");
+        let hypothesis = split("the cat was under the bed");
+
+        let unigram = rouge_n(std::slice::from_ref(&reference), &hypothesis, 1);
+        assert_eq!(unigram.precision, 1.0);
+        assert!((unigram.recall - 6.0 / 7.0).abs() < 1e-12);
+
+        let bigram = rouge_n(std::slice::from_ref(&reference), &hypothesis, 2);
+        assert!((bigram.recall - 4.0 / 6.0).abs() < 1e-12);
+
+        let best = rouge_n(&[split("a dog barked"), reference], &hypothesis, 1);
+        assert_eq!(best, unigram);
+        assert_eq!(
+            rouge_n(&[split("x")], &split("the the"), 1),
+            RougeScore::default()
+        );
+    }
+
+    #[test]
+    fn rouge_l_uses_longest_common_subsequence() {
+        let reference = split("police killed the gunman");
+        assert_eq!(
+            longest_common_subsequence(&reference, &split("police kill the gunman")),
+            3
+        );
+        let score = rouge_l(&[reference], &split("the gunman kill police"));
+        assert_eq!(score.recall, 0.5);
+        assert_eq!(score.precision, 0.5);
+    }
+}
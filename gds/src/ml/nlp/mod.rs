@@ -1,7 +1,9 @@
 pub mod chunk;
 pub mod classify;
 pub mod cluster;
+pub mod embed;
 pub mod inference;
+pub mod metrics;
 pub mod parse;
 pub mod sem;
 pub mod stem;
@@ -17,6 +19,7 @@ pub use classify::rte::*;
 pub use classify::util::*;
 
 pub use chunk::api::*;
+pub use chunk::named_entity::*;
 pub use chunk::regexp::*;
 pub use chunk::types::*;
 pub use chunk::util as chunk_util;
@@ -27,12 +30,20 @@ pub use cluster::gaac::*;
 pub use cluster::kmeans::*;
 pub use cluster::util as cluster_util;
 
+pub use embed::word2vec::*;
+
+pub use metrics::*;
+
 pub use parse::cfg::*;
+pub use parse::cky::*;
 pub use parse::dependency::*;
+pub use parse::earley::*;
 pub use parse::evaluate::*;
+pub use parse::induce::*;
 pub use parse::recursivedescent::*;
 pub use parse::shiftreduce::*;
 pub use parse::util::*;
+pub use parse::viterbi::*;
 
 pub use stem::lancaster::*;
 pub use stem::porter::*;
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::error::Error;
 use std::fmt;
 
@@ -61,6 +62,9 @@ pub enum ParseError {
     UncoveredToken(String),
     InvalidHeadIndex { index: usize, head: isize },
     InvalidDependencyRow(String),
+    MissingProbability { lhs: String },
+    InvalidProbability { lhs: String, prob: String },
+    ProbabilitySumMismatch { lhs: String, total: String },
 }
 
 impl fmt::Display for ParseError {
@@ -80,12 +84,26 @@ impl fmt::Display for ParseError {
             ParseError::InvalidDependencyRow(row) => {
                 write!(f, "invalid dependency row: '{row}'")
             }
+            ParseError::MissingProbability { lhs } => {
+                write!(f, "production for lhs '{lhs}' has no probability")
+            }
+            ParseError::InvalidProbability { lhs, prob } => {
+                write!(f, "probability {prob} for lhs '{lhs}' is outside (0, 1]")
+            }
+            ParseError::ProbabilitySumMismatch { lhs, total } => {
+                write!(
+                    f,
+                    "probabilities for lhs '{lhs}' sum to {total}, expected 1"
+                )
+            }
         }
     }
 }
 
 impl Error for ParseError {}
 
+const PROBABILITY_TOLERANCE: f64 = 1e-6;
+
 #[derive(Debug, Clone)]
 pub struct Cfg {
     start: String,
@@ -111,6 +129,17 @@ impl Cfg {
         Ok(Self { start, productions })
     }
 
+    /// Build a PCFG: every production needs a probability in `(0, 1]` and the
+    /// probabilities of each lhs must sum to one.
+    pub fn new_probabilistic(
+        start: impl Into<String>,
+        productions: Vec<Production>,
+    ) -> Result<Self, ParseError> {
+        let grammar = Self::new(start, productions)?;
+        grammar.check_probabilities()?;
+        Ok(grammar)
+    }
+
     pub fn start(&self) -> &str {
         &self.start
     }
@@ -119,6 +148,58 @@ impl Cfg {
         &self.productions
     }
 
+    pub fn is_probabilistic(&self) -> bool {
+        self.productions
+            .iter()
+            .all(|production| production.prob().is_some())
+    }
+
+    /// Left-hand sides in order of first appearance.
+    pub fn nonterminals(&self) -> Vec<&str> {
+        let mut seen = HashSet::new();
+        self.productions
+            .iter()
+            .map(Production::lhs)
+            .filter(|lhs| seen.insert(*lhs))
+            .collect()
+    }
+
+    pub fn productions_for<'a>(&'a self, lhs: &'a str) -> impl Iterator<Item = &'a Production> {
+        self.productions
+            .iter()
+            .filter(move |production| production.lhs == lhs)
+    }
+
+    pub fn check_probabilities(&self) -> Result<(), ParseError> {
+        let mut totals: Vec<(&str, f64)> = Vec::new();
+        for production in &self.productions {
+            let prob = production
+                .prob()
+                .ok_or_else(|| ParseError::MissingProbability {
+                    lhs: production.lhs.clone(),
+                })?;
+            if !(prob > 0.0 && prob <= 1.0) {
+                return Err(ParseError::InvalidProbability {
+                    lhs: production.lhs.clone(),
+                    prob: prob.to_string(),
+                });
+            }
+            match totals.iter_mut().find(|(lhs, _)| *lhs == production.lhs) {
+                Some((_, total)) => *total += prob,
+                None => totals.push((production.lhs.as_str(), prob)),
+            }
+        }
+        for (lhs, total) in totals {
+            if (total - 1.0).abs() > PROBABILITY_TOLERANCE {
+                return Err(ParseError::ProbabilitySumMismatch {
+                    lhs: lhs.to_string(),
+                    total: total.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
     pub fn check_coverage_tokens(&self, tokens: &[Token]) -> Result<(), ParseError> {
         self.check_coverage_texts(tokens.iter().map(Token::text))
     }
@@ -169,4 +250,31 @@ mod tests {
             Err(ParseError::UncoveredToken(token)) if token == "cat"
         ));
     }
+
+    #[test]
+    fn probabilistic_cfg_checks_lhs_sums() {
+        let productions = vec![
+            Production::new("S", vec![GrammarSymbol::nonterminal("NP")]).with_prob(1.0),
+            Production::new("NP", vec![GrammarSymbol::terminal("dog")]).with_prob(0.6),
+            Production::new("NP", vec![GrammarSymbol::terminal("cat")]).with_prob(0.4),
+        ];
+        let grammar = Cfg::new_probabilistic("S", productions.clone()).expect("valid pcfg");
+        assert!(grammar.is_probabilistic());
+        assert_eq!(grammar.nonterminals(), vec!["S", "NP"]);
+        assert_eq!(grammar.productions_for("NP").count(), 2);
+
+        let mut skewed = productions.clone();
+        skewed[2] = Production::new("NP", vec![GrammarSymbol::terminal("cat")]).with_prob(0.5);
+        assert!(matches!(
+            Cfg::new_probabilistic("S", skewed),
+            Err(ParseError::ProbabilitySumMismatch { lhs, .. }) if lhs == "NP"
+        ));
+
+        let mut missing = productions;
+        missing[0] = Production::new("S", vec![GrammarSymbol::nonterminal("NP")]);
+        assert!(matches!(
+            Cfg::new_probabilistic("S", missing),
+            Err(ParseError::MissingProbability { .. })
+        ));
+    }
 }
@@ -0,0 +1,533 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::collections::dataset::language::token::Token;
+use crate::collections::dataset::language::tree::TreeValue;
+
+use super::cfg::{Cfg, GrammarSymbol, ParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CnfRhs {
+    Binary(String, String),
+    Lexical(String),
+}
+
+/// A production of a [`CnfGrammar`].
+///
+/// `chain` lists the nonterminals of the unit productions that were collapsed
+/// into this one, outermost first, so `A -> B -> C -> 'x'` becomes
+/// `A -> 'x'` with chain `[B, C]`. `prob` is the product of the probabilities
+/// along the chain, or `1.0` for grammars without probabilities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CnfProduction {
+    lhs: String,
+    rhs: CnfRhs,
+    chain: Vec<String>,
+    prob: f64,
+}
+
+impl CnfProduction {
+    pub fn lhs(&self) -> &str {
+        &self.lhs
+    }
+
+    pub fn rhs(&self) -> &CnfRhs {
+        &self.rhs
+    }
+
+    pub fn chain(&self) -> &[String] {
+        &self.chain
+    }
+
+    pub fn prob(&self) -> f64 {
+        self.prob
+    }
+}
+
+/// A [`Cfg`] converted to Chomsky normal form.
+///
+/// Terminals inside longer right-hand sides are lifted into helper
+/// nonterminals, long right-hand sides are binarized NLTK-style (`A|<C-D>`),
+/// and unit productions are collapsed into [`CnfProduction::chain`]. Helper
+/// nonterminals are tracked so trees can be read back in terms of the original
+/// grammar.
+#[derive(Debug, Clone)]
+pub struct CnfGrammar {
+    start: String,
+    productions: Vec<CnfProduction>,
+    helpers: HashSet<String>,
+}
+
+impl CnfGrammar {
+    pub fn from_cfg(grammar: &Cfg) -> Self {
+        let mut names = grammar
+            .nonterminals()
+            .into_iter()
+            .map(ToString::to_string)
+            .collect::<HashSet<_>>();
+        let mut helpers = HashSet::new();
+        let mut helper_names: HashMap<String, String> = HashMap::new();
+        let mut binarized: Vec<(String, Vec<GrammarSymbol>, f64)> = Vec::new();
+        let mut seen_helper_rules = HashSet::new();
+
+        for production in grammar.productions() {
+            let prob = production.prob().unwrap_or(1.0);
+            let mut rhs = production.rhs().to_vec();
+
+            if rhs.len() > 1 {
+                for symbol in rhs.iter_mut() {
+                    if let GrammarSymbol::Terminal(terminal) = symbol {
+                        let helper = helper_name(
+                            &mut names,
+                            &mut helper_names,
+                            &mut helpers,
+                            format!("'{terminal}'"),
+                        );
+                        if seen_helper_rules.insert(helper.clone()) {
+                            binarized.push((
+                                helper.clone(),
+                                vec![GrammarSymbol::Terminal(terminal.clone())],
+                                1.0,
+                            ));
+                        }
+                        *symbol = GrammarSymbol::Nonterminal(helper);
+                    }
+                }
+            }
+
+            let mut lhs = production.lhs().to_string();
+            let mut rule_prob = prob;
+            while rhs.len() > 2 {
+                let rest = rhs.split_off(1);
+                let rest_label = rest.iter().map(symbol_name).collect::<Vec<_>>().join("-");
+                let helper = helper_name(
+                    &mut names,
+                    &mut helper_names,
+                    &mut helpers,
+                    format!("{}|<{rest_label}>", production.lhs()),
+                );
+                rhs.push(GrammarSymbol::Nonterminal(helper.clone()));
+                binarized.push((lhs, rhs, rule_prob));
+                lhs = helper;
+                rhs = rest;
+                rule_prob = 1.0;
+                if !seen_helper_rules.insert(lhs.clone()) {
+                    // An identical helper already expands the remainder.
+                    rhs.clear();
+                    break;
+                }
+            }
+            if !rhs.is_empty() {
+                binarized.push((lhs, rhs, rule_prob));
+            }
+        }
+
+        let productions = collapse_unit_productions(&binarized);
+        Self {
+            start: grammar.start().to_string(),
+            productions,
+            helpers,
+        }
+    }
+
+    pub fn start(&self) -> &str {
+        &self.start
+    }
+
+    pub fn productions(&self) -> &[CnfProduction] {
+        &self.productions
+    }
+
+    pub fn is_helper(&self, symbol: &str) -> bool {
+        self.helpers.contains(symbol)
+    }
+
+    /// Wraps `children` in the unit chain of `production` and returns the
+    /// child sequence of a `production.lhs()` node.
+    pub(crate) fn apply_chain(
+        &self,
+        production: &CnfProduction,
+        children: Vec<TreeValue>,
+    ) -> Vec<TreeValue> {
+        production
+            .chain
+            .iter()
+            .rev()
+            .fold(children, |inner, label| {
+                vec![TreeValue::node(label.clone(), inner)]
+            })
+    }
+
+    /// Child sequence contributed by `symbol` when it appears on a right-hand
+    /// side: helpers are spliced into their parent, other symbols become nodes.
+    pub(crate) fn wrap(&self, symbol: &str, children: Vec<TreeValue>) -> Vec<TreeValue> {
+        if self.is_helper(symbol) {
+            children
+        } else {
+            vec![TreeValue::node(symbol, children)]
+        }
+    }
+}
+
+fn symbol_name(symbol: &GrammarSymbol) -> String {
+    match symbol {
+        GrammarSymbol::Nonterminal(symbol) | GrammarSymbol::Terminal(symbol) => symbol.clone(),
+    }
+}
+
+fn helper_name(
+    names: &mut HashSet<String>,
+    helper_names: &mut HashMap<String, String>,
+    helpers: &mut HashSet<String>,
+    base: String,
+) -> String {
+    if let Some(name) = helper_names.get(&base) {
+        return name.clone();
+    }
+    let mut name = base.clone();
+    while names.contains(&name) {
+        name.push('\'');
+    }
+    names.insert(name.clone());
+    helpers.insert(name.clone());
+    helper_names.insert(base, name.clone());
+    name
+}
+
+/// Replaces unit productions `A -> B` by copies of the non-unit productions
+/// reachable from `B`, following each simple unit path once.
+fn collapse_unit_productions(rules: &[(String, Vec<GrammarSymbol>, f64)]) -> Vec<CnfProduction> {
+    let mut units: HashMap<&str, Vec<(&str, f64)>> = HashMap::new();
+    let mut lhs_order = Vec::new();
+    for (lhs, rhs, prob) in rules {
+        if !lhs_order.contains(&lhs.as_str()) {
+            lhs_order.push(lhs.as_str());
+        }
+        if let [GrammarSymbol::Nonterminal(target)] = rhs.as_slice() {
+            units
+                .entry(lhs.as_str())
+                .or_default()
+                .push((target.as_str(), *prob));
+        }
+    }
+
+    let mut productions = Vec::new();
+    for lhs in lhs_order {
+        let mut stack = vec![(lhs, Vec::<String>::new(), 1.0)];
+        while let Some((current, chain, chain_prob)) = stack.pop() {
+            for (rule_lhs, rhs, prob) in rules {
+                if rule_lhs != current {
+                    continue;
+                }
+                let rhs = match rhs.as_slice() {
+                    [GrammarSymbol::Terminal(terminal)] => CnfRhs::Lexical(terminal.clone()),
+                    [GrammarSymbol::Nonterminal(left), GrammarSymbol::Nonterminal(right)] => {
+                        CnfRhs::Binary(left.clone(), right.clone())
+                    }
+                    _ => continue,
+                };
+                productions.push(CnfProduction {
+                    lhs: lhs.to_string(),
+                    rhs,
+                    chain: chain.clone(),
+                    prob: chain_prob * prob,
+                });
+            }
+            for (target, prob) in units.get(current).into_iter().flatten().rev() {
+                if *target == lhs || chain.iter().any(|label| label == *target) {
+                    continue;
+                }
+                let mut next_chain = chain.clone();
+                next_chain.push(target.to_string());
+                stack.push((*target, next_chain, chain_prob * prob));
+            }
+        }
+    }
+    productions
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Backpointer {
+    Lexical,
+    Binary(usize),
+}
+
+/// Per span, the CNF productions covering it and the splits they were found at.
+type CkyChart = HashMap<(usize, usize), HashMap<usize, Vec<Backpointer>>>;
+
+/// CKY parser over the Chomsky normal form of a [`Cfg`].
+///
+/// The grammar is converted once with [`CnfGrammar::from_cfg`]; parses are
+/// returned in terms of the original grammar, with binarization helpers spliced
+/// out and collapsed unit chains restored.
+#[derive(Debug, Clone)]
+pub struct CkyParser {
+    grammar: Cfg,
+    cnf: CnfGrammar,
+}
+
+impl CkyParser {
+    pub fn new(grammar: Cfg) -> Self {
+        let cnf = CnfGrammar::from_cfg(&grammar);
+        Self { grammar, cnf }
+    }
+
+    pub fn grammar(&self) -> &Cfg {
+        &self.grammar
+    }
+
+    pub fn cnf(&self) -> &CnfGrammar {
+        &self.cnf
+    }
+
+    pub fn parse_tokens(&self, tokens: &[Token]) -> Result<Vec<TreeValue>, ParseError> {
+        self.parse_token_texts(tokens.iter().map(|token| token.text().to_string()))
+    }
+
+    pub fn parse_token_texts<I, S>(&self, tokens: I) -> Result<Vec<TreeValue>, ParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let tokens = tokens.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.grammar
+            .check_coverage_texts(tokens.iter().map(String::as_str))?;
+        if tokens.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let chart = self.fill_chart(&tokens);
+        let mut memo = HashMap::new();
+        let mut parses = Vec::new();
+        for (index, production) in self.cnf.productions().iter().enumerate() {
+            if production.lhs() != self.cnf.start() {
+                continue;
+            }
+            for children in self.expansions(&chart, &tokens, index, 0, tokens.len(), &mut memo) {
+                parses.push(TreeValue::node(production.lhs(), children));
+            }
+        }
+        Ok(parses)
+    }
+
+    fn fill_chart(&self, tokens: &[String]) -> CkyChart {
+        let productions = self.cnf.productions();
+        let mut labels: HashMap<(usize, usize), HashSet<&str>> = HashMap::new();
+        let mut chart: CkyChart = HashMap::new();
+
+        for (position, token) in tokens.iter().enumerate() {
+            for (index, production) in productions.iter().enumerate() {
+                if matches!(production.rhs(), CnfRhs::Lexical(terminal) if terminal == token) {
+                    chart
+                        .entry((position, position + 1))
+                        .or_default()
+                        .entry(index)
+                        .or_default()
+                        .push(Backpointer::Lexical);
+                    labels
+                        .entry((position, position + 1))
+                        .or_default()
+                        .insert(production.lhs());
+                }
+            }
+        }
+
+        for width in 2..=tokens.len() {
+            for start in 0..=tokens.len() - width {
+                let end = start + width;
+                for split in start + 1..end {
+                    let (Some(left), Some(right)) =
+                        (labels.get(&(start, split)), labels.get(&(split, end)))
+                    else {
+                        continue;
+                    };
+                    let mut found = Vec::new();
+                    for (index, production) in productions.iter().enumerate() {
+                        if let CnfRhs::Binary(b, c) = production.rhs() {
+                            if left.contains(b.as_str()) && right.contains(c.as_str()) {
+                                found.push(index);
+                            }
+                        }
+                    }
+                    for index in found {
+                        chart
+                            .entry((start, end))
+                            .or_default()
+                            .entry(index)
+                            .or_default()
+                            .push(Backpointer::Binary(split));
+                        labels
+                            .entry((start, end))
+                            .or_default()
+                            .insert(productions[index].lhs());
+                    }
+                }
+            }
+        }
+
+        chart
+    }
+
+    /// Child sequences of a node built by CNF production `index` over `start..end`.
+    fn expansions(
+        &self,
+        chart: &CkyChart,
+        tokens: &[String],
+        index: usize,
+        start: usize,
+        end: usize,
+        memo: &mut HashMap<(usize, usize, usize), Vec<Vec<TreeValue>>>,
+    ) -> Vec<Vec<TreeValue>> {
+        if let Some(cached) = memo.get(&(index, start, end)) {
+            return cached.clone();
+        }
+        let Some(backpointers) = chart.get(&(start, end)).and_then(|cell| cell.get(&index)) else {
+            return Vec::new();
+        };
+
+        let production = &self.cnf.productions()[index];
+        let mut out = Vec::new();
+        for backpointer in backpointers {
+            match (backpointer, production.rhs()) {
+                (Backpointer::Lexical, CnfRhs::Lexical(_)) => {
+                    out.push(vec![TreeValue::leaf(tokens[start].clone())]);
+                }
+                (Backpointer::Binary(split), CnfRhs::Binary(left, right)) => {
+                    let lefts = self.symbol_expansions(chart, tokens, left, start, *split, memo);
+                    let rights = self.symbol_expansions(chart, tokens, right, *split, end, memo);
+                    for left in &lefts {
+                        for right in &rights {
+                            let mut children = left.clone();
+                            children.extend(right.iter().cloned());
+                            out.push(children);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let out = out
+            .into_iter()
+            .map(|children| self.cnf.apply_chain(production, children))
+            .collect::<Vec<_>>();
+        memo.insert((index, start, end), out.clone());
+        out
+    }
+
+    /// Child sequences contributed by `symbol` spanning `start..end` on a
+    /// right-hand side.
+    fn symbol_expansions(
+        &self,
+        chart: &CkyChart,
+        tokens: &[String],
+        symbol: &str,
+        start: usize,
+        end: usize,
+        memo: &mut HashMap<(usize, usize, usize), Vec<Vec<TreeValue>>>,
+    ) -> Vec<Vec<TreeValue>> {
+        let Some(cell) = chart.get(&(start, end)) else {
+            return Vec::new();
+        };
+        let mut indices = cell
+            .keys()
+            .copied()
+            .filter(|index| self.cnf.productions()[*index].lhs() == symbol)
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+
+        let mut out = Vec::new();
+        for index in indices {
+            for children in self.expansions(chart, tokens, index, start, end, memo) {
+                out.push(self.cnf.wrap(symbol, children));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::nlp::parse::cfg::Production;
+    use crate::ml::nlp::parse::earley::EarleyChartParser;
+
+    fn nt(symbol: &str) -> GrammarSymbol {
+        GrammarSymbol::nonterminal(symbol)
+    }
+
+    fn t(symbol: &str) -> GrammarSymbol {
+        GrammarSymbol::terminal(symbol)
+    }
+
+    /// Not in CNF: a ternary rule with a terminal, and unit chains.
+    fn grammar() -> Cfg {
+        Cfg::new(
+            "S",
+            vec![
+                Production::new("S", vec![nt("NP"), nt("VP")]),
+                Production::new("VP", vec![nt("V"), nt("NP"), nt("PP")]),
+                Production::new("VP", vec![nt("V"), nt("NP")]),
+                Production::new("NP", vec![nt("NP"), nt("PP")]),
+                Production::new("NP", vec![nt("Name")]),
+                Production::new("NP", vec![nt("Det"), nt("N")]),
+                Production::new("Name", vec![t("John")]),
+                Production::new("PP", vec![t("with"), nt("NP")]),
+                Production::new("Det", vec![t("the")]),
+                Production::new("N", vec![t("dog")]),
+                Production::new("N", vec![t("stick")]),
+                Production::new("V", vec![t("hit")]),
+            ],
+        )
+        .expect("valid grammar")
+    }
+
+    fn sentence() -> Vec<&'static str> {
+        vec!["John", "hit", "the", "dog", "with", "the", "stick"]
+    }
+
+    #[test]
+    fn cnf_conversion_yields_binary_and_lexical_rules() {
+        let cnf = CnfGrammar::from_cfg(&grammar());
+
+        assert!(cnf.is_helper("VP|<NP-PP>"));
+        assert!(cnf.is_helper("'with'"));
+        let collapsed = cnf
+            .productions()
+            .iter()
+            .find(|production| {
+                production.lhs() == "NP" && production.rhs() == &CnfRhs::Lexical("John".into())
+            })
+            .expect("unit chain collapsed");
+        assert_eq!(collapsed.chain(), ["Name".to_string()]);
+    }
+
+    #[test]
+    fn cky_agrees_with_earley_on_ambiguous_sentence() {
+        let cky = CkyParser::new(grammar())
+            .parse_token_texts(sentence())
+            .expect("parsing should run")
+            .iter()
+            .map(TreeValue::format_bracketed)
+            .collect::<HashSet<_>>();
+        let earley = EarleyChartParser::new(grammar())
+            .parse_token_texts(sentence())
+            .expect("parsing should run")
+            .iter()
+            .map(TreeValue::format_bracketed)
+            .collect::<HashSet<_>>();
+
+        assert_eq!(cky.len(), 2);
+        assert_eq!(cky, earley);
+        assert!(cky.contains(
+            "(S (NP (Name John)) (VP (V hit) (NP (Det the) (N dog)) (PP with (NP (Det the) (N stick)))))"
+        ));
+    }
+
+    #[test]
+    fn cky_returns_no_parse_for_ungrammatical_sentence() {
+        let parses = CkyParser::new(grammar())
+            .parse_token_texts(["the", "dog", "John"])
+            .expect("parsing should run");
+        assert!(parses.is_empty());
+    }
+}
@@ -71,6 +71,25 @@ pub(super) fn has_cycle(arcs: &[isize]) -> bool {
     false
 }
 
+/// Word tokens laid out as if joined by single spaces.
+pub(super) fn tokens_from_words<S: AsRef<str>>(words: &[S]) -> Vec<Token> {
+    let mut cursor = 0usize;
+    words
+        .iter()
+        .map(|word| {
+            let word = word.as_ref();
+            let start = cursor;
+            let end = start + word.len();
+            cursor = end + 1;
+            Token::new(
+                word.to_string(),
+                TokenSpan::new(start, end),
+                TokenKind::Word,
+            )
+        })
+        .collect()
+}
+
 pub(super) fn graph_from_arcs(tokens: &[&str], arcs: &[isize]) -> Option<DependencyGraph> {
     if tokens.len() != arcs.len() {
         return None;
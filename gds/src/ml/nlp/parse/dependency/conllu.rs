@@ -0,0 +1,188 @@
+//! CoNLL-U reading and writing.
+//!
+//! Only the columns a dependency parser needs are kept: FORM, LEMMA, UPOS,
+//! XPOS, HEAD and DEPREL. Comment lines, multiword token ranges (`1-2`) and
+//! empty nodes (`1.1`) are skipped.
+
+use crate::ml::nlp::parse::cfg::ParseError;
+
+use super::common::tokens_from_words;
+use super::{DependencyEdge, DependencyGraph};
+
+/// The relation CoNLL-U uses for the sentence root.
+pub const CONLLU_ROOT: &str = "root";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConlluToken {
+    pub form: String,
+    pub lemma: String,
+    pub upos: String,
+    pub xpos: String,
+    /// 1-based head index; 0 marks the root.
+    pub head: usize,
+    pub deprel: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConlluSentence {
+    pub tokens: Vec<ConlluToken>,
+}
+
+impl ConlluSentence {
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    pub fn words(&self) -> Vec<&str> {
+        self.tokens.iter().map(|t| t.form.as_str()).collect()
+    }
+
+    pub fn tags(&self) -> Vec<&str> {
+        self.tokens.iter().map(|t| t.upos.as_str()).collect()
+    }
+
+    /// 0-based heads, `None` for the root.
+    pub fn heads(&self) -> Vec<Option<usize>> {
+        self.tokens.iter().map(|t| t.head.checked_sub(1)).collect()
+    }
+
+    /// The gold tree. Tokens attached to the root after the first one are
+    /// hung under the first root so the graph keeps a single root.
+    pub fn to_graph(&self) -> DependencyGraph {
+        let tokens = tokens_from_words(&self.words());
+        let mut root = None;
+        let mut edges = Vec::new();
+        for (dep, token) in self.tokens.iter().enumerate() {
+            match (token.head.checked_sub(1), root) {
+                (Some(head), _) => edges.push(DependencyEdge::new(head, dep, &token.deprel)),
+                (None, None) => root = Some(dep),
+                (None, Some(first)) => edges.push(DependencyEdge::new(first, dep, &token.deprel)),
+            }
+        }
+        DependencyGraph::from_tokens(&tokens, edges, root)
+    }
+
+    /// A sentence carrying the heads and relations of `graph`, with optional
+    /// UPOS tags (one per node).
+    pub fn from_graph(graph: &DependencyGraph, tags: Option<&[&str]>) -> Self {
+        let tokens = graph
+            .nodes()
+            .iter()
+            .map(|node| {
+                let index = node.index();
+                let (head, deprel) = match graph.head_of(index) {
+                    Some(head) => (
+                        head + 1,
+                        graph.relation_of(index).unwrap_or("dep").to_string(),
+                    ),
+                    None => (0, CONLLU_ROOT.to_string()),
+                };
+                ConlluToken {
+                    form: node.text().to_string(),
+                    lemma: "_".to_string(),
+                    upos: tags
+                        .and_then(|tags| tags.get(index))
+                        .map_or("_", |tag| *tag)
+                        .to_string(),
+                    xpos: "_".to_string(),
+                    head,
+                    deprel,
+                }
+            })
+            .collect();
+        Self { tokens }
+    }
+
+    /// Ten tab-separated columns per token, unused columns as `_`.
+    pub fn to_conllu(&self) -> String {
+        self.tokens
+            .iter()
+            .enumerate()
+            .map(|(index, t)| {
+                format!(
+                    "{}\t{}\t{}\t{}\t{}\t_\t{}\t{}\t_\t_",
+                    index + 1,
+                    t.form,
+                    t.lemma,
+                    t.upos,
+                    t.xpos,
+                    t.head,
+                    t.deprel
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Parse CoNLL-U text into sentences (blank-line separated).
+pub fn parse_conllu(input: &str) -> Result<Vec<ConlluSentence>, ParseError> {
+    let mut sentences = Vec::new();
+    let mut current = ConlluSentence::default();
+
+    for line in input.lines().map(str::trim_end) {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                sentences.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let columns = line.split('\t').collect::<Vec<_>>();
+        if columns.len() != 10 {
+            return Err(ParseError::InvalidDependencyRow(line.to_string()));
+        }
+        if columns[0].contains(['-', '.']) {
+            continue;
+        }
+        let id = columns[0]
+            .parse::<usize>()
+            .map_err(|_| ParseError::InvalidDependencyRow(line.to_string()))?;
+        if id != current.len() + 1 {
+            return Err(ParseError::InvalidDependencyRow(line.to_string()));
+        }
+        let head = columns[6]
+            .parse::<usize>()
+            .map_err(|_| ParseError::InvalidDependencyRow(line.to_string()))?;
+
+        current.tokens.push(ConlluToken {
+            form: columns[1].to_string(),
+            lemma: columns[2].to_string(),
+            upos: columns[3].to_string(),
+            xpos: columns[4].to_string(),
+            head,
+            deprel: columns[7].to_string(),
+        });
+    }
+    if !current.is_empty() {
+        sentences.push(current);
+    }
+
+    for sentence in &sentences {
+        for (index, token) in sentence.tokens.iter().enumerate() {
+            if token.head > sentence.len() {
+                return Err(ParseError::InvalidHeadIndex {
+                    index,
+                    head: token.head as isize,
+                });
+            }
+        }
+    }
+    Ok(sentences)
+}
+
+/// Render parsed graphs as CoNLL-U, one block per sentence.
+pub fn to_conllu(graphs: &[DependencyGraph]) -> String {
+    graphs
+        .iter()
+        .map(|graph| ConlluSentence::from_graph(graph, None).to_conllu() + "\n")
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,15 +1,21 @@
 mod common;
+mod conllu;
 mod grammar;
 mod graph;
 mod io;
 mod nonprojective;
 mod projective;
+mod transition;
 
+pub use conllu::{parse_conllu, to_conllu, ConlluSentence, ConlluToken, CONLLU_ROOT};
 pub use grammar::DependencyGrammar;
 pub use graph::{DependencyEdge, DependencyGraph, DependencyNode};
 pub use io::{parse_malt_tab, to_dot};
 pub use nonprojective::NonprojectiveDependencyParser;
 pub use projective::ProjectiveDependencyParser;
+pub use transition::{
+    is_projective, TrainingSummary, Transition, TransitionParser, TransitionSystem,
+};
 
 #[cfg(test)]
 mod tests {
@@ -60,4 +66,73 @@ mod tests {
         let parses = parser.parse(&["the", "man", "saw", "the", "dog"]);
         assert!(!parses.is_empty());
     }
+
+    const TREEBANK: &str = "\
+# sent_id = 1
+1\tthe\tthe\tDET\t_\t_\t2\tdet\t_\t_
+2\tcat\tcat\tNOUN\t_\t_\t3\tnsubj\t_\t_
+3\tsaw\tsee\tVERB\t_\t_\t0\troot\t_\t_
+4\ta\ta\tDET\t_\t_\t5\tdet\t_\t_
+5\tdog\tdog\tNOUN\t_\t_\t3\tobj\t_\t_
+
+1\ta\ta\tDET\t_\t_\t2\tdet\t_\t_
+2\tdog\tdog\tNOUN\t_\t_\t3\tnsubj\t_\t_
+3-4\tsleeps.\t_\t_\t_\t_\t_\t_\t_\t_
+3\tsleeps\tsleep\tVERB\t_\t_\t0\troot\t_\t_
+4\t.\t.\tPUNCT\t_\t_\t3\tpunct\t_\t_
+
+1\tdogs\tdog\tNOUN\t_\t_\t2\tnsubj\t_\t_
+2\tchase\tchase\tVERB\t_\t_\t0\troot\t_\t_
+3\tthe\tthe\tDET\t_\t_\t5\tdet\t_\t_
+4\tbig\tbig\tADJ\t_\t_\t5\tamod\t_\t_
+5\tcats\tcat\tNOUN\t_\t_\t2\tobj\t_\t_
+";
+
+    #[test]
+    fn conllu_round_trips_through_graph() {
+        let sentences = parse_conllu(TREEBANK).expect("should parse conllu");
+        assert_eq!(sentences.len(), 3);
+        assert_eq!(sentences[1].words(), ["a", "dog", "sleeps", "."]);
+
+        let graph = sentences[0].to_graph();
+        assert_eq!(graph.root(), Some(2));
+        assert_eq!(graph.head_of(1), Some(2));
+        assert_eq!(graph.relation_of(4), Some("obj"));
+
+        let written = ConlluSentence::from_graph(&graph, Some(&sentences[0].tags()));
+        assert_eq!(written.tokens[2].deprel, CONLLU_ROOT);
+        assert_eq!(
+            written.to_conllu().lines().next(),
+            Some("1\tthe\t_\tDET\t_\t_\t2\tdet\t_\t_")
+        );
+        assert!(parse_conllu("1\tbad\t_\t_\t_\t_\t9\tdep\t_\t_").is_err());
+    }
+
+    #[test]
+    fn projectivity_detects_crossing_arcs() {
+        assert!(is_projective(&[2, 0, 2]));
+        assert!(!is_projective(&[3, 4, 0, 3]));
+    }
+
+    #[test]
+    fn transition_parsers_learn_training_trees() {
+        let sentences = parse_conllu(TREEBANK).unwrap();
+        for system in [TransitionSystem::ArcStandard, TransitionSystem::ArcEager] {
+            let mut parser = TransitionParser::new(system);
+            let summary = parser.train(&sentences, 10, 7);
+            assert_eq!(summary.sentences, 3);
+            assert_eq!(summary.skipped_non_projective, 0);
+
+            let (las, uas) = parser.evaluate(&sentences).unwrap();
+            assert_eq!((las, uas), (1.0, 1.0), "{system:?}");
+
+            let graph = parser.parse(
+                &["the", "dog", "saw", "a", "cat"],
+                &["DET", "NOUN", "VERB", "DET", "NOUN"],
+            );
+            assert_eq!(graph.root(), Some(2));
+            assert_eq!(graph.head_of(1), Some(2));
+            assert!(!graph.contains_cycle());
+        }
+    }
 }
@@ -0,0 +1,536 @@
+//! Transition-based dependency parsing with an averaged-perceptron oracle.
+//!
+//! Two transition systems are supported:
+//!
+//! - **arc-standard**: `SHIFT`, `LEFT-ARC` and `RIGHT-ARC` over the top two
+//!   stack items; a word is attached only once its own dependents are done;
+//! - **arc-eager**: `SHIFT`, `LEFT-ARC`, `RIGHT-ARC` and `REDUCE` between the
+//!   stack top and the first buffer word; right dependents attach as early as
+//!   possible.
+//!
+//! Training replays the static oracle of each gold tree and updates an
+//! [`AveragedPerceptron`] whenever its best *valid* transition disagrees.
+//! Both oracles require projective trees, so non-projective training
+//! sentences are skipped and counted.
+
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::ml::nlp::parse::evaluate::{DependencyEvaluator, EvaluationError};
+use crate::ml::nlp::tag::perceptron::AveragedPerceptron;
+
+use super::common::tokens_from_words;
+use super::conllu::ConlluSentence;
+use super::{DependencyEdge, DependencyGraph};
+
+const ROOT_WORD: &str = "<ROOT>";
+const NONE: &str = "<NONE>";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TransitionSystem {
+    #[default]
+    ArcStandard,
+    ArcEager,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Transition {
+    Shift,
+    Reduce,
+    LeftArc(String),
+    RightArc(String),
+}
+
+impl Transition {
+    /// Perceptron class name, e.g. `LEFT:nsubj`.
+    pub fn class(&self) -> String {
+        match self {
+            Transition::Shift => "SHIFT".to_string(),
+            Transition::Reduce => "REDUCE".to_string(),
+            Transition::LeftArc(label) => format!("LEFT:{label}"),
+            Transition::RightArc(label) => format!("RIGHT:{label}"),
+        }
+    }
+
+    pub fn from_class(class: &str) -> Option<Self> {
+        match class {
+            "SHIFT" => Some(Transition::Shift),
+            "REDUCE" => Some(Transition::Reduce),
+            _ => class
+                .strip_prefix("LEFT:")
+                .map(|label| Transition::LeftArc(label.to_string()))
+                .or_else(|| {
+                    class
+                        .strip_prefix("RIGHT:")
+                        .map(|label| Transition::RightArc(label.to_string()))
+                }),
+        }
+    }
+}
+
+/// Parser configuration: stack, buffer and partial arcs. Position 0 is the
+/// artificial root; words occupy positions `1..=n`.
+#[derive(Debug, Clone)]
+struct State {
+    stack: Vec<usize>,
+    buffer: usize,
+    n: usize,
+    heads: Vec<Option<usize>>,
+    labels: Vec<Option<String>>,
+    children: Vec<Vec<usize>>,
+}
+
+impl State {
+    fn new(n: usize) -> Self {
+        Self {
+            stack: vec![0],
+            buffer: 1,
+            n,
+            heads: vec![None; n + 1],
+            labels: vec![None; n + 1],
+            children: vec![Vec::new(); n + 1],
+        }
+    }
+
+    fn s(&self, depth: usize) -> Option<usize> {
+        self.stack
+            .len()
+            .checked_sub(depth + 1)
+            .map(|i| self.stack[i])
+    }
+
+    fn b(&self, offset: usize) -> Option<usize> {
+        let position = self.buffer + offset;
+        (position <= self.n).then_some(position)
+    }
+
+    fn root_assigned(&self) -> bool {
+        self.heads.contains(&Some(0))
+    }
+
+    fn is_terminal(&self, system: TransitionSystem) -> bool {
+        match system {
+            TransitionSystem::ArcStandard => self.b(0).is_none() && self.stack.len() == 1,
+            TransitionSystem::ArcEager => self.b(0).is_none(),
+        }
+    }
+
+    fn is_valid(&self, system: TransitionSystem, transition: &Transition) -> bool {
+        let (s0, s1, b0) = (self.s(0), self.s(1), self.b(0));
+        match (system, transition) {
+            (_, Transition::Shift) => b0.is_some(),
+            (TransitionSystem::ArcStandard, Transition::Reduce) => false,
+            (TransitionSystem::ArcStandard, Transition::LeftArc(_)) => {
+                matches!(s1, Some(s1) if s1 != 0)
+            }
+            (TransitionSystem::ArcStandard, Transition::RightArc(_)) => {
+                matches!(s1, Some(s1) if s1 != 0 || b0.is_none())
+            }
+            (TransitionSystem::ArcEager, Transition::Reduce) => {
+                matches!(s0, Some(s0) if s0 != 0 && self.heads[s0].is_some())
+            }
+            (TransitionSystem::ArcEager, Transition::LeftArc(_)) => {
+                b0.is_some() && matches!(s0, Some(s0) if s0 != 0 && self.heads[s0].is_none())
+            }
+            (TransitionSystem::ArcEager, Transition::RightArc(_)) => {
+                b0.is_some() && matches!(s0, Some(s0) if s0 != 0 || !self.root_assigned())
+            }
+        }
+    }
+
+    fn attach(&mut self, head: usize, dep: usize, label: &str) {
+        self.heads[dep] = Some(head);
+        self.labels[dep] = Some(label.to_string());
+        self.children[head].push(dep);
+    }
+
+    /// Apply a transition already checked with [`State::is_valid`].
+    fn apply(&mut self, system: TransitionSystem, transition: &Transition) {
+        match (system, transition) {
+            (_, Transition::Shift) => {
+                self.stack.push(self.buffer);
+                self.buffer += 1;
+            }
+            (_, Transition::Reduce) => {
+                self.stack.pop();
+            }
+            (TransitionSystem::ArcStandard, Transition::LeftArc(label)) => {
+                let s0 = self.stack.pop().expect("valid left-arc has s0");
+                let s1 = self.stack.pop().expect("valid left-arc has s1");
+                self.attach(s0, s1, label);
+                self.stack.push(s0);
+            }
+            (TransitionSystem::ArcStandard, Transition::RightArc(label)) => {
+                let s0 = self.stack.pop().expect("valid right-arc has s0");
+                let s1 = *self.stack.last().expect("valid right-arc has s1");
+                self.attach(s1, s0, label);
+            }
+            (TransitionSystem::ArcEager, Transition::LeftArc(label)) => {
+                let s0 = self.stack.pop().expect("valid left-arc has s0");
+                self.attach(self.buffer, s0, label);
+            }
+            (TransitionSystem::ArcEager, Transition::RightArc(label)) => {
+                let s0 = *self.stack.last().expect("valid right-arc has s0");
+                self.attach(s0, self.buffer, label);
+                self.stack.push(self.buffer);
+                self.buffer += 1;
+            }
+        }
+    }
+
+    fn leftmost(&self, position: Option<usize>) -> Option<usize> {
+        let position = position?;
+        self.children[position]
+            .iter()
+            .copied()
+            .filter(|&child| child < position)
+            .min()
+    }
+
+    fn rightmost(&self, position: Option<usize>) -> Option<usize> {
+        let position = position?;
+        self.children[position]
+            .iter()
+            .copied()
+            .filter(|&child| child > position)
+            .max()
+    }
+}
+
+/// Gold arcs by position (root = 0) used by the static oracles.
+struct Gold<'a> {
+    heads: Vec<usize>,
+    labels: Vec<&'a str>,
+}
+
+impl<'a> Gold<'a> {
+    fn new(sentence: &'a ConlluSentence) -> Self {
+        let mut heads = vec![0];
+        let mut labels = vec![""];
+        for token in &sentence.tokens {
+            heads.push(token.head);
+            labels.push(token.deprel.as_str());
+        }
+        Self { heads, labels }
+    }
+
+    fn children_done(&self, state: &State, position: usize) -> bool {
+        (1..self.heads.len())
+            .filter(|&dep| self.heads[dep] == position)
+            .all(|dep| state.heads[dep].is_some())
+    }
+
+    fn transition(&self, system: TransitionSystem, state: &State) -> Transition {
+        let label = |position: usize| self.labels[position].to_string();
+        match system {
+            TransitionSystem::ArcStandard => {
+                if let (Some(s0), Some(s1)) = (state.s(0), state.s(1)) {
+                    if s1 != 0 && self.heads[s1] == s0 {
+                        return Transition::LeftArc(label(s1));
+                    }
+                    if self.heads[s0] == s1 && self.children_done(state, s0) {
+                        return Transition::RightArc(label(s0));
+                    }
+                }
+                Transition::Shift
+            }
+            TransitionSystem::ArcEager => {
+                if let (Some(s0), Some(b0)) = (state.s(0), state.b(0)) {
+                    if s0 != 0 && self.heads[s0] == b0 {
+                        return Transition::LeftArc(label(s0));
+                    }
+                    if self.heads[b0] == s0 {
+                        return Transition::RightArc(label(b0));
+                    }
+                    if s0 != 0 && state.heads[s0].is_some() && self.children_done(state, s0) {
+                        return Transition::Reduce;
+                    }
+                }
+                Transition::Shift
+            }
+        }
+    }
+}
+
+/// Whether the 1-based heads (0 = root) of a sentence form a projective tree.
+pub fn is_projective(heads: &[usize]) -> bool {
+    let arcs = heads
+        .iter()
+        .enumerate()
+        .map(|(i, &head)| {
+            let dep = i + 1;
+            (head.min(dep), head.max(dep))
+        })
+        .collect::<Vec<_>>();
+    // Two arcs cross when exactly one end of one lies strictly inside the other.
+    let crosses = |(a, b): (usize, usize), (c, d): (usize, usize)| a < c && c < b && b < d;
+    arcs.iter().all(|&x| arcs.iter().all(|&y| !crosses(x, y)))
+}
+
+/// Outcome of [`TransitionParser::train`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TrainingSummary {
+    pub sentences: usize,
+    pub skipped_non_projective: usize,
+    /// Share of oracle transitions the model predicted, per iteration
+    /// (before weight averaging).
+    pub transition_accuracy: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TransitionParser {
+    system: TransitionSystem,
+    model: AveragedPerceptron,
+}
+
+impl TransitionParser {
+    pub fn new(system: TransitionSystem) -> Self {
+        Self {
+            system,
+            model: AveragedPerceptron::new(),
+        }
+    }
+
+    pub fn system(&self) -> TransitionSystem {
+        self.system
+    }
+
+    /// Train on gold CoNLL-U sentences for `iterations` shuffled passes.
+    pub fn train(
+        &mut self,
+        sentences: &[ConlluSentence],
+        iterations: usize,
+        seed: u64,
+    ) -> TrainingSummary {
+        let mut summary = TrainingSummary::default();
+        let mut usable = Vec::new();
+        for sentence in sentences.iter().filter(|s| !s.is_empty()) {
+            let heads = sentence.tokens.iter().map(|t| t.head).collect::<Vec<_>>();
+            if is_projective(&heads) {
+                usable.push(sentence);
+            } else {
+                summary.skipped_non_projective += 1;
+            }
+        }
+        summary.sentences = usable.len();
+
+        self.model.classes.insert(Transition::Shift.class());
+        if self.system == TransitionSystem::ArcEager {
+            self.model.classes.insert(Transition::Reduce.class());
+        }
+        for token in usable.iter().flat_map(|s| &s.tokens) {
+            let label = token.deprel.clone();
+            self.model
+                .classes
+                .insert(Transition::LeftArc(label.clone()).class());
+            self.model
+                .classes
+                .insert(Transition::RightArc(label).class());
+        }
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        for _ in 0..iterations {
+            usable.shuffle(&mut rng);
+            let (mut correct, mut total) = (0usize, 0usize);
+            for sentence in &usable {
+                let (words, tags) = normalize(&sentence.words(), &sentence.tags());
+                let gold = Gold::new(sentence);
+                let mut state = State::new(sentence.len());
+                while !state.is_terminal(self.system) {
+                    let truth = gold.transition(self.system, &state);
+                    if !state.is_valid(self.system, &truth) {
+                        break;
+                    }
+                    let features = features(&state, &words, &tags);
+                    let guess = self.best_valid(&state, &features);
+                    self.model.update(&truth.class(), &guess.class(), &features);
+                    correct += usize::from(guess == truth);
+                    total += 1;
+                    state.apply(self.system, &truth);
+                }
+            }
+            summary.transition_accuracy.push(if total == 0 {
+                0.0
+            } else {
+                correct as f64 / total as f64
+            });
+        }
+        self.model.average_weights();
+        summary
+    }
+
+    /// Parse one sentence given its words and part-of-speech tags (missing
+    /// tags are treated as unknown).
+    pub fn parse<S: AsRef<str>, T: AsRef<str>>(&self, words: &[S], tags: &[T]) -> DependencyGraph {
+        let tokens = tokens_from_words(words);
+        let n = words.len();
+        let (norm_words, norm_tags) = normalize(words, tags);
+
+        let mut state = State::new(n);
+        // Both systems finish within 2n transitions; the bound guards against
+        // an untrained model that keeps proposing no-ops.
+        for _ in 0..(2 * n + 2) {
+            if state.is_terminal(self.system) {
+                break;
+            }
+            let features = features(&state, &norm_words, &norm_tags);
+            let transition = self.best_valid(&state, &features);
+            state.apply(self.system, &transition);
+        }
+
+        // The first root-attached word is the root; words left without a head
+        // (or attached to the root a second time) hang under it.
+        let root = (1..=n)
+            .find(|&p| state.heads[p] == Some(0))
+            .or_else(|| (1..=n).find(|&p| state.heads[p].is_none()))
+            .map(|p| p - 1);
+        let edges = (1..=n)
+            .filter(|&p| Some(p - 1) != root)
+            .map(|p| {
+                let head = match state.heads[p] {
+                    Some(head) if head > 0 => head - 1,
+                    _ => root.expect("a non-empty parse has a root"),
+                };
+                let label = state.labels[p].clone().unwrap_or_else(|| "dep".to_string());
+                DependencyEdge::new(head, p - 1, label)
+            })
+            .collect();
+        DependencyGraph::from_tokens(&tokens, edges, root)
+    }
+
+    /// Parse a CoNLL-U sentence using its FORM and UPOS columns.
+    pub fn parse_sentence(&self, sentence: &ConlluSentence) -> DependencyGraph {
+        self.parse(&sentence.words(), &sentence.tags())
+    }
+
+    /// `(LAS, UAS)` against gold sentences, punctuation excluded as in
+    /// [`DependencyEvaluator`].
+    pub fn evaluate(&self, sentences: &[ConlluSentence]) -> Result<(f64, f64), EvaluationError> {
+        let parsed = sentences.iter().map(|s| self.parse_sentence(s)).collect();
+        let gold = sentences.iter().map(ConlluSentence::to_graph).collect();
+        DependencyEvaluator::new(parsed, gold).eval()
+    }
+
+    fn best_valid(&self, state: &State, features: &HashMap<String, f64>) -> Transition {
+        let scores = self.model.scores(features);
+        let mut best: Option<(Transition, f64)> = None;
+        // `classes` is ordered, so ties resolve deterministically.
+        for class in &self.model.classes {
+            let Some(transition) = Transition::from_class(class) else {
+                continue;
+            };
+            if !state.is_valid(self.system, &transition) {
+                continue;
+            }
+            let score = scores.get(class).copied().unwrap_or(0.0);
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_score)| score > *best_score)
+            {
+                best = Some((transition, score));
+            }
+        }
+        best.map(|(transition, _)| transition).unwrap_or_else(|| {
+            [
+                Transition::Shift,
+                Transition::Reduce,
+                Transition::RightArc("dep".to_string()),
+                Transition::LeftArc("dep".to_string()),
+            ]
+            .into_iter()
+            .find(|transition| state.is_valid(self.system, transition))
+            .unwrap_or(Transition::Shift)
+        })
+    }
+}
+
+/// Lowercased words and tags with the artificial root at position 0.
+fn normalize<S: AsRef<str>, T: AsRef<str>>(words: &[S], tags: &[T]) -> (Vec<String>, Vec<String>) {
+    let mut norm_words = vec![ROOT_WORD.to_string()];
+    let mut norm_tags = vec![ROOT_WORD.to_string()];
+    for (i, word) in words.iter().enumerate() {
+        norm_words.push(word.as_ref().to_lowercase());
+        norm_tags.push(tags.get(i).map_or("_", |tag| tag.as_ref()).to_string());
+    }
+    (norm_words, norm_tags)
+}
+
+fn features(state: &State, words: &[String], tags: &[String]) -> HashMap<String, f64> {
+    let word = |p: Option<usize>| p.map_or(NONE, |p| words[p].as_str());
+    let tag = |p: Option<usize>| p.map_or(NONE, |p| tags[p].as_str());
+    let label = |p: Option<usize>| p.and_then(|p| state.labels[p].as_deref()).unwrap_or(NONE);
+
+    let (s0, s1, s2) = (state.s(0), state.s(1), state.s(2));
+    let (b0, b1, b2) = (state.b(0), state.b(1), state.b(2));
+    let distance = |a: Option<usize>, b: Option<usize>| match (a, b) {
+        (Some(a), Some(b)) => a.abs_diff(b).min(5).to_string(),
+        _ => NONE.to_string(),
+    };
+    let valency = |p: Option<usize>| p.map_or(0, |p| state.children[p].len()).to_string();
+
+    let templates = [
+        ("bias", String::new()),
+        ("s0w", word(s0).to_string()),
+        ("s0t", tag(s0).to_string()),
+        ("s0wt", format!("{} {}", word(s0), tag(s0))),
+        ("s1w", word(s1).to_string()),
+        ("s1t", tag(s1).to_string()),
+        ("s1wt", format!("{} {}", word(s1), tag(s1))),
+        ("s2t", tag(s2).to_string()),
+        ("b0w", word(b0).to_string()),
+        ("b0t", tag(b0).to_string()),
+        ("b0wt", format!("{} {}", word(b0), tag(b0))),
+        ("b1w", word(b1).to_string()),
+        ("b1t", tag(b1).to_string()),
+        ("b2t", tag(b2).to_string()),
+        ("s0t b0t", format!("{} {}", tag(s0), tag(b0))),
+        ("s0w b0w", format!("{} {}", word(s0), word(b0))),
+        ("s0wt b0t", format!("{} {} {}", word(s0), tag(s0), tag(b0))),
+        ("s0t b0wt", format!("{} {} {}", tag(s0), word(b0), tag(b0))),
+        ("s1t s0t", format!("{} {}", tag(s1), tag(s0))),
+        ("s1w s0w", format!("{} {}", word(s1), word(s0))),
+        ("s1wt s0t", format!("{} {} {}", word(s1), tag(s1), tag(s0))),
+        ("s1t s0wt", format!("{} {} {}", tag(s1), word(s0), tag(s0))),
+        (
+            "s1t s0t b0t",
+            format!("{} {} {}", tag(s1), tag(s0), tag(b0)),
+        ),
+        (
+            "s2t s1t s0t",
+            format!("{} {} {}", tag(s2), tag(s1), tag(s0)),
+        ),
+        (
+            "s0t b0t b1t",
+            format!("{} {} {}", tag(s0), tag(b0), tag(b1)),
+        ),
+        (
+            "b0t b1t b2t",
+            format!("{} {} {}", tag(b0), tag(b1), tag(b2)),
+        ),
+        ("s0l", label(state.leftmost(s0)).to_string()),
+        ("s0r", label(state.rightmost(s0)).to_string()),
+        ("s1l", label(state.leftmost(s1)).to_string()),
+        ("s1r", label(state.rightmost(s1)).to_string()),
+        ("b0l", label(state.leftmost(b0)).to_string()),
+        ("s0lt", format!("{} {}", tag(s0), tag(state.leftmost(s0)))),
+        ("s0rt", format!("{} {}", tag(s0), tag(state.rightmost(s0)))),
+        ("s0 label", label(s0).to_string()),
+        (
+            "d s0 b0",
+            format!("{} {} {}", distance(s0, b0), tag(s0), tag(b0)),
+        ),
+        (
+            "d s1 s0",
+            format!("{} {} {}", distance(s1, s0), tag(s1), tag(s0)),
+        ),
+        ("s0v", format!("{} {}", tag(s0), valency(s0))),
+        ("s1v", format!("{} {}", tag(s1), valency(s1))),
+    ];
+    templates
+        .into_iter()
+        .map(|(name, value)| (format!("{name}={value}"), 1.0))
+        .collect()
+}
@@ -0,0 +1,387 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::collections::dataset::language::token::Token;
+use crate::collections::dataset::language::tree::TreeValue;
+
+use super::cfg::{Cfg, GrammarSymbol, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct EarleyItem {
+    production: usize,
+    dot: usize,
+    start: usize,
+}
+
+/// Earley chart parser.
+///
+/// Unlike [`super::RecursiveDescentParser`] it terminates on left-recursive
+/// grammars, and unlike [`super::ShiftReduceParser`] it returns every parse of
+/// an ambiguous sentence. Unary cycles (`A -> B`, `B -> A`) are followed at most
+/// once per span, so the parse list stays finite.
+#[derive(Debug, Clone)]
+pub struct EarleyChartParser {
+    grammar: Cfg,
+}
+
+impl EarleyChartParser {
+    pub fn new(grammar: Cfg) -> Self {
+        Self { grammar }
+    }
+
+    pub fn grammar(&self) -> &Cfg {
+        &self.grammar
+    }
+
+    pub fn parse_tokens(&self, tokens: &[Token]) -> Result<Vec<TreeValue>, ParseError> {
+        self.parse_token_texts(tokens.iter().map(|token| token.text().to_string()))
+    }
+
+    pub fn parse_token_texts<I, S>(&self, tokens: I) -> Result<Vec<TreeValue>, ParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let tokens = tokens.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.grammar
+            .check_coverage_texts(tokens.iter().map(String::as_str))?;
+        if tokens.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let completed = self.fill_chart(&tokens);
+        let mut builder = TreeBuilder {
+            grammar: &self.grammar,
+            tokens: &tokens,
+            completed: &completed,
+            memo: HashMap::new(),
+            active: HashSet::new(),
+        };
+        let (trees, _) = builder.trees(self.grammar.start(), 0, tokens.len());
+        Ok(trees)
+    }
+
+    /// Whether the sentence is in the grammar's language.
+    pub fn recognizes<I, S>(&self, tokens: I) -> Result<bool, ParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let tokens = tokens.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.grammar
+            .check_coverage_texts(tokens.iter().map(String::as_str))?;
+        if tokens.is_empty() {
+            return Ok(false);
+        }
+
+        let completed = self.fill_chart(&tokens);
+        let start = self.grammar.start();
+        Ok(self
+            .grammar
+            .productions()
+            .iter()
+            .enumerate()
+            .any(|(index, production)| {
+                production.lhs() == start && completed.contains(&(index, 0, tokens.len()))
+            }))
+    }
+
+    /// Runs predict/scan/complete over the sentence and returns every completed
+    /// `(production, start, end)` span.
+    fn fill_chart(&self, tokens: &[String]) -> HashSet<(usize, usize, usize)> {
+        let productions = self.grammar.productions();
+        let mut chart: Vec<Vec<EarleyItem>> = vec![Vec::new(); tokens.len() + 1];
+        let mut seen: Vec<HashSet<EarleyItem>> = vec![HashSet::new(); tokens.len() + 1];
+        let mut completed = HashSet::new();
+
+        for (index, production) in productions.iter().enumerate() {
+            if production.lhs() == self.grammar.start() {
+                push_item(&mut chart, &mut seen, 0, index, 0, 0);
+            }
+        }
+
+        for position in 0..=tokens.len() {
+            let mut cursor = 0;
+            while cursor < chart[position].len() {
+                let item = chart[position][cursor];
+                cursor += 1;
+                let production = &productions[item.production];
+
+                match production.rhs().get(item.dot) {
+                    None => {
+                        completed.insert((item.production, item.start, position));
+                        let waiting = chart[item.start]
+                            .iter()
+                            .filter(|candidate| {
+                                matches!(
+                                    productions[candidate.production].rhs().get(candidate.dot),
+                                    Some(GrammarSymbol::Nonterminal(symbol)) if symbol == production.lhs()
+                                )
+                            })
+                            .copied()
+                            .collect::<Vec<_>>();
+                        for candidate in waiting {
+                            push_item(
+                                &mut chart,
+                                &mut seen,
+                                position,
+                                candidate.production,
+                                candidate.dot + 1,
+                                candidate.start,
+                            );
+                        }
+                    }
+                    Some(GrammarSymbol::Nonterminal(symbol)) => {
+                        for (index, candidate) in productions.iter().enumerate() {
+                            if candidate.lhs() == symbol {
+                                push_item(&mut chart, &mut seen, position, index, 0, position);
+                            }
+                        }
+                    }
+                    Some(GrammarSymbol::Terminal(terminal)) => {
+                        if tokens.get(position) == Some(terminal) {
+                            push_item(
+                                &mut chart,
+                                &mut seen,
+                                position + 1,
+                                item.production,
+                                item.dot + 1,
+                                item.start,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        completed
+    }
+}
+
+fn push_item(
+    chart: &mut [Vec<EarleyItem>],
+    seen: &mut [HashSet<EarleyItem>],
+    position: usize,
+    production: usize,
+    dot: usize,
+    start: usize,
+) {
+    let item = EarleyItem {
+        production,
+        dot,
+        start,
+    };
+    if seen[position].insert(item) {
+        chart[position].push(item);
+    }
+}
+
+/// Reads trees back out of the completed spans of an Earley chart.
+struct TreeBuilder<'a> {
+    grammar: &'a Cfg,
+    tokens: &'a [String],
+    completed: &'a HashSet<(usize, usize, usize)>,
+    memo: HashMap<(String, usize, usize), Vec<TreeValue>>,
+    active: HashSet<(String, usize, usize)>,
+}
+
+impl TreeBuilder<'_> {
+    /// All trees for `symbol` over `start..end`. The flag reports whether a unary
+    /// cycle was cut while building them; such results are not memoized because
+    /// they depend on which span started the cycle.
+    fn trees(&mut self, symbol: &str, start: usize, end: usize) -> (Vec<TreeValue>, bool) {
+        let key = (symbol.to_string(), start, end);
+        if let Some(trees) = self.memo.get(&key) {
+            return (trees.clone(), false);
+        }
+        if !self.active.insert(key.clone()) {
+            return (Vec::new(), true);
+        }
+
+        let grammar = self.grammar;
+        let mut trees = Vec::new();
+        let mut cut = false;
+        for (index, production) in grammar.productions().iter().enumerate() {
+            if production.lhs() != symbol || !self.completed.contains(&(index, start, end)) {
+                continue;
+            }
+            let (sequences, production_cut) = self.children(production.rhs(), start, end);
+            cut |= production_cut;
+            trees.extend(
+                sequences
+                    .into_iter()
+                    .map(|children| TreeValue::node(symbol, children)),
+            );
+        }
+
+        self.active.remove(&key);
+        if !cut {
+            self.memo.insert(key, trees.clone());
+        }
+        (trees, cut)
+    }
+
+    /// All ways `rhs` can cover `start..end`, as child sequences.
+    fn children(
+        &mut self,
+        rhs: &[GrammarSymbol],
+        start: usize,
+        end: usize,
+    ) -> (Vec<Vec<TreeValue>>, bool) {
+        let Some((first, rest)) = rhs.split_first() else {
+            return if start == end {
+                (vec![Vec::new()], false)
+            } else {
+                (Vec::new(), false)
+            };
+        };
+        // Every symbol covers at least one token since epsilon rules are rejected.
+        if end < start + rhs.len() {
+            return (Vec::new(), false);
+        }
+
+        let mut sequences = Vec::new();
+        let mut cut = false;
+        match first {
+            GrammarSymbol::Terminal(terminal) => {
+                if self.tokens[start] == *terminal {
+                    let (tails, tail_cut) = self.children(rest, start + 1, end);
+                    cut |= tail_cut;
+                    for tail in tails {
+                        let mut sequence = vec![TreeValue::leaf(terminal.clone())];
+                        sequence.extend(tail);
+                        sequences.push(sequence);
+                    }
+                }
+            }
+            GrammarSymbol::Nonterminal(symbol) => {
+                for split in start + 1..=end - rest.len() {
+                    if !self.spans(symbol, start, split) {
+                        continue;
+                    }
+                    let (tails, tail_cut) = self.children(rest, split, end);
+                    cut |= tail_cut;
+                    if tails.is_empty() {
+                        continue;
+                    }
+                    let (heads, head_cut) = self.trees(symbol, start, split);
+                    cut |= head_cut;
+                    for head in &heads {
+                        for tail in &tails {
+                            let mut sequence = vec![head.clone()];
+                            sequence.extend(tail.iter().cloned());
+                            sequences.push(sequence);
+                        }
+                    }
+                }
+            }
+        }
+        (sequences, cut)
+    }
+
+    fn spans(&self, symbol: &str, start: usize, end: usize) -> bool {
+        self.grammar
+            .productions()
+            .iter()
+            .enumerate()
+            .any(|(index, production)| {
+                production.lhs() == symbol && self.completed.contains(&(index, start, end))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::nlp::parse::cfg::Production;
+
+    fn nt(symbol: &str) -> GrammarSymbol {
+        GrammarSymbol::nonterminal(symbol)
+    }
+
+    fn t(symbol: &str) -> GrammarSymbol {
+        GrammarSymbol::terminal(symbol)
+    }
+
+    fn pp_attachment_grammar() -> Cfg {
+        Cfg::new(
+            "S",
+            vec![
+                Production::new("S", vec![nt("NP"), nt("VP")]),
+                Production::new("VP", vec![nt("V"), nt("NP")]),
+                Production::new("VP", vec![nt("VP"), nt("PP")]),
+                Production::new("NP", vec![nt("NP"), nt("PP")]),
+                Production::new("NP", vec![t("I")]),
+                Production::new("NP", vec![nt("Det"), nt("N")]),
+                Production::new("PP", vec![nt("P"), nt("NP")]),
+                Production::new("Det", vec![t("the")]),
+                Production::new("N", vec![t("man")]),
+                Production::new("N", vec![t("telescope")]),
+                Production::new("V", vec![t("saw")]),
+                Production::new("P", vec![t("with")]),
+            ],
+        )
+        .expect("valid grammar")
+    }
+
+    fn sentence() -> Vec<&'static str> {
+        vec!["I", "saw", "the", "man", "with", "the", "telescope"]
+    }
+
+    #[test]
+    fn earley_returns_all_parses_of_left_recursive_grammar() {
+        let parser = EarleyChartParser::new(pp_attachment_grammar());
+        let parses = parser
+            .parse_token_texts(sentence())
+            .expect("parsing should run");
+        let bracketed = parses
+            .iter()
+            .map(TreeValue::format_bracketed)
+            .collect::<HashSet<_>>();
+
+        assert_eq!(parses.len(), 2);
+        assert!(bracketed.contains(
+            "(S (NP I) (VP (VP (V saw) (NP (Det the) (N man))) (PP (P with) (NP (Det the) (N telescope)))))"
+        ));
+        assert!(bracketed.contains(
+            "(S (NP I) (VP (V saw) (NP (NP (Det the) (N man)) (PP (P with) (NP (Det the) (N telescope))))))"
+        ));
+    }
+
+    #[test]
+    fn earley_rejects_ungrammatical_sentences() {
+        let parser = EarleyChartParser::new(pp_attachment_grammar());
+        assert!(parser
+            .parse_token_texts(["saw", "I", "the", "man"])
+            .expect("parsing should run")
+            .is_empty());
+        assert!(!parser
+            .recognizes(["saw", "I", "the", "man"])
+            .expect("recognition should run"));
+        assert!(parser
+            .recognizes(sentence())
+            .expect("recognition should run"));
+        assert!(matches!(
+            parser.parse_token_texts(["I", "saw", "a", "dog"]),
+            Err(ParseError::UncoveredToken(token)) if token == "a"
+        ));
+    }
+
+    #[test]
+    fn earley_terminates_on_unary_cycles() {
+        let grammar = Cfg::new(
+            "S",
+            vec![
+                Production::new("S", vec![nt("A")]),
+                Production::new("A", vec![nt("S")]),
+                Production::new("A", vec![t("x")]),
+            ],
+        )
+        .expect("valid grammar");
+        let parses = EarleyChartParser::new(grammar)
+            .parse_token_texts(["x"])
+            .expect("parsing should run");
+
+        assert_eq!(parses.len(), 1);
+        assert_eq!(parses[0].format_bracketed(), "(S (A x))");
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use crate::collections::dataset::language::tree::TreeValue;
+use crate::ml::nlp::tree::{GrammarTreeExt, ProductionRhs};
+
+use super::cfg::{Cfg, GrammarSymbol, ParseError, Production};
+
+/// Induce a PCFG from a treebank of [`NlpTree`](crate::ml::nlp::tree::NlpTree)
+/// parses, in the manner of NLTK's `induce_pcfg`.
+///
+/// Each production read off the trees gets the relative frequency
+/// `count(A -> β) / count(A)`. Productions keep the order in which they first
+/// appear in the treebank.
+pub fn induce_pcfg_from_treebank<'a>(
+    start: impl Into<String>,
+    treebank: impl IntoIterator<Item = &'a TreeValue>,
+) -> Result<Cfg, ParseError> {
+    let mut order: Vec<(String, Vec<GrammarSymbol>)> = Vec::new();
+    let mut counts: HashMap<(String, Vec<GrammarSymbol>), usize> = HashMap::new();
+    let mut lhs_counts: HashMap<String, usize> = HashMap::new();
+
+    for tree in treebank {
+        for production in tree.productions() {
+            let lhs = production.lhs.symbol().to_string();
+            let rhs = production
+                .rhs
+                .iter()
+                .map(|symbol| match symbol {
+                    ProductionRhs::Nonterminal(nonterminal) => {
+                        GrammarSymbol::nonterminal(nonterminal.symbol())
+                    }
+                    ProductionRhs::Terminal(terminal) => GrammarSymbol::terminal(terminal.clone()),
+                })
+                .collect::<Vec<_>>();
+
+            *lhs_counts.entry(lhs.clone()).or_default() += 1;
+            let key = (lhs, rhs);
+            let count = counts.entry(key.clone()).or_default();
+            if *count == 0 {
+                order.push(key);
+            }
+            *count += 1;
+        }
+    }
+
+    let productions = order
+        .into_iter()
+        .map(|key| {
+            let prob = counts[&key] as f64 / lhs_counts[&key.0] as f64;
+            let (lhs, rhs) = key;
+            Production::new(lhs, rhs).with_prob(prob)
+        })
+        .collect();
+    Cfg::new_probabilistic(start, productions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::nlp::parse::viterbi::ViterbiParser;
+    use crate::ml::nlp::tree::ParseTreeExt;
+
+    fn treebank() -> Vec<TreeValue> {
+        [
+            "(S (NP (D the) (N dog)) (VP (V chased) (NP (D the) (N cat))))",
+            "(S (NP (D the) (N cat)) (VP (V slept)))",
+            "(S (NP (N Rex)) (VP (V chased) (NP (D a) (N cat))))",
+        ]
+        .into_iter()
+        .map(|tree| TreeValue::fromstring(tree).expect("valid tree"))
+        .collect()
+    }
+
+    fn prob(grammar: &Cfg, lhs: &str, rhs: &[GrammarSymbol]) -> f64 {
+        grammar
+            .productions_for(lhs)
+            .find(|production| production.rhs() == rhs)
+            .and_then(Production::prob)
+            .expect("production present")
+    }
+
+    #[test]
+    fn induced_probabilities_are_relative_frequencies() {
+        let grammar = induce_pcfg_from_treebank("S", &treebank()).expect("valid treebank");
+
+        assert!(grammar.is_probabilistic());
+        assert_eq!(grammar.nonterminals(), vec!["S", "NP", "D", "N", "VP", "V"]);
+        let np_dn = [
+            GrammarSymbol::nonterminal("D"),
+            GrammarSymbol::nonterminal("N"),
+        ];
+        assert!((prob(&grammar, "NP", &np_dn) - 0.8).abs() < 1e-9);
+        assert!((prob(&grammar, "N", &[GrammarSymbol::terminal("cat")]) - 0.6).abs() < 1e-9);
+        assert!(
+            (prob(&grammar, "VP", &[GrammarSymbol::nonterminal("V")]) - 1.0 / 3.0).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn induced_grammar_parses_unseen_sentences() {
+        let grammar = induce_pcfg_from_treebank("S", &treebank()).expect("valid treebank");
+        let parse = ViterbiParser::new(grammar)
+            .expect("probabilistic grammar")
+            .parse_token_texts(["Rex", "slept"])
+            .expect("parsing should run")
+            .expect("expected parse");
+
+        assert_eq!(
+            parse.tree().format_bracketed(),
+            "(S (NP (N Rex)) (VP (V slept)))"
+        );
+        assert!(parse.prob() > 0.0);
+    }
+
+    #[test]
+    fn empty_treebank_is_rejected() {
+        assert!(matches!(
+            induce_pcfg_from_treebank("S", &Vec::new()),
+            Err(ParseError::EmptyProductions)
+        ));
+    }
+}
@@ -1,13 +1,21 @@
 pub mod cfg;
+pub mod cky;
 pub mod dependency;
+pub mod earley;
 pub mod evaluate;
+pub mod induce;
 pub mod recursivedescent;
 pub mod shiftreduce;
 pub mod util;
+pub mod viterbi;
 
 pub use cfg::*;
+pub use cky::*;
 pub use dependency::*;
+pub use earley::*;
 pub use evaluate::*;
+pub use induce::*;
 pub use recursivedescent::*;
 pub use shiftreduce::*;
 pub use util::*;
+pub use viterbi::*;
@@ -1,6 +1,8 @@
 use crate::collections::dataset::language::tree::TreeValue;
 
 use super::cfg::{Cfg, ParseError};
+use super::cky::CkyParser;
+use super::earley::EarleyChartParser;
 use super::recursivedescent::RecursiveDescentParser;
 use super::shiftreduce::ShiftReduceParser;
 
@@ -8,12 +10,16 @@ use super::shiftreduce::ShiftReduceParser;
 pub enum ParserFlavor {
     RecursiveDescent,
     ShiftReduce,
+    Earley,
+    Cky,
 }
 
 #[derive(Debug, Clone)]
 pub enum LoadedParser {
     RecursiveDescent(RecursiveDescentParser),
     ShiftReduce(ShiftReduceParser),
+    Earley(EarleyChartParser),
+    Cky(CkyParser),
 }
 
 impl LoadedParser {
@@ -32,6 +38,8 @@ impl LoadedParser {
                 let parse = parser.parse_token_texts(tokens)?;
                 Ok(parse.into_iter().collect())
             }
+            LoadedParser::Earley(parser) => parser.parse_token_texts(tokens),
+            LoadedParser::Cky(parser) => parser.parse_token_texts(tokens),
         }
     }
 }
@@ -42,6 +50,8 @@ pub fn load_parser(grammar: Cfg, flavor: ParserFlavor) -> LoadedParser {
             LoadedParser::RecursiveDescent(RecursiveDescentParser::new(grammar))
         }
         ParserFlavor::ShiftReduce => LoadedParser::ShiftReduce(ShiftReduceParser::new(grammar)),
+        ParserFlavor::Earley => LoadedParser::Earley(EarleyChartParser::new(grammar)),
+        ParserFlavor::Cky => LoadedParser::Cky(CkyParser::new(grammar)),
     }
 }
 
@@ -258,6 +268,20 @@ mod tests {
         assert!(results.iter().all(|result| result.passed));
     }
 
+    #[test]
+    fn chart_parsers_agree_with_recursive_descent() {
+        let tokens = ["I", "saw", "her"];
+        let expected = load_parser(grammar(), ParserFlavor::RecursiveDescent)
+            .parse_token_texts(tokens)
+            .expect("parsing should run");
+        for flavor in [ParserFlavor::Earley, ParserFlavor::Cky] {
+            let parses = load_parser(grammar(), flavor)
+                .parse_token_texts(tokens)
+                .expect("parsing should run");
+            assert_eq!(parses, expected, "{flavor:?}");
+        }
+    }
+
     #[test]
     fn tagged_sentence_to_conll_shapes_rows() {
         let rows = tagged_sentence_to_conll(vec![("This", "DT"), ("works", "VBZ")]);
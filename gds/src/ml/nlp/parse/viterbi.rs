@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use crate::collections::dataset::language::token::Token;
+use crate::collections::dataset::language::tree::{ProbabilisticTree, TreeValue};
+
+use super::cfg::{Cfg, ParseError};
+use super::cky::{CnfGrammar, CnfRhs};
+
+#[derive(Debug, Clone, Copy)]
+struct ViterbiEntry {
+    log_prob: f64,
+    production: usize,
+    split: Option<usize>,
+}
+
+/// Viterbi parser for probabilistic grammars.
+///
+/// Runs CKY over the [`CnfGrammar`] of the PCFG, keeping only the most probable
+/// derivation of each nonterminal per span, and returns the single most likely
+/// parse. Probabilities are accumulated in log space.
+#[derive(Debug, Clone)]
+pub struct ViterbiParser {
+    grammar: Cfg,
+    cnf: CnfGrammar,
+}
+
+impl ViterbiParser {
+    pub fn new(grammar: Cfg) -> Result<Self, ParseError> {
+        grammar.check_probabilities()?;
+        let cnf = CnfGrammar::from_cfg(&grammar);
+        Ok(Self { grammar, cnf })
+    }
+
+    pub fn grammar(&self) -> &Cfg {
+        &self.grammar
+    }
+
+    pub fn parse_tokens(&self, tokens: &[Token]) -> Result<Option<ProbabilisticTree>, ParseError> {
+        self.parse_token_texts(tokens.iter().map(|token| token.text().to_string()))
+    }
+
+    pub fn parse_token_texts<I, S>(
+        &self,
+        tokens: I,
+    ) -> Result<Option<ProbabilisticTree>, ParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let tokens = tokens.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.grammar
+            .check_coverage_texts(tokens.iter().map(String::as_str))?;
+        if tokens.is_empty() {
+            return Ok(None);
+        }
+
+        let chart = self.fill_chart(&tokens);
+        let start = self.cnf.start();
+        let Some(entry) = chart
+            .get(&(0, tokens.len()))
+            .and_then(|cell| cell.get(start))
+        else {
+            return Ok(None);
+        };
+
+        let children = self.children(&chart, &tokens, start, 0, tokens.len());
+        Ok(Some(ProbabilisticTree::new(
+            TreeValue::node(start, children),
+            entry.log_prob.exp(),
+        )))
+    }
+
+    fn fill_chart(
+        &self,
+        tokens: &[String],
+    ) -> HashMap<(usize, usize), HashMap<&str, ViterbiEntry>> {
+        let productions = self.cnf.productions();
+        let mut chart: HashMap<(usize, usize), HashMap<&str, ViterbiEntry>> = HashMap::new();
+
+        for (position, token) in tokens.iter().enumerate() {
+            let cell = chart.entry((position, position + 1)).or_default();
+            for (index, production) in productions.iter().enumerate() {
+                if matches!(production.rhs(), CnfRhs::Lexical(terminal) if terminal == token) {
+                    keep_best(
+                        cell,
+                        production.lhs(),
+                        ViterbiEntry {
+                            log_prob: production.prob().ln(),
+                            production: index,
+                            split: None,
+                        },
+                    );
+                }
+            }
+        }
+
+        for width in 2..=tokens.len() {
+            for start in 0..=tokens.len() - width {
+                let end = start + width;
+                let mut cell = HashMap::new();
+                for split in start + 1..end {
+                    let (Some(left), Some(right)) =
+                        (chart.get(&(start, split)), chart.get(&(split, end)))
+                    else {
+                        continue;
+                    };
+                    for (index, production) in productions.iter().enumerate() {
+                        let CnfRhs::Binary(b, c) = production.rhs() else {
+                            continue;
+                        };
+                        let (Some(left), Some(right)) =
+                            (left.get(b.as_str()), right.get(c.as_str()))
+                        else {
+                            continue;
+                        };
+                        keep_best(
+                            &mut cell,
+                            production.lhs(),
+                            ViterbiEntry {
+                                log_prob: production.prob().ln() + left.log_prob + right.log_prob,
+                                production: index,
+                                split: Some(split),
+                            },
+                        );
+                    }
+                }
+                if !cell.is_empty() {
+                    chart.insert((start, end), cell);
+                }
+            }
+        }
+
+        chart
+    }
+
+    fn children(
+        &self,
+        chart: &HashMap<(usize, usize), HashMap<&str, ViterbiEntry>>,
+        tokens: &[String],
+        symbol: &str,
+        start: usize,
+        end: usize,
+    ) -> Vec<TreeValue> {
+        let entry = chart[&(start, end)][symbol];
+        let production = &self.cnf.productions()[entry.production];
+        let children = match (production.rhs(), entry.split) {
+            (CnfRhs::Binary(left, right), Some(split)) => {
+                let mut children = self
+                    .cnf
+                    .wrap(left, self.children(chart, tokens, left, start, split));
+                children.extend(
+                    self.cnf
+                        .wrap(right, self.children(chart, tokens, right, split, end)),
+                );
+                children
+            }
+            _ => vec![TreeValue::leaf(tokens[start].clone())],
+        };
+        self.cnf.apply_chain(production, children)
+    }
+}
+
+fn keep_best<'a>(cell: &mut HashMap<&'a str, ViterbiEntry>, lhs: &'a str, entry: ViterbiEntry) {
+    match cell.get(lhs) {
+        Some(best) if best.log_prob >= entry.log_prob => {}
+        _ => {
+            cell.insert(lhs, entry);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::nlp::parse::cfg::{GrammarSymbol, Production};
+
+    fn rule(lhs: &str, rhs: &[&str], prob: f64) -> Production {
+        let rhs = rhs
+            .iter()
+            .map(|symbol| match symbol.strip_prefix('\'') {
+                Some(terminal) => GrammarSymbol::terminal(terminal),
+                None => GrammarSymbol::nonterminal(*symbol),
+            })
+            .collect();
+        Production::new(lhs, rhs).with_prob(prob)
+    }
+
+    fn pcfg() -> Cfg {
+        Cfg::new_probabilistic(
+            "S",
+            vec![
+                rule("S", &["NP", "VP"], 1.0),
+                rule("VP", &["V", "NP"], 0.7),
+                rule("VP", &["VP", "PP"], 0.3),
+                rule("NP", &["NP", "PP"], 0.2),
+                rule("NP", &["Det", "N"], 0.5),
+                rule("NP", &["Pro"], 0.3),
+                rule("PP", &["P", "NP"], 1.0),
+                rule("Pro", &["'I"], 1.0),
+                rule("Det", &["'the"], 1.0),
+                rule("N", &["'man"], 0.5),
+                rule("N", &["'telescope"], 0.5),
+                rule("V", &["'saw"], 1.0),
+                rule("P", &["'with"], 1.0),
+            ],
+        )
+        .expect("valid pcfg")
+    }
+
+    #[test]
+    fn viterbi_picks_most_probable_attachment() {
+        let parser = ViterbiParser::new(pcfg()).expect("probabilistic grammar");
+        let parse = parser
+            .parse_token_texts(["I", "saw", "the", "man", "with", "the", "telescope"])
+            .expect("parsing should run")
+            .expect("expected parse");
+
+        // VP attachment: 0.3 * 0.3 * 0.7 * (0.5 * 0.5)^2; NP attachment scores 0.002625.
+        assert!((parse.prob() - 0.0039375).abs() < 1e-12);
+        assert_eq!(
+            parse.tree().format_bracketed(),
+            "(S (NP (Pro I)) (VP (VP (V saw) (NP (Det the) (N man))) (PP (P with) (NP (Det the) (N telescope)))))"
+        );
+    }
+
+    #[test]
+    fn viterbi_requires_probabilities() {
+        let grammar = Cfg::new(
+            "S",
+            vec![Production::new("S", vec![GrammarSymbol::terminal("x")])],
+        )
+        .expect("valid grammar");
+        assert!(matches!(
+            ViterbiParser::new(grammar),
+            Err(ParseError::MissingProbability { .. })
+        ));
+    }
+
+    #[test]
+    fn viterbi_returns_none_without_parse() {
+        let parser = ViterbiParser::new(pcfg()).expect("probabilistic grammar");
+        assert!(parser
+            .parse_token_texts(["the", "man", "I"])
+            .expect("parsing should run")
+            .is_none());
+    }
+}
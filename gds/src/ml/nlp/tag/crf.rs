@@ -0,0 +1,535 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::collections::dataset::language::tag::Tag;
+use crate::collections::dataset::language::tagger::Tagger;
+use crate::collections::dataset::language::token::Token;
+
+use super::hmm::argmax;
+use super::perceptron::{tag_token_texts, PosTaggedSentence};
+
+/// Observation feature template for [`CrfTagger`]. Each template yields one
+/// feature string per position, which is paired with every tag.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CrfFeatureTemplate {
+    Bias,
+    /// Lowercased word at a relative offset; positions outside the sentence
+    /// read as `<s>` or `</s>`.
+    Word(isize),
+    /// First `n` characters of the current word.
+    Prefix(usize),
+    /// Last `n` characters of the current word.
+    Suffix(usize),
+    /// Character classes of the current word with runs collapsed, e.g. `Xx`, `d`.
+    Shape,
+}
+
+impl CrfFeatureTemplate {
+    pub fn default_templates() -> Vec<Self> {
+        vec![
+            Self::Bias,
+            Self::Word(-1),
+            Self::Word(0),
+            Self::Word(1),
+            Self::Prefix(1),
+            Self::Suffix(2),
+            Self::Suffix(3),
+            Self::Shape,
+        ]
+    }
+
+    fn extract(&self, words: &[String], position: usize) -> String {
+        let word = &words[position];
+        match self {
+            Self::Bias => "bias".to_string(),
+            Self::Word(offset) => {
+                let text = match position.checked_add_signed(*offset) {
+                    Some(index) if index < words.len() => words[index].to_lowercase(),
+                    _ if *offset < 0 => "<s>".to_string(),
+                    _ => "</s>".to_string(),
+                };
+                format!("w[{offset}]={text}")
+            }
+            Self::Prefix(n) => format!("prefix{n}={}", word.chars().take(*n).collect::<String>()),
+            Self::Suffix(n) => {
+                let chars = word.chars().collect::<Vec<_>>();
+                let suffix = chars[chars.len().saturating_sub(*n)..]
+                    .iter()
+                    .collect::<String>();
+                format!("suffix{n}={suffix}")
+            }
+            Self::Shape => format!("shape={}", word_shape(word)),
+        }
+    }
+}
+
+/// Collapse a word to its character classes: `X` upper, `x` lower, `d` digit,
+/// other characters kept as is. Consecutive repeats are merged.
+pub fn word_shape(word: &str) -> String {
+    let mut shape = String::new();
+    for c in word.chars() {
+        let class = if c.is_uppercase() {
+            'X'
+        } else if c.is_lowercase() {
+            'x'
+        } else if c.is_ascii_digit() {
+            'd'
+        } else {
+            c
+        };
+        if !shape.ends_with(class) {
+            shape.push(class);
+        }
+    }
+    shape
+}
+
+/// Stochastic gradient settings for [`CrfTagger::train`].
+#[derive(Debug, Clone)]
+pub struct CrfTrainingConfig {
+    pub epochs: usize,
+    pub learning_rate: f64,
+    /// L2 penalty, applied to the weights touched by each sentence.
+    pub l2: f64,
+}
+
+impl Default for CrfTrainingConfig {
+    fn default() -> Self {
+        Self {
+            epochs: 20,
+            learning_rate: 0.1,
+            l2: 1e-4,
+        }
+    }
+}
+
+/// Linear-chain conditional random field tagger.
+///
+/// Observation features come from [`CrfFeatureTemplate`]s and are weighted per
+/// tag; tag bigrams carry transition weights. Training maximizes the
+/// conditional log-likelihood by SGD, with expectations from forward-backward.
+/// Decoding is Viterbi over the same scores.
+#[derive(Debug, Clone)]
+pub struct CrfTagger {
+    templates: Vec<CrfFeatureTemplate>,
+    config: CrfTrainingConfig,
+    tags: Vec<String>,
+    state_weights: HashMap<String, Vec<f64>>,
+    start_weights: Vec<f64>,
+    transition_weights: Vec<Vec<f64>>,
+}
+
+impl Default for CrfTagger {
+    fn default() -> Self {
+        Self {
+            templates: CrfFeatureTemplate::default_templates(),
+            config: CrfTrainingConfig::default(),
+            tags: Vec::new(),
+            state_weights: HashMap::new(),
+            start_weights: Vec::new(),
+            transition_weights: Vec::new(),
+        }
+    }
+}
+
+impl CrfTagger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_templates(mut self, templates: Vec<CrfFeatureTemplate>) -> Self {
+        self.templates = templates;
+        self
+    }
+
+    pub fn with_config(mut self, config: CrfTrainingConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn templates(&self) -> &[CrfFeatureTemplate] {
+        &self.templates
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn train(&mut self, sentences: &[PosTaggedSentence]) {
+        self.tags = sentences
+            .iter()
+            .flatten()
+            .map(|(_, tag)| tag.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let n = self.tags.len();
+        let index = self
+            .tags
+            .iter()
+            .enumerate()
+            .map(|(i, tag)| (tag.clone(), i))
+            .collect::<HashMap<_, _>>();
+
+        let examples = sentences
+            .iter()
+            .filter(|sentence| !sentence.is_empty())
+            .map(|sentence| {
+                let words = sentence.iter().map(|(w, _)| w.clone()).collect::<Vec<_>>();
+                let gold = sentence.iter().map(|(_, t)| index[t]).collect::<Vec<_>>();
+                (self.features(&words), gold)
+            })
+            .collect::<Vec<_>>();
+
+        self.state_weights = HashMap::new();
+        for feature in examples
+            .iter()
+            .flat_map(|(features, _)| features.iter().flatten())
+        {
+            self.state_weights
+                .entry(feature.clone())
+                .or_insert_with(|| vec![0.0; n]);
+        }
+        self.start_weights = vec![0.0; n];
+        self.transition_weights = vec![vec![0.0; n]; n];
+
+        for _ in 0..self.config.epochs {
+            for (features, gold) in &examples {
+                self.sgd_step(features, gold);
+            }
+        }
+    }
+
+    pub fn tag(&self, tokens: &[String]) -> PosTaggedSentence {
+        if self.tags.is_empty() {
+            return tokens
+                .iter()
+                .map(|word| (word.clone(), "UNK".to_string()))
+                .collect();
+        }
+
+        let path = self.viterbi(&self.emissions(&self.features(tokens)));
+        tokens
+            .iter()
+            .zip(path)
+            .map(|(word, tag)| (word.clone(), self.tags[tag].clone()))
+            .collect()
+    }
+
+    /// Conditional log-likelihood of gold sentences under the current weights.
+    /// Sentences containing tags unseen in training are skipped.
+    pub fn log_likelihood(&self, sentences: &[PosTaggedSentence]) -> f64 {
+        let mut total = 0.0;
+        for sentence in sentences.iter().filter(|sentence| !sentence.is_empty()) {
+            let Some(gold) = sentence
+                .iter()
+                .map(|(_, tag)| self.tags.iter().position(|known| known == tag))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            let words = sentence.iter().map(|(w, _)| w.clone()).collect::<Vec<_>>();
+            let emissions = self.emissions(&self.features(&words));
+            let (alpha, _) = self.forward_backward(&emissions);
+            total += self.path_score(&emissions, &gold) - log_sum_exp(&alpha[alpha.len() - 1]);
+        }
+        total
+    }
+
+    fn features(&self, words: &[String]) -> Vec<Vec<String>> {
+        (0..words.len())
+            .map(|position| {
+                self.templates
+                    .iter()
+                    .map(|template| template.extract(words, position))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn emissions(&self, features: &[Vec<String>]) -> Vec<Vec<f64>> {
+        let n = self.tags.len();
+        features
+            .iter()
+            .map(|position| {
+                let mut scores = vec![0.0; n];
+                for weights in position.iter().filter_map(|f| self.state_weights.get(f)) {
+                    for (score, weight) in scores.iter_mut().zip(weights) {
+                        *score += weight;
+                    }
+                }
+                scores
+            })
+            .collect()
+    }
+
+    fn path_score(&self, emissions: &[Vec<f64>], path: &[usize]) -> f64 {
+        let mut score = self.start_weights[path[0]];
+        for (position, &tag) in path.iter().enumerate() {
+            score += emissions[position][tag];
+            if position > 0 {
+                score += self.transition_weights[path[position - 1]][tag];
+            }
+        }
+        score
+    }
+
+    /// Log-space forward and backward tables.
+    fn forward_backward(&self, emissions: &[Vec<f64>]) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+        let n = self.tags.len();
+        let len = emissions.len();
+
+        let mut alpha = Vec::with_capacity(len);
+        alpha.push(
+            self.start_weights
+                .iter()
+                .zip(&emissions[0])
+                .map(|(start, emit)| start + emit)
+                .collect::<Vec<_>>(),
+        );
+        for emit in &emissions[1..] {
+            let prev_alpha = &alpha[alpha.len() - 1];
+            let next = emit
+                .iter()
+                .enumerate()
+                .map(|(tag, emit)| {
+                    let incoming = prev_alpha
+                        .iter()
+                        .zip(&self.transition_weights)
+                        .map(|(score, row)| score + row[tag])
+                        .collect::<Vec<_>>();
+                    log_sum_exp(&incoming) + emit
+                })
+                .collect();
+            alpha.push(next);
+        }
+
+        let mut beta = vec![vec![0.0; n]; len];
+        for position in (0..len - 1).rev() {
+            let ahead = emissions[position + 1]
+                .iter()
+                .zip(&beta[position + 1])
+                .map(|(emit, beta)| emit + beta)
+                .collect::<Vec<_>>();
+            beta[position] = self
+                .transition_weights
+                .iter()
+                .map(|row| {
+                    let outgoing = row
+                        .iter()
+                        .zip(&ahead)
+                        .map(|(transition, ahead)| transition + ahead)
+                        .collect::<Vec<_>>();
+                    log_sum_exp(&outgoing)
+                })
+                .collect();
+        }
+
+        (alpha, beta)
+    }
+
+    /// One gradient ascent step on a single sentence: observed minus expected
+    /// feature counts, with L2 shrinkage on the weights involved.
+    fn sgd_step(&mut self, features: &[Vec<String>], gold: &[usize]) {
+        let n = self.tags.len();
+        let rate = self.config.learning_rate;
+        let decay = 1.0 - rate * self.config.l2;
+        let emissions = self.emissions(features);
+        let (alpha, beta) = self.forward_backward(&emissions);
+        let log_z = log_sum_exp(&alpha[alpha.len() - 1]);
+
+        let marginal = |position: usize, tag: usize| {
+            (alpha[position][tag] + beta[position][tag] - log_z).exp()
+        };
+
+        for (tag, weight) in self.start_weights.iter_mut().enumerate() {
+            let observed = if gold[0] == tag { 1.0 } else { 0.0 };
+            *weight = *weight * decay + rate * (observed - marginal(0, tag));
+        }
+
+        for (position, position_features) in features.iter().enumerate() {
+            for feature in position_features {
+                let weights = self
+                    .state_weights
+                    .get_mut(feature)
+                    .expect("training features are registered before SGD");
+                for (tag, weight) in weights.iter_mut().enumerate() {
+                    let observed = if gold[position] == tag { 1.0 } else { 0.0 };
+                    *weight = *weight * decay + rate * (observed - marginal(position, tag));
+                }
+            }
+        }
+
+        let mut gradient = vec![vec![0.0; n]; n];
+        for position in 1..gold.len() {
+            gradient[gold[position - 1]][gold[position]] += 1.0;
+            for (prev, row) in gradient.iter_mut().enumerate() {
+                for (tag, cell) in row.iter_mut().enumerate() {
+                    *cell -= (alpha[position - 1][prev]
+                        + self.transition_weights[prev][tag]
+                        + emissions[position][tag]
+                        + beta[position][tag]
+                        - log_z)
+                        .exp();
+                }
+            }
+        }
+        for (weights, gradients) in self.transition_weights.iter_mut().zip(&gradient) {
+            for (weight, gradient) in weights.iter_mut().zip(gradients) {
+                *weight = *weight * decay + rate * gradient;
+            }
+        }
+    }
+
+    fn viterbi(&self, emissions: &[Vec<f64>]) -> Vec<usize> {
+        let Some(first) = emissions.first() else {
+            return Vec::new();
+        };
+        let n = self.tags.len();
+        let mut scores = self
+            .start_weights
+            .iter()
+            .zip(first)
+            .map(|(start, emit)| start + emit)
+            .collect::<Vec<_>>();
+        let mut backpointers = Vec::with_capacity(emissions.len());
+
+        for emit in &emissions[1..] {
+            let mut next = vec![f64::NEG_INFINITY; n];
+            let mut back = vec![0usize; n];
+            for tag in 0..n {
+                for (prev, score) in scores.iter().enumerate() {
+                    let candidate = score + self.transition_weights[prev][tag];
+                    if candidate > next[tag] {
+                        next[tag] = candidate;
+                        back[tag] = prev;
+                    }
+                }
+                next[tag] += emit[tag];
+            }
+            scores = next;
+            backpointers.push(back);
+        }
+
+        let mut best = argmax(&scores);
+        let mut path = vec![best];
+        for back in backpointers.iter().rev() {
+            best = back[best];
+            path.push(best);
+        }
+        path.reverse();
+        path
+    }
+}
+
+impl Tagger for CrfTagger {
+    fn tag_tokens(&self, tokens: &[Token]) -> Vec<Tag> {
+        tag_token_texts(tokens, |words| self.tag(words))
+    }
+}
+
+fn log_sum_exp(values: &[f64]) -> f64 {
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if max == f64::NEG_INFINITY {
+        return max;
+    }
+    max + values.iter().map(|v| (v - max).exp()).sum::<f64>().ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::nlp::tag::perceptron::pos_tagged_to_tags;
+
+    fn sentence(pairs: &[(&str, &str)]) -> PosTaggedSentence {
+        pairs
+            .iter()
+            .map(|(word, tag)| (word.to_string(), tag.to_string()))
+            .collect()
+    }
+
+    fn words(text: &str) -> Vec<String> {
+        text.split_whitespace().map(str::to_string).collect()
+    }
+
+    fn corpus() -> Vec<PosTaggedSentence> {
+        vec![
+            sentence(&[("John", "NNP"), ("is", "VBZ"), ("running", "VBG")]),
+            sentence(&[("Mary", "NNP"), ("is", "VBZ"), ("singing", "VBG")]),
+            sentence(&[
+                ("the", "DT"),
+                ("dog", "NN"),
+                ("is", "VBZ"),
+                ("eating", "VBG"),
+            ]),
+            sentence(&[("the", "DT"), ("cat", "NN"), ("sleeps", "VBZ")]),
+        ]
+    }
+
+    #[test]
+    fn crf_fits_training_data_and_likelihood_improves() {
+        let mut short = CrfTagger::new().with_config(CrfTrainingConfig {
+            epochs: 1,
+            ..CrfTrainingConfig::default()
+        });
+        short.train(&corpus());
+        let mut tagger = CrfTagger::new();
+        tagger.train(&corpus());
+
+        assert!(tagger.log_likelihood(&corpus()) > short.log_likelihood(&corpus()));
+        assert!(tagger.log_likelihood(&corpus()) < 0.0);
+
+        let gold = corpus()
+            .iter()
+            .map(|sentence| pos_tagged_to_tags(sentence))
+            .collect::<Vec<_>>();
+        assert_eq!(tagger.accuracy(&gold), 1.0);
+        let report = tagger.per_tag_accuracy(&gold);
+        assert_eq!(report["VBZ"].total, 4);
+        assert_eq!(report["VBZ"].correct, 4);
+    }
+
+    #[test]
+    fn crf_generalizes_through_shape_and_suffix_features() {
+        let mut tagger = CrfTagger::new();
+        tagger.train(&corpus());
+
+        let tagged = tagger.tag(&words("Alice is jumping"));
+        let tags = tagged
+            .iter()
+            .map(|(_, tag)| tag.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(tags, vec!["NNP", "VBZ", "VBG"]);
+    }
+
+    #[test]
+    fn feature_templates_extract_expected_strings() {
+        let words = words("The cat");
+        let extracted = CrfFeatureTemplate::default_templates()
+            .iter()
+            .map(|template| template.extract(&words, 0))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            extracted,
+            vec![
+                "bias",
+                "w[-1]=<s>",
+                "w[0]=the",
+                "w[1]=cat",
+                "prefix1=T",
+                "suffix2=he",
+                "suffix3=The",
+                "shape=Xx",
+            ]
+        );
+        assert_eq!(word_shape("McDonald's"), "XxXx'x");
+        assert_eq!(word_shape("2024"), "d");
+        assert_eq!(word_shape("U.S."), "X.X.");
+    }
+
+    #[test]
+    fn untrained_crf_tags_unknown() {
+        let tagged = CrfTagger::new().tag(&words("hello"));
+        assert_eq!(tagged, vec![("hello".to_string(), "UNK".to_string())]);
+        assert!(CrfTagger::new().tag(&[]).is_empty());
+    }
+}
@@ -0,0 +1,287 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::collections::dataset::language::tag::Tag;
+use crate::collections::dataset::language::tagger::Tagger;
+use crate::collections::dataset::language::token::Token;
+
+use super::perceptron::{tag_token_texts, PosTaggedSentence};
+
+/// Supervised first-order hidden Markov model tagger.
+///
+/// Start, transition and emission distributions are relative frequencies with
+/// Lidstone (add-γ) smoothing, so unseen transitions and unknown words keep a
+/// small non-zero probability. Sentences are decoded with Viterbi in log space.
+#[derive(Debug, Clone)]
+pub struct HmmTagger {
+    smoothing: f64,
+    tags: Vec<String>,
+    start: Vec<f64>,
+    transitions: Vec<Vec<f64>>,
+    emissions: HashMap<String, Vec<f64>>,
+    unseen: Vec<f64>,
+}
+
+impl Default for HmmTagger {
+    fn default() -> Self {
+        Self {
+            smoothing: Self::DEFAULT_SMOOTHING,
+            tags: Vec::new(),
+            start: Vec::new(),
+            transitions: Vec::new(),
+            emissions: HashMap::new(),
+            unseen: Vec::new(),
+        }
+    }
+}
+
+impl HmmTagger {
+    pub const DEFAULT_SMOOTHING: f64 = 0.1;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the Lidstone γ added to every count. Must be positive.
+    pub fn with_smoothing(mut self, smoothing: f64) -> Self {
+        assert!(smoothing > 0.0, "HMM smoothing must be positive");
+        self.smoothing = smoothing;
+        self
+    }
+
+    pub fn smoothing(&self) -> f64 {
+        self.smoothing
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn train(&mut self, sentences: &[PosTaggedSentence]) {
+        self.tags = sentences
+            .iter()
+            .flatten()
+            .map(|(_, tag)| tag.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let index = self
+            .tags
+            .iter()
+            .enumerate()
+            .map(|(i, tag)| (tag.as_str(), i))
+            .collect::<HashMap<_, _>>();
+        let n = self.tags.len();
+
+        let mut start_counts = vec![0usize; n];
+        let mut transition_counts = vec![vec![0usize; n]; n];
+        let mut emission_counts = HashMap::<String, Vec<usize>>::new();
+        let mut tag_counts = vec![0usize; n];
+
+        for sentence in sentences {
+            let mut prev = None::<usize>;
+            for (word, tag) in sentence {
+                let current = index[tag.as_str()];
+                match prev {
+                    None => start_counts[current] += 1,
+                    Some(prev) => transition_counts[prev][current] += 1,
+                }
+                emission_counts
+                    .entry(word.clone())
+                    .or_insert_with(|| vec![0; n])[current] += 1;
+                tag_counts[current] += 1;
+                prev = Some(current);
+            }
+        }
+
+        let gamma = self.smoothing;
+        self.start = lidstone(&start_counts, gamma);
+        self.transitions = transition_counts
+            .iter()
+            .map(|row| lidstone(row, gamma))
+            .collect();
+
+        // One extra outcome per tag is reserved for unknown words.
+        let outcomes = (emission_counts.len() + 1) as f64;
+        let denominators = tag_counts
+            .iter()
+            .map(|&count| count as f64 + gamma * outcomes)
+            .collect::<Vec<_>>();
+        self.unseen = denominators.iter().map(|d| (gamma / d).ln()).collect();
+        self.emissions = emission_counts
+            .into_iter()
+            .map(|(word, counts)| {
+                let log_probs = counts
+                    .iter()
+                    .zip(&denominators)
+                    .map(|(&count, d)| ((count as f64 + gamma) / d).ln())
+                    .collect();
+                (word, log_probs)
+            })
+            .collect();
+    }
+
+    pub fn tag(&self, tokens: &[String]) -> PosTaggedSentence {
+        if self.tags.is_empty() {
+            return tokens
+                .iter()
+                .map(|word| (word.clone(), "UNK".to_string()))
+                .collect();
+        }
+
+        let path = self.viterbi(tokens);
+        tokens
+            .iter()
+            .zip(path)
+            .map(|(word, tag)| (word.clone(), self.tags[tag].clone()))
+            .collect()
+    }
+
+    fn emission(&self, word: &str) -> &[f64] {
+        self.emissions
+            .get(word)
+            .map(Vec::as_slice)
+            .unwrap_or(&self.unseen)
+    }
+
+    fn viterbi(&self, tokens: &[String]) -> Vec<usize> {
+        let Some(first) = tokens.first() else {
+            return Vec::new();
+        };
+        let n = self.tags.len();
+        let mut scores = self
+            .start
+            .iter()
+            .zip(self.emission(first))
+            .map(|(start, emit)| start + emit)
+            .collect::<Vec<_>>();
+        let mut backpointers = Vec::with_capacity(tokens.len());
+
+        for word in &tokens[1..] {
+            let emit = self.emission(word);
+            let mut next = vec![f64::NEG_INFINITY; n];
+            let mut back = vec![0usize; n];
+            for current in 0..n {
+                for (prev, score) in scores.iter().enumerate() {
+                    let candidate = score + self.transitions[prev][current];
+                    if candidate > next[current] {
+                        next[current] = candidate;
+                        back[current] = prev;
+                    }
+                }
+                next[current] += emit[current];
+            }
+            scores = next;
+            backpointers.push(back);
+        }
+
+        let mut best = argmax(&scores);
+        let mut path = vec![best];
+        for back in backpointers.iter().rev() {
+            best = back[best];
+            path.push(best);
+        }
+        path.reverse();
+        path
+    }
+}
+
+impl Tagger for HmmTagger {
+    fn tag_tokens(&self, tokens: &[Token]) -> Vec<Tag> {
+        tag_token_texts(tokens, |words| self.tag(words))
+    }
+}
+
+/// Log probabilities of add-γ smoothed counts.
+fn lidstone(counts: &[usize], gamma: f64) -> Vec<f64> {
+    let total = counts.iter().sum::<usize>() as f64 + gamma * counts.len() as f64;
+    counts
+        .iter()
+        .map(|&count| ((count as f64 + gamma) / total).ln())
+        .collect()
+}
+
+pub(crate) fn argmax(scores: &[f64]) -> usize {
+    let mut best = 0;
+    for (index, score) in scores.iter().enumerate() {
+        if *score > scores[best] {
+            best = index;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::nlp::tag::perceptron::pos_tagged_to_tags;
+
+    fn sentence(pairs: &[(&str, &str)]) -> PosTaggedSentence {
+        pairs
+            .iter()
+            .map(|(word, tag)| (word.to_string(), tag.to_string()))
+            .collect()
+    }
+
+    fn words(text: &str) -> Vec<String> {
+        text.split_whitespace().map(str::to_string).collect()
+    }
+
+    fn corpus() -> Vec<PosTaggedSentence> {
+        vec![
+            sentence(&[("I", "PRP"), ("can", "MD"), ("fish", "VB")]),
+            sentence(&[("a", "DT"), ("can", "NN"), ("rusts", "VBZ")]),
+            sentence(&[("I", "PRP"), ("fish", "VB")]),
+            sentence(&[("the", "DT"), ("fish", "NN"), ("swims", "VBZ")]),
+        ]
+    }
+
+    fn tags_of(tagged: &PosTaggedSentence) -> Vec<&str> {
+        tagged.iter().map(|(_, tag)| tag.as_str()).collect()
+    }
+
+    #[test]
+    fn hmm_uses_context_to_disambiguate() {
+        let mut tagger = HmmTagger::new();
+        tagger.train(&corpus());
+
+        assert_eq!(tagger.tags().len(), 6);
+        assert_eq!(
+            tags_of(&tagger.tag(&words("I can fish"))),
+            vec!["PRP", "MD", "VB"]
+        );
+        assert_eq!(
+            tags_of(&tagger.tag(&words("the can rusts"))),
+            vec!["DT", "NN", "VBZ"]
+        );
+    }
+
+    #[test]
+    fn hmm_smoothing_handles_unknown_words_and_transitions() {
+        let mut tagger = HmmTagger::new().with_smoothing(0.5);
+        tagger.train(&corpus());
+
+        assert_eq!(
+            tags_of(&tagger.tag(&words("a dog swims"))),
+            vec!["DT", "NN", "VBZ"]
+        );
+        // "swims I" never occurs in training but still decodes.
+        assert_eq!(tagger.tag(&words("swims I")).len(), 2);
+        assert!(tagger.tag(&[]).is_empty());
+    }
+
+    #[test]
+    fn hmm_reports_per_tag_accuracy() {
+        let mut tagger = HmmTagger::new();
+        tagger.train(&corpus());
+
+        let gold = corpus()
+            .iter()
+            .map(|sentence| pos_tagged_to_tags(sentence))
+            .collect::<Vec<_>>();
+        let report = tagger.per_tag_accuracy(&gold);
+        assert_eq!(report.len(), 6);
+        assert_eq!(report["VB"].total, 2);
+        assert!(report.values().all(|entry| entry.accuracy() == 1.0));
+        assert_eq!(tagger.accuracy(&gold), 1.0);
+    }
+}
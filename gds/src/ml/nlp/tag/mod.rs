@@ -1,3 +1,7 @@
+pub mod crf;
+pub mod hmm;
 pub mod perceptron;
 
+pub use crf::*;
+pub use hmm::*;
 pub use perceptron::*;
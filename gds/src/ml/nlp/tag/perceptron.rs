@@ -5,9 +5,45 @@ use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use crate::collections::dataset::language::tag::Tag;
+use crate::collections::dataset::language::tagger::Tagger;
+use crate::collections::dataset::language::token::{Token, TokenSpan};
+
 pub type PosTaggedToken = (String, String);
 pub type PosTaggedSentence = Vec<PosTaggedToken>;
 
+/// Convert a `(word, tag)` sentence into [`Tag`]s, with spans laid out as if the
+/// words were joined by single spaces. This is the gold format expected by
+/// [`Tagger::accuracy`] and [`Tagger::per_tag_accuracy`].
+pub fn pos_tagged_to_tags(sentence: &[PosTaggedToken]) -> Vec<Tag> {
+    let mut offset = 0usize;
+    sentence
+        .iter()
+        .map(|(word, tag)| {
+            let span = TokenSpan::new(offset, offset + word.len());
+            offset = span.end() + 1;
+            Tag::new(word.clone(), tag.clone(), span)
+        })
+        .collect()
+}
+
+/// Tag a token slice with a word-level tagging function, keeping each token's
+/// text and span.
+pub(crate) fn tag_token_texts(
+    tokens: &[Token],
+    tag: impl FnOnce(&[String]) -> PosTaggedSentence,
+) -> Vec<Tag> {
+    let words = tokens
+        .iter()
+        .map(|token| token.text().to_string())
+        .collect::<Vec<_>>();
+    tag(&words)
+        .into_iter()
+        .zip(tokens)
+        .map(|((word, tag), token)| Tag::new(word, tag, token.span()))
+        .collect()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct PerceptronTaggerState {
     weights: HashMap<String, HashMap<String, f64>>,
@@ -71,6 +107,26 @@ impl AveragedPerceptron {
         best_label.unwrap_or_else(|| "UNK".to_string())
     }
 
+    /// Score of every known class, for callers that restrict the argmax
+    /// (e.g. to the transitions valid in a parser state).
+    pub fn scores(&self, features: &HashMap<String, f64>) -> HashMap<String, f64> {
+        let mut scores = self
+            .classes
+            .iter()
+            .map(|class| (class.clone(), 0.0))
+            .collect::<HashMap<_, _>>();
+        for (feature, value) in features {
+            if let Some(weights) = self.weights.get(feature) {
+                for (label, weight) in weights {
+                    if let Some(score) = scores.get_mut(label) {
+                        *score += value * weight;
+                    }
+                }
+            }
+        }
+        scores
+    }
+
     pub fn update(&mut self, truth: &str, guess: &str, features: &HashMap<String, f64>) {
         self.i += 1;
         if truth == guess {
@@ -390,6 +446,12 @@ impl PerceptronTagger {
     }
 }
 
+impl Tagger for PerceptronTagger {
+    fn tag_tokens(&self, tokens: &[Token]) -> Vec<Tag> {
+        tag_token_texts(tokens, |words| self.tag(words))
+    }
+}
+
 fn collect_classes(sentences: &[PosTaggedSentence]) -> BTreeSet<String> {
     let mut out = BTreeSet::new();
     for sentence in sentences {
@@ -469,6 +531,23 @@ mod tests {
         assert!(tagger.accuracy(&train) > 0.0);
     }
 
+    #[test]
+    fn perceptron_tagger_implements_tagger_trait() {
+        let train = vec![vec![
+            ("The".to_string(), "DT".to_string()),
+            ("cat".to_string(), "NN".to_string()),
+        ]];
+        let mut tagger = PerceptronTagger::new();
+        tagger.train(&train, 3);
+
+        let gold = train
+            .iter()
+            .map(|s| pos_tagged_to_tags(s))
+            .collect::<Vec<_>>();
+        assert_eq!(gold[0][1].span(), TokenSpan::new(4, 7));
+        assert_eq!(Tagger::accuracy(&tagger, &gold), tagger.accuracy(&train));
+    }
+
     #[test]
     fn perceptron_tagger_json_roundtrip() {
         let train = vec![
@@ -1,8 +1,10 @@
 use crate::ml::metrics::Metric;
+use crate::ml::metrics::MetricComparator;
 use crate::ml::metrics::MetricConsumer;
 use crate::ml::metrics::ModelCandidateStats;
 use crate::ml::metrics::ModelSpecificMetricsHandler;
 use crate::ml::metrics::ModelStatsBuilder;
+use crate::ml::models::automl::HyperParameterOptimizer;
 use crate::ml::models::TrainerConfig;
 use crate::ml::splitting::StratifiedKFoldSplitter;
 use parking_lot::RwLock;
@@ -55,6 +57,27 @@ impl MetricConsumer for LockedStatsConsumer {
     }
 }
 
+/// Forwards validation metrics while capturing the main metric of the fold,
+/// oriented so that higher is better.
+struct FoldScoreConsumer {
+    inner: LockedStatsConsumer,
+    main_metric: String,
+    comparator: MetricComparator,
+    score: Option<f64>,
+}
+
+impl MetricConsumer for FoldScoreConsumer {
+    fn consume(&mut self, metric: &dyn Metric, value: f64) {
+        if metric.name() == self.main_metric {
+            self.score = Some(match self.comparator {
+                MetricComparator::Natural => value,
+                MetricComparator::Inverse => -value,
+            });
+        }
+        self.inner.consume(metric, value);
+    }
+}
+
 impl<MODEL> CrossValidation<MODEL> {
     /// Creates a new CrossValidation instance
     pub fn new(
@@ -75,15 +98,19 @@ impl<MODEL> CrossValidation<MODEL> {
         }
     }
 
-    /// Selects the best model through cross validation
+    /// Selects the best model through cross validation.
+    ///
+    /// Trials are drawn from the optimizer one at a time and their fold scores are reported
+    /// back, so adaptive optimizers can steer later proposals. Trials the optimizer prunes
+    /// are not recorded in the training statistics. Returns the config of the best trial.
     pub fn select_model(
         &self,
         outer_train_set: ReadOnlyHugeLongArray,
         targets: impl Fn(u64) -> i64 + Send + Sync + 'static,
         distinct_internal_targets: BTreeSet<i64>,
         training_statistics: &mut TrainingStatistics,
-        model_candidates: impl Iterator<Item = Box<dyn TrainerConfig>>,
-    ) {
+        optimizer: &mut dyn HyperParameterOptimizer,
+    ) -> Option<Box<dyn TrainerConfig>> {
         log::debug!("Creating validation folds");
 
         let validation_splits = StratifiedKFoldSplitter::new(
@@ -97,11 +124,17 @@ impl<MODEL> CrossValidation<MODEL> {
 
         log::debug!("Selecting best model");
 
-        for (trial, model_params) in model_candidates.enumerate() {
-            log::debug!("Starting trial {}", trial + 1);
+        let main_metric = training_statistics.evaluation_metric().to_string();
+        let comparator = training_statistics.evaluation_comparator();
+        let mut candidate_configs: Vec<Box<dyn TrainerConfig>> = Vec::new();
+        let mut trial = 0;
+
+        while let Some(model_params) = optimizer.next() {
+            trial += 1;
+            log::debug!("Starting trial {}", trial);
 
             if *self.termination_flag.read() {
-                return;
+                break;
             }
 
             log::info!(
@@ -117,6 +150,8 @@ impl<MODEL> CrossValidation<MODEL> {
                 &self.metrics,
                 validation_stats_builder.clone(),
             );
+            let mut fold_scores = Vec::with_capacity(validation_splits.len());
+            let mut pruned = false;
 
             for (fold, split) in validation_splits.iter().enumerate() {
                 let train_set = split.train_set();
@@ -131,14 +166,37 @@ impl<MODEL> CrossValidation<MODEL> {
                 );
                 log::debug!("Finished fold {} training", fold + 1);
 
-                let mut validation_consumer =
-                    LockedStatsConsumer::new(validation_stats_builder.clone());
+                let mut validation_consumer = FoldScoreConsumer {
+                    inner: LockedStatsConsumer::new(validation_stats_builder.clone()),
+                    main_metric: main_metric.clone(),
+                    comparator,
+                    score: None,
+                };
                 (self.model_evaluator)(validation_set, &trained_model, &mut validation_consumer);
                 (self.model_evaluator)(
                     train_set,
                     &trained_model,
                     &mut train_stats_builder as &mut dyn MetricConsumer,
                 );
+
+                if let Some(score) = validation_consumer.score {
+                    fold_scores.push(score);
+                }
+                if fold + 1 < validation_splits.len() && optimizer.should_prune(&fold_scores) {
+                    pruned = true;
+                    break;
+                }
+            }
+
+            optimizer.report(&fold_scores);
+            if pruned {
+                log::info!(
+                    "Pruned trial {} after {} of {} folds",
+                    trial,
+                    fold_scores.len(),
+                    validation_splits.len()
+                );
+                continue;
             }
 
             let candidate_stats = ModelCandidateStats::new(
@@ -150,10 +208,12 @@ impl<MODEL> CrossValidation<MODEL> {
                     .build(),
             );
             training_statistics.add_candidate_stats(candidate_stats);
+            let candidate = candidate_configs.len();
+            candidate_configs.push(model_params);
 
-            let validation_stats = training_statistics.validation_metrics_avg(trial);
-            let train_stats = training_statistics.train_metrics_avg(trial);
-            let main_metric = training_statistics.get_main_metric(trial);
+            let validation_stats = training_statistics.validation_metrics_avg(candidate);
+            let train_stats = training_statistics.train_metrics_avg(candidate);
+            let main_metric = training_statistics.get_main_metric(candidate);
 
             log::info!(
                 "Main validation metric ({}): {:.4}",
@@ -163,15 +223,20 @@ impl<MODEL> CrossValidation<MODEL> {
             log::info!("Validation metrics: {:?}", validation_stats);
             log::info!("Training metrics: {:?}", train_stats);
 
-            log::debug!("Completed trial {}", trial + 1);
+            log::debug!("Completed trial {}", trial);
+        }
+
+        if candidate_configs.is_empty() {
+            return None;
         }
 
-        let best_trial = training_statistics.best_trial_idx() + 1;
+        let best_candidate = training_statistics.best_trial_idx();
         let best_trial_score = training_statistics.best_trial_score();
         log::info!(
-            "Best trial was Trial {} with main validation metric {:.4}",
-            best_trial,
+            "Best candidate was Candidate {} with main validation metric {:.4}",
+            best_candidate + 1,
             best_trial_score
         );
+        Some(candidate_configs.swap_remove(best_candidate))
     }
 }
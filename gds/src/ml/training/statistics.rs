@@ -1,8 +1,12 @@
+use crate::ml::explain::FeatureImportance;
+use crate::ml::explain::FeatureImportanceKind;
+use crate::ml::metrics::ConfusionMatrix;
 use crate::ml::metrics::EvaluationScores;
 use crate::ml::metrics::Metric;
 use crate::ml::metrics::MetricComparator;
 use crate::ml::metrics::ModelCandidateStats;
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -18,6 +22,8 @@ pub struct TrainingStatistics {
     metrics: Vec<MetricSpec>,
     test_scores: HashMap<String, f64>,
     outer_train_scores: HashMap<String, f64>,
+    confusion_matrices: BTreeMap<String, ConfusionMatrix>,
+    feature_importances: BTreeMap<FeatureImportanceKind, FeatureImportance>,
 }
 
 impl TrainingStatistics {
@@ -35,6 +41,8 @@ impl TrainingStatistics {
             metrics: metric_specs,
             test_scores: HashMap::new(),
             outer_train_scores: HashMap::new(),
+            confusion_matrices: BTreeMap::new(),
+            feature_importances: BTreeMap::new(),
         }
     }
 
@@ -62,6 +70,37 @@ impl TrainingStatistics {
         self.outer_train_scores.insert(metric_name, score);
     }
 
+    /// Adds the confusion matrix of the winning model on a split, e.g. `test`
+    pub fn add_confusion_matrix(&mut self, split: impl Into<String>, matrix: ConfusionMatrix) {
+        self.confusion_matrices.insert(split.into(), matrix);
+    }
+
+    /// Gets the confusion matrix of the winning model on a split
+    pub fn confusion_matrix(&self, split: &str) -> Option<&ConfusionMatrix> {
+        self.confusion_matrices.get(split)
+    }
+
+    /// Adds a feature importance of the winning model, replacing one of the same kind
+    pub fn add_feature_importance(&mut self, importance: FeatureImportance) {
+        self.feature_importances
+            .insert(importance.kind(), importance);
+    }
+
+    /// Gets the feature importance of the winning model of the given kind
+    pub fn feature_importance(&self, kind: FeatureImportanceKind) -> Option<&FeatureImportance> {
+        self.feature_importances.get(&kind)
+    }
+
+    /// Renders all feature importances, ranked, keyed by their kind
+    pub fn feature_importance_map(&self, feature_labels: &[String]) -> serde_json::Value {
+        serde_json::Value::Object(
+            self.feature_importances
+                .iter()
+                .map(|(kind, importance)| (kind.to_string(), importance.to_map(feature_labels)))
+                .collect(),
+        )
+    }
+
     /// Gets the main metric value for a trial
     pub fn get_main_metric(&self, trial: usize) -> f64 {
         self.model_candidate_stats[trial]
@@ -166,6 +205,17 @@ impl TrainingStatistics {
             )
             .unwrap(),
         );
+        if !self.confusion_matrices.is_empty() {
+            map.insert(
+                "confusionMatrices".to_string(),
+                serde_json::Value::Object(
+                    self.confusion_matrices
+                        .iter()
+                        .map(|(split, matrix)| (split.clone(), matrix.to_map()))
+                        .collect(),
+                ),
+            );
+        }
         map
     }
 
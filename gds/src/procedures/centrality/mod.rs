@@ -7,6 +7,7 @@ pub mod degree_centrality;
 pub mod harmonic;
 pub mod hits;
 pub mod pagerank;
+pub mod text_rank;
 
 pub use articulation_points::*;
 pub use betweenness::*;
@@ -17,3 +18,4 @@ pub use degree_centrality::*;
 pub use harmonic::*;
 pub use hits::*;
 pub use pagerank::*;
+pub use text_rank::*;
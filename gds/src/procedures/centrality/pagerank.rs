@@ -39,7 +39,7 @@ use crate::algo::pagerank::{
 use crate::collections::backends::vec::VecDouble;
 use crate::core::graph_dimensions::ConcreteGraphDimensions;
 use crate::projection::eval::algorithm::AlgorithmError;
-use crate::projection::Orientation;
+use crate::projection::{Orientation, RelationshipType};
 use crate::task::concurrency::Concurrency;
 use crate::task::memory::{MemoryEstimation, MemoryRange};
 use crate::task::progress::ProgressTracker;
@@ -83,6 +83,8 @@ use std::time::Instant;
 pub struct PageRankFacade<Store: GraphStore = DefaultGraphStore> {
     graph_store: Arc<Store>,
     config: PageRankConfig,
+    /// Relationship types to traverse; all types when unset
+    relationship_types: Option<HashSet<RelationshipType>>,
     /// Task registry for progress tracking
     task_registry: Arc<dyn TaskRegistryFactory>,
 }
@@ -98,6 +100,7 @@ impl<Store: GraphStore> PageRankFacade<Store> {
         Self {
             graph_store,
             config: PageRankConfig::default(),
+            relationship_types: None,
             task_registry: Arc::new(EmptyTaskRegistryFactory),
         }
     }
@@ -111,6 +114,7 @@ impl<Store: GraphStore> PageRankFacade<Store> {
         Ok(Self {
             graph_store,
             config,
+            relationship_types: None,
             task_registry: Arc::new(EmptyTaskRegistryFactory),
         })
     }
@@ -198,6 +202,17 @@ impl<Store: GraphStore> PageRankFacade<Store> {
         self
     }
 
+    /// Restrict the run to the given relationship types.
+    ///
+    /// By default every relationship type in the store is traversed.
+    pub fn relationship_types(
+        mut self,
+        relationship_types: impl IntoIterator<Item = RelationshipType>,
+    ) -> Self {
+        self.relationship_types = Some(relationship_types.into_iter().collect());
+        self
+    }
+
     /// Set maximum iterations
     ///
     /// The algorithm will stop after this many iterations or when converged,
@@ -249,11 +264,21 @@ impl<Store: GraphStore> PageRankFacade<Store> {
         self.validate()?;
         let start = Instant::now();
 
-        let storage = PageRankStorageRuntime::new(
-            self.graph_store.as_ref(),
-            self.orientation()?,
-            self.config.relationship_weight_property.as_deref(),
-        )?;
+        let storage = match &self.relationship_types {
+            Some(relationship_types) => {
+                PageRankStorageRuntime::with_relationship_types_orientation_and_weight_property(
+                    self.graph_store.as_ref(),
+                    relationship_types,
+                    self.orientation()?,
+                    self.config.relationship_weight_property.as_deref(),
+                )?
+            }
+            None => PageRankStorageRuntime::new(
+                self.graph_store.as_ref(),
+                self.orientation()?,
+                self.config.relationship_weight_property.as_deref(),
+            )?,
+        };
 
         let source_set = self
             .config
@@ -0,0 +1,256 @@
+//! TextRank Facade
+//!
+//! **What is it?**: PageRank over the weighted `CO_OCCURS` relationships of a [`TextGraph`]
+//! **Why care?**: Terms that co-occur with many other central terms summarize a corpus
+//! **Complexity**: O(k*(V + E)) where k is iterations
+//! **Best for**: Keyword and keyphrase extraction
+//!
+//! Only `Token` nodes are ranked. Keyphrases are maximal runs of adjacent
+//! top-ranked tokens within a sentence, scored by the sum of their members.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! # use std::sync::Arc;
+//! # use gds::collections::dataset::corpus::{Corpus, TextGraphBuilder};
+//! # use gds::collections::dataset::language::{PunktSentenceTokenizer, WhitespaceTokenizer};
+//! # use gds::procedures::centrality::TextRankFacade;
+//! # let corpus = Corpus::from_texts(&["graph algorithms rank graph nodes"]).unwrap();
+//! # let (sentences, words) = (PunktSentenceTokenizer::new(), WhitespaceTokenizer);
+//! let graph = TextGraphBuilder::new(&sentences, &words).build(&corpus).unwrap();
+//! let keywords = TextRankFacade::new(Arc::new(graph)).top_k(5).keywords()?;
+//! # Ok::<(), gds::projection::eval::algorithm::AlgorithmError>(())
+//! ```
+
+use crate::algo::algorithms::Result;
+use crate::collections::dataset::corpus::{
+    TextGraph, TextNodeKind, TEXT_GRAPH_CO_OCCURS, TEXT_GRAPH_WEIGHT,
+};
+use crate::procedures::centrality::PageRankFacade;
+use crate::projection::RelationshipType;
+use crate::types::prelude::GraphStore;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// A ranked term.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyword {
+    pub node_id: u64,
+    pub term: String,
+    pub score: f64,
+}
+
+/// A run of adjacent keywords and its summed score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyphrase {
+    pub terms: Vec<String>,
+    pub score: f64,
+}
+
+impl Keyphrase {
+    pub fn text(&self) -> String {
+        self.terms.join(" ")
+    }
+}
+
+/// TextRank facade - fluent configuration
+///
+/// ## Default Configuration
+/// - iterations: 20
+/// - damping_factor: 0.85
+/// - tolerance: 1e-4
+/// - top_k: 10
+/// - weighted: true (co-occurrence counts weight the random walk)
+#[derive(Clone)]
+pub struct TextRankFacade {
+    graph: Arc<TextGraph>,
+    iterations: u32,
+    damping_factor: f64,
+    tolerance: f64,
+    concurrency: usize,
+    top_k: usize,
+    weighted: bool,
+}
+
+impl TextRankFacade {
+    pub fn new(graph: Arc<TextGraph>) -> Self {
+        Self {
+            graph,
+            iterations: 20,
+            damping_factor: 0.85,
+            tolerance: 1e-4,
+            concurrency: 4,
+            top_k: 10,
+            weighted: true,
+        }
+    }
+
+    pub fn iterations(mut self, n: u32) -> Self {
+        self.iterations = n;
+        self
+    }
+
+    pub fn damping_factor(mut self, d: f64) -> Self {
+        self.damping_factor = d;
+        self
+    }
+
+    pub fn tolerance(mut self, t: f64) -> Self {
+        self.tolerance = t;
+        self
+    }
+
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Number of keywords (and keyphrases) to return.
+    pub fn top_k(mut self, k: usize) -> Self {
+        self.top_k = k;
+        self
+    }
+
+    /// Use co-occurrence counts as relationship weights (default) or treat
+    /// every co-occurrence edge equally.
+    pub fn weighted(mut self, weighted: bool) -> Self {
+        self.weighted = weighted;
+        self
+    }
+
+    fn scores(&self) -> Result<Vec<f64>> {
+        let store = self.graph.store();
+        let co_occurs = RelationshipType::of(TEXT_GRAPH_CO_OCCURS);
+        if !store.has_relationship_type(&co_occurs) {
+            return Ok(vec![1.0 - self.damping_factor; self.graph.node_count()]);
+        }
+
+        let mut pagerank = PageRankFacade::new(Arc::clone(store))
+            .relationship_types([co_occurs])
+            .iterations(self.iterations)
+            .damping_factor(self.damping_factor)
+            .tolerance(self.tolerance)
+            .concurrency(self.concurrency);
+        if self.weighted {
+            pagerank = pagerank.relationship_weight_property(TEXT_GRAPH_WEIGHT);
+        }
+        Ok(pagerank.stream()?.map(|score| score.score).collect())
+    }
+
+    /// The `top_k` highest-scoring terms, best first.
+    pub fn keywords(&self) -> Result<Vec<Keyword>> {
+        let scores = self.scores()?;
+        let mut keywords = self
+            .graph
+            .node_ids(TextNodeKind::Token)
+            .map(|node_id| Keyword {
+                node_id: node_id as u64,
+                term: self.graph.nodes()[node_id].text.clone(),
+                score: scores[node_id],
+            })
+            .collect::<Vec<_>>();
+        keywords.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.term.cmp(&b.term))
+        });
+        keywords.truncate(self.top_k);
+        Ok(keywords)
+    }
+
+    /// The `top_k` highest-scoring keyphrases, best first.
+    pub fn keyphrases(&self) -> Result<Vec<Keyphrase>> {
+        let keywords = self.keywords()?;
+        let scores = keywords
+            .iter()
+            .map(|keyword| (keyword.node_id as usize, keyword.score))
+            .collect::<HashMap<_, _>>();
+
+        let mut seen = HashSet::new();
+        let mut phrases = Vec::new();
+        for sentence in self.graph.sentence_tokens() {
+            for run in sentence.split(|token| !scores.contains_key(token)) {
+                if run.is_empty() {
+                    continue;
+                }
+                let terms = run
+                    .iter()
+                    .map(|&token| self.graph.nodes()[token].text.clone())
+                    .collect::<Vec<_>>();
+                if seen.insert(terms.clone()) {
+                    let score = run.iter().map(|token| scores[token]).sum();
+                    phrases.push(Keyphrase { terms, score });
+                }
+            }
+        }
+
+        phrases.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.terms.cmp(&b.terms))
+        });
+        phrases.truncate(self.top_k);
+        Ok(phrases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::dataset::corpus::{Corpus, TextGraphBuilder};
+    use crate::collections::dataset::language::tokenizer::{RegexpTokenizer, WhitespaceTokenizer};
+
+    fn graph(texts: &[&str]) -> Arc<TextGraph> {
+        let sentences = RegexpTokenizer::new(r"[^.!?\s][^.!?]*").unwrap();
+        let words = WhitespaceTokenizer;
+        let graph = TextGraphBuilder::new(&sentences, &words)
+            .stopwords(["of", "the"])
+            .build(&Corpus::from_texts(texts).unwrap())
+            .unwrap();
+        Arc::new(graph)
+    }
+
+    #[test]
+    fn ranks_hub_terms_first() {
+        let graph =
+            graph(&["machine learning helps. machine learning scales. machine learning works."]);
+        let keywords = TextRankFacade::new(graph)
+            .iterations(100)
+            .tolerance(1e-10)
+            .concurrency(1)
+            .top_k(2)
+            .keywords()
+            .unwrap();
+
+        let terms = keywords.iter().map(|k| k.term.as_str()).collect::<Vec<_>>();
+        assert_eq!(terms, vec!["learning", "machine"]);
+        assert!(keywords[0].score > keywords[1].score);
+    }
+
+    #[test]
+    fn merges_adjacent_keywords_into_phrases() {
+        let graph = graph(&[
+            "the theory of machine learning helps",
+            "machine learning scales. machine learning works",
+        ]);
+        let phrases = TextRankFacade::new(graph)
+            .concurrency(1)
+            .top_k(2)
+            .keyphrases()
+            .unwrap();
+
+        assert_eq!(phrases.len(), 1);
+        assert_eq!(phrases[0].text(), "machine learning");
+    }
+
+    #[test]
+    fn graph_without_co_occurrences_scores_uniformly() {
+        let keywords = TextRankFacade::new(graph(&["alpha. beta."]))
+            .keywords()
+            .unwrap();
+
+        assert_eq!(keywords.len(), 2);
+        assert_eq!(keywords[0].score, keywords[1].score);
+        assert_eq!(keywords[0].term, "alpha");
+    }
+}
@@ -1,20 +1,37 @@
 //! Java-shaped Model Catalog facade.
 //!
 //! This mirrors (a small subset of) Neo4j GDS `model-catalog-facade`.
-//! For now we keep this as an in-memory, process-local catalog with per-user scoping.
+//! Models live in an in-memory, process-local catalog with per-user scoping; when a
+//! store location is configured they can additionally be stored to and loaded from
+//! disk in the format defined by [`crate::core::model::ModelFileWriter`].
 
+use std::any::Any;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock};
+use std::time::Instant;
 
-use chrono::{DateTime, FixedOffset, Utc};
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::algo::embeddings::graphsage::algo::{GraphSageModelData, MODEL_TYPE as GRAPH_SAGE};
+use crate::algo::embeddings::graphsage::graphsage_model_trainer::GraphSageTrainMetrics;
+use crate::algo::embeddings::graphsage::types::GraphSageTrainConfig;
 use crate::core::model::{
-    InMemoryModelCatalog, Model, ModelCatalog, ModelCatalogCustomInfo, ModelCatalogListener,
-    ModelConfig, ModelData,
+    validate_store_name, InMemoryModelCatalog, Model, ModelCatalog, ModelCatalogCustomInfo,
+    ModelCatalogListener, ModelConfig, ModelData, ModelFileReader, ModelFileWriter,
+    UntypedModelFileHeader,
+};
+use crate::procedures::pipelines::{
+    PipelineModelCustomInfo, PipelineModelData, PipelineModelTrainConfig,
+};
+use crate::projection::eval::pipeline::{
+    LinkPredictionTrainingPipeline, NodeClassificationTrainingPipeline,
+    NodeRegressionTrainingPipeline,
 };
 use crate::types::user::User;
+use serde::de::DeserializeOwned;
 
 /// A loose map type for Java `Map<String, Object>`.
 pub type AnyMap = HashMap<String, Value>;
@@ -39,6 +56,24 @@ pub struct ModelExistsResult {
     pub exists: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelStoreResult {
+    pub model_name: String,
+    pub store_millis: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelLoadResult {
+    pub model_name: String,
+    pub load_millis: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelDeleteResult {
+    pub model_name: String,
+    pub delete_millis: u64,
+}
+
 pub trait ModelCatalogProcedureFacade {
     const NO_VALUE: &'static str = "__NO_VALUE";
 
@@ -47,12 +82,24 @@ pub trait ModelCatalogProcedureFacade {
     fn exists(&self, model_name: &str) -> Vec<ModelExistsResult>;
 
     fn list(&self, model_name: &str) -> Vec<ModelCatalogResult>;
+
+    /// Writes a loaded model to the configured store location.
+    fn store(&self, model_name: &str, fail_if_unsupported: bool) -> Vec<ModelStoreResult>;
+
+    /// Loads a stored model into the in-memory catalog.
+    fn load(&self, model_name: &str) -> Vec<ModelLoadResult>;
+
+    /// Removes a stored model from disk; the in-memory copy, if any, stays loaded.
+    fn delete(&self, model_name: &str) -> Vec<ModelDeleteResult>;
 }
 
 fn validate_model_name(model_name: &str) -> &str {
     if model_name.trim().is_empty() {
         panic!("modelName must not be blank");
     }
+    if let Err(e) = validate_store_name("modelName", model_name) {
+        panic!("{e}");
+    }
     model_name
 }
 
@@ -69,6 +116,22 @@ struct ModelMeta {
 }
 
 impl ModelMeta {
+    fn from_header(header: &UntypedModelFileHeader) -> Self {
+        Self {
+            model_type: header.algo_type.clone(),
+            model_info: value_to_map(header.custom_info.clone()),
+            creation_time: header.creation_time.with_timezone(&utc_offset()),
+            train_config: value_to_map(header.train_config.clone()),
+            graph_schema: value_to_map(header.graph_schema.to_map()),
+            loaded: false,
+            stored: true,
+            published: header
+                .shared_with
+                .iter()
+                .any(|u| u == crate::core::model::ALL_USERS),
+        }
+    }
+
     fn to_result(&self, model_name: &str) -> ModelCatalogResult {
         ModelCatalogResult {
             model_name: model_name.to_string(),
@@ -84,15 +147,31 @@ impl ModelMeta {
     }
 }
 
+type ModelKey = (String, String);
+
+/// Writes a type-erased catalog entry to a store location.
+type ModelWriter =
+    Arc<dyn Fn(&(dyn Any + Send + Sync), &Path) -> anyhow::Result<PathBuf> + Send + Sync>;
+
+/// Reads a stored model of one algo type and inserts it into the catalog.
+type ModelLoader = Arc<dyn Fn(&ModelCatalogFacade, &Path) -> anyhow::Result<()> + Send + Sync>;
+
 /// A model catalog implementation that stores actual models (type-erased) and keeps
 /// a parallel metadata index used by the facade `list/exists/drop` results.
 ///
 /// This lets algorithm code use the `ModelCatalog` trait, while the facade can
 /// render results without downcasting models.
+///
+/// `set` also captures a writer for the concrete model type, so `store` can persist
+/// an entry without knowing its type. Loading goes the other way and needs a loader
+/// registered per algo type (see [`ModelCatalogFacade::register_loader`]).
 #[derive(Default)]
 pub struct ModelCatalogFacade {
     inner: InMemoryModelCatalog,
-    meta: parking_lot::RwLock<HashMap<(String, String), ModelMeta>>,
+    meta: parking_lot::RwLock<HashMap<ModelKey, ModelMeta>>,
+    writers: parking_lot::RwLock<HashMap<ModelKey, ModelWriter>>,
+    loaders: parking_lot::RwLock<HashMap<String, ModelLoader>>,
+    store_location: parking_lot::RwLock<Option<PathBuf>>,
 }
 
 impl ModelCatalogFacade {
@@ -100,6 +179,152 @@ impl ModelCatalogFacade {
         Self::default()
     }
 
+    /// Catalog with loaders for the model types this crate produces.
+    pub fn with_default_loaders() -> Self {
+        let catalog = Self::new();
+        catalog.register_loader::<GraphSageModelData, GraphSageTrainConfig, GraphSageTrainMetrics>(
+            GRAPH_SAGE,
+        );
+        for algo_type in [
+            NodeClassificationTrainingPipeline::MODEL_TYPE,
+            NodeRegressionTrainingPipeline::MODEL_TYPE,
+            LinkPredictionTrainingPipeline::MODEL_TYPE,
+        ] {
+            catalog.register_loader::<PipelineModelData, PipelineModelTrainConfig, PipelineModelCustomInfo>(
+                algo_type,
+            );
+        }
+        catalog
+    }
+
+    pub fn with_store_location(self, store_location: impl Into<PathBuf>) -> Self {
+        self.set_store_location(Some(store_location.into()));
+        self
+    }
+
+    pub fn set_store_location(&self, store_location: Option<PathBuf>) {
+        *self.store_location.write() = store_location;
+    }
+
+    pub fn store_location(&self) -> Option<PathBuf> {
+        self.store_location.read().clone()
+    }
+
+    /// Registers how stored models of `algo_type` are read back.
+    pub fn register_loader<D, C, I>(&self, algo_type: &str)
+    where
+        D: ModelData + DeserializeOwned + 'static,
+        C: ModelConfig + DeserializeOwned + 'static,
+        I: ModelCatalogCustomInfo + DeserializeOwned + 'static,
+    {
+        let loader: ModelLoader = Arc::new(|catalog: &ModelCatalogFacade, dir: &Path| {
+            let model = ModelFileReader::new(dir).read::<D, C, I>()?;
+            catalog.set(model)
+        });
+        self.loaders.write().insert(algo_type.to_string(), loader);
+    }
+
+    /// Loads a stored model of known type from `store_location` into the catalog.
+    pub fn load<D, C, I>(
+        &self,
+        username: &str,
+        model_name: &str,
+        store_location: &Path,
+    ) -> anyhow::Result<Arc<Model<D, C, I>>>
+    where
+        D: ModelData + DeserializeOwned + 'static,
+        C: ModelConfig + DeserializeOwned + 'static,
+        I: ModelCatalogCustomInfo + DeserializeOwned + 'static,
+    {
+        self.ensure_not_loaded(username, model_name)?;
+        let model =
+            ModelFileReader::for_model(store_location, username, model_name)?.read::<D, C, I>()?;
+        self.set(model)?;
+        self.get(username, model_name)
+    }
+
+    /// Loads a stored model through the loader registered for its algo type.
+    pub fn load_untyped(
+        &self,
+        username: &str,
+        model_name: &str,
+        store_location: &Path,
+    ) -> anyhow::Result<Arc<dyn Any + Send + Sync>> {
+        self.ensure_not_loaded(username, model_name)?;
+        let reader = ModelFileReader::for_model(store_location, username, model_name)?;
+        let header = reader.read_untyped_header()?;
+        let loader = self
+            .loaders
+            .read()
+            .get(&header.algo_type)
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!("No model loader registered for type `{}`", header.algo_type)
+            })?;
+        loader(self, reader.dir())?;
+        self.get_untyped(username, model_name)
+    }
+
+    /// Lists models of `username` stored below `store_location`.
+    pub fn list_stored(
+        &self,
+        username: &str,
+        store_location: &Path,
+    ) -> anyhow::Result<Vec<ModelCatalogResult>> {
+        Ok(ModelFileReader::list(store_location)?
+            .iter()
+            .filter(|header| header.creator == username)
+            .map(|header| {
+                let mut meta = ModelMeta::from_header(header);
+                meta.loaded = self.exists(username, &header.name);
+                meta.to_result(&header.name)
+            })
+            .collect())
+    }
+
+    /// Deletes the stored files of a model; a loaded copy stays in the catalog.
+    pub fn delete_stored(
+        &self,
+        username: &str,
+        model_name: &str,
+        store_location: &Path,
+    ) -> anyhow::Result<()> {
+        ModelFileReader::for_model(store_location, username, model_name)?.delete()?;
+        if let Some(meta) = self
+            .meta
+            .write()
+            .get_mut(&(username.to_string(), model_name.to_string()))
+        {
+            meta.stored = false;
+        }
+        Ok(())
+    }
+
+    fn ensure_not_loaded(&self, username: &str, model_name: &str) -> anyhow::Result<()> {
+        if self.exists(username, model_name) {
+            anyhow::bail!("Model `{model_name}` is already loaded for user `{username}`");
+        }
+        Ok(())
+    }
+
+    fn set_writer_for_model<D, C, I>(&self, model: &Model<D, C, I>)
+    where
+        D: ModelData + 'static,
+        C: ModelConfig + 'static,
+        I: ModelCatalogCustomInfo + 'static,
+    {
+        let writer: ModelWriter = Arc::new(|any: &(dyn Any + Send + Sync), dir: &Path| {
+            let model = any
+                .downcast_ref::<Model<D, C, I>>()
+                .ok_or_else(|| anyhow::anyhow!("Model type mismatch"))?;
+            ModelFileWriter::write(model, dir)
+        });
+        self.writers.write().insert(
+            (model.creator().to_string(), model.name().to_string()),
+            writer,
+        );
+    }
+
     fn set_meta_for_model<D, C, I>(&self, model: &Model<D, C, I>)
     where
         D: ModelData,
@@ -113,10 +338,7 @@ impl ModelCatalogFacade {
 
         let model_info = value_to_map(model.custom_info().to_map());
 
-        let creation_time = model
-            .creation_time()
-            .with_timezone(&Utc)
-            .with_timezone(&FixedOffset::east_opt(0).expect("UTC offset"));
+        let creation_time = model.creation_time().with_timezone(&utc_offset());
 
         let train_config = model
             .train_config()
@@ -170,6 +392,10 @@ impl ModelCatalogFacade {
     }
 }
 
+fn utc_offset() -> FixedOffset {
+    FixedOffset::east_opt(0).expect("UTC offset")
+}
+
 fn value_to_map(value: Value) -> AnyMap {
     match value {
         Value::Object(map) => map.into_iter().collect(),
@@ -194,6 +420,7 @@ impl ModelCatalog for ModelCatalogFacade {
         I: ModelCatalogCustomInfo + 'static,
     {
         self.set_meta_for_model(&model);
+        self.set_writer_for_model(&model);
         self.inner.set(model)
     }
 
@@ -225,6 +452,9 @@ impl ModelCatalog for ModelCatalogFacade {
     ) -> anyhow::Result<Arc<dyn std::any::Any + Send + Sync>> {
         let dropped = self.inner.drop(username, model_name)?;
         self.drop_meta(username, model_name);
+        self.writers
+            .write()
+            .remove(&(username.to_string(), model_name.to_string()));
         Ok(dropped)
     }
 
@@ -246,7 +476,19 @@ impl ModelCatalog for ModelCatalogFacade {
         model_name: &str,
         model_dir: &std::path::Path,
     ) -> anyhow::Result<Arc<dyn std::any::Any + Send + Sync>> {
-        self.inner.store(username, model_name, model_dir)
+        let key = (username.to_string(), model_name.to_string());
+        let model = self.inner.get_untyped(username, model_name)?;
+        let writer = self
+            .writers
+            .read()
+            .get(&key)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Model `{model_name}` cannot be stored"))?;
+        writer(model.as_ref(), model_dir)?;
+        if let Some(meta) = self.meta.write().get_mut(&key) {
+            meta.stored = true;
+        }
+        Ok(model)
     }
 
     fn model_count(&self) -> usize {
@@ -255,9 +497,9 @@ impl ModelCatalog for ModelCatalogFacade {
 
     fn remove_all_loaded(&self) {
         self.inner.remove_all_loaded();
-        // Best-effort: keep meta aligned with inner list.
-        // In-memory store() isn't implemented, so this is currently a no-op for stored models.
+        // Stored copies stay on disk and are reported by `list_stored`.
         self.meta.write().clear();
+        self.writers.write().clear();
     }
 
     fn verify_model_can_be_stored(
@@ -279,7 +521,7 @@ pub struct LocalModelCatalogProcedureFacade {
 
 pub(crate) fn shared_in_memory_model_catalog() -> Arc<ModelCatalogFacade> {
     static CATALOG: OnceLock<Arc<ModelCatalogFacade>> = OnceLock::new();
-    Arc::clone(CATALOG.get_or_init(|| Arc::new(ModelCatalogFacade::new())))
+    Arc::clone(CATALOG.get_or_init(|| Arc::new(ModelCatalogFacade::with_default_loaders())))
 }
 
 impl Default for LocalModelCatalogProcedureFacade {
@@ -299,6 +541,16 @@ impl LocalModelCatalogProcedureFacade {
     fn exists_impl(&self, model_name: &str) -> Option<ModelMeta> {
         self.model_catalog.meta_for(&self.username, model_name)
     }
+
+    fn required_store_location(&self) -> PathBuf {
+        self.model_catalog
+            .store_location()
+            .unwrap_or_else(|| panic!("No model store location is configured"))
+    }
+}
+
+fn elapsed_millis(start: Instant) -> u64 {
+    start.elapsed().as_millis() as u64
 }
 
 impl ModelCatalogProcedureFacade for LocalModelCatalogProcedureFacade {
@@ -347,20 +599,87 @@ impl ModelCatalogProcedureFacade for LocalModelCatalogProcedureFacade {
     }
 
     fn list(&self, model_name: &str) -> Vec<ModelCatalogResult> {
-        if model_name == Self::NO_VALUE {
-            return self
+        let mut results: Vec<ModelCatalogResult> = self
+            .model_catalog
+            .list_meta(&self.username)
+            .into_iter()
+            .map(|(name, meta)| meta.to_result(&name))
+            .collect();
+
+        // Stored models that are not loaded are listed with `loaded = false`.
+        if let Some(store_location) = self.model_catalog.store_location() {
+            match self
                 .model_catalog
-                .list_meta(&self.username)
-                .into_iter()
-                .map(|(name, meta)| meta.to_result(&name))
-                .collect();
+                .list_stored(&self.username, &store_location)
+            {
+                Ok(stored) => results.extend(stored.into_iter().filter(|result| !result.loaded)),
+                Err(e) => log::warn!(
+                    "Failed to list stored models at {}: {e:#}",
+                    store_location.display()
+                ),
+            }
+            results.sort_by(|a, b| a.model_name.cmp(&b.model_name));
+        }
+
+        if model_name == Self::NO_VALUE {
+            return results;
         }
 
         let model_name = validate_model_name(model_name);
+        results.retain(|result| result.model_name == model_name);
+        results
+    }
 
-        self.exists_impl(model_name)
-            .map(|meta| vec![meta.to_result(model_name)])
-            .unwrap_or_default()
+    fn store(&self, model_name: &str, fail_if_unsupported: bool) -> Vec<ModelStoreResult> {
+        let model_name = validate_model_name(model_name);
+        let store_location = self.required_store_location();
+        let start = Instant::now();
+
+        match <ModelCatalogFacade as ModelCatalog>::store(
+            self.model_catalog.as_ref(),
+            &self.username,
+            model_name,
+            &store_location,
+        ) {
+            Ok(_) => vec![ModelStoreResult {
+                model_name: model_name.to_string(),
+                store_millis: elapsed_millis(start),
+            }],
+            Err(e) if fail_if_unsupported => panic!("{e}"),
+            Err(_) => vec![],
+        }
+    }
+
+    fn load(&self, model_name: &str) -> Vec<ModelLoadResult> {
+        let model_name = validate_model_name(model_name);
+        let store_location = self.required_store_location();
+        let start = Instant::now();
+
+        if !self.model_catalog.exists(&self.username, model_name) {
+            self.model_catalog
+                .load_untyped(&self.username, model_name, &store_location)
+                .unwrap_or_else(|e| panic!("{e}"));
+        }
+
+        vec![ModelLoadResult {
+            model_name: model_name.to_string(),
+            load_millis: elapsed_millis(start),
+        }]
+    }
+
+    fn delete(&self, model_name: &str) -> Vec<ModelDeleteResult> {
+        let model_name = validate_model_name(model_name);
+        let store_location = self.required_store_location();
+        let start = Instant::now();
+
+        self.model_catalog
+            .delete_stored(&self.username, model_name, &store_location)
+            .unwrap_or_else(|e| panic!("{e}"));
+
+        vec![ModelDeleteResult {
+            model_name: model_name.to_string(),
+            delete_millis: elapsed_millis(start),
+        }]
     }
 }
 
@@ -371,7 +690,7 @@ mod tests {
     use crate::types::schema::GraphSchema;
     use serde_json::json;
 
-    #[derive(Debug, Clone, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     struct DummyData;
 
     impl ModelData for DummyData {
@@ -380,7 +699,7 @@ mod tests {
         }
     }
 
-    #[derive(Debug, Clone, Serialize)]
+    #[derive(Debug, Clone, Serialize, Deserialize)]
     struct DummyInfo;
 
     impl ModelCatalogCustomInfo for DummyInfo {
@@ -389,7 +708,7 @@ mod tests {
         }
     }
 
-    #[derive(Debug, Clone, Serialize)]
+    #[derive(Debug, Clone, Serialize, Deserialize)]
     struct DummyConfig {
         model_name: String,
         model_user: String,
@@ -466,4 +785,78 @@ mod tests {
         assert_eq!(exists_after.len(), 1);
         assert!(!exists_after[0].exists);
     }
+
+    fn dummy_model(name: &str) -> Model<DummyData, DummyConfig, DummyInfo> {
+        Model::new(
+            "alice".to_string(),
+            name.to_string(),
+            "DummyAlgo".to_string(),
+            GraphSchema::empty(),
+            Some(DummyData),
+            DummyConfig {
+                model_name: name.to_string(),
+                model_user: "alice".to_string(),
+            },
+            "0.0-test".to_string(),
+            DummyInfo,
+        )
+    }
+
+    #[test]
+    fn store_load_delete_round_trip() {
+        let store_location =
+            std::env::temp_dir().join(format!("gds-model-catalog-{}", uuid::Uuid::new_v4()));
+        let catalog = Arc::new(ModelCatalogFacade::new().with_store_location(&store_location));
+        catalog.register_loader::<DummyData, DummyConfig, DummyInfo>("DummyAlgo");
+        let facade =
+            LocalModelCatalogProcedureFacade::new(User::from("alice"), Arc::clone(&catalog));
+
+        catalog.set(dummy_model("m1")).unwrap();
+        assert_eq!(facade.store("m1", true).len(), 1);
+        assert!(facade.list("m1")[0].stored);
+
+        // A fresh process only sees the stored copy.
+        facade.drop("m1", true);
+        let listed = facade.list("m1");
+        assert_eq!(listed.len(), 1);
+        assert!(!listed[0].loaded);
+        assert!(listed[0].stored);
+
+        assert_eq!(facade.load("m1").len(), 1);
+        let loaded = catalog
+            .get::<DummyData, DummyConfig, DummyInfo>("alice", "m1")
+            .unwrap();
+        assert_eq!(loaded.data(), Some(&DummyData));
+        assert!(loaded.is_stored());
+
+        assert_eq!(facade.delete("m1").len(), 1);
+        let listed = facade.list("m1");
+        assert_eq!(listed.len(), 1);
+        assert!(listed[0].loaded);
+        assert!(!listed[0].stored);
+
+        std::fs::remove_dir_all(store_location).ok();
+    }
+
+    #[test]
+    fn typed_load_rejects_already_loaded_models() {
+        let store_location =
+            std::env::temp_dir().join(format!("gds-model-catalog-{}", uuid::Uuid::new_v4()));
+        let catalog = ModelCatalogFacade::new();
+        catalog.set(dummy_model("m2")).unwrap();
+        catalog.store("alice", "m2", &store_location).unwrap();
+
+        let err = catalog
+            .load::<DummyData, DummyConfig, DummyInfo>("alice", "m2", &store_location)
+            .unwrap_err();
+        assert!(err.to_string().contains("already loaded"));
+
+        <ModelCatalogFacade as ModelCatalog>::drop(&catalog, "alice", "m2").unwrap();
+        let loaded = catalog
+            .load::<DummyData, DummyConfig, DummyInfo>("alice", "m2", &store_location)
+            .unwrap();
+        assert_eq!(loaded.name(), "m2");
+
+        std::fs::remove_dir_all(store_location).ok();
+    }
 }
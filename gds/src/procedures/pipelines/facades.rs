@@ -5,6 +5,7 @@ use crate::projection::eval::pipeline::LinkPredictionSplitConfig;
 use crate::projection::eval::pipeline::NodeFeatureStep;
 use crate::projection::eval::pipeline::NodePropertyPredictionSplitConfig;
 use crate::projection::eval::pipeline::PipelineCatalog;
+use crate::projection::eval::pipeline::SearchStrategy;
 use crate::projection::eval::pipeline::TrainingMethod;
 use crate::task::memory::MemoryEstimationResult;
 use crate::task::runtime::TaskStage;
@@ -24,6 +25,12 @@ pub trait LinkPredictionFacade {
         raw_configuration: RawConfig,
     ) -> Vec<PipelineInfoResult>;
 
+    fn add_gradient_boosting(
+        &self,
+        pipeline_name: &str,
+        configuration: RawConfig,
+    ) -> Vec<PipelineInfoResult>;
+
     fn add_logistic_regression(
         &self,
         pipeline_name: &str,
@@ -85,6 +92,12 @@ pub trait LinkPredictionFacade {
 }
 
 pub trait NodeClassificationFacade {
+    fn add_gradient_boosting(
+        &self,
+        pipeline_name: &str,
+        configuration: RawConfig,
+    ) -> Vec<NodePipelineInfoResult>;
+
     fn add_logistic_regression(
         &self,
         pipeline_name: &str,
@@ -171,12 +184,21 @@ pub trait NodeClassificationFacade {
 }
 
 pub trait NodeRegressionFacade {
+    fn add_gradient_boosting(
+        &self,
+        pipeline_name: &str,
+        configuration: RawConfig,
+    ) -> Vec<NodePipelineInfoResult>;
+
     fn add_logistic_regression(
         &self,
         pipeline_name: &str,
         configuration: RawConfig,
     ) -> Vec<NodePipelineInfoResult>;
 
+    fn add_mlp(&self, pipeline_name: &str, configuration: RawConfig)
+        -> Vec<NodePipelineInfoResult>;
+
     fn add_node_property(
         &self,
         pipeline_name: &str,
@@ -190,6 +212,9 @@ pub trait NodeRegressionFacade {
         configuration: RawConfig,
     ) -> Vec<NodePipelineInfoResult>;
 
+    fn add_svm(&self, pipeline_name: &str, configuration: RawConfig)
+        -> Vec<NodePipelineInfoResult>;
+
     fn configure_auto_tuning(
         &self,
         pipeline_name: &str,
@@ -442,8 +467,20 @@ impl LocalLinkPredictionFacade {
             .and_then(|v| v.as_u64())
             .map(|v| v as usize)
             .unwrap_or(AutoTuningConfig::MAX_TRIALS);
-
-        AutoTuningConfig::new(max_trials).unwrap_or_else(|e| panic!("{e}"))
+        let search_strategy = configuration
+            .get("searchStrategy")
+            .and_then(|v| v.as_str())
+            .map(|v| SearchStrategy::parse(v).unwrap_or_else(|e| panic!("{e}")))
+            .unwrap_or_default();
+        let pruning = configuration
+            .get("pruning")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        AutoTuningConfig::new(max_trials)
+            .unwrap_or_else(|e| panic!("{e}"))
+            .with_search_strategy(search_strategy)
+            .with_pruning(pruning)
     }
 }
 
@@ -468,6 +505,21 @@ impl LinkPredictionFacade for LocalLinkPredictionFacade {
             )]
     }
 
+    fn add_gradient_boosting(
+        &self,
+        pipeline_name: &str,
+        configuration: RawConfig,
+    ) -> Vec<PipelineInfoResult> {
+        let pipeline_name = PipelineName::parse(pipeline_name).unwrap_or_else(|e| panic!("{e}"));
+        vec![self
+            .pipeline_applications
+            .add_training_method_to_link_prediction_pipeline(
+                &pipeline_name,
+                TrainingMethod::GradientBoostingClassification,
+                configuration,
+            )]
+    }
+
     fn add_logistic_regression(
         &self,
         pipeline_name: &str,
@@ -632,12 +684,38 @@ impl LocalNodeClassificationFacade {
             .and_then(|v| v.as_u64())
             .map(|v| v as usize)
             .unwrap_or(AutoTuningConfig::MAX_TRIALS);
-
-        AutoTuningConfig::new(max_trials).unwrap_or_else(|e| panic!("{e}"))
+        let search_strategy = configuration
+            .get("searchStrategy")
+            .and_then(|v| v.as_str())
+            .map(|v| SearchStrategy::parse(v).unwrap_or_else(|e| panic!("{e}")))
+            .unwrap_or_default();
+        let pruning = configuration
+            .get("pruning")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        AutoTuningConfig::new(max_trials)
+            .unwrap_or_else(|e| panic!("{e}"))
+            .with_search_strategy(search_strategy)
+            .with_pruning(pruning)
     }
 }
 
 impl NodeClassificationFacade for LocalNodeClassificationFacade {
+    fn add_gradient_boosting(
+        &self,
+        pipeline_name: &str,
+        configuration: RawConfig,
+    ) -> Vec<NodePipelineInfoResult> {
+        let pipeline_name = PipelineName::parse(pipeline_name).unwrap_or_else(|e| panic!("{e}"));
+        vec![self.pipeline_applications.add_training_method(
+            &pipeline_name,
+            TrainingMethod::GradientBoostingClassification,
+            configuration,
+            true,
+        )]
+    }
+
     fn add_logistic_regression(
         &self,
         pipeline_name: &str,
@@ -864,12 +942,38 @@ impl LocalNodeRegressionFacade {
             .and_then(|v| v.as_u64())
             .map(|v| v as usize)
             .unwrap_or(AutoTuningConfig::MAX_TRIALS);
-
-        AutoTuningConfig::new(max_trials).unwrap_or_else(|e| panic!("{e}"))
+        let search_strategy = configuration
+            .get("searchStrategy")
+            .and_then(|v| v.as_str())
+            .map(|v| SearchStrategy::parse(v).unwrap_or_else(|e| panic!("{e}")))
+            .unwrap_or_default();
+        let pruning = configuration
+            .get("pruning")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        AutoTuningConfig::new(max_trials)
+            .unwrap_or_else(|e| panic!("{e}"))
+            .with_search_strategy(search_strategy)
+            .with_pruning(pruning)
     }
 }
 
 impl NodeRegressionFacade for LocalNodeRegressionFacade {
+    fn add_gradient_boosting(
+        &self,
+        pipeline_name: &str,
+        configuration: RawConfig,
+    ) -> Vec<NodePipelineInfoResult> {
+        let pipeline_name = PipelineName::parse(pipeline_name).unwrap_or_else(|e| panic!("{e}"));
+        vec![self.pipeline_applications.add_training_method(
+            &pipeline_name,
+            TrainingMethod::GradientBoostingRegression,
+            configuration,
+            false,
+        )]
+    }
+
     fn add_logistic_regression(
         &self,
         pipeline_name: &str,
@@ -884,6 +988,20 @@ impl NodeRegressionFacade for LocalNodeRegressionFacade {
         )]
     }
 
+    fn add_mlp(
+        &self,
+        pipeline_name: &str,
+        configuration: RawConfig,
+    ) -> Vec<NodePipelineInfoResult> {
+        let pipeline_name = PipelineName::parse(pipeline_name).unwrap_or_else(|e| panic!("{e}"));
+        vec![self.pipeline_applications.add_training_method(
+            &pipeline_name,
+            TrainingMethod::MLPRegression,
+            configuration,
+            false,
+        )]
+    }
+
     fn add_node_property(
         &self,
         pipeline_name: &str,
@@ -914,6 +1032,20 @@ impl NodeRegressionFacade for LocalNodeRegressionFacade {
         )]
     }
 
+    fn add_svm(
+        &self,
+        pipeline_name: &str,
+        configuration: RawConfig,
+    ) -> Vec<NodePipelineInfoResult> {
+        let pipeline_name = PipelineName::parse(pipeline_name).unwrap_or_else(|e| panic!("{e}"));
+        vec![self.pipeline_applications.add_training_method(
+            &pipeline_name,
+            TrainingMethod::SVMRegression,
+            configuration,
+            false,
+        )]
+    }
+
     fn configure_auto_tuning(
         &self,
         pipeline_name: &str,
@@ -1550,10 +1682,13 @@ mod executor_backed_facade_tests {
 
         let predictions = make_facade().node_regression().stream(
             "graph",
-            AnyMap::from([(
-                "modelName".to_string(),
-                Value::String("regression-model".to_string()),
-            )]),
+            AnyMap::from([
+                (
+                    "modelName".to_string(),
+                    Value::String("regression-model".to_string()),
+                ),
+                ("explain".to_string(), Value::Bool(true)),
+            ]),
         );
 
         assert_eq!(predictions.len(), 60);
@@ -1563,6 +1698,11 @@ mod executor_backed_facade_tests {
                 let expected = 2.0 * (prediction.node_id as f64 / 59.0) + 1.0;
                 let error = prediction.predicted_value - expected;
                 assert!(prediction.predicted_value.is_finite());
+
+                let explanation = prediction.explanation.as_ref().expect("explanation");
+                let explained = explanation["baseValue"].as_f64().unwrap()
+                    + explanation["contributions"]["feature"].as_f64().unwrap();
+                assert!((explained - prediction.predicted_value).abs() < 1e-9);
                 error * error
             })
             .sum::<f64>()
@@ -1775,6 +1915,28 @@ mod link_prediction_facade_tests {
             Value::from(17)
         );
 
+        let boosted = facade.node_classification().add_gradient_boosting(
+            "nc1",
+            AnyMap::from([("maxDepth".to_string(), Value::from(4))]),
+        );
+        assert_eq!(
+            boosted[0].parameter_space["GradientBoostingClassification"][0]["maxDepth"],
+            Value::from(4)
+        );
+
+        let tuned = facade.node_classification().configure_auto_tuning(
+            "nc1",
+            AnyMap::from([
+                ("maxTrials".to_string(), Value::from(30)),
+                ("searchStrategy".to_string(), Value::from("tpe")),
+            ]),
+        );
+        assert_eq!(
+            tuned[0].auto_tuning_config["searchStrategy"],
+            Value::from("TreeParzenEstimator")
+        );
+        assert_eq!(tuned[0].auto_tuning_config["pruning"], Value::from(true));
+
         facade.node_regression().create_pipeline("nr1");
         let regression = facade.node_regression().add_logistic_regression(
             "nr1",
@@ -1784,6 +1946,31 @@ mod link_prediction_facade_tests {
             regression[0].parameter_space["LinearRegression"][0]["penalty"],
             Value::from(0.75)
         );
+
+        let mlp = facade.node_regression().add_mlp(
+            "nr1",
+            AnyMap::from([("loss".to_string(), Value::from("huber"))]),
+        );
+        assert_eq!(
+            mlp[0].parameter_space["MLPRegression"][0]["loss"],
+            Value::from("huber")
+        );
+
+        let svm = facade.node_regression().add_svm(
+            "nr1",
+            AnyMap::from([
+                ("kernel".to_string(), Value::from("rbf")),
+                ("epsilon".to_string(), Value::from(0.2)),
+            ]),
+        );
+        assert_eq!(
+            svm[0].parameter_space["SVMRegression"][0]["kernel"],
+            Value::from("rbf")
+        );
+        assert_eq!(
+            svm[0].parameter_space["SVMRegression"][0]["epsilon"],
+            Value::from(0.2)
+        );
     }
 }
 
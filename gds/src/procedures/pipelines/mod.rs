@@ -54,6 +54,7 @@ pub mod node_regression_train_side_effects;
 pub mod pipeline_applications;
 pub mod pipeline_catalog_result_transformer;
 pub mod pipeline_info_result_transformer;
+pub mod pipeline_model_data;
 pub mod pipeline_name;
 pub mod pipeline_repository;
 pub mod predicted_probabilities;
@@ -118,6 +119,7 @@ pub use node_regression_train_side_effects::*;
 pub use pipeline_applications::*;
 pub use pipeline_catalog_result_transformer::*;
 pub use pipeline_info_result_transformer::*;
+pub use pipeline_model_data::*;
 pub use pipeline_name::*;
 pub use pipeline_repository::*;
 pub use predicted_probabilities::*;
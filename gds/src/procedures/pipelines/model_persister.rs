@@ -16,24 +16,43 @@ impl ModelPersister {
         Self { log, model_catalog }
     }
 
-    pub fn persist_model<D, C, I>(&self, model: Model<D, C, I>, persist_to_disk: bool)
+    /// Adds the model to the catalog and, if requested, stores it at the catalog's
+    /// store location.
+    ///
+    /// A failed disk store is returned; the model stays loaded in the catalog.
+    pub fn persist_model<D, C, I>(
+        &self,
+        model: Model<D, C, I>,
+        persist_to_disk: bool,
+    ) -> anyhow::Result<()>
     where
         D: ModelData + 'static,
         C: ModelConfig + 'static,
         I: ModelCatalogCustomInfo + 'static,
     {
-        self.model_catalog
-            .set(model)
-            .unwrap_or_else(|e| panic!("{e}"));
-        if persist_to_disk {
-            self.log
-                .warn("Model persistence to disk is not yet wired in Rust pipelines");
+        let username = model.creator().to_string();
+        let model_name = model.name().to_string();
+
+        self.model_catalog.set(model)?;
+
+        if !persist_to_disk {
+            return Ok(());
         }
-    }
 
-    pub fn warn_unimplemented(&self, model_kind: &str) {
-        self.log.warn(&format!(
-            "Model persistence for {model_kind} is not yet wired in Rust pipelines",
-        ));
+        let Some(store_location) = self.model_catalog.store_location() else {
+            self.log.warn(&format!(
+                "Model `{model_name}` was not stored to disk: no model store location is configured",
+            ));
+            return Ok(());
+        };
+
+        ModelCatalog::store(
+            self.model_catalog.as_ref(),
+            &username,
+            &model_name,
+            &store_location,
+        )
+        .map(|_| ())
+        .map_err(|e| anyhow::anyhow!("Failed to store model `{model_name}`: {e}"))
     }
 }
@@ -13,6 +13,11 @@ pub trait NodeClassificationPredictPipelineConfig {
     fn relationship_types(&self) -> &[String];
     fn include_predicted_probabilities(&self) -> bool;
 
+    /// Whether to explain every prediction with per-feature contributions.
+    fn explain(&self) -> bool {
+        false
+    }
+
     fn node_labels(&self) -> &[String] {
         self.target_node_labels()
     }
@@ -28,6 +33,7 @@ pub struct NodeClassificationPredictPipelineBaseConfig {
     target_node_labels: Vec<String>,
     relationship_types: Vec<String>,
     include_predicted_probabilities: bool,
+    explain: bool,
 }
 
 impl NodeClassificationPredictPipelineBaseConfig {
@@ -50,9 +56,15 @@ impl NodeClassificationPredictPipelineBaseConfig {
             target_node_labels,
             relationship_types,
             include_predicted_probabilities,
+            explain: false,
         }
     }
 
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
+    }
+
     pub fn from_map(username: String, mut config: AnyMap) -> Self {
         let graph_name = config
             .remove("graphName")
@@ -100,6 +112,11 @@ impl NodeClassificationPredictPipelineBaseConfig {
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
 
+        let explain = config
+            .remove("explain")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
         Self::new(
             graph_name,
             concurrency,
@@ -110,6 +127,7 @@ impl NodeClassificationPredictPipelineBaseConfig {
             relationship_types,
             include_predicted_probabilities,
         )
+        .with_explain(explain)
     }
 
     pub fn with_train_config_defaults(
@@ -165,6 +183,7 @@ impl NodeClassificationPredictPipelineBaseConfig {
             "includePredictedProbabilities".to_string(),
             Value::Bool(self.include_predicted_probabilities),
         );
+        map.insert("explain".to_string(), Value::Bool(self.explain));
         map
     }
 }
@@ -203,6 +222,10 @@ impl NodeClassificationPredictPipelineConfig for NodeClassificationPredictPipeli
     fn include_predicted_probabilities(&self) -> bool {
         self.include_predicted_probabilities
     }
+
+    fn explain(&self) -> bool {
+        self.explain
+    }
 }
 
 #[cfg(test)]
@@ -2,7 +2,8 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use crate::ml::core::subgraph::LocalIdMap;
-use crate::ml::models::{ClassifierData, ClassifierFactory, FeaturesFactory};
+use crate::ml::explain::feature_labels;
+use crate::ml::models::{Classifier, ClassifierData, ClassifierFactory, Features, FeaturesFactory};
 use crate::ml::node_classification::NodeClassificationPredict;
 use crate::projection::eval::pipeline::NodePropertyPredictPipeline;
 use crate::projection::eval::pipeline::PipelineGraphFilter;
@@ -136,7 +137,8 @@ impl<'a> PredictPipelineExecutor<NodePropertyPredictPipeline, NodeClassification
 
         let classifier = ClassifierFactory::create(self.classifier_data);
 
-        let classifier = Arc::from(classifier);
+        let classifier: Arc<dyn Classifier> = Arc::from(classifier);
+        let features: Arc<dyn Features> = Arc::from(features);
 
         let expected_dimension = self.classifier_data.feature_dimension();
         if features.feature_dimension() != expected_dimension {
@@ -149,8 +151,8 @@ impl<'a> PredictPipelineExecutor<NodePropertyPredictPipeline, NodeClassification
         }
 
         let predict = NodeClassificationPredict::new(
-            classifier,
-            Arc::from(features),
+            Arc::clone(&classifier),
+            Arc::clone(&features),
             MIN_BATCH_SIZE,
             self.configuration.include_predicted_probabilities(),
             Concurrency::of(self.configuration.concurrency()),
@@ -159,11 +161,33 @@ impl<'a> PredictPipelineExecutor<NodePropertyPredictPipeline, NodeClassification
         );
 
         let result = predict.compute();
-        Ok(NodeClassificationPipelineResult::of_for_node_ids(
+        let pipeline_result = NodeClassificationPipelineResult::of_for_node_ids(
             &result,
             &self.class_id_map,
             prediction_node_ids,
             root_node_count,
+        );
+        if !self.configuration.explain() {
+            return Ok(pipeline_result);
+        }
+
+        let predicted_classes = result.predicted_classes();
+        let explanations = (0..features.size())
+            .map(|index| {
+                classifier
+                    .explain(features.get(index), predicted_classes.get(index) as usize)
+                    .ok_or_else(|| {
+                        PredictPipelineExecutorError::ExecutionFailed(format!(
+                            "Explaining predictions is not supported for models of type `{}`.",
+                            self.classifier_data.trainer_method()
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(pipeline_result.with_explanations(
+            explanations,
+            feature_labels(&self.pipeline.feature_properties(), expected_dimension),
         ))
     }
 
@@ -45,4 +45,8 @@ impl NodeClassificationPredictPipelineConfig for NodeClassificationPredictPipeli
     fn include_predicted_probabilities(&self) -> bool {
         false
     }
+
+    fn explain(&self) -> bool {
+        self.base.explain()
+    }
 }
@@ -64,6 +64,7 @@ impl StreamResultBuilder<NodeClassificationPipelineResult, NodeClassificationStr
                 node_id: original.get(),
                 predicted_class: predicted,
                 predicted_probabilities: probs,
+                explanation: result.explanation_map(row_id),
             });
         }
 
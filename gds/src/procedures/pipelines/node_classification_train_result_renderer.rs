@@ -1,5 +1,6 @@
 use crate::applications::algorithms::machinery::{AlgorithmProcessingTimings, ResultRenderer};
 use crate::core::loading::GraphResources;
+use crate::ml::explain::feature_labels;
 use crate::procedures::pipelines::types::{MLTrainResult, NodeClassificationPipelineTrainResult};
 use crate::projection::eval::pipeline::node_pipeline::NodeClassificationModelResult;
 use crate::projection::eval::pipeline::NodePropertyPipelineBaseTrainConfig;
@@ -42,6 +43,16 @@ fn render_model_info(model_result: &NodeClassificationModelResult) -> HashMap<St
         "modelInfo".to_string(),
         Value::Object(model_result.model_info().to_map().into_iter().collect()),
     );
+    let feature_labels = feature_labels(
+        &model_result.model_info().pipeline().feature_properties(),
+        model_result.classifier().data().feature_dimension(),
+    );
+    info.insert(
+        "featureImportance".to_string(),
+        model_result
+            .training_statistics()
+            .feature_importance_map(&feature_labels),
+    );
     info
 }
 
@@ -3,26 +3,33 @@ use crate::core::loading::GraphResources;
 use crate::projection::eval::pipeline::node_pipeline::classification::node_classification_model_result::NodeClassificationModelResult;
 use crate::projection::eval::pipeline::node_pipeline::classification::node_classification_pipeline_train_config::NodeClassificationPipelineTrainConfig;
 
+use super::node_classification_model;
 use super::ModelPersister;
 
 /// Side effects for node classification training.
 ///
-/// Model persistence is not yet wired to the Rust model catalog; we currently
-/// emit a warning when a model is produced.
+/// Converts the trained model into its catalog form and hands it to the
+/// [`ModelPersister`], which optionally stores it to disk. No metadata is returned when
+/// the model could not be persisted.
 pub struct NodeClassificationTrainSideEffects {
     model_persister: ModelPersister,
-    #[allow(dead_code)]
     configuration: NodeClassificationPipelineTrainConfig,
+    model_name: String,
+    store_model_to_disk: bool,
 }
 
 impl NodeClassificationTrainSideEffects {
     pub fn new(
         model_persister: ModelPersister,
         configuration: NodeClassificationPipelineTrainConfig,
+        model_name: String,
+        store_model_to_disk: bool,
     ) -> Self {
         Self {
             model_persister,
             configuration,
+            model_name,
+            store_model_to_disk,
         }
     }
 }
@@ -33,10 +40,19 @@ impl SideEffect<NodeClassificationModelResult, ()> for NodeClassificationTrainSi
         _graph_resources: &GraphResources,
         result: Option<&NodeClassificationModelResult>,
     ) -> Option<()> {
-        if result.is_some() {
-            self.model_persister
-                .warn_unimplemented("NodeClassificationModelResult");
+        let result = result?;
+        let model =
+            node_classification_model(self.configuration.username(), &self.model_name, result)
+                .unwrap_or_else(|e| panic!("Failed to convert trained model: {e}"));
+        match self
+            .model_persister
+            .persist_model(model, self.store_model_to_disk)
+        {
+            Ok(()) => Some(()),
+            Err(e) => {
+                log::warn!("{e:#}");
+                None
+            }
         }
-        None
     }
 }
@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
 use crate::collections::HugeDoubleArray;
+use crate::ml::explain::Explanation;
 
 pub struct NodeRegressionPipelineResult {
     predicted_values: Arc<HugeDoubleArray>,
     predicted_node_ids: Option<Arc<Vec<u64>>>,
     root_node_count: usize,
+    explanations: Option<Arc<Vec<Explanation>>>,
+    feature_labels: Arc<Vec<String>>,
 }
 
 impl NodeRegressionPipelineResult {
@@ -31,9 +34,22 @@ impl NodeRegressionPipelineResult {
             predicted_values: Arc::new(predicted_values),
             predicted_node_ids: predicted_node_ids.map(Arc::new),
             root_node_count,
+            explanations: None,
+            feature_labels: Arc::new(Vec::new()),
         }
     }
 
+    /// Attaches one explanation per predicted node, labelled by `feature_labels`.
+    pub fn with_explanations(
+        mut self,
+        explanations: Vec<Explanation>,
+        feature_labels: Vec<String>,
+    ) -> Self {
+        self.explanations = Some(Arc::new(explanations));
+        self.feature_labels = Arc::new(feature_labels);
+        self
+    }
+
     pub fn predicted_values(&self) -> &Arc<HugeDoubleArray> {
         &self.predicted_values
     }
@@ -52,6 +68,18 @@ impl NodeRegressionPipelineResult {
     pub fn root_node_count(&self) -> usize {
         self.root_node_count
     }
+
+    pub fn explanations(&self) -> Option<&[Explanation]> {
+        self.explanations.as_deref().map(Vec::as_slice)
+    }
+
+    /// The explanation of the prediction at `index`, keyed by feature label.
+    pub fn explanation_map(&self, index: usize) -> Option<serde_json::Value> {
+        self.explanations
+            .as_ref()
+            .and_then(|explanations| explanations.get(index))
+            .map(|explanation| explanation.to_map(&self.feature_labels))
+    }
 }
 
 #[cfg(test)]
@@ -12,6 +12,11 @@ pub trait NodeRegressionPredictPipelineConfig {
     fn target_node_labels(&self) -> &[String];
     fn relationship_types(&self) -> &[String];
 
+    /// Whether to explain every prediction with per-feature contributions.
+    fn explain(&self) -> bool {
+        false
+    }
+
     fn node_labels(&self) -> &[String] {
         self.target_node_labels()
     }
@@ -26,6 +31,7 @@ pub struct NodeRegressionPredictPipelineBaseConfig {
     username_override: Option<String>,
     target_node_labels: Vec<String>,
     relationship_types: Vec<String>,
+    explain: bool,
 }
 
 impl NodeRegressionPredictPipelineBaseConfig {
@@ -46,9 +52,15 @@ impl NodeRegressionPredictPipelineBaseConfig {
             username_override,
             target_node_labels,
             relationship_types,
+            explain: false,
         }
     }
 
+    pub fn with_explain(mut self, explain: bool) -> Self {
+        self.explain = explain;
+        self
+    }
+
     pub fn from_map(username: String, mut config: AnyMap) -> Self {
         let graph_name = config
             .remove("graphName")
@@ -91,6 +103,11 @@ impl NodeRegressionPredictPipelineBaseConfig {
             })
             .unwrap_or_default();
 
+        let explain = config
+            .remove("explain")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
         Self::new(
             graph_name,
             concurrency,
@@ -100,6 +117,7 @@ impl NodeRegressionPredictPipelineBaseConfig {
             target_node_labels,
             relationship_types,
         )
+        .with_explain(explain)
     }
 
     pub fn with_train_config_defaults(
@@ -151,6 +169,7 @@ impl NodeRegressionPredictPipelineBaseConfig {
                     .collect(),
             ),
         );
+        map.insert("explain".to_string(), Value::Bool(self.explain));
         map
     }
 }
@@ -185,6 +204,10 @@ impl NodeRegressionPredictPipelineConfig for NodeRegressionPredictPipelineBaseCo
     fn relationship_types(&self) -> &[String] {
         &self.relationship_types
     }
+
+    fn explain(&self) -> bool {
+        self.explain
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,8 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
-use crate::ml::models::{FeaturesFactory, Regressor};
+use crate::ml::explain::feature_labels;
+use crate::ml::models::{Features, FeaturesFactory, Regressor};
 use crate::ml::node_regression::NodeRegressionPredict;
 use crate::projection::eval::pipeline::{
     node_pipeline::NodePropertyPredictPipeline, PipelineGraphFilter, PredictPipelineExecutor,
@@ -135,19 +136,39 @@ impl<'a> PredictPipelineExecutor<NodePropertyPredictPipeline, NodeRegressionPipe
             )));
         }
 
+        let features: Arc<dyn Features> = Arc::from(features);
         let predict = NodeRegressionPredict::new(
             Arc::clone(&self.regressor),
-            Arc::from(features),
+            Arc::clone(&features),
             Concurrency::of(self.configuration.concurrency()),
             self.progress_tracker.clone(),
             self.termination_flag.clone(),
         );
 
         let predictions = predict.compute();
-        Ok(NodeRegressionPipelineResult::of_for_node_ids(
+        let pipeline_result = NodeRegressionPipelineResult::of_for_node_ids(
             predictions,
             prediction_node_ids,
             root_node_count,
+        );
+        if !self.configuration.explain() {
+            return Ok(pipeline_result);
+        }
+
+        let explanations = (0..features.size())
+            .map(|index| {
+                self.regressor.explain(features.get(index)).ok_or_else(|| {
+                    PredictPipelineExecutorError::ExecutionFailed(format!(
+                        "Explaining predictions is not supported for models of type `{}`.",
+                        self.regressor.data().trainer_method()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(pipeline_result.with_explanations(
+            explanations,
+            feature_labels(&self.pipeline.feature_properties(), expected_dimension),
         ))
     }
 
@@ -46,6 +46,7 @@ impl StreamResultBuilder<NodeRegressionPipelineResult, NodeRegressionStreamResul
             rows.push(NodeRegressionStreamResult {
                 node_id: original.get(),
                 predicted_value: predicted.get(row_id),
+                explanation: result.explanation_map(row_id),
             });
         }
 
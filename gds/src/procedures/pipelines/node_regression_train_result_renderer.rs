@@ -1,5 +1,6 @@
 use crate::applications::algorithms::machinery::{AlgorithmProcessingTimings, ResultRenderer};
 use crate::core::loading::GraphResources;
+use crate::ml::explain::feature_labels;
 use crate::procedures::pipelines::types::{MLTrainResult, NodeRegressionPipelineTrainResult};
 use crate::projection::eval::pipeline::node_pipeline::NodePropertyPipelineBaseTrainConfig;
 use crate::projection::eval::pipeline::node_pipeline::NodeRegressionTrainPipelineResult;
@@ -42,6 +43,16 @@ fn render_model_info(result: &NodeRegressionTrainPipelineResult) -> HashMap<Stri
         "modelInfo".to_string(),
         Value::Object(result.model_info().to_map().into_iter().collect()),
     );
+    let feature_labels = feature_labels(
+        &result.model_info().pipeline().feature_properties(),
+        result.regressor().data().feature_dimension(),
+    );
+    info.insert(
+        "featureImportance".to_string(),
+        result
+            .training_statistics()
+            .feature_importance_map(&feature_labels),
+    );
     info
 }
 
@@ -6,10 +6,13 @@ use crate::applications::services::logging::Log;
 use crate::collections::backends::vec::VecDouble;
 use crate::core::graph_dimensions::ConcreteGraphDimensions;
 use crate::core::loading::GraphResources;
+use crate::core::model::{ModelFileReader, ModelFileWriter};
+use crate::ml::explain::feature_labels;
 use crate::ml::metrics::{ClassificationMetricSpecification, RegressionMetric};
 use crate::ml::models::automl::TunableTrainerConfig as AutoMlTrainerConfig;
 use crate::ml::models::Regressor;
 use crate::ml::models::TrainingMethod as MlTrainingMethod;
+use crate::ml::training::TrainingStatistics;
 use crate::projection::eval::pipeline::LinkFeatureStepFactory;
 use crate::projection::eval::pipeline::LinkPredictionSplitConfig;
 use crate::projection::eval::pipeline::LinkPredictionTrainingPipeline;
@@ -31,9 +34,9 @@ use crate::projection::eval::pipeline::{
 use crate::projection::eval::pipeline::{NodePropertyStep, PipelineCatalogEntry};
 use crate::task::concurrency::Concurrency;
 use crate::task::memory::{MemoryEstimationResult, MemoryRange, MemoryTree};
-use crate::task::runtime::TaskStage;
 use crate::task::runtime::TaskFrameKind;
 use crate::task::runtime::TaskFrameStorageBackend;
+use crate::task::runtime::TaskStage;
 use crate::types::catalog::{GraphCatalog, InMemoryGraphCatalog};
 use crate::types::graph_store::DefaultGraphStore;
 use crate::types::graph_store::GraphStore;
@@ -43,6 +46,7 @@ use crate::types::user::User;
 use dyn_clone::clone_box;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use super::node_classification_predict_computation::NodeClassificationPredictComputation;
@@ -70,6 +74,10 @@ use super::node_regression_predict_pipeline_stream_result_builder::NodeRegressio
 use super::node_regression_train_computation::NodeRegressionTrainComputation;
 use super::pipeline_catalog_result_transformer::create_pipeline_catalog_result;
 use super::pipeline_info_result_transformer::create_pipeline_info_result;
+use super::pipeline_model_data::{
+    node_classification_model, node_classification_model_result, node_regression_model,
+    node_regression_model_parts, PipelineModel,
+};
 use super::{PipelineName, PipelineRepository};
 use crate::procedures::pipelines::predicted_probabilities::as_properties;
 use crate::procedures::pipelines::types::{
@@ -97,8 +105,8 @@ pub struct PipelineApplications {
 
 struct NodeRegressionRuntimeModel {
     regressor: Arc<dyn Regressor>,
-    pipeline: NodePropertyPredictPipeline,
     train_config: NodeRegressionPipelineTrainConfig,
+    model_info: NodeRegressionPipelineModelInfo,
 }
 
 #[derive(Default)]
@@ -110,6 +118,7 @@ struct PipelineRuntimeModels {
 #[derive(Default)]
 pub struct PipelineModelStore {
     models: parking_lot::RwLock<PipelineRuntimeModels>,
+    store_location: parking_lot::RwLock<Option<PathBuf>>,
 }
 
 impl PipelineModelStore {
@@ -117,6 +126,82 @@ impl PipelineModelStore {
         Self::default()
     }
 
+    /// Directory below which `storeModelToDisk` writes trained pipeline models.
+    pub fn with_store_location(self, store_location: impl Into<PathBuf>) -> Self {
+        *self.store_location.write() = Some(store_location.into());
+        self
+    }
+
+    pub fn store_location(&self) -> Option<PathBuf> {
+        self.store_location.read().clone()
+    }
+
+    /// Writes a trained NC/NR model to the store location.
+    pub fn store(&self, username: &str, model_name: &str) -> Result<PathBuf, String> {
+        let store_location = self
+            .store_location()
+            .ok_or_else(|| "No model store location is configured".to_string())?;
+        let key = (username.to_string(), model_name.to_string());
+
+        let model = {
+            let models = self.models.read();
+            if let Some(model) = models.node_classification.get(&key) {
+                node_classification_model(username, model_name, model)
+            } else if let Some(model) = models.node_regression.get(&key) {
+                node_regression_model(
+                    username,
+                    model_name,
+                    model.regressor.as_ref(),
+                    &model.train_config,
+                    &model.model_info,
+                )
+            } else {
+                return Err(format!(
+                    "Model `{model_name}` does not exist for user `{username}`"
+                ));
+            }
+        }
+        .map_err(|e| e.to_string())?;
+
+        ModelFileWriter::write(&model, &store_location).map_err(|e| e.to_string())
+    }
+
+    /// Loads a stored NC/NR model from the store location, making it available for prediction.
+    pub fn load(&self, username: &str, model_name: &str) -> Result<(), String> {
+        let store_location = self
+            .store_location()
+            .ok_or_else(|| "No model store location is configured".to_string())?;
+        self.verify_can_store(username, model_name)?;
+
+        let model: PipelineModel =
+            ModelFileReader::for_model(&store_location, username, model_name)
+                .and_then(|reader| reader.read())
+                .map_err(|e| e.to_string())?;
+
+        match model.algo_type() {
+            NodeClassificationTrainingPipeline::MODEL_TYPE => {
+                let result = node_classification_model_result(&model).map_err(|e| e.to_string())?;
+                self.insert_node_classification(username, model_name.to_string(), Arc::new(result))
+            }
+            NodeRegressionTrainingPipeline::MODEL_TYPE => {
+                let (regressor, train_config, model_info) =
+                    node_regression_model_parts(&model).map_err(|e| e.to_string())?;
+                self.insert_node_regression(
+                    username,
+                    model_name.to_string(),
+                    Arc::new(NodeRegressionRuntimeModel {
+                        regressor: Arc::from(regressor),
+                        train_config,
+                        model_info,
+                    }),
+                )
+            }
+            other => Err(format!(
+                "Model `{model_name}` of type `{other}` cannot be loaded as a node pipeline model"
+            )),
+        }
+    }
+
     fn verify_can_store(&self, username: &str, model_name: &str) -> Result<(), String> {
         let key = (username.to_string(), model_name.to_string());
         let models = self.models.read();
@@ -800,6 +885,7 @@ impl PipelineApplications {
         self.model_store
             .verify_can_store(self.user.username(), &model_name)
             .unwrap_or_else(|e| panic!("nodeClassification.train failed: {e}"));
+        let store_model_to_disk = optional_bool(&configuration, "storeModelToDisk");
         let train_config = parse_node_classification_train_config(&self.user, configuration);
         let computation = NodeClassificationTrainComputation::new(
             self.pipeline_repository.clone(),
@@ -814,8 +900,17 @@ impl PipelineApplications {
         let model_result = Arc::new(model_result);
 
         self.model_store
-            .insert_node_classification(self.user.username(), model_name, Arc::clone(&model_result))
+            .insert_node_classification(
+                self.user.username(),
+                model_name.clone(),
+                Arc::clone(&model_result),
+            )
             .unwrap_or_else(|e| panic!("nodeClassification.train failed: {e}"));
+        if store_model_to_disk {
+            self.model_store
+                .store(self.user.username(), &model_name)
+                .unwrap_or_else(|e| panic!("nodeClassification.train failed: {e}"));
+        }
 
         render_node_classification_train_result(
             model_result.as_ref(),
@@ -944,7 +1039,7 @@ impl PipelineApplications {
         let computation = NodeRegressionPredictComputation::new(
             config_with_defaults,
             "NodeRegressionPredictPipeline".to_string(),
-            clone_predict_pipeline(&trained_model.pipeline),
+            clone_predict_pipeline(trained_model.model_info.pipeline()),
             Arc::clone(&trained_model.regressor),
         );
 
@@ -1047,7 +1142,7 @@ impl PipelineApplications {
         let computation = NodeRegressionPredictComputation::new(
             config_with_defaults,
             "NodeRegressionPredictPipeline".to_string(),
-            clone_predict_pipeline(&trained_model.pipeline),
+            clone_predict_pipeline(trained_model.model_info.pipeline()),
             Arc::clone(&trained_model.regressor),
         );
 
@@ -1091,6 +1186,7 @@ impl PipelineApplications {
         self.model_store
             .verify_can_store(self.user.username(), &model_name)
             .unwrap_or_else(|e| panic!("nodeRegression.train failed: {e}"));
+        let store_model_to_disk = optional_bool(&configuration, "storeModelToDisk");
         let train_config = parse_node_regression_train_config(&self.user, configuration);
         let computation = NodeRegressionTrainComputation::new(
             self.pipeline_repository.clone(),
@@ -1103,24 +1199,32 @@ impl PipelineApplications {
             .unwrap_or_else(|e| panic!("nodeRegression.train failed: {e}"));
 
         let (regressor, train_config, model_info, training_statistics) = model_result.into_parts();
+        let results = render_node_regression_train_result(
+            &train_config,
+            &model_info,
+            regressor.data().feature_dimension(),
+            &training_statistics,
+            AlgorithmProcessingTimings::unavailable(),
+        );
+
         self.model_store
             .insert_node_regression(
                 self.user.username(),
-                model_name,
+                model_name.clone(),
                 Arc::new(NodeRegressionRuntimeModel {
                     regressor: Arc::from(regressor),
-                    pipeline: clone_predict_pipeline(model_info.pipeline()),
-                    train_config: train_config.clone(),
+                    train_config,
+                    model_info,
                 }),
             )
             .unwrap_or_else(|e| panic!("nodeRegression.train failed: {e}"));
+        if store_model_to_disk {
+            self.model_store
+                .store(self.user.username(), &model_name)
+                .unwrap_or_else(|e| panic!("nodeRegression.train failed: {e}"));
+        }
 
-        render_node_regression_train_result(
-            &train_config,
-            &model_info,
-            &training_statistics.to_map(),
-            AlgorithmProcessingTimings::unavailable(),
-        )
+        results
     }
 
     pub fn node_regression_train_estimate(
@@ -1508,7 +1612,7 @@ fn rebuild_node_regression_with_features(
     next
 }
 
-fn parse_node_classification_train_config(
+pub(super) fn parse_node_classification_train_config(
     user: &User,
     configuration: AnyMap,
 ) -> NodeClassificationPipelineTrainConfig {
@@ -1536,7 +1640,7 @@ fn parse_node_classification_train_config(
     .with_username(user.username())
 }
 
-fn parse_node_regression_train_config(
+pub(super) fn parse_node_regression_train_config(
     user: &User,
     configuration: AnyMap,
 ) -> NodeRegressionPipelineTrainConfig {
@@ -1587,6 +1691,12 @@ fn parse_trainer_config(
         TrainingMethod::MLPClassification => MlTrainingMethod::MLPClassification,
         TrainingMethod::LinearRegression => MlTrainingMethod::LinearRegression,
         TrainingMethod::RandomForestRegression => MlTrainingMethod::RandomForestRegression,
+        TrainingMethod::GradientBoostingClassification => {
+            MlTrainingMethod::GradientBoostingClassification
+        }
+        TrainingMethod::GradientBoostingRegression => MlTrainingMethod::GradientBoostingRegression,
+        TrainingMethod::MLPRegression => MlTrainingMethod::MLPRegression,
+        TrainingMethod::SVMRegression => MlTrainingMethod::SVMRegression,
     };
     let inner = AutoMlTrainerConfig::of(&configuration, ml_method)
         .unwrap_or_else(|e| panic!("Invalid {method} trainer configuration: {e}"));
@@ -1626,6 +1736,13 @@ fn optional_usize(configuration: &AnyMap, key: &str) -> Option<usize> {
     optional_u64(configuration, key).map(|value| value as usize)
 }
 
+fn optional_bool(configuration: &AnyMap, key: &str) -> bool {
+    configuration
+        .get(key)
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
 fn optional_string(configuration: &AnyMap, key: &str) -> Option<String> {
     configuration
         .get(key)
@@ -1671,6 +1788,16 @@ fn render_node_classification_train_result(
         "modelInfo".to_string(),
         Value::Object(model_result.model_info().to_map().into_iter().collect()),
     );
+    let feature_labels = feature_labels(
+        &model_result.model_info().pipeline().feature_properties(),
+        model_result.classifier().data().feature_dimension(),
+    );
+    model_info.insert(
+        "featureImportance".to_string(),
+        model_result
+            .training_statistics()
+            .feature_importance_map(&feature_labels),
+    );
 
     let train_config = model_result.train_config();
     let mut configuration = HashMap::new();
@@ -1737,7 +1864,8 @@ fn render_node_classification_train_result(
 fn render_node_regression_train_result(
     train_config: &NodeRegressionPipelineTrainConfig,
     model_info: &NodeRegressionPipelineModelInfo,
-    training_statistics: &AnyMap,
+    feature_dimension: usize,
+    training_statistics: &TrainingStatistics,
     timings: AlgorithmProcessingTimings,
 ) -> Vec<NodeRegressionPipelineTrainResult> {
     let mut rendered_model_info = HashMap::new();
@@ -1749,6 +1877,14 @@ fn render_node_regression_train_result(
         "modelInfo".to_string(),
         Value::Object(model_info.to_map().into_iter().collect()),
     );
+    let feature_labels = feature_labels(
+        &model_info.pipeline().feature_properties(),
+        feature_dimension,
+    );
+    rendered_model_info.insert(
+        "featureImportance".to_string(),
+        training_statistics.feature_importance_map(&feature_labels),
+    );
 
     let mut configuration = HashMap::new();
     configuration.insert(
@@ -1807,7 +1943,7 @@ fn render_node_regression_train_result(
             model_info: rendered_model_info,
             configuration,
         },
-        model_selection_stats: training_statistics.clone(),
+        model_selection_stats: training_statistics.to_map(),
     }]
 }
 
@@ -0,0 +1,537 @@
+//! Catalog representation of trained pipeline models.
+//!
+//! Trained NC/NR/LP pipelines are stored as `Model<PipelineModelData,
+//! PipelineModelTrainConfig, PipelineModelCustomInfo>`:
+//!
+//! - the data holds the trained classifier/regressor bytes (bincode in `model.data`);
+//! - the train config holds the procedure-level train configuration map;
+//! - the custom info holds the model info (best parameters, metrics, classes) and the
+//!   frozen predict pipeline, i.e. node property steps and feature steps.
+//!
+//! Everything JSON-valued lives in the config and custom info, which end up in the JSON
+//! model header, since bincode cannot decode `serde_json::Value`.
+
+use crate::config::{BaseConfig, Config};
+use crate::core::model::{Model, ModelCatalogCustomInfo, ModelConfig, ModelData};
+use crate::ml::models::{Classifier, ModelSerializer, Regressor, SerializedModelData};
+use crate::ml::training::statistics::TrainingStatistics;
+use crate::projection::eval::pipeline::link_pipeline::train::LinkPredictionTrainConfig;
+use crate::projection::eval::pipeline::LinkFeatureStepFactory;
+use crate::projection::eval::pipeline::LinkPredictionModelInfo;
+use crate::projection::eval::pipeline::LinkPredictionPredictPipeline;
+use crate::projection::eval::pipeline::LinkPredictionTrainingPipeline;
+use crate::projection::eval::pipeline::NodeClassificationModelResult;
+use crate::projection::eval::pipeline::NodeClassificationPipelineModelInfo;
+use crate::projection::eval::pipeline::NodeClassificationTrainingPipeline;
+use crate::projection::eval::pipeline::NodeFeatureStep;
+use crate::projection::eval::pipeline::NodePropertyPipelineBaseTrainConfig;
+use crate::projection::eval::pipeline::NodePropertyPredictPipeline;
+use crate::projection::eval::pipeline::NodeRegressionPipelineModelInfo;
+use crate::projection::eval::pipeline::NodeRegressionPipelineTrainConfig;
+use crate::projection::eval::pipeline::NodeRegressionTrainingPipeline;
+use crate::projection::eval::pipeline::{ExecutableNodePropertyStep, NodePropertyStep, Pipeline};
+use crate::types::schema::GraphSchema;
+use crate::types::user::User;
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+use super::pipeline_applications::{
+    parse_node_classification_train_config, parse_node_regression_train_config,
+};
+use super::types::AnyMap;
+
+/// Catalog model type for trained pipelines.
+pub type PipelineModel =
+    Model<PipelineModelData, PipelineModelTrainConfig, PipelineModelCustomInfo>;
+
+/// Trained classifier or regressor of a pipeline model.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineModelData {
+    pub model: SerializedModelData,
+}
+
+impl ModelData for PipelineModelData {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Train configuration of a pipeline model, kept in its procedure map form.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineModelTrainConfig {
+    pub model_name: String,
+    pub model_user: String,
+    pub parameters: AnyMap,
+}
+
+impl Config for PipelineModelTrainConfig {}
+
+impl BaseConfig for PipelineModelTrainConfig {
+    fn parameters(&self) -> HashMap<String, Value> {
+        let mut parameters = self.parameters.clone();
+        parameters.insert("modelName".to_string(), json!(self.model_name));
+        parameters
+    }
+}
+
+impl ModelConfig for PipelineModelTrainConfig {
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn model_user(&self) -> &str {
+        &self.model_user
+    }
+}
+
+/// A node property step as recorded in a stored pipeline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodePropertyStepSnapshot {
+    pub proc_name: String,
+    pub config: AnyMap,
+    pub context_node_labels: Vec<String>,
+    pub context_relationship_types: Vec<String>,
+}
+
+impl NodePropertyStepSnapshot {
+    fn of(step: &dyn ExecutableNodePropertyStep) -> Self {
+        Self {
+            proc_name: step.proc_name().to_string(),
+            config: step.config().clone(),
+            context_node_labels: step.context_node_labels().to_vec(),
+            context_relationship_types: step.context_relationship_types().to_vec(),
+        }
+    }
+
+    fn to_step(&self) -> Box<dyn ExecutableNodePropertyStep> {
+        Box::new(NodePropertyStep::with_context(
+            self.proc_name.clone(),
+            self.config.clone(),
+            self.context_node_labels.clone(),
+            self.context_relationship_types.clone(),
+        ))
+    }
+}
+
+/// A link feature step as recorded in a stored link prediction pipeline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkFeatureStepSnapshot {
+    pub name: String,
+    pub node_properties: Vec<String>,
+}
+
+/// Model info and frozen predict pipeline of a stored pipeline model.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineModelCustomInfo {
+    pub best_parameters: Value,
+    pub metrics: AnyMap,
+    #[serde(default)]
+    pub classes: Vec<i64>,
+    #[serde(default)]
+    pub node_property_steps: Vec<NodePropertyStepSnapshot>,
+    #[serde(default)]
+    pub feature_properties: Vec<String>,
+    #[serde(default)]
+    pub link_feature_steps: Vec<LinkFeatureStepSnapshot>,
+}
+
+impl PipelineModelCustomInfo {
+    fn of_node_pipeline(
+        best_parameters: &Value,
+        metrics: &HashMap<String, Value>,
+        classes: Vec<i64>,
+        pipeline: &NodePropertyPredictPipeline,
+    ) -> Self {
+        Self {
+            best_parameters: best_parameters.clone(),
+            metrics: metrics.clone(),
+            classes,
+            node_property_steps: pipeline
+                .node_property_steps()
+                .iter()
+                .map(|step| NodePropertyStepSnapshot::of(step.as_ref()))
+                .collect(),
+            feature_properties: pipeline.feature_properties(),
+            link_feature_steps: Vec::new(),
+        }
+    }
+
+    fn node_predict_pipeline(&self) -> NodePropertyPredictPipeline {
+        NodePropertyPredictPipeline::new(
+            self.node_property_steps
+                .iter()
+                .map(NodePropertyStepSnapshot::to_step)
+                .collect(),
+            self.feature_properties
+                .iter()
+                .map(NodeFeatureStep::of)
+                .collect(),
+        )
+    }
+
+    fn link_predict_pipeline(&self) -> Result<LinkPredictionPredictPipeline> {
+        let feature_steps = self
+            .link_feature_steps
+            .iter()
+            .map(|step| {
+                LinkFeatureStepFactory::create_from_name(&step.name, step.node_properties.clone())
+                    .map_err(|err| anyhow!(err))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(LinkPredictionPredictPipeline::from_steps(
+            Default::default(),
+            feature_steps,
+        ))
+    }
+}
+
+impl ModelCatalogCustomInfo for PipelineModelCustomInfo {
+    fn to_map(&self) -> Value {
+        serde_json::to_value(self).unwrap_or(Value::Null)
+    }
+
+    fn training_method(&self) -> Option<String> {
+        self.best_parameters
+            .get("methodName")
+            .and_then(Value::as_str)
+            .map(ToString::to_string)
+    }
+}
+
+/// Converts a trained node classification model into its catalog form.
+pub fn node_classification_model(
+    username: &str,
+    model_name: &str,
+    result: &NodeClassificationModelResult,
+) -> Result<PipelineModel> {
+    let train_config = result.train_config();
+    let metrics = train_config
+        .metrics_specs()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    let model_info = result.model_info();
+
+    Ok(Model::new(
+        username.to_string(),
+        model_name.to_string(),
+        result.model_type().to_string(),
+        result.graph_schema().clone(),
+        Some(PipelineModelData {
+            model: ModelSerializer::serialize_classifier(result.classifier_data())?,
+        }),
+        node_train_config(username, model_name, train_config, metrics),
+        result.gds_version().to_string(),
+        PipelineModelCustomInfo::of_node_pipeline(
+            model_info.best_parameters(),
+            model_info.metrics(),
+            model_info.classes().to_vec(),
+            model_info.pipeline(),
+        ),
+    ))
+}
+
+/// Restores a node classification model from its catalog form.
+///
+/// Training statistics are not persisted, so the restored result carries empty ones.
+pub fn node_classification_model_result(
+    model: &PipelineModel,
+) -> Result<NodeClassificationModelResult> {
+    expect_algo_type(model, NodeClassificationTrainingPipeline::MODEL_TYPE)?;
+    let data = loaded_data(model)?;
+    let info = model.custom_info();
+    let train_config = parse_node_classification_train_config(
+        &User::new(model.creator().to_string(), false),
+        model.train_config().parameters.clone(),
+    );
+
+    Ok(NodeClassificationModelResult::new_with_metadata(
+        model.gds_version().to_string(),
+        model.algo_type().to_string(),
+        model.graph_schema().clone(),
+        ModelSerializer::deserialize_classifier(&data.model)?,
+        train_config,
+        NodeClassificationPipelineModelInfo::new(
+            info.best_parameters.clone(),
+            info.metrics.clone(),
+            info.node_predict_pipeline(),
+            info.classes.clone(),
+        ),
+        TrainingStatistics::new(&[]),
+    ))
+}
+
+/// Converts a trained node regression model into its catalog form.
+pub fn node_regression_model(
+    username: &str,
+    model_name: &str,
+    regressor: &dyn Regressor,
+    train_config: &NodeRegressionPipelineTrainConfig,
+    model_info: &NodeRegressionPipelineModelInfo,
+) -> Result<PipelineModel> {
+    let metrics = train_config
+        .metrics()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    Ok(Model::new(
+        username.to_string(),
+        model_name.to_string(),
+        NodeRegressionTrainingPipeline::MODEL_TYPE.to_string(),
+        GraphSchema::empty(),
+        Some(PipelineModelData {
+            model: ModelSerializer::serialize_regressor(regressor.data())?,
+        }),
+        node_train_config(username, model_name, train_config, metrics),
+        env!("CARGO_PKG_VERSION").to_string(),
+        PipelineModelCustomInfo::of_node_pipeline(
+            model_info.best_parameters(),
+            model_info.metrics(),
+            Vec::new(),
+            model_info.pipeline(),
+        ),
+    ))
+}
+
+/// Restores the regressor, train config and model info of a node regression model.
+pub fn node_regression_model_parts(
+    model: &PipelineModel,
+) -> Result<(
+    Box<dyn Regressor>,
+    NodeRegressionPipelineTrainConfig,
+    NodeRegressionPipelineModelInfo,
+)> {
+    expect_algo_type(model, NodeRegressionTrainingPipeline::MODEL_TYPE)?;
+    let data = loaded_data(model)?;
+    let info = model.custom_info();
+    let train_config = parse_node_regression_train_config(
+        &User::new(model.creator().to_string(), false),
+        model.train_config().parameters.clone(),
+    );
+
+    Ok((
+        ModelSerializer::deserialize_regressor(&data.model)?,
+        train_config,
+        NodeRegressionPipelineModelInfo::new(
+            info.best_parameters.clone(),
+            info.metrics.clone(),
+            info.node_predict_pipeline(),
+        ),
+    ))
+}
+
+/// Converts a trained link prediction model into its catalog form.
+pub fn link_prediction_model(
+    username: &str,
+    model_name: &str,
+    classifier: &dyn Classifier,
+    train_config: &LinkPredictionTrainConfig,
+    model_info: &LinkPredictionModelInfo,
+) -> Result<PipelineModel> {
+    let custom_info = PipelineModelCustomInfo {
+        best_parameters: model_info.best_parameters().clone(),
+        metrics: model_info.metrics().clone(),
+        link_feature_steps: model_info
+            .pipeline()
+            .feature_steps()
+            .iter()
+            .map(|step| LinkFeatureStepSnapshot {
+                name: step.name().to_string(),
+                node_properties: step.input_node_properties(),
+            })
+            .collect(),
+        ..PipelineModelCustomInfo::default()
+    };
+
+    Ok(Model::new(
+        username.to_string(),
+        model_name.to_string(),
+        LinkPredictionTrainingPipeline::MODEL_TYPE.to_string(),
+        GraphSchema::empty(),
+        Some(PipelineModelData {
+            model: ModelSerializer::serialize_classifier(classifier.data())?,
+        }),
+        PipelineModelTrainConfig {
+            model_name: model_name.to_string(),
+            model_user: username.to_string(),
+            parameters: train_config.to_map(),
+        },
+        env!("CARGO_PKG_VERSION").to_string(),
+        custom_info,
+    ))
+}
+
+/// Restores the classifier and model info of a link prediction model.
+pub fn link_prediction_model_parts(
+    model: &PipelineModel,
+) -> Result<(Box<dyn Classifier>, LinkPredictionModelInfo)> {
+    expect_algo_type(model, LinkPredictionTrainingPipeline::MODEL_TYPE)?;
+    let data = loaded_data(model)?;
+    let info = model.custom_info();
+
+    Ok((
+        ModelSerializer::deserialize_classifier(&data.model)?,
+        LinkPredictionModelInfo::new(
+            info.best_parameters.clone(),
+            info.metrics.clone(),
+            info.link_predict_pipeline()?,
+        ),
+    ))
+}
+
+fn node_train_config(
+    username: &str,
+    model_name: &str,
+    train_config: &dyn NodePropertyPipelineBaseTrainConfig,
+    metrics: Vec<String>,
+) -> PipelineModelTrainConfig {
+    let mut parameters = AnyMap::new();
+    parameters.insert("pipeline".to_string(), json!(train_config.pipeline()));
+    parameters.insert(
+        "targetNodeLabels".to_string(),
+        json!(train_config.target_node_labels()),
+    );
+    parameters.insert(
+        "relationshipTypes".to_string(),
+        json!(train_config.relationship_types()),
+    );
+    parameters.insert(
+        "targetProperty".to_string(),
+        json!(train_config.target_property()),
+    );
+    if let Some(seed) = train_config.random_seed() {
+        parameters.insert("randomSeed".to_string(), json!(seed));
+    }
+    parameters.insert("concurrency".to_string(), json!(train_config.concurrency()));
+    parameters.insert("metrics".to_string(), json!(metrics));
+
+    PipelineModelTrainConfig {
+        model_name: model_name.to_string(),
+        model_user: username.to_string(),
+        parameters,
+    }
+}
+
+fn expect_algo_type(model: &PipelineModel, algo_type: &str) -> Result<()> {
+    if model.algo_type() != algo_type {
+        bail!(
+            "Model `{}` is of type `{}`, expected `{algo_type}`",
+            model.name(),
+            model.algo_type()
+        );
+    }
+    Ok(())
+}
+
+fn loaded_data(model: &PipelineModel) -> Result<&PipelineModelData> {
+    model
+        .data()
+        .ok_or_else(|| anyhow!("Model `{}` is not loaded", model.name()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::model::{ModelFileReader, ModelFileWriter};
+    use crate::ml::decision_tree::{DecisionTreePredictor, TreeNode};
+    use crate::ml::metrics::ClassificationMetricSpecification;
+    use crate::ml::models::random_forest::RandomForestClassifierData;
+    use crate::ml::models::ClassifierFactory;
+    use crate::projection::eval::pipeline::NodeClassificationPipelineTrainConfig;
+    use std::sync::Arc;
+
+    fn trained_result() -> NodeClassificationModelResult {
+        let mut root = TreeNode::new_split(0, 0.5);
+        root.set_left_child(TreeNode::new_leaf(0usize));
+        root.set_right_child(TreeNode::new_leaf(1usize));
+        let classifier = ClassifierFactory::create(&RandomForestClassifierData {
+            decision_trees: vec![Arc::new(DecisionTreePredictor::new(root))],
+            num_classes: 2,
+            num_features: 1,
+        });
+
+        let train_config = NodeClassificationPipelineTrainConfig::new_with_username(
+            "alice".to_string(),
+            "pipe".to_string(),
+            vec!["Person".to_string()],
+            vec!["KNOWS".to_string()],
+            "class".to_string(),
+            Some(42),
+            2,
+            ClassificationMetricSpecification::parse_list(&["ACCURACY".to_string()]).unwrap(),
+        );
+        let pipeline = NodePropertyPredictPipeline::new(
+            vec![Box::new(NodePropertyStep::with_context(
+                "gds.pageRank.mutate".to_string(),
+                HashMap::from([("mutateProperty".to_string(), json!("pr"))]),
+                vec!["Person".to_string()],
+                Vec::new(),
+            ))],
+            vec![NodeFeatureStep::of("pr")],
+        );
+        let model_info = NodeClassificationPipelineModelInfo::new(
+            json!({ "methodName": "RandomForest" }),
+            HashMap::from([("ACCURACY".to_string(), json!({ "test": 1.0 }))]),
+            pipeline,
+            vec![3, 7],
+        );
+
+        NodeClassificationModelResult::new(
+            classifier,
+            train_config,
+            model_info,
+            TrainingStatistics::new(&[]),
+        )
+    }
+
+    #[test]
+    fn node_classification_model_survives_disk_round_trip() {
+        let root =
+            std::env::temp_dir().join(format!("gds-pipeline-model-{}", uuid::Uuid::new_v4()));
+        let original = trained_result();
+
+        let model = node_classification_model("alice", "nc-model", &original).unwrap();
+        assert_eq!(
+            model.custom_info().training_method().as_deref(),
+            Some("RandomForest")
+        );
+        let dir = ModelFileWriter::write(&model, &root).unwrap();
+        let loaded: PipelineModel = ModelFileReader::new(&dir).read().unwrap();
+        let restored = node_classification_model_result(&loaded).unwrap();
+
+        assert_eq!(restored.model_info().classes(), &[3, 7]);
+        assert_eq!(
+            restored.model_info().pipeline().feature_properties(),
+            vec!["pr"]
+        );
+        let steps = restored.model_info().pipeline().node_property_steps();
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].proc_name(), "gds.pageRank.mutate");
+        assert_eq!(steps[0].context_node_labels(), &["Person".to_string()]);
+
+        let config = restored.train_config();
+        assert_eq!(config.pipeline(), "pipe");
+        assert_eq!(config.random_seed(), Some(42));
+        assert_eq!(config.relationship_types(), vec!["KNOWS"]);
+        assert_eq!(
+            restored.classifier().predict_probabilities(&[0.9]),
+            original.classifier().predict_probabilities(&[0.9])
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn rejects_mismatched_model_type() {
+        let model = node_classification_model("alice", "nc-model", &trained_result()).unwrap();
+        assert!(node_regression_model_parts(&model).is_err());
+        assert!(link_prediction_model_parts(&model).is_err());
+    }
+}
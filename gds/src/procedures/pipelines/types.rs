@@ -76,6 +76,8 @@ pub struct NodeClassificationStreamResult {
     pub node_id: i64,
     pub predicted_class: i64,
     pub predicted_probabilities: Option<Vec<f64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +85,8 @@ pub struct NodeClassificationStreamResult {
 pub struct NodeRegressionStreamResult {
     pub node_id: i64,
     pub predicted_value: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<Value>,
 }
 
 /// Minimal stand-in for the Java `StandardMutateResult` supertype.
@@ -0,0 +1,343 @@
+//! Document Vectors Facade
+//!
+//! **What is it?**: Puts TF-IDF / BM25 document vectors onto a graph store
+//! **Why care?**: Document dedup and clustering can then run through `knn` or `louvain`
+//! **Complexity**: O(D² · nnz) for the exact top-k similarity graph over D documents
+//! **Best for**: Corpora projected with [`TextGraphBuilder`](crate::collections::dataset::corpus::TextGraphBuilder)
+//!
+//! Two mutations are offered:
+//! - `mutate_vectors` writes dense document vectors as a double-array node
+//!   property (non-document nodes hold no value);
+//! - `mutate_similarity` adds a relationship type linking every document to
+//!   its `top_k` most cosine-similar documents, with the similarity as a
+//!   relationship property.
+
+use crate::algo::algorithms::{ConfigValidator, MutationResult, Result};
+use crate::collections::backends::vec::VecDoubleArray;
+use crate::collections::dataset::corpus::{TextGraph, TextNodeKind};
+use crate::collections::dataset::language::text::SparseVector;
+use crate::projection::eval::algorithm::AlgorithmError;
+use crate::projection::{NodeLabel, RelationshipType};
+use crate::types::graph::MappedNodeId;
+use crate::types::prelude::{DefaultGraphStore, GraphStore};
+use crate::types::properties::node::{DefaultDoubleArrayNodePropertyValues, NodePropertyValues};
+use crate::types::properties::relationship::{
+    DefaultRelationshipPropertyValues, RelationshipPropertyValues,
+};
+use crate::types::schema::Direction;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// One document-to-document similarity, by node id.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DocumentSimilarityRow {
+    pub source: u64,
+    pub target: u64,
+    pub similarity: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct DocumentVectorsMutateResult {
+    pub summary: MutationResult,
+    pub updated_store: Arc<DefaultGraphStore>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DocumentSimilarityMutateResult {
+    pub relationship_type: RelationshipType,
+    pub relationships_written: u64,
+    pub summary: MutationResult,
+    pub updated_store: Arc<DefaultGraphStore>,
+}
+
+/// Document vectors facade - fluent configuration
+///
+/// ## Default Configuration
+/// - top_k: 10
+/// - similarity_cutoff: 0.0 (pairs at or below the cutoff are dropped)
+pub struct DocumentVectorsFacade {
+    graph_store: Arc<DefaultGraphStore>,
+    document_nodes: Vec<u64>,
+    document_label: Option<NodeLabel>,
+    vectors: Vec<SparseVector>,
+    top_k: usize,
+    similarity_cutoff: f64,
+}
+
+impl DocumentVectorsFacade {
+    /// Vectors belong to nodes `0..vectors.len()` of `graph_store`.
+    pub fn new(graph_store: Arc<DefaultGraphStore>, vectors: Vec<SparseVector>) -> Self {
+        let document_nodes = (0..vectors.len() as u64).collect();
+        Self {
+            graph_store,
+            document_nodes,
+            document_label: None,
+            vectors,
+            top_k: 10,
+            similarity_cutoff: 0.0,
+        }
+    }
+
+    /// Vectors belong to the `Document` nodes of a text graph, in corpus order.
+    pub fn for_text_graph(graph: &TextGraph, vectors: Vec<SparseVector>) -> Self {
+        Self {
+            graph_store: Arc::clone(graph.store()),
+            document_nodes: graph
+                .node_ids(TextNodeKind::Document)
+                .map(|id| id as u64)
+                .collect(),
+            document_label: Some(TextNodeKind::Document.label()),
+            vectors,
+            top_k: 10,
+            similarity_cutoff: 0.0,
+        }
+    }
+
+    pub fn top_k(mut self, k: usize) -> Self {
+        self.top_k = k;
+        self
+    }
+
+    pub fn similarity_cutoff(mut self, cutoff: f64) -> Self {
+        self.similarity_cutoff = cutoff;
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.top_k == 0 {
+            return Err(AlgorithmError::Execution("top_k must be positive".into()));
+        }
+        if self.document_nodes.len() != self.vectors.len() {
+            return Err(AlgorithmError::Execution(format!(
+                "expected {} document vectors, got {}",
+                self.document_nodes.len(),
+                self.vectors.len()
+            )));
+        }
+        let node_count = self.graph_store.node_count() as u64;
+        if let Some(node) = self.document_nodes.iter().find(|&&node| node >= node_count) {
+            return Err(AlgorithmError::Execution(format!(
+                "document node {node} is outside the graph ({node_count} nodes)"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Exact top-k cosine neighbours of every document, by source then rank.
+    pub fn stream(&self) -> Result<Vec<DocumentSimilarityRow>> {
+        self.validate()?;
+        let mut rows = Vec::new();
+        for (i, vector) in self.vectors.iter().enumerate() {
+            let mut neighbours = self
+                .vectors
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(j, other)| (j, vector.cosine(other)))
+                .filter(|(_, similarity)| *similarity > self.similarity_cutoff)
+                .collect::<Vec<_>>();
+            neighbours.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            neighbours.truncate(self.top_k);
+            rows.extend(
+                neighbours
+                    .into_iter()
+                    .map(|(j, similarity)| DocumentSimilarityRow {
+                        source: self.document_nodes[i],
+                        target: self.document_nodes[j],
+                        similarity,
+                    }),
+            );
+        }
+        Ok(rows)
+    }
+
+    /// Write dense document vectors as a double-array node property.
+    pub fn mutate_vectors(self, property_name: &str) -> Result<DocumentVectorsMutateResult> {
+        self.validate()?;
+        ConfigValidator::non_empty_string(property_name, "property_name")?;
+        let start = Instant::now();
+
+        let node_count = self.graph_store.node_count();
+        let mut data: Vec<Option<Vec<f64>>> = vec![None; node_count];
+        for (&node, vector) in self.document_nodes.iter().zip(&self.vectors) {
+            data[node as usize] = Some(vector.to_dense());
+        }
+        let values = DefaultDoubleArrayNodePropertyValues::<VecDoubleArray>::from_collection(
+            VecDoubleArray::from(data),
+            node_count,
+        );
+        let values: Arc<dyn NodePropertyValues> = Arc::new(values);
+
+        let mut new_store = self.graph_store.as_ref().clone();
+        let labels: HashSet<NodeLabel> = match &self.document_label {
+            Some(label) => HashSet::from([label.clone()]),
+            None => new_store.node_labels(),
+        };
+        new_store
+            .add_node_property(labels, property_name.to_string(), values)
+            .map_err(|e| {
+                AlgorithmError::Execution(format!(
+                    "document vectors mutate failed to add property: {e}"
+                ))
+            })?;
+
+        Ok(DocumentVectorsMutateResult {
+            summary: MutationResult::new(
+                self.document_nodes.len() as u64,
+                property_name.to_string(),
+                start.elapsed(),
+            ),
+            updated_store: Arc::new(new_store),
+        })
+    }
+
+    /// Add a directed top-k similarity relationship type with the cosine
+    /// similarity stored under `property_name`.
+    pub fn mutate_similarity(
+        self,
+        relationship_type: &str,
+        property_name: &str,
+    ) -> Result<DocumentSimilarityMutateResult> {
+        ConfigValidator::non_empty_string(relationship_type, "relationship_type")?;
+        ConfigValidator::non_empty_string(property_name, "property_name")?;
+        let start = Instant::now();
+        let rows = self.stream()?;
+
+        let rel_type = RelationshipType::of(relationship_type);
+        if self.graph_store.has_relationship_type(&rel_type) {
+            return Err(AlgorithmError::Execution(format!(
+                "relationship type '{relationship_type}' already exists"
+            )));
+        }
+
+        // Rows are grouped by source and `stream` keeps targets in rank order;
+        // property values follow the outgoing-list order.
+        let mut outgoing = vec![Vec::new(); self.graph_store.node_count()];
+        let mut similarities = Vec::with_capacity(rows.len());
+        for row in &rows {
+            outgoing[row.source as usize].push(MappedNodeId::new(row.target));
+            similarities.push(row.similarity);
+        }
+
+        let mut new_store = self
+            .graph_store
+            .with_added_relationship_type_preserve_name(
+                rel_type.clone(),
+                outgoing,
+                Direction::Directed,
+            )
+            .map_err(|e| {
+                AlgorithmError::Execution(format!(
+                    "document similarity mutate failed to add relationships: {e}"
+                ))
+            })?;
+        if !similarities.is_empty() {
+            let element_count = similarities.len();
+            let values: Arc<dyn RelationshipPropertyValues> = Arc::new(
+                DefaultRelationshipPropertyValues::with_values(similarities, 0.0, element_count),
+            );
+            new_store
+                .add_relationship_property(rel_type.clone(), property_name, values)
+                .map_err(|e| {
+                    AlgorithmError::Execution(format!(
+                        "document similarity mutate failed to add property: {e}"
+                    ))
+                })?;
+        }
+
+        Ok(DocumentSimilarityMutateResult {
+            relationship_type: rel_type,
+            relationships_written: rows.len() as u64,
+            summary: MutationResult::new(
+                self.document_nodes.len() as u64,
+                property_name.to_string(),
+                start.elapsed(),
+            ),
+            updated_store: Arc::new(new_store),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::dataset::corpus::{Corpus, TextGraphBuilder};
+    use crate::collections::dataset::language::text::TfidfVectorizer;
+    use crate::collections::dataset::language::tokenizer::{LineTokenizer, WhitespaceTokenizer};
+    use crate::collections::dataset::language::LineBlankMode;
+    use crate::projection::Orientation;
+
+    fn fixture() -> (TextGraph, Vec<SparseVector>) {
+        let corpus = Corpus::from_texts(&[
+            "graph databases store graph data",
+            "graph databases store nodes",
+            "cats sleep all day",
+        ])
+        .unwrap();
+        let lines = LineTokenizer::new(LineBlankMode::Discard);
+        let graph = TextGraphBuilder::new(&lines, &WhitespaceTokenizer)
+            .build(&corpus)
+            .unwrap();
+        let vectors = TfidfVectorizer::new()
+            .fit_corpus(&corpus, &WhitespaceTokenizer)
+            .unwrap();
+        (graph, vectors)
+    }
+
+    #[test]
+    fn streams_top_k_similar_documents() {
+        let (graph, vectors) = fixture();
+        let rows = DocumentVectorsFacade::for_text_graph(&graph, vectors)
+            .top_k(1)
+            .stream()
+            .unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[0].source, rows[0].target), (0, 1));
+        assert_eq!((rows[1].source, rows[1].target), (1, 0));
+        assert!(rows[0].similarity > 0.5);
+    }
+
+    #[test]
+    fn mutates_vectors_onto_document_nodes() {
+        let (graph, vectors) = fixture();
+        let dimension = vectors[0].dimension();
+        let result = DocumentVectorsFacade::for_text_graph(&graph, vectors)
+            .mutate_vectors("tfidf")
+            .unwrap();
+
+        assert_eq!(result.summary.nodes_updated, 3);
+        let values = result.updated_store.node_property_values("tfidf").unwrap();
+        let vector = values.double_array_value(2).unwrap();
+        assert_eq!(vector.len(), dimension);
+    }
+
+    #[test]
+    fn mutates_similarity_relationships() {
+        let (graph, vectors) = fixture();
+        let result = DocumentVectorsFacade::for_text_graph(&graph, vectors)
+            .top_k(2)
+            .mutate_similarity("SIMILAR", "score")
+            .unwrap();
+
+        assert_eq!(result.relationships_written, 2);
+        let store = result.updated_store;
+        let rel_type = RelationshipType::of("SIMILAR");
+        assert_eq!(store.relationship_count_for_type(&rel_type), 2);
+
+        let view = store
+            .get_graph_with_types_selectors_and_orientation(
+                &HashSet::from([rel_type.clone()]),
+                &std::collections::HashMap::from([(rel_type, "score".to_string())]),
+                Orientation::Natural,
+            )
+            .unwrap();
+        let weights = view
+            .stream_relationships_weighted(MappedNodeId::new(0), 0.0)
+            .map(|cursor| cursor.weight())
+            .collect::<Vec<_>>();
+        assert_eq!(weights.len(), 1);
+        assert!(weights[0] > 0.5);
+    }
+}
@@ -412,10 +412,18 @@ mod tests {
         ]);
         id_map.add_node_label(label_a.clone());
         id_map.add_node_label(label_b.clone());
-        id_map.add_node_id_to_label(MappedNodeId::new(0), label_a.clone());
-        id_map.add_node_id_to_label(MappedNodeId::new(1), label_a.clone());
-        id_map.add_node_id_to_label(MappedNodeId::new(2), label_b.clone());
-        id_map.add_node_id_to_label(MappedNodeId::new(3), label_b.clone());
+        id_map
+            .add_node_id_to_label(MappedNodeId::new(0), label_a.clone())
+            .unwrap();
+        id_map
+            .add_node_id_to_label(MappedNodeId::new(1), label_a.clone())
+            .unwrap();
+        id_map
+            .add_node_id_to_label(MappedNodeId::new(2), label_b.clone())
+            .unwrap();
+        id_map
+            .add_node_id_to_label(MappedNodeId::new(3), label_b.clone())
+            .unwrap();
 
         // Bipartite-ish: 0->2 and 1->2 gives 0 and 1 identical neighborhoods.
         let outgoing = vec![
@@ -1,8 +1,10 @@
+pub mod document_vectors;
 pub mod filtered_knn;
 pub mod filtered_node_similarity;
 pub mod knn;
 pub mod node_similarity;
 
+pub use document_vectors::*;
 pub use filtered_knn::*;
 pub use filtered_node_similarity::*;
 pub use knn::*;
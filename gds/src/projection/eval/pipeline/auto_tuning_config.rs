@@ -1,11 +1,48 @@
 //! Configuration for hyperparameter auto-tuning during model training.
 //!
 //! Controls how many different parameter combinations should be tried
-//! when searching for optimal hyperparameters.
+//! when searching for optimal hyperparameters, and how they are proposed.
 
 use crate::config::validation::ConfigError;
+use crate::ml::models::automl::HyperParameterOptimizer;
+use crate::ml::models::automl::RandomSearch;
+use crate::ml::models::automl::TreeParzenEstimator;
+use crate::ml::models::automl::TunableTrainerConfig;
+use crate::ml::models::TrainingMethod;
 use std::collections::HashMap;
 
+/// Strategy used to propose hyperparameter combinations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchStrategy {
+    /// Samples every trial independently and uniformly from the ranges.
+    #[default]
+    RandomSearch,
+    /// Proposes trials based on the scores of previous trials.
+    TreeParzenEstimator,
+}
+
+impl SearchStrategy {
+    /// Parses a strategy name, case-insensitively.
+    pub fn parse(value: &str) -> Result<Self, AutoTuningConfigError> {
+        match value.to_ascii_lowercase().as_str() {
+            "randomsearch" | "random" => Ok(Self::RandomSearch),
+            "treeparzenestimator" | "tpe" => Ok(Self::TreeParzenEstimator),
+            _ => Err(AutoTuningConfigError::UnknownSearchStrategy {
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for SearchStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchStrategy::RandomSearch => write!(f, "RandomSearch"),
+            SearchStrategy::TreeParzenEstimator => write!(f, "TreeParzenEstimator"),
+        }
+    }
+}
+
 /// Configuration for automatic hyperparameter tuning.
 ///
 /// Specifies how many trials (different parameter combinations) should be
@@ -15,6 +52,10 @@ use std::collections::HashMap;
 pub struct AutoTuningConfig {
     /// Maximum number of hyperparameter combinations to try (must be >= 1)
     max_trials: usize,
+    /// How trials are proposed
+    search_strategy: SearchStrategy,
+    /// Whether strategies that support it may abandon clearly worse trials early
+    pruning: bool,
 }
 
 impl AutoTuningConfig {
@@ -35,7 +76,22 @@ impl AutoTuningConfig {
                 min: 1,
             });
         }
-        Ok(Self { max_trials })
+        Ok(Self {
+            max_trials,
+            ..Self::default()
+        })
+    }
+
+    /// Use the given search strategy.
+    pub fn with_search_strategy(mut self, search_strategy: SearchStrategy) -> Self {
+        self.search_strategy = search_strategy;
+        self
+    }
+
+    /// Enable or disable trial pruning.
+    pub fn with_pruning(mut self, pruning: bool) -> Self {
+        self.pruning = pruning;
+        self
     }
 
     /// Get the maximum number of trials.
@@ -43,6 +99,35 @@ impl AutoTuningConfig {
         self.max_trials
     }
 
+    /// Get the search strategy.
+    pub fn search_strategy(&self) -> SearchStrategy {
+        self.search_strategy
+    }
+
+    /// Whether trial pruning is enabled.
+    pub fn pruning(&self) -> bool {
+        self.pruning
+    }
+
+    /// Create the optimizer proposing the trials of the given parameter space.
+    pub fn optimizer(
+        &self,
+        parameter_space: HashMap<TrainingMethod, Vec<TunableTrainerConfig>>,
+        random_seed: Option<u64>,
+    ) -> Box<dyn HyperParameterOptimizer> {
+        match self.search_strategy {
+            SearchStrategy::RandomSearch => Box::new(RandomSearch::new_with_seed(
+                parameter_space,
+                self.max_trials,
+                random_seed,
+            )),
+            SearchStrategy::TreeParzenEstimator => Box::new(
+                TreeParzenEstimator::new(parameter_space, self.max_trials, random_seed)
+                    .with_pruning(self.pruning),
+            ),
+        }
+    }
+
     /// Convert to a map representation (for serialization/debugging).
     pub fn to_map(&self) -> HashMap<String, serde_json::Value> {
         let mut map = HashMap::new();
@@ -50,6 +135,11 @@ impl AutoTuningConfig {
             "maxTrials".to_string(),
             serde_json::Value::Number(self.max_trials.into()),
         );
+        map.insert(
+            "searchStrategy".to_string(),
+            serde_json::Value::String(self.search_strategy.to_string()),
+        );
+        map.insert("pruning".to_string(), serde_json::Value::Bool(self.pruning));
         map
     }
 
@@ -68,6 +158,8 @@ impl Default for AutoTuningConfig {
     fn default() -> Self {
         Self {
             max_trials: Self::MAX_TRIALS,
+            search_strategy: SearchStrategy::default(),
+            pruning: true,
         }
     }
 }
@@ -82,6 +174,11 @@ pub enum AutoTuningConfigError {
         /// The minimum allowed value
         min: usize,
     },
+    /// The search strategy name is not recognized
+    UnknownSearchStrategy {
+        /// The invalid value provided
+        value: String,
+    },
 }
 
 impl std::fmt::Display for AutoTuningConfigError {
@@ -90,6 +187,11 @@ impl std::fmt::Display for AutoTuningConfigError {
             AutoTuningConfigError::InvalidMaxTrials { value, min } => {
                 write!(f, "Invalid maxTrials: {} (must be >= {})", value, min)
             }
+            AutoTuningConfigError::UnknownSearchStrategy { value } => write!(
+                f,
+                "Invalid searchStrategy: {} (expected RandomSearch or TreeParzenEstimator)",
+                value
+            ),
         }
     }
 }
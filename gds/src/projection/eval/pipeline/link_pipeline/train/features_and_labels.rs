@@ -1,5 +1,7 @@
 // Phase 1.4: FeaturesAndLabels - Training data container for link prediction
 
+use crate::ml::models::Features as ModelFeatures;
+
 /// Features type: vector of feature vectors (one per relationship)
 pub type Features = Vec<Vec<f64>>;
 
@@ -21,6 +23,10 @@ pub type Labels = Vec<i32>;
 /// Features are computed for both positive and negative examples using link functions
 /// on node properties.
 ///
+/// Each example also belongs to a ranking group: a positive and the negatives sampled
+/// for it share a group, so ranking metrics like `HITS@k` and `MRR` rank every positive
+/// only against its own negatives. By default all examples share group 0.
+///
 /// # Example Flow
 ///
 /// ```text
@@ -38,6 +44,9 @@ pub struct FeaturesAndLabels {
 
     /// Binary labels: 1 = relationship exists, 0 = doesn't exist
     labels: Labels,
+
+    /// Ranking group of every example
+    groups: Vec<u64>,
 }
 
 impl FeaturesAndLabels {
@@ -58,7 +67,27 @@ impl FeaturesAndLabels {
             "Features and labels must have the same length"
         );
 
-        Self { features, labels }
+        let groups = vec![0; labels.len()];
+        Self {
+            features,
+            labels,
+            groups,
+        }
+    }
+
+    /// Assigns the ranking group of every example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one group per example.
+    pub fn with_groups(mut self, groups: Vec<u64>) -> Self {
+        assert_eq!(
+            groups.len(),
+            self.labels.len(),
+            "Groups and labels must have the same length"
+        );
+        self.groups = groups;
+        self
     }
 
     /// Returns the features.
@@ -71,6 +100,11 @@ impl FeaturesAndLabels {
         &self.labels
     }
 
+    /// Returns the ranking groups.
+    pub fn groups(&self) -> &[u64] {
+        &self.groups
+    }
+
     /// Returns the number of training examples.
     ///
     /// Equal to the number of relationship pairs (positive + negative).
@@ -79,6 +113,16 @@ impl FeaturesAndLabels {
     }
 }
 
+impl ModelFeatures for FeaturesAndLabels {
+    fn size(&self) -> usize {
+        self.features.len()
+    }
+
+    fn get(&self, id: usize) -> &[f64] {
+        &self.features[id]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,6 +166,21 @@ mod tests {
         assert_eq!(data.size(), 0);
     }
 
+    #[test]
+    fn test_groups() {
+        let data = FeaturesAndLabels::new(vec![vec![0.0]; 3], vec![1, 0, 0]);
+        assert_eq!(data.groups(), &[0, 0, 0]);
+
+        let data = data.with_groups(vec![7, 7, 8]);
+        assert_eq!(data.groups(), &[7, 7, 8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Groups and labels must have the same length")]
+    fn test_groups_length_mismatch() {
+        FeaturesAndLabels::new(vec![vec![0.0]; 2], vec![1, 0]).with_groups(vec![0]);
+    }
+
     #[test]
     fn test_large_size() {
         let features: Features = (0..1000).map(|i| vec![i as f64, (i + 1) as f64]).collect();
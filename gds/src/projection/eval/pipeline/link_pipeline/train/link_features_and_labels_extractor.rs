@@ -4,6 +4,8 @@ use super::FeaturesAndLabels;
 use crate::projection::eval::pipeline::link_pipeline::{LinkFeatureExtractor, LinkFeatureStep};
 use crate::task::concurrency::{Concurrency, TerminationFlag};
 use crate::types::graph::Graph;
+use crate::types::graph::MappedNodeId;
+use rayon::prelude::*;
 
 /// Label value for POSITIVE relationships (relationship exists).
 pub const POSITIVE: i32 = 1;
@@ -13,8 +15,13 @@ pub const NEGATIVE: i32 = 0;
 
 /// Extract features and labels for link prediction training.
 ///
+/// `graph` is a split graph: every relationship is an example, and its selected relationship
+/// property holds the label, `1.0` for a positive and `0.0` for a sampled negative. Examples
+/// are grouped by source node, so ranking metrics rank each positive against the negatives
+/// sampled for the same source.
+///
 /// # Arguments
-/// * `graph` - The graph containing relationships to extract from
+/// * `graph` - The split graph containing labelled relationships
 /// * `feature_steps` - List of link feature steps to apply
 /// * `concurrency` - Number of parallel workers
 /// * `termination_flag` - Allows early termination
@@ -24,14 +31,49 @@ pub const NEGATIVE: i32 = 0;
 pub fn extract_features_and_labels(
     graph: &dyn Graph,
     feature_steps: Vec<Box<dyn LinkFeatureStep>>,
-    concurrency: Concurrency,
+    _concurrency: Concurrency,
     termination_flag: &TerminationFlag,
 ) -> Result<FeaturesAndLabels, String> {
-    // TODO: integrate negative sampling. For now, label existing relationships as positive.
-    let features =
-        LinkFeatureExtractor::extract_features(graph, feature_steps, concurrency, termination_flag);
-    let labels = vec![POSITIVE; features.len()];
-    Ok(FeaturesAndLabels::new(features, labels))
+    let mut examples = Vec::new();
+    for source_index in 0..graph.node_count() {
+        let source = MappedNodeId::try_from(source_index)
+            .expect("graph node count exceeds the mapped node ID domain");
+        for cursor in graph.stream_relationships(source, f64::NAN) {
+            let label = if cursor.property() == f64::from(POSITIVE) {
+                POSITIVE
+            } else if cursor.property() == f64::from(NEGATIVE) {
+                NEGATIVE
+            } else {
+                return Err(format!(
+                    "Relationship ({source}, {}) has label {}, expected {POSITIVE} or {NEGATIVE}",
+                    cursor.target_id(),
+                    cursor.property()
+                ));
+            };
+            examples.push((source, cursor.target_id(), label));
+        }
+    }
+
+    let extractor = LinkFeatureExtractor::of(graph, feature_steps);
+    let features = examples
+        .par_iter()
+        .map(|&(source, target, _)| {
+            if !termination_flag.running() {
+                return vec![0.0; extractor.feature_dimension()];
+            }
+            extractor.extract_features_for_pair(source, target)
+        })
+        .collect();
+    if !termination_flag.running() {
+        return Err("Link feature extraction was terminated".to_string());
+    }
+
+    let labels = examples.iter().map(|&(_, _, label)| label).collect();
+    let groups = examples
+        .iter()
+        .map(|&(source, _, _)| source.get())
+        .collect();
+    Ok(FeaturesAndLabels::new(features, labels).with_groups(groups))
 }
 
 #[cfg(test)]
@@ -1,27 +1,48 @@
 // Phase 5.2: LinkPredictionTrain - Training orchestration for link prediction
 
-use super::{FeaturesAndLabels, LinkPredictionTrainConfig, LinkPredictionTrainResult};
+use super::{
+    extract_features_and_labels, FeaturesAndLabels, LinkPredictionTrainConfig,
+    LinkPredictionTrainResult,
+};
+use crate::collections::HugeIntArray;
+use crate::ml::metrics::link::{LinkMetric, SignedProbabilitiesBuilder};
+use crate::ml::metrics::{Metric, MetricConsumer, ModelSpecificMetricsHandler};
+use crate::ml::models::automl::{
+    HyperParameterOptimizer, TunableTrainerConfig as MlTunableTrainerConfig,
+};
+use crate::ml::models::{
+    base::TrainerConfigTrait, Classifier, ClassifierTrainerFactory,
+    TrainingMethod as MlTrainingMethod,
+};
+use crate::ml::training::{CrossValidation, TrainingStatistics};
 use crate::projection::eval::pipeline::link_pipeline::{
-    LinkPredictionSplitConfig, LinkPredictionTrainingPipeline,
+    LinkFeatureStepWrapper, LinkPredictionSplitConfig, LinkPredictionTrainingPipeline,
 };
-use crate::task::progress::{LeafTask, Tasks};
+use crate::projection::eval::pipeline::TrainingMethod as PipelineTrainingMethod;
+use crate::projection::eval::pipeline::{Pipeline, TrainingPipeline};
+use crate::task::concurrency::{Concurrency, TerminationFlag};
+use crate::task::progress::{LeafTask, NoopProgressTracker, Tasks};
+use crate::types::graph::Graph;
+use parking_lot::RwLock;
+use std::collections::{BTreeSet, HashMap};
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// Link prediction training orchestrator.
 ///
 /// Coordinates feature extraction, model selection, and evaluation.
 pub struct LinkPredictionTrain {
-    /// Train graph (contains TRAIN relationships)
-    train_graph: PhantomData<()>, // Note: placeholder for Graph.
+    /// Train graph (contains labelled TRAIN relationships)
+    train_graph: Arc<dyn Graph>,
 
-    /// Validation graph (contains TEST relationships)
-    validation_graph: PhantomData<()>, // Note: placeholder for Graph.
+    /// Validation graph (contains labelled TEST relationships)
+    validation_graph: Arc<dyn Graph>,
 
     /// Link prediction pipeline
-    pipeline: PhantomData<LinkPredictionTrainingPipeline>, // Note: placeholder for pipeline.
+    pipeline: LinkPredictionTrainingPipeline,
 
     /// Training configuration
-    _config: LinkPredictionTrainConfig,
+    config: LinkPredictionTrainConfig,
 
     /// Class ID map (NEGATIVE=0, POSITIVE=1)
     class_id_map: PhantomData<()>, // Note: placeholder for LocalIdMap.
@@ -30,7 +51,7 @@ pub struct LinkPredictionTrain {
     progress_tracker: PhantomData<()>, // Note: placeholder for ProgressTracker.
 
     /// Termination flag
-    termination_flag: PhantomData<()>, // Note: placeholder for TerminationFlag.
+    termination_flag: TerminationFlag,
 }
 
 impl LinkPredictionTrain {
@@ -43,85 +64,86 @@ impl LinkPredictionTrain {
     /// Creates a new LinkPredictionTrain orchestrator.
     /// # Arguments
     ///
-    /// * `train_graph` - Graph with TRAIN relationships
-    /// * `validation_graph` - Graph with TEST relationships
-    /// * `pipeline` - Feature extraction pipeline
+    /// * `train_graph` - Graph with labelled TRAIN relationships
+    /// * `validation_graph` - Graph with labelled TEST relationships
+    /// * `pipeline` - Feature extraction pipeline and model candidates
     /// * `config` - Training configuration
     /// * `progress_tracker` - Progress tracking
     /// * `termination_flag` - Interrupt handling
     pub fn new(
-        _train_graph: PhantomData<()>,
-        _validation_graph: PhantomData<()>,
-        _pipeline: PhantomData<LinkPredictionTrainingPipeline>,
+        train_graph: Arc<dyn Graph>,
+        validation_graph: Arc<dyn Graph>,
+        pipeline: LinkPredictionTrainingPipeline,
         config: LinkPredictionTrainConfig,
         _progress_tracker: PhantomData<()>,
-        _termination_flag: PhantomData<()>,
+        termination_flag: TerminationFlag,
     ) -> Self {
         Self {
-            train_graph: PhantomData,
-            validation_graph: PhantomData,
-            pipeline: PhantomData,
-            _config: config,
+            train_graph,
+            validation_graph,
+            pipeline,
+            config,
             class_id_map: PhantomData,
             progress_tracker: PhantomData,
-            termination_flag: PhantomData,
+            termination_flag,
         }
     }
 
     /// Computes the training result.
     ///
     /// Training flow:
-    /// 1. Extract train features
+    /// 1. Extract train and test features from the split graphs
     /// 2. Find best model candidate
     /// 3. Train best model
     /// 4. Evaluate metrics
+    ///
+    /// The relationships of both split graphs are the examples: their selected relationship
+    /// property is the label, `1.0` for positives and `0.0` for sampled negatives.
     pub fn compute(&self) -> Result<LinkPredictionTrainResult, String> {
-        // Deferred: implement full training flow.
+        self.pipeline.validate_before_execution()?;
+        let train_data = self.extract_features_and_labels(self.train_graph.as_ref())?;
+        let test_data = self.extract_features_and_labels(self.validation_graph.as_ref())?;
+        self.train_and_evaluate(&train_data, &test_data)
+    }
 
-        // Keep placeholder private methods lint-clean in non-test builds.
-        let placeholder_data = FeaturesAndLabels::new(Vec::new(), Vec::new());
-        self.find_best_model_candidate(&placeholder_data, PhantomData);
-        let classifier = self.train_model(&placeholder_data, PhantomData, PhantomData);
-        self.compute_train_metric(&placeholder_data, classifier, PhantomData);
-        self.compute_test_metric(classifier, PhantomData);
+    /// Selects, trains and evaluates a classifier on extracted link features.
+    ///
+    /// Every configured metric is scored on each validation fold of every candidate, on
+    /// the outer train set and on the test set. Ranking metrics rank each positive against
+    /// the negatives of its group, see [`FeaturesAndLabels::with_groups`].
+    pub fn train_and_evaluate(
+        &self,
+        train_data: &FeaturesAndLabels,
+        test_data: &FeaturesAndLabels,
+    ) -> Result<LinkPredictionTrainResult, String> {
+        let metrics = self.metrics()?;
+        let link_metrics = self.config.link_metrics()?;
+        let mut training_statistics = TrainingStatistics::new(&metrics);
+
+        let train_data = Arc::new(train_data.clone());
+        let best_config =
+            self.find_best_model_candidate(&train_data, metrics, &mut training_statistics)?;
+
+        let all_rows = Arc::new((0..train_data.size() as u64).collect::<Vec<_>>());
+        let classifier = self.train_model(&train_data, &all_rows, best_config.as_ref());
+
+        self.compute_train_metric(
+            &train_data,
+            classifier.as_ref(),
+            &link_metrics,
+            &mut training_statistics,
+        );
+        self.compute_test_metric(
+            test_data,
+            classifier.as_ref(),
+            &link_metrics,
+            &mut training_statistics,
+        );
 
-        // 1. Extract train features
-        // progress_tracker.begin_sub_task("Extract train features");
-        // let train_data = extract_features_and_labels(
-        //     &self.train_graph,
-        //     &self.pipeline.feature_steps(),
-        //     self.config.concurrency(),
-        //     &self.progress_tracker,
-        //     &self.termination_flag,
-        // );
-        // progress_tracker.end_sub_task("Extract train features");
-
-        // 2. Initialize training statistics
-        // let mut training_statistics = TrainingStatistics::new(self.config.metrics());
-
-        // 3. Find best model candidate (Cross-Validation)
-        // self.find_best_model_candidate(&train_data, &training_statistics);
-
-        // 4. Train best model on full train set
-        // progress_tracker.begin_sub_task("Train best model");
-        // let classifier = self.train_model(
-        //     &train_data,
-        //     training_statistics.best_parameters(),
-        // );
-        // progress_tracker.end_sub_task("Train best model");
-
-        // 5. Compute train metrics
-        // progress_tracker.begin_sub_task("Compute train metrics");
-        // self.compute_train_metric(&train_data, &classifier, &mut training_statistics);
-        // progress_tracker.end_sub_task("Compute train metrics");
-
-        // 6. Compute test metrics
-        // progress_tracker.begin_sub_task("Evaluate on test data");
-        // self.compute_test_metric(&classifier, &mut training_statistics);
-        // progress_tracker.end_sub_task("Evaluate on test data");
-
-        // For now, return placeholder result
-        Err("LinkPredictionTrain::compute() not yet implemented".to_string())
+        Ok(LinkPredictionTrainResult::new(
+            classifier,
+            training_statistics,
+        ))
     }
 
     /// Generates progress tasks for the training pipeline.
@@ -194,62 +216,325 @@ impl LinkPredictionTrain {
         tasks
     }
 
-    // === Private methods (placeholders) ===
+    // === Private methods ===
+
+    fn extract_features_and_labels(&self, graph: &dyn Graph) -> Result<FeaturesAndLabels, String> {
+        let feature_steps = self
+            .pipeline
+            .feature_steps()
+            .iter()
+            .map(LinkFeatureStepWrapper::to_link_step_box)
+            .collect();
+        extract_features_and_labels(
+            graph,
+            feature_steps,
+            Concurrency::of(1),
+            &self.termination_flag,
+        )
+    }
 
-    /// Finds best model candidate via cross-validation.
-    ///
-    /// TODO: implement RandomSearch + CrossValidation and update training statistics.
+    fn metrics(&self) -> Result<Vec<Box<dyn Metric>>, String> {
+        if self.config.metrics().is_empty() {
+            return Err("Need at least one metric for link prediction training.".to_string());
+        }
+        self.config
+            .metrics()
+            .iter()
+            .map(|metric| LinkMetric::parse_link_metric(metric))
+            .collect()
+    }
+
+    /// Finds best model candidate via cross-validation over the train examples,
+    /// recording per-fold train and validation scores of every candidate.
     fn find_best_model_candidate(
         &self,
-        _train_data: &FeaturesAndLabels,
-        _training_statistics: PhantomData<()>, // Note: placeholder for TrainingStatistics.
-    ) {
-        // Deferred: implement cross-validation model selection.
+        train_data: &Arc<FeaturesAndLabels>,
+        metrics: Vec<Box<dyn Metric>>,
+        training_statistics: &mut TrainingStatistics,
+    ) -> Result<Box<dyn TrainerConfigTrait>, String> {
+        let mut optimizer = self.hyper_parameter_optimizer()?;
+        let link_metrics = Arc::new(self.config.link_metrics()?);
+        let negative_class_weight = self.config.negative_class_weight();
+        let random_seed = self.config.random_seed();
+        let termination_flag = self.termination_flag.clone();
+        let targets = Arc::new(HugeIntArray::from_vec(train_data.labels().clone()));
+
+        let cv = CrossValidation::new(
+            Arc::new(RwLock::new(false)),
+            metrics,
+            self.pipeline.split_config().validation_folds() as usize,
+            random_seed,
+            Box::new({
+                let train_data = Arc::clone(train_data);
+                move |train_set, trainer_config, metrics_handler, _name| {
+                    let trainer = ClassifierTrainerFactory::create(
+                        trainer_config,
+                        2,
+                        &termination_flag,
+                        &NoopProgressTracker,
+                        &Concurrency::of(1),
+                        random_seed,
+                        false,
+                        metrics_handler,
+                    );
+                    trainer.train(
+                        train_data.as_ref(),
+                        &targets,
+                        &Arc::new(to_rows(&train_set)),
+                    )
+                }
+            }),
+            Box::new({
+                let train_data = Arc::clone(train_data);
+                move |eval_set, model: &Box<dyn Classifier>, consumer| {
+                    let scores = evaluate_link_metrics(
+                        train_data.as_ref(),
+                        to_rows(&eval_set).into_iter().map(|row| row as usize),
+                        model.as_ref(),
+                        &link_metrics,
+                        negative_class_weight,
+                    );
+                    consume_scores(&link_metrics, &scores, consumer);
+                }
+            }),
+        );
+
+        let labels = Arc::new(train_data.labels().clone());
+        let outer_train = Arc::new((0..train_data.size() as i64).collect::<Vec<_>>());
+        cv.select_model(
+            outer_train,
+            move |row| labels[row as usize] as i64,
+            BTreeSet::from([Self::NEGATIVE, Self::POSITIVE]),
+            training_statistics,
+            optimizer.as_mut(),
+        )
+        .ok_or_else(|| "Link prediction model selection produced no winning candidate".to_string())
     }
 
-    /// Trains a model with given parameters.
-    ///
-    /// TODO: create trainer, fit on features/labels, and return classifier.
+    /// Trains a classifier on the given rows of the train examples.
     fn train_model(
         &self,
-        _features_and_labels: &FeaturesAndLabels,
-        _train_set: PhantomData<()>, // Note: placeholder for ReadOnlyHugeLongArray.
-        _trainer_config: PhantomData<()>, // Note: placeholder for TrainerConfig.
-    ) -> PhantomData<()> {
-        // Deferred: implement model training.
-        PhantomData
+        features_and_labels: &FeaturesAndLabels,
+        train_set: &Arc<Vec<u64>>,
+        trainer_config: &dyn TrainerConfigTrait,
+    ) -> Box<dyn Classifier> {
+        let trainer = ClassifierTrainerFactory::create(
+            trainer_config,
+            2,
+            &self.termination_flag,
+            &NoopProgressTracker,
+            &Concurrency::of(1),
+            self.config.random_seed(),
+            false,
+            &ModelSpecificMetricsHandler::noop(),
+        );
+        let targets = HugeIntArray::from_vec(features_and_labels.labels().clone());
+        trainer.train(features_and_labels, &targets, train_set)
     }
 
-    /// Computes train metrics.
-    ///
-    /// TODO: predict on train set, compute metrics, update statistics.
+    /// Scores the winning classifier on the full train set.
     fn compute_train_metric(
         &self,
-        _train_data: &FeaturesAndLabels,
-        _classifier: PhantomData<()>,
-        _training_statistics: PhantomData<()>,
+        train_data: &FeaturesAndLabels,
+        classifier: &dyn Classifier,
+        link_metrics: &[LinkMetric],
+        training_statistics: &mut TrainingStatistics,
     ) {
-        // Deferred: implement train metric computation.
+        let scores = evaluate_link_metrics(
+            train_data,
+            0..train_data.size(),
+            classifier,
+            link_metrics,
+            self.config.negative_class_weight(),
+        );
+        for (metric, score) in scores {
+            training_statistics.add_outer_train_score(metric, score);
+        }
     }
 
-    /// Computes test metrics.
-    ///
-    /// TODO: extract test features, predict on test set, compute metrics.
+    /// Scores the winning classifier on the test set.
     fn compute_test_metric(
         &self,
-        _classifier: PhantomData<()>,
-        _training_statistics: PhantomData<()>,
+        test_data: &FeaturesAndLabels,
+        classifier: &dyn Classifier,
+        link_metrics: &[LinkMetric],
+        training_statistics: &mut TrainingStatistics,
     ) {
-        // Deferred: implement test metric computation.
+        let scores = evaluate_link_metrics(
+            test_data,
+            0..test_data.size(),
+            classifier,
+            link_metrics,
+            self.config.negative_class_weight(),
+        );
+        for (metric, score) in scores {
+            training_statistics.add_test_score(metric, score);
+        }
+    }
+
+    fn hyper_parameter_optimizer(&self) -> Result<Box<dyn HyperParameterOptimizer>, String> {
+        let mut parameter_space = HashMap::new();
+
+        for (method, configs) in self.pipeline.training_parameter_space() {
+            let ml_method = map_training_method(*method)?;
+            let method_configs = parameter_space.entry(ml_method).or_insert_with(Vec::new);
+            for cfg in configs {
+                let tunable = MlTunableTrainerConfig::of(&cfg.to_map(), ml_method)
+                    .map_err(|error| format!("Invalid {method} parameter space: {error}"))?;
+                method_configs.push(tunable);
+            }
+        }
+
+        if parameter_space.values().all(Vec::is_empty) {
+            return Err("Need at least one model candidate for link prediction training.".into());
+        }
+
+        Ok(self
+            .pipeline
+            .auto_tuning_config()
+            .optimizer(parameter_space, self.config.random_seed()))
     }
 
     // Note: Memory estimation will be added once the pipeline is fully wired.
 }
 
+fn map_training_method(method: PipelineTrainingMethod) -> Result<MlTrainingMethod, String> {
+    match method {
+        PipelineTrainingMethod::LogisticRegression => Ok(MlTrainingMethod::LogisticRegression),
+        PipelineTrainingMethod::RandomForestClassification => {
+            Ok(MlTrainingMethod::RandomForestClassification)
+        }
+        PipelineTrainingMethod::SVMClassification => Ok(MlTrainingMethod::SVMClassification),
+        PipelineTrainingMethod::MLPClassification => Ok(MlTrainingMethod::MLPClassification),
+        PipelineTrainingMethod::GradientBoostingClassification => {
+            Ok(MlTrainingMethod::GradientBoostingClassification)
+        }
+        other => Err(format!(
+            "Unsupported training method for link prediction: {other:?}"
+        )),
+    }
+}
+
+fn to_rows(values: &[i64]) -> Vec<u64> {
+    values
+        .iter()
+        .map(|value| u64::try_from(*value).expect("training example id must be non-negative"))
+        .collect()
+}
+
+/// Scores the link metrics over the given rows, keyed by metric name.
+fn evaluate_link_metrics(
+    data: &FeaturesAndLabels,
+    rows: impl IntoIterator<Item = usize>,
+    classifier: &dyn Classifier,
+    link_metrics: &[LinkMetric],
+    negative_class_weight: f64,
+) -> HashMap<String, f64> {
+    let mut builder = SignedProbabilitiesBuilder::new(data.size());
+    for row in rows {
+        let probability = classifier.predict_probabilities(&data.features()[row])
+            [LinkPredictionTrain::POSITIVE as usize];
+        let is_positive = i64::from(data.labels()[row]) == LinkPredictionTrain::POSITIVE;
+        builder.add_grouped_probability(probability, is_positive, data.groups()[row]);
+    }
+    let signed_probabilities = builder.build();
+
+    link_metrics
+        .iter()
+        .map(|metric| {
+            (
+                metric.name().to_string(),
+                metric.compute(&signed_probabilities, negative_class_weight),
+            )
+        })
+        .collect()
+}
+
+fn consume_scores(
+    link_metrics: &[LinkMetric],
+    scores: &HashMap<String, f64>,
+    consumer: &mut dyn MetricConsumer,
+) {
+    for metric in link_metrics {
+        if let Some(score) = scores.get(metric.name()) {
+            consumer.consume(metric, *score);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::projection::eval::pipeline::link_pipeline::LinkPredictionSplitConfig;
+    use crate::config::GraphStoreConfig;
+    use crate::projection::eval::pipeline::link_pipeline::{
+        HadamardFeatureStep, LinkPredictionSplitConfig,
+    };
+    use crate::projection::eval::pipeline::TunableTrainerConfig;
+    use crate::projection::RelationshipType;
+    use crate::types::graph::{MappedNodeId, RelationshipTopology, SimpleIdMap};
+    use crate::types::graph_store::{
+        Capabilities, DatabaseId, DatabaseInfo, DatabaseLocation, DefaultGraphStore, GraphName,
+    };
+    use crate::types::prelude::GraphStore;
+    use crate::types::properties::relationship::{
+        DefaultRelationshipPropertyValues, RelationshipPropertyValues,
+    };
+    use crate::types::schema::{Direction, MutableGraphSchema};
+    use std::collections::HashSet;
+
+    /// A split graph: node property `x`, and `(source, target, label)` examples as
+    /// relationships whose `label` property is selected.
+    fn split_graph(x: &[f64], examples: &[(usize, usize, f64)]) -> Arc<dyn Graph> {
+        let mut examples = examples.to_vec();
+        examples.sort_by_key(|&(source, _, _)| source);
+
+        let mut outgoing: Vec<Vec<MappedNodeId>> = vec![Vec::new(); x.len()];
+        let mut labels = Vec::with_capacity(examples.len());
+        for &(source, target, label) in &examples {
+            outgoing[source].push(MappedNodeId::try_from(target).unwrap());
+            labels.push(label);
+        }
+
+        let rel_type = RelationshipType::of("SPLIT");
+        let mut schema = MutableGraphSchema::empty();
+        schema
+            .relationship_schema_mut()
+            .add_relationship_type(rel_type.clone(), Direction::Directed);
+        let original_ids: Vec<i64> = (0..x.len()).map(|node| node as i64).collect();
+        let mut store = DefaultGraphStore::new(
+            GraphStoreConfig::default(),
+            GraphName::new("g"),
+            DatabaseInfo::new(
+                DatabaseId::new("db"),
+                DatabaseLocation::remote("localhost", 7687, None, None),
+            ),
+            schema.build(),
+            Capabilities::default(),
+            SimpleIdMap::from_original_ids(original_ids),
+            HashMap::from([(rel_type.clone(), RelationshipTopology::new(outgoing, None))]),
+        );
+        store
+            .add_node_property_f64("x".to_string(), x.to_vec())
+            .unwrap();
+        let element_count = labels.len();
+        let label_values: Arc<dyn RelationshipPropertyValues> = Arc::new(
+            DefaultRelationshipPropertyValues::with_values(labels, 0.0, element_count),
+        );
+        store
+            .add_relationship_property(rel_type.clone(), "label", label_values)
+            .unwrap();
+
+        store
+            .get_graph_with_types_and_selectors(
+                &HashSet::from([rel_type.clone()]),
+                &HashMap::from([(rel_type, "label".to_string())]),
+            )
+            .unwrap()
+    }
+
+    fn empty_graph() -> Arc<dyn Graph> {
+        split_graph(&[0.0], &[])
+    }
 
     #[test]
     fn test_class_constants() {
@@ -268,101 +553,245 @@ mod tests {
             .unwrap();
 
         let _trainer = LinkPredictionTrain::new(
-            PhantomData,
-            PhantomData,
-            PhantomData,
+            empty_graph(),
+            empty_graph(),
+            LinkPredictionTrainingPipeline::new(),
             config,
             PhantomData,
-            PhantomData,
+            TerminationFlag::running_true(),
         );
     }
 
     #[test]
-    fn test_compute_not_implemented() {
+    fn test_progress_tasks() {
+        let split_config = LinkPredictionSplitConfig::default();
+        let tasks = LinkPredictionTrain::progress_tasks(1000, &split_config, 10);
+
+        // Should have multiple stages
+        assert!(!tasks.is_empty());
+
+        // Check task names
+        let task_names: Vec<&str> = tasks.iter().map(|t| t.base().description()).collect();
+        assert!(task_names.contains(&"Extract train features"));
+        assert!(task_names.contains(&"Train best model"));
+        assert!(task_names.contains(&"Compute train metrics"));
+    }
+
+    #[derive(Clone)]
+    struct TestLinkTrainerConfig {
+        parameters: HashMap<String, serde_json::Value>,
+    }
+
+    impl TunableTrainerConfig for TestLinkTrainerConfig {
+        fn training_method(&self) -> PipelineTrainingMethod {
+            PipelineTrainingMethod::LogisticRegression
+        }
+
+        fn is_concrete(&self) -> bool {
+            !self.parameters.values().any(serde_json::Value::is_object)
+        }
+
+        fn to_map(&self) -> HashMap<String, serde_json::Value> {
+            self.parameters.clone()
+        }
+    }
+
+    /// One positive and two sampled negatives per group; positives score high on the
+    /// first feature.
+    fn grouped_examples(groups: u64, offset: u64) -> FeaturesAndLabels {
+        let mut features = Vec::new();
+        let mut labels = Vec::new();
+        let mut group_ids = Vec::new();
+        for group in 0..groups {
+            let jitter = (group % 5) as f64 * 0.05;
+            for (label, x) in [(1, 1.0 + jitter), (0, -1.0 - jitter), (0, -0.5 + jitter)] {
+                features.push(vec![x, 1.0]);
+                labels.push(label);
+                group_ids.push(offset + group);
+            }
+        }
+        FeaturesAndLabels::new(features, labels).with_groups(group_ids)
+    }
+
+    fn logistic_regression_pipeline() -> LinkPredictionTrainingPipeline {
+        let mut pipeline = LinkPredictionTrainingPipeline::new();
+        pipeline.add_feature_step(Box::new(HadamardFeatureStep::new(vec!["x".to_string()])));
+        pipeline.add_trainer_config(Box::new(TestLinkTrainerConfig {
+            parameters: HashMap::from([
+                ("penalty".to_string(), serde_json::json!(0.0)),
+                ("learningRate".to_string(), serde_json::json!(0.1)),
+                ("maxEpochs".to_string(), serde_json::json!(50)),
+            ]),
+        }));
+        pipeline
+    }
+
+    /// Every node links to a node `offset + 2` steps further, which has the same sign of
+    /// `x`, and has two sampled negatives of the other sign around it.
+    fn same_sign_examples(node_count: usize, offset: usize) -> Vec<(usize, usize, f64)> {
+        (0..node_count)
+            .flat_map(|source| {
+                [
+                    (source, (source + offset + 1) % node_count, 0.0),
+                    (source, (source + offset + 2) % node_count, 1.0),
+                    (source, (source + offset + 3) % node_count, 0.0),
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_compute_trains_on_split_graphs() {
         let config = LinkPredictionTrainConfig::builder()
             .pipeline("test".to_string())
             .target_relationship_type("KNOWS".to_string())
             .graph_name("graph".to_string())
             .username("user".to_string())
+            .metrics(vec![
+                "ROC_AUC".to_string(),
+                "LOG_LOSS".to_string(),
+                "HITS@1".to_string(),
+                "MRR".to_string(),
+            ])
+            .random_seed(42)
             .build()
             .unwrap();
+        let x: Vec<f64> = (0..40)
+            .map(|node| {
+                let magnitude = 1.0 + (node % 3) as f64 * 0.1;
+                if node % 2 == 0 {
+                    magnitude
+                } else {
+                    -magnitude
+                }
+            })
+            .collect();
 
         let trainer = LinkPredictionTrain::new(
-            PhantomData,
-            PhantomData,
-            PhantomData,
+            split_graph(&x, &same_sign_examples(40, 0)),
+            split_graph(&x, &same_sign_examples(40, 2)),
+            logistic_regression_pipeline(),
             config,
             PhantomData,
-            PhantomData,
+            TerminationFlag::running_true(),
         );
 
-        let result = trainer.compute();
-
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("not yet implemented"));
+        let result = trainer.compute().unwrap();
+        let statistics = result.training_statistics();
+        let test = statistics.winning_model_test_metrics();
+        assert_eq!(test["ROC_AUC"], 1.0);
+        assert_eq!(test["HITS@1"], 1.0);
+        assert_eq!(test["MRR"], 1.0);
+        assert!(test["LOG_LOSS"] < 0.5);
+        assert_eq!(
+            statistics.winning_model_outer_train_metrics()["ROC_AUC"],
+            1.0
+        );
     }
 
     #[test]
-    fn test_progress_tasks() {
-        let split_config = LinkPredictionSplitConfig::default();
-        let tasks = LinkPredictionTrain::progress_tasks(1000, &split_config, 10);
-
-        // Should have multiple stages
-        assert!(!tasks.is_empty());
+    fn test_compute_rejects_unlabelled_relationships() {
+        let config = LinkPredictionTrainConfig::builder()
+            .pipeline("test".to_string())
+            .target_relationship_type("KNOWS".to_string())
+            .graph_name("graph".to_string())
+            .username("user".to_string())
+            .build()
+            .unwrap();
+        let x = [1.0, -1.0, 1.0];
+        let trainer = LinkPredictionTrain::new(
+            split_graph(&x, &[(0, 2, 1.0), (0, 1, 0.5)]),
+            split_graph(&x, &[(0, 2, 1.0)]),
+            logistic_regression_pipeline(),
+            config,
+            PhantomData,
+            TerminationFlag::running_true(),
+        );
 
-        // Check task names
-        let task_names: Vec<&str> = tasks.iter().map(|t| t.base().description()).collect();
-        assert!(task_names.contains(&"Extract train features"));
-        assert!(task_names.contains(&"Train best model"));
-        assert!(task_names.contains(&"Compute train metrics"));
+        let error = trainer.compute().unwrap_err();
+        assert!(error.contains("has label 0.5"), "{error}");
     }
 
     #[test]
-    fn test_compute_returns_error_until_implemented() {
+    fn test_train_and_evaluate_reports_link_metrics() {
         let config = LinkPredictionTrainConfig::builder()
             .pipeline("test".to_string())
             .target_relationship_type("KNOWS".to_string())
             .graph_name("graph".to_string())
             .username("user".to_string())
+            .metrics(vec![
+                "ROC_AUC".to_string(),
+                "AUCPR".to_string(),
+                "LOG_LOSS".to_string(),
+                "HITS@1".to_string(),
+                "MRR".to_string(),
+            ])
+            .random_seed(42)
             .build()
             .unwrap();
+        let mut pipeline = LinkPredictionTrainingPipeline::new();
+        pipeline.add_trainer_config(Box::new(TestLinkTrainerConfig {
+            parameters: HashMap::from([
+                ("penalty".to_string(), serde_json::json!(0.0)),
+                ("learningRate".to_string(), serde_json::json!(0.1)),
+                ("maxEpochs".to_string(), serde_json::json!(50)),
+            ]),
+        }));
 
         let trainer = LinkPredictionTrain::new(
-            PhantomData,
-            PhantomData,
-            PhantomData,
+            empty_graph(),
+            empty_graph(),
+            pipeline,
             config,
             PhantomData,
-            PhantomData,
+            TerminationFlag::running_true(),
         );
 
-        assert!(trainer.compute().is_err());
+        let result = trainer
+            .train_and_evaluate(&grouped_examples(30, 0), &grouped_examples(10, 100))
+            .unwrap();
+        let statistics = result.training_statistics();
+
+        let validation = &statistics.best_candidate().validation_stats;
+        for metric in ["ROC_AUC", "AUCPR", "LOG_LOSS", "HITS@1", "MRR"] {
+            let scores = validation
+                .get(metric)
+                .unwrap_or_else(|| panic!("missing validation scores for {metric}"));
+            assert_eq!(scores.folds.len(), 3, "{metric}");
+            assert!(statistics
+                .winning_model_outer_train_metrics()
+                .contains_key(metric));
+            assert!(statistics.winning_model_test_metrics().contains_key(metric));
+        }
+
+        let test = statistics.winning_model_test_metrics();
+        assert_eq!(test["ROC_AUC"], 1.0);
+        assert_eq!(test["HITS@1"], 1.0);
+        assert_eq!(test["MRR"], 1.0);
+        assert!(test["LOG_LOSS"] < 0.5);
     }
 
     #[test]
-    fn test_private_stubs_are_callable() {
+    fn test_train_and_evaluate_rejects_unknown_metric() {
         let config = LinkPredictionTrainConfig::builder()
             .pipeline("test".to_string())
             .target_relationship_type("KNOWS".to_string())
             .graph_name("graph".to_string())
             .username("user".to_string())
+            .metrics(vec!["ACCURACY".to_string()])
             .build()
             .unwrap();
-
         let trainer = LinkPredictionTrain::new(
-            PhantomData,
-            PhantomData,
-            PhantomData,
+            empty_graph(),
+            empty_graph(),
+            LinkPredictionTrainingPipeline::new(),
             config,
             PhantomData,
-            PhantomData,
+            TerminationFlag::running_true(),
         );
 
-        let train_data = FeaturesAndLabels::new(vec![vec![0.0]], vec![0]);
-
-        trainer.find_best_model_candidate(&train_data, PhantomData);
-        let classifier = trainer.train_model(&train_data, PhantomData, PhantomData);
-        trainer.compute_train_metric(&train_data, classifier, PhantomData);
-        trainer.compute_test_metric(classifier, PhantomData);
+        let data = grouped_examples(3, 0);
+        let error = trainer.train_and_evaluate(&data, &data).unwrap_err();
+        assert!(error.contains("ACCURACY"), "{error}");
     }
 }
@@ -1,6 +1,8 @@
 // Phase 5.1: LinkPredictionTrainConfig - Training configuration for link prediction
 
 use crate::config::validation::ConfigError;
+use crate::ml::metrics::LinkMetric;
+use crate::ml::metrics::OutOfBagError;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 
@@ -29,8 +31,8 @@ pub struct LinkPredictionTrainConfig {
     /// Target node label (default: "*" = all)
     target_node_label: String,
 
-    /// Evaluation metrics (default: [AUCPR])
-    metrics: Vec<String>, // Note: placeholder until LinkMetric enum is introduced here.
+    /// Evaluation metrics (default: [AUCPR]), parsed with [`LinkMetric::parse`]
+    metrics: Vec<String>,
 
     /// Graph name
     graph_name: String,
@@ -86,6 +88,16 @@ impl LinkPredictionTrainConfig {
         &self.metrics
     }
 
+    /// Parses the metrics, e.g. `AUCPR`, `ROC_AUC`, `LOG_LOSS`, `HITS@10` or `MRR`.
+    /// `OUT_OF_BAG_ERROR` is model specific and not part of the result.
+    pub fn link_metrics(&self) -> Result<Vec<LinkMetric>, String> {
+        self.metrics
+            .iter()
+            .filter(|metric| !metric.eq_ignore_ascii_case(OutOfBagError::NAME))
+            .map(|metric| LinkMetric::parse(metric))
+            .collect()
+    }
+
     /// Returns the main metric (first in list).
     pub fn main_metric(&self) -> &str {
         &self.metrics[0]
@@ -427,6 +439,32 @@ mod tests {
         assert!(labels.contains(&"Product"));
     }
 
+    #[test]
+    fn test_link_metrics() {
+        let config = LinkPredictionTrainConfig::builder()
+            .pipeline("test".to_string())
+            .target_relationship_type("KNOWS".to_string())
+            .metrics(vec![
+                "ROC_AUC".to_string(),
+                "hits@10".to_string(),
+                "MRR".to_string(),
+                "OUT_OF_BAG_ERROR".to_string(),
+            ])
+            .graph_name("graph".to_string())
+            .username("user".to_string())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.link_metrics().unwrap(),
+            vec![
+                LinkMetric::RocAuc,
+                LinkMetric::hits_at(10),
+                LinkMetric::MeanReciprocalRank
+            ]
+        );
+    }
+
     #[test]
     fn test_main_metric() {
         let config = LinkPredictionTrainConfig::builder()
@@ -146,6 +146,14 @@ fn parse_training_method(method: &str) -> Option<TrainingMethod> {
         "RandomForestRegression" | "randomForestRegression" => {
             Some(TrainingMethod::RandomForestRegression)
         }
+        "GradientBoostingClassification"
+        | "gradientBoostingClassification"
+        | "GradientBoosting" => Some(TrainingMethod::GradientBoostingClassification),
+        "GradientBoostingRegression" | "gradientBoostingRegression" => {
+            Some(TrainingMethod::GradientBoostingRegression)
+        }
+        "MLPRegression" | "mlpRegression" => Some(TrainingMethod::MLPRegression),
+        "SVMRegression" | "svmRegression" => Some(TrainingMethod::SVMRegression),
         _ => None,
     }
 }
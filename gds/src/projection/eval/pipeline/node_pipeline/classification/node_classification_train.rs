@@ -7,11 +7,13 @@ use crate::collections::long_multiset::LongMultiSet;
 use crate::collections::{HugeIntArray, HugeLongArray};
 use crate::core::model::ModelCatalog;
 use crate::ml::core::subgraph::LocalIdMap;
+use crate::ml::explain::{permutation_importance, DEFAULT_PERMUTATION_REPEATS};
 use crate::ml::metrics::classification::ClassificationMetric;
 use crate::ml::metrics::ClassificationMetricSpecification;
+use crate::ml::metrics::ConfusionMatrix;
 use crate::ml::metrics::{Metric, ModelSpecificMetricsHandler};
 use crate::ml::models::automl::{
-    create_trainer_config_from_map, RandomSearch, TunableTrainerConfig as MlTunableTrainerConfig,
+    HyperParameterOptimizer, TunableTrainerConfig as MlTunableTrainerConfig,
 };
 use crate::ml::models::{
     base::TrainerConfigTrait, Classifier, ClassifierTrainerFactory, Features,
@@ -387,11 +389,7 @@ impl NodeClassificationTrain {
         let distinct_targets: BTreeSet<i64> =
             (0..self.class_id_map.size()).map(|v| v as i64).collect();
 
-        let candidates = self.collect_candidate_configs()?;
-        let candidate_configs_for_cv: Vec<Box<dyn TrainerConfigTrait>> = candidates
-            .iter()
-            .map(|(method, config_map)| create_trainer_config_from_map(config_map.clone(), *method))
-            .collect();
+        let mut optimizer = self.hyper_parameter_optimizer()?;
 
         let cv_termination_flag = Arc::new(RwLock::new(false));
         let random_seed = self.train_config.random_seed();
@@ -450,26 +448,22 @@ impl NodeClassificationTrain {
         );
 
         let outer_train = node_splits.outer_split().train_set();
-        cv.select_model(
-            outer_train,
-            {
-                let labels_vec = Arc::clone(&labels_vec);
-                move |node_id| {
-                    let row = usize::try_from(node_id)
-                        .expect("training example id must fit the model row domain");
-                    labels_vec[row]
-                }
-            },
-            distinct_targets,
-            &mut training_statistics,
-            candidate_configs_for_cv.into_iter(),
-        );
-
-        let best_idx = training_statistics.best_trial_idx();
-        let (best_method, best_map) = candidates
-            .get(best_idx)
-            .expect("At least one trainer config is required");
-        let best_config = create_trainer_config_from_map(best_map.clone(), *best_method);
+        let best_config = cv
+            .select_model(
+                outer_train,
+                {
+                    let labels_vec = Arc::clone(&labels_vec);
+                    move |node_id| {
+                        let row = usize::try_from(node_id)
+                            .expect("training example id must fit the model row domain");
+                        labels_vec[row]
+                    }
+                },
+                distinct_targets,
+                &mut training_statistics,
+                optimizer.as_mut(),
+            )
+            .ok_or("Node classification model selection produced no winning candidate")?;
 
         let classifier = self.train_simple_model(
             node_splits.outer_split(),
@@ -489,12 +483,61 @@ impl NodeClassificationTrain {
         for (metric, score) in &test_scores {
             training_statistics.add_test_score(metric.clone(), *score);
         }
+        let labels = labels_as_long(&self.targets);
+        for (split, evaluation_set) in [
+            ("outerTrain", node_splits.outer_split().train_set()),
+            ("test", node_splits.outer_split().test_set()),
+        ] {
+            let evaluation = predict_evaluation_set(
+                &evaluation_set,
+                classifier.as_ref(),
+                features.as_ref(),
+                &labels,
+            );
+            training_statistics.add_confusion_matrix(
+                split,
+                ConfusionMatrix::compute(
+                    &evaluation.labels,
+                    &evaluation.predictions,
+                    &self.class_id_map,
+                ),
+            );
+        }
+
+        let test_set = node_splits.outer_split().test_set();
+        let main_metric = classification_metrics
+            .iter()
+            .copied()
+            .find(|metric| metric.name() == training_statistics.evaluation_metric());
+        if let Some(main_metric) = main_metric {
+            let comparator = training_statistics.evaluation_comparator();
+            let test_ids = to_row_ids(&test_set);
+            training_statistics.add_feature_importance(permutation_importance(
+                features.as_ref(),
+                &test_ids,
+                DEFAULT_PERMUTATION_REPEATS,
+                random_seed,
+                |features| {
+                    let scores = evaluate_metrics(
+                        &test_set,
+                        classifier.as_ref(),
+                        features,
+                        &labels,
+                        &[main_metric],
+                    );
+                    comparator.orient(scores[main_metric.name()])
+                },
+            ));
+        }
 
         let retrained = self.retrain_best_model(
             node_splits.all_training_examples(),
             features.as_ref(),
             best_config.as_ref(),
         )?;
+        if let Some(importance) = retrained.feature_importance() {
+            training_statistics.add_feature_importance(importance);
+        }
 
         self.progress_tracker.end_subtask();
 
@@ -615,12 +658,9 @@ impl NodeClassificationTrain {
         (train_scores, test_scores)
     }
 
-    fn collect_candidate_configs(
+    fn hyper_parameter_optimizer(
         &self,
-    ) -> Result<
-        Vec<(MlTrainingMethod, HashMap<String, serde_json::Value>)>,
-        Box<dyn std::error::Error + Send + Sync>,
-    > {
+    ) -> Result<Box<dyn HyperParameterOptimizer>, Box<dyn std::error::Error + Send + Sync>> {
         let mut parameter_space = HashMap::new();
 
         for (method, configs) in self.pipeline.training_parameter_space() {
@@ -637,15 +677,10 @@ impl NodeClassificationTrain {
             return Err("Need at least one classification model candidate for training.".into());
         }
 
-        let candidates = RandomSearch::new_with_seed(
-            parameter_space,
-            self.pipeline.auto_tuning_config().max_trials(),
-            self.train_config.random_seed(),
-        )
-        .map(|config| (config.method(), config.to_map()))
-        .collect();
-
-        Ok(candidates)
+        Ok(self
+            .pipeline
+            .auto_tuning_config()
+            .optimizer(parameter_space, self.train_config.random_seed()))
     }
 }
 
@@ -657,6 +692,9 @@ fn map_training_method(method: PipelineTrainingMethod) -> Result<MlTrainingMetho
         }
         PipelineTrainingMethod::SVMClassification => Ok(MlTrainingMethod::SVMClassification),
         PipelineTrainingMethod::MLPClassification => Ok(MlTrainingMethod::MLPClassification),
+        PipelineTrainingMethod::GradientBoostingClassification => {
+            Ok(MlTrainingMethod::GradientBoostingClassification)
+        }
         other => Err(format!(
             "Unsupported training method for classification: {other:?}"
         )),
@@ -812,21 +850,32 @@ fn to_u64_arc(values: Arc<Vec<i64>>) -> Arc<Vec<u64>> {
     )
 }
 
-fn evaluate_metrics(
-    evaluation_set: &Arc<Vec<i64>>,
-    classifier: &dyn Classifier,
-    features: &dyn Features,
-    labels: &HugeLongArray,
-    metrics: &[&dyn ClassificationMetric],
-) -> HashMap<String, f64> {
-    let eval_ids: Vec<usize> = evaluation_set
+/// The labels, predicted classes and class probabilities of an evaluation set.
+struct Evaluation {
+    labels: HugeLongArray,
+    predictions: HugeLongArray,
+    probabilities: Vec<Vec<f64>>,
+}
+
+fn to_row_ids(evaluation_set: &[i64]) -> Vec<usize> {
+    evaluation_set
         .iter()
         .map(|value| {
             usize::try_from(*value).expect("evaluation example id must fit the model row domain")
         })
-        .collect();
+        .collect()
+}
+
+fn predict_evaluation_set(
+    evaluation_set: &Arc<Vec<i64>>,
+    classifier: &dyn Classifier,
+    features: &dyn Features,
+    labels: &HugeLongArray,
+) -> Evaluation {
+    let eval_ids = to_row_ids(evaluation_set);
     let mut predictions = HugeLongArray::new(eval_ids.len());
     let mut eval_labels = HugeLongArray::new(eval_ids.len());
+    let mut probabilities = Vec::with_capacity(eval_ids.len());
 
     for (idx, node_id) in eval_ids.iter().enumerate() {
         let probs = classifier.predict_probabilities(features.get(*node_id));
@@ -838,14 +887,35 @@ fn evaluate_metrics(
             .unwrap_or(0);
         predictions.set(idx, predicted);
         eval_labels.set(idx, labels.get(*node_id));
+        probabilities.push(probs);
     }
 
+    Evaluation {
+        labels: eval_labels,
+        predictions,
+        probabilities,
+    }
+}
+
+fn evaluate_metrics(
+    evaluation_set: &Arc<Vec<i64>>,
+    classifier: &dyn Classifier,
+    features: &dyn Features,
+    labels: &HugeLongArray,
+    metrics: &[&dyn ClassificationMetric],
+) -> HashMap<String, f64> {
+    let evaluation = predict_evaluation_set(evaluation_set, classifier, features, labels);
+
     metrics
         .iter()
         .map(|metric| {
             (
                 metric.name().to_string(),
-                metric.compute(&eval_labels, &predictions),
+                metric.compute_with_probabilities(
+                    &evaluation.labels,
+                    &evaluation.predictions,
+                    &evaluation.probabilities,
+                ),
             )
         })
         .collect()
@@ -858,6 +928,7 @@ mod tests {
     use crate::collections::backends::vec::VecLong;
     use crate::core::graph_dimensions::ConcreteGraphDimensions;
     use crate::core::model::EmptyModelCatalog;
+    use crate::ml::explain::FeatureImportanceKind;
     use crate::projection::eval::pipeline::NodeFeatureStep;
     use crate::projection::eval::pipeline::TunableTrainerConfig;
     use crate::task::progress::NoopProgressTracker;
@@ -1218,6 +1289,25 @@ mod tests {
         assert!(statistics
             .winning_model_test_metrics()
             .contains_key("ACCURACY"));
+        let test_matrix = statistics
+            .confusion_matrix("test")
+            .expect("test confusion matrix");
+        assert!(test_matrix.total() > 0);
+        assert!(statistics_map["confusionMatrices"]["outerTrain"]["perClass"].is_object());
+        let validation_folds = candidates[0]["metrics"]["ACCURACY"]["validation"]["folds"]
+            .as_array()
+            .expect("per-fold validation scores");
+        assert!(validation_folds.len() >= 2);
+
+        let impurity = statistics
+            .feature_importance(FeatureImportanceKind::Impurity)
+            .expect("impurity importance of the forest");
+        assert_eq!(impurity.scores().len(), 1);
+        assert!(statistics
+            .feature_importance(FeatureImportanceKind::Permutation)
+            .is_some());
+        let importance_map = statistics.feature_importance_map(&["feature".to_string()]);
+        assert_eq!(importance_map["impurity"][0]["feature"], "feature");
     }
 
     #[test]
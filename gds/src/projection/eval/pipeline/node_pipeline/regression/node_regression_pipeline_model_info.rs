@@ -254,6 +254,14 @@ fn parse_training_method(method: &str) -> Option<TrainingMethod> {
         "MLPClassification" | "mlpClassification" | "MultilayerPerceptron" => {
             Some(TrainingMethod::MLPClassification)
         }
+        "GradientBoostingRegression" | "gradientBoostingRegression" | "GradientBoosting" => {
+            Some(TrainingMethod::GradientBoostingRegression)
+        }
+        "GradientBoostingClassification" | "gradientBoostingClassification" => {
+            Some(TrainingMethod::GradientBoostingClassification)
+        }
+        "MLPRegression" | "mlpRegression" => Some(TrainingMethod::MLPRegression),
+        "SVMRegression" | "svmRegression" => Some(TrainingMethod::SVMRegression),
         _ => None,
     }
 }
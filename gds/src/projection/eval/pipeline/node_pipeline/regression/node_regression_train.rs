@@ -3,10 +3,11 @@ use super::node_regression_train_result::NodeRegressionTrainResult;
 use super::node_regression_training_pipeline::NodeRegressionTrainingPipeline;
 use crate::collections::HugeDoubleArray;
 use crate::core::model::ModelCatalog;
+use crate::ml::explain::{permutation_importance, DEFAULT_PERMUTATION_REPEATS};
 use crate::ml::metrics::regression::RegressionMetric;
 use crate::ml::metrics::Metric;
 use crate::ml::models::automl::{
-    create_trainer_config_from_map, RandomSearch, TunableTrainerConfig as MlTunableTrainerConfig,
+    HyperParameterOptimizer, TunableTrainerConfig as MlTunableTrainerConfig,
 };
 use crate::ml::models::base::TrainerConfigTrait;
 use crate::ml::models::{Features, RegressionTrainerFactory, Regressor};
@@ -327,11 +328,7 @@ impl NodeRegressionTrain {
             return Err("Node regression training was terminated".into());
         }
 
-        let candidates = self.collect_candidate_configs()?;
-        let candidate_configs_for_cv: Vec<Box<dyn TrainerConfigTrait>> = candidates
-            .iter()
-            .map(|(method, config_map)| create_trainer_config_from_map(config_map.clone(), *method))
-            .collect();
+        let mut optimizer = self.hyper_parameter_optimizer()?;
 
         let metrics_for_cv: Vec<Box<dyn Metric>> = metrics
             .iter()
@@ -386,22 +383,20 @@ impl NodeRegressionTrain {
             }),
         );
 
-        cv.select_model(
+        let best_config = cv.select_model(
             node_splits.outer_split().train_set(),
             |_| 0,
             BTreeSet::from([0]),
             &mut training_statistics,
-            candidate_configs_for_cv.into_iter(),
+            optimizer.as_mut(),
         );
 
         if !self.termination_flag.running() {
             return Err("Node regression training was terminated".into());
         }
 
-        let (best_method, best_map) = candidates
-            .get(training_statistics.best_trial_idx())
-            .ok_or("Node regression model selection produced no winning candidate")?;
-        let best_config = create_trainer_config_from_map(best_map.clone(), *best_method);
+        let best_config =
+            best_config.ok_or("Node regression model selection produced no winning candidate")?;
 
         let regressor = self.train_simple_model(
             node_splits.outer_split(),
@@ -416,11 +411,46 @@ impl NodeRegressionTrain {
             &mut training_statistics,
         );
 
+        let test_set = node_splits.outer_split().test_set();
+        let main_metric = metrics
+            .iter()
+            .copied()
+            .find(|metric| metric.name() == training_statistics.evaluation_metric());
+        if let Some(main_metric) = main_metric {
+            let comparator = training_statistics.evaluation_comparator();
+            let test_ids: Vec<usize> = test_set
+                .iter()
+                .map(|id| {
+                    usize::try_from(*id)
+                        .expect("evaluation example id must fit the model row domain")
+                })
+                .collect();
+            training_statistics.add_feature_importance(permutation_importance(
+                features.as_ref(),
+                &test_ids,
+                DEFAULT_PERMUTATION_REPEATS,
+                random_seed,
+                |features| {
+                    let scores = evaluate_metrics(
+                        &test_set,
+                        features,
+                        regressor.as_ref(),
+                        &self.targets,
+                        &[main_metric],
+                    );
+                    comparator.orient(scores[main_metric.name()])
+                },
+            ));
+        }
+
         let retrained = self.retrain_best_model(
             node_splits.all_training_examples(),
             features.as_ref(),
             best_config.as_ref(),
         )?;
+        if let Some(importance) = retrained.feature_importance() {
+            training_statistics.add_feature_importance(importance);
+        }
 
         self.progress_tracker.end_subtask();
 
@@ -521,19 +551,16 @@ impl NodeRegressionTrain {
         }
     }
 
-    fn collect_candidate_configs(
+    fn hyper_parameter_optimizer(
         &self,
-    ) -> Result<
-        Vec<(
-            crate::ml::models::TrainingMethod,
-            HashMap<String, serde_json::Value>,
-        )>,
-        Box<dyn std::error::Error + Send + Sync>,
-    > {
+    ) -> Result<Box<dyn HyperParameterOptimizer>, Box<dyn std::error::Error + Send + Sync>> {
         let mut parameter_space = HashMap::new();
         for method in [
             PipelineTrainingMethod::LinearRegression,
             PipelineTrainingMethod::RandomForestRegression,
+            PipelineTrainingMethod::GradientBoostingRegression,
+            PipelineTrainingMethod::MLPRegression,
+            PipelineTrainingMethod::SVMRegression,
         ] {
             let Some(configs) = self.pipeline.training_parameter_space().get(&method) else {
                 continue;
@@ -551,15 +578,10 @@ impl NodeRegressionTrain {
             return Err("Need at least one regression model candidate for training.".into());
         }
 
-        let candidates = RandomSearch::new_with_seed(
-            parameter_space,
-            self.pipeline.auto_tuning_config().max_trials(),
-            self.train_config.random_seed(),
-        )
-        .map(|config| (config.method(), config.to_map()))
-        .collect();
-
-        Ok(candidates)
+        Ok(self
+            .pipeline
+            .auto_tuning_config()
+            .optimizer(parameter_space, self.train_config.random_seed()))
     }
 }
 
@@ -571,6 +593,11 @@ fn map_training_method(method: PipelineTrainingMethod) -> crate::ml::models::Tra
         PipelineTrainingMethod::RandomForestRegression => {
             crate::ml::models::TrainingMethod::RandomForestRegression
         }
+        PipelineTrainingMethod::GradientBoostingRegression => {
+            crate::ml::models::TrainingMethod::GradientBoostingRegression
+        }
+        PipelineTrainingMethod::MLPRegression => crate::ml::models::TrainingMethod::MLPRegression,
+        PipelineTrainingMethod::SVMRegression => crate::ml::models::TrainingMethod::SVMRegression,
         other => panic!("Unsupported training method for regression: {other:?}"),
     }
 }
@@ -747,6 +774,7 @@ mod tests {
     use super::*;
     use crate::collections::backends::vec::VecDouble;
     use crate::core::model::EmptyModelCatalog;
+    use crate::ml::explain::FeatureImportanceKind;
     use crate::projection::eval::pipeline::NodeFeatureStep;
     use crate::projection::eval::pipeline::TunableTrainerConfig;
     use crate::task::progress::NoopProgressTracker;
@@ -1037,10 +1065,128 @@ mod tests {
         assert!(statistics
             .winning_model_test_metrics()
             .contains_key("MEAN_SQUARED_ERROR"));
+
+        let permutation = statistics
+            .feature_importance(FeatureImportanceKind::Permutation)
+            .expect("permutation importance on the test set");
+        assert!(permutation.scores()[0] > 0.0);
+        let impurity = statistics
+            .feature_importance(FeatureImportanceKind::Impurity)
+            .expect("impurity importance of the forest");
+        assert!((impurity.scores()[0] - 1.0).abs() < 1e-9);
+
+        let row = [12.0];
+        let explanation = result
+            .regressor()
+            .explain(&row)
+            .expect("forest explanation");
+        assert!((explanation.output() - result.regressor().predict(&row)).abs() < 1e-9);
     }
 
     #[test]
-    fn test_run_materializes_tunable_linear_regression_candidates() {
+    fn test_run_prefers_non_linear_candidates_for_non_linear_target() {
+        let mut graph_store = DefaultGraphStore::random(&RandomGraphConfig {
+            seed: Some(42),
+            node_count: 60,
+            ..RandomGraphConfig::default()
+        })
+        .expect("random graph");
+        let node_count = graph_store.node_count();
+        let labels = graph_store.node_labels();
+        let xs: Vec<f64> = (0..node_count)
+            .map(|node_id| node_id as f64 / (node_count - 1) as f64)
+            .collect();
+        let targets: Vec<f64> = xs
+            .iter()
+            .map(|x| (2.0 * std::f64::consts::PI * x).sin())
+            .collect();
+        graph_store
+            .add_node_property(
+                labels.clone(),
+                "feature",
+                Arc::new(DefaultDoubleNodePropertyValues::from_collection(
+                    VecDouble::from(xs),
+                    node_count,
+                )),
+            )
+            .expect("feature property");
+        graph_store
+            .add_node_property(
+                labels,
+                "target",
+                Arc::new(DefaultDoubleNodePropertyValues::from_collection(
+                    VecDouble::from(targets),
+                    node_count,
+                )),
+            )
+            .expect("target property");
+
+        let mut pipeline = NodeRegressionTrainingPipeline::new();
+        pipeline.add_feature_step(NodeFeatureStep::of("feature"));
+        pipeline.add_trainer_config(Box::new(FixedRegressionTrainerConfig {
+            method: PipelineTrainingMethod::LinearRegression,
+            parameters: HashMap::from([
+                ("maxEpochs".to_string(), serde_json::json!(100)),
+                ("learningRate".to_string(), serde_json::json!(0.05)),
+            ]),
+        }));
+        pipeline.add_trainer_config(Box::new(FixedRegressionTrainerConfig {
+            method: PipelineTrainingMethod::SVMRegression,
+            parameters: HashMap::from([
+                ("kernel".to_string(), serde_json::json!("rbf")),
+                ("gamma".to_string(), serde_json::json!(20.0)),
+                ("c".to_string(), serde_json::json!(10.0)),
+                ("epsilon".to_string(), serde_json::json!(0.05)),
+            ]),
+        }));
+        pipeline.add_trainer_config(Box::new(FixedRegressionTrainerConfig {
+            method: PipelineTrainingMethod::MLPRegression,
+            parameters: HashMap::from([
+                ("loss".to_string(), serde_json::json!("huber")),
+                ("hiddenLayerSizes".to_string(), serde_json::json!([8])),
+                ("maxEpochs".to_string(), serde_json::json!(20)),
+            ]),
+        }));
+
+        let graph_store = Arc::new(graph_store);
+        let config = NodeRegressionPipelineTrainConfig::new(
+            "test-pipeline".to_string(),
+            vec!["*".to_string()],
+            "target".to_string(),
+            Some(42),
+            vec![RegressionMetric::MeanSquaredError],
+        )
+        .with_concurrency(1);
+        let producer = NodeFeatureProducer::create(Arc::clone(&graph_store), config.clone());
+        let mut trainer = NodeRegressionTrain::create(
+            graph_store,
+            pipeline,
+            config,
+            producer,
+            Box::new(NoopProgressTracker),
+        );
+
+        let result = trainer.run().expect("regression training should run");
+        let statistics = result.training_statistics();
+        let statistics_map = statistics.to_map();
+        let candidates = statistics_map["modelCandidates"]
+            .as_array()
+            .expect("model candidate statistics");
+
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(
+            result.regressor().data().trainer_method(),
+            crate::ml::models::TrainingMethod::SVMRegression
+        );
+        assert_eq!(statistics.best_parameters()["method"], "SVMRegression");
+        assert_eq!(statistics.best_parameters()["kernel"], "rbf");
+        let quarter = result.regressor().predict(&[0.25]);
+        assert!((quarter - 1.0).abs() < 0.2, "prediction at 0.25: {quarter}");
+    }
+
+    fn train_tunable_linear_regression(
+        auto_tuning_config: crate::projection::eval::pipeline::AutoTuningConfig,
+    ) -> NodeRegressionTrainResult {
         let mut graph_store = DefaultGraphStore::random(&RandomGraphConfig {
             seed: Some(42),
             node_count: 60,
@@ -1091,10 +1237,7 @@ mod tests {
                 ),
             ]),
         }));
-        pipeline.set_auto_tuning_config(
-            crate::projection::eval::pipeline::AutoTuningConfig::new(3)
-                .expect("valid AutoML trial count"),
-        );
+        pipeline.set_auto_tuning_config(auto_tuning_config);
 
         let graph_store = Arc::new(graph_store);
         let config = NodeRegressionPipelineTrainConfig::new(
@@ -1114,9 +1257,17 @@ mod tests {
             Box::new(NoopProgressTracker),
         );
 
-        let result = trainer
+        trainer
             .run()
-            .expect("AutoML regression training should run");
+            .expect("AutoML regression training should run")
+    }
+
+    #[test]
+    fn test_run_materializes_tunable_linear_regression_candidates() {
+        let result = train_tunable_linear_regression(
+            crate::projection::eval::pipeline::AutoTuningConfig::new(3)
+                .expect("valid AutoML trial count"),
+        );
         let statistics = result.training_statistics();
         let statistics_map = statistics.to_map();
         let candidates = statistics_map["modelCandidates"]
@@ -1143,6 +1294,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_run_with_tree_parzen_estimator() {
+        let result = train_tunable_linear_regression(
+            crate::projection::eval::pipeline::AutoTuningConfig::new(8)
+                .expect("valid AutoML trial count")
+                .with_search_strategy(
+                    crate::projection::eval::pipeline::SearchStrategy::TreeParzenEstimator,
+                ),
+        );
+        let statistics = result.training_statistics();
+        let statistics_map = statistics.to_map();
+        let candidates = statistics_map["modelCandidates"]
+            .as_array()
+            .expect("model candidate statistics");
+
+        // Pruned trials are not recorded, but the uniform startup trials always finish.
+        assert!((5..=8).contains(&candidates.len()));
+        assert_eq!(statistics.best_parameters()["method"], "LinearRegression");
+    }
+
     #[test]
     fn test_try_create_reports_missing_target_property() {
         let config = RandomGraphConfig {
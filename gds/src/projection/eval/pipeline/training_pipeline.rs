@@ -24,10 +24,14 @@ impl TrainingType {
                 TrainingMethod::RandomForestClassification,
                 TrainingMethod::SVMClassification,
                 TrainingMethod::MLPClassification,
+                TrainingMethod::GradientBoostingClassification,
             ],
             TrainingType::Regression => vec![
                 TrainingMethod::LinearRegression,
                 TrainingMethod::RandomForestRegression,
+                TrainingMethod::GradientBoostingRegression,
+                TrainingMethod::MLPRegression,
+                TrainingMethod::SVMRegression,
             ],
         }
     }
@@ -44,6 +48,10 @@ pub enum TrainingMethod {
     MLPClassification,
     LinearRegression,
     RandomForestRegression,
+    GradientBoostingClassification,
+    GradientBoostingRegression,
+    MLPRegression,
+    SVMRegression,
 }
 
 impl std::fmt::Display for TrainingMethod {
@@ -55,6 +63,10 @@ impl std::fmt::Display for TrainingMethod {
             Self::MLPClassification => write!(f, "MLPClassification"),
             Self::LinearRegression => write!(f, "LinearRegression"),
             Self::RandomForestRegression => write!(f, "RandomForestRegression"),
+            Self::GradientBoostingClassification => write!(f, "GradientBoostingClassification"),
+            Self::GradientBoostingRegression => write!(f, "GradientBoostingRegression"),
+            Self::MLPRegression => write!(f, "MLPRegression"),
+            Self::SVMRegression => write!(f, "SVMRegression"),
         }
     }
 }
@@ -244,19 +256,23 @@ mod tests {
     #[test]
     fn test_training_type_classification_methods() {
         let methods = TrainingType::Classification.supported_methods();
-        assert_eq!(methods.len(), 4);
+        assert_eq!(methods.len(), 5);
         assert!(methods.contains(&TrainingMethod::LogisticRegression));
         assert!(methods.contains(&TrainingMethod::RandomForestClassification));
         assert!(methods.contains(&TrainingMethod::SVMClassification));
         assert!(methods.contains(&TrainingMethod::MLPClassification));
+        assert!(methods.contains(&TrainingMethod::GradientBoostingClassification));
     }
 
     #[test]
     fn test_training_type_regression_methods() {
         let methods = TrainingType::Regression.supported_methods();
-        assert_eq!(methods.len(), 2);
+        assert_eq!(methods.len(), 5);
         assert!(methods.contains(&TrainingMethod::LinearRegression));
         assert!(methods.contains(&TrainingMethod::RandomForestRegression));
+        assert!(methods.contains(&TrainingMethod::GradientBoostingRegression));
+        assert!(methods.contains(&TrainingMethod::MLPRegression));
+        assert!(methods.contains(&TrainingMethod::SVMRegression));
     }
 
     // Mock implementations for testing
@@ -48,6 +48,10 @@ pub struct ArrowProjectionConfig {
     /// Preferred collections backend for properties/topology storage
     /// Default: Arrow
     pub collections_backend: crate::config::CollectionsBackend,
+
+    /// Whether to store adjacency as sorted, delta + varint compressed CSR blocks
+    /// Default: false
+    pub compress_adjacency: bool,
 }
 
 impl ArrowProjectionConfig {
@@ -110,6 +114,7 @@ impl Default for ArrowProjectionConfig {
             log_progress: false,
             batch_size: 10_000,
             collections_backend: crate::config::CollectionsBackend::Arrow,
+            compress_adjacency: false,
         }
     }
 }
@@ -124,6 +129,7 @@ pub struct ArrowProjectionConfigBuilder {
     log_progress: Option<bool>,
     batch_size: Option<usize>,
     collections_backend: Option<crate::config::CollectionsBackend>,
+    compress_adjacency: Option<bool>,
 }
 
 impl ArrowProjectionConfigBuilder {
@@ -169,6 +175,12 @@ impl ArrowProjectionConfigBuilder {
         self
     }
 
+    /// Set whether to compress adjacency lists.
+    pub fn compress_adjacency(mut self, compress: bool) -> Self {
+        self.compress_adjacency = Some(compress);
+        self
+    }
+
     /// Build the configuration.
     ///
     /// Uses defaults for unset fields, then validates.
@@ -183,6 +195,7 @@ impl ArrowProjectionConfigBuilder {
             collections_backend: self
                 .collections_backend
                 .unwrap_or(crate::config::CollectionsBackend::Arrow),
+            compress_adjacency: self.compress_adjacency.unwrap_or(false),
         };
 
         config.validate()?;
@@ -236,6 +249,7 @@ mod tests {
         assert!(config.validate_schema);
         assert!(!config.log_progress);
         assert_eq!(config.batch_size, 10_000);
+        assert!(!config.compress_adjacency);
     }
 
     #[test]
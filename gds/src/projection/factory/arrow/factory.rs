@@ -12,9 +12,10 @@ use super::config::{ArrowProjectionConfig, ArrowProjectionError};
 use super::reference::{EdgeTableReference, NodeTableReference};
 use crate::projection::factory::arrow::reference::ArrowReference;
 use crate::projection::factory::GraphStoreFactory;
+use crate::task::concurrency::Concurrency;
+use crate::types::graph::ExternalNodeKey;
+use crate::types::graph::IdMap;
 use crate::types::graph::MappedNodeId;
-use crate::types::graph::OriginalNodeId;
-use crate::types::graph::PartialIdMap;
 use crate::types::graph::RelationshipTopology;
 use crate::types::graph::SimpleIdMap;
 use crate::types::graph_store::DefaultGraphStore;
@@ -272,29 +273,54 @@ impl ArrowNativeFactory {
         &self,
         node_table: &Arc<NodeTableReference>,
         edge_table: &Arc<EdgeTableReference>,
-        _config: &ArrowProjectionConfig,
+        config: &ArrowProjectionConfig,
     ) -> Result<DefaultGraphStore, ArrowProjectionError> {
-        // Build id map from node ids (assume dense i64 ids)
-        let node_ids: Vec<i64> = node_table.id_column().values().to_vec();
-        let id_map = SimpleIdMap::try_from_original_ids(node_ids.iter().copied())
-            .map_err(|error| ArrowProjectionError::Import(error.to_string()))?;
+        // Build id map from node ids; string keys are interned and get synthetic ids
+        let (id_map, node_ids) = match node_table.key_column() {
+            Some(keys) => {
+                let id_map = SimpleIdMap::try_from_external_keys(keys.values_iter())
+                    .map_err(|error| ArrowProjectionError::Import(error.to_string()))?;
+                let node_ids: Vec<i64> = (0..keys.len() as i64).collect();
+                (id_map, node_ids)
+            }
+            None => {
+                let node_ids: Vec<i64> = node_table.id_column().values().to_vec();
+                let id_map = SimpleIdMap::try_from_original_ids_concurrent(
+                    &node_ids,
+                    Concurrency::of(config.concurrency),
+                )
+                .map_err(|error| ArrowProjectionError::Import(error.to_string()))?;
+                (id_map, node_ids)
+            }
+        };
 
         // Build topology (outgoing adjacency) from source/target columns
-        let source = edge_table.source_column();
-        let target = edge_table.target_column();
+        let endpoints: Box<dyn Iterator<Item = (ExternalNodeKey, ExternalNodeKey)>> =
+            match edge_table.key_columns() {
+                Some((source, target)) => Box::new(
+                    source
+                        .values_iter()
+                        .zip(target.values_iter())
+                        .map(|(s, t)| (s.into(), t.into())),
+                ),
+                None => Box::new(
+                    edge_table
+                        .source_column()
+                        .values()
+                        .iter()
+                        .zip(edge_table.target_column().values().iter())
+                        .map(|(&s, &t)| (s.into(), t.into())),
+                ),
+            };
         let node_count = node_ids.len();
         let mut outgoing: Vec<Vec<MappedNodeId>> = vec![Vec::new(); node_count];
-        for (&s, &t) in source.values().iter().zip(target.values().iter()) {
-            let mapped_source = id_map
-                .to_mapped_node_id(OriginalNodeId::new(s))
-                .ok_or_else(|| {
-                    ArrowProjectionError::Import(format!("source id {s} missing in nodes"))
-                })?;
-            let mapped_target = id_map
-                .to_mapped_node_id(OriginalNodeId::new(t))
-                .ok_or_else(|| {
-                    ArrowProjectionError::Import(format!("target id {t} missing in nodes"))
-                })?;
+        for (s, t) in endpoints {
+            let mapped_source = id_map.to_mapped_node_id_by_key(&s).ok_or_else(|| {
+                ArrowProjectionError::Import(format!("source id {s} missing in nodes"))
+            })?;
+            let mapped_target = id_map.to_mapped_node_id_by_key(&t).ok_or_else(|| {
+                ArrowProjectionError::Import(format!("target id {t} missing in nodes"))
+            })?;
             let source_index = mapped_source.to_usize().ok_or_else(|| {
                 ArrowProjectionError::Import(format!(
                     "mapped source {mapped_source} exceeds physical index space"
@@ -305,8 +331,16 @@ impl ArrowNativeFactory {
                 .expect("mapped source must belong to the imported node domain")
                 .push(mapped_target);
         }
-        let topology = RelationshipTopology::try_new(outgoing, None)
-            .map_err(|error| ArrowProjectionError::Import(error.to_string()))?;
+        // No relationship properties are imported, so rows can be reordered freely.
+        let topology = if config.compress_adjacency {
+            for row in &mut outgoing {
+                row.sort_unstable();
+            }
+            RelationshipTopology::try_new_compressed(outgoing, None)
+        } else {
+            RelationshipTopology::try_new(outgoing, None)
+        }
+        .map_err(|error| ArrowProjectionError::Import(error.to_string()))?;
 
         let schema = infer_materialized_schema(node_table);
 
@@ -555,4 +589,76 @@ mod tests {
         assert!(store.schema().graph_properties().contains_key("node_count"));
         validate_graph_store_schema(&store).expect("schema must describe materialized store");
     }
+
+    #[test]
+    fn build_with_compressed_adjacency() {
+        use crate::projection::factory::arrow::test_utils::{sample_edge_table, sample_node_table};
+        use crate::types::graph::Degrees;
+        use crate::types::graph_store::GraphStore;
+        use crate::types::properties::relationship::RelationshipPredicate;
+
+        let factory = ArrowNativeFactory::from_tables(
+            Arc::new(sample_node_table()),
+            Arc::new(sample_edge_table()),
+        );
+        let config = ArrowProjectionConfig::builder()
+            .compress_adjacency(true)
+            .build()
+            .unwrap();
+        let store = factory
+            .build_graph_store(&config)
+            .expect("build graph store");
+
+        assert_eq!(store.relationship_count(), 2);
+        let graph = store.graph();
+        assert_eq!(graph.degree(MappedNodeId::ZERO), 1);
+        assert!(graph.exists(MappedNodeId::ZERO, MappedNodeId::new(1)));
+        assert!(!graph.exists(MappedNodeId::new(1), MappedNodeId::ZERO));
+    }
+
+    #[test]
+    fn build_from_string_keyed_tables() {
+        use crate::applications::graph_store_catalog::applications::stream_applications::StreamRelationshipsApplication;
+        use arrow2::array::{Array, Utf8Array};
+        use arrow2::chunk::Chunk;
+        use arrow2::datatypes::{DataType, Field, Schema};
+
+        let keys: Box<dyn Array> = Box::new(Utf8Array::<i32>::from_slice([
+            "ada@example.com",
+            "bob@example.com",
+        ]));
+        let nodes = NodeTableReference::new(
+            "nodes",
+            Chunk::new(vec![keys]),
+            Arc::new(Schema::from(vec![Field::new("id", DataType::Utf8, false)])),
+        )
+        .unwrap();
+        let sources: Box<dyn Array> = Box::new(Utf8Array::<i32>::from_slice(["bob@example.com"]));
+        let targets: Box<dyn Array> = Box::new(Utf8Array::<i32>::from_slice(["ada@example.com"]));
+        let edges = EdgeTableReference::new(
+            "edges",
+            Chunk::new(vec![sources, targets]),
+            Arc::new(Schema::from(vec![
+                Field::new("source", DataType::Utf8, false),
+                Field::new("target", DataType::Utf8, false),
+            ])),
+        )
+        .unwrap();
+
+        let store = ArrowNativeFactory::from_tables(Arc::new(nodes), Arc::new(edges))
+            .build_graph_store(&ArrowProjectionConfig::default())
+            .expect("build graph store");
+        let graph = store.graph();
+        let bob = graph
+            .to_mapped_node_id_by_key(&"bob@example.com".into())
+            .unwrap();
+        assert_eq!(graph.external_node_key(bob), Some("bob@example.com".into()));
+
+        let streamed = StreamRelationshipsApplication
+            .compute(&store, &[])
+            .expect("stream relationships");
+        assert_eq!(streamed.len(), 1);
+        assert_eq!(streamed[0].source_node_key, Some("bob@example.com".into()));
+        assert_eq!(streamed[0].target_node_key, Some("ada@example.com".into()));
+    }
 }
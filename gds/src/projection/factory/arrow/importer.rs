@@ -345,7 +345,9 @@ impl NodeAccumulator {
                 id_map.add_node_label(label.clone());
                 let mapped_node_id = MappedNodeId::try_from(node_index)
                     .expect("node index was validated while constructing the id map");
-                id_map.add_node_id_to_label(mapped_node_id, label);
+                id_map
+                    .add_node_id_to_label(mapped_node_id, label)
+                    .expect("node index was validated while constructing the id map");
             }
         }
 
@@ -1211,7 +1213,7 @@ mod tests {
         let knows = topologies.get(&RelationshipType::of("KNOWS")).unwrap();
         assert_eq!(knows.relationship_count(), 2);
         assert_eq!(
-            knows.outgoing(MappedNodeId::ZERO).unwrap(),
+            knows.outgoing(MappedNodeId::ZERO).unwrap().as_ref(),
             &[MappedNodeId::new(1), MappedNodeId::new(2)]
         );
 
@@ -1219,7 +1221,7 @@ mod tests {
         let works_at = topologies.get(&RelationshipType::of("WORKS_AT")).unwrap();
         assert_eq!(works_at.relationship_count(), 1);
         assert_eq!(
-            works_at.outgoing(MappedNodeId::new(1)).unwrap(),
+            works_at.outgoing(MappedNodeId::new(1)).unwrap().as_ref(),
             &[MappedNodeId::new(2)]
         );
     }
@@ -109,7 +109,7 @@ pub trait ArrowReference {
 /// Reference to a node table stored as arrow2 Chunk.
 ///
 /// Expected schema conventions:
-/// - ID column: "id" or "nodeId" (Int64)
+/// - ID column: "id" or "nodeId" (Int64, or Utf8 for string node keys)
 /// - Label column: "label" or "labels" (Utf8)
 /// - Property columns: any additional columns
 ///
@@ -155,11 +155,11 @@ impl NodeTableReference {
                 column_name: "id or nodeId".to_string(),
             })?;
 
-        // Validate ID column is Int64
+        // Validate ID column is Int64 or Utf8
         let id_field = &schema.fields[id_column_idx];
-        if id_field.data_type != DataType::Int64 {
+        if !is_node_key_type(&id_field.data_type) {
             return Err(ArrowReferenceError::InvalidColumnType {
-                expected: "Int64".to_string(),
+                expected: "Int64 or Utf8".to_string(),
                 actual: format!("{:?}", id_field.data_type),
             });
         }
@@ -200,6 +200,8 @@ impl NodeTableReference {
     }
 
     /// Returns a reference to the ID column as Int64Array
+    ///
+    /// Panics for tables keyed by strings, see [`Self::key_column`].
     pub fn id_column(&self) -> &PrimitiveArray<i64> {
         self.chunk.arrays()[self.id_column_idx]
             .as_any()
@@ -207,6 +209,13 @@ impl NodeTableReference {
             .expect("ID column should be Int64")
     }
 
+    /// Returns the ID column as Utf8Array when nodes are keyed by strings
+    pub fn key_column(&self) -> Option<&Utf8Array<i32>> {
+        self.chunk.arrays()[self.id_column_idx]
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+    }
+
     /// Returns a reference to the label column as Utf8Array (if present)
     pub fn label_column(&self) -> Option<&Utf8Array<i32>> {
         self.label_column_idx.map(|idx| {
@@ -250,6 +259,11 @@ impl ArrowReference for NodeTableReference {
     }
 }
 
+/// Node identifier columns hold integer ids or string keys.
+fn is_node_key_type(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::Int64 | DataType::Utf8)
+}
+
 // ================================================================================================
 // EdgeTableReference - Wrapper for edge tables (arrow2 Chunk)
 // ================================================================================================
@@ -257,8 +271,8 @@ impl ArrowReference for NodeTableReference {
 /// Reference to an edge table stored as arrow2 Chunk.
 ///
 /// Expected schema conventions:
-/// - Source column: "source" or "sourceId" (Int64)
-/// - Target column: "target" or "targetId" (Int64)
+/// - Source column: "source" or "sourceId" (Int64, or Utf8 for string node keys)
+/// - Target column: "target" or "targetId" (same type as the source column)
 /// - Type column: "type" or "relationshipType" (Utf8, optional)
 /// - Property columns: any additional columns
 ///
@@ -316,19 +330,19 @@ impl EdgeTableReference {
                 column_name: "target or targetId".to_string(),
             })?;
 
-        // Validate source/target columns are Int64
+        // Validate source/target columns are both Int64 or both Utf8
         let source_field = &schema.fields[source_column_idx];
-        if source_field.data_type != DataType::Int64 {
+        if !is_node_key_type(&source_field.data_type) {
             return Err(ArrowReferenceError::InvalidColumnType {
-                expected: "Int64".to_string(),
+                expected: "Int64 or Utf8".to_string(),
                 actual: format!("{:?}", source_field.data_type),
             });
         }
 
         let target_field = &schema.fields[target_column_idx];
-        if target_field.data_type != DataType::Int64 {
+        if target_field.data_type != source_field.data_type {
             return Err(ArrowReferenceError::InvalidColumnType {
-                expected: "Int64".to_string(),
+                expected: format!("{:?}", source_field.data_type),
                 actual: format!("{:?}", target_field.data_type),
             });
         }
@@ -390,6 +404,18 @@ impl EdgeTableReference {
             .expect("Target column should be Int64")
     }
 
+    /// Returns the source and target columns as Utf8Arrays when nodes are keyed by strings
+    pub fn key_columns(&self) -> Option<(&Utf8Array<i32>, &Utf8Array<i32>)> {
+        let arrays = self.chunk.arrays();
+        let source = arrays[self.source_column_idx]
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()?;
+        let target = arrays[self.target_column_idx]
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()?;
+        Some((source, target))
+    }
+
     /// Returns a reference to the type column as Utf8Array (if present)
     pub fn type_column(&self) -> Option<&Utf8Array<i32>> {
         self.type_column_idx.map(|idx| {
@@ -667,9 +693,13 @@ mod tests {
 
     #[test]
     fn test_invalid_id_type_error() {
-        let id_array: Box<dyn Array> = Box::new(Utf8Array::<i32>::from_slice(["1", "2"]));
+        let id_array: Box<dyn Array> = Box::new(PrimitiveArray::<f64>::from_slice([1.0, 2.0]));
         let chunk = Chunk::new(vec![id_array]);
-        let schema = Arc::new(Schema::from(vec![Field::new("id", DataType::Utf8, false)]));
+        let schema = Arc::new(Schema::from(vec![Field::new(
+            "id",
+            DataType::Float64,
+            false,
+        )]));
 
         let result = NodeTableReference::new("nodes", chunk, schema);
         assert!(matches!(
@@ -678,6 +708,44 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_string_keyed_tables() {
+        let ids: Box<dyn Array> = Box::new(Utf8Array::<i32>::from_slice(["a", "b"]));
+        let nodes = NodeTableReference::new(
+            "nodes",
+            Chunk::new(vec![ids]),
+            Arc::new(Schema::from(vec![Field::new("id", DataType::Utf8, false)])),
+        )
+        .unwrap();
+        assert_eq!(nodes.key_column().unwrap().value(1), "b");
+
+        let edge_schema = |target_type| {
+            Arc::new(Schema::from(vec![
+                Field::new("source", DataType::Utf8, false),
+                Field::new("target", target_type, false),
+            ]))
+        };
+        let source: Box<dyn Array> = Box::new(Utf8Array::<i32>::from_slice(["a"]));
+        let target: Box<dyn Array> = Box::new(Utf8Array::<i32>::from_slice(["b"]));
+        let edges = EdgeTableReference::new(
+            "edges",
+            Chunk::new(vec![source.clone(), target]),
+            edge_schema(DataType::Utf8),
+        )
+        .unwrap();
+        assert_eq!(edges.key_columns().unwrap().1.value(0), "b");
+
+        let int_target: Box<dyn Array> = Box::new(PrimitiveArray::<i64>::from_slice([1]));
+        assert!(matches!(
+            EdgeTableReference::new(
+                "edges",
+                Chunk::new(vec![source, int_target]),
+                edge_schema(DataType::Int64),
+            ),
+            Err(ArrowReferenceError::InvalidColumnType { .. })
+        ));
+    }
+
     #[test]
     fn test_empty_chunk_error() {
         let chunk = Chunk::<Box<dyn Array>>::new(vec![]);
@@ -38,11 +38,11 @@ impl AdjacencyList for RelationshipTopology {
         }
 
         match direction {
-            TraversalDirection::Outgoing => Ok(self.outgoing(node).map_or(0, <[_]>::len)),
+            TraversalDirection::Outgoing => Ok(self.outgoing_degree(node).unwrap_or(0)),
             TraversalDirection::Incoming if !self.is_inverse_indexed() => {
                 Err(NeighborCursorError::InverseIndexUnavailable)
             }
-            TraversalDirection::Incoming => Ok(self.incoming(node).map_or(0, <[_]>::len)),
+            TraversalDirection::Incoming => Ok(self.incoming_degree(node).unwrap_or(0)),
         }
     }
 
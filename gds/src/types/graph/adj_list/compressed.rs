@@ -0,0 +1,585 @@
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use super::AdjacencyCursor;
+use crate::types::graph::MappedNodeId;
+use crate::types::properties::mapped::{
+    AccessPattern, MappedFile, MappedFileError, MappedFileKind, MappedFileWriter, MappedSlice,
+};
+
+/// Compressed sparse row adjacency for one direction of a relationship-type partition.
+///
+/// Every row is stored as a block of ascending targets: the first target is written as-is and
+/// each following target as the gap to its predecessor, all as little-endian base-128 varints
+/// ("vlongs"). Rows of nearby node ids therefore cost one or two bytes per relationship instead
+/// of a full `MappedNodeId`, and no row owns a heap allocation.
+///
+/// The arrays live either on the heap or in a memory-mapped file written by
+/// [`CompressedAdjacency::write_to`]; cursors read both the same way.
+#[derive(Debug, Clone)]
+pub struct CompressedAdjacency {
+    storage: AdjacencyStorage,
+}
+
+/// Relationship offsets (row `i` holds relationships `offsets[i]..offsets[i + 1]`), byte
+/// offsets (row `i` is encoded in `blocks[block_offsets[i]..block_offsets[i + 1]]`) and blocks.
+#[derive(Debug, Clone)]
+enum AdjacencyStorage {
+    Heap {
+        offsets: Vec<usize>,
+        block_offsets: Vec<usize>,
+        blocks: Vec<u8>,
+    },
+    Mapped {
+        offsets: MappedSlice<u64>,
+        block_offsets: MappedSlice<u64>,
+        blocks: MappedSlice<u8>,
+    },
+}
+
+impl AdjacencyStorage {
+    fn offset(&self, index: usize) -> Option<usize> {
+        match self {
+            Self::Heap { offsets, .. } => offsets.get(index).copied(),
+            Self::Mapped { offsets, .. } => offsets.get(index).map(|&offset| offset as usize),
+        }
+    }
+
+    fn block_offset(&self, index: usize) -> Option<usize> {
+        match self {
+            Self::Heap { block_offsets, .. } => block_offsets.get(index).copied(),
+            Self::Mapped { block_offsets, .. } => {
+                block_offsets.get(index).map(|&offset| offset as usize)
+            }
+        }
+    }
+
+    fn row_count(&self) -> usize {
+        match self {
+            Self::Heap { offsets, .. } => offsets.len() - 1,
+            Self::Mapped { offsets, .. } => offsets.len() - 1,
+        }
+    }
+
+    fn blocks(&self) -> &[u8] {
+        match self {
+            Self::Heap { blocks, .. } => blocks,
+            Self::Mapped { blocks, .. } => blocks,
+        }
+    }
+}
+
+impl PartialEq for CompressedAdjacency {
+    fn eq(&self, other: &Self) -> bool {
+        let rows = self.node_count();
+        rows == other.node_count()
+            && (0..=rows).all(|index| {
+                self.storage.offset(index) == other.storage.offset(index)
+                    && self.storage.block_offset(index) == other.storage.block_offset(index)
+            })
+            && self.storage.blocks() == other.storage.blocks()
+    }
+}
+
+impl Eq for CompressedAdjacency {}
+
+impl CompressedAdjacency {
+    /// Encodes ascending rows. Returns the index of the first row that is not sorted.
+    ///
+    /// Rows are encoded one at a time, so passing owned rows frees each one as soon as it is
+    /// encoded instead of keeping every list alive next to the blocks.
+    pub fn try_from_sorted_rows<I>(rows: I) -> Result<Self, usize>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[MappedNodeId]>,
+    {
+        let rows = rows.into_iter();
+        let (row_count, _) = rows.size_hint();
+        let mut offsets = Vec::with_capacity(row_count + 1);
+        let mut block_offsets = Vec::with_capacity(row_count + 1);
+        let mut blocks = Vec::new();
+        offsets.push(0);
+        block_offsets.push(0);
+
+        for (index, row) in rows.enumerate() {
+            let row = row.as_ref();
+            let mut previous = 0;
+            for &target in row {
+                let value = target.get();
+                if value < previous {
+                    return Err(index);
+                }
+                encode_vlong(value - previous, &mut blocks);
+                previous = value;
+            }
+            offsets.push(offsets[index] + row.len());
+            block_offsets.push(blocks.len());
+        }
+        blocks.shrink_to_fit();
+
+        Ok(Self {
+            storage: AdjacencyStorage::Heap {
+                offsets,
+                block_offsets,
+                blocks,
+            },
+        })
+    }
+
+    /// Writes the adjacency to a file that [`CompressedAdjacency::open`] maps back.
+    pub fn write_to(&self, path: &Path) -> Result<(), MappedFileError> {
+        self.write_with_aux(path, 0)
+    }
+
+    pub(crate) fn write_with_aux(&self, path: &Path, aux: u64) -> Result<(), MappedFileError> {
+        let rows = self.node_count();
+        let mut writer = MappedFileWriter::create(path, MappedFileKind::Adjacency, rows, aux)?;
+        writer.section((0..=rows).map(|index| self.offset_at(index) as u64))?;
+        writer.section((0..=rows).map(|index| self.block_offset_at(index) as u64))?;
+        writer.section(self.storage.blocks().iter().copied())?;
+        writer.finish()
+    }
+
+    /// Maps an adjacency file; rows are decoded straight from the page cache.
+    pub fn open(path: &Path, access: AccessPattern) -> Result<Self, MappedFileError> {
+        Ok(Self::open_with_aux(path, access, false)?.0)
+    }
+
+    /// Maps an adjacency file and returns its aux header word. When `targets_are_rows` is set,
+    /// every target must also be a row, as in a topology, and files with other targets are
+    /// rejected.
+    pub(crate) fn open_with_aux(
+        path: &Path,
+        access: AccessPattern,
+        targets_are_rows: bool,
+    ) -> Result<(Self, u64), MappedFileError> {
+        let file = MappedFile::open_kind(path, MappedFileKind::Adjacency, access)?;
+        let rows = file.count();
+        let mut sections = file.sections();
+        let offsets: MappedSlice<u64> = sections.next(rows + 1)?;
+        let block_offsets: MappedSlice<u64> = sections.next(rows + 1)?;
+        let ascending = |values: &[u64]| values[0] == 0 && values.windows(2).all(|w| w[0] <= w[1]);
+        if !ascending(&offsets) || !ascending(&block_offsets) {
+            return Err(MappedFileError::Invalid(
+                "adjacency offsets are not ascending".into(),
+            ));
+        }
+        let blocks = sections.next(block_offsets[rows] as usize)?;
+        let adjacency = Self {
+            storage: AdjacencyStorage::Mapped {
+                offsets,
+                block_offsets,
+                blocks,
+            },
+        };
+        adjacency.validate_blocks(targets_are_rows.then_some(rows))?;
+        Ok((adjacency, file.aux()))
+    }
+
+    /// Checks that every row decodes to exactly its degree of targets within its own bytes,
+    /// and that every target is below `node_count` when given.
+    ///
+    /// Runs once per mapped file, so cursors and row decoding never see corrupt data.
+    fn validate_blocks(&self, node_count: Option<usize>) -> Result<(), MappedFileError> {
+        let blocks = self.storage.blocks();
+        for index in 0..self.node_count() {
+            let end = self.block_offset_at(index + 1);
+            let row = &blocks[..end];
+            let mut position = self.block_offset_at(index);
+            let mut previous = 0u64;
+            for _ in 0..self.degree(index) {
+                let (gap, next) = decode_vlong(row, position).map_err(|error| {
+                    MappedFileError::Invalid(format!("adjacency row {index}: {error}"))
+                })?;
+                previous = previous.checked_add(gap).ok_or_else(|| {
+                    MappedFileError::Invalid(format!("adjacency row {index} overflows node ids"))
+                })?;
+                if let Some(node_count) = node_count.filter(|&count| previous >= count as u64) {
+                    return Err(MappedFileError::Invalid(format!(
+                        "adjacency row {index} targets node {previous} of {node_count} nodes"
+                    )));
+                }
+                position = next;
+            }
+            if position != end {
+                return Err(MappedFileError::Invalid(format!(
+                    "adjacency row {index} encodes {} bytes past its targets",
+                    end - position
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the arrays are read from a mapped file.
+    pub fn is_mapped(&self) -> bool {
+        matches!(self.storage, AdjacencyStorage::Mapped { .. })
+    }
+
+    /// Applies an access hint to the mapped file; heap adjacencies ignore it.
+    pub fn advise(&self, access: AccessPattern) -> std::io::Result<()> {
+        match &self.storage {
+            AdjacencyStorage::Heap { .. } => Ok(()),
+            AdjacencyStorage::Mapped { blocks, .. } => blocks.advise(access),
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.storage.row_count()
+    }
+
+    pub fn relationship_count(&self) -> usize {
+        self.offset_at(self.node_count())
+    }
+
+    /// Number of targets in row `index`, or 0 for rows outside the node range.
+    pub fn degree(&self, index: usize) -> usize {
+        self.row_range(index).map_or(0, |range| range.len())
+    }
+
+    /// Relationship offsets covered by row `index`.
+    pub fn row_range(&self, index: usize) -> Option<std::ops::Range<usize>> {
+        Some(self.storage.offset(index)?..self.storage.offset(index.checked_add(1)?)?)
+    }
+
+    /// Decodes row `index` into a new vector.
+    pub fn decode_row(&self, index: usize) -> Vec<MappedNodeId> {
+        let mut row = Vec::with_capacity(self.degree(index));
+        self.decode_into(index, &mut row);
+        row
+    }
+
+    /// Appends the decoded targets of row `index` to `out`.
+    pub fn decode_into(&self, index: usize, out: &mut Vec<MappedNodeId>) {
+        let Some(range) = self.row_range(index) else {
+            return;
+        };
+        let blocks = self.storage.blocks();
+        let mut position = self.block_offset_at(index);
+        let mut previous = 0;
+        for _ in range {
+            let (gap, next) = decode_vlong(blocks, position).expect(VALIDATED_BLOCKS);
+            previous += gap;
+            position = next;
+            out.push(MappedNodeId::new(previous));
+        }
+    }
+
+    /// Returns a heap copy with empty rows appended up to `node_count` rows.
+    pub fn with_node_count(&self, node_count: usize) -> Self {
+        let rows = self.node_count();
+        let mut offsets: Vec<usize> = (0..=rows).map(|index| self.offset_at(index)).collect();
+        let mut block_offsets: Vec<usize> = (0..=rows)
+            .map(|index| self.block_offset_at(index))
+            .collect();
+        let relationship_count = self.relationship_count();
+        let block_end = self.storage.blocks().len();
+        for _ in rows..node_count {
+            offsets.push(relationship_count);
+            block_offsets.push(block_end);
+        }
+        Self {
+            storage: AdjacencyStorage::Heap {
+                offsets,
+                block_offsets,
+                blocks: self.storage.blocks().to_vec(),
+            },
+        }
+    }
+
+    /// Heap bytes held by this adjacency; mapped arrays live in the page cache instead.
+    pub fn size_in_bytes(&self) -> usize {
+        match &self.storage {
+            AdjacencyStorage::Heap {
+                offsets,
+                block_offsets,
+                blocks,
+            } => {
+                (offsets.capacity() + block_offsets.capacity()) * std::mem::size_of::<usize>()
+                    + blocks.capacity()
+            }
+            AdjacencyStorage::Mapped { .. } => 0,
+        }
+    }
+
+    pub fn cursor(self: &Arc<Self>, index: usize) -> CompressedAdjacencyCursor {
+        let mut cursor = CompressedAdjacencyCursor::new(Arc::clone(self));
+        cursor.init(index, self.degree(index));
+        cursor
+    }
+
+    fn offset_at(&self, index: usize) -> usize {
+        self.storage.offset(index).expect("row index in range")
+    }
+
+    fn block_offset_at(&self, index: usize) -> usize {
+        self.storage
+            .block_offset(index)
+            .expect("row index in range")
+    }
+}
+
+/// Decoding only fails on corrupt bytes, which heap encoding never produces and mapped files
+/// are checked for on open.
+const VALIDATED_BLOCKS: &str = "adjacency blocks are validated on open";
+
+/// Streaming decoder over one row of a [`CompressedAdjacency`].
+///
+/// The next target is decoded ahead of time so that `peek_vlong` does not need mutable access.
+#[derive(Debug, Clone)]
+pub struct CompressedAdjacencyCursor {
+    adjacency: Arc<CompressedAdjacency>,
+    position: usize,
+    size: usize,
+    remaining: usize,
+    next: Option<MappedNodeId>,
+}
+
+impl CompressedAdjacencyCursor {
+    pub fn new(adjacency: Arc<CompressedAdjacency>) -> Self {
+        Self {
+            adjacency,
+            position: 0,
+            size: 0,
+            remaining: 0,
+            next: None,
+        }
+    }
+
+    fn decode_next(&mut self, previous: u64) {
+        self.next = (self.remaining > 0).then(|| {
+            let (gap, position) = decode_vlong(self.adjacency.storage.blocks(), self.position)
+                .expect(VALIDATED_BLOCKS);
+            self.position = position;
+            MappedNodeId::new(previous + gap)
+        });
+    }
+}
+
+impl AdjacencyCursor for CompressedAdjacencyCursor {
+    /// The `degree` hint may shorten the row but never extends it past the encoded targets.
+    fn init(&mut self, index: usize, degree: usize) {
+        self.size = degree.min(self.adjacency.degree(index));
+        self.remaining = self.size;
+        self.position = self
+            .adjacency
+            .storage
+            .block_offset(index)
+            .unwrap_or_default();
+        self.decode_next(0);
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    fn next_vlong(&mut self) -> Option<MappedNodeId> {
+        let current = self.next?;
+        self.remaining -= 1;
+        self.decode_next(current.get());
+        Some(current)
+    }
+
+    fn peek_vlong(&self) -> Option<MappedNodeId> {
+        self.next
+    }
+
+    fn skip_until(&mut self, node_id: MappedNodeId) -> Option<MappedNodeId> {
+        while let Some(target) = self.next_vlong() {
+            if target > node_id {
+                return Some(target);
+            }
+        }
+        None
+    }
+
+    fn advance(&mut self, node_id: MappedNodeId) -> Option<MappedNodeId> {
+        while let Some(target) = self.next_vlong() {
+            if target >= node_id {
+                return Some(target);
+            }
+        }
+        None
+    }
+
+    fn advance_by(&mut self, n: usize) -> Option<MappedNodeId> {
+        for _ in 0..n {
+            self.next_vlong()?;
+        }
+        self.next_vlong()
+    }
+}
+
+/// Appends `value` as a little-endian base-128 varint.
+pub fn encode_vlong(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Decodes the varint starting at `position`, returning the value and the following position.
+///
+/// Fails with [`io::ErrorKind::InvalidData`] if the varint runs past the end of `bytes` or does
+/// not fit 64 bits.
+pub fn decode_vlong(bytes: &[u8], mut position: usize) -> io::Result<(u64, usize)> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let &byte = bytes.get(position).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "vlong runs past the end of the data",
+            )
+        })?;
+        position += 1;
+        let bits = u64::from(byte & 0x7f);
+        if shift > 63 || (shift == 63 && bits > 1) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "vlong does not fit 64 bits",
+            ));
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok((value, position));
+        }
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::graph::AdjacencyCursorExt;
+
+    fn ids(values: &[u64]) -> Vec<MappedNodeId> {
+        values.iter().copied().map(MappedNodeId::new).collect()
+    }
+
+    #[test]
+    fn vlongs_round_trip() {
+        let mut bytes = Vec::new();
+        for value in [0, 1, 127, 128, 300, u64::MAX] {
+            encode_vlong(value, &mut bytes);
+        }
+        let mut position = 0;
+        for expected in [0, 1, 127, 128, 300, u64::MAX] {
+            let (value, next) = decode_vlong(&bytes, position).unwrap();
+            assert_eq!(value, expected);
+            position = next;
+        }
+        assert_eq!(position, bytes.len());
+        assert_eq!(bytes.len(), 1 + 1 + 1 + 2 + 2 + 10);
+    }
+
+    #[test]
+    fn rows_decode_through_cursor() {
+        let rows = vec![ids(&[1, 2, 2, 1_000]), ids(&[]), ids(&[0, 3])];
+        let adjacency = Arc::new(CompressedAdjacency::try_from_sorted_rows(&rows).unwrap());
+        assert_eq!(adjacency.relationship_count(), 6);
+        assert_eq!(adjacency.row_range(2), Some(4..6));
+
+        let mut cursor = adjacency.cursor(0);
+        assert_eq!(cursor.size(), 4);
+        assert_eq!(cursor.peek_vlong(), Some(MappedNodeId::new(1)));
+        assert_eq!(
+            cursor.advance(MappedNodeId::new(2)),
+            Some(MappedNodeId::new(2))
+        );
+        assert_eq!(
+            cursor.skip_until(MappedNodeId::new(2)),
+            Some(MappedNodeId::new(1_000))
+        );
+        assert!(!cursor.has_next());
+
+        cursor.init(1, 0);
+        assert_eq!(cursor.next_vlong(), None);
+        cursor.init(2, usize::MAX);
+        assert_eq!(cursor.collect_remaining(), rows[2]);
+        assert_eq!(adjacency.decode_row(0), rows[0]);
+
+        let grown = adjacency.with_node_count(5);
+        assert_eq!(grown.node_count(), 5);
+        assert_eq!(grown.row_range(4), Some(6..6));
+        assert_eq!(grown.decode_row(2), rows[2]);
+    }
+
+    #[test]
+    fn mapped_rows_match_heap_rows() {
+        let rows = vec![ids(&[1, 2, 2, 1_000]), ids(&[]), ids(&[0, 3])];
+        let heap = CompressedAdjacency::try_from_sorted_rows(&rows).unwrap();
+        let path = std::env::temp_dir().join(format!("gds-adjacency-{}", uuid::Uuid::new_v4()));
+        heap.write_to(&path).unwrap();
+
+        let mapped = Arc::new(CompressedAdjacency::open(&path, AccessPattern::Sequential).unwrap());
+        assert!(mapped.is_mapped());
+        assert_eq!(*mapped, heap);
+        assert_eq!(mapped.size_in_bytes(), 0);
+        assert_eq!(mapped.cursor(2).collect_remaining(), rows[2]);
+        assert_eq!(mapped.with_node_count(4).decode_row(0), rows[0]);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn decoding_rejects_truncated_and_oversized_vlongs() {
+        assert!(decode_vlong(&[0x80], 0).is_err());
+        assert!(decode_vlong(&[], 0).is_err());
+        let mut oversized = vec![0xff; 9];
+        oversized.push(0x02);
+        assert!(decode_vlong(&oversized, 0).is_err());
+    }
+
+    #[test]
+    fn corrupt_mapped_files_are_rejected_on_open() {
+        let rows = vec![ids(&[1, 2, 2, 1_000]), ids(&[]), ids(&[0, 3])];
+        let path = std::env::temp_dir().join(format!("gds-adjacency-{}", uuid::Uuid::new_v4()));
+        CompressedAdjacency::try_from_sorted_rows(&rows)
+            .unwrap()
+            .write_to(&path)
+            .unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        // Each attempt maps and drops the file before it is rewritten.
+        let open = |bytes: &[u8]| {
+            std::fs::write(&path, bytes).unwrap();
+            CompressedAdjacency::open(&path, AccessPattern::Random).map(|_| ())
+        };
+        assert!(open(&bytes).is_ok());
+
+        // Header, two offset sections of four u64s, then 7 bytes of blocks padded to 8.
+        assert!(matches!(
+            open(&bytes[..bytes.len() - 8]),
+            Err(MappedFileError::Invalid(_))
+        ));
+        let mut unterminated = bytes.clone();
+        unterminated[64 + 2 * 32 + 6] |= 0x80;
+        assert!(matches!(
+            open(&unterminated),
+            Err(MappedFileError::Invalid(message)) if message.contains("row 2")
+        ));
+        let mut oversized_count = bytes.clone();
+        oversized_count[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(matches!(
+            open(&oversized_count),
+            Err(MappedFileError::Invalid(_))
+        ));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn rejects_unsorted_rows() {
+        assert_eq!(
+            CompressedAdjacency::try_from_sorted_rows(&[ids(&[1]), ids(&[2, 1])]),
+            Err(1)
+        );
+    }
+}
@@ -1,3 +1,7 @@
+mod adjacency_cursor;
 mod adjacency_list;
+mod compressed;
 
+pub use adjacency_cursor::{AdjacencyCursor, AdjacencyCursorExt};
 pub use adjacency_list::AdjacencyList;
+pub use compressed::{decode_vlong, encode_vlong, CompressedAdjacency, CompressedAdjacencyCursor};
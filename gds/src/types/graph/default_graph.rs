@@ -6,8 +6,9 @@ use crate::types::graph::characteristics::GraphCharacteristicsBuilder;
 use crate::types::graph::degrees::Degrees;
 use crate::types::graph::id_map::NodeLabelConsumer;
 use crate::types::graph::id_map::{
-    BatchNodeIterable, FilteredIdMap, IdMap, MappedNodeId, NodeConsumer, NodeIdBatch,
-    NodeIdIterator, NodeIterator, OriginalNodeId, PartialIdMap, RelationshipIndex, SimpleIdMap,
+    BatchNodeIterable, ExternalNodeKey, FilteredIdMap, IdMap, MappedNodeId, NodeConsumer,
+    NodeIdBatch, NodeIdIterator, NodeIterator, OriginalNodeId, PartialIdMap, RelationshipIndex,
+    SimpleIdMap, SimpleIdMapError,
 };
 use crate::types::graph::Neighbor;
 use crate::types::graph::NeighborCursor;
@@ -466,6 +467,14 @@ impl IdMap for DefaultGraph {
         self.id_map.to_original_node_id(mapped_node_id)
     }
 
+    fn external_node_key(&self, mapped_node_id: MappedNodeId) -> Option<ExternalNodeKey> {
+        self.id_map.external_node_key(mapped_node_id)
+    }
+
+    fn to_mapped_node_id_by_key(&self, key: &ExternalNodeKey) -> Option<MappedNodeId> {
+        self.id_map.to_mapped_node_id_by_key(key)
+    }
+
     fn to_root_node_id(&self, mapped_node_id: MappedNodeId) -> Option<MappedNodeId> {
         self.id_map.to_root_node_id(mapped_node_id)
     }
@@ -506,8 +515,12 @@ impl IdMap for DefaultGraph {
         Arc::make_mut(&mut self.id_map).add_node_label(node_label);
     }
 
-    fn add_node_id_to_label(&mut self, node_id: MappedNodeId, node_label: NodeLabel) {
-        Arc::make_mut(&mut self.id_map).add_node_id_to_label(node_id, node_label);
+    fn add_node_id_to_label(
+        &mut self,
+        node_id: MappedNodeId,
+        node_label: NodeLabel,
+    ) -> Result<(), SimpleIdMapError> {
+        Arc::make_mut(&mut self.id_map).add_node_id_to_label(node_id, node_label)
     }
 
     fn root_id_map(&self) -> &dyn IdMap {
@@ -555,8 +568,13 @@ impl Degrees for DefaultGraph {
         let mut unique = HashSet::new();
         for rel_type in &self.ordered_types {
             if let Some(topology) = self.topology_for(rel_type) {
-                if let Some(neighbors) = topology.outgoing(node_id) {
-                    unique.extend(neighbors.iter().copied());
+                // The cursor decodes compressed rows in place instead of allocating each row.
+                let mut cursor = Arc::clone(topology).new_cursor();
+                if cursor.reset(node_id, TraversalDirection::Outgoing).is_ok() {
+                    unique.extend(
+                        std::iter::from_fn(|| cursor.next_neighbor())
+                            .map(|neighbor| neighbor.target),
+                    );
                 }
             }
         }
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::OriginalNodeId;
+
+/// Identifier of a node in the source data.
+///
+/// Integer keys are used as original node IDs directly. String and composite keys are interned
+/// through an [`ExternalKeyIndex`], which assigns each of them a synthetic original node ID.
+/// Serialized untagged, so keys appear in results as a number, a string or a
+/// `{"label": .., "key": ..}` object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ExternalNodeKey {
+    Id(i64),
+    Text(String),
+    Composite { label: String, key: String },
+}
+
+impl ExternalNodeKey {
+    pub fn composite(label: impl Into<String>, key: impl Into<String>) -> Self {
+        Self::Composite {
+            label: label.into(),
+            key: key.into(),
+        }
+    }
+
+    /// The integer key, if this is not an interned key.
+    pub fn as_id(&self) -> Option<i64> {
+        match self {
+            Self::Id(id) => Some(*id),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ExternalNodeKey {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Id(id) => write!(formatter, "{id}"),
+            Self::Text(text) => formatter.write_str(text),
+            Self::Composite { label, key } => write!(formatter, "{label}:{key}"),
+        }
+    }
+}
+
+impl From<i64> for ExternalNodeKey {
+    fn from(value: i64) -> Self {
+        Self::Id(value)
+    }
+}
+
+impl From<OriginalNodeId> for ExternalNodeKey {
+    fn from(value: OriginalNodeId) -> Self {
+        Self::Id(value.get())
+    }
+}
+
+impl From<String> for ExternalNodeKey {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for ExternalNodeKey {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// Interning table between external keys and the synthetic original node IDs `0..len`.
+#[derive(Debug, Clone, Default)]
+pub struct ExternalKeyIndex {
+    keys: Vec<ExternalNodeKey>,
+    original_ids: HashMap<ExternalNodeKey, OriginalNodeId>,
+}
+
+impl ExternalKeyIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the original node ID of `key`, assigning the next free one to unseen keys.
+    /// The flag is `true` when the key was newly interned.
+    pub fn intern(&mut self, key: ExternalNodeKey) -> (OriginalNodeId, bool) {
+        if let Some(&original_id) = self.original_ids.get(&key) {
+            return (original_id, false);
+        }
+        let original_id = OriginalNodeId::new(self.keys.len() as i64);
+        self.keys.push(key.clone());
+        self.original_ids.insert(key, original_id);
+        (original_id, true)
+    }
+
+    pub fn original_id(&self, key: &ExternalNodeKey) -> Option<OriginalNodeId> {
+        self.original_ids.get(key).copied()
+    }
+
+    pub fn key(&self, original_id: OriginalNodeId) -> Option<&ExternalNodeKey> {
+        self.keys.get(usize::try_from(original_id.get()).ok()?)
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interning_is_stable() {
+        let mut index = ExternalKeyIndex::new();
+        let alice = ExternalNodeKey::from("alice@example.com");
+        let order = ExternalNodeKey::composite("Order", "A-17");
+
+        assert_eq!(index.intern(alice.clone()), (OriginalNodeId::new(0), true));
+        assert_eq!(index.intern(order.clone()), (OriginalNodeId::new(1), true));
+        assert_eq!(index.intern(alice.clone()), (OriginalNodeId::new(0), false));
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.original_id(&order), Some(OriginalNodeId::new(1)));
+        assert_eq!(index.key(OriginalNodeId::new(1)), Some(&order));
+        assert_eq!(index.key(OriginalNodeId::new(-1)), None);
+    }
+
+    #[test]
+    fn keys_serialize_untagged() {
+        let keys = vec![
+            ExternalNodeKey::Id(7),
+            ExternalNodeKey::from("a1b2"),
+            ExternalNodeKey::composite("Person", "42"),
+        ];
+        let json = serde_json::to_string(&keys).unwrap();
+        assert_eq!(json, r#"[7,"a1b2",{"label":"Person","key":"42"}]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<ExternalNodeKey>>(&json).unwrap(),
+            keys
+        );
+        assert_eq!(keys[2].to_string(), "Person:42");
+    }
+}
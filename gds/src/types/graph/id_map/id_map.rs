@@ -5,7 +5,8 @@ use crate::types::schema::NodeLabel;
 
 use super::{
     batch_node_iterable::BatchNodeIterable, filtered_id_map::FilteredIdMap,
-    node_iterator::NodeIterator, partial_id_map::PartialIdMap, MappedNodeId, OriginalNodeId,
+    node_iterator::NodeIterator, partial_id_map::PartialIdMap, ExternalNodeKey, MappedNodeId,
+    OriginalNodeId, SimpleIdMapError,
 };
 
 /// Lower bound of mapped identifiers.
@@ -45,6 +46,17 @@ pub trait IdMap: PartialIdMap + NodeIterator + BatchNodeIterable {
     /// Maps a compact mapped identifier back to the original identifier (usually the database ID).
     fn to_original_node_id(&self, mapped_node_id: MappedNodeId) -> Option<OriginalNodeId>;
 
+    /// Source-data key of a mapped node. Without interned keys this is the original identifier.
+    fn external_node_key(&self, mapped_node_id: MappedNodeId) -> Option<ExternalNodeKey> {
+        self.to_original_node_id(mapped_node_id)
+            .map(ExternalNodeKey::from)
+    }
+
+    /// Resolves a source-data key. Only integer keys resolve unless the mapping interns keys.
+    fn to_mapped_node_id_by_key(&self, key: &ExternalNodeKey) -> Option<MappedNodeId> {
+        self.to_mapped_node_id(OriginalNodeId::new(key.as_id()?))
+    }
+
     /// Maps a mapped identifier from a filtered mapping back into the root mapped space.
     fn to_root_node_id(&self, mapped_node_id: MappedNodeId) -> Option<MappedNodeId> {
         Some(mapped_node_id)
@@ -84,7 +96,13 @@ pub trait IdMap: PartialIdMap + NodeIterator + BatchNodeIterable {
     fn add_node_label(&mut self, node_label: NodeLabel);
 
     /// Associates the given node with the provided label.
-    fn add_node_id_to_label(&mut self, node_id: MappedNodeId, node_label: NodeLabel);
+    ///
+    /// Fails with [`SimpleIdMapError::NodeOutOfRange`] for ids at or beyond the node count.
+    fn add_node_id_to_label(
+        &mut self,
+        node_id: MappedNodeId,
+        node_label: NodeLabel,
+    ) -> Result<(), SimpleIdMapError>;
 
     /// Returns the root mapping for nested filtered mappings. For root mappings this should
     /// return `self`.
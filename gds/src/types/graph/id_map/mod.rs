@@ -1,14 +1,16 @@
 #![allow(clippy::module_inception)]
 
 mod batch_node_iterable;
+mod external_key;
 mod filtered_id_map;
-mod ids;
 mod id_map;
+mod ids;
 mod node_iterator;
 mod partial_id_map;
 mod simple;
 
 pub use batch_node_iterable::{BatchNodeIterable, NodeIdBatch, NodeIdBatchIter};
+pub use external_key::{ExternalKeyIndex, ExternalNodeKey};
 pub use filtered_id_map::FilteredIdMap;
 pub use id_map::{IdMap, NodeLabelConsumer, NO_TYPE, START_NODE_ID};
 pub use ids::{MappedNodeId, OriginalNodeId, RelationshipIndex};
@@ -1,7 +1,11 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{hash_map::Entry, HashMap, HashSet};
 use std::error::Error;
 use std::fmt;
+use std::sync::Arc;
 
+use rayon::prelude::*;
+
+use crate::collections::{BitSet, HugeSparseLongArray, HugeSparseLongArrayBuilder};
 use crate::types::schema::NodeLabel;
 
 use super::{
@@ -9,13 +13,24 @@ use super::{
     id_map::{IdMap, NodeLabelConsumer},
     node_iterator::{NodeIdIterator, NodeIterator},
     partial_id_map::PartialIdMap,
-    Concurrency, FilteredIdMap, MappedNodeId, OriginalNodeId,
+    Concurrency, ExternalKeyIndex, ExternalNodeKey, FilteredIdMap, MappedNodeId, OriginalNodeId,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Forward-index value of original ids that are not mapped.
+const NOT_FOUND: i64 = -1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SimpleIdMapError {
     DuplicateOriginalId(OriginalNodeId),
+    DuplicateExternalKey(ExternalNodeKey),
+    /// A string or composite key was given to a map of integer ids.
+    UnkeyedMap(ExternalNodeKey),
     NodeCountOverflow,
+    /// A mapped id at or beyond the node count.
+    NodeOutOfRange {
+        node_id: MappedNodeId,
+        node_count: usize,
+    },
 }
 
 impl fmt::Display for SimpleIdMapError {
@@ -24,23 +39,188 @@ impl fmt::Display for SimpleIdMapError {
             Self::DuplicateOriginalId(original_id) => {
                 write!(formatter, "duplicate original node ID {original_id}")
             }
+            Self::DuplicateExternalKey(key) => write!(formatter, "duplicate node key {key}"),
+            Self::UnkeyedMap(key) => {
+                write!(
+                    formatter,
+                    "node key {key} requires a map built from external keys"
+                )
+            }
             Self::NodeCountOverflow => formatter.write_str("node count exceeds mapped ID space"),
+            Self::NodeOutOfRange {
+                node_id,
+                node_count,
+            } => write!(
+                formatter,
+                "mapped node ID {node_id} is out of range for node count {node_count}"
+            ),
         }
     }
 }
 
 impl Error for SimpleIdMapError {}
 
-/// Simple in-memory [`IdMap`] implementation built on top of Rust `HashMap`s.
+/// Root in-memory [`IdMap`] implementation.
 ///
-/// This mirrors the minimal behaviour that existed in the original `id_map.rs` file and
-/// serves as a reference implementation until a more specialised store is introduced.
-#[derive(Debug, Clone, Default)]
+/// Original ids are looked up through a sparse paged array that only allocates the 32K-id pages
+/// in which enough ids occur; ids on sparsely used pages and negative ids go to a hash map
+/// instead, so scattered ids cost a map entry rather than a page each. Mapped ids index a plain
+/// vector of original ids. Label membership is one bitset per label, so label checks and counts
+/// cost a bit per node and label rather than a hash set per node.
+///
+/// Maps built with [`SimpleIdMap::try_from_external_keys`] carry an [`ExternalKeyIndex`]: their
+/// original ids are the interned key ids, and streaming and export report the keys instead.
+#[derive(Debug, Clone)]
 pub struct SimpleIdMap {
-    forward: HashMap<OriginalNodeId, MappedNodeId>,
+    forward: ForwardIndex,
     reverse: Vec<OriginalNodeId>,
-    labels_by_node: HashMap<MappedNodeId, HashSet<NodeLabel>>,
-    available_labels: HashSet<NodeLabel>,
+    /// Registered labels; labels without nodes have an empty set.
+    labels: HashMap<NodeLabel, BitSet>,
+    external_keys: Option<Arc<ExternalKeyIndex>>,
+}
+
+impl Default for SimpleIdMap {
+    fn default() -> Self {
+        Self {
+            forward: ForwardIndexBuilder::new().build(),
+            reverse: Vec::new(),
+            labels: HashMap::new(),
+            external_keys: None,
+        }
+    }
+}
+
+/// Ids a page needs before it moves from the hash map into a dense page.
+///
+/// A dense page costs 8 bytes per slot, a map entry several times that per id.
+const DENSE_PAGE_MIN_IDS: usize = HugeSparseLongArray::PAGE_SIZE / 8;
+
+/// Original-to-mapped id lookup.
+#[derive(Debug, Clone)]
+struct ForwardIndex {
+    dense: HugeSparseLongArray,
+    /// Negative ids, which cannot index the paged array, and ids on pages too sparse to allocate.
+    sparse: HashMap<OriginalNodeId, MappedNodeId>,
+    /// Number of non-negative `sparse` ids per page.
+    sparse_page_sizes: HashMap<usize, usize>,
+}
+
+impl ForwardIndex {
+    fn get(&self, original: OriginalNodeId) -> Option<MappedNodeId> {
+        if let Ok(index) = usize::try_from(original.get()) {
+            let mapped = self.dense.get(index);
+            if mapped != NOT_FOUND {
+                return Some(MappedNodeId::new(mapped as u64));
+            }
+        }
+        self.sparse.get(&original).copied()
+    }
+
+    fn into_builder(self) -> ForwardIndexBuilder {
+        ForwardIndexBuilder {
+            dense: self.dense.into_builder(),
+            sparse: self.sparse,
+            sparse_page_sizes: self.sparse_page_sizes,
+        }
+    }
+}
+
+/// Forward index under construction for one contiguous range of mapped ids.
+struct ForwardIndexBuilder {
+    dense: HugeSparseLongArrayBuilder,
+    sparse: HashMap<OriginalNodeId, MappedNodeId>,
+    sparse_page_sizes: HashMap<usize, usize>,
+}
+
+impl ForwardIndexBuilder {
+    fn new() -> Self {
+        Self {
+            dense: HugeSparseLongArray::builder(NOT_FOUND),
+            sparse: HashMap::new(),
+            sparse_page_sizes: HashMap::new(),
+        }
+    }
+
+    fn build(self) -> ForwardIndex {
+        ForwardIndex {
+            dense: self.dense.into_array(),
+            sparse: self.sparse,
+            sparse_page_sizes: self.sparse_page_sizes,
+        }
+    }
+
+    fn insert(
+        &mut self,
+        original: OriginalNodeId,
+        mapped: MappedNodeId,
+    ) -> Result<(), SimpleIdMapError> {
+        let index = usize::try_from(original.get()).ok();
+        let inserted = match index {
+            Some(index) if self.dense.has_page(index) => {
+                self.dense.set_if_absent(index, mapped.get() as i64)
+            }
+            _ => match self.sparse.entry(original) {
+                Entry::Occupied(_) => false,
+                Entry::Vacant(entry) => {
+                    entry.insert(mapped);
+                    true
+                }
+            },
+        };
+        if !inserted {
+            return Err(SimpleIdMapError::DuplicateOriginalId(original));
+        }
+        if let Some(index) = index.filter(|&index| !self.dense.has_page(index)) {
+            let page = index / HugeSparseLongArray::PAGE_SIZE;
+            let size = self.sparse_page_sizes.entry(page).or_insert(0);
+            *size += 1;
+            if *size >= DENSE_PAGE_MIN_IDS {
+                self.promote_page(page)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves the sparse ids of `page` into a dense page.
+    fn promote_page(&mut self, page: usize) -> Result<(), SimpleIdMapError> {
+        let mut remaining = self.sparse_page_sizes.remove(&page).unwrap_or(0);
+        let first = page * HugeSparseLongArray::PAGE_SIZE;
+        for index in first..first + HugeSparseLongArray::PAGE_SIZE {
+            if remaining == 0 {
+                break;
+            }
+            let original = OriginalNodeId::new(index as i64);
+            if let Some(mapped) = self.sparse.remove(&original) {
+                if !self.dense.set_if_absent(index, mapped.get() as i64) {
+                    return Err(SimpleIdMapError::DuplicateOriginalId(original));
+                }
+                remaining -= 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Merges the index of another range of mapped ids.
+    ///
+    /// Dense pages move across whole, or word by word where both ranges use a page; only the
+    /// ids of sparsely used pages are inserted one at a time.
+    fn merge(&mut self, other: Self) -> Result<(), SimpleIdMapError> {
+        let other_dense = other.dense.into_array();
+        let other_pages = other_dense.page_indices().collect::<Vec<_>>();
+        self.dense.merge_disjoint(other_dense).map_err(|index| {
+            SimpleIdMapError::DuplicateOriginalId(OriginalNodeId::new(index as i64))
+        })?;
+        // Sparse ids of this range whose page the other range made dense move into it.
+        for page in other_pages {
+            if self.sparse_page_sizes.contains_key(&page) {
+                self.promote_page(page)?;
+            }
+        }
+        for (original, mapped) in other.sparse {
+            self.insert(original, mapped)?;
+        }
+        Ok(())
+    }
 }
 
 impl SimpleIdMap {
@@ -53,23 +233,53 @@ impl SimpleIdMap {
         I: IntoIterator<Item = T>,
         T: Into<OriginalNodeId>,
     {
-        let mut forward = HashMap::new();
+        let mut forward = ForwardIndexBuilder::new();
         let mut reverse = Vec::new();
         for (index, original) in ids.into_iter().enumerate() {
             let original = original.into();
             let mapped =
                 MappedNodeId::try_from(index).map_err(|_| SimpleIdMapError::NodeCountOverflow)?;
-            if forward.insert(original, mapped).is_some() {
-                return Err(SimpleIdMapError::DuplicateOriginalId(original));
-            }
+            forward.insert(original, mapped)?;
             reverse.push(original);
         }
 
-        Ok(Self {
-            forward,
-            reverse,
-            ..Default::default()
-        })
+        Ok(Self::from_parts(forward, reverse))
+    }
+
+    /// Builds the forward index of `ids` in `concurrency` parallel ranges, then merges them.
+    pub fn try_from_original_ids_concurrent<T>(
+        ids: &[T],
+        concurrency: Concurrency,
+    ) -> Result<Self, SimpleIdMapError>
+    where
+        T: Into<OriginalNodeId> + Copy + Sync,
+    {
+        MappedNodeId::try_from(ids.len()).map_err(|_| SimpleIdMapError::NodeCountOverflow)?;
+        let chunk_size = ids.len().div_ceil(concurrency.value()).max(1);
+
+        let partial_indices = ids
+            .par_chunks(chunk_size)
+            .enumerate()
+            .map(|(chunk_index, chunk)| {
+                let mut forward = ForwardIndexBuilder::new();
+                let start = chunk_index * chunk_size;
+                for (offset, &original) in chunk.iter().enumerate() {
+                    forward.insert(original.into(), MappedNodeId::new((start + offset) as u64))?;
+                }
+                Ok(forward)
+            })
+            .collect::<Result<Vec<_>, SimpleIdMapError>>()?;
+
+        let mut partial_indices = partial_indices.into_iter();
+        let mut forward = partial_indices
+            .next()
+            .unwrap_or_else(ForwardIndexBuilder::new);
+        for partial in partial_indices {
+            forward.merge(partial)?;
+        }
+        let reverse = ids.par_iter().map(|&original| original.into()).collect();
+
+        Ok(Self::from_parts(forward, reverse))
     }
 
     pub fn from_original_ids<I, T>(ids: I) -> Self
@@ -80,15 +290,142 @@ impl SimpleIdMap {
         Self::try_from_original_ids(ids).expect("original node IDs must be unique")
     }
 
+    fn from_parts(forward: ForwardIndexBuilder, reverse: Vec<OriginalNodeId>) -> Self {
+        Self {
+            forward: forward.build(),
+            reverse,
+            labels: HashMap::new(),
+            external_keys: None,
+        }
+    }
+
+    /// Builds a map over string or composite source keys, interning them in key order.
+    pub fn try_from_external_keys<I, K>(keys: I) -> Result<Self, SimpleIdMapError>
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<ExternalNodeKey>,
+    {
+        let mut index = ExternalKeyIndex::new();
+        let mut original_ids = Vec::new();
+        for key in keys {
+            let key = key.into();
+            let (original_id, interned) = index.intern(key.clone());
+            if !interned {
+                return Err(SimpleIdMapError::DuplicateExternalKey(key));
+            }
+            original_ids.push(original_id);
+        }
+
+        Ok(Self::try_from_original_ids(original_ids)?.with_external_keys(Arc::new(index)))
+    }
+
+    /// Resolves original ids through `keys`, e.g. for a subgraph of a keyed map.
+    pub fn with_external_keys(mut self, keys: Arc<ExternalKeyIndex>) -> Self {
+        self.external_keys = Some(keys);
+        self
+    }
+
+    pub fn external_keys(&self) -> Option<&Arc<ExternalKeyIndex>> {
+        self.external_keys.as_ref()
+    }
+
+    /// Maps `ids` after the existing nodes and returns the first new mapped id.
+    ///
+    /// Nothing is changed if any id is already mapped or repeated within `ids`.
+    pub fn try_append_original_ids<I, T>(
+        &mut self,
+        ids: I,
+    ) -> Result<MappedNodeId, SimpleIdMapError>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OriginalNodeId>,
+    {
+        let ids = ids
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<OriginalNodeId>>();
+        let first = self.reverse.len();
+        MappedNodeId::try_from(first + ids.len())
+            .map_err(|_| SimpleIdMapError::NodeCountOverflow)?;
+        let mut seen = HashSet::with_capacity(ids.len());
+        if let Some(&duplicate) = ids
+            .iter()
+            .find(|&&original| self.to_mapped_node_id(original).is_some() || !seen.insert(original))
+        {
+            return Err(SimpleIdMapError::DuplicateOriginalId(duplicate));
+        }
+
+        let mut forward =
+            std::mem::replace(&mut self.forward, ForwardIndexBuilder::new().build()).into_builder();
+        for (offset, &original) in ids.iter().enumerate() {
+            forward
+                .insert(original, MappedNodeId::new((first + offset) as u64))
+                .expect("validated unique original ids");
+        }
+        self.forward = forward.build();
+        self.reverse.extend(ids);
+
+        Ok(MappedNodeId::new(first as u64))
+    }
+
+    /// Appends nodes by source key, interning string and composite keys in keyed maps.
+    ///
+    /// Maps without an [`ExternalKeyIndex`] only accept integer keys.
+    pub fn try_append_external_keys<I, K>(
+        &mut self,
+        keys: I,
+    ) -> Result<MappedNodeId, SimpleIdMapError>
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<ExternalNodeKey>,
+    {
+        let keys = keys
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<ExternalNodeKey>>();
+        let mut seen = HashSet::with_capacity(keys.len());
+        for key in &keys {
+            if self.to_mapped_node_id_by_key(key).is_some() || !seen.insert(key) {
+                return Err(SimpleIdMapError::DuplicateExternalKey(key.clone()));
+            }
+        }
+
+        if self.external_keys.is_none() {
+            let ids = keys
+                .into_iter()
+                .map(|key| match key.as_id() {
+                    Some(id) => Ok(OriginalNodeId::new(id)),
+                    None => Err(SimpleIdMapError::UnkeyedMap(key)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            return self.try_append_original_ids(ids);
+        }
+        MappedNodeId::try_from(self.reverse.len() + keys.len())
+            .map_err(|_| SimpleIdMapError::NodeCountOverflow)?;
+        // Keys of a shared index may already be interned by the map this one was derived from.
+        let index = Arc::make_mut(self.external_keys.as_mut().expect("keyed map"));
+        let original_ids = keys
+            .into_iter()
+            .map(|key| index.intern(key).0)
+            .collect::<Vec<_>>();
+        self.try_append_original_ids(original_ids)
+    }
+
     fn mapped_range(&self) -> impl ExactSizeIterator<Item = MappedNodeId> + '_ {
         (0..self.reverse.len())
             .map(|index| MappedNodeId::try_from(index).expect("validated SimpleIdMap node count"))
     }
+
+    fn label_index(&self, mapped_node_id: MappedNodeId) -> Option<usize> {
+        mapped_node_id
+            .to_usize()
+            .filter(|&index| index < self.reverse.len())
+    }
 }
 
 impl PartialIdMap for SimpleIdMap {
     fn to_mapped_node_id(&self, original_node_id: OriginalNodeId) -> Option<MappedNodeId> {
-        self.forward.get(&original_node_id).copied()
+        self.forward.get(original_node_id)
     }
 
     fn root_node_count(&self) -> Option<usize> {
@@ -114,12 +451,10 @@ impl NodeIterator for SimpleIdMap {
             return self.iter();
         }
 
-        Box::new(self.mapped_range().filter(move |node_id| {
-            self.labels_by_node
-                .get(node_id)
-                .map(|node_labels| labels.iter().any(|label| node_labels.contains(label)))
-                .unwrap_or(false)
-        }))
+        Box::new(
+            self.mapped_range()
+                .filter(move |&node_id| labels.iter().any(|label| self.has_label(node_id, label))),
+        )
     }
 }
 
@@ -157,6 +492,21 @@ impl IdMap for SimpleIdMap {
         self.reverse.get(mapped_node_id.to_usize()?).copied()
     }
 
+    fn external_node_key(&self, mapped_node_id: MappedNodeId) -> Option<ExternalNodeKey> {
+        let original_id = self.to_original_node_id(mapped_node_id)?;
+        match &self.external_keys {
+            Some(keys) => keys.key(original_id).cloned(),
+            None => Some(original_id.into()),
+        }
+    }
+
+    fn to_mapped_node_id_by_key(&self, key: &ExternalNodeKey) -> Option<MappedNodeId> {
+        match &self.external_keys {
+            Some(keys) => self.to_mapped_node_id(keys.original_id(key)?),
+            None => self.to_mapped_node_id(OriginalNodeId::new(key.as_id()?)),
+        }
+    }
+
     fn to_root_node_id(&self, mapped_node_id: MappedNodeId) -> Option<MappedNodeId> {
         Some(mapped_node_id)
     }
@@ -166,21 +516,22 @@ impl IdMap for SimpleIdMap {
     }
 
     fn node_count_for_label(&self, node_label: &NodeLabel) -> usize {
-        self.labels_by_node
-            .values()
-            .filter(|labels| labels.contains(node_label))
-            .count()
+        self.labels.get(node_label).map_or(0, BitSet::cardinality)
     }
 
     fn highest_original_id(&self) -> Option<OriginalNodeId> {
-        self.forward.keys().copied().max()
+        self.reverse.iter().copied().max()
     }
 
     fn node_labels(&self, mapped_node_id: MappedNodeId) -> HashSet<NodeLabel> {
-        self.labels_by_node
-            .get(&mapped_node_id)
-            .cloned()
-            .unwrap_or_default()
+        let Some(index) = self.label_index(mapped_node_id) else {
+            return HashSet::new();
+        };
+        self.labels
+            .iter()
+            .filter(|(_, nodes)| nodes.get(index))
+            .map(|(label, _)| label.clone())
+            .collect()
     }
 
     fn for_each_node_label(
@@ -188,36 +539,51 @@ impl IdMap for SimpleIdMap {
         mapped_node_id: MappedNodeId,
         consumer: &mut dyn NodeLabelConsumer,
     ) {
-        if let Some(labels) = self.labels_by_node.get(&mapped_node_id) {
-            for label in labels {
-                if !consumer.accept(label) {
-                    break;
-                }
+        let Some(index) = self.label_index(mapped_node_id) else {
+            return;
+        };
+        for (label, nodes) in &self.labels {
+            if nodes.get(index) && !consumer.accept(label) {
+                break;
             }
         }
     }
 
     fn available_node_labels(&self) -> HashSet<NodeLabel> {
-        self.available_labels.clone()
+        self.labels.keys().cloned().collect()
     }
 
     fn has_label(&self, mapped_node_id: MappedNodeId, label: &NodeLabel) -> bool {
-        self.labels_by_node
-            .get(&mapped_node_id)
-            .map(|labels| labels.contains(label))
-            .unwrap_or(false)
+        self.label_index(mapped_node_id)
+            .zip(self.labels.get(label))
+            .is_some_and(|(index, nodes)| nodes.get(index))
     }
 
     fn add_node_label(&mut self, node_label: NodeLabel) {
-        self.available_labels.insert(node_label);
+        let node_count = self.reverse.len();
+        self.labels
+            .entry(node_label)
+            .or_insert_with(|| BitSet::new(node_count));
     }
 
-    fn add_node_id_to_label(&mut self, node_id: MappedNodeId, node_label: NodeLabel) {
-        self.available_labels.insert(node_label.clone());
-        self.labels_by_node
-            .entry(node_id)
-            .or_default()
-            .insert(node_label);
+    fn add_node_id_to_label(
+        &mut self,
+        node_id: MappedNodeId,
+        node_label: NodeLabel,
+    ) -> Result<(), SimpleIdMapError> {
+        let node_count = self.reverse.len();
+        let index = node_id
+            .to_usize()
+            .filter(|&index| index < node_count)
+            .ok_or(SimpleIdMapError::NodeOutOfRange {
+                node_id,
+                node_count,
+            })?;
+        self.labels
+            .entry(node_label)
+            .or_insert_with(|| BitSet::new(node_count))
+            .set(index);
+        Ok(())
     }
 
     fn root_id_map(&self) -> &dyn IdMap {
@@ -290,11 +656,250 @@ mod tests {
         let mut map = SimpleIdMap::from_original_ids([1, 2]);
         let label = NodeLabel::of("Person");
         map.add_node_label(label.clone());
-        map.add_node_id_to_label(MappedNodeId::new(0), label.clone());
+        map.add_node_id_to_label(MappedNodeId::new(0), label.clone())
+            .unwrap();
         assert!(map.has_label(MappedNodeId::new(0), &label));
         assert_eq!(map.node_count_for_label(&label), 1);
 
         let labels = map.node_labels(MappedNodeId::new(0));
         assert!(labels.contains(&label));
     }
+
+    #[test]
+    fn sparse_and_negative_ids() {
+        let ids = [3_000_000_000_i64, -7, 42, i64::MAX];
+        let map = SimpleIdMap::from_original_ids(ids);
+        for (mapped, original) in ids.into_iter().enumerate() {
+            assert_eq!(
+                map.to_mapped_node_id(original.into()),
+                Some(MappedNodeId::new(mapped as u64))
+            );
+        }
+        assert_eq!(map.to_mapped_node_id(41.into()), None);
+        assert_eq!(map.to_mapped_node_id((-8).into()), None);
+        assert_eq!(map.highest_original_id(), Some(i64::MAX.into()));
+        assert!(matches!(
+            SimpleIdMap::try_from_original_ids([-1, -1]),
+            Err(SimpleIdMapError::DuplicateOriginalId(original_id))
+                if original_id == OriginalNodeId::new(-1)
+        ));
+    }
+
+    #[test]
+    fn scattered_ids_do_not_allocate_pages() {
+        let scattered: Vec<i64> = (0..1_000).map(|i| i << 20).collect();
+        let map = SimpleIdMap::from_original_ids(scattered.iter().copied());
+        assert_eq!(map.forward.dense.page_count(), 0);
+        for (mapped, &original) in scattered.iter().enumerate() {
+            assert_eq!(
+                map.to_mapped_node_id(original.into()),
+                Some(MappedNodeId::new(mapped as u64))
+            );
+        }
+        assert_eq!(map.to_mapped_node_id(((1 << 20) + 1).into()), None);
+
+        let mut map =
+            SimpleIdMap::try_from_original_ids_concurrent(&scattered, Concurrency::of(4)).unwrap();
+        assert_eq!(map.forward.dense.page_count(), 0);
+        // Filling one page past the density threshold moves its ids into a dense page.
+        map.try_append_original_ids(1..HugeSparseLongArray::PAGE_SIZE as i64)
+            .unwrap();
+        assert_eq!(map.forward.dense.page_count(), 1);
+        assert_eq!(map.forward.sparse.len(), scattered.len() - 1);
+        assert_eq!(map.to_mapped_node_id(0.into()), Some(MappedNodeId::new(0)));
+        assert_eq!(
+            map.to_mapped_node_id(1.into()),
+            Some(MappedNodeId::new(1_000))
+        );
+        assert_eq!(
+            map.to_mapped_node_id((1 << 20).into()),
+            Some(MappedNodeId::new(1))
+        );
+        assert!(matches!(
+            map.try_append_original_ids([5]),
+            Err(SimpleIdMapError::DuplicateOriginalId(original_id))
+                if original_id == OriginalNodeId::new(5)
+        ));
+    }
+
+    #[test]
+    fn concurrent_construction_matches_sequential() {
+        let ids: Vec<i64> = (0..10_000).map(|i| (i * 7_919) % 100_003 - 50).collect();
+        let sequential = SimpleIdMap::from_original_ids(ids.iter().copied());
+        let concurrent =
+            SimpleIdMap::try_from_original_ids_concurrent(&ids, Concurrency::of(4)).unwrap();
+        assert_eq!(concurrent.node_count(), ids.len());
+        for &original in &ids {
+            assert_eq!(
+                concurrent.to_mapped_node_id(original.into()),
+                sequential.to_mapped_node_id(original.into())
+            );
+        }
+        assert_eq!(
+            concurrent.to_original_node_id(MappedNodeId::new(9_999)),
+            Some(ids[9_999].into())
+        );
+
+        let mut duplicated = ids.clone();
+        duplicated.push(ids[17]);
+        assert!(matches!(
+            SimpleIdMap::try_from_original_ids_concurrent(&duplicated, Concurrency::of(4)),
+            Err(SimpleIdMapError::DuplicateOriginalId(original_id))
+                if original_id == OriginalNodeId::new(ids[17])
+        ));
+        let empty: [i64; 0] = [];
+        assert_eq!(
+            SimpleIdMap::try_from_original_ids_concurrent(&empty, Concurrency::of(4))
+                .unwrap()
+                .node_count(),
+            0
+        );
+    }
+
+    #[test]
+    fn concurrent_chunks_sharing_dense_pages_merge() {
+        // Interleaving dense ids across chunks makes every chunk fill the same pages.
+        let page_size = HugeSparseLongArray::PAGE_SIZE as i64;
+        let ids: Vec<i64> = (0..4 * page_size)
+            .map(|i| (i % 4) * page_size + i / 4)
+            .collect();
+        let concurrent =
+            SimpleIdMap::try_from_original_ids_concurrent(&ids, Concurrency::of(4)).unwrap();
+        assert_eq!(concurrent.forward.dense.page_count(), 4);
+        assert!(concurrent.forward.sparse.is_empty());
+        for (mapped, &original) in ids.iter().enumerate() {
+            assert_eq!(
+                concurrent.to_mapped_node_id(original.into()),
+                Some(MappedNodeId::new(mapped as u64))
+            );
+        }
+
+        let mut duplicated = ids.clone();
+        duplicated.push(ids[1]);
+        assert!(matches!(
+            SimpleIdMap::try_from_original_ids_concurrent(&duplicated, Concurrency::of(4)),
+            Err(SimpleIdMapError::DuplicateOriginalId(original_id))
+                if original_id == OriginalNodeId::new(ids[1])
+        ));
+    }
+
+    #[test]
+    fn label_bitsets() {
+        let mut map = SimpleIdMap::from_original_ids([1, 2, 3]);
+        let person = NodeLabel::of("Person");
+        let city = NodeLabel::of("City");
+        map.add_node_label(city.clone());
+        map.add_node_id_to_label(MappedNodeId::new(0), person.clone())
+            .unwrap();
+        map.add_node_id_to_label(MappedNodeId::new(2), person.clone())
+            .unwrap();
+
+        assert_eq!(map.node_count_for_label(&person), 2);
+        assert_eq!(map.node_count_for_label(&city), 0);
+        assert_eq!(
+            map.available_node_labels(),
+            HashSet::from([person.clone(), city])
+        );
+        assert!(!map.has_label(MappedNodeId::new(1), &person));
+        assert!(!map.has_label(MappedNodeId::new(9), &person));
+        assert!(matches!(
+            map.add_node_id_to_label(MappedNodeId::new(3), person.clone()),
+            Err(SimpleIdMapError::NodeOutOfRange { node_id, node_count: 3 })
+                if node_id == MappedNodeId::new(3)
+        ));
+        assert_eq!(map.node_count_for_label(&person), 2);
+
+        let filter = HashSet::from([person]);
+        let labelled: Vec<_> = map.iter_with_labels(&filter).collect();
+        assert_eq!(labelled, vec![MappedNodeId::new(0), MappedNodeId::new(2)]);
+    }
+
+    #[test]
+    fn external_keys_round_trip() {
+        let keys = [
+            ExternalNodeKey::from("alice@example.com"),
+            ExternalNodeKey::composite("Order", "A-17"),
+            ExternalNodeKey::from("bob@example.com"),
+        ];
+        let map = SimpleIdMap::try_from_external_keys(keys.clone()).unwrap();
+        for (mapped, key) in keys.iter().enumerate() {
+            let mapped = MappedNodeId::new(mapped as u64);
+            assert_eq!(map.to_mapped_node_id_by_key(key), Some(mapped));
+            assert_eq!(map.external_node_key(mapped).as_ref(), Some(key));
+        }
+        assert_eq!(map.to_mapped_node_id_by_key(&"carol".into()), None);
+        assert_eq!(map.to_mapped_node_id_by_key(&0.into()), None);
+
+        let subset = SimpleIdMap::from_original_ids([OriginalNodeId::new(2)])
+            .with_external_keys(Arc::clone(map.external_keys().unwrap()));
+        assert_eq!(
+            subset.external_node_key(MappedNodeId::new(0)),
+            Some(keys[2].clone())
+        );
+
+        assert_eq!(
+            SimpleIdMap::try_from_external_keys(["a", "b", "a"]).unwrap_err(),
+            SimpleIdMapError::DuplicateExternalKey("a".into())
+        );
+        let plain = SimpleIdMap::from_original_ids([10]);
+        assert_eq!(
+            plain.external_node_key(MappedNodeId::new(0)),
+            Some(ExternalNodeKey::Id(10))
+        );
+        assert_eq!(
+            plain.to_mapped_node_id_by_key(&10.into()),
+            Some(MappedNodeId::new(0))
+        );
+    }
+
+    #[test]
+    fn appends_extend_the_mapping() {
+        let mut map = SimpleIdMap::from_original_ids([10, -3]);
+        map.add_node_id_to_label(MappedNodeId::new(1), NodeLabel::of("A"))
+            .unwrap();
+        assert_eq!(
+            map.try_append_original_ids([70_000, -4]),
+            Ok(MappedNodeId::new(2))
+        );
+        assert_eq!(map.node_count(), 4);
+        assert_eq!(map.to_mapped_node_id(10.into()), Some(MappedNodeId::new(0)));
+        assert_eq!(
+            map.to_mapped_node_id(70_000.into()),
+            Some(MappedNodeId::new(2))
+        );
+        assert_eq!(
+            map.to_mapped_node_id((-4).into()),
+            Some(MappedNodeId::new(3))
+        );
+        map.add_node_id_to_label(MappedNodeId::new(3), NodeLabel::of("A"))
+            .unwrap();
+        assert_eq!(map.node_count_for_label(&NodeLabel::of("A")), 2);
+
+        assert_eq!(
+            map.try_append_original_ids([5, 10]),
+            Err(SimpleIdMapError::DuplicateOriginalId(10.into()))
+        );
+        assert_eq!(map.to_mapped_node_id(5.into()), None);
+        assert_eq!(
+            map.try_append_external_keys(["x"]),
+            Err(SimpleIdMapError::UnkeyedMap("x".into()))
+        );
+
+        let mut keyed = SimpleIdMap::try_from_external_keys(["a"]).unwrap();
+        let shared = Arc::clone(keyed.external_keys().unwrap());
+        assert_eq!(
+            keyed.try_append_external_keys(["b", "c"]),
+            Ok(MappedNodeId::new(1))
+        );
+        assert_eq!(
+            keyed.to_mapped_node_id_by_key(&"c".into()),
+            Some(MappedNodeId::new(2))
+        );
+        assert_eq!(shared.len(), 1);
+        assert_eq!(
+            keyed.try_append_external_keys(["d", "a"]),
+            Err(SimpleIdMapError::DuplicateExternalKey("a".into()))
+        );
+        assert_eq!(keyed.node_count(), 3);
+    }
 }
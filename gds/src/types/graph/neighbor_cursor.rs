@@ -2,6 +2,8 @@ use std::error::Error;
 use std::fmt;
 use std::sync::Arc;
 
+use crate::types::graph::AdjacencyCursor;
+use crate::types::graph::CompressedAdjacencyCursor;
 use crate::types::graph::MappedNodeId;
 use crate::types::graph::RelationshipIndex;
 use crate::types::graph::RelationshipTopology;
@@ -101,6 +103,10 @@ pub struct TopologyNeighborCursor {
     direction: TraversalDirection,
     position: usize,
     size: usize,
+    /// Row decoder, present while the current row is compressed.
+    decoder: Option<CompressedAdjacencyCursor>,
+    /// Copy of the current row when relationships were appended to it.
+    appended_row: Option<Vec<(MappedNodeId, RelationshipIndex)>>,
 }
 
 impl TopologyNeighborCursor {
@@ -111,13 +117,39 @@ impl TopologyNeighborCursor {
             direction: TraversalDirection::Outgoing,
             position: 0,
             size: 0,
+            decoder: None,
+            appended_row: None,
         }
     }
 
+    fn adjacent_at(&self, position: usize) -> Option<MappedNodeId> {
+        if let Some(decoder) = &self.decoder {
+            return decoder.peek_vlong();
+        }
+        let row = match self.direction {
+            TraversalDirection::Outgoing => self.topology.outgoing(self.node)?,
+            TraversalDirection::Incoming => self.topology.incoming(self.node)?,
+        };
+        row.get(position).copied()
+    }
+
     fn neighbor_at(&self, position: usize) -> Option<Neighbor> {
+        if let Some(row) = &self.appended_row {
+            let &(adjacent, relationship_index) = row.get(position)?;
+            let (source, target) = match self.direction {
+                TraversalDirection::Outgoing => (self.node, adjacent),
+                TraversalDirection::Incoming => (adjacent, self.node),
+            };
+            return Some(Neighbor {
+                relationship_index,
+                source,
+                target,
+            });
+        }
+        let adjacent = self.adjacent_at(position)?;
         match self.direction {
             TraversalDirection::Outgoing => {
-                let target = *self.topology.outgoing(self.node)?.get(position)?;
+                let target = adjacent;
                 let relationship_index = self.topology.relationship_index(self.node, position)?;
                 Some(Neighbor {
                     relationship_index,
@@ -126,7 +158,7 @@ impl TopologyNeighborCursor {
                 })
             }
             TraversalDirection::Incoming => {
-                let source = *self.topology.incoming(self.node)?.get(position)?;
+                let source = adjacent;
                 let relationship_index = *self
                     .topology
                     .incoming_relationship_indices(self.node)?
@@ -157,9 +189,16 @@ impl NeighborCursor for TopologyNeighborCursor {
         self.direction = direction;
         self.position = 0;
         self.size = match direction {
-            TraversalDirection::Outgoing => self.topology.outgoing(node).map_or(0, <[_]>::len),
-            TraversalDirection::Incoming => self.topology.incoming(node).map_or(0, <[_]>::len),
+            TraversalDirection::Outgoing => self.topology.outgoing_degree(node).unwrap_or(0),
+            TraversalDirection::Incoming => self.topology.incoming_degree(node).unwrap_or(0),
         };
+        self.appended_row = self.topology.appended_row(node, direction);
+        self.decoder = self
+            .topology
+            .compressed_adjacency(direction)
+            .zip(node.to_usize())
+            .filter(|_| self.appended_row.is_none())
+            .map(|(adjacency, index)| adjacency.cursor(index));
         Ok(())
     }
 
@@ -173,6 +212,9 @@ impl NeighborCursor for TopologyNeighborCursor {
 
     fn next_neighbor(&mut self) -> Option<Neighbor> {
         let neighbor = self.neighbor_at(self.position)?;
+        if let Some(decoder) = self.decoder.as_mut() {
+            decoder.next_vlong();
+        }
         self.position += 1;
         Some(neighbor)
     }
@@ -298,4 +340,27 @@ mod tests {
         assert_eq!(cursor.advance(MappedNodeId::new(4)), None);
         assert_eq!(cursor.next_neighbor(), None);
     }
+
+    #[test]
+    fn compressed_topology_yields_the_same_neighbors() {
+        let lists = topology();
+        let compressed = Arc::new(lists.compress().unwrap());
+        let mut expected = TopologyNeighborCursor::new(lists);
+        let mut actual = TopologyNeighborCursor::new(compressed);
+
+        for (node, direction) in [
+            (MappedNodeId::ZERO, TraversalDirection::Outgoing),
+            (MappedNodeId::new(1), TraversalDirection::Incoming),
+            (MappedNodeId::new(1), TraversalDirection::Outgoing),
+        ] {
+            expected.reset(node, direction).unwrap();
+            actual.reset(node, direction).unwrap();
+            assert_eq!(actual.size(), expected.size());
+            assert_eq!(actual.peek_neighbor(), expected.peek_neighbor());
+            assert_eq!(
+                std::iter::from_fn(|| actual.next_neighbor()).collect::<Vec<_>>(),
+                std::iter::from_fn(|| expected.next_neighbor()).collect::<Vec<_>>()
+            );
+        }
+    }
 }
@@ -1,12 +1,17 @@
-use std::collections::HashMap;
+use std::borrow::Cow;
 use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::fmt;
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::types::graph::id_map::MappedNodeId;
 use crate::types::graph::id_map::RelationshipIndex;
+use crate::types::graph::CompressedAdjacency;
 use crate::types::graph::TopologyNeighborCursor;
+use crate::types::graph::TraversalDirection;
+use crate::types::properties::mapped::{AccessPattern, MappedFileError};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RelationshipTopologyError {
@@ -24,6 +29,10 @@ pub enum RelationshipTopologyError {
         source: MappedNodeId,
         target: MappedNodeId,
     },
+    UnsortedAdjacency {
+        node: MappedNodeId,
+        direction: TraversalDirection,
+    },
 }
 
 impl fmt::Display for RelationshipTopologyError {
@@ -48,6 +57,10 @@ impl fmt::Display for RelationshipTopologyError {
                 formatter,
                 "inverse relationship {source} -> {target} has no matching outgoing relationship"
             ),
+            Self::UnsortedAdjacency { node, direction } => write!(
+                formatter,
+                "{direction:?} adjacency of node {node} must be sorted for compression"
+            ),
         }
     }
 }
@@ -55,17 +68,101 @@ impl fmt::Display for RelationshipTopologyError {
 impl Error for RelationshipTopologyError {}
 
 /// In-memory adjacency representation used by the default graph implementation.
+///
+/// Rows are kept either as one vector per node or, after [`RelationshipTopology::compress`],
+/// as delta + varint encoded CSR blocks. Both layouts expose the same canonical relationship
+/// indices, so property stores do not depend on the chosen layout.
+///
+/// Relationships added by [`RelationshipTopology::try_append`] are kept in delta rows next to
+/// the encoded ones until [`RelationshipTopology::merge_appended`] folds them in; reads see both.
 #[derive(Debug, Clone)]
 pub struct RelationshipTopology {
-    outgoing: Vec<Vec<MappedNodeId>>,
-    incoming: Option<Vec<Vec<MappedNodeId>>>,
-    incoming_relationship_indices: Option<Vec<Vec<RelationshipIndex>>>,
-    offsets: Vec<usize>,
+    layout: Arc<TopologyLayout>,
     relationship_count: usize,
     has_parallel_edges: bool,
+    /// Nodes past the encoded rows have no encoded relationships.
+    node_capacity: usize,
+    /// Delta rows of appended relationships, oldest first.
+    appended: Vec<Arc<AppendedRows>>,
+}
+
+#[derive(Debug, Clone)]
+enum TopologyLayout {
+    Lists {
+        outgoing: Vec<Vec<MappedNodeId>>,
+        incoming: Option<Vec<Vec<MappedNodeId>>>,
+        incoming_relationship_indices: Option<Vec<Vec<RelationshipIndex>>>,
+        offsets: Vec<usize>,
+    },
+    Compressed {
+        outgoing: Arc<CompressedAdjacency>,
+        incoming: Option<Arc<CompressedAdjacency>>,
+        /// Canonical indices of the incoming rows, addressed by incoming relationship offset.
+        incoming_relationship_indices: Option<Vec<RelationshipIndex>>,
+    },
+}
+
+impl TopologyLayout {
+    fn node_count(&self) -> usize {
+        match self {
+            Self::Lists { outgoing, .. } => outgoing.len(),
+            Self::Compressed { outgoing, .. } => outgoing.node_count(),
+        }
+    }
+}
+
+/// Appended relationships with their canonical indices, by node.
+#[derive(Debug, Clone, Default)]
+struct AppendedRows {
+    /// `(target, relationship index)` pairs by source, in append order.
+    outgoing: HashMap<usize, Vec<(MappedNodeId, RelationshipIndex)>>,
+    /// `(source, relationship index)` pairs by target; empty without an inverse index.
+    incoming: HashMap<usize, Vec<(MappedNodeId, RelationshipIndex)>>,
+    relationship_count: usize,
+}
+
+impl AppendedRows {
+    fn row(
+        &self,
+        index: usize,
+        direction: TraversalDirection,
+    ) -> Option<&[(MappedNodeId, RelationshipIndex)]> {
+        match direction {
+            TraversalDirection::Outgoing => self.outgoing.get(&index),
+            TraversalDirection::Incoming => self.incoming.get(&index),
+        }
+        .map(Vec::as_slice)
+    }
+
+    fn merged_with(&self, newer: &Self) -> Self {
+        let mut merged = self.clone();
+        for (rows, newer_rows) in [
+            (&mut merged.outgoing, &newer.outgoing),
+            (&mut merged.incoming, &newer.incoming),
+        ] {
+            for (&index, row) in newer_rows {
+                rows.entry(index).or_default().extend_from_slice(row);
+            }
+        }
+        merged.relationship_count += newer.relationship_count;
+        merged
+    }
 }
 
 impl RelationshipTopology {
+    fn encoded(
+        layout: TopologyLayout,
+        relationship_count: usize,
+        has_parallel_edges: bool,
+    ) -> Self {
+        Self {
+            node_capacity: layout.node_count(),
+            layout: Arc::new(layout),
+            relationship_count,
+            has_parallel_edges,
+            appended: Vec::new(),
+        }
+    }
     /// Creates a new topology from outgoing adjacency lists.
     pub fn try_new(
         outgoing: Vec<Vec<MappedNodeId>>,
@@ -153,21 +250,210 @@ impl RelationshipTopology {
             sorted.windows(2).any(|window| window[0] == window[1])
         });
 
-        Ok(Self {
-            outgoing,
-            incoming,
-            incoming_relationship_indices,
-            offsets,
+        Ok(Self::encoded(
+            TopologyLayout::Lists {
+                outgoing,
+                incoming,
+                incoming_relationship_indices,
+                offsets,
+            },
             relationship_count,
             has_parallel_edges,
-        })
+        ))
     }
 
     pub fn new(outgoing: Vec<Vec<MappedNodeId>>, incoming: Option<Vec<Vec<MappedNodeId>>>) -> Self {
         Self::try_new(outgoing, incoming).expect("relationship topology must be valid")
     }
 
-    /// Returns the number of relationships encoded in this topology.
+    /// Creates a compressed topology from rows sorted by target (and incoming rows by source).
+    ///
+    /// Rows are not reordered, since relationship indices follow row order; unsorted rows
+    /// fail with [`RelationshipTopologyError::UnsortedAdjacency`]. The rows are encoded
+    /// straight into CSR blocks and freed one by one, so peak memory stays close to the input
+    /// lists instead of building the list layout first.
+    pub fn try_new_compressed(
+        outgoing: Vec<Vec<MappedNodeId>>,
+        incoming: Option<Vec<Vec<MappedNodeId>>>,
+    ) -> Result<Self, RelationshipTopologyError> {
+        let node_count = outgoing.len();
+        let mut relationship_count = 0usize;
+        let mut has_parallel_edges = false;
+        for (source_index, targets) in outgoing.iter().enumerate() {
+            let source = MappedNodeId::try_from(source_index)
+                .map_err(|_| RelationshipTopologyError::RelationshipCountOverflow)?;
+            if let Some(&target) = targets
+                .iter()
+                .find(|target| target.to_usize().is_none_or(|index| index >= node_count))
+            {
+                return Err(RelationshipTopologyError::TargetOutOfRange {
+                    source,
+                    target,
+                    node_count,
+                });
+            }
+            // Sorted rows keep parallel edges adjacent; unsorted rows fail below.
+            has_parallel_edges |= targets.windows(2).any(|window| window[0] == window[1]);
+            relationship_count = relationship_count
+                .checked_add(targets.len())
+                .ok_or(RelationshipTopologyError::RelationshipCountOverflow)?;
+        }
+        RelationshipIndex::try_from(relationship_count.saturating_sub(1))
+            .map_err(|_| RelationshipTopologyError::RelationshipCountOverflow)?;
+
+        let incoming_relationship_indices = incoming
+            .as_deref()
+            .map(|incoming| inverse_relationship_indices(&outgoing, incoming))
+            .transpose()?;
+
+        Ok(Self::encoded(
+            TopologyLayout::Compressed {
+                outgoing: compress_rows(outgoing, TraversalDirection::Outgoing)?,
+                incoming: incoming
+                    .map(|rows| compress_rows(rows, TraversalDirection::Incoming))
+                    .transpose()?,
+                incoming_relationship_indices,
+            },
+            relationship_count,
+            has_parallel_edges,
+        ))
+    }
+
+    /// Creates a topology in the compressed or list layout, so rebuilds can keep the layout of
+    /// the topology they derive from. Compressed rows must be sorted, see
+    /// [`RelationshipTopology::try_new_compressed`].
+    pub fn try_with_layout(
+        compressed: bool,
+        outgoing: Vec<Vec<MappedNodeId>>,
+        incoming: Option<Vec<Vec<MappedNodeId>>>,
+    ) -> Result<Self, RelationshipTopologyError> {
+        if compressed {
+            Self::try_new_compressed(outgoing, incoming)
+        } else {
+            Self::try_new(outgoing, incoming)
+        }
+    }
+
+    /// Re-encodes this topology as compressed CSR blocks.
+    ///
+    /// Every row must already be sorted; relationship indices are preserved, and appended
+    /// relationships stay in their delta rows.
+    pub fn compress(&self) -> Result<Self, RelationshipTopologyError> {
+        let TopologyLayout::Lists {
+            outgoing,
+            incoming,
+            incoming_relationship_indices,
+            ..
+        } = self.layout.as_ref()
+        else {
+            return Ok(self.clone());
+        };
+
+        Ok(Self {
+            layout: Arc::new(TopologyLayout::Compressed {
+                outgoing: compress_rows(outgoing, TraversalDirection::Outgoing)?,
+                incoming: incoming
+                    .as_deref()
+                    .map(|rows| compress_rows(rows, TraversalDirection::Incoming))
+                    .transpose()?,
+                incoming_relationship_indices: incoming_relationship_indices
+                    .as_ref()
+                    .map(|rows| rows.concat()),
+            }),
+            relationship_count: self.relationship_count,
+            has_parallel_edges: self.has_parallel_edges,
+            node_capacity: self.node_capacity,
+            appended: self.appended.clone(),
+        })
+    }
+
+    /// Returns true when rows are stored as compressed CSR blocks.
+    pub fn is_compressed(&self) -> bool {
+        matches!(*self.layout, TopologyLayout::Compressed { .. })
+    }
+
+    /// Writes the outgoing rows to a mapped adjacency file, compressing them first if needed.
+    ///
+    /// Only the outgoing direction is persisted, so inverse-indexed topologies are rejected
+    /// rather than reopened without their index by [`RelationshipTopology::open_mapped`]. Rows
+    /// must be sorted by target: sorting them here would change relationship indices and detach
+    /// relationship properties written alongside, so unsorted rows are rejected with
+    /// [`MappedFileError::Invalid`]. Appended relationships must be merged first, see
+    /// [`RelationshipTopology::merge_appended`].
+    pub fn write_mapped(&self, path: &Path) -> Result<(), MappedFileError> {
+        if self.is_inverse_indexed() {
+            return Err(MappedFileError::Invalid(
+                "mapped topologies hold outgoing rows only; drop the inverse index first"
+                    .to_string(),
+            ));
+        }
+        if self.has_appended_relationships() {
+            return Err(MappedFileError::Invalid(
+                "appended relationships must be merged before writing a mapped topology"
+                    .to_string(),
+            ));
+        }
+        let aux = u64::from(self.has_parallel_edges);
+        match self.layout.as_ref() {
+            TopologyLayout::Compressed { outgoing, .. }
+                if outgoing.node_count() == self.node_capacity =>
+            {
+                outgoing.write_with_aux(path, aux)
+            }
+            TopologyLayout::Compressed { outgoing, .. } => outgoing
+                .with_node_count(self.node_capacity)
+                .write_with_aux(path, aux),
+            TopologyLayout::Lists { .. } => {
+                CompressedAdjacency::try_from_sorted_rows(self.outgoing_lists().as_ref())
+                    .map_err(|node| {
+                        MappedFileError::Invalid(format!(
+                    "outgoing adjacency of node {node} must be sorted to write a mapped topology"
+                ))
+                    })?
+                    .write_with_aux(path, aux)
+            }
+        }
+    }
+
+    /// Maps a file written by [`RelationshipTopology::write_mapped`].
+    ///
+    /// Files whose targets are not nodes of the file are rejected.
+    /// Rows are decoded from the page cache, so the topology can exceed available memory.
+    /// Scans over all nodes should pass [`AccessPattern::Sequential`], traversals that jump
+    /// between rows [`AccessPattern::Random`].
+    pub fn open_mapped(path: &Path, access: AccessPattern) -> Result<Self, MappedFileError> {
+        let (outgoing, aux) = CompressedAdjacency::open_with_aux(path, access, true)?;
+        let relationship_count = outgoing.relationship_count();
+        Ok(Self::encoded(
+            TopologyLayout::Compressed {
+                outgoing: Arc::new(outgoing),
+                incoming: None,
+                incoming_relationship_indices: None,
+            },
+            relationship_count,
+            aux != 0,
+        ))
+    }
+
+    /// Returns the encoded compressed rows for `direction` when this topology is compressed.
+    ///
+    /// The blocks hold neither appended relationships nor rows past their node count.
+    pub fn compressed_adjacency(
+        &self,
+        direction: TraversalDirection,
+    ) -> Option<&Arc<CompressedAdjacency>> {
+        match (self.layout.as_ref(), direction) {
+            (TopologyLayout::Compressed { outgoing, .. }, TraversalDirection::Outgoing) => {
+                Some(outgoing)
+            }
+            (TopologyLayout::Compressed { incoming, .. }, TraversalDirection::Incoming) => {
+                incoming.as_ref()
+            }
+            (TopologyLayout::Lists { .. }, _) => None,
+        }
+    }
+
+    /// Returns the number of relationships encoded in this topology, appended ones included.
     pub fn relationship_count(&self) -> usize {
         self.relationship_count
     }
@@ -178,10 +464,18 @@ impl RelationshipTopology {
     }
 
     /// Returns the outgoing adjacency for the given node, if available.
-    pub fn outgoing(&self, node: MappedNodeId) -> Option<&[MappedNodeId]> {
-        self.outgoing
-            .get(node.to_usize()?)
-            .map(|neighbors| neighbors.as_slice())
+    ///
+    /// List rows are borrowed, but on a compressed topology, or for a node with appended
+    /// relationships, every call allocates a new vector and decodes the whole row into it.
+    /// Traversals and other per-node hot paths should use
+    /// [`RelationshipTopology::neighbor_cursor`] or an adjacency cursor, which decode in place.
+    pub fn outgoing(&self, node: MappedNodeId) -> Option<Cow<'_, [MappedNodeId]>> {
+        self.row(node, TraversalDirection::Outgoing)
+    }
+
+    /// Returns the number of outgoing relationships of the given node.
+    pub fn outgoing_degree(&self, node: MappedNodeId) -> Option<usize> {
+        self.degree(node, TraversalDirection::Outgoing)
     }
 
     pub fn relationship_index(
@@ -189,53 +483,502 @@ impl RelationshipTopology {
         source: MappedNodeId,
         neighbor_offset: usize,
     ) -> Option<RelationshipIndex> {
-        let source_index = source.to_usize()?;
-        let row_start = *self.offsets.get(source_index)?;
-        let row_end = *self.offsets.get(source_index.checked_add(1)?)?;
-        let physical_index = row_start.checked_add(neighbor_offset)?;
-        (physical_index < row_end)
-            .then(|| RelationshipIndex::try_from(physical_index).ok())
-            .flatten()
+        let source_index = self.node_index(source)?;
+        if self.has_appended_row(source_index, TraversalDirection::Outgoing) {
+            return self
+                .relationships(source, TraversalDirection::Outgoing)?
+                .get(neighbor_offset)
+                .map(|&(_, relationship_index)| relationship_index);
+        }
+        self.encoded_relationship_index(source_index, neighbor_offset)
     }
 
-    /// Returns all outgoing adjacency lists.
-    pub fn outgoing_lists(&self) -> &[Vec<MappedNodeId>] {
-        &self.outgoing
+    /// Returns all outgoing adjacency lists, decoding them when compressed.
+    ///
+    /// Decoding materializes the full list layout, so this is meant for rebuilds, not reads.
+    pub fn outgoing_lists(&self) -> Cow<'_, [Vec<MappedNodeId>]> {
+        match self.layout.as_ref() {
+            TopologyLayout::Lists { outgoing, .. }
+                if self.appended.is_empty() && outgoing.len() == self.node_capacity =>
+            {
+                Cow::Borrowed(outgoing.as_slice())
+            }
+            _ => Cow::Owned(
+                (0..self.node_capacity)
+                    .map(|index| {
+                        self.outgoing(MappedNodeId::new(index as u64))
+                            .map(Cow::into_owned)
+                            .unwrap_or_default()
+                    })
+                    .collect(),
+            ),
+        }
     }
 
     /// Returns the incoming adjacency for the given node when an inverse index exists.
-    pub fn incoming(&self, node: MappedNodeId) -> Option<&[MappedNodeId]> {
-        self.incoming
-            .as_ref()
-            .and_then(|lists| lists.get(node.to_usize()?))
-            .map(|neighbors| neighbors.as_slice())
+    ///
+    /// Like [`RelationshipTopology::outgoing`], compressed rows and rows with appended
+    /// relationships are decoded into a new vector on every call.
+    pub fn incoming(&self, node: MappedNodeId) -> Option<Cow<'_, [MappedNodeId]>> {
+        self.row(node, TraversalDirection::Incoming)
+    }
+
+    /// Returns the number of incoming relationships when an inverse index exists.
+    pub fn incoming_degree(&self, node: MappedNodeId) -> Option<usize> {
+        self.degree(node, TraversalDirection::Incoming)
     }
 
     pub fn incoming_relationship_indices(
         &self,
         node: MappedNodeId,
-    ) -> Option<&[RelationshipIndex]> {
-        self.incoming_relationship_indices
-            .as_ref()
-            .and_then(|indices| indices.get(node.to_usize()?))
-            .map(Vec::as_slice)
+    ) -> Option<Cow<'_, [RelationshipIndex]>> {
+        let index = self.node_index(node)?;
+        if self.has_appended_row(index, TraversalDirection::Incoming) {
+            return self
+                .relationships(node, TraversalDirection::Incoming)
+                .map(|row| Cow::Owned(row.into_iter().map(|(_, index)| index).collect()));
+        }
+        self.encoded_incoming_relationship_indices(index)
+            .map(Cow::Borrowed)
     }
 
     /// Returns true when an inverse index is available.
     pub fn is_inverse_indexed(&self) -> bool {
-        self.incoming.is_some()
+        match self.layout.as_ref() {
+            TopologyLayout::Lists { incoming, .. } => incoming.is_some(),
+            TopologyLayout::Compressed { incoming, .. } => incoming.is_some(),
+        }
     }
 
     /// Returns the total number of nodes tracked by this topology.
     pub fn node_capacity(&self) -> usize {
-        self.outgoing.len()
+        self.node_capacity
+    }
+
+    /// Returns a copy whose node domain is grown to `node_capacity` with empty rows.
+    ///
+    /// The encoded rows are shared, not copied, and relationship indices are unchanged, so
+    /// property columns remain valid.
+    pub fn with_node_capacity(&self, node_capacity: usize) -> Self {
+        let mut grown = self.clone();
+        grown.node_capacity = node_capacity.max(self.node_capacity);
+        grown
     }
 
+    /// Returns a copy with `(source, target)` relationships appended.
+    ///
+    /// Appended relationships take the next canonical indices in order, from
+    /// [`RelationshipTopology::relationship_count`] on. They are kept in delta rows that reads
+    /// merge with the encoded ones, after the encoded relationships of a list row and in target
+    /// order in a compressed row, so an append costs the size of the batch instead of a
+    /// re-encode. The inverse index, when present, covers them too.
+    pub fn try_append(
+        &self,
+        relationships: &[(MappedNodeId, MappedNodeId)],
+    ) -> Result<Self, RelationshipTopologyError> {
+        let node_count = self.node_capacity;
+        let inverse_indexed = self.is_inverse_indexed();
+        let mut rows = AppendedRows {
+            relationship_count: relationships.len(),
+            ..AppendedRows::default()
+        };
+        let mut has_parallel_edges = self.has_parallel_edges;
+        let mut row_targets = HashMap::<usize, HashSet<MappedNodeId>>::new();
+        for (offset, &(source, target)) in relationships.iter().enumerate() {
+            let (Some(source_index), Some(target_index)) =
+                (self.node_index(source), self.node_index(target))
+            else {
+                return Err(RelationshipTopologyError::TargetOutOfRange {
+                    source,
+                    target,
+                    node_count,
+                });
+            };
+            let relationship_index = self
+                .relationship_count
+                .checked_add(offset)
+                .and_then(|index| RelationshipIndex::try_from(index).ok())
+                .ok_or(RelationshipTopologyError::RelationshipCountOverflow)?;
+            if !has_parallel_edges {
+                let targets = row_targets.entry(source_index).or_insert_with(|| {
+                    self.outgoing(source)
+                        .map(|row| row.iter().copied().collect())
+                        .unwrap_or_default()
+                });
+                has_parallel_edges = !targets.insert(target);
+            }
+            rows.outgoing
+                .entry(source_index)
+                .or_default()
+                .push((target, relationship_index));
+            if inverse_indexed {
+                rows.incoming
+                    .entry(target_index)
+                    .or_default()
+                    .push((source, relationship_index));
+            }
+        }
+
+        let mut appended = self.appended.clone();
+        if !relationships.is_empty() {
+            appended.push(Arc::new(rows));
+        }
+        // Folding a delta into the previous one while that is at most twice as large keeps the
+        // sizes geometric: reads consult few deltas, and each relationship is copied few times.
+        while let [.., older, newer] = appended.as_slice() {
+            if older.relationship_count > 2 * newer.relationship_count {
+                break;
+            }
+            let merged = older.merged_with(newer);
+            appended.truncate(appended.len() - 2);
+            appended.push(Arc::new(merged));
+        }
+
+        Ok(Self {
+            layout: Arc::clone(&self.layout),
+            relationship_count: self.relationship_count + relationships.len(),
+            has_parallel_edges,
+            node_capacity: self.node_capacity,
+            appended,
+        })
+    }
+
+    /// Returns true when relationships were appended since the rows were encoded.
+    pub fn has_appended_relationships(&self) -> bool {
+        !self.appended.is_empty()
+    }
+
+    /// Returns the number of appended relationships that are not merged into the rows yet.
+    pub fn appended_relationship_count(&self) -> usize {
+        self.appended
+            .iter()
+            .map(|rows| rows.relationship_count)
+            .sum()
+    }
+
+    /// Returns true once the appended relationships are as many as the encoded relationships
+    /// and the nodes, so that [`RelationshipTopology::merge_appended`] costs no more than the
+    /// appends that led up to it.
+    pub fn should_merge_appended(&self) -> bool {
+        let appended = self.appended_relationship_count();
+        appended > 0 && appended >= (self.relationship_count - appended).max(self.node_capacity)
+    }
+
+    /// Re-encodes the topology with its appended relationships folded into the rows, keeping
+    /// the layout, the inverse index and the order in which every row is read.
+    ///
+    /// Relationship indices follow row positions again. The returned vector holds, for every
+    /// new index, the index the relationship had before, so property columns can follow.
+    pub fn merge_appended(
+        &self,
+    ) -> Result<(Self, Vec<RelationshipIndex>), RelationshipTopologyError> {
+        let mut outgoing = Vec::with_capacity(self.node_capacity);
+        let mut old_indices = Vec::with_capacity(self.relationship_count);
+        for index in 0..self.node_capacity {
+            let source = MappedNodeId::try_from(index)
+                .map_err(|_| RelationshipTopologyError::RelationshipCountOverflow)?;
+            let row = self
+                .relationships(source, TraversalDirection::Outgoing)
+                .unwrap_or_default();
+            old_indices.extend(
+                row.iter()
+                    .map(|&(_, relationship_index)| relationship_index),
+            );
+            outgoing.push(row.into_iter().map(|(target, _)| target).collect());
+        }
+        let incoming = self.is_inverse_indexed().then(|| {
+            (0..self.node_capacity)
+                .map(|index| {
+                    self.incoming(MappedNodeId::new(index as u64))
+                        .map(Cow::into_owned)
+                        .unwrap_or_default()
+                })
+                .collect()
+        });
+        let merged = Self::try_with_layout(self.is_compressed(), outgoing, incoming)?;
+        Ok((merged, old_indices))
+    }
+
+    /// Returns the row of `node` as `(adjacent node, relationship index)` pairs, in read order.
+    ///
+    /// The row is copied, so this is meant for rebuilds, not reads.
+    pub(crate) fn relationships(
+        &self,
+        node: MappedNodeId,
+        direction: TraversalDirection,
+    ) -> Option<Vec<(MappedNodeId, RelationshipIndex)>> {
+        let index = self.node_index(node)?;
+        let adjacent = self.encoded_row(index, direction)?;
+        let mut row = match direction {
+            TraversalDirection::Outgoing => adjacent
+                .iter()
+                .enumerate()
+                .map(|(offset, &target)| {
+                    let relationship_index = self
+                        .encoded_relationship_index(index, offset)
+                        .expect("offset lies within the encoded row");
+                    (target, relationship_index)
+                })
+                .collect::<Vec<_>>(),
+            TraversalDirection::Incoming => adjacent
+                .iter()
+                .copied()
+                .zip(
+                    self.encoded_incoming_relationship_indices(index)?
+                        .iter()
+                        .copied(),
+                )
+                .collect(),
+        };
+        let encoded_len = row.len();
+        for rows in &self.appended {
+            if let Some(appended) = rows.row(index, direction) {
+                row.extend_from_slice(appended);
+            }
+        }
+        // Compressed rows are read in adjacent order; the stable sort keeps encoded parallel
+        // relationships before appended ones, as in list rows.
+        if self.is_compressed() && row.len() > encoded_len {
+            row.sort_by_key(|&(adjacent, _)| adjacent);
+        }
+        Some(row)
+    }
+
+    /// Returns the row of `node` when relationships were appended to it, see
+    /// [`RelationshipTopology::relationships`]; rows without appends are read in place.
+    pub(crate) fn appended_row(
+        &self,
+        node: MappedNodeId,
+        direction: TraversalDirection,
+    ) -> Option<Vec<(MappedNodeId, RelationshipIndex)>> {
+        let index = self.node_index(node)?;
+        if self.has_appended_row(index, direction) {
+            self.relationships(node, direction)
+        } else {
+            None
+        }
+    }
+
+    fn node_index(&self, node: MappedNodeId) -> Option<usize> {
+        node.to_usize().filter(|&index| index < self.node_capacity)
+    }
+
+    fn has_appended_row(&self, index: usize, direction: TraversalDirection) -> bool {
+        self.appended
+            .iter()
+            .any(|rows| rows.row(index, direction).is_some())
+    }
+
+    fn row(
+        &self,
+        node: MappedNodeId,
+        direction: TraversalDirection,
+    ) -> Option<Cow<'_, [MappedNodeId]>> {
+        let index = self.node_index(node)?;
+        if self.has_appended_row(index, direction) {
+            return self
+                .relationships(node, direction)
+                .map(|row| Cow::Owned(row.into_iter().map(|(adjacent, _)| adjacent).collect()));
+        }
+        self.encoded_row(index, direction)
+    }
+
+    fn degree(&self, node: MappedNodeId, direction: TraversalDirection) -> Option<usize> {
+        let index = self.node_index(node)?;
+        let encoded = match (self.layout.as_ref(), direction) {
+            (TopologyLayout::Lists { outgoing, .. }, TraversalDirection::Outgoing) => {
+                outgoing.get(index).map_or(0, Vec::len)
+            }
+            (TopologyLayout::Lists { incoming, .. }, TraversalDirection::Incoming) => {
+                incoming.as_ref()?.get(index).map_or(0, Vec::len)
+            }
+            (TopologyLayout::Compressed { outgoing, .. }, TraversalDirection::Outgoing) => {
+                outgoing.degree(index)
+            }
+            (TopologyLayout::Compressed { incoming, .. }, TraversalDirection::Incoming) => {
+                incoming.as_ref()?.degree(index)
+            }
+        };
+        let appended = self
+            .appended
+            .iter()
+            .filter_map(|rows| rows.row(index, direction))
+            .map(<[_]>::len)
+            .sum::<usize>();
+        Some(encoded + appended)
+    }
+
+    /// Encoded row of `index`, empty past the encoded rows; `None` for incoming rows without an
+    /// inverse index.
+    fn encoded_row(
+        &self,
+        index: usize,
+        direction: TraversalDirection,
+    ) -> Option<Cow<'_, [MappedNodeId]>> {
+        let rows = match (self.layout.as_ref(), direction) {
+            (TopologyLayout::Lists { outgoing, .. }, TraversalDirection::Outgoing) => {
+                return Some(Cow::Borrowed(
+                    outgoing.get(index).map_or(&[], Vec::as_slice),
+                ));
+            }
+            (TopologyLayout::Lists { incoming, .. }, TraversalDirection::Incoming) => {
+                return Some(Cow::Borrowed(
+                    incoming.as_ref()?.get(index).map_or(&[], Vec::as_slice),
+                ));
+            }
+            (TopologyLayout::Compressed { outgoing, .. }, TraversalDirection::Outgoing) => outgoing,
+            (TopologyLayout::Compressed { incoming, .. }, TraversalDirection::Incoming) => {
+                incoming.as_ref()?
+            }
+        };
+        Some(Cow::Owned(rows.decode_row(index)))
+    }
+
+    fn encoded_relationship_index(
+        &self,
+        source_index: usize,
+        neighbor_offset: usize,
+    ) -> Option<RelationshipIndex> {
+        let (row_start, row_end) = match self.layout.as_ref() {
+            TopologyLayout::Lists { offsets, .. } => (
+                *offsets.get(source_index)?,
+                *offsets.get(source_index.checked_add(1)?)?,
+            ),
+            TopologyLayout::Compressed { outgoing, .. } => {
+                let range = outgoing.row_range(source_index)?;
+                (range.start, range.end)
+            }
+        };
+        let physical_index = row_start.checked_add(neighbor_offset)?;
+        (physical_index < row_end)
+            .then(|| RelationshipIndex::try_from(physical_index).ok())
+            .flatten()
+    }
+
+    /// Canonical indices of the encoded incoming row of `index`, empty past the encoded rows.
+    fn encoded_incoming_relationship_indices(&self, index: usize) -> Option<&[RelationshipIndex]> {
+        match self.layout.as_ref() {
+            TopologyLayout::Lists {
+                incoming_relationship_indices,
+                ..
+            } => Some(
+                incoming_relationship_indices
+                    .as_ref()?
+                    .get(index)
+                    .map_or(&[], Vec::as_slice),
+            ),
+            TopologyLayout::Compressed {
+                incoming,
+                incoming_relationship_indices,
+                ..
+            } => {
+                let indices = incoming_relationship_indices.as_ref()?;
+                Some(
+                    incoming
+                        .as_ref()?
+                        .row_range(index)
+                        .and_then(|range| indices.get(range))
+                        .unwrap_or(&[]),
+                )
+            }
+        }
+    }
     pub fn neighbor_cursor(self: &Arc<Self>) -> TopologyNeighborCursor {
         TopologyNeighborCursor::new(Arc::clone(self))
     }
 }
 
+fn compress_rows<I>(
+    rows: I,
+    direction: TraversalDirection,
+) -> Result<Arc<CompressedAdjacency>, RelationshipTopologyError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[MappedNodeId]>,
+{
+    CompressedAdjacency::try_from_sorted_rows(rows)
+        .map(Arc::new)
+        .map_err(|index| RelationshipTopologyError::UnsortedAdjacency {
+            node: MappedNodeId::try_from(index).unwrap_or(MappedNodeId::ZERO),
+            direction,
+        })
+}
+
+/// Canonical relationship indices of the incoming rows, addressed by incoming relationship
+/// offset.
+///
+/// Sources are visited in ascending order, so with incoming rows sorted by source the k-th
+/// relationship reaching a target fills the k-th slot of its incoming row; one cursor per
+/// target replaces a lookup per relationship.
+fn inverse_relationship_indices(
+    outgoing: &[Vec<MappedNodeId>],
+    incoming: &[Vec<MappedNodeId>],
+) -> Result<Vec<RelationshipIndex>, RelationshipTopologyError> {
+    if incoming.len() != outgoing.len() {
+        return Err(RelationshipTopologyError::InverseNodeCountMismatch {
+            outgoing: outgoing.len(),
+            incoming: incoming.len(),
+        });
+    }
+    if let Some(target_index) = incoming
+        .iter()
+        .position(|sources| sources.windows(2).any(|window| window[0] > window[1]))
+    {
+        return Err(RelationshipTopologyError::UnsortedAdjacency {
+            node: MappedNodeId::try_from(target_index).unwrap_or(MappedNodeId::ZERO),
+            direction: TraversalDirection::Incoming,
+        });
+    }
+
+    let mut row_starts = Vec::with_capacity(incoming.len() + 1);
+    row_starts.push(0usize);
+    for sources in incoming {
+        let row_start = *row_starts.last().expect("offset zero is present");
+        row_starts.push(
+            row_start
+                .checked_add(sources.len())
+                .ok_or(RelationshipTopologyError::RelationshipCountOverflow)?,
+        );
+    }
+    let mut next_slot = row_starts[..incoming.len()].to_vec();
+    let mut indices = vec![RelationshipIndex::ZERO; row_starts[incoming.len()]];
+
+    let mut physical_index = 0usize;
+    for (source_index, targets) in outgoing.iter().enumerate() {
+        let source = MappedNodeId::try_from(source_index)
+            .map_err(|_| RelationshipTopologyError::RelationshipCountOverflow)?;
+        for &target in targets {
+            let target_index = target
+                .to_usize()
+                .expect("targets are validated against the node count");
+            let slot = next_slot[target_index];
+            if slot == row_starts[target_index + 1]
+                || incoming[target_index][slot - row_starts[target_index]] != source
+            {
+                return Err(RelationshipTopologyError::InverseRelationshipMismatch {
+                    source,
+                    target,
+                });
+            }
+            indices[slot] = RelationshipIndex::try_from(physical_index)
+                .map_err(|_| RelationshipTopologyError::RelationshipCountOverflow)?;
+            next_slot[target_index] += 1;
+            physical_index += 1;
+        }
+    }
+
+    if let Some(target_index) =
+        (0..incoming.len()).find(|&index| next_slot[index] != row_starts[index + 1])
+    {
+        return Err(RelationshipTopologyError::InverseRelationshipMismatch {
+            source: incoming[target_index][next_slot[target_index] - row_starts[target_index]],
+            target: MappedNodeId::try_from(target_index)
+                .map_err(|_| RelationshipTopologyError::RelationshipCountOverflow)?,
+        });
+    }
+    Ok(indices)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -282,8 +1025,188 @@ mod tests {
         .unwrap();
 
         assert_eq!(
-            topology.incoming_relationship_indices(one),
+            topology.incoming_relationship_indices(one).as_deref(),
             Some([RelationshipIndex::new(0), RelationshipIndex::new(1)].as_slice())
         );
     }
+
+    #[test]
+    fn compressed_layout_preserves_rows_and_relationship_indices() {
+        let zero = MappedNodeId::ZERO;
+        let one = MappedNodeId::new(1);
+        let two = MappedNodeId::new(2);
+        let lists = RelationshipTopology::try_new(
+            vec![vec![one, two], vec![two], vec![]],
+            Some(vec![vec![], vec![zero], vec![zero, one]]),
+        )
+        .unwrap();
+        let compressed = lists.compress().unwrap();
+
+        assert!(compressed.is_compressed());
+        assert_eq!(compressed.relationship_count(), 3);
+        assert_eq!(compressed.outgoing(zero).unwrap().as_ref(), &[one, two]);
+        assert_eq!(compressed.outgoing_degree(one), Some(1));
+        assert_eq!(compressed.incoming(two).unwrap().as_ref(), &[zero, one]);
+        assert_eq!(
+            compressed.outgoing_lists().as_ref(),
+            lists.outgoing_lists().as_ref()
+        );
+        assert_eq!(
+            compressed.relationship_index(one, 0),
+            lists.relationship_index(one, 0)
+        );
+        assert_eq!(
+            compressed.incoming_relationship_indices(two),
+            lists.incoming_relationship_indices(two)
+        );
+    }
+
+    #[test]
+    fn direct_compression_matches_compressed_lists() {
+        let zero = MappedNodeId::ZERO;
+        let one = MappedNodeId::new(1);
+        let two = MappedNodeId::new(2);
+        let outgoing = vec![vec![one, one, two], vec![two], vec![zero]];
+        let incoming = vec![vec![two], vec![zero, zero], vec![zero, one]];
+        let lists =
+            RelationshipTopology::try_new(outgoing.clone(), Some(incoming.clone())).unwrap();
+        let direct =
+            RelationshipTopology::try_new_compressed(outgoing.clone(), Some(incoming)).unwrap();
+
+        assert!(direct.is_compressed());
+        assert!(direct.has_parallel_edges());
+        assert_eq!(direct.relationship_count(), lists.relationship_count());
+        assert_eq!(
+            direct.outgoing_lists().as_ref(),
+            lists.outgoing_lists().as_ref()
+        );
+        for node in [zero, one, two] {
+            assert_eq!(
+                direct.incoming_relationship_indices(node),
+                lists.incoming_relationship_indices(node)
+            );
+        }
+
+        assert!(matches!(
+            RelationshipTopology::try_new_compressed(
+                outgoing.clone(),
+                Some(vec![vec![two], vec![zero], vec![zero, one]]),
+            ),
+            Err(RelationshipTopologyError::InverseRelationshipMismatch { source, target })
+                if source == zero && target == one
+        ));
+        assert!(matches!(
+            RelationshipTopology::try_new_compressed(
+                outgoing,
+                Some(vec![vec![two], vec![zero, zero, one], vec![zero, one]]),
+            ),
+            Err(RelationshipTopologyError::InverseRelationshipMismatch { source, target })
+                if source == one && target == one
+        ));
+    }
+
+    #[test]
+    fn compression_requires_sorted_rows() {
+        assert!(matches!(
+            RelationshipTopology::try_new_compressed(
+                vec![vec![MappedNodeId::new(1), MappedNodeId::ZERO], vec![]],
+                None,
+            ),
+            Err(RelationshipTopologyError::UnsortedAdjacency { .. })
+        ));
+    }
+
+    #[test]
+    fn growing_node_capacity_keeps_relationship_indices() {
+        let zero = MappedNodeId::ZERO;
+        let one = MappedNodeId::new(1);
+        let lists = RelationshipTopology::new(
+            vec![vec![one], vec![zero]],
+            Some(vec![vec![one], vec![zero]]),
+        );
+        for topology in [lists.clone(), lists.compress().unwrap()] {
+            let grown = topology.with_node_capacity(4);
+            assert_eq!(grown.node_capacity(), 4);
+            assert_eq!(grown.relationship_count(), 2);
+            assert_eq!(
+                grown.relationship_index(one, 0),
+                topology.relationship_index(one, 0)
+            );
+            assert_eq!(grown.outgoing_degree(MappedNodeId::new(3)), Some(0));
+            assert_eq!(grown.incoming_degree(MappedNodeId::new(3)), Some(0));
+            assert_eq!(
+                grown.incoming_relationship_indices(zero),
+                topology.incoming_relationship_indices(zero)
+            );
+        }
+    }
+
+    #[test]
+    fn mapped_topology_reads_outgoing_rows_from_disk() {
+        let one = MappedNodeId::new(1);
+        let two = MappedNodeId::new(2);
+        let lists = RelationshipTopology::new(vec![vec![one, one, two], vec![two], vec![]], None);
+        let path = std::env::temp_dir().join(format!("gds-topology-{}", uuid::Uuid::new_v4()));
+        lists.write_mapped(&path).unwrap();
+
+        let mapped = RelationshipTopology::open_mapped(&path, AccessPattern::Random).unwrap();
+        assert!(mapped.is_compressed());
+        assert!(mapped.has_parallel_edges());
+        assert!(!mapped.is_inverse_indexed());
+        assert_eq!(mapped.relationship_count(), 4);
+        assert_eq!(mapped.node_capacity(), 3);
+        assert_eq!(
+            mapped.outgoing_lists().as_ref(),
+            lists.outgoing_lists().as_ref()
+        );
+        assert_eq!(
+            mapped.relationship_index(one, 0),
+            lists.relationship_index(one, 0)
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn write_mapped_rejects_unsorted_rows() {
+        let lists = RelationshipTopology::new(
+            vec![
+                vec![MappedNodeId::new(1)],
+                vec![MappedNodeId::new(1), MappedNodeId::ZERO],
+            ],
+            None,
+        );
+        let path = std::env::temp_dir().join(format!("gds-topology-{}", uuid::Uuid::new_v4()));
+        assert!(matches!(
+            lists.write_mapped(&path),
+            Err(MappedFileError::Invalid(message)) if message.contains("node 1 must be sorted")
+        ));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn mapped_topologies_reject_inverse_indices_and_foreign_targets() {
+        let path = std::env::temp_dir().join(format!("gds-topology-{}", uuid::Uuid::new_v4()));
+        let inverse = RelationshipTopology::new(
+            vec![vec![MappedNodeId::new(1)], vec![]],
+            Some(vec![vec![], vec![MappedNodeId::ZERO]]),
+        );
+        assert!(matches!(
+            inverse.write_mapped(&path),
+            Err(MappedFileError::Invalid(message)) if message.contains("outgoing rows only")
+        ));
+        assert!(!path.exists());
+
+        // A bare adjacency may target ids past its rows; a topology may not.
+        CompressedAdjacency::try_from_sorted_rows(&[vec![MappedNodeId::new(5)], vec![]])
+            .unwrap()
+            .write_to(&path)
+            .unwrap();
+        assert!(CompressedAdjacency::open(&path, AccessPattern::Random).is_ok());
+        assert!(matches!(
+            RelationshipTopology::open_mapped(&path, AccessPattern::Random),
+            Err(MappedFileError::Invalid(message)) if message.contains("targets node 5")
+        ));
+        std::fs::remove_file(path).unwrap();
+    }
 }
@@ -0,0 +1,786 @@
+//! Append batches and the change log of a growing graph store.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::projection::{NodeLabel, RelationshipType};
+use crate::types::graph::id_map::{ExternalNodeKey, MappedNodeId, RelationshipIndex};
+use crate::types::properties::node::NodePropertyValues;
+use crate::types::properties::property_values::{
+    checked_double_to_long_property, checked_long_to_double_property,
+};
+use crate::types::properties::relationship::RelationshipPropertyValues;
+use crate::types::properties::{PropertyValues, PropertyValuesError, PropertyValuesResult};
+use crate::types::ValueType;
+
+/// One property column of an append batch, holding a value per batch element.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppendedPropertyValues {
+    Long(Vec<i64>),
+    Double(Vec<f64>),
+}
+
+impl AppendedPropertyValues {
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Long(values) => values.len(),
+            Self::Double(values) => values.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            Self::Long(_) => ValueType::Long,
+            Self::Double(_) => ValueType::Double,
+        }
+    }
+
+    pub(crate) fn long_value(&self, index: usize) -> Option<i64> {
+        match self {
+            Self::Long(values) => values.get(index).copied(),
+            Self::Double(_) => None,
+        }
+    }
+
+    pub(crate) fn double_value(&self, index: usize) -> Option<f64> {
+        match self {
+            Self::Long(_) => None,
+            Self::Double(values) => values.get(index).copied(),
+        }
+    }
+
+    fn concat(&self, newer: &Self) -> Self {
+        match (self, newer) {
+            (Self::Long(values), Self::Long(newer)) => Self::Long([&values[..], newer].concat()),
+            (Self::Double(values), Self::Double(newer)) => {
+                Self::Double([&values[..], newer].concat())
+            }
+            _ => panic!("appended batches of one column share its value type"),
+        }
+    }
+}
+
+/// Values appended to a property column, batch by batch.
+///
+/// A batch is folded into the previous one while that is at most twice as large, so lookups
+/// search few batches and every value is copied few times.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AppendedColumn {
+    /// Batches with the offset of their first value, oldest first.
+    batches: Vec<(usize, Arc<AppendedPropertyValues>)>,
+    len: usize,
+}
+
+impl AppendedColumn {
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn with_values(&self, values: AppendedPropertyValues) -> Self {
+        let mut batches = self.batches.clone();
+        let len = self.len + values.len();
+        if !values.is_empty() {
+            batches.push((self.len, Arc::new(values)));
+        }
+        while let [.., (start, older), (_, newer)] = batches.as_slice() {
+            if older.len() > 2 * newer.len() {
+                break;
+            }
+            let merged = (*start, Arc::new(older.concat(newer)));
+            batches.truncate(batches.len() - 2);
+            batches.push(merged);
+        }
+        Self { batches, len }
+    }
+
+    /// Grows the column by `count` elements without values.
+    pub(crate) fn with_absent(&self, count: usize) -> Self {
+        Self {
+            batches: self.batches.clone(),
+            len: self.len + count,
+        }
+    }
+
+    fn locate(&self, offset: usize) -> Option<(&AppendedPropertyValues, usize)> {
+        let batch = self
+            .batches
+            .partition_point(|(start, _)| *start <= offset)
+            .checked_sub(1)?;
+        let (start, values) = &self.batches[batch];
+        Some((values, offset - start))
+    }
+
+    fn long_value(&self, offset: usize) -> Option<i64> {
+        let (values, index) = self.locate(offset)?;
+        values.long_value(index)
+    }
+
+    fn double_value(&self, offset: usize) -> Option<f64> {
+        let (values, index) = self.locate(offset)?;
+        values.double_value(index)
+    }
+
+    fn max_long_value(&self) -> Option<i64> {
+        self.batches
+            .iter()
+            .filter_map(|(_, values)| match values.as_ref() {
+                AppendedPropertyValues::Long(values) => values.iter().copied().max(),
+                AppendedPropertyValues::Double(_) => None,
+            })
+            .max()
+    }
+
+    fn max_double_value(&self) -> Option<f64> {
+        self.batches
+            .iter()
+            .filter_map(|(_, values)| match values.as_ref() {
+                AppendedPropertyValues::Long(_) => None,
+                AppendedPropertyValues::Double(values) => values.iter().copied().reduce(f64::max),
+            })
+            .reduce(f64::max)
+    }
+}
+
+/// A node property column grown by appends: the existing column, shared rather than copied,
+/// followed by the values of the appended nodes.
+///
+/// Long and double columns hold a value for every appended node; array columns hold none.
+#[derive(Debug, Clone)]
+pub(crate) struct AppendedNodePropertyValues {
+    base: Arc<dyn NodePropertyValues>,
+    base_count: usize,
+    appended: AppendedColumn,
+}
+
+impl AppendedNodePropertyValues {
+    pub(crate) fn new(base: Arc<dyn NodePropertyValues>, base_count: usize) -> Self {
+        Self {
+            base,
+            base_count,
+            appended: AppendedColumn::default(),
+        }
+    }
+
+    /// Returns the column grown from `values`, reusing the shared part when `values` was grown
+    /// before.
+    pub(crate) fn grow(values: &Arc<dyn NodePropertyValues>, node_count: usize) -> Self {
+        values
+            .as_any()
+            .and_then(|values| values.downcast_ref::<Self>())
+            .cloned()
+            .unwrap_or_else(|| Self::new(Arc::clone(values), node_count))
+    }
+
+    pub(crate) fn with_appended(mut self, appended: AppendedColumn) -> Self {
+        self.appended = appended;
+        self
+    }
+
+    pub(crate) fn appended(&self) -> &AppendedColumn {
+        &self.appended
+    }
+
+    pub(crate) fn base_count(&self) -> usize {
+        self.base_count
+    }
+
+    fn appended_offset(&self, node_id: u64) -> Option<usize> {
+        usize::try_from(node_id).ok()?.checked_sub(self.base_count)
+    }
+
+    fn missing(&self, node_id: u64, offset: usize) -> PropertyValuesError {
+        if offset < self.appended.len() {
+            PropertyValuesError::ValueNotFound(node_id)
+        } else {
+            PropertyValuesError::InvalidNodeId(node_id)
+        }
+    }
+}
+
+impl PropertyValues for AppendedNodePropertyValues {
+    fn value_type(&self) -> ValueType {
+        self.base.value_type()
+    }
+
+    fn element_count(&self) -> usize {
+        self.base_count + self.appended.len()
+    }
+}
+
+impl NodePropertyValues for AppendedNodePropertyValues {
+    fn double_value(&self, node_id: u64) -> PropertyValuesResult<f64> {
+        let Some(offset) = self.appended_offset(node_id) else {
+            return self.base.double_value(node_id);
+        };
+        match self.value_type() {
+            ValueType::Double => self
+                .appended
+                .double_value(offset)
+                .ok_or_else(|| self.missing(node_id, offset)),
+            ValueType::Long => checked_long_to_double_property(
+                self.appended
+                    .long_value(offset)
+                    .ok_or_else(|| self.missing(node_id, offset))?,
+            ),
+            value_type => Err(PropertyValuesError::unsupported_type(
+                value_type,
+                ValueType::Double,
+            )),
+        }
+    }
+
+    fn long_value(&self, node_id: u64) -> PropertyValuesResult<i64> {
+        let Some(offset) = self.appended_offset(node_id) else {
+            return self.base.long_value(node_id);
+        };
+        match self.value_type() {
+            ValueType::Long => self
+                .appended
+                .long_value(offset)
+                .ok_or_else(|| self.missing(node_id, offset)),
+            ValueType::Double => checked_double_to_long_property(
+                self.appended
+                    .double_value(offset)
+                    .ok_or_else(|| self.missing(node_id, offset))?,
+            ),
+            value_type => Err(PropertyValuesError::unsupported_type(
+                value_type,
+                ValueType::Long,
+            )),
+        }
+    }
+
+    fn double_array_value(&self, node_id: u64) -> PropertyValuesResult<Vec<f64>> {
+        match self.appended_offset(node_id) {
+            None => self.base.double_array_value(node_id),
+            Some(offset) => Err(self.missing(node_id, offset)),
+        }
+    }
+
+    fn float_array_value(&self, node_id: u64) -> PropertyValuesResult<Vec<f32>> {
+        match self.appended_offset(node_id) {
+            None => self.base.float_array_value(node_id),
+            Some(offset) => Err(self.missing(node_id, offset)),
+        }
+    }
+
+    fn long_array_value(&self, node_id: u64) -> PropertyValuesResult<Vec<i64>> {
+        match self.appended_offset(node_id) {
+            None => self.base.long_array_value(node_id),
+            Some(offset) => Err(self.missing(node_id, offset)),
+        }
+    }
+
+    fn get_object(&self, node_id: u64) -> PropertyValuesResult<Box<dyn std::any::Any>> {
+        let Some(offset) = self.appended_offset(node_id) else {
+            return self.base.get_object(node_id);
+        };
+        match self.value_type() {
+            ValueType::Long => Ok(Box::new(self.long_value(node_id)?)),
+            ValueType::Double => Ok(Box::new(self.double_value(node_id)?)),
+            _ => Err(self.missing(node_id, offset)),
+        }
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.base.dimension()
+    }
+
+    fn get_max_long_property_value(&self) -> Option<i64> {
+        self.base
+            .get_max_long_property_value()
+            .into_iter()
+            .chain(self.appended.max_long_value())
+            .max()
+    }
+
+    fn get_max_double_property_value(&self) -> Option<f64> {
+        self.base
+            .get_max_double_property_value()
+            .into_iter()
+            .chain(self.appended.max_double_value())
+            .reduce(f64::max)
+    }
+
+    fn has_value(&self, node_id: u64) -> bool {
+        match self.appended_offset(node_id) {
+            None => self.base.has_value(node_id),
+            Some(offset) => {
+                offset < self.appended.len()
+                    && matches!(self.value_type(), ValueType::Long | ValueType::Double)
+            }
+        }
+    }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+/// A relationship property column grown by appends: the existing column, shared rather than
+/// copied, followed by the values of the appended relationships in index order.
+#[derive(Debug, Clone)]
+pub(crate) struct AppendedRelationshipPropertyValues {
+    base: Arc<dyn RelationshipPropertyValues>,
+    base_count: usize,
+    appended: AppendedColumn,
+}
+
+impl AppendedRelationshipPropertyValues {
+    /// Returns the column grown from `values`, reusing the shared part when `values` was grown
+    /// before.
+    pub(crate) fn grow(
+        values: &Arc<dyn RelationshipPropertyValues>,
+        relationship_count: usize,
+    ) -> Self {
+        values
+            .as_any()
+            .and_then(|values| values.downcast_ref::<Self>())
+            .cloned()
+            .unwrap_or_else(|| Self {
+                base: Arc::clone(values),
+                base_count: relationship_count,
+                appended: AppendedColumn::default(),
+            })
+    }
+
+    pub(crate) fn with_values(mut self, values: AppendedPropertyValues) -> Self {
+        self.appended = self.appended.with_values(values);
+        self
+    }
+
+    fn appended_offset(&self, rel_index: RelationshipIndex) -> Option<usize> {
+        rel_index.to_usize()?.checked_sub(self.base_count)
+    }
+}
+
+impl PropertyValues for AppendedRelationshipPropertyValues {
+    fn value_type(&self) -> ValueType {
+        self.base.value_type()
+    }
+
+    fn element_count(&self) -> usize {
+        self.base_count + self.appended.len()
+    }
+}
+
+impl RelationshipPropertyValues for AppendedRelationshipPropertyValues {
+    fn double_value(&self, rel_index: RelationshipIndex) -> PropertyValuesResult<f64> {
+        let Some(offset) = self.appended_offset(rel_index) else {
+            return self.base.double_value(rel_index);
+        };
+        let missing = || PropertyValuesError::ValueNotFound(rel_index.get());
+        match self.value_type() {
+            ValueType::Long => checked_long_to_double_property(
+                self.appended.long_value(offset).ok_or_else(missing)?,
+            ),
+            _ => self.appended.double_value(offset).ok_or_else(missing),
+        }
+    }
+
+    fn long_value(&self, rel_index: RelationshipIndex) -> PropertyValuesResult<i64> {
+        let Some(offset) = self.appended_offset(rel_index) else {
+            return self.base.long_value(rel_index);
+        };
+        let missing = || PropertyValuesError::ValueNotFound(rel_index.get());
+        match self.value_type() {
+            ValueType::Double => checked_double_to_long_property(
+                self.appended.double_value(offset).ok_or_else(missing)?,
+            ),
+            _ => self.appended.long_value(offset).ok_or_else(missing),
+        }
+    }
+
+    fn get_object(
+        &self,
+        rel_index: RelationshipIndex,
+    ) -> PropertyValuesResult<Box<dyn std::any::Any>> {
+        if self.appended_offset(rel_index).is_none() {
+            return self.base.get_object(rel_index);
+        }
+        match self.value_type() {
+            ValueType::Long => Ok(Box::new(self.long_value(rel_index)?)),
+            _ => Ok(Box::new(self.double_value(rel_index)?)),
+        }
+    }
+
+    fn default_value(&self) -> f64 {
+        self.base.default_value()
+    }
+
+    fn has_value(&self, rel_index: RelationshipIndex) -> bool {
+        match self.appended_offset(rel_index) {
+            None => self.base.has_value(rel_index),
+            Some(offset) => offset < self.appended.len(),
+        }
+    }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+/// New nodes with their labels and property values.
+///
+/// Keys follow the store's id map: integer keys for maps of original ids, any
+/// [`ExternalNodeKey`] for maps built from external keys.
+#[derive(Debug, Clone, Default)]
+pub struct NodeBatch {
+    keys: Vec<ExternalNodeKey>,
+    labels: Vec<Vec<NodeLabel>>,
+    properties: HashMap<String, AppendedPropertyValues>,
+}
+
+impl NodeBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_node(
+        mut self,
+        key: impl Into<ExternalNodeKey>,
+        labels: impl IntoIterator<Item = NodeLabel>,
+    ) -> Self {
+        self.keys.push(key.into());
+        self.labels.push(labels.into_iter().collect());
+        self
+    }
+
+    /// Sets a property column; it must hold one value per node of the batch.
+    pub fn with_property(mut self, key: impl Into<String>, values: AppendedPropertyValues) -> Self {
+        self.properties.insert(key.into(), values);
+        self
+    }
+
+    pub fn keys(&self) -> &[ExternalNodeKey] {
+        &self.keys
+    }
+
+    pub fn labels(&self) -> &[Vec<NodeLabel>] {
+        &self.labels
+    }
+
+    pub fn properties(&self) -> &HashMap<String, AppendedPropertyValues> {
+        &self.properties
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+/// New relationships of one type, addressed by node key, with their property values.
+#[derive(Debug, Clone)]
+pub struct RelationshipBatch {
+    relationship_type: RelationshipType,
+    sources: Vec<ExternalNodeKey>,
+    targets: Vec<ExternalNodeKey>,
+    properties: HashMap<String, AppendedPropertyValues>,
+}
+
+impl RelationshipBatch {
+    pub fn new(relationship_type: RelationshipType) -> Self {
+        Self {
+            relationship_type,
+            sources: Vec::new(),
+            targets: Vec::new(),
+            properties: HashMap::new(),
+        }
+    }
+
+    pub fn with_relationship(
+        mut self,
+        source: impl Into<ExternalNodeKey>,
+        target: impl Into<ExternalNodeKey>,
+    ) -> Self {
+        self.sources.push(source.into());
+        self.targets.push(target.into());
+        self
+    }
+
+    /// Sets a property column; it must hold one value per relationship of the batch.
+    pub fn with_property(mut self, key: impl Into<String>, values: AppendedPropertyValues) -> Self {
+        self.properties.insert(key.into(), values);
+        self
+    }
+
+    pub fn relationship_type(&self) -> &RelationshipType {
+        &self.relationship_type
+    }
+
+    pub fn relationships(&self) -> impl Iterator<Item = (&ExternalNodeKey, &ExternalNodeKey)> {
+        self.sources.iter().zip(&self.targets)
+    }
+
+    pub fn properties(&self) -> &HashMap<String, AppendedPropertyValues> {
+        &self.properties
+    }
+
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+/// A structural change applied to a graph store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphChange {
+    /// Nodes `first..first + count` were mapped.
+    NodesAdded { first: MappedNodeId, count: usize },
+    /// Relationships were added to one type, as stored (undirected types list both directions).
+    RelationshipsAdded {
+        relationship_type: RelationshipType,
+        relationships: Vec<(MappedNodeId, MappedNodeId)>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeLogEntry {
+    version: u64,
+    time: DateTime<Utc>,
+    change: GraphChange,
+}
+
+impl ChangeLogEntry {
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn time(&self) -> DateTime<Utc> {
+        self.time
+    }
+
+    pub fn change(&self) -> &GraphChange {
+        &self.change
+    }
+}
+
+/// Ordered record of the appends applied to a store.
+///
+/// Every append bumps the version; incremental algorithms remember the version they last saw
+/// and replay [`ChangeLog::changes_since`] instead of recomputing from scratch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangeLog {
+    entries: Vec<ChangeLogEntry>,
+    /// Version of the newest drained entry, so versions keep increasing after a drain.
+    drained_version: u64,
+}
+
+impl ChangeLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Version of the latest change, or 0 for a store that was never appended to.
+    pub fn version(&self) -> u64 {
+        self.entries
+            .last()
+            .map_or(self.drained_version, ChangeLogEntry::version)
+    }
+
+    /// Version of the newest drained change, or 0 when nothing was drained.
+    ///
+    /// Readers that last saw an older version missed changes that [`ChangeLog::changes_since`]
+    /// no longer returns, and must recompute from scratch.
+    pub fn drained_version(&self) -> u64 {
+        self.drained_version
+    }
+
+    /// Removes the changes up to and including `version` and returns them, oldest first.
+    ///
+    /// Call it once every reader has caught up with `version`, so that the log only keeps the
+    /// changes someone still has to replay.
+    pub fn drain_until(&mut self, version: u64) -> Vec<ChangeLogEntry> {
+        let end = self
+            .entries
+            .partition_point(|entry| entry.version <= version);
+        if let Some(last) = end.checked_sub(1).map(|index| &self.entries[index]) {
+            self.drained_version = last.version;
+        }
+        self.entries.drain(..end).collect()
+    }
+
+    /// Changes with a version greater than `version`, oldest first.
+    pub fn changes_since(&self, version: u64) -> &[ChangeLogEntry] {
+        let start = self
+            .entries
+            .partition_point(|entry| entry.version <= version);
+        &self.entries[start..]
+    }
+
+    pub fn entries(&self) -> &[ChangeLogEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub(crate) fn record(&mut self, time: DateTime<Utc>, change: GraphChange) -> u64 {
+        let version = self.version() + 1;
+        self.entries.push(ChangeLogEntry {
+            version,
+            time,
+            change,
+        });
+        version
+    }
+}
+
+/// Outcome of an append: the new change-log version and what was added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AppendResult {
+    version: u64,
+    node_count: usize,
+    relationship_count: usize,
+}
+
+impl AppendResult {
+    pub(crate) fn new(version: u64, node_count: usize, relationship_count: usize) -> Self {
+        Self {
+            version,
+            node_count,
+            relationship_count,
+        }
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Number of appended nodes.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Number of stored relationships that were appended.
+    pub fn relationship_count(&self) -> usize {
+        self.relationship_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn change_log_versions_are_monotonic() {
+        let mut log = ChangeLog::new();
+        assert_eq!(log.version(), 0);
+        let now = Utc::now();
+        let first = log.record(
+            now,
+            GraphChange::NodesAdded {
+                first: MappedNodeId::ZERO,
+                count: 2,
+            },
+        );
+        let second = log.record(
+            now,
+            GraphChange::RelationshipsAdded {
+                relationship_type: RelationshipType::of("KNOWS"),
+                relationships: vec![(MappedNodeId::ZERO, MappedNodeId::new(1))],
+            },
+        );
+        assert_eq!((first, second), (1, 2));
+        assert_eq!(log.changes_since(0).len(), 2);
+        assert_eq!(log.changes_since(1)[0].version(), 2);
+        assert!(log.changes_since(2).is_empty());
+    }
+
+    #[test]
+    fn draining_keeps_versions_monotonic() {
+        let mut log = ChangeLog::new();
+        let now = Utc::now();
+        for first in 0..3 {
+            log.record(
+                now,
+                GraphChange::NodesAdded {
+                    first: MappedNodeId::new(first),
+                    count: 1,
+                },
+            );
+        }
+
+        let drained = log.drain_until(2);
+        assert_eq!(
+            drained
+                .iter()
+                .map(ChangeLogEntry::version)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!((log.len(), log.version(), log.drained_version()), (1, 3, 2));
+        assert_eq!(log.changes_since(0)[0].version(), 3);
+
+        assert_eq!(log.drain_until(3).len(), 1);
+        assert!(log.is_empty());
+        assert_eq!(log.version(), 3);
+        assert_eq!(
+            log.record(
+                now,
+                GraphChange::NodesAdded {
+                    first: MappedNodeId::new(3),
+                    count: 1,
+                },
+            ),
+            4
+        );
+        assert!(log.drain_until(0).is_empty());
+        assert_eq!(log.drained_version(), 3);
+    }
+
+    #[test]
+    fn appended_columns_fold_batches_geometrically() {
+        let mut column = AppendedColumn::default();
+        for value in 0..100 {
+            column = column.with_values(AppendedPropertyValues::Long(vec![value]));
+        }
+        assert_eq!(column.len(), 100);
+        assert!(column.batches.len() <= 8);
+        assert!((0..100).all(|offset| column.long_value(offset) == Some(offset as i64)));
+        assert_eq!(column.long_value(100), None);
+        assert_eq!(column.max_long_value(), Some(99));
+    }
+
+    #[test]
+    fn batches_collect_columns() {
+        let batch = NodeBatch::new()
+            .with_node("a", [NodeLabel::of("Person")])
+            .with_node("b", [])
+            .with_property("age", AppendedPropertyValues::Long(vec![30, 40]));
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.labels()[1], Vec::<NodeLabel>::new());
+        assert_eq!(batch.properties()["age"].long_value(1), Some(40));
+        assert_eq!(batch.properties()["age"].double_value(1), None);
+
+        let relationships = RelationshipBatch::new(RelationshipType::of("KNOWS"))
+            .with_relationship("a", "b")
+            .with_property("weight", AppendedPropertyValues::Double(vec![0.5]));
+        assert_eq!(
+            relationships.relationships().collect::<Vec<_>>(),
+            vec![(&"a".into(), &"b".into())]
+        );
+        assert_eq!(
+            relationships.properties()["weight"].value_type(),
+            ValueType::Double
+        );
+    }
+}
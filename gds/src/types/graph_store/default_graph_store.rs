@@ -1,7 +1,9 @@
+use super::append::{AppendedNodePropertyValues, AppendedRelationshipPropertyValues};
 use super::{
-    Capabilities, DatabaseInfo, DeletionResult, GraphName, GraphStore, GraphStoreError,
-    GraphStoreResult, GraphViewError, GraphViewResult, GraphViewSpec, InducedSubgraphResult,
-    ProjectedPropertiesResult,
+    AppendResult, AppendedPropertyValues, Capabilities, ChangeLog, ChangeLogEntry, DatabaseInfo,
+    DeletionResult, GraphChange, GraphName, GraphStore, GraphStoreError, GraphStoreResult,
+    GraphViewError, GraphViewResult, GraphViewSpec, InducedSubgraphResult, NodeBatch,
+    ProjectedPropertiesResult, RelationshipBatch,
 };
 use crate::collections::backends::arrow::{ArrowDoubleArray, ArrowLongArray};
 use crate::collections::backends::factory::{
@@ -15,15 +17,19 @@ use crate::collections::backends::vec::{
 use crate::config::GraphStoreConfig;
 use crate::projection::Orientation;
 use crate::projection::{NodeLabel, RelationshipType};
-use crate::types::graph::id_map::{MappedNodeId, OriginalNodeId, RelationshipIndex};
+use crate::types::graph::id_map::{
+    ExternalNodeKey, MappedNodeId, OriginalNodeId, RelationshipIndex,
+};
 use crate::types::graph::{
     id_map::{IdMap, SimpleIdMap},
     DefaultGraph, Graph, GraphCharacteristics, GraphCharacteristicsBuilder, RelationshipTopology,
+    RelationshipTopologyError, TraversalDirection,
 };
 use crate::types::properties::graph::{
     DefaultDoubleGraphPropertyValues, DefaultGraphPropertyStore, DefaultLongGraphPropertyValues,
     GraphProperty, GraphPropertyStore, GraphPropertyValues,
 };
+use crate::types::properties::node::open_mapped_node_property_values;
 use crate::types::properties::node::{
     DefaultDoubleArrayNodePropertyValues, DefaultLongArrayNodePropertyValues,
 };
@@ -44,6 +50,7 @@ use crate::types::properties::relationship::{
 use crate::types::properties::relationship::{
     RelationshipPropertyStore, RelationshipPropertyStoreBuilder,
 };
+use crate::types::properties::AccessPattern;
 use crate::types::properties::PropertyStore;
 use crate::types::properties::PropertyValues;
 use crate::types::properties::PropertyValuesError;
@@ -52,11 +59,12 @@ use crate::types::schema::{
     Aggregation, Direction, GraphSchema, MutableGraphSchema, PropertySchemaTrait,
     RelationshipPropertySchema, RelationshipSchema, RelationshipSchemaEntry,
 };
-use crate::types::DefaultValue;
 use crate::types::PropertyState;
 use crate::types::ValueType;
+use crate::types::{DefaultValue, DefaultValueError};
 use chrono::{DateTime, Utc};
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::algo::algorithms::scaling::{MinMaxScaler, Scaler};
@@ -156,6 +164,7 @@ pub struct DefaultGraphStore {
     node_properties_by_label: HashMap<String, HashSet<String>>,
     relationship_property_stores: HashMap<RelationshipType, DefaultRelationshipPropertyStore>,
     has_relationship_properties: bool,
+    change_log: Arc<ChangeLog>,
 }
 
 impl DefaultGraphStore {
@@ -232,6 +241,7 @@ impl DefaultGraphStore {
             node_properties_by_label: HashMap::new(),
             relationship_property_stores: HashMap::new(),
             has_relationship_properties: false,
+            change_log: Arc::new(ChangeLog::new()),
         };
 
         store.rebuild_relationship_metadata();
@@ -396,7 +406,12 @@ impl DefaultGraphStore {
                 adj.dedup();
             }
 
-            let undirected = RelationshipTopology::try_new(outgoing, None).map_err(|error| {
+            let undirected = RelationshipTopology::try_with_layout(
+                topology.is_compressed(),
+                outgoing,
+                None,
+            )
+            .map_err(|error| {
                 GraphStoreError::InvalidOperation(format!(
                     "invalid undirected topology for relationship type '{rel_type}': {error}"
                 ))
@@ -461,12 +476,15 @@ impl DefaultGraphStore {
         relationship_types: Option<&HashSet<RelationshipType>>,
     ) -> GraphStoreResult<DefaultGraphStore> {
         let node_count = self.node_count();
+        // Rebuilt rows take indices by row position, which appended relationships do not have.
+        let mut store = self.clone();
+        store.compact_appends()?;
 
         let mut new_relationship_topologies: HashMap<RelationshipType, Arc<RelationshipTopology>> =
             HashMap::new();
         let selected = relationship_types.cloned();
 
-        for (rel_type, topology) in &self.relationship_topologies {
+        for (rel_type, topology) in &store.relationship_topologies {
             let should_index = selected
                 .as_ref()
                 .map(|set| set.contains(rel_type))
@@ -504,16 +522,19 @@ impl DefaultGraphStore {
                 adj.sort_unstable();
             }
 
-            let inverse_topology = RelationshipTopology::try_new(outgoing, Some(incoming))
-                .map_err(|error| {
-                    GraphStoreError::InvalidOperation(format!(
-                        "invalid inverse topology for relationship type '{rel_type}': {error}"
-                    ))
-                })?;
+            let inverse_topology = RelationshipTopology::try_with_layout(
+                topology.is_compressed(),
+                outgoing,
+                Some(incoming),
+            )
+            .map_err(|error| {
+                GraphStoreError::InvalidOperation(format!(
+                    "invalid inverse topology for relationship type '{rel_type}': {error}"
+                ))
+            })?;
             new_relationship_topologies.insert(rel_type.clone(), Arc::new(inverse_topology));
         }
 
-        let mut store = self.clone();
         store.graph_name = graph_name;
         store.relationship_topologies = new_relationship_topologies;
 
@@ -538,11 +559,14 @@ impl DefaultGraphStore {
         )
     }
 
+    /// The new type takes the adjacency layout of the store. A compressed store sorts every row
+    /// by target before encoding it, so property values added for the new type afterwards must
+    /// follow the sorted order.
     pub(crate) fn with_added_relationship_type_and_properties(
         &self,
         graph_name: GraphName,
         rel_type: RelationshipType,
-        outgoing: Vec<Vec<MappedNodeId>>,
+        mut outgoing: Vec<Vec<MappedNodeId>>,
         direction: Direction,
         property_schemas: Vec<RelationshipPropertySchema>,
     ) -> GraphStoreResult<DefaultGraphStore> {
@@ -553,12 +577,19 @@ impl DefaultGraphStore {
                 outgoing.len()
             )));
         }
+        if self.has_compressed_adjacency() {
+            for row in &mut outgoing {
+                row.sort();
+            }
+        }
 
-        let topology = RelationshipTopology::try_new(outgoing, None).map_err(|error| {
-            GraphStoreError::InvalidOperation(format!(
-                "invalid topology for relationship type '{rel_type}': {error}"
-            ))
-        })?;
+        let topology =
+            RelationshipTopology::try_with_layout(self.has_compressed_adjacency(), outgoing, None)
+                .map_err(|error| {
+                    GraphStoreError::InvalidOperation(format!(
+                        "invalid topology for relationship type '{rel_type}': {error}"
+                    ))
+                })?;
         let mut relationship_topologies = self.relationship_topologies.clone();
         relationship_topologies.insert(rel_type.clone(), Arc::new(topology));
         self.validate_retained_relationship_property_cardinalities(&relationship_topologies)?;
@@ -819,11 +850,12 @@ impl DefaultGraphStore {
             }
 
             let rebuilt_topology =
-                RelationshipTopology::try_new(new_outgoing, None).map_err(|error| {
-                    GraphStoreError::InvalidOperation(format!(
-                        "invalid collapsed topology for relationship type '{rel_type}': {error}"
-                    ))
-                })?;
+                RelationshipTopology::try_with_layout(topology.is_compressed(), new_outgoing, None)
+                    .map_err(|error| {
+                        GraphStoreError::InvalidOperation(format!(
+                            "invalid collapsed topology for relationship type '{rel_type}': {error}"
+                        ))
+                    })?;
             new_relationship_topologies.insert(rel_type.clone(), Arc::new(rebuilt_topology));
         }
 
@@ -895,6 +927,9 @@ impl DefaultGraphStore {
         let mut new_id_map =
             SimpleIdMap::try_from_original_ids(selected_original_node_ids.iter().copied())
                 .map_err(|error| GraphStoreError::InvalidOperation(error.to_string()))?;
+        if let Some(keys) = self.id_map.external_keys() {
+            new_id_map = new_id_map.with_external_keys(Arc::clone(keys));
+        }
         for (new_mapped_index, &original_id) in selected_original_node_ids.iter().enumerate() {
             let new_mapped = MappedNodeId::try_from(new_mapped_index).map_err(|_| {
                 GraphStoreError::InvalidOperation(
@@ -911,7 +946,9 @@ impl DefaultGraphStore {
                 })?;
             for label in self.id_map.node_labels(old_mapped) {
                 new_id_map.add_node_label(label.clone());
-                new_id_map.add_node_id_to_label(new_mapped, label);
+                new_id_map
+                    .add_node_id_to_label(new_mapped, label)
+                    .map_err(|error| GraphStoreError::InvalidOperation(error.to_string()))?;
             }
         }
 
@@ -920,21 +957,36 @@ impl DefaultGraphStore {
         let mut relationship_topologies: HashMap<RelationshipType, RelationshipTopology> =
             HashMap::new();
         let mut kept_by_type: HashMap<RelationshipType, usize> = HashMap::new();
+        let mut source_indices_by_type: HashMap<RelationshipType, Vec<RelationshipIndex>> =
+            HashMap::new();
 
         for (rel_type, topology) in &self.relationship_topologies {
-            let mut outgoing: Vec<Vec<MappedNodeId>> = vec![Vec::new(); n];
+            // Kept relationships with the index they had in this store, so properties follow
+            // them when a compressed row is re-sorted under the new node ids.
+            let mut rows: Vec<Vec<(MappedNodeId, RelationshipIndex)>> = vec![Vec::new(); n];
 
             for (new_source_index, &old_source) in selected_ordered_old_mapped.iter().enumerate() {
-                let neighbors = match topology.outgoing(old_source) {
-                    Some(neighbors) => neighbors,
-                    None => continue,
-                };
-                for &old_target in neighbors {
+                let neighbors =
+                    match topology.relationships(old_source, TraversalDirection::Outgoing) {
+                        Some(neighbors) => neighbors,
+                        None => continue,
+                    };
+                for (old_target, old_index) in neighbors {
                     if let Some(&new_target) = old_mapped_to_new.get(&old_target) {
-                        outgoing[new_source_index].push(new_target);
+                        rows[new_source_index].push((new_target, old_index));
                     }
                 }
             }
+            if topology.is_compressed() {
+                rows.iter_mut()
+                    .for_each(|row| row.sort_by_key(|&(target, _)| target));
+            }
+            let source_indices: Vec<RelationshipIndex> =
+                rows.iter().flatten().map(|&(_, index)| index).collect();
+            let outgoing: Vec<Vec<MappedNodeId>> = rows
+                .into_iter()
+                .map(|row| row.into_iter().map(|(target, _)| target).collect())
+                .collect();
 
             let incoming = if topology.is_inverse_indexed() {
                 let mut incoming: Vec<Vec<MappedNodeId>> = vec![Vec::new(); n];
@@ -961,23 +1013,23 @@ impl DefaultGraphStore {
                 None
             };
 
-            let induced = RelationshipTopology::try_new(outgoing, incoming).map_err(|error| {
-                GraphStoreError::InvalidOperation(format!(
-                    "invalid induced topology for relationship type '{rel_type}': {error}"
-                ))
-            })?;
+            let induced =
+                RelationshipTopology::try_with_layout(topology.is_compressed(), outgoing, incoming)
+                    .map_err(|error| {
+                        GraphStoreError::InvalidOperation(format!(
+                            "invalid induced topology for relationship type '{rel_type}': {error}"
+                        ))
+                    })?;
             let kept = induced.relationship_count();
             if kept > 0 {
                 kept_by_type.insert(rel_type.clone(), kept);
                 relationship_topologies.insert(rel_type.clone(), induced);
+                source_indices_by_type.insert(rel_type.clone(), source_indices);
             }
         }
 
-        let relationship_property_stores = self.project_relationship_properties(
-            &selected_ordered_old_mapped,
-            &old_mapped_to_new,
-            &relationship_topologies,
-        )?;
+        let relationship_property_stores =
+            self.project_relationship_properties(&source_indices_by_type)?;
 
         let store = DefaultGraphStore::try_new(
             self.config.as_ref().clone(),
@@ -1133,20 +1185,16 @@ impl DefaultGraphStore {
         })
     }
 
+    /// Projects relationship properties onto induced topologies; `source_indices_by_type` holds,
+    /// in new relationship order, the index each kept relationship had in this store.
     fn project_relationship_properties(
         &self,
-        selected_ordered_old_mapped: &[MappedNodeId],
-        old_mapped_to_new: &HashMap<MappedNodeId, MappedNodeId>,
-        new_relationship_topologies: &HashMap<RelationshipType, RelationshipTopology>,
+        source_indices_by_type: &HashMap<RelationshipType, Vec<RelationshipIndex>>,
     ) -> GraphStoreResult<HashMap<RelationshipType, DefaultRelationshipPropertyStore>> {
         let mut projected = HashMap::new();
 
-        for (rel_type, new_topology) in new_relationship_topologies {
-            let old_topology = match self.relationship_topologies.get(rel_type) {
-                Some(t) => t,
-                None => continue,
-            };
-            let new_count = new_topology.relationship_count();
+        for (rel_type, source_indices) in source_indices_by_type {
+            let new_count = source_indices.len();
             if new_count == 0 {
                 continue;
             }
@@ -1163,25 +1211,11 @@ impl DefaultGraphStore {
                 match values.value_type() {
                     ValueType::Double => {
                         let mut data = Vec::with_capacity(new_count);
-                        for &old_source in selected_ordered_old_mapped {
-                            if let Some(old_neighbors) = old_topology.outgoing(old_source) {
-                                for (neighbor_idx, &old_target) in old_neighbors.iter().enumerate()
-                                {
-                                    if old_mapped_to_new.contains_key(&old_target) {
-                                        let old_index = old_topology
-                                            .relationship_index(old_source, neighbor_idx)
-                                            .ok_or_else(|| {
-                                                GraphStoreError::InvalidOperation(format!(
-                                                    "missing canonical relationship index for {old_source} at offset {neighbor_idx}"
-                                                ))
-                                            })?;
-                                        let v = values.double_value(old_index).map_err(|err| {
-                                            GraphStoreError::InvalidOperation(format!("{err}"))
-                                        })?;
-                                        data.push(v);
-                                    }
-                                }
-                            }
+                        for &old_index in source_indices {
+                            let v = values.double_value(old_index).map_err(|err| {
+                                GraphStoreError::InvalidOperation(format!("{err}"))
+                            })?;
+                            data.push(v);
                         }
 
                         if data.is_empty() {
@@ -1201,25 +1235,11 @@ impl DefaultGraphStore {
                     }
                     ValueType::Long => {
                         let mut data = Vec::with_capacity(new_count);
-                        for &old_source in selected_ordered_old_mapped {
-                            if let Some(old_neighbors) = old_topology.outgoing(old_source) {
-                                for (neighbor_idx, &old_target) in old_neighbors.iter().enumerate()
-                                {
-                                    if old_mapped_to_new.contains_key(&old_target) {
-                                        let old_index = old_topology
-                                            .relationship_index(old_source, neighbor_idx)
-                                            .ok_or_else(|| {
-                                                GraphStoreError::InvalidOperation(format!(
-                                                    "missing canonical relationship index for {old_source} at offset {neighbor_idx}"
-                                                ))
-                                            })?;
-                                        let v = values.long_value(old_index).map_err(|err| {
-                                            GraphStoreError::InvalidOperation(format!("{err}"))
-                                        })?;
-                                        data.push(v);
-                                    }
-                                }
-                            }
+                        for &old_index in source_indices {
+                            let v = values.long_value(old_index).map_err(|err| {
+                                GraphStoreError::InvalidOperation(format!("{err}"))
+                            })?;
+                            data.push(v);
                         }
 
                         if data.is_empty() {
@@ -1239,25 +1259,11 @@ impl DefaultGraphStore {
                     }
                     ValueType::Int => {
                         let mut data = Vec::with_capacity(new_count);
-                        for &old_source in selected_ordered_old_mapped {
-                            if let Some(old_neighbors) = old_topology.outgoing(old_source) {
-                                for (neighbor_idx, &old_target) in old_neighbors.iter().enumerate()
-                                {
-                                    if old_mapped_to_new.contains_key(&old_target) {
-                                        let old_index = old_topology
-                                            .relationship_index(old_source, neighbor_idx)
-                                            .ok_or_else(|| {
-                                                GraphStoreError::InvalidOperation(format!(
-                                                    "missing canonical relationship index for {old_source} at offset {neighbor_idx}"
-                                                ))
-                                            })?;
-                                        let v = values.long_value(old_index).map_err(|err| {
-                                            GraphStoreError::InvalidOperation(format!("{err}"))
-                                        })?;
-                                        data.push(v as i32);
-                                    }
-                                }
-                            }
+                        for &old_index in source_indices {
+                            let v = values.long_value(old_index).map_err(|err| {
+                                GraphStoreError::InvalidOperation(format!("{err}"))
+                            })?;
+                            data.push(v as i32);
                         }
 
                         if data.is_empty() {
@@ -1288,6 +1294,14 @@ impl DefaultGraphStore {
         Ok(projected)
     }
 
+    /// True when the relationship topologies use the compressed layout, which relationship
+    /// types added later follow.
+    fn has_compressed_adjacency(&self) -> bool {
+        self.relationship_topologies
+            .values()
+            .any(|topology| topology.is_compressed())
+    }
+
     fn set_modified(&mut self) {
         self.modification_time = Utc::now();
     }
@@ -1349,6 +1363,24 @@ impl DefaultGraphStore {
         self.add_node_property(labels, key, pv)
     }
 
+    /// Add a node property read from a memory-mapped column file instead of the heap.
+    ///
+    /// Values stay in the page cache, so algorithms can read columns larger than memory; pair
+    /// with topologies from [`RelationshipTopology::open_mapped`].
+    pub fn add_mapped_node_property(
+        &mut self,
+        key: String,
+        path: &Path,
+        access: AccessPattern,
+    ) -> Result<(), GraphStoreError> {
+        let pv = open_mapped_node_property_values(path, access).map_err(|error| {
+            GraphStoreError::InvalidOperation(format!("cannot map node property '{key}': {error}"))
+        })?;
+
+        let labels = self.node_schema_labels_for_mutation();
+        self.add_node_property(labels, key, pv)
+    }
+
     /// Add a graph property with i64 values using the store's config for backend selection.
     pub fn add_graph_property_i64(
         &mut self,
@@ -1381,6 +1413,593 @@ impl DefaultGraphStore {
         self.add_graph_property(key, pv)
     }
 
+    // === Appends ===
+
+    /// Change log of the appends applied to this store.
+    pub fn change_log(&self) -> &ChangeLog {
+        &self.change_log
+    }
+
+    /// Removes the change-log entries up to and including `version` and returns them, oldest
+    /// first; see [`ChangeLog::drain_until`].
+    pub fn drain_change_log(&mut self, version: u64) -> Vec<ChangeLogEntry> {
+        Arc::make_mut(&mut self.change_log).drain_until(version)
+    }
+
+    /// Merges appended relationships into the rows of their topologies and copies grown node
+    /// and relationship property columns into plain ones.
+    ///
+    /// Appends do this lazily as they accumulate. Rebuilds that need every relationship index
+    /// to match its row position, such as adding an inverse index, compact first.
+    pub fn compact_appends(&mut self) -> GraphStoreResult<()> {
+        let pending = self
+            .relationship_topologies
+            .iter()
+            .filter(|(_, topology)| topology.has_appended_relationships())
+            .map(|(rel_type, _)| rel_type.clone())
+            .collect::<Vec<_>>();
+        for rel_type in pending {
+            let (merged, old_indices) = self.relationship_topologies[&rel_type]
+                .merge_appended()
+                .map_err(|error| {
+                    GraphStoreError::InvalidOperation(format!(
+                        "invalid topology for relationship type '{rel_type}': {error}"
+                    ))
+                })?;
+            if let Some(store) = self.relationship_property_stores.get(&rel_type) {
+                let mut builder = DefaultRelationshipPropertyStore::builder();
+                for property in store.columns() {
+                    let values = self.plain_relationship_property_values(
+                        property.property_schema(),
+                        Some(property.values()),
+                        &old_indices,
+                    )?;
+                    builder = builder.put(RelationshipProperty::with_schema(
+                        property.property_schema().clone(),
+                        values,
+                    ));
+                }
+                self.relationship_property_stores
+                    .insert(rel_type.clone(), builder.build());
+            }
+            self.relationship_topologies
+                .insert(rel_type, Arc::new(merged));
+        }
+
+        let grown = self
+            .node_properties
+            .columns()
+            .filter(|property| {
+                property
+                    .values()
+                    .as_any()
+                    .is_some_and(|values| values.is::<AppendedNodePropertyValues>())
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for property in grown {
+            let values = self.plain_node_property_values(&property, property.values())?;
+            self.node_properties
+                .replace_column(NodeProperty::with_schema(
+                    property.property_schema().clone(),
+                    values,
+                ))
+                .map_err(|error| GraphStoreError::InvalidOperation(error.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Appends nodes after the existing ones.
+    ///
+    /// The id map, every relationship topology and every node property column grow together;
+    /// columns missing from the batch take their schema default for the new nodes. Batch
+    /// columns must name existing node properties. Nothing changes when the batch is rejected.
+    ///
+    /// Topologies and columns share their existing rows and values with the grown store, so an
+    /// append costs the size of the batch. A column is copied once as many nodes were appended
+    /// to it as it held before, which keeps reads one lookup deep at no more than constant
+    /// amortized cost per node.
+    pub fn append_nodes(&mut self, batch: NodeBatch) -> GraphStoreResult<AppendResult> {
+        let count = batch.len();
+        let old_count = self.node_count();
+        let node_count = old_count + count;
+        for (key, values) in batch.properties() {
+            if values.len() != count {
+                return Err(GraphStoreError::InvalidOperation(format!(
+                    "node property '{key}' has {} values but the batch contains {count} nodes",
+                    values.len()
+                )));
+            }
+            let property = self
+                .node_properties
+                .get(key)
+                .ok_or_else(|| GraphStoreError::PropertyNotFound(key.clone()))?;
+            if property.values().value_type() != values.value_type() {
+                return Err(GraphStoreError::SchemaError(format!(
+                    "node property '{key}' is {:?} but the batch holds {:?}",
+                    property.values().value_type(),
+                    values.value_type()
+                )));
+            }
+        }
+
+        let mut properties = Vec::with_capacity(self.node_properties.len());
+        for property in self.node_properties.columns() {
+            let values = self.appended_node_property_values(
+                property,
+                batch.properties().get(property.key()),
+                old_count,
+                count,
+            )?;
+            properties.push(
+                NodeProperty::try_with_schema(property.property_schema().clone(), values)
+                    .map_err(|error| GraphStoreError::SchemaError(error.to_string()))?,
+            );
+        }
+        let relationship_topologies = self
+            .relationship_topologies
+            .iter()
+            .map(|(rel_type, topology)| {
+                (
+                    rel_type.clone(),
+                    Arc::new(topology.with_node_capacity(node_count)),
+                )
+            })
+            .collect();
+
+        let id_map = Arc::make_mut(&mut self.id_map);
+        let first = id_map
+            .try_append_external_keys(batch.keys().iter().cloned())
+            .map_err(|error| {
+                GraphStoreError::InvalidOperation(format!("cannot append nodes: {error}"))
+            })?;
+        let mut new_labels = HashSet::new();
+        for (offset, labels) in batch.labels().iter().enumerate() {
+            let node_id = MappedNodeId::new(first.get() + offset as u64);
+            for label in labels {
+                let label = Self::to_schema_label(label);
+                id_map
+                    .add_node_id_to_label(node_id, label.clone())
+                    .map_err(|error| {
+                        GraphStoreError::InvalidOperation(format!("cannot append nodes: {error}"))
+                    })?;
+                new_labels.insert(label);
+            }
+        }
+        let available_labels = self.schema.node_schema().available_labels();
+        if !new_labels.is_subset(&available_labels) {
+            let mut schema = MutableGraphSchema::from_schema(&self.schema);
+            for label in new_labels.difference(&available_labels) {
+                schema.node_schema_mut().get_or_create_label(label.clone());
+            }
+            self.schema = Arc::new(schema.build());
+        }
+        for property in properties {
+            self.node_properties
+                .replace_column(property)
+                .map_err(|error| GraphStoreError::InvalidOperation(error.to_string()))?;
+        }
+        self.relationship_topologies = relationship_topologies;
+
+        self.set_modified();
+        let version = Arc::make_mut(&mut self.change_log).record(
+            self.modification_time,
+            GraphChange::NodesAdded { first, count },
+        );
+        Ok(AppendResult::new(version, count, 0))
+    }
+
+    /// Appends relationships of one type, creating the type when it does not exist yet.
+    ///
+    /// Endpoints are resolved by node key, and undirected types store each relationship in both
+    /// directions. The topology keeps its layout and inverse index; for compressed rows the new
+    /// targets are read in order. Every property column of the type is extended, with the
+    /// schema default for columns missing from the batch. Batch columns of an existing type
+    /// must name existing properties; a new type takes its columns from the batch.
+    ///
+    /// The new relationships are kept in delta rows and column batches, see
+    /// [`RelationshipTopology::try_append`], so an append costs the size of the batch. Once
+    /// they are as many as the relationships and nodes the topology was encoded with, the type
+    /// is re-encoded with them merged in, at constant amortized cost per relationship.
+    pub fn append_relationships(
+        &mut self,
+        batch: RelationshipBatch,
+    ) -> GraphStoreResult<AppendResult> {
+        let relationship_type = batch.relationship_type().clone();
+        let count = batch.len();
+        let existing_topology = self.relationship_topologies.get(&relationship_type);
+        let property_store = self.relationship_property_stores.get(&relationship_type);
+        for (key, values) in batch.properties() {
+            if values.len() != count {
+                return Err(GraphStoreError::InvalidOperation(format!(
+                    "relationship property '{key}' has {} values but the batch contains {count} relationships",
+                    values.len()
+                )));
+            }
+            match property_store.and_then(|store| store.get(key)) {
+                Some(property) if property.values().value_type() != values.value_type() => {
+                    return Err(GraphStoreError::SchemaError(format!(
+                        "relationship property '{key}' is {:?} but the batch holds {:?}",
+                        property.values().value_type(),
+                        values.value_type()
+                    )));
+                }
+                None if existing_topology.is_some() => {
+                    return Err(GraphStoreError::PropertyNotFound(key.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        let direction = self
+            .schema
+            .relationship_schema()
+            .get(&relationship_type)
+            .map_or(Direction::Directed, |entry| entry.direction());
+        let resolve = |key: &ExternalNodeKey| {
+            self.id_map.to_mapped_node_id_by_key(key).ok_or_else(|| {
+                GraphStoreError::InvalidOperation(format!(
+                    "cannot append relationships: unknown node key {key}"
+                ))
+            })
+        };
+        let mut relationships = Vec::with_capacity(count);
+        let mut positions = Vec::with_capacity(count);
+        for (index, (source, target)) in batch.relationships().enumerate() {
+            let (source, target) = (resolve(source)?, resolve(target)?);
+            relationships.push((source, target));
+            positions.push(index);
+            if direction == Direction::Undirected {
+                relationships.push((target, source));
+                positions.push(index);
+            }
+        }
+
+        let empty_topology;
+        let old_topology = match existing_topology {
+            Some(topology) => topology.as_ref(),
+            None => {
+                empty_topology = RelationshipTopology::try_with_layout(
+                    self.has_compressed_adjacency(),
+                    vec![Vec::new(); self.node_count()],
+                    None,
+                )
+                .expect("empty rows form a valid topology");
+                &empty_topology
+            }
+        };
+        let invalid_topology = |error: RelationshipTopologyError| {
+            GraphStoreError::InvalidOperation(format!(
+                "invalid topology for relationship type '{relationship_type}': {error}"
+            ))
+        };
+        let mut topology = old_topology
+            .try_append(&relationships)
+            .map_err(invalid_topology)?;
+
+        let mut schema = MutableGraphSchema::from_schema(&self.schema);
+        schema
+            .relationship_schema_mut()
+            .get_or_create_type(relationship_type.clone(), direction);
+        let mut columns = Vec::new();
+        match property_store {
+            Some(store) => {
+                for property in store.columns() {
+                    columns.push((
+                        property.property_schema().clone(),
+                        Some(property.values_arc()),
+                    ));
+                }
+            }
+            None => {
+                for (key, values) in batch.properties() {
+                    let property_schema = RelationshipPropertySchema::with_aggregation(
+                        key.clone(),
+                        values.value_type(),
+                        DefaultValue::of(values.value_type()),
+                        PropertyState::Persistent,
+                        Aggregation::None,
+                    );
+                    schema.relationship_schema_mut().add_property_schema(
+                        relationship_type.clone(),
+                        direction,
+                        property_schema.clone(),
+                    );
+                    columns.push((property_schema, None));
+                }
+            }
+        }
+        let mut grown_columns = Vec::with_capacity(columns.len());
+        for (property_schema, existing) in columns {
+            let existing = match existing {
+                Some(values) => values,
+                None => self.plain_relationship_property_values(&property_schema, None, &[])?,
+            };
+            let values = self.appended_relationship_property_values(
+                &property_schema,
+                &existing,
+                old_topology.relationship_count(),
+                batch.properties().get(property_schema.key()),
+                &positions,
+            )?;
+            grown_columns.push((property_schema, values));
+        }
+        if topology.should_merge_appended() {
+            let (merged, old_indices) = topology.merge_appended().map_err(invalid_topology)?;
+            topology = merged;
+            for (property_schema, values) in &mut grown_columns {
+                *values = self.plain_relationship_property_values(
+                    property_schema,
+                    Some(values.as_ref()),
+                    &old_indices,
+                )?;
+            }
+        }
+        let mut builder = DefaultRelationshipPropertyStore::builder();
+        for (property_schema, values) in grown_columns {
+            builder = builder.put(
+                RelationshipProperty::try_with_schema(property_schema, values)
+                    .map_err(|error| GraphStoreError::SchemaError(error.to_string()))?,
+            );
+        }
+        let property_store = builder.build();
+
+        if property_store.is_empty() {
+            self.relationship_property_stores.remove(&relationship_type);
+        } else {
+            self.relationship_property_stores
+                .insert(relationship_type.clone(), property_store);
+        }
+        self.relationship_topologies
+            .insert(relationship_type.clone(), Arc::new(topology));
+        self.schema = Arc::new(schema.build());
+        self.rebuild_relationship_metadata();
+        self.refresh_relationship_property_state();
+
+        self.set_modified();
+        let relationship_count = relationships.len();
+        let version = Arc::make_mut(&mut self.change_log).record(
+            self.modification_time,
+            GraphChange::RelationshipsAdded {
+                relationship_type,
+                relationships,
+            },
+        );
+        Ok(AppendResult::new(version, 0, relationship_count))
+    }
+
+    /// Grows `property` by `count` nodes, which take their value from `appended` or the schema
+    /// default; array columns hold no value for them.
+    fn appended_node_property_values(
+        &self,
+        property: &NodeProperty,
+        appended: Option<&AppendedPropertyValues>,
+        old_count: usize,
+        count: usize,
+    ) -> GraphStoreResult<Arc<dyn NodePropertyValues>> {
+        let grown = AppendedNodePropertyValues::grow(&property.values_arc(), old_count);
+        let default_value = property.property_schema().default_value();
+        let default_error = |error: DefaultValueError| {
+            GraphStoreError::SchemaError(format!(
+                "node property '{}' default: {error}",
+                property.key()
+            ))
+        };
+        let column = match (property.values().value_type(), appended) {
+            (ValueType::Long | ValueType::Double, Some(values)) => {
+                grown.appended().with_values(values.clone())
+            }
+            (ValueType::Long, None) => {
+                let default = default_value.long_value().map_err(default_error)?;
+                grown
+                    .appended()
+                    .with_values(AppendedPropertyValues::Long(vec![default; count]))
+            }
+            (ValueType::Double, None) => {
+                let default = default_value.double_value().map_err(default_error)?;
+                grown
+                    .appended()
+                    .with_values(AppendedPropertyValues::Double(vec![default; count]))
+            }
+            (ValueType::DoubleArray | ValueType::LongArray, _) => {
+                grown.appended().with_absent(count)
+            }
+            (value_type, _) => {
+                return Err(GraphStoreError::InvalidOperation(format!(
+                    "cannot append nodes: node property '{}' of type {value_type:?} cannot be extended",
+                    property.key()
+                )))
+            }
+        };
+        let grown = grown.with_appended(column);
+        if grown.appended().len() < grown.base_count() {
+            return Ok(Arc::new(grown));
+        }
+        self.plain_node_property_values(property, &grown)
+    }
+
+    /// Copies `values` into a plain column for `property`; nodes without a value take the schema
+    /// default, or no value in array columns.
+    fn plain_node_property_values(
+        &self,
+        property: &NodeProperty,
+        values: &dyn NodePropertyValues,
+    ) -> GraphStoreResult<Arc<dyn NodePropertyValues>> {
+        let node_count = values.node_count();
+        let default_value = property.property_schema().default_value();
+        let default_error = |error: DefaultValueError| {
+            GraphStoreError::SchemaError(format!(
+                "node property '{}' default: {error}",
+                property.key()
+            ))
+        };
+        match values.value_type() {
+            ValueType::Long => {
+                let default = default_value.long_value().map_err(default_error)?;
+                let column = (0..node_count)
+                    .map(|node| values.long_value(node as u64).unwrap_or(default))
+                    .collect();
+                let config = self.config.node_collections_config::<i64>(node_count);
+                let backend = create_long_backend_from_config(&config, column);
+                Ok(build_node_long_property_values(backend, node_count))
+            }
+            ValueType::Double => {
+                let default = default_value.double_value().map_err(default_error)?;
+                let column = (0..node_count)
+                    .map(|node| values.double_value(node as u64).unwrap_or(default))
+                    .collect();
+                let config = self.config.node_collections_config::<f64>(node_count);
+                let backend = create_double_backend_from_config(&config, column);
+                Ok(build_node_double_property_values(backend, node_count))
+            }
+            ValueType::DoubleArray => {
+                let column = (0..node_count)
+                    .map(|node| values.double_array_value(node as u64).ok())
+                    .collect::<Vec<_>>();
+                Ok(build_node_double_array_property_values(
+                    VecDoubleArray::from(column),
+                    node_count,
+                ))
+            }
+            ValueType::LongArray => {
+                let column = (0..node_count)
+                    .map(|node| values.long_array_value(node as u64).ok())
+                    .collect::<Vec<_>>();
+                Ok(build_node_long_array_property_values(
+                    VecLongArray::from(column),
+                    node_count,
+                ))
+            }
+            value_type => Err(GraphStoreError::InvalidOperation(format!(
+                "cannot append nodes: node property '{}' of type {value_type:?} cannot be extended",
+                property.key()
+            ))),
+        }
+    }
+
+    /// Grows a relationship column by the appended relationships, stored at `positions` of the
+    /// batch; columns missing from the batch take the schema default.
+    fn appended_relationship_property_values(
+        &self,
+        property_schema: &RelationshipPropertySchema,
+        existing: &Arc<dyn RelationshipPropertyValues>,
+        relationship_count: usize,
+        appended: Option<&AppendedPropertyValues>,
+        positions: &[usize],
+    ) -> GraphStoreResult<Arc<dyn RelationshipPropertyValues>> {
+        let key = property_schema.key();
+        let default_error = |error: DefaultValueError| {
+            GraphStoreError::SchemaError(format!("relationship property '{key}' default: {error}"))
+        };
+        let values = match property_schema.value_type() {
+            ValueType::Long => {
+                let default = property_schema
+                    .default_value()
+                    .long_value()
+                    .map_err(default_error)?;
+                AppendedPropertyValues::Long(
+                    positions
+                        .iter()
+                        .map(|&position| {
+                            appended
+                                .and_then(|values| values.long_value(position))
+                                .unwrap_or(default)
+                        })
+                        .collect(),
+                )
+            }
+            ValueType::Double => {
+                let default = property_schema
+                    .default_value()
+                    .double_value()
+                    .map_err(default_error)?;
+                AppendedPropertyValues::Double(
+                    positions
+                        .iter()
+                        .map(|&position| {
+                            appended
+                                .and_then(|values| values.double_value(position))
+                                .unwrap_or(default)
+                        })
+                        .collect(),
+                )
+            }
+            value_type => {
+                return Err(GraphStoreError::InvalidOperation(format!(
+                    "cannot append relationships: property '{key}' of type {value_type:?} cannot be extended"
+                )))
+            }
+        };
+        Ok(Arc::new(
+            AppendedRelationshipPropertyValues::grow(existing, relationship_count)
+                .with_values(values),
+        ))
+    }
+
+    /// Copies a relationship column into a plain one, reading `old_indices` in order; missing
+    /// values take the schema default.
+    fn plain_relationship_property_values(
+        &self,
+        property_schema: &RelationshipPropertySchema,
+        values: Option<&dyn RelationshipPropertyValues>,
+        old_indices: &[RelationshipIndex],
+    ) -> GraphStoreResult<Arc<dyn RelationshipPropertyValues>> {
+        let key = property_schema.key();
+        let default_error = |error: DefaultValueError| {
+            GraphStoreError::SchemaError(format!("relationship property '{key}' default: {error}"))
+        };
+        let relationship_count = old_indices.len();
+        match property_schema.value_type() {
+            ValueType::Long => {
+                let default = property_schema
+                    .default_value()
+                    .long_value()
+                    .map_err(default_error)?;
+                let column = old_indices
+                    .iter()
+                    .map(|&index| {
+                        values
+                            .and_then(|values| values.long_value(index).ok())
+                            .unwrap_or(default)
+                    })
+                    .collect();
+                let config = self
+                    .config
+                    .relationship_collections_config::<i64>(relationship_count);
+                let backend = create_long_backend_from_config(&config, column);
+                Ok(build_relationship_long_property_values(
+                    backend,
+                    relationship_count,
+                ))
+            }
+            ValueType::Double => {
+                let default = property_schema
+                    .default_value()
+                    .double_value()
+                    .map_err(default_error)?;
+                let column = old_indices
+                    .iter()
+                    .map(|&index| {
+                        values
+                            .and_then(|values| values.double_value(index).ok())
+                            .unwrap_or(default)
+                    })
+                    .collect();
+                let config = self
+                    .config
+                    .relationship_collections_config::<f64>(relationship_count);
+                let backend = create_double_backend_from_config(&config, column);
+                Ok(build_relationship_double_property_values(
+                    backend,
+                    relationship_count,
+                ))
+            }
+            value_type => Err(GraphStoreError::InvalidOperation(format!(
+                "cannot append relationships: property '{key}' of type {value_type:?} cannot be extended"
+            ))),
+        }
+    }
+
     fn to_schema_label(label: &NodeLabel) -> NodeLabel {
         NodeLabel::of(label.name())
     }
@@ -2224,13 +2843,13 @@ fn orient_topology(
     let node_count = topology.node_capacity();
     let mut buckets = vec![Vec::<(MappedNodeId, RelationshipIndex)>::new(); node_count];
 
-    for (source_index, targets) in topology.outgoing_lists().iter().enumerate() {
+    for source_index in 0..node_count {
         let source = MappedNodeId::try_from(source_index)
             .expect("validated topology node capacity must fit mapped ID space");
-        for (neighbor_offset, &target) in targets.iter().enumerate() {
-            let old_index = topology
-                .relationship_index(source, neighbor_offset)
-                .expect("validated topology row must have a canonical relationship index");
+        let row = topology
+            .relationships(source, TraversalDirection::Outgoing)
+            .expect("validated topology node capacity covers every source");
+        for (target, old_index) in row {
             match orientation {
                 Orientation::Natural => buckets[source_index].push((target, old_index)),
                 Orientation::Reverse => {
@@ -2260,7 +2879,11 @@ fn orient_topology(
 
     let mut outgoing = Vec::with_capacity(node_count);
     let mut old_indices = Vec::new();
-    for bucket in buckets {
+    for mut bucket in buckets {
+        // Undirected buckets interleave both directions; compressed rows must be sorted.
+        if topology.is_compressed() {
+            bucket.sort_by_key(|&(target, _)| target);
+        }
         let mut targets = Vec::with_capacity(bucket.len());
         for (target, old_index) in bucket {
             targets.push(target);
@@ -2275,7 +2898,10 @@ fn orient_topology(
         None
     };
 
-    (RelationshipTopology::new(outgoing, incoming), old_indices)
+    let oriented =
+        RelationshipTopology::try_with_layout(topology.is_compressed(), outgoing, incoming)
+            .expect("oriented rows of a valid topology form a valid topology");
+    (oriented, old_indices)
 }
 
 fn build_incoming(outgoing: &[Vec<MappedNodeId>]) -> Vec<Vec<MappedNodeId>> {
@@ -3007,6 +3633,147 @@ mod tests {
         assert_eq!(values.long_value(2).unwrap(), 30);
     }
 
+    #[test]
+    fn appending_nodes_grows_every_component() {
+        let mut store = sample_store();
+        store
+            .add_node_property_i64("age".into(), vec![30, 40, 50])
+            .unwrap();
+        store
+            .add_node_property_f64("score".into(), vec![0.5, 0.25, 1.0])
+            .unwrap();
+        let before = store.modification_time();
+
+        let result = store
+            .append_nodes(
+                NodeBatch::new()
+                    .with_node(7, [NodeLabel::of("Person")])
+                    .with_node(8, [])
+                    .with_property("age", AppendedPropertyValues::Long(vec![60, 70])),
+            )
+            .unwrap();
+        assert_eq!((result.version(), result.node_count()), (1, 2));
+        assert!(store.modification_time() >= before);
+        assert_eq!(store.node_count(), 5);
+        assert_eq!(
+            store.nodes().to_mapped_node_id_by_key(&8.into()),
+            Some(MappedNodeId::new(4))
+        );
+        assert_eq!(store.node_count_for_label(&NodeLabel::of("Person")), 1);
+        let age = store.node_property_values("age").unwrap();
+        assert_eq!(age.long_value(0).unwrap(), 30);
+        assert_eq!(age.long_value(4).unwrap(), 70);
+        let score = store.node_property_values("score").unwrap();
+        assert!(score.double_value(3).unwrap().is_nan());
+        let graph = store.graph();
+        assert_eq!(graph.degree(MappedNodeId::ZERO), 2);
+        assert_eq!(graph.degree(MappedNodeId::new(4)), 0);
+
+        assert!(store
+            .append_nodes(NodeBatch::new().with_node(9, []).with_node(0, []))
+            .is_err());
+        assert!(store
+            .append_nodes(
+                NodeBatch::new()
+                    .with_node(9, [])
+                    .with_property("unknown", AppendedPropertyValues::Long(vec![1]))
+            )
+            .is_err());
+        assert_eq!(store.node_count(), 5);
+        assert_eq!(store.change_log().version(), 1);
+        assert_eq!(
+            store.change_log().changes_since(0)[0].change(),
+            &GraphChange::NodesAdded {
+                first: MappedNodeId::new(3),
+                count: 2
+            }
+        );
+    }
+
+    #[test]
+    fn appending_relationships_reindexes_properties_and_inverse_index() {
+        let rel_type = RelationshipType::of("KNOWS");
+        let mut store = sample_store()
+            .with_inverse_indices(GraphName::new("g"))
+            .unwrap();
+        store
+            .add_relationship_property(
+                rel_type.clone(),
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![1.0, 2.0, 3.0],
+                    3,
+                )),
+            )
+            .unwrap();
+
+        let result = store
+            .append_relationships(
+                RelationshipBatch::new(rel_type.clone())
+                    .with_relationship(0, 0)
+                    .with_relationship(2, 1)
+                    .with_property("weight", AppendedPropertyValues::Double(vec![4.0, 5.0])),
+            )
+            .unwrap();
+        assert_eq!(result.relationship_count(), 2);
+        assert_eq!(store.relationship_count(), 5);
+
+        let graph = store.graph();
+        let weight_values = store
+            .relationship_property_values(&rel_type, "weight")
+            .unwrap();
+        let weight = |index: RelationshipIndex| weight_values.double_value(index).unwrap();
+        let outgoing = |node: u64| {
+            graph
+                .stream_relationships(MappedNodeId::new(node), 0.0)
+                .map(|cursor| (cursor.target_id(), weight(cursor.relationship_index())))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            outgoing(0),
+            vec![
+                (MappedNodeId::new(1), 1.0),
+                (MappedNodeId::new(2), 2.0),
+                (MappedNodeId::ZERO, 4.0)
+            ]
+        );
+        assert_eq!(outgoing(2), vec![(MappedNodeId::new(1), 5.0)]);
+        let incoming = graph
+            .stream_inverse_relationships(MappedNodeId::new(1), 0.0)
+            .map(|cursor| (cursor.source_id(), weight(cursor.relationship_index())))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            incoming,
+            vec![(MappedNodeId::ZERO, 1.0), (MappedNodeId::new(2), 5.0)]
+        );
+
+        let likes = RelationshipType::of("LIKES");
+        store
+            .append_relationships(
+                RelationshipBatch::new(likes.clone())
+                    .with_relationship(1, 2)
+                    .with_property("since", AppendedPropertyValues::Long(vec![2020])),
+            )
+            .unwrap();
+        assert_eq!(store.relationship_count_for_type(&likes), 1);
+        assert!(store.has_relationship_property(&likes, "since"));
+        assert!(store
+            .append_relationships(RelationshipBatch::new(rel_type.clone()).with_relationship(0, 42))
+            .is_err());
+        assert_eq!(store.relationship_count(), 6);
+        validate_graph_store_schema(&store).unwrap();
+
+        let changes = store.change_log().changes_since(1);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].change(),
+            &GraphChange::RelationshipsAdded {
+                relationship_type: likes,
+                relationships: vec![(MappedNodeId::new(1), MappedNodeId::new(2))],
+            }
+        );
+    }
+
     #[test]
     fn manages_relationship_properties() {
         let mut store = sample_store();
@@ -3524,4 +4291,218 @@ mod tests {
         let collected: Vec<f64> = values.double_values().collect();
         assert_eq!(collected, vec![0.25, 0.75]);
     }
+
+    fn compressed_store() -> DefaultGraphStore {
+        let knows = RelationshipType::of("KNOWS");
+        let compressed = sample_store().relationship_topologies[&knows]
+            .compress()
+            .unwrap();
+        let mut store = sample_store()
+            .with_rebuilt_relationship_topologies(
+                GraphName::new("compressed"),
+                HashMap::from([(knows.clone(), compressed)]),
+            )
+            .unwrap();
+        store
+            .add_relationship_property(
+                knows,
+                "weight",
+                Arc::new(DefaultRelationshipPropertyValues::with_default(
+                    vec![1.0, 2.0, 3.0],
+                    3,
+                )),
+            )
+            .unwrap();
+        store
+    }
+
+    #[test]
+    fn pending_appends_read_in_row_order_until_compacted() {
+        let knows = RelationshipType::of("KNOWS");
+        let mut store = compressed_store();
+        store
+            .append_nodes(NodeBatch::new().with_node(3, []))
+            .unwrap();
+        store
+            .append_relationships(
+                RelationshipBatch::new(knows.clone())
+                    .with_relationship(0, 0)
+                    .with_relationship(3, 1)
+                    .with_property("weight", AppendedPropertyValues::Double(vec![4.0, 5.0])),
+            )
+            .unwrap();
+        assert!(store.relationship_topologies[&knows].has_appended_relationships());
+
+        let outgoing = |store: &DefaultGraphStore, node: u64| {
+            let weights = store
+                .relationship_property_values(&knows, "weight")
+                .unwrap();
+            store
+                .graph()
+                .stream_relationships(MappedNodeId::new(node), 0.0)
+                .map(|cursor| {
+                    (
+                        cursor.target_id(),
+                        weights.double_value(cursor.relationship_index()).unwrap(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let expected = vec![
+            (MappedNodeId::ZERO, 4.0),
+            (MappedNodeId::new(1), 1.0),
+            (MappedNodeId::new(2), 2.0),
+        ];
+        assert_eq!(outgoing(&store, 0), expected);
+        assert_eq!(outgoing(&store, 3), vec![(MappedNodeId::new(1), 5.0)]);
+        assert_eq!(store.graph().degree(MappedNodeId::new(3)), 1);
+
+        store.compact_appends().unwrap();
+        let topology = &store.relationship_topologies[&knows];
+        assert!(!topology.has_appended_relationships());
+        assert!(topology.is_compressed());
+        assert_eq!(store.relationship_count(), 5);
+        assert_eq!(outgoing(&store, 0), expected);
+        assert_eq!(outgoing(&store, 3), vec![(MappedNodeId::new(1), 5.0)]);
+        validate_graph_store_schema(&store).unwrap();
+    }
+
+    #[test]
+    fn draining_the_change_log_keeps_its_version() {
+        let mut store = sample_store();
+        for key in [3, 4] {
+            store
+                .append_nodes(NodeBatch::new().with_node(key, []))
+                .unwrap();
+        }
+
+        let drained = store.drain_change_log(1);
+        assert_eq!(drained.len(), 1);
+        assert_eq!(drained[0].version(), 1);
+        assert_eq!(store.change_log().changes_since(0).len(), 1);
+        assert_eq!(store.drain_change_log(5).len(), 1);
+        assert_eq!(store.change_log().version(), 2);
+        let result = store
+            .append_nodes(NodeBatch::new().with_node(5, []))
+            .unwrap();
+        assert_eq!(result.version(), 3);
+    }
+
+    #[test]
+    fn rebuilds_keep_the_compressed_layout() {
+        let knows = RelationshipType::of("KNOWS");
+        let likes = RelationshipType::of("LIKES");
+        let store = compressed_store();
+        let is_compressed = |store: &DefaultGraphStore, relationship_type: &RelationshipType| {
+            store.relationship_topologies[relationship_type].is_compressed()
+        };
+
+        let undirected = store.to_undirected(GraphName::new("undirected")).unwrap();
+        assert!(is_compressed(&undirected, &knows));
+        let inverse = store
+            .with_inverse_indices(GraphName::new("inverse"))
+            .unwrap();
+        assert!(is_compressed(&inverse, &knows));
+        let collapsed = store
+            .collapse_paths_degree2(GraphName::new("collapsed"), None)
+            .unwrap();
+        assert!(is_compressed(&collapsed, &knows));
+
+        let added = store
+            .with_added_relationship_type_preserve_name(
+                likes.clone(),
+                vec![vec![MappedNodeId::new(2)], vec![MappedNodeId::ZERO], vec![]],
+                Direction::Directed,
+            )
+            .unwrap();
+        assert!(is_compressed(&added, &likes));
+        let unsorted = store
+            .with_added_relationship_type_preserve_name(
+                likes.clone(),
+                vec![
+                    vec![MappedNodeId::new(2), MappedNodeId::new(1)],
+                    vec![],
+                    vec![],
+                ],
+                Direction::Directed,
+            )
+            .unwrap();
+        assert!(is_compressed(&unsorted, &likes));
+        assert_eq!(
+            unsorted.relationship_topologies[&likes]
+                .outgoing(MappedNodeId::ZERO)
+                .unwrap()
+                .as_ref(),
+            &[MappedNodeId::new(1), MappedNodeId::new(2)]
+        );
+    }
+
+    #[test]
+    fn induced_compressed_subgraph_sorts_rows_with_their_properties() {
+        let knows = RelationshipType::of("KNOWS");
+        // Reversing the node order reverses the order of the targets in every row.
+        let induced = compressed_store()
+            .commit_induced_subgraph_by_original_node_ids(
+                GraphName::new("induced"),
+                &[
+                    OriginalNodeId::new(2),
+                    OriginalNodeId::new(1),
+                    OriginalNodeId::new(0),
+                ],
+            )
+            .unwrap()
+            .store;
+        assert!(induced.relationship_topologies[&knows].is_compressed());
+
+        let weights = induced
+            .relationship_property_values(&knows, "weight")
+            .unwrap();
+        let graph = induced.graph();
+        let outgoing = |node: u64| {
+            graph
+                .stream_relationships(MappedNodeId::new(node), 0.0)
+                .map(|cursor| {
+                    (
+                        cursor.target_id(),
+                        weights.double_value(cursor.relationship_index()).unwrap(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            outgoing(2),
+            vec![(MappedNodeId::ZERO, 2.0), (MappedNodeId::new(1), 1.0)]
+        );
+        assert_eq!(outgoing(1), vec![(MappedNodeId::ZERO, 3.0)]);
+    }
+
+    #[test]
+    fn undirected_view_of_compressed_topology_sorts_merged_rows() {
+        let knows = RelationshipType::of("KNOWS");
+        // Node 2 sees source 1 before its own target 0 while the rows are merged.
+        let topology = RelationshipTopology::try_new_compressed(
+            vec![vec![], vec![MappedNodeId::new(2)], vec![MappedNodeId::ZERO]],
+            None,
+        )
+        .unwrap();
+        let store = sample_store()
+            .with_rebuilt_relationship_topologies(
+                GraphName::new("compressed"),
+                HashMap::from([(knows, topology)]),
+            )
+            .unwrap();
+
+        let graph = store
+            .get_graph_with_types_selectors_and_orientation(
+                &HashSet::new(),
+                &HashMap::new(),
+                Orientation::Undirected,
+            )
+            .unwrap();
+        let targets = graph
+            .stream_relationships(MappedNodeId::new(2), 0.0)
+            .map(|cursor| cursor.target_id())
+            .collect::<Vec<_>>();
+        assert_eq!(targets, vec![MappedNodeId::ZERO, MappedNodeId::new(1)]);
+    }
 }
@@ -4,6 +4,7 @@
 //! The GraphStore module provides the main orchestration layer for graph data management,
 //! including schema, properties, topology, and filtered views.
 
+mod append;
 mod capabilities;
 mod database_id;
 mod database_info;
@@ -18,6 +19,7 @@ mod miscellaneous_tests;
 mod schema_validation;
 mod shell_store_control;
 
+pub use append::*;
 pub use capabilities::*;
 pub use database_id::*;
 pub use database_info::*;
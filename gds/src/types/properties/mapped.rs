@@ -0,0 +1,470 @@
+//! Memory-mapped column files for out-of-core graph data.
+//!
+//! A mapped file is a 64-byte header followed by 8-byte aligned little-endian sections. Readers
+//! view the sections in place, so values come straight from the page cache: the kernel pages
+//! data in on first access and evicts it under memory pressure, which lets read-only
+//! algorithms run on graphs larger than RAM.
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Seek, SeekFrom, Write};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use memmap2::Mmap;
+use thiserror::Error;
+
+const MAGIC: [u8; 8] = *b"GDSMMAP1";
+const HEADER_SIZE: usize = 64;
+const SECTION_ALIGNMENT: usize = 8;
+
+/// Content of a mapped file, recorded in its header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappedFileKind {
+    LongValues = 1,
+    DoubleValues = 2,
+    LongArrayValues = 3,
+    DoubleArrayValues = 4,
+    Adjacency = 5,
+}
+
+impl MappedFileKind {
+    fn from_code(code: u32) -> Option<Self> {
+        match code {
+            1 => Some(Self::LongValues),
+            2 => Some(Self::DoubleValues),
+            3 => Some(Self::LongArrayValues),
+            4 => Some(Self::DoubleArrayValues),
+            5 => Some(Self::Adjacency),
+            _ => None,
+        }
+    }
+}
+
+/// Expected access pattern of a mapped structure, passed to the kernel as `madvise` hints.
+///
+/// Full scans such as PageRank or WCC iterations should use [`AccessPattern::Sequential`] for
+/// aggressive read-ahead. Point lookups such as random walks or link prediction should use
+/// [`AccessPattern::Random`] so that pages which are never touched are not read ahead.
+/// Hints are ignored on non-Unix targets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AccessPattern {
+    #[default]
+    Normal,
+    Sequential,
+    Random,
+    /// Start reading the whole file into the page cache now.
+    WillNeed,
+}
+
+#[derive(Debug, Error)]
+pub enum MappedFileError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("not a mapped column file")]
+    BadMagic,
+
+    #[error("expected a {expected:?} file but found kind {found}")]
+    KindMismatch {
+        expected: MappedFileKind,
+        found: u32,
+    },
+
+    #[error("invalid mapped data: {0}")]
+    Invalid(String),
+
+    #[error("mapped files require a little-endian target")]
+    UnsupportedPlatform,
+}
+
+/// Plain little-endian element types that can be viewed in place.
+pub trait MappedElement: Copy + Send + Sync + 'static + sealed::Sealed {
+    #[doc(hidden)]
+    fn write_le(self, out: &mut impl Write) -> io::Result<()>;
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for u8 {}
+    impl Sealed for u64 {}
+    impl Sealed for i64 {}
+    impl Sealed for f64 {}
+}
+
+macro_rules! mapped_element {
+    ($($ty:ty),*) => {
+        $(impl MappedElement for $ty {
+            fn write_le(self, out: &mut impl Write) -> io::Result<()> {
+                out.write_all(&self.to_le_bytes())
+            }
+        })*
+    };
+}
+
+mapped_element!(u8, u64, i64, f64);
+
+/// An open mapped file and its header fields.
+#[derive(Clone)]
+pub struct MappedFile {
+    mmap: Arc<Mmap>,
+    kind: MappedFileKind,
+    count: usize,
+    aux: u64,
+}
+
+impl fmt::Debug for MappedFile {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("MappedFile")
+            .field("kind", &self.kind)
+            .field("count", &self.count)
+            .field("len", &self.mmap.len())
+            .finish()
+    }
+}
+
+impl MappedFile {
+    /// Maps `path` read-only and applies the `access` hint.
+    pub fn open(path: &Path, access: AccessPattern) -> Result<Self, MappedFileError> {
+        if cfg!(target_endian = "big") {
+            return Err(MappedFileError::UnsupportedPlatform);
+        }
+        let file = File::open(path)?;
+        // SAFETY: the mapping is read-only and mapped files are immutable once written; they
+        // must not be modified or truncated while a mapping is alive. `MappedFileWriter`
+        // renames a new file over an existing one, so live mappings keep the old contents.
+        let mmap = unsafe { Mmap::map(&file)? };
+        if mmap.len() < HEADER_SIZE || mmap[..8] != MAGIC {
+            return Err(MappedFileError::BadMagic);
+        }
+        let code = u32::from_le_bytes(mmap[8..12].try_into().expect("4 header bytes"));
+        let kind = MappedFileKind::from_code(code)
+            .ok_or_else(|| MappedFileError::Invalid(format!("unknown mapped file kind {code}")))?;
+        let read_u64 =
+            |at: usize| u64::from_le_bytes(mmap[at..at + 8].try_into().expect("8 bytes"));
+        let (count, aux) = (read_u64(16), read_u64(24));
+        // Every kind stores at least one byte per counted element.
+        let count = usize::try_from(count)
+            .ok()
+            .filter(|&count| count <= mmap.len())
+            .ok_or_else(|| {
+                MappedFileError::Invalid(format!(
+                    "header count {count} exceeds file length {}",
+                    mmap.len()
+                ))
+            })?;
+
+        let file = Self {
+            mmap: Arc::new(mmap),
+            kind,
+            count,
+            aux,
+        };
+        file.advise(access)?;
+        Ok(file)
+    }
+
+    /// Opens `path` and checks that it holds `kind`.
+    pub fn open_kind(
+        path: &Path,
+        kind: MappedFileKind,
+        access: AccessPattern,
+    ) -> Result<Self, MappedFileError> {
+        let file = Self::open(path, access)?;
+        if file.kind != kind {
+            return Err(MappedFileError::KindMismatch {
+                expected: kind,
+                found: file.kind as u32,
+            });
+        }
+        Ok(file)
+    }
+
+    pub fn kind(&self) -> MappedFileKind {
+        self.kind
+    }
+
+    /// Number of elements (values or nodes) described by the file.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Kind-specific header word.
+    pub fn aux(&self) -> u64 {
+        self.aux
+    }
+
+    pub fn advise(&self, access: AccessPattern) -> io::Result<()> {
+        advise(&self.mmap, access)
+    }
+
+    /// Views the sections of the file, in write order, as slices of `lengths[i]` elements.
+    pub(crate) fn sections(&self) -> SectionReader<'_> {
+        SectionReader {
+            file: self,
+            position: HEADER_SIZE,
+        }
+    }
+}
+
+/// Reads consecutive sections of a [`MappedFile`].
+pub(crate) struct SectionReader<'a> {
+    file: &'a MappedFile,
+    position: usize,
+}
+
+impl SectionReader<'_> {
+    pub(crate) fn next<T: MappedElement>(
+        &mut self,
+        len: usize,
+    ) -> Result<MappedSlice<T>, MappedFileError> {
+        let bytes = len
+            .checked_mul(std::mem::size_of::<T>())
+            .ok_or_else(|| MappedFileError::Invalid("section length overflows".into()))?;
+        let end = self
+            .position
+            .checked_add(bytes)
+            .filter(|&end| end <= self.file.mmap.len())
+            .ok_or_else(|| {
+                MappedFileError::Invalid(format!(
+                    "section of {len} elements at byte {} exceeds file length {}",
+                    self.position,
+                    self.file.mmap.len()
+                ))
+            })?;
+        let slice = MappedSlice {
+            mmap: Arc::clone(&self.file.mmap),
+            offset: self.position,
+            len,
+            _element: PhantomData,
+        };
+        self.position = end.next_multiple_of(SECTION_ALIGNMENT);
+        Ok(slice)
+    }
+}
+
+/// A typed, zero-copy view of one section of a mapped file.
+pub struct MappedSlice<T: MappedElement> {
+    mmap: Arc<Mmap>,
+    offset: usize,
+    len: usize,
+    _element: PhantomData<T>,
+}
+
+impl<T: MappedElement> MappedSlice<T> {
+    /// Applies an access hint to the whole file this slice belongs to.
+    pub fn advise(&self, access: AccessPattern) -> io::Result<()> {
+        advise(&self.mmap, access)
+    }
+}
+
+impl<T: MappedElement> Deref for MappedSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: the section lies within the mapping (checked by `SectionReader::next`),
+        // starts at a multiple of 8 bytes from the page-aligned mapping base, holds
+        // little-endian values on a little-endian target (checked by `MappedFile::open`), and
+        // every bit pattern is a valid `T`. The mapping lives as long as `self.mmap`.
+        unsafe {
+            std::slice::from_raw_parts(self.mmap.as_ptr().add(self.offset).cast::<T>(), self.len)
+        }
+    }
+}
+
+impl<T: MappedElement> Clone for MappedSlice<T> {
+    fn clone(&self) -> Self {
+        Self {
+            mmap: Arc::clone(&self.mmap),
+            offset: self.offset,
+            len: self.len,
+            _element: PhantomData,
+        }
+    }
+}
+
+impl<T: MappedElement> fmt::Debug for MappedSlice<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("MappedSlice")
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+/// Streams a mapped file to disk: header first, then 8-byte aligned sections.
+///
+/// The file is written next to its target and renamed over it by
+/// [`MappedFileWriter::finish`], so a file that is still mapped is replaced rather than
+/// truncated under the mapping. A writer dropped before `finish` removes its partial file.
+pub(crate) struct MappedFileWriter {
+    out: BufWriter<File>,
+    position: usize,
+    path: PathBuf,
+    temp_path: PathBuf,
+    finished: bool,
+}
+
+impl MappedFileWriter {
+    pub(crate) fn create(
+        path: &Path,
+        kind: MappedFileKind,
+        count: usize,
+        aux: u64,
+    ) -> Result<Self, MappedFileError> {
+        let file_name = path.file_name().ok_or_else(|| {
+            MappedFileError::Invalid(format!("{} does not name a file", path.display()))
+        })?;
+        let mut temp_name = std::ffi::OsString::from(".");
+        temp_name.push(file_name);
+        temp_name.push(format!(".{}.tmp", uuid::Uuid::new_v4()));
+        let temp_path = path.with_file_name(temp_name);
+        let mut out = BufWriter::new(File::create_new(&temp_path)?);
+        let mut header = [0u8; HEADER_SIZE];
+        header[..8].copy_from_slice(&MAGIC);
+        header[8..12].copy_from_slice(&(kind as u32).to_le_bytes());
+        header[16..24].copy_from_slice(&(count as u64).to_le_bytes());
+        header[24..32].copy_from_slice(&aux.to_le_bytes());
+        out.write_all(&header)?;
+        Ok(Self {
+            out,
+            position: HEADER_SIZE,
+            path: path.to_path_buf(),
+            temp_path,
+            finished: false,
+        })
+    }
+
+    /// Writes one section and returns the number of elements written.
+    pub(crate) fn section<T: MappedElement>(
+        &mut self,
+        values: impl IntoIterator<Item = T>,
+    ) -> Result<usize, MappedFileError> {
+        let mut len = 0;
+        for value in values {
+            value.write_le(&mut self.out)?;
+            len += 1;
+        }
+        self.position += len * std::mem::size_of::<T>();
+        let padded = self.position.next_multiple_of(SECTION_ALIGNMENT);
+        self.out
+            .write_all(&[0; SECTION_ALIGNMENT][..padded - self.position])?;
+        self.position = padded;
+        Ok(len)
+    }
+
+    /// Overwrites the header's aux word once it is known.
+    pub(crate) fn set_aux(&mut self, aux: u64) -> Result<(), MappedFileError> {
+        self.out.seek(SeekFrom::Start(24))?;
+        self.out.write_all(&aux.to_le_bytes())?;
+        self.out.seek(SeekFrom::Start(self.position as u64))?;
+        Ok(())
+    }
+
+    /// Syncs the file and moves it to its target path, replacing any file there.
+    pub(crate) fn finish(mut self) -> Result<(), MappedFileError> {
+        self.out.flush()?;
+        self.out.get_ref().sync_all()?;
+        fs::rename(&self.temp_path, &self.path)?;
+        self.finished = true;
+        Ok(())
+    }
+}
+
+impl Drop for MappedFileWriter {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = fs::remove_file(&self.temp_path);
+        }
+    }
+}
+
+#[cfg(unix)]
+fn advise(mmap: &Mmap, access: AccessPattern) -> io::Result<()> {
+    use memmap2::Advice;
+
+    let advice = match access {
+        AccessPattern::Normal => Advice::Normal,
+        AccessPattern::Sequential => Advice::Sequential,
+        AccessPattern::Random => Advice::Random,
+        AccessPattern::WillNeed => Advice::WillNeed,
+    };
+    mmap.advise(advice)
+}
+
+#[cfg(not(unix))]
+fn advise(_mmap: &Mmap, _access: AccessPattern) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_round_trip_with_alignment() {
+        let path = std::env::temp_dir().join(format!("gds-mapped-{}", uuid::Uuid::new_v4()));
+        let mut writer = MappedFileWriter::create(&path, MappedFileKind::Adjacency, 3, 0).unwrap();
+        writer.section([1u8, 2, 3]).unwrap();
+        writer.section([-4i64, 5]).unwrap();
+        writer.set_aux(9).unwrap();
+        writer.finish().unwrap();
+
+        let file = MappedFile::open(&path, AccessPattern::Sequential).unwrap();
+        assert_eq!(
+            (file.kind(), file.count(), file.aux()),
+            (MappedFileKind::Adjacency, 3, 9)
+        );
+        let mut sections = file.sections();
+        assert_eq!(&*sections.next::<u8>(3).unwrap(), &[1, 2, 3]);
+        assert_eq!(&*sections.next::<i64>(2).unwrap(), &[-4, 5]);
+        assert!(sections.next::<i64>(1).is_err());
+        assert!(matches!(
+            MappedFile::open_kind(&path, MappedFileKind::LongValues, AccessPattern::Random),
+            Err(MappedFileError::KindMismatch { found: 5, .. })
+        ));
+
+        // Mapped files must not change under a live mapping.
+        drop(sections);
+        drop(file);
+        std::fs::write(&path, b"not mapped").unwrap();
+        assert!(matches!(
+            MappedFile::open(&path, AccessPattern::Normal),
+            Err(MappedFileError::BadMagic)
+        ));
+        std::fs::remove_file(path).unwrap();
+    }
+    #[test]
+    fn rewriting_a_mapped_file_leaves_open_mappings_intact() {
+        let dir = std::env::temp_dir().join(format!("gds-mapped-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&dir).unwrap();
+        let path = dir.join("values");
+        let write = |values: [i64; 2]| {
+            let mut writer =
+                MappedFileWriter::create(&path, MappedFileKind::LongValues, 2, 0).unwrap();
+            writer.section(values).unwrap();
+            writer.finish().unwrap();
+        };
+        write([1, 2]);
+        let old = MappedFile::open(&path, AccessPattern::Normal).unwrap();
+        let old_values = old.sections().next::<i64>(2).unwrap();
+
+        write([3, 4]);
+        assert_eq!(&*old_values, &[1, 2]);
+        let new = MappedFile::open(&path, AccessPattern::Normal).unwrap();
+        assert_eq!(&*new.sections().next::<i64>(2).unwrap(), &[3, 4]);
+
+        // An unfinished writer leaves neither a partial file nor a changed target.
+        let mut writer = MappedFileWriter::create(&path, MappedFileKind::LongValues, 2, 0).unwrap();
+        writer.section([5i64, 6]).unwrap();
+        drop(writer);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+        let reopened = MappedFile::open(&path, AccessPattern::Normal).unwrap();
+        assert_eq!(&*reopened.sections().next::<i64>(2).unwrap(), &[3, 4]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,4 +1,5 @@
 // Base property system
+pub mod mapped;
 pub mod property;
 pub mod property_store;
 pub mod property_values;
@@ -9,6 +10,7 @@ pub mod node;
 pub mod relationship;
 
 // Re-export base property system for convenience
+pub use mapped::*;
 pub use property::*;
 pub use property_store::*;
 pub use property_values::*;
@@ -0,0 +1,280 @@
+//! Node property values held in compressed pages.
+//!
+//! Long columns with few distinct values or a narrow range, such as community ids or component
+//! labels, take a fraction of the memory of a plain column. Lookups decode one value from one
+//! page, so algorithms read them like any other property.
+
+use crate::collections::extensions::compressed_pages::CompressedColumn;
+use crate::collections::extensions::compression::{CompressionAlgorithm, CompressionError};
+use crate::types::properties::node::{
+    DoubleNodePropertyValues, LongNodePropertyValues, NodePropertyValues,
+};
+use crate::types::properties::property_values::{
+    checked_double_to_long_property, checked_long_to_double_property,
+};
+use crate::types::properties::{PropertyValues, PropertyValuesError, PropertyValuesResult};
+use crate::types::ValueType;
+
+/// Long node properties stored in compressed pages.
+#[derive(Debug, Clone)]
+pub struct CompressedLongNodePropertyValues {
+    values: CompressedColumn<i64>,
+    max_value: Option<i64>,
+}
+
+impl CompressedLongNodePropertyValues {
+    /// Compresses one value per node, in node id order.
+    pub fn compress(
+        values: &[i64],
+        algorithm: &CompressionAlgorithm,
+    ) -> Result<Self, CompressionError> {
+        Ok(Self {
+            values: CompressedColumn::compress(values, algorithm)?,
+            max_value: values.iter().copied().max(),
+        })
+    }
+
+    /// Compresses any long node property column.
+    pub fn from_values(
+        values: &dyn NodePropertyValues,
+        algorithm: &CompressionAlgorithm,
+    ) -> Result<Self, CompressionError> {
+        let column = (0..values.node_count() as u64)
+            .map(|node| values.long_value(node))
+            .collect::<PropertyValuesResult<Vec<_>>>()
+            .map_err(|error| CompressionError::CompressionFailed(error.to_string()))?;
+        Self::compress(&column, algorithm)
+    }
+
+    pub fn size_in_bytes(&self) -> usize {
+        self.values.size_in_bytes()
+    }
+
+    /// Compressed size over the size of a plain `i64` column.
+    pub fn compression_ratio(&self) -> f64 {
+        self.values.compression_ratio()
+    }
+
+    fn value(&self, node_id: u64) -> PropertyValuesResult<i64> {
+        usize::try_from(node_id)
+            .ok()
+            .and_then(|index| self.values.get(index))
+            .ok_or(PropertyValuesError::InvalidNodeId(node_id))
+    }
+}
+
+/// Double node properties stored in compressed pages.
+#[derive(Debug, Clone)]
+pub struct CompressedDoubleNodePropertyValues {
+    values: CompressedColumn<f64>,
+    max_value: Option<f64>,
+}
+
+impl CompressedDoubleNodePropertyValues {
+    /// Compresses one value per node, in node id order.
+    pub fn compress(
+        values: &[f64],
+        algorithm: &CompressionAlgorithm,
+    ) -> Result<Self, CompressionError> {
+        Ok(Self {
+            values: CompressedColumn::compress(values, algorithm)?,
+            max_value: values.iter().copied().reduce(f64::max),
+        })
+    }
+
+    /// Compresses any double node property column.
+    pub fn from_values(
+        values: &dyn NodePropertyValues,
+        algorithm: &CompressionAlgorithm,
+    ) -> Result<Self, CompressionError> {
+        let column = (0..values.node_count() as u64)
+            .map(|node| values.double_value(node))
+            .collect::<PropertyValuesResult<Vec<_>>>()
+            .map_err(|error| CompressionError::CompressionFailed(error.to_string()))?;
+        Self::compress(&column, algorithm)
+    }
+
+    pub fn size_in_bytes(&self) -> usize {
+        self.values.size_in_bytes()
+    }
+
+    /// Compressed size over the size of a plain `f64` column.
+    pub fn compression_ratio(&self) -> f64 {
+        self.values.compression_ratio()
+    }
+
+    fn value(&self, node_id: u64) -> PropertyValuesResult<f64> {
+        usize::try_from(node_id)
+            .ok()
+            .and_then(|index| self.values.get(index))
+            .ok_or(PropertyValuesError::InvalidNodeId(node_id))
+    }
+}
+
+impl PropertyValues for CompressedLongNodePropertyValues {
+    fn value_type(&self) -> ValueType {
+        ValueType::Long
+    }
+
+    fn element_count(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl NodePropertyValues for CompressedLongNodePropertyValues {
+    fn double_value(&self, node_id: u64) -> PropertyValuesResult<f64> {
+        checked_long_to_double_property(self.value(node_id)?)
+    }
+
+    fn long_value(&self, node_id: u64) -> PropertyValuesResult<i64> {
+        self.value(node_id)
+    }
+
+    fn double_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<f64>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Long,
+            ValueType::DoubleArray,
+        ))
+    }
+
+    fn float_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<f32>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Long,
+            ValueType::FloatArray,
+        ))
+    }
+
+    fn long_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<i64>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Long,
+            ValueType::LongArray,
+        ))
+    }
+
+    fn get_object(&self, node_id: u64) -> PropertyValuesResult<Box<dyn std::any::Any>> {
+        Ok(Box::new(self.value(node_id)?))
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn get_max_long_property_value(&self) -> Option<i64> {
+        self.max_value
+    }
+
+    fn get_max_double_property_value(&self) -> Option<f64> {
+        None
+    }
+
+    fn has_value(&self, node_id: u64) -> bool {
+        node_id < self.values.len() as u64
+    }
+}
+
+impl LongNodePropertyValues for CompressedLongNodePropertyValues {
+    fn long_value_unchecked(&self, node_id: u64) -> i64 {
+        self.value(node_id).unwrap_or_default()
+    }
+}
+
+impl PropertyValues for CompressedDoubleNodePropertyValues {
+    fn value_type(&self) -> ValueType {
+        ValueType::Double
+    }
+
+    fn element_count(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl NodePropertyValues for CompressedDoubleNodePropertyValues {
+    fn double_value(&self, node_id: u64) -> PropertyValuesResult<f64> {
+        self.value(node_id)
+    }
+
+    fn long_value(&self, node_id: u64) -> PropertyValuesResult<i64> {
+        checked_double_to_long_property(self.value(node_id)?)
+    }
+
+    fn double_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<f64>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Double,
+            ValueType::DoubleArray,
+        ))
+    }
+
+    fn float_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<f32>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Double,
+            ValueType::FloatArray,
+        ))
+    }
+
+    fn long_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<i64>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Double,
+            ValueType::LongArray,
+        ))
+    }
+
+    fn get_object(&self, node_id: u64) -> PropertyValuesResult<Box<dyn std::any::Any>> {
+        Ok(Box::new(self.value(node_id)?))
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn get_max_long_property_value(&self) -> Option<i64> {
+        None
+    }
+
+    fn get_max_double_property_value(&self) -> Option<f64> {
+        self.max_value
+    }
+
+    fn has_value(&self, node_id: u64) -> bool {
+        node_id < self.values.len() as u64
+    }
+}
+
+impl DoubleNodePropertyValues for CompressedDoubleNodePropertyValues {
+    fn double_value_unchecked(&self, node_id: u64) -> f64 {
+        self.value(node_id).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn community_ids_use_a_fraction_of_the_memory() {
+        let communities: Vec<i64> = (0..50_000).map(|node| (node % 40) * 1_000 + 7).collect();
+        let values =
+            CompressedLongNodePropertyValues::compress(&communities, &CompressionAlgorithm::Auto)
+                .unwrap();
+        assert!(values.size_in_bytes() * 5 < communities.len() * 8);
+        assert_eq!(values.node_count(), 50_000);
+        assert_eq!(values.long_value(12_345).unwrap(), communities[12_345]);
+        assert_eq!(values.long_value_unchecked(49_999), communities[49_999]);
+        assert_eq!(values.get_max_long_property_value(), Some(39_007));
+        assert!(matches!(
+            values.long_value(50_000),
+            Err(PropertyValuesError::InvalidNodeId(50_000))
+        ));
+
+        let scores = CompressedDoubleNodePropertyValues::compress(
+            &[0.5, 0.5, 2.0],
+            &CompressionAlgorithm::Dictionary,
+        )
+        .unwrap();
+        assert_eq!(scores.double_value(2).unwrap(), 2.0);
+        assert_eq!(scores.get_max_double_property_value(), Some(2.0));
+        let copy =
+            CompressedDoubleNodePropertyValues::from_values(&scores, &CompressionAlgorithm::Auto)
+                .unwrap();
+        assert_eq!(copy.double_value_unchecked(0), 0.5);
+    }
+}
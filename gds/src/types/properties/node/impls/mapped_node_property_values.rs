@@ -0,0 +1,628 @@
+//! Node property values read from memory-mapped column files.
+//!
+//! Scalar columns hold one value per node. Array columns hold a presence bitmap, `node_count + 1`
+//! offsets and the concatenated elements, so a lookup touches two pages at most and a full scan
+//! streams through the file front to back.
+
+use std::path::Path;
+
+use crate::types::properties::mapped::{
+    AccessPattern, MappedElement, MappedFile, MappedFileError, MappedFileKind, MappedFileWriter,
+    MappedSlice,
+};
+use crate::types::properties::node::{
+    DoubleArrayNodePropertyValues, DoubleNodePropertyValues, LongArrayNodePropertyValues,
+    LongNodePropertyValues, NodePropertyValues,
+};
+use crate::types::properties::property_values::{
+    checked_double_to_long_property, checked_long_to_double_property,
+};
+use crate::types::properties::{PropertyValues, PropertyValuesError, PropertyValuesResult};
+use crate::types::ValueType;
+
+/// Header `aux` of array files whose arrays differ in length.
+const VARIABLE_DIMENSION: u64 = u64::MAX;
+
+/// Long node properties backed by a mapped file.
+#[derive(Debug, Clone)]
+pub struct MappedLongNodePropertyValues {
+    values: MappedSlice<i64>,
+}
+
+impl MappedLongNodePropertyValues {
+    /// Writes one value per node, in node id order.
+    pub fn write(path: &Path, values: &[i64]) -> Result<(), MappedFileError> {
+        let mut writer =
+            MappedFileWriter::create(path, MappedFileKind::LongValues, values.len(), 0)?;
+        writer.section(values.iter().copied())?;
+        writer.finish()
+    }
+
+    pub fn open(path: &Path, access: AccessPattern) -> Result<Self, MappedFileError> {
+        let file = MappedFile::open_kind(path, MappedFileKind::LongValues, access)?;
+        let values = file.sections().next(file.count())?;
+        Ok(Self { values })
+    }
+
+    fn value(&self, node_id: u64) -> PropertyValuesResult<i64> {
+        usize::try_from(node_id)
+            .ok()
+            .and_then(|index| self.values.get(index).copied())
+            .ok_or(PropertyValuesError::InvalidNodeId(node_id))
+    }
+}
+
+/// Double node properties backed by a mapped file.
+#[derive(Debug, Clone)]
+pub struct MappedDoubleNodePropertyValues {
+    values: MappedSlice<f64>,
+}
+
+impl MappedDoubleNodePropertyValues {
+    /// Writes one value per node, in node id order.
+    pub fn write(path: &Path, values: &[f64]) -> Result<(), MappedFileError> {
+        let mut writer =
+            MappedFileWriter::create(path, MappedFileKind::DoubleValues, values.len(), 0)?;
+        writer.section(values.iter().copied())?;
+        writer.finish()
+    }
+
+    pub fn open(path: &Path, access: AccessPattern) -> Result<Self, MappedFileError> {
+        let file = MappedFile::open_kind(path, MappedFileKind::DoubleValues, access)?;
+        let values = file.sections().next(file.count())?;
+        Ok(Self { values })
+    }
+
+    fn value(&self, node_id: u64) -> PropertyValuesResult<f64> {
+        usize::try_from(node_id)
+            .ok()
+            .and_then(|index| self.values.get(index).copied())
+            .ok_or(PropertyValuesError::InvalidNodeId(node_id))
+    }
+}
+
+/// Presence bitmap, offsets and elements of a mapped array column.
+#[derive(Debug, Clone)]
+struct MappedArrays<T: MappedElement> {
+    present: MappedSlice<u8>,
+    offsets: MappedSlice<u64>,
+    values: MappedSlice<T>,
+    dimension: Option<usize>,
+}
+
+impl<T: MappedElement> MappedArrays<T> {
+    fn write(
+        path: &Path,
+        kind: MappedFileKind,
+        arrays: &[Option<Vec<T>>],
+    ) -> Result<(), MappedFileError> {
+        let mut writer = MappedFileWriter::create(path, kind, arrays.len(), VARIABLE_DIMENSION)?;
+        let mut bitmap = vec![0u8; arrays.len().div_ceil(8)];
+        for (node, array) in arrays.iter().enumerate() {
+            if array.is_some() {
+                bitmap[node / 8] |= 1 << (node % 8);
+            }
+        }
+        writer.section(bitmap)?;
+        let lengths = arrays
+            .iter()
+            .map(|array| array.as_ref().map_or(0, Vec::len));
+        writer.section(std::iter::once(0u64).chain(lengths.scan(0u64, |end, len| {
+            *end += len as u64;
+            Some(*end)
+        })))?;
+        writer.section(arrays.iter().flatten().flatten().copied())?;
+
+        let mut present = arrays.iter().flatten().map(Vec::len);
+        if let Some(first) = present.next() {
+            if present.all(|len| len == first) {
+                writer.set_aux(first as u64)?;
+            }
+        }
+        writer.finish()
+    }
+
+    fn open(
+        path: &Path,
+        kind: MappedFileKind,
+        access: AccessPattern,
+    ) -> Result<Self, MappedFileError> {
+        let file = MappedFile::open_kind(path, kind, access)?;
+        let count = file.count();
+        let mut sections = file.sections();
+        let present = sections.next(count.div_ceil(8))?;
+        let offsets: MappedSlice<u64> = sections.next(count + 1)?;
+        let value_count = offsets[count] as usize;
+        if offsets.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(MappedFileError::Invalid(
+                "array offsets are not sorted".into(),
+            ));
+        }
+        let values = sections.next(value_count)?;
+        let dimension = (file.aux() != VARIABLE_DIMENSION).then_some(file.aux() as usize);
+        Ok(Self {
+            present,
+            offsets,
+            values,
+            dimension,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn has_value(&self, node_id: u64) -> bool {
+        usize::try_from(node_id)
+            .ok()
+            .filter(|&node| node < self.len())
+            .is_some_and(|node| self.present[node / 8] & (1 << (node % 8)) != 0)
+    }
+
+    fn get(&self, node_id: u64) -> Option<&[T]> {
+        if !self.has_value(node_id) {
+            return None;
+        }
+        let node = node_id as usize;
+        Some(&self.values[self.offsets[node] as usize..self.offsets[node + 1] as usize])
+    }
+}
+
+/// Long array node properties backed by a mapped file.
+#[derive(Debug, Clone)]
+pub struct MappedLongArrayNodePropertyValues {
+    arrays: MappedArrays<i64>,
+}
+
+impl MappedLongArrayNodePropertyValues {
+    /// Writes one optional array per node, in node id order.
+    pub fn write(path: &Path, arrays: &[Option<Vec<i64>>]) -> Result<(), MappedFileError> {
+        MappedArrays::write(path, MappedFileKind::LongArrayValues, arrays)
+    }
+
+    pub fn open(path: &Path, access: AccessPattern) -> Result<Self, MappedFileError> {
+        let arrays = MappedArrays::open(path, MappedFileKind::LongArrayValues, access)?;
+        Ok(Self { arrays })
+    }
+
+    /// Borrows a node's array straight from the mapping.
+    pub fn array(&self, node_id: u64) -> Option<&[i64]> {
+        self.arrays.get(node_id)
+    }
+
+    fn value(&self, node_id: u64) -> PropertyValuesResult<&[i64]> {
+        self.array(node_id)
+            .ok_or(PropertyValuesError::InvalidNodeId(node_id))
+    }
+}
+
+/// Double array node properties backed by a mapped file.
+#[derive(Debug, Clone)]
+pub struct MappedDoubleArrayNodePropertyValues {
+    arrays: MappedArrays<f64>,
+}
+
+impl MappedDoubleArrayNodePropertyValues {
+    /// Writes one optional array per node, in node id order.
+    pub fn write(path: &Path, arrays: &[Option<Vec<f64>>]) -> Result<(), MappedFileError> {
+        MappedArrays::write(path, MappedFileKind::DoubleArrayValues, arrays)
+    }
+
+    pub fn open(path: &Path, access: AccessPattern) -> Result<Self, MappedFileError> {
+        let arrays = MappedArrays::open(path, MappedFileKind::DoubleArrayValues, access)?;
+        Ok(Self { arrays })
+    }
+
+    /// Borrows a node's array straight from the mapping.
+    pub fn array(&self, node_id: u64) -> Option<&[f64]> {
+        self.arrays.get(node_id)
+    }
+
+    fn value(&self, node_id: u64) -> PropertyValuesResult<&[f64]> {
+        self.array(node_id)
+            .ok_or(PropertyValuesError::InvalidNodeId(node_id))
+    }
+}
+
+/// Opens any mapped node property file with the matching implementation.
+pub fn open_mapped_node_property_values(
+    path: &Path,
+    access: AccessPattern,
+) -> Result<std::sync::Arc<dyn NodePropertyValues>, MappedFileError> {
+    let kind = MappedFile::open(path, AccessPattern::Normal)?.kind();
+    Ok(match kind {
+        MappedFileKind::LongValues => {
+            std::sync::Arc::new(MappedLongNodePropertyValues::open(path, access)?)
+        }
+        MappedFileKind::DoubleValues => {
+            std::sync::Arc::new(MappedDoubleNodePropertyValues::open(path, access)?)
+        }
+        MappedFileKind::LongArrayValues => {
+            std::sync::Arc::new(MappedLongArrayNodePropertyValues::open(path, access)?)
+        }
+        MappedFileKind::DoubleArrayValues => {
+            std::sync::Arc::new(MappedDoubleArrayNodePropertyValues::open(path, access)?)
+        }
+        MappedFileKind::Adjacency => {
+            return Err(MappedFileError::Invalid(
+                "adjacency files do not hold node properties".into(),
+            ))
+        }
+    })
+}
+
+/// Writes any long, double, long array or double array node property column.
+pub fn write_mapped_node_property_values(
+    path: &Path,
+    values: &dyn NodePropertyValues,
+) -> Result<(), MappedFileError> {
+    let node_count = values.node_count() as u64;
+    let unsupported = |error: PropertyValuesError| MappedFileError::Invalid(error.to_string());
+    match values.value_type() {
+        ValueType::Long => {
+            let column = (0..node_count)
+                .map(|node| values.long_value(node))
+                .collect::<PropertyValuesResult<Vec<_>>>()
+                .map_err(unsupported)?;
+            MappedLongNodePropertyValues::write(path, &column)
+        }
+        ValueType::Double => {
+            let column = (0..node_count)
+                .map(|node| values.double_value(node))
+                .collect::<PropertyValuesResult<Vec<_>>>()
+                .map_err(unsupported)?;
+            MappedDoubleNodePropertyValues::write(path, &column)
+        }
+        ValueType::LongArray => {
+            let column = (0..node_count)
+                .map(|node| values.long_array_value(node).ok())
+                .collect::<Vec<_>>();
+            MappedLongArrayNodePropertyValues::write(path, &column)
+        }
+        ValueType::DoubleArray => {
+            let column = (0..node_count)
+                .map(|node| values.double_array_value(node).ok())
+                .collect::<Vec<_>>();
+            MappedDoubleArrayNodePropertyValues::write(path, &column)
+        }
+        other => Err(MappedFileError::Invalid(format!(
+            "{other:?} node properties cannot be mapped"
+        ))),
+    }
+}
+
+impl PropertyValues for MappedLongNodePropertyValues {
+    fn value_type(&self) -> ValueType {
+        ValueType::Long
+    }
+
+    fn element_count(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl NodePropertyValues for MappedLongNodePropertyValues {
+    fn double_value(&self, node_id: u64) -> PropertyValuesResult<f64> {
+        checked_long_to_double_property(self.value(node_id)?)
+    }
+
+    fn long_value(&self, node_id: u64) -> PropertyValuesResult<i64> {
+        self.value(node_id)
+    }
+
+    fn double_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<f64>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Long,
+            ValueType::DoubleArray,
+        ))
+    }
+
+    fn float_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<f32>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Long,
+            ValueType::FloatArray,
+        ))
+    }
+
+    fn long_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<i64>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Long,
+            ValueType::LongArray,
+        ))
+    }
+
+    fn get_object(&self, node_id: u64) -> PropertyValuesResult<Box<dyn std::any::Any>> {
+        Ok(Box::new(self.value(node_id)?))
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn get_max_long_property_value(&self) -> Option<i64> {
+        self.values.iter().copied().max()
+    }
+
+    fn get_max_double_property_value(&self) -> Option<f64> {
+        None
+    }
+
+    fn has_value(&self, node_id: u64) -> bool {
+        node_id < self.values.len() as u64
+    }
+}
+
+impl LongNodePropertyValues for MappedLongNodePropertyValues {
+    fn long_value_unchecked(&self, node_id: u64) -> i64 {
+        self.values[node_id as usize]
+    }
+}
+
+impl PropertyValues for MappedDoubleNodePropertyValues {
+    fn value_type(&self) -> ValueType {
+        ValueType::Double
+    }
+
+    fn element_count(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl NodePropertyValues for MappedDoubleNodePropertyValues {
+    fn double_value(&self, node_id: u64) -> PropertyValuesResult<f64> {
+        self.value(node_id)
+    }
+
+    fn long_value(&self, node_id: u64) -> PropertyValuesResult<i64> {
+        checked_double_to_long_property(self.value(node_id)?)
+    }
+
+    fn double_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<f64>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Double,
+            ValueType::DoubleArray,
+        ))
+    }
+
+    fn float_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<f32>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Double,
+            ValueType::FloatArray,
+        ))
+    }
+
+    fn long_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<i64>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::Double,
+            ValueType::LongArray,
+        ))
+    }
+
+    fn get_object(&self, node_id: u64) -> PropertyValuesResult<Box<dyn std::any::Any>> {
+        Ok(Box::new(self.value(node_id)?))
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn get_max_long_property_value(&self) -> Option<i64> {
+        None
+    }
+
+    fn get_max_double_property_value(&self) -> Option<f64> {
+        self.values.iter().copied().reduce(f64::max)
+    }
+
+    fn has_value(&self, node_id: u64) -> bool {
+        node_id < self.values.len() as u64
+    }
+}
+
+impl DoubleNodePropertyValues for MappedDoubleNodePropertyValues {
+    fn double_value_unchecked(&self, node_id: u64) -> f64 {
+        self.values[node_id as usize]
+    }
+}
+
+impl PropertyValues for MappedLongArrayNodePropertyValues {
+    fn value_type(&self) -> ValueType {
+        ValueType::LongArray
+    }
+
+    fn element_count(&self) -> usize {
+        self.arrays.len()
+    }
+}
+
+impl NodePropertyValues for MappedLongArrayNodePropertyValues {
+    fn double_value(&self, _node_id: u64) -> PropertyValuesResult<f64> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::LongArray,
+            ValueType::Double,
+        ))
+    }
+
+    fn long_value(&self, _node_id: u64) -> PropertyValuesResult<i64> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::LongArray,
+            ValueType::Long,
+        ))
+    }
+
+    fn double_array_value(&self, node_id: u64) -> PropertyValuesResult<Vec<f64>> {
+        Ok(self.value(node_id)?.iter().map(|&v| v as f64).collect())
+    }
+
+    fn float_array_value(&self, node_id: u64) -> PropertyValuesResult<Vec<f32>> {
+        Ok(self.value(node_id)?.iter().map(|&v| v as f32).collect())
+    }
+
+    fn long_array_value(&self, node_id: u64) -> PropertyValuesResult<Vec<i64>> {
+        Ok(self.value(node_id)?.to_vec())
+    }
+
+    fn get_object(&self, node_id: u64) -> PropertyValuesResult<Box<dyn std::any::Any>> {
+        Ok(Box::new(self.value(node_id)?.to_vec()))
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.arrays.dimension
+    }
+
+    fn get_max_long_property_value(&self) -> Option<i64> {
+        None
+    }
+
+    fn get_max_double_property_value(&self) -> Option<f64> {
+        None
+    }
+
+    fn has_value(&self, node_id: u64) -> bool {
+        self.arrays.has_value(node_id)
+    }
+}
+
+impl LongArrayNodePropertyValues for MappedLongArrayNodePropertyValues {
+    fn long_array_value_unchecked(&self, node_id: u64) -> Option<Vec<i64>> {
+        self.array(node_id).map(<[i64]>::to_vec)
+    }
+}
+
+impl PropertyValues for MappedDoubleArrayNodePropertyValues {
+    fn value_type(&self) -> ValueType {
+        ValueType::DoubleArray
+    }
+
+    fn element_count(&self) -> usize {
+        self.arrays.len()
+    }
+}
+
+impl NodePropertyValues for MappedDoubleArrayNodePropertyValues {
+    fn double_value(&self, _node_id: u64) -> PropertyValuesResult<f64> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::DoubleArray,
+            ValueType::Double,
+        ))
+    }
+
+    fn long_value(&self, _node_id: u64) -> PropertyValuesResult<i64> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::DoubleArray,
+            ValueType::Long,
+        ))
+    }
+
+    fn double_array_value(&self, node_id: u64) -> PropertyValuesResult<Vec<f64>> {
+        Ok(self.value(node_id)?.to_vec())
+    }
+
+    fn float_array_value(&self, node_id: u64) -> PropertyValuesResult<Vec<f32>> {
+        Ok(self.value(node_id)?.iter().map(|&v| v as f32).collect())
+    }
+
+    fn long_array_value(&self, _node_id: u64) -> PropertyValuesResult<Vec<i64>> {
+        Err(PropertyValuesError::unsupported_type(
+            ValueType::DoubleArray,
+            ValueType::LongArray,
+        ))
+    }
+
+    fn get_object(&self, node_id: u64) -> PropertyValuesResult<Box<dyn std::any::Any>> {
+        Ok(Box::new(self.value(node_id)?.to_vec()))
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.arrays.dimension
+    }
+
+    fn get_max_long_property_value(&self) -> Option<i64> {
+        None
+    }
+
+    fn get_max_double_property_value(&self) -> Option<f64> {
+        None
+    }
+
+    fn has_value(&self, node_id: u64) -> bool {
+        self.arrays.has_value(node_id)
+    }
+}
+
+impl DoubleArrayNodePropertyValues for MappedDoubleArrayNodePropertyValues {
+    fn double_array_value_unchecked(&self, node_id: u64) -> Option<Vec<f64>> {
+        self.array(node_id).map(<[f64]>::to_vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path() -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("gds-mapped-node-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn scalar_columns_round_trip() {
+        let path = temp_path();
+        MappedLongNodePropertyValues::write(&path, &[3, -1, 7]).unwrap();
+        let values = open_mapped_node_property_values(&path, AccessPattern::Sequential).unwrap();
+        assert_eq!(values.value_type(), ValueType::Long);
+        assert_eq!(values.node_count(), 3);
+        assert_eq!(values.long_value(1).unwrap(), -1);
+        assert_eq!(values.double_value(2).unwrap(), 7.0);
+        assert_eq!(values.get_max_long_property_value(), Some(7));
+        assert!(matches!(
+            values.long_value(3),
+            Err(PropertyValuesError::InvalidNodeId(3))
+        ));
+
+        let copy = temp_path();
+        write_mapped_node_property_values(&copy, values.as_ref()).unwrap();
+        let reopened = MappedLongNodePropertyValues::open(&copy, AccessPattern::Random).unwrap();
+        assert_eq!(reopened.long_value_unchecked(2), 7);
+
+        let double_path = temp_path();
+        MappedDoubleNodePropertyValues::write(&double_path, &[0.5, 2.0]).unwrap();
+        let doubles =
+            MappedDoubleNodePropertyValues::open(&double_path, AccessPattern::Random).unwrap();
+        assert_eq!(doubles.double_value_unchecked(0), 0.5);
+        assert_eq!(doubles.long_value(1).unwrap(), 2);
+        assert!(doubles.long_value(0).is_err());
+
+        for path in [path, copy, double_path] {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn array_columns_keep_missing_values_and_dimension() {
+        let path = temp_path();
+        MappedDoubleArrayNodePropertyValues::write(
+            &path,
+            &[Some(vec![1.0, 2.0]), None, Some(vec![3.0, 4.0])],
+        )
+        .unwrap();
+        let values =
+            MappedDoubleArrayNodePropertyValues::open(&path, AccessPattern::Normal).unwrap();
+        assert_eq!(values.dimension(), Some(2));
+        assert_eq!(values.array(2), Some(&[3.0, 4.0][..]));
+        assert!(!values.has_value(1));
+        assert!(values.double_array_value(1).is_err());
+        assert_eq!(values.float_array_value(0).unwrap(), vec![1.0f32, 2.0]);
+
+        let long_path = temp_path();
+        MappedLongArrayNodePropertyValues::write(&long_path, &[Some(vec![1]), Some(vec![2, 3])])
+            .unwrap();
+        let longs =
+            MappedLongArrayNodePropertyValues::open(&long_path, AccessPattern::Normal).unwrap();
+        assert_eq!(longs.dimension(), None);
+        assert_eq!(longs.long_array_value(1).unwrap(), vec![2, 3]);
+        assert_eq!(longs.double_array_value(0).unwrap(), vec![1.0]);
+
+        std::fs::remove_file(path).unwrap();
+        std::fs::remove_file(long_path).unwrap();
+    }
+}
@@ -1,7 +1,11 @@
+pub mod compressed_node_property_values;
 pub mod default_node_property;
 pub mod default_node_property_store;
 pub mod default_node_property_values;
+pub mod mapped_node_property_values;
 
+pub use compressed_node_property_values::*;
 pub use default_node_property::DefaultNodeProperty;
 pub use default_node_property_store::*;
 pub use default_node_property_values::*;
+pub use mapped_node_property_values::*;
@@ -47,6 +47,11 @@ pub trait NodePropertyValues: PropertyValues + std::fmt::Debug + Send + Sync {
 
     /// Returns whether the node has a value.
     fn has_value(&self, node_id: u64) -> bool;
+
+    /// Returns these values as `Any`, for implementations that can be downcast.
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        None
+    }
 }
 
 // ========== Specialized traits for typed node property values ==========
@@ -0,0 +1,232 @@
+//! Relationship property values read from memory-mapped column files.
+//!
+//! Values are stored in relationship index order, one per relationship, with the property's
+//! default value kept in the file header.
+
+use std::path::Path;
+
+use crate::types::graph::RelationshipIndex;
+use crate::types::properties::mapped::{
+    AccessPattern, MappedFile, MappedFileError, MappedFileKind, MappedFileWriter, MappedSlice,
+};
+use crate::types::properties::property_values::{
+    checked_double_to_long_property, checked_long_to_double_property,
+};
+use crate::types::properties::relationship::{
+    DoubleRelationshipPropertyValues, LongRelationshipPropertyValues, RelationshipPropertyValues,
+};
+use crate::types::properties::{PropertyValues, PropertyValuesError, PropertyValuesResult};
+use crate::types::ValueType;
+
+/// Long relationship properties backed by a mapped file.
+#[derive(Debug, Clone)]
+pub struct MappedLongRelationshipPropertyValues {
+    values: MappedSlice<i64>,
+    default_value: i64,
+}
+
+impl MappedLongRelationshipPropertyValues {
+    /// Writes one value per relationship, in relationship index order.
+    pub fn write(path: &Path, values: &[i64], default_value: i64) -> Result<(), MappedFileError> {
+        let mut writer = MappedFileWriter::create(
+            path,
+            MappedFileKind::LongValues,
+            values.len(),
+            default_value as u64,
+        )?;
+        writer.section(values.iter().copied())?;
+        writer.finish()
+    }
+
+    pub fn open(path: &Path, access: AccessPattern) -> Result<Self, MappedFileError> {
+        let file = MappedFile::open_kind(path, MappedFileKind::LongValues, access)?;
+        let values = file.sections().next(file.count())?;
+        Ok(Self {
+            values,
+            default_value: file.aux() as i64,
+        })
+    }
+
+    fn value(&self, rel_index: RelationshipIndex) -> PropertyValuesResult<i64> {
+        rel_index
+            .to_usize()
+            .and_then(|index| self.values.get(index).copied())
+            .ok_or(PropertyValuesError::ValueNotFound(rel_index.get()))
+    }
+}
+
+/// Double relationship properties backed by a mapped file.
+#[derive(Debug, Clone)]
+pub struct MappedDoubleRelationshipPropertyValues {
+    values: MappedSlice<f64>,
+    default_value: f64,
+}
+
+impl MappedDoubleRelationshipPropertyValues {
+    /// Writes one value per relationship, in relationship index order.
+    pub fn write(path: &Path, values: &[f64], default_value: f64) -> Result<(), MappedFileError> {
+        let mut writer = MappedFileWriter::create(
+            path,
+            MappedFileKind::DoubleValues,
+            values.len(),
+            default_value.to_bits(),
+        )?;
+        writer.section(values.iter().copied())?;
+        writer.finish()
+    }
+
+    /// Copies any relationship property column into a mapped double file.
+    pub fn write_from(
+        path: &Path,
+        values: &dyn RelationshipPropertyValues,
+    ) -> Result<(), MappedFileError> {
+        let column = (0..values.relationship_count() as u64)
+            .map(|index| values.double_value(RelationshipIndex::new(index)))
+            .collect::<PropertyValuesResult<Vec<_>>>()
+            .map_err(|error| MappedFileError::Invalid(error.to_string()))?;
+        Self::write(path, &column, values.default_value())
+    }
+
+    pub fn open(path: &Path, access: AccessPattern) -> Result<Self, MappedFileError> {
+        let file = MappedFile::open_kind(path, MappedFileKind::DoubleValues, access)?;
+        let values = file.sections().next(file.count())?;
+        Ok(Self {
+            values,
+            default_value: f64::from_bits(file.aux()),
+        })
+    }
+
+    fn value(&self, rel_index: RelationshipIndex) -> PropertyValuesResult<f64> {
+        rel_index
+            .to_usize()
+            .and_then(|index| self.values.get(index).copied())
+            .ok_or(PropertyValuesError::ValueNotFound(rel_index.get()))
+    }
+}
+
+impl PropertyValues for MappedLongRelationshipPropertyValues {
+    fn value_type(&self) -> ValueType {
+        ValueType::Long
+    }
+
+    fn element_count(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl RelationshipPropertyValues for MappedLongRelationshipPropertyValues {
+    fn double_value(&self, rel_index: RelationshipIndex) -> PropertyValuesResult<f64> {
+        checked_long_to_double_property(self.value(rel_index)?)
+    }
+
+    fn long_value(&self, rel_index: RelationshipIndex) -> PropertyValuesResult<i64> {
+        self.value(rel_index)
+    }
+
+    fn get_object(
+        &self,
+        rel_index: RelationshipIndex,
+    ) -> PropertyValuesResult<Box<dyn std::any::Any>> {
+        Ok(Box::new(self.value(rel_index)?))
+    }
+
+    fn default_value(&self) -> f64 {
+        self.default_value as f64
+    }
+
+    fn has_value(&self, rel_index: RelationshipIndex) -> bool {
+        rel_index
+            .to_usize()
+            .is_some_and(|index| index < self.values.len())
+    }
+}
+
+impl LongRelationshipPropertyValues for MappedLongRelationshipPropertyValues {
+    fn long_value_unchecked(&self, rel_index: RelationshipIndex) -> i64 {
+        self.value(rel_index).unwrap_or(self.default_value)
+    }
+}
+
+impl PropertyValues for MappedDoubleRelationshipPropertyValues {
+    fn value_type(&self) -> ValueType {
+        ValueType::Double
+    }
+
+    fn element_count(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl RelationshipPropertyValues for MappedDoubleRelationshipPropertyValues {
+    fn double_value(&self, rel_index: RelationshipIndex) -> PropertyValuesResult<f64> {
+        self.value(rel_index)
+    }
+
+    fn long_value(&self, rel_index: RelationshipIndex) -> PropertyValuesResult<i64> {
+        checked_double_to_long_property(self.value(rel_index)?)
+    }
+
+    fn get_object(
+        &self,
+        rel_index: RelationshipIndex,
+    ) -> PropertyValuesResult<Box<dyn std::any::Any>> {
+        Ok(Box::new(self.value(rel_index)?))
+    }
+
+    fn default_value(&self) -> f64 {
+        self.default_value
+    }
+
+    fn has_value(&self, rel_index: RelationshipIndex) -> bool {
+        rel_index
+            .to_usize()
+            .is_some_and(|index| index < self.values.len())
+    }
+}
+
+impl DoubleRelationshipPropertyValues for MappedDoubleRelationshipPropertyValues {
+    fn double_value_unchecked(&self, rel_index: RelationshipIndex) -> f64 {
+        self.value(rel_index).unwrap_or(self.default_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::properties::relationship::DefaultRelationshipPropertyValues;
+
+    #[test]
+    fn mapped_relationship_values_keep_default() {
+        let path = std::env::temp_dir().join(format!("gds-mapped-rel-{}", uuid::Uuid::new_v4()));
+        let heap = DefaultRelationshipPropertyValues::with_values(vec![0.5, 2.0], 1.5, 2);
+        MappedDoubleRelationshipPropertyValues::write_from(&path, &heap).unwrap();
+
+        let values =
+            MappedDoubleRelationshipPropertyValues::open(&path, AccessPattern::Random).unwrap();
+        assert_eq!(values.relationship_count(), 2);
+        assert_eq!(values.default_value(), 1.5);
+        assert_eq!(values.double_value(RelationshipIndex::new(1)).unwrap(), 2.0);
+        assert_eq!(values.long_value(RelationshipIndex::new(1)).unwrap(), 2);
+        assert_eq!(
+            values.double_value_unchecked(RelationshipIndex::new(5)),
+            1.5
+        );
+        assert!(!values.has_value(RelationshipIndex::new(2)));
+
+        let long_path =
+            std::env::temp_dir().join(format!("gds-mapped-rel-{}", uuid::Uuid::new_v4()));
+        MappedLongRelationshipPropertyValues::write(&long_path, &[4, -2], -1).unwrap();
+        let longs =
+            MappedLongRelationshipPropertyValues::open(&long_path, AccessPattern::Sequential)
+                .unwrap();
+        assert_eq!(longs.long_value_unchecked(RelationshipIndex::ZERO), 4);
+        assert_eq!(longs.default_value(), -1.0);
+        assert!(matches!(
+            longs.long_value(RelationshipIndex::new(2)),
+            Err(PropertyValuesError::ValueNotFound(2))
+        ));
+
+        std::fs::remove_file(path).unwrap();
+        std::fs::remove_file(long_path).unwrap();
+    }
+}
@@ -5,6 +5,7 @@ pub mod default_relationship_property_store;
 // Legacy consolidated module - kept for backward compatibility
 // New code should use the macro-generated implementations
 pub mod default_relationship_property_values;
+pub mod mapped_relationship_property_values;
 
 // Modular value type implementations - using macro system instead
 // pub mod values; // Removed - using macro-generated implementations
@@ -15,3 +16,4 @@ pub use default_relationship_property_store::*;
 
 // Re-export macro-generated relationship property values
 pub use default_relationship_property_values::*;
+pub use mapped_relationship_property_values::*;
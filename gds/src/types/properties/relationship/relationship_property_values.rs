@@ -29,6 +29,11 @@ pub trait RelationshipPropertyValues: PropertyValues + std::fmt::Debug + Send +
 
     /// Returns whether the relationship has a value.
     fn has_value(&self, rel_index: RelationshipIndex) -> bool;
+
+    /// Returns these values as `Any`, for implementations that can be downcast.
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        None
+    }
 }
 
 // ========== Specialized traits for typed relationship property values ==========
@@ -273,11 +273,13 @@ impl Randomizable<RandomGraphConfig> for DefaultGraphStore {
         for mapped_id in 0..config.node_count {
             let label_index = rng.gen_range(0..node_labels.len());
             let label = node_labels[label_index].clone();
-            id_map.add_node_id_to_label(
-                MappedNodeId::try_from(mapped_id)
-                    .expect("graph node count must fit mapped node IDs"),
-                label,
-            );
+            id_map
+                .add_node_id_to_label(
+                    MappedNodeId::try_from(mapped_id)
+                        .expect("graph node count must fit mapped node IDs"),
+                    label,
+                )
+                .expect("random node IDs stay below the node count");
         }
 
         let direction = if config.directed {
@@ -326,7 +328,7 @@ impl Randomizable<RandomGraphConfig> for DefaultGraphStore {
             // Relationship property values are indexed by a stable ordering:
             // flatten outgoing adjacency lists in node-id order.
             let mut weights: Vec<f64> = Vec::with_capacity(topology.relationship_count());
-            for neighbors in topology.outgoing_lists() {
+            for neighbors in topology.outgoing_lists().iter() {
                 for _ in neighbors {
                     // Keep weights strictly positive to avoid confusing "free" edges in demos.
                     weights.push(rng.gen_range(0.1..1.0));
@@ -377,6 +377,9 @@ fn graphsage_train_config_valid_and_invalid() {
         activation_function: ActivationFunctionType::Relu,
         is_multi_label: false,
         projected_feature_dimension: None,
+        attention_heads: 1,
+        label_property: None,
+        optimizer: Default::default(),
     };
     assert!(ValidatedConfig::validate(&valid).is_ok());
 
@@ -276,7 +276,8 @@ fn build_store(
     for (mapped, label) in (0..node_count).zip(labels.iter().cycle()) {
         let mapped = MappedNodeId::try_from(mapped).expect("fixture node must fit mapped ID space");
         IdMap::add_node_label(&mut id_map, label.clone());
-        IdMap::add_node_id_to_label(&mut id_map, mapped, label.clone());
+        IdMap::add_node_id_to_label(&mut id_map, mapped, label.clone())
+            .expect("fixture node must be in range");
     }
 
     let mut relationship_topologies = HashMap::new();
@@ -323,7 +324,8 @@ fn build_store_multi(
     for (mapped, label) in (0..node_count).zip(labels.iter().cycle()) {
         let mapped = MappedNodeId::try_from(mapped).expect("fixture node must fit mapped ID space");
         IdMap::add_node_label(&mut id_map, label.clone());
-        IdMap::add_node_id_to_label(&mut id_map, mapped, label.clone());
+        IdMap::add_node_id_to_label(&mut id_map, mapped, label.clone())
+            .expect("fixture node must be in range");
     }
 
     let mut relationship_topologies = HashMap::new();